@@ -148,6 +148,9 @@ impl VisitMut for AddAttr {
     fn visit_field_mut(&mut self, f: &mut Field) {
         f.attrs
             .push(parse_quote!(#[cfg_attr(feature = "rkyv", omit_bounds)]));
+        f.attrs.push(parse_quote!(
+            #[cfg_attr(feature = "rkyv", archive_attr(omit_bounds))]
+        ));
     }
 }
 
@@ -195,6 +198,15 @@ pub fn ast_node(
                         serialize = "__S: rkyv::ser::Serializer + rkyv::ser::ScratchSpace"
                     ))
                 )]
+                #[cfg_attr(
+                    feature = "rkyv",
+                    archive_attr(
+                        derive(bytecheck::CheckBytes),
+                        check_bytes(
+                            bound = "__C: rkyv::validation::ArchiveContext, <__C as rkyv::Fallible>::Error: std::error::Error"
+                        )
+                    )
+                )]
                 #[serde(untagged)]
                 input
             }))
@@ -244,6 +256,15 @@ pub fn ast_node(
                         feature = "rkyv",
                         archive(bound(serialize = "__S: rkyv::ser::Serializer + rkyv::ser::ScratchSpace"))
                     )]
+                    #[cfg_attr(
+                        feature = "rkyv",
+                        archive_attr(
+                            derive(bytecheck::CheckBytes),
+                            check_bytes(
+                                bound = "__C: rkyv::validation::ArchiveContext, <__C as rkyv::Fallible>::Error: std::error::Error"
+                            )
+                        )
+                    )]
                     serde_tag
                     #[serde(rename_all = "camelCase")]
                     serde_rename
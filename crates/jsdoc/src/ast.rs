@@ -296,6 +296,8 @@ pub struct PropertyTag {
 
     pub name_path: NamePath,
     pub ty: Option<Text>,
+    /// `ty` parsed as a type expression, if it's valid.
+    pub type_expr: Option<TypeExpr>,
     pub desc: Text,
 }
 
@@ -306,34 +308,46 @@ pub struct ParameterTag {
     pub name: Option<Text>,
     #[serde(rename = "typeExpression")]
     pub ty: Option<Text>,
+    /// `ty` parsed as a type expression, if it's valid.
+    pub type_expr: Option<TypeExpr>,
 
     pub desc: Text,
 }
 
 #[ast_node]
-#[derive(Eq)]
 pub struct EnumTag {
     pub span: Span,
     pub ty: Text,
+    /// `ty` parsed as a type expression, if it's valid.
+    pub type_expr: Option<TypeExpr>,
 }
 
 #[ast_node]
 pub struct ReturnTag {
     pub span: Span,
     pub ty: Option<Text>,
+    /// `ty` parsed as a type expression, if it's valid.
+    pub type_expr: Option<TypeExpr>,
     pub description: Text,
 }
 
 #[ast_node]
 pub struct ThisTag {
     pub span: Span,
-    pub name_path: NamePath,
+    /// The type of `this`, like `{Foo}`.
+    pub ty: Option<Text>,
+    /// `ty` parsed as a type expression, if it's valid.
+    pub type_expr: Option<TypeExpr>,
+    /// The name path of `this`, like `Foo`. This is [None] if `ty` is used.
+    pub name_path: Option<NamePath>,
 }
 
 #[ast_node]
 pub struct TypeTag {
     pub span: Span,
     pub name: Text,
+    /// `name` parsed as a type expression, if it's valid.
+    pub type_expr: Option<TypeExpr>,
 }
 
 #[ast_node]
@@ -361,14 +375,19 @@ pub enum Type {
     /// `?`
     #[tag("UnknownType")]
     Unknown(UnknownType),
+    /// `?T` or `T?`
     #[tag("NullableType")]
     Nullable(NullableType),
+    /// `!T` or `T!`
     #[tag("NonNullableType")]
     NonNullable(NonNullableType),
+    /// `T=`
     #[tag("OptionalType")]
     Optional(OptionalType),
+    /// `function(string): number` or `(a: string) => number`
     #[tag("FunctionType")]
     Function(FunctionType),
+    /// `...T`
     #[tag("VariadicType")]
     Variadic(VariadicType),
     // https://jsdoc.app/about-namepaths.html
@@ -376,8 +395,29 @@ pub enum Type {
     NamePath(NamePathType),
     #[tag("UnionType")]
     Union(UnionType),
+    #[tag("IntersectionType")]
+    Intersection(IntersectionType),
     #[tag("ParenType")]
     Paren(ParenType),
+    /// `Array.<T>` or `Array<T>`
+    #[tag("GenericType")]
+    Generic(GenericType),
+    /// `T[]`
+    #[tag("ArrayType")]
+    Array(ArrayType),
+    /// `{a: string, b}`
+    #[tag("RecordType")]
+    Record(RecordType),
+    /// `[string, number]`
+    #[tag("TupleType")]
+    Tuple(TupleType),
+    #[tag("StringLiteralType")]
+    StrLit(StrLitType),
+    #[tag("NumericLiteralType")]
+    NumLit(NumLitType),
+    /// `import("./foo").Bar`
+    #[tag("ImportType")]
+    Import(ImportType),
 }
 
 #[ast_node]
@@ -386,6 +426,12 @@ pub struct UnionType {
     pub types: Vec<Type>,
 }
 
+#[ast_node]
+pub struct IntersectionType {
+    pub span: Span,
+    pub types: Vec<Type>,
+}
+
 #[ast_node]
 pub struct ParenType {
     pub span: Span,
@@ -422,11 +468,27 @@ pub struct OptionalType {
     pub ty: Box<Type>,
 }
 
-/// TODO: Add fields
 #[ast_node]
 pub struct FunctionType {
     pub span: Span,
-    pub ty: Box<Type>,
+    /// `this:T` of closure-style function types.
+    #[serde(rename = "thisType")]
+    pub this_ty: Option<Box<Type>>,
+    /// `new:T` of closure-style function types.
+    #[serde(rename = "newType")]
+    pub new_ty: Option<Box<Type>>,
+    pub params: Vec<FunctionParam>,
+    #[serde(rename = "returnType")]
+    pub ret_ty: Option<Box<Type>>,
+}
+
+#[ast_node]
+pub struct FunctionParam {
+    pub span: Span,
+    /// Only arrow-style function types have names.
+    pub name: Option<Text>,
+    /// [None] for an untyped parameter of an arrow-style function type.
+    pub ty: Option<Type>,
 }
 
 #[ast_node]
@@ -438,7 +500,63 @@ pub struct VariadicType {
 #[ast_node]
 pub struct NamePathType {
     pub span: Span,
-    pub ty: Box<Type>,
+    pub name: NamePath,
+}
+
+#[ast_node]
+pub struct GenericType {
+    pub span: Span,
+    pub name: NamePath,
+    pub args: Vec<Type>,
+}
+
+#[ast_node]
+pub struct ArrayType {
+    pub span: Span,
+    #[serde(rename = "elementType")]
+    pub elem_ty: Box<Type>,
+}
+
+#[ast_node]
+pub struct RecordType {
+    pub span: Span,
+    pub fields: Vec<RecordField>,
+}
+
+#[ast_node]
+pub struct RecordField {
+    pub span: Span,
+    pub key: Text,
+    /// `key?: T`
+    pub optional: bool,
+    pub ty: Option<Type>,
+}
+
+#[ast_node]
+pub struct TupleType {
+    pub span: Span,
+    pub types: Vec<Type>,
+}
+
+#[ast_node]
+#[derive(Eq)]
+pub struct StrLitType {
+    pub span: Span,
+    /// Without quotes.
+    pub value: Text,
+}
+
+#[ast_node]
+pub struct NumLitType {
+    pub span: Span,
+    pub value: f64,
+}
+
+#[ast_node]
+pub struct ImportType {
+    pub span: Span,
+    pub src: Text,
+    pub qualifier: Option<NamePath>,
 }
 
 /// represents a top level: { type } expression in a JSDoc comment.
@@ -628,6 +746,8 @@ pub struct MemberOfTag {
 pub struct TypeDefTag {
     pub span: Span,
     pub ty: Option<Text>,
+    /// `ty` parsed as a type expression, if it's valid.
+    pub type_expr: Option<TypeExpr>,
     pub name_path: NamePath,
 }
 
@@ -737,5 +857,7 @@ pub struct VersionTag {
 pub struct YieldTag {
     pub span: Span,
     pub value: Option<Text>,
+    /// `value` parsed as a type expression, if it's valid.
+    pub type_expr: Option<TypeExpr>,
     pub description: Text,
 }
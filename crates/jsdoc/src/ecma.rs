@@ -0,0 +1,869 @@
+//! Integration with `swc_ecma_ast`.
+
+use crate::ast::{self, JsDoc, Tag, Text, TypeExpr};
+use swc_atoms::{js_word, JsWord};
+use swc_common::{
+    collections::AHashMap,
+    comments::{CommentKind, Comments},
+    util::take::Take,
+    BytePos, Span, Spanned, DUMMY_SP,
+};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+/// Converts a jsdoc type to a typescript type.
+///
+/// Closure-style types are mapped in the same way as typescript does for
+/// javascript files. For example, `Object.<string, number>` becomes
+/// `{ [x: string]: number }` and `?` becomes `any`.
+pub fn to_ts_type(ty: &ast::Type) -> TsType {
+    let span = ty.span();
+
+    match ty {
+        ast::Type::All(..) | ast::Type::Unknown(..) => {
+            keyword(span, TsKeywordTypeKind::TsAnyKeyword)
+        }
+
+        ast::Type::Nullable(ty) => union(
+            span,
+            vec![
+                to_ts_type(&ty.ty),
+                keyword(span, TsKeywordTypeKind::TsNullKeyword),
+            ],
+        ),
+
+        ast::Type::NonNullable(ty) => to_ts_type(&ty.ty),
+
+        ast::Type::Optional(ty) => union(
+            span,
+            vec![
+                to_ts_type(&ty.ty),
+                keyword(span, TsKeywordTypeKind::TsUndefinedKeyword),
+            ],
+        ),
+
+        ast::Type::Variadic(ty) => array(span, to_ts_type(&ty.ty)),
+
+        ast::Type::Function(f) => fn_type(f),
+
+        ast::Type::NamePath(ty) => name_path_type(span, &ty.name),
+
+        ast::Type::Union(ty) => union(span, ty.types.iter().map(to_ts_type).collect()),
+
+        ast::Type::Intersection(ty) => TsType::TsUnionOrIntersectionType(
+            TsUnionOrIntersectionType::TsIntersectionType(TsIntersectionType {
+                span,
+                types: ty
+                    .types
+                    .iter()
+                    .map(to_ts_type)
+                    .map(|ty| match ty {
+                        TsType::TsFnOrConstructorType(..)
+                        | TsType::TsUnionOrIntersectionType(
+                            TsUnionOrIntersectionType::TsUnionType(..),
+                        ) => paren(ty),
+                        _ => ty,
+                    })
+                    .map(Box::new)
+                    .collect(),
+            }),
+        ),
+
+        ast::Type::Paren(ty) => paren(to_ts_type(&ty.ty)),
+
+        ast::Type::Generic(ty) => generic_type(ty),
+
+        ast::Type::Array(ty) => array(span, to_ts_type(&ty.elem_ty)),
+
+        ast::Type::Record(ty) => TsType::TsTypeLit(TsTypeLit {
+            span,
+            members: ty
+                .fields
+                .iter()
+                .map(|field| {
+                    property(
+                        field.span,
+                        &field.key,
+                        field.optional,
+                        field.ty.as_ref().map(to_ts_type),
+                    )
+                })
+                .collect(),
+        }),
+
+        ast::Type::Tuple(ty) => TsType::TsTupleType(TsTupleType {
+            span,
+            elem_types: ty
+                .types
+                .iter()
+                .map(|ty| TsTupleElement {
+                    span: ty.span(),
+                    label: None,
+                    ty: to_ts_type(ty),
+                })
+                .collect(),
+        }),
+
+        ast::Type::StrLit(ty) => TsType::TsLitType(TsLitType {
+            span,
+            lit: TsLit::Str(str_lit(&ty.value)),
+        }),
+
+        ast::Type::NumLit(ty) => TsType::TsLitType(TsLitType {
+            span,
+            lit: TsLit::Number(Number {
+                span,
+                value: ty.value,
+            }),
+        }),
+
+        ast::Type::Import(ty) => TsType::TsImportType(TsImportType {
+            span,
+            arg: str_lit(&ty.src),
+            qualifier: ty
+                .qualifier
+                .as_ref()
+                .map(|name| entity_name(&name.components)),
+            type_args: None,
+        }),
+    }
+}
+
+/// Adds type annotations to functions, variables and class members using
+/// their jsdoc comments, and declares type aliases for `@typedef`s.
+///
+/// Existing type annotations are preserved.
+pub fn annotate<C>(comments: C) -> impl Fold + VisitMut
+where
+    C: Comments,
+{
+    as_folder(Annotator { comments })
+}
+
+struct Annotator<C>
+where
+    C: Comments,
+{
+    comments: C,
+}
+
+impl<C> Annotator<C>
+where
+    C: Comments,
+{
+    fn jsdocs(&self, pos: BytePos) -> Vec<JsDoc> {
+        leading_jsdocs(&self.comments, pos)
+    }
+
+    fn jsdoc(&self, pos: BytePos) -> Option<JsDoc> {
+        leading_jsdocs(&self.comments, pos).pop()
+    }
+}
+
+impl<C> VisitMut for Annotator<C>
+where
+    C: Comments,
+{
+    noop_visit_mut_type!();
+
+    fn visit_mut_class_member(&mut self, member: &mut ClassMember) {
+        if let Some(doc) = self.jsdoc(member.span().lo) {
+            match member {
+                ClassMember::Constructor(c) => {
+                    for param in &mut c.params {
+                        if let ParamOrTsParamProp::Param(param) = param {
+                            annotate_param(&mut param.pat, &doc);
+                        }
+                    }
+                }
+                ClassMember::Method(m) => annotate_fn(&mut m.function, &doc),
+                ClassMember::PrivateMethod(m) => annotate_fn(&mut m.function, &doc),
+                ClassMember::ClassProp(p) => {
+                    if p.type_ann.is_none() {
+                        p.type_ann = type_tag(&doc).map(type_ann);
+                    }
+                }
+                ClassMember::PrivateProp(p) => {
+                    if p.type_ann.is_none() {
+                        p.type_ann = type_tag(&doc).map(type_ann);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        member.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        let mut buf = Vec::with_capacity(items.len());
+
+        for mut item in items.take() {
+            let docs = self.jsdocs(item.span().lo);
+
+            buf.extend(docs.iter().flat_map(typedefs).map(|decl| {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    span: decl.span,
+                    decl: Decl::TsTypeAlias(decl),
+                }))
+            }));
+
+            if let Some(doc) = docs.last() {
+                match &mut item {
+                    ModuleItem::Stmt(Stmt::Decl(decl))
+                    | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                        annotate_decl(decl, doc)
+                    }
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                        decl: DefaultDecl::Fn(f),
+                        ..
+                    })) => annotate_fn(&mut f.function, doc),
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(e)) => {
+                        annotate_expr(&mut e.expr, doc)
+                    }
+                    _ => {}
+                }
+            }
+
+            item.visit_mut_with(self);
+            buf.push(item);
+        }
+
+        *items = buf;
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        let mut buf = Vec::with_capacity(stmts.len());
+
+        for mut stmt in stmts.take() {
+            let docs = self.jsdocs(stmt.span().lo);
+
+            buf.extend(
+                docs.iter()
+                    .flat_map(typedefs)
+                    .map(|decl| Stmt::Decl(Decl::TsTypeAlias(decl))),
+            );
+
+            if let (Some(doc), Stmt::Decl(decl)) = (docs.last(), &mut stmt) {
+                annotate_decl(decl, doc);
+            }
+
+            stmt.visit_mut_with(self);
+            buf.push(stmt);
+        }
+
+        *stmts = buf;
+    }
+}
+
+/// Parsed jsdoc comments of a module, created by [collect_docs].
+#[derive(Debug, Default)]
+pub struct DeclDocs {
+    /// Jsdoc of functions, classes, variables and typescript declarations.
+    ///
+    /// This is only reliable if the resolver is applied to the module.
+    pub ids: AHashMap<Id, JsDoc>,
+    /// Jsdoc of statements, module declarations, class members and object
+    /// properties, keyed by the span of the documented node.
+    ///
+    /// Unlike `ids`, this includes nodes without names, like
+    /// `Foo.prototype.bar = function () {}`.
+    pub spans: AHashMap<Span, JsDoc>,
+}
+
+impl DeclDocs {
+    pub fn get(&self, id: &Id) -> Option<&JsDoc> {
+        self.ids.get(id)
+    }
+
+    pub fn get_by_span(&self, span: Span) -> Option<&JsDoc> {
+        self.spans.get(&span)
+    }
+}
+
+/// Parses the jsdoc comment of each declaration in `module`.
+///
+/// If a node has multiple jsdoc comments, the last one is used.
+///
+/// The resolver should be applied to `module` before calling this, as
+/// [DeclDocs::ids] is keyed by the syntax context of identifiers. Otherwise,
+/// declarations with the same name in different scopes share an entry.
+pub fn collect_docs<C>(module: &Module, comments: C) -> DeclDocs
+where
+    C: Comments,
+{
+    let mut v = DocCollector {
+        comments,
+        docs: Default::default(),
+    };
+    module.visit_with(&mut v);
+    v.docs
+}
+
+struct DocCollector<C>
+where
+    C: Comments,
+{
+    comments: C,
+    docs: DeclDocs,
+}
+
+impl<C> DocCollector<C>
+where
+    C: Comments,
+{
+    fn add_span(&mut self, span: Span) -> Option<JsDoc> {
+        let doc = leading_jsdocs(&self.comments, span.lo).pop()?;
+        self.docs.spans.insert(span, doc.clone());
+        Some(doc)
+    }
+
+    fn add_ident(&mut self, i: &Ident, doc: &JsDoc) {
+        self.docs
+            .ids
+            .insert((i.sym.clone(), i.span.ctxt), doc.clone());
+    }
+
+    fn add_decl(&mut self, decl: &Decl, doc: &JsDoc) {
+        match decl {
+            Decl::Class(c) => self.add_ident(&c.ident, doc),
+            Decl::Fn(f) => self.add_ident(&f.ident, doc),
+            Decl::Var(v) => {
+                for decl in &v.decls {
+                    if let Pat::Ident(name) = &decl.name {
+                        self.add_ident(&name.id, doc);
+                    }
+                }
+            }
+            Decl::TsInterface(d) => self.add_ident(&d.id, doc),
+            Decl::TsTypeAlias(d) => self.add_ident(&d.id, doc),
+            Decl::TsEnum(d) => self.add_ident(&d.id, doc),
+            Decl::TsModule(d) => {
+                if let TsModuleName::Ident(i) = &d.id {
+                    self.add_ident(i, doc);
+                }
+            }
+        }
+    }
+}
+
+impl<C> Visit for DocCollector<C>
+where
+    C: Comments,
+{
+    noop_visit_type!();
+
+    fn visit_class_member(&mut self, n: &ClassMember) {
+        self.add_span(n.span());
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_module_decl(&mut self, n: &ModuleDecl) {
+        if let Some(doc) = self.add_span(n.span()) {
+            match n {
+                ModuleDecl::ExportDecl(e) => self.add_decl(&e.decl, &doc),
+                ModuleDecl::ExportDefaultDecl(e) => match &e.decl {
+                    DefaultDecl::Class(ClassExpr {
+                        ident: Some(ident), ..
+                    })
+                    | DefaultDecl::Fn(FnExpr {
+                        ident: Some(ident), ..
+                    }) => self.add_ident(ident, &doc),
+                    DefaultDecl::TsInterfaceDecl(d) => self.add_ident(&d.id, &doc),
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_prop(&mut self, n: &Prop) {
+        self.add_span(n.span());
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_stmt(&mut self, n: &Stmt) {
+        if let Some(doc) = self.add_span(n.span()) {
+            if let Stmt::Decl(decl) = n {
+                self.add_decl(decl, &doc);
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+/// Parses jsdoc comments before `pos`.
+fn leading_jsdocs<C>(comments: &C, pos: BytePos) -> Vec<JsDoc>
+where
+    C: Comments,
+{
+    let comments = comments.get_leading(pos).unwrap_or_default();
+
+    comments
+        .iter()
+        .filter(|c| c.kind == CommentKind::Block && c.text.starts_with('*'))
+        .filter_map(|c| crate::parse(c.into()).ok().map(|(_, doc)| doc))
+        .collect()
+}
+
+fn annotate_decl(decl: &mut Decl, doc: &JsDoc) {
+    match decl {
+        Decl::Fn(f) => annotate_fn(&mut f.function, doc),
+        Decl::Var(v) => {
+            for decl in &mut v.decls {
+                match decl.init.as_deref_mut() {
+                    Some(init @ (Expr::Fn(..) | Expr::Arrow(..))) => annotate_expr(init, doc),
+                    _ => {
+                        if let Pat::Ident(name) = &mut decl.name {
+                            if name.type_ann.is_none() {
+                                name.type_ann = type_tag(doc).map(type_ann);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn annotate_expr(e: &mut Expr, doc: &JsDoc) {
+    match e {
+        Expr::Fn(f) => annotate_fn(&mut f.function, doc),
+        Expr::Arrow(f) => {
+            for param in &mut f.params {
+                annotate_param(param, doc);
+            }
+
+            if f.return_type.is_none() {
+                f.return_type = return_tag(doc).map(type_ann);
+            }
+        }
+        Expr::Paren(e) => annotate_expr(&mut e.expr, doc),
+        _ => {}
+    }
+}
+
+fn annotate_fn(f: &mut Function, doc: &JsDoc) {
+    for param in &mut f.params {
+        annotate_param(&mut param.pat, doc);
+    }
+
+    if f.return_type.is_none() {
+        f.return_type = return_tag(doc).map(type_ann);
+    }
+}
+
+fn annotate_param(pat: &mut Pat, doc: &JsDoc) {
+    match pat {
+        Pat::Ident(i) => {
+            if i.type_ann.is_some() {
+                return;
+            }
+
+            if let Some((ty, optional)) = param_tag(doc, &i.id.sym) {
+                i.id.optional |= optional;
+                i.type_ann = Some(type_ann(ty));
+            }
+        }
+
+        // Default values make a parameter optional.
+        Pat::Assign(AssignPat { left, .. }) => {
+            if let Pat::Ident(i) = &mut **left {
+                if i.type_ann.is_none() {
+                    i.type_ann = param_tag(doc, &i.id.sym).map(|(ty, _)| type_ann(ty));
+                }
+            }
+        }
+
+        Pat::Rest(RestPat {
+            arg, type_ann: ann, ..
+        }) => {
+            if let Pat::Ident(i) = &**arg {
+                if ann.is_none() {
+                    *ann = param_tag(doc, &i.id.sym).map(|(ty, _)| match ty {
+                        TsType::TsArrayType(..) => type_ann(ty),
+                        _ => type_ann(array(ty.span(), ty)),
+                    });
+                }
+            }
+        }
+
+        _ => {}
+    }
+}
+
+/// Returns the type of `@param` named `name`, and whether it's optional.
+///
+/// Optional types and `...` of rest parameters are removed from the returned
+/// type.
+fn param_tag(doc: &JsDoc, name: &str) -> Option<(TsType, bool)> {
+    doc.tags.iter().find_map(|tag| {
+        let tag = match &tag.tag {
+            Tag::Parameter(tag) => tag,
+            _ => return None,
+        };
+
+        let (tag_name, bracketed) = match tag.name.as_ref().map(|name| &*name.value)? {
+            // `[name]` or `[name=default]`
+            n if n.starts_with('[') && n.ends_with(']') => {
+                let n = &n[1..n.len() - 1];
+                (n.split('=').next().unwrap_or(n).trim(), true)
+            }
+            n => (n, false),
+        };
+        if tag_name != name {
+            return None;
+        }
+
+        let TypeExpr { ty, .. } = tag.type_expr.as_ref()?;
+        Some(match ty {
+            ast::Type::Optional(ty) => (to_ts_type(&ty.ty), true),
+            ast::Type::Variadic(ty) => (to_ts_type(&ty.ty), bracketed),
+            _ => (to_ts_type(ty), bracketed),
+        })
+    })
+}
+
+fn return_tag(doc: &JsDoc) -> Option<TsType> {
+    doc.tags.iter().find_map(|tag| match &tag.tag {
+        Tag::Return(tag) => tag.type_expr.as_ref().map(|e| to_ts_type(&e.ty)),
+        _ => None,
+    })
+}
+
+fn type_tag(doc: &JsDoc) -> Option<TsType> {
+    doc.tags.iter().find_map(|tag| match &tag.tag {
+        Tag::Type(tag) => tag.type_expr.as_ref().map(|e| to_ts_type(&e.ty)),
+        _ => None,
+    })
+}
+
+/// Creates type aliases for `@typedef`s.
+///
+/// `@property` tags following `@typedef {Object}` are used as members of the
+/// type.
+fn typedefs(doc: &JsDoc) -> impl '_ + Iterator<Item = TsTypeAliasDecl> {
+    doc.tags.iter().enumerate().filter_map(move |(idx, tag)| {
+        let tag = match &tag.tag {
+            Tag::TypeDef(tag) => tag,
+            _ => return None,
+        };
+        let name = tag.name_path.components.last()?;
+        if Ident::verify_symbol(&name.value).is_err() {
+            return None;
+        }
+
+        let props = doc.tags[idx + 1..]
+            .iter()
+            .map_while(|tag| match &tag.tag {
+                Tag::Property(prop) => Some(prop),
+                _ => None,
+            })
+            .filter_map(|prop| {
+                let key = match &*prop.name_path.components {
+                    [key] => key,
+                    _ => return None,
+                };
+                let (ty, optional) = match prop.type_expr.as_ref().map(|e| &e.ty) {
+                    Some(ast::Type::Optional(ty)) => (Some(to_ts_type(&ty.ty)), true),
+                    ty => (ty.map(to_ts_type), false),
+                };
+
+                Some(property(prop.span, key, optional, ty))
+            })
+            .collect::<Vec<_>>();
+
+        let ty = match tag.type_expr.as_ref().map(|e| &e.ty) {
+            Some(ast::Type::NamePath(ty))
+                if !props.is_empty()
+                    && matches!(&*ty.name.components, [n] if &*n.value == "Object" || &*n.value == "object") =>
+            {
+                TsType::TsTypeLit(TsTypeLit {
+                    span: ty.span,
+                    members: props,
+                })
+            }
+            Some(ty) => to_ts_type(ty),
+            None if !props.is_empty() => TsType::TsTypeLit(TsTypeLit {
+                span: tag.span,
+                members: props,
+            }),
+            None => return None,
+        };
+
+        Some(TsTypeAliasDecl {
+            span: tag.span,
+            declare: false,
+            id: Ident::new(name.value.clone(), name.span),
+            type_params: None,
+            type_ann: Box::new(ty),
+        })
+    })
+}
+
+fn name_path_type(span: Span, name: &ast::NamePath) -> TsType {
+    if let [single] = &*name.components {
+        let kind = match &*single.value {
+            "any" | "Object" => Some(TsKeywordTypeKind::TsAnyKeyword),
+            "unknown" => Some(TsKeywordTypeKind::TsUnknownKeyword),
+            // Boxed primitives are almost always used to mean the primitive types in jsdoc.
+            "string" | "String" => Some(TsKeywordTypeKind::TsStringKeyword),
+            "number" | "Number" => Some(TsKeywordTypeKind::TsNumberKeyword),
+            "boolean" | "Boolean" => Some(TsKeywordTypeKind::TsBooleanKeyword),
+            "bigint" => Some(TsKeywordTypeKind::TsBigIntKeyword),
+            "symbol" => Some(TsKeywordTypeKind::TsSymbolKeyword),
+            "object" => Some(TsKeywordTypeKind::TsObjectKeyword),
+            "void" => Some(TsKeywordTypeKind::TsVoidKeyword),
+            "undefined" => Some(TsKeywordTypeKind::TsUndefinedKeyword),
+            "null" => Some(TsKeywordTypeKind::TsNullKeyword),
+            "never" => Some(TsKeywordTypeKind::TsNeverKeyword),
+            _ => None,
+        };
+        if let Some(kind) = kind {
+            return keyword(span, kind);
+        }
+
+        match &*single.value {
+            "this" => return TsType::TsThisType(TsThisType { span }),
+            "function" => {
+                let name = Text {
+                    span: single.span,
+                    value: "Function".into(),
+                };
+                return type_ref(span, &[name], None);
+            }
+            "Array" => return array(span, keyword(span, TsKeywordTypeKind::TsAnyKeyword)),
+            "Promise" => {
+                return type_ref(
+                    span,
+                    &name.components,
+                    Some(vec![keyword(span, TsKeywordTypeKind::TsAnyKeyword)]),
+                )
+            }
+            _ => {}
+        }
+    }
+
+    // `module:foo/bar.Baz` => `import("foo/bar").Baz`
+    if let Some(src) = name.components[0].value.strip_prefix("module:") {
+        return TsType::TsImportType(TsImportType {
+            span,
+            arg: str_lit(&Text {
+                span: name.components[0].span,
+                value: src.into(),
+            }),
+            qualifier: if name.components.len() > 1 {
+                Some(entity_name(&name.components[1..]))
+            } else {
+                None
+            },
+            type_args: None,
+        });
+    }
+
+    type_ref(span, &name.components, None)
+}
+
+fn generic_type(ty: &ast::GenericType) -> TsType {
+    let span = ty.span;
+
+    if let [name] = &*ty.name.components {
+        match (&*name.value, &*ty.args) {
+            ("Array", [elem]) => return array(span, to_ts_type(elem)),
+
+            ("Object", [key, value]) => {
+                return TsType::TsTypeLit(TsTypeLit {
+                    span,
+                    members: vec![TsTypeElement::TsIndexSignature(TsIndexSignature {
+                        span,
+                        params: vec![TsFnParam::Ident(BindingIdent {
+                            id: Ident::new("x".into(), key.span()),
+                            type_ann: Some(type_ann(to_ts_type(key))),
+                        })],
+                        type_ann: Some(type_ann(to_ts_type(value))),
+                        readonly: false,
+                        is_static: false,
+                    })],
+                })
+            }
+
+            _ => {}
+        }
+    }
+
+    type_ref(
+        span,
+        &ty.name.components,
+        Some(ty.args.iter().map(to_ts_type).collect()),
+    )
+}
+
+fn fn_type(f: &ast::FunctionType) -> TsType {
+    let mut params = vec![];
+
+    if let Some(this_ty) = &f.this_ty {
+        params.push(TsFnParam::Ident(BindingIdent {
+            id: Ident::new(js_word!("this"), this_ty.span()),
+            type_ann: Some(type_ann(to_ts_type(this_ty))),
+        }));
+    }
+
+    for (idx, param) in f.params.iter().enumerate() {
+        let sym: JsWord = match &param.name {
+            Some(name) => name.value.clone(),
+            // Same as typescript
+            None => format!("arg{}", idx).into(),
+        };
+        let id = Ident::new(sym, param.name.as_ref().map_or(param.span, |n| n.span));
+
+        params.push(match &param.ty {
+            Some(ast::Type::Variadic(ty)) => TsFnParam::Rest(RestPat {
+                span: param.span,
+                dot3_token: ty.span,
+                arg: Box::new(Pat::Ident(id.into())),
+                type_ann: Some(type_ann(array(ty.span, to_ts_type(&ty.ty)))),
+            }),
+            Some(ast::Type::Optional(ty)) => TsFnParam::Ident(BindingIdent {
+                id: Ident {
+                    optional: true,
+                    ..id
+                },
+                type_ann: Some(type_ann(to_ts_type(&ty.ty))),
+            }),
+            Some(ty) => TsFnParam::Ident(BindingIdent {
+                id,
+                type_ann: Some(type_ann(to_ts_type(ty))),
+            }),
+            None => TsFnParam::Ident(id.into()),
+        });
+    }
+
+    let ret_ty = match &f.ret_ty {
+        Some(ty) => to_ts_type(ty),
+        None => keyword(f.span, TsKeywordTypeKind::TsAnyKeyword),
+    };
+
+    TsType::TsFnOrConstructorType(match &f.new_ty {
+        Some(new_ty) => TsFnOrConstructorType::TsConstructorType(TsConstructorType {
+            span: f.span,
+            params,
+            type_params: None,
+            type_ann: type_ann(to_ts_type(new_ty)),
+            is_abstract: false,
+        }),
+        None => TsFnOrConstructorType::TsFnType(TsFnType {
+            span: f.span,
+            params,
+            type_params: None,
+            type_ann: type_ann(ret_ty),
+        }),
+    })
+}
+
+fn property(span: Span, key: &Text, optional: bool, ty: Option<TsType>) -> TsTypeElement {
+    let key = if Ident::verify_symbol(&key.value).is_ok() {
+        Expr::Ident(Ident::new(key.value.clone(), key.span))
+    } else {
+        Expr::Lit(Lit::Str(str_lit(key)))
+    };
+
+    TsTypeElement::TsPropertySignature(TsPropertySignature {
+        span,
+        readonly: false,
+        key: Box::new(key),
+        computed: false,
+        optional,
+        init: None,
+        params: vec![],
+        type_ann: ty.map(type_ann),
+        type_params: None,
+    })
+}
+
+fn type_ref(span: Span, components: &[Text], args: Option<Vec<TsType>>) -> TsType {
+    TsType::TsTypeRef(TsTypeRef {
+        span,
+        type_name: entity_name(components),
+        type_params: args.map(|args| TsTypeParamInstantiation {
+            span,
+            params: args.into_iter().map(Box::new).collect(),
+        }),
+    })
+}
+
+fn entity_name(components: &[Text]) -> TsEntityName {
+    let (last, rest) = components
+        .split_last()
+        .expect("name path should have at least one component");
+    let right = Ident::new(last.value.clone(), last.span);
+
+    if rest.is_empty() {
+        return TsEntityName::Ident(right);
+    }
+
+    TsEntityName::TsQualifiedName(Box::new(TsQualifiedName {
+        left: entity_name(rest),
+        right,
+    }))
+}
+
+fn union(span: Span, types: Vec<TsType>) -> TsType {
+    TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(TsUnionType {
+        span,
+        types: types
+            .into_iter()
+            .map(|ty| match ty {
+                TsType::TsFnOrConstructorType(..) => paren(ty),
+                _ => ty,
+            })
+            .map(Box::new)
+            .collect(),
+    }))
+}
+
+fn array(span: Span, elem: TsType) -> TsType {
+    let elem = match elem {
+        TsType::TsFnOrConstructorType(..) | TsType::TsUnionOrIntersectionType(..) => paren(elem),
+        _ => elem,
+    };
+
+    TsType::TsArrayType(TsArrayType {
+        span,
+        elem_type: Box::new(elem),
+    })
+}
+
+fn paren(ty: TsType) -> TsType {
+    TsType::TsParenthesizedType(TsParenthesizedType {
+        span: ty.span(),
+        type_ann: Box::new(ty),
+    })
+}
+
+fn keyword(span: Span, kind: TsKeywordTypeKind) -> TsType {
+    TsType::TsKeywordType(TsKeywordType { span, kind })
+}
+
+fn str_lit(text: &Text) -> Str {
+    Str {
+        span: text.span,
+        value: text.value.clone(),
+        has_escape: false,
+        kind: Default::default(),
+    }
+}
+
+fn type_ann(ty: TsType) -> TsTypeAnn {
+    TsTypeAnn {
+        span: DUMMY_SP,
+        type_ann: Box::new(ty),
+    }
+}
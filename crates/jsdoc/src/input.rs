@@ -15,7 +15,10 @@ pub struct Input<'i> {
 
 impl<'a, 'b> From<&'a Comment> for Input<'a> {
     fn from(c: &'a Comment) -> Self {
-        Self::new(c.span.lo, c.span.hi, &c.text)
+        // Skip `/*` or `//`
+        let start = c.span.lo + BytePos(2);
+
+        Self::new(start, start + BytePos(c.text.len() as _), &c.text)
     }
 }
 
@@ -35,20 +38,21 @@ impl<'i> Input<'i> {
 }
 
 macro_rules! impl_slice {
-    ($T:ident) => {
+    ($T:ident, $offset:expr) => {
         impl Slice<$T<usize>> for Input<'_> {
             fn slice(&self, range: $T<usize>) -> Self {
+                let start = self.start + BytePos($offset(&range) as _);
                 let s = self.src.slice(range);
 
-                Self::new(self.start, self.start + BytePos(s.as_bytes().len() as _), s)
+                Self::new(start, start + BytePos(s.as_bytes().len() as _), s)
             }
         }
     };
 }
 
-impl_slice!(Range);
-impl_slice!(RangeFrom);
-impl_slice!(RangeTo);
+impl_slice!(Range, |r: &Range<usize>| r.start);
+impl_slice!(RangeFrom, |r: &RangeFrom<usize>| r.start);
+impl_slice!(RangeTo, |_: &RangeTo<usize>| 0);
 
 impl<'i> From<Input<'i>> for Text {
     fn from(i: Input) -> Self {
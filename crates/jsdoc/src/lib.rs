@@ -1,4 +1,7 @@
-pub use self::input::Input;
+pub use self::{
+    input::Input,
+    ty::{parse_type, parse_type_expr},
+};
 use crate::ast::*;
 use nom::{
     bytes::complete::{tag, take_while},
@@ -8,7 +11,10 @@ use nom::{
 use swc_common::{Span, Spanned, SyntaxContext};
 
 pub mod ast;
+#[cfg(feature = "ecma")]
+pub mod ecma;
 mod input;
+mod ty;
 
 pub fn parse(i: Input) -> IResult<Input, JsDoc> {
     let i = skip(i);
@@ -20,10 +26,16 @@ pub fn parse(i: Input) -> IResult<Input, JsDoc> {
 
     i = skip(i);
 
-    while i.starts_with('@') {
-        let (input, tag) = parse_tag_item(i)?;
-        i = input;
-        tags.push(tag);
+    while !i.is_empty() {
+        if i.starts_with('@') {
+            let (input, tag) = parse_tag_item(i)?;
+            i = input;
+            tags.push(tag);
+        } else {
+            // TODO: Multi-line text of tags
+            let (input, _) = parse_line(i)?;
+            i = input;
+        }
         i = skip(i);
     }
 
@@ -80,8 +92,11 @@ pub fn parse_tag_item(i: Input) -> IResult<Input, TagItem> {
 
         "borrows" => {
             let (input, from) = parse_name_path(i)?;
-            let (_, input) = tag("as")(input)?;
-            let (input, to) = parse_name_path(input)?;
+            // `@borrows foo` is a shorthand of `@borrows foo as foo`
+            let (input, to) = match tag::<_, _, (_, ErrorKind)>("as")(skip_ws(input)) {
+                Ok((_, input)) => parse_name_path(input)?,
+                Err(_) => (input, from.clone()),
+            };
             i = input;
             Tag::Borrows(BorrowsTag { span, from, to })
         }
@@ -147,9 +162,13 @@ pub fn parse_tag_item(i: Input) -> IResult<Input, TagItem> {
         }
 
         "enum" => {
-            let (input, ty) = parse_type(i)?;
+            let (input, ty) = parse_type_str(i)?;
             i = input;
-            Tag::Enum(EnumTag { span, ty })
+            Tag::Enum(EnumTag {
+                span,
+                type_expr: TypeExpr::from_text(&ty),
+                ty,
+            })
         }
 
         "event" => {
@@ -209,7 +228,7 @@ pub fn parse_tag_item(i: Input) -> IResult<Input, TagItem> {
         "ignore" => Tag::Ignore(IgnoreTag { span }),
 
         "implements" => {
-            let (input, class) = parse_type(i)?;
+            let (input, class) = parse_type_str(i)?;
             i = input;
             Tag::Implements(ImplementsTag { span, class })
         }
@@ -323,11 +342,12 @@ pub fn parse_tag_item(i: Input) -> IResult<Input, TagItem> {
 
         "param" | "arg" | "argument" => {
             let (input, ty) = parse_opt_type(i)?;
-            let (input, name) = parse_opt_word(input)?;
+            let (input, name) = parse_param_name(input)?;
             let (input, desc) = parse_line(input)?;
             i = input;
             Tag::Parameter(ParameterTag {
                 span,
+                type_expr: parse_opt_type_expr(&ty),
                 ty,
                 name,
                 desc,
@@ -347,6 +367,7 @@ pub fn parse_tag_item(i: Input) -> IResult<Input, TagItem> {
             i = input;
             Tag::Property(PropertyTag {
                 span,
+                type_expr: parse_opt_type_expr(&ty),
                 ty,
                 name_path,
                 desc,
@@ -375,6 +396,7 @@ pub fn parse_tag_item(i: Input) -> IResult<Input, TagItem> {
             i = input;
             Tag::Return(ReturnTag {
                 span,
+                type_expr: parse_opt_type_expr(&ty),
                 ty,
                 description,
             })
@@ -401,9 +423,25 @@ pub fn parse_tag_item(i: Input) -> IResult<Input, TagItem> {
         }
 
         "this" => {
-            let (input, name_path) = parse_name_path(i)?;
-            i = input;
-            Tag::This(ThisTag { span, name_path })
+            if skip_ws(i).starts_with('{') {
+                let (input, ty) = parse_opt_type(i)?;
+                i = input;
+                Tag::This(ThisTag {
+                    span,
+                    type_expr: parse_opt_type_expr(&ty),
+                    ty,
+                    name_path: None,
+                })
+            } else {
+                let (input, name_path) = parse_name_path(i)?;
+                i = input;
+                Tag::This(ThisTag {
+                    span,
+                    ty: None,
+                    type_expr: None,
+                    name_path: Some(name_path),
+                })
+            }
         }
 
         "throws" => {
@@ -427,7 +465,11 @@ pub fn parse_tag_item(i: Input) -> IResult<Input, TagItem> {
         "type" => {
             let (input, name) = parse_line(i)?;
             i = input;
-            Tag::Type(TypeTag { span, name })
+            Tag::Type(TypeTag {
+                span,
+                type_expr: TypeExpr::from_text(&name),
+                name,
+            })
         }
 
         "typedef" => {
@@ -436,6 +478,7 @@ pub fn parse_tag_item(i: Input) -> IResult<Input, TagItem> {
             i = input;
             Tag::TypeDef(TypeDefTag {
                 span,
+                type_expr: parse_opt_type_expr(&ty),
                 ty,
                 name_path,
             })
@@ -459,6 +502,7 @@ pub fn parse_tag_item(i: Input) -> IResult<Input, TagItem> {
             i = input;
             Tag::Yield(YieldTag {
                 span,
+                type_expr: parse_opt_type_expr(&value),
                 value,
                 description,
             })
@@ -495,10 +539,14 @@ fn parse_str(i: Input) -> IResult<Input, Text> {
     parse_line(i)
 }
 
-fn parse_type(i: Input) -> IResult<Input, Text> {
+fn parse_type_str(i: Input) -> IResult<Input, Text> {
     parse_line(i)
 }
 
+fn parse_opt_type_expr(ty: &Option<Text>) -> Option<TypeExpr> {
+    ty.as_ref().and_then(TypeExpr::from_text)
+}
+
 // ----- ----- Done ----- -----
 
 fn trim(i: Input) -> Input {
@@ -519,10 +567,19 @@ fn trim(i: Input) -> Input {
 fn parse_opt_type(i: Input) -> IResult<Input, Option<Text>> {
     let i = skip_ws(i);
     if i.starts_with('{') {
-        if let Some(pos) = i.find('}') {
-            let ret = i.slice(..pos + 1);
-            let i = i.slice(pos + 1..);
-            return Ok((i, Some(ret.into())));
+        let mut depth = 0;
+        for (pos, c) in i.char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => continue,
+            }
+
+            if depth == 0 {
+                let ret = i.slice(..pos + 1);
+                let i = i.slice(pos + 1..);
+                return Ok((i, Some(ret.into())));
+            }
         }
     }
 
@@ -543,7 +600,8 @@ fn parse_one_of<'i>(i: Input<'i>, list: &[&str]) -> IResult<Input<'i>, Text> {
     Err(nom::Err::Error((i, ErrorKind::Tag)))
 }
 
-fn parse_name_path(mut i: Input) -> IResult<Input, NamePath> {
+fn parse_name_path(i: Input) -> IResult<Input, NamePath> {
+    let mut i = skip_ws(i);
     let lo = i.span().lo;
     let mut components = vec![];
 
@@ -572,6 +630,24 @@ fn parse_name_path(mut i: Input) -> IResult<Input, NamePath> {
     }
 }
 
+/// `name`, `options.name`, `[name]` or `[name=default]`
+fn parse_param_name(i: Input) -> IResult<Input, Option<Text>> {
+    let i = skip_ws(i);
+
+    let end = if i.starts_with('[') {
+        i.find(']').map(|pos| pos + 1)
+    } else {
+        i.find(char::is_whitespace)
+    }
+    .unwrap_or(i.len());
+
+    if end == 0 {
+        return Ok((i, None));
+    }
+
+    Ok((i.slice(end..), Some(i.slice(..end).into())))
+}
+
 fn parse_opt_word(i: Input) -> IResult<Input, Option<Text>> {
     let (i, v) = parse_word(i)?;
     if v.value.is_empty() {
@@ -586,8 +662,14 @@ fn parse_word(i: Input) -> IResult<Input, Text> {
         !(('a' <= *c && *c <= 'z') || ('A' <= *c && *c <= 'Z' || *c == '<' || *c == '>'))
     });
 
-    if let Some((idx, _)) = res {
-        let rest = i.slice(idx + 1..);
+    if let Some((idx, c)) = res {
+        // Line breaks are preserved so that the next line is not parsed as a part of
+        // the current tag.
+        let rest = if c == '\n' || c == '\r' {
+            i.slice(idx..)
+        } else {
+            i.slice(idx + 1..)
+        };
         let ret = i.slice(..idx);
 
         Ok((rest, ret.into()))
@@ -607,7 +689,7 @@ fn parse_line(i: Input) -> IResult<Input, Text> {
 
         Ok((rest, ret.into()))
     } else {
-        Ok((i, Input::empty().into()))
+        Ok((i.slice(i.len()..), i.into()))
     }
 }
 
@@ -750,6 +832,81 @@ mod tests {
         assert_eq!(&*skip(i), "");
     }
 
+    #[test]
+    fn param_tag_record_type() {
+        let (_, ret) = parse_tag_item(input(
+            "@param {{a: number, b: string}} options The options.
+       ",
+        ))
+        .unwrap();
+
+        match ret.tag {
+            Tag::Parameter(tag) => {
+                assert_eq!(
+                    tag.ty.as_ref().map(|v| &*v.value),
+                    Some("{{a: number, b: string}}")
+                );
+                assert_eq!(tag.name.as_ref().map(|v| &*v.value), Some("options"));
+
+                match tag.type_expr.unwrap().ty {
+                    Type::Record(ty) => assert_eq!(ty.fields.len(), 2),
+                    ty => panic!("Invalid type: {:?}", ty),
+                }
+            }
+            _ => panic!("Invalid tag: {:?}", ret.tag),
+        }
+    }
+
+    #[test]
+    fn enum_tag_type_expr() {
+        let (_, ret) = parse_tag_item(input("@enum {number}\n")).unwrap();
+
+        match ret.tag {
+            Tag::Enum(tag) => match tag.type_expr.unwrap().ty {
+                Type::NamePath(ty) => assert_eq!(&*ty.name.components[0].value, "number"),
+                ty => panic!("Invalid type: {:?}", ty),
+            },
+            _ => panic!("Invalid tag: {:?}", ret.tag),
+        }
+    }
+
+    #[test]
+    fn this_tag_type() {
+        let (_, ret) = parse_tag_item(input("@this {(Foo|Bar)}\n")).unwrap();
+
+        match ret.tag {
+            Tag::This(tag) => {
+                assert_eq!(tag.ty.as_ref().map(|v| &*v.value), Some("{(Foo|Bar)}"));
+                assert_eq!(tag.name_path, None);
+
+                match tag.type_expr.unwrap().ty {
+                    Type::Paren(ty) => match *ty.ty {
+                        Type::Union(ty) => assert_eq!(ty.types.len(), 2),
+                        ty => panic!("Invalid type: {:?}", ty),
+                    },
+                    ty => panic!("Invalid type: {:?}", ty),
+                }
+            }
+            _ => panic!("Invalid tag: {:?}", ret.tag),
+        }
+    }
+
+    #[test]
+    fn this_tag_name_path() {
+        let (_, ret) = parse_tag_item(input("@this Foo\n")).unwrap();
+
+        match ret.tag {
+            Tag::This(tag) => {
+                assert_eq!(tag.type_expr, None);
+
+                let name_path = tag.name_path.unwrap();
+                let components: Vec<_> = name_path.components.iter().map(|v| &*v.value).collect();
+                assert_eq!(components, ["Foo"]);
+            }
+            _ => panic!("Invalid tag: {:?}", ret.tag),
+        }
+    }
+
     #[test]
     fn trim_1() {
         assert_eq!(&*trim(input(" foo ")), "foo");
@@ -0,0 +1,891 @@
+//! Parser for type expressions.
+//!
+//! Both closure-style (`function(string, number=): boolean`, `Object.<K, V>`)
+//! and typescript-style (`(a: string) => boolean`, `{ a?: number }`) syntaxes
+//! are accepted.
+
+use crate::{ast::*, Input};
+use nom::{error::ErrorKind, IResult, Slice};
+use swc_common::{BytePos, Span};
+
+impl TypeExpr {
+    /// Parses the type expression stored in a tag, like `{string}`.
+    ///
+    /// Returns [None] if `text` is not a valid type expression.
+    pub fn from_text(text: &Text) -> Option<Self> {
+        let i = Input::new(text.span.lo, text.span.hi, &text.value);
+        let (rest, ty) = parse_type_expr(i).ok()?;
+
+        if skip_ws(rest).is_empty() {
+            Some(ty)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses a type expression wrapped in braces, like `{Array<string>}`.
+pub fn parse_type_expr(i: Input) -> IResult<Input, TypeExpr> {
+    let i = skip_ws(i);
+    let lo = i.span().lo;
+
+    let i = expect(i, "{")?;
+    let (i, ty) = parse_type(i)?;
+    let i = expect(i, "}")?;
+
+    Ok((
+        i,
+        TypeExpr {
+            span: span(lo, i),
+            ty,
+        },
+    ))
+}
+
+/// Parses a type without the surrounding braces.
+pub fn parse_type(i: Input) -> IResult<Input, Type> {
+    let i = skip_ws(i);
+    let lo = i.span().lo;
+    // Leading `|` is allowed by typescript.
+    let i = eat(i, "|").unwrap_or(i);
+
+    let (mut i, ty) = parse_intersection(i)?;
+    let mut types = vec![ty];
+
+    while let Some(rest) = eat(i, "|") {
+        let (rest, ty) = parse_intersection(rest)?;
+        i = rest;
+        types.push(ty);
+    }
+
+    if types.len() == 1 {
+        return Ok((i, types.remove(0)));
+    }
+
+    Ok((
+        i,
+        Type::Union(UnionType {
+            span: span(lo, i),
+            types,
+        }),
+    ))
+}
+
+fn parse_intersection(i: Input) -> IResult<Input, Type> {
+    let i = skip_ws(i);
+    let lo = i.span().lo;
+
+    let (mut i, ty) = parse_prefix(i)?;
+    let mut types = vec![ty];
+
+    while let Some(rest) = eat(i, "&") {
+        let (rest, ty) = parse_prefix(rest)?;
+        i = rest;
+        types.push(ty);
+    }
+
+    if types.len() == 1 {
+        return Ok((i, types.remove(0)));
+    }
+
+    Ok((
+        i,
+        Type::Intersection(IntersectionType {
+            span: span(lo, i),
+            types,
+        }),
+    ))
+}
+
+fn parse_prefix(i: Input) -> IResult<Input, Type> {
+    let i = skip_ws(i);
+    let lo = i.span().lo;
+
+    if let Some(rest) = eat(i, "...") {
+        let (rest, ty) = parse_prefix(rest)?;
+        return Ok((
+            rest,
+            Type::Variadic(VariadicType {
+                span: span(lo, rest),
+                ty: Box::new(ty),
+            }),
+        ));
+    }
+
+    if let Some(rest) = eat(i, "!") {
+        let (rest, ty) = parse_prefix(rest)?;
+        return Ok((
+            rest,
+            Type::NonNullable(NonNullableType {
+                span: span(lo, rest),
+                ty: Box::new(ty),
+            }),
+        ));
+    }
+
+    // `?` without an operand is the unknown type, which is handled by
+    // `parse_primary`.
+    if let Some(rest) = eat(i, "?") {
+        if starts_type(rest) {
+            let (rest, ty) = parse_prefix(rest)?;
+            return Ok((
+                rest,
+                Type::Nullable(NullableType {
+                    span: span(lo, rest),
+                    ty: Box::new(ty),
+                }),
+            ));
+        }
+    }
+
+    parse_postfix(i)
+}
+
+fn parse_postfix(i: Input) -> IResult<Input, Type> {
+    let i = skip_ws(i);
+    let lo = i.span().lo;
+
+    let (mut i, mut ty) = parse_primary(i)?;
+
+    loop {
+        if let Some(rest) = eat(i, "[").and_then(|rest| eat(rest, "]")) {
+            i = rest;
+            ty = Type::Array(ArrayType {
+                span: span(lo, i),
+                elem_ty: Box::new(ty),
+            });
+            continue;
+        }
+
+        if let Some(rest) = eat(i, "=") {
+            if !rest.starts_with('>') {
+                i = rest;
+                ty = Type::Optional(OptionalType {
+                    span: span(lo, i),
+                    ty: Box::new(ty),
+                });
+                continue;
+            }
+        }
+
+        if let Some(rest) = eat(i, "!") {
+            i = rest;
+            ty = Type::NonNullable(NonNullableType {
+                span: span(lo, i),
+                ty: Box::new(ty),
+            });
+            continue;
+        }
+
+        if let Some(rest) = eat(i, "?") {
+            if !skip_ws(rest).starts_with(':') {
+                i = rest;
+                ty = Type::Nullable(NullableType {
+                    span: span(lo, i),
+                    ty: Box::new(ty),
+                });
+                continue;
+            }
+        }
+
+        return Ok((i, ty));
+    }
+}
+
+fn parse_primary(i: Input) -> IResult<Input, Type> {
+    let i = skip_ws(i);
+    let lo = i.span().lo;
+
+    let c = match i.chars().next() {
+        Some(c) => c,
+        None => return Err(nom::Err::Error((i, ErrorKind::Eof))),
+    };
+
+    match c {
+        '*' => {
+            let i = i.slice(1..);
+            Ok((i, Type::All(AllType { span: span(lo, i) })))
+        }
+
+        '?' => {
+            let i = i.slice(1..);
+            Ok((i, Type::Unknown(UnknownType { span: span(lo, i) })))
+        }
+
+        '(' => {
+            if let Ok(res) = parse_arrow_fn(i) {
+                return Ok(res);
+            }
+
+            let i = expect(i, "(")?;
+            let (i, ty) = parse_type(i)?;
+            let i = expect(i, ")")?;
+            Ok((
+                i,
+                Type::Paren(ParenType {
+                    span: span(lo, i),
+                    ty: Box::new(ty),
+                }),
+            ))
+        }
+
+        '{' => parse_record(i),
+
+        '[' => {
+            let (i, types) = parse_list(i, "[", "]", parse_type)?;
+            Ok((
+                i,
+                Type::Tuple(TupleType {
+                    span: span(lo, i),
+                    types,
+                }),
+            ))
+        }
+
+        '"' | '\'' => {
+            let (i, value) = parse_str_lit(i)?;
+            Ok((
+                i,
+                Type::StrLit(StrLitType {
+                    span: span(lo, i),
+                    value,
+                }),
+            ))
+        }
+
+        '-' | '0'..='9' => {
+            let (i, value) = parse_num_lit(i)?;
+            Ok((
+                i,
+                Type::NumLit(NumLitType {
+                    span: span(lo, i),
+                    value,
+                }),
+            ))
+        }
+
+        _ => {
+            let (rest, word) = parse_ident(i)?;
+            let followed_by_paren = skip_ws(rest).starts_with('(');
+
+            match &*word {
+                "function" if followed_by_paren => parse_closure_fn(lo, rest),
+                "import" if followed_by_paren => parse_import(lo, rest),
+                _ => parse_name_path_type(i),
+            }
+        }
+    }
+}
+
+/// `function(this:T, new:T, string, number=): boolean`
+///
+/// `i` should be after `function`.
+fn parse_closure_fn(lo: BytePos, i: Input) -> IResult<Input, Type> {
+    let mut this_ty = None;
+    let mut new_ty = None;
+    let mut params = vec![];
+
+    let mut i = expect(i, "(")?;
+    loop {
+        if let Some(rest) = eat(i, ")") {
+            i = rest;
+            break;
+        }
+
+        let param_lo = skip_ws(i).span().lo;
+        if let Some(rest) = eat_keyword(i, "this").and_then(|rest| eat(rest, ":")) {
+            let (rest, ty) = parse_type(rest)?;
+            i = rest;
+            this_ty = Some(Box::new(ty));
+        } else if let Some(rest) = eat_keyword(i, "new").and_then(|rest| eat(rest, ":")) {
+            let (rest, ty) = parse_type(rest)?;
+            i = rest;
+            new_ty = Some(Box::new(ty));
+        } else {
+            let (rest, ty) = parse_type(i)?;
+            i = rest;
+            params.push(FunctionParam {
+                span: span(param_lo, i),
+                name: None,
+                ty: Some(ty),
+            });
+        }
+
+        match eat(i, ",") {
+            Some(rest) => i = rest,
+            None => {
+                i = expect(i, ")")?;
+                break;
+            }
+        }
+    }
+
+    let (i, ret_ty) = match eat(i, ":") {
+        Some(rest) => {
+            let (rest, ty) = parse_type(rest)?;
+            (rest, Some(Box::new(ty)))
+        }
+        None => (i, None),
+    };
+
+    Ok((
+        i,
+        Type::Function(FunctionType {
+            span: span(lo, i),
+            this_ty,
+            new_ty,
+            params,
+            ret_ty,
+        }),
+    ))
+}
+
+/// `(a: string, b?: number, ...c: any[]) => boolean`
+fn parse_arrow_fn(i: Input) -> IResult<Input, Type> {
+    let i = skip_ws(i);
+    let lo = i.span().lo;
+
+    let (i, params) = parse_list(i, "(", ")", parse_arrow_fn_param)?;
+    let i = expect(i, "=>")?;
+    let (i, ret_ty) = parse_type(i)?;
+
+    Ok((
+        i,
+        Type::Function(FunctionType {
+            span: span(lo, i),
+            this_ty: None,
+            new_ty: None,
+            params,
+            ret_ty: Some(Box::new(ret_ty)),
+        }),
+    ))
+}
+
+fn parse_arrow_fn_param(i: Input) -> IResult<Input, FunctionParam> {
+    let i = skip_ws(i);
+    let lo = i.span().lo;
+
+    let (i, is_rest) = match eat(i, "...") {
+        Some(rest) => (rest, true),
+        None => (i, false),
+    };
+    let (i, name) = parse_ident(skip_ws(i))?;
+    let (i, optional) = match eat(i, "?") {
+        Some(rest) => (rest, true),
+        None => (i, false),
+    };
+
+    let (i, ty) = match eat(i, ":") {
+        Some(rest) => {
+            let ty_lo = skip_ws(rest).span().lo;
+            let (rest, mut ty) = parse_type(rest)?;
+            if optional {
+                ty = Type::Optional(OptionalType {
+                    span: span(ty_lo, rest),
+                    ty: Box::new(ty),
+                });
+            }
+            if is_rest {
+                ty = Type::Variadic(VariadicType {
+                    span: span(ty_lo, rest),
+                    ty: Box::new(ty),
+                });
+            }
+            (rest, Some(ty))
+        }
+        None => (i, None),
+    };
+
+    Ok((
+        i,
+        FunctionParam {
+            span: span(lo, i),
+            name: Some(name.into()),
+            ty,
+        },
+    ))
+}
+
+/// `import("./foo").Bar`
+///
+/// `i` should be after `import`.
+fn parse_import(lo: BytePos, i: Input) -> IResult<Input, Type> {
+    let i = expect(i, "(")?;
+    let (i, src) = parse_str_lit(skip_ws(i))?;
+    let i = expect(i, ")")?;
+
+    let (i, qualifier) = match eat(i, ".") {
+        Some(rest) => {
+            let (rest, name) = parse_name_path(rest)?;
+            (rest, Some(name))
+        }
+        None => (i, None),
+    };
+
+    Ok((
+        i,
+        Type::Import(ImportType {
+            span: span(lo, i),
+            src,
+            qualifier,
+        }),
+    ))
+}
+
+fn parse_record(i: Input) -> IResult<Input, Type> {
+    let i = skip_ws(i);
+    let lo = i.span().lo;
+
+    let mut fields = vec![];
+    let mut i = expect(i, "{")?;
+
+    loop {
+        if let Some(rest) = eat(i, "}") {
+            i = rest;
+            break;
+        }
+
+        let (rest, field) = parse_record_field(i)?;
+        i = rest;
+        fields.push(field);
+
+        match eat(i, ",").or_else(|| eat(i, ";")) {
+            Some(rest) => i = rest,
+            None => {
+                i = expect(i, "}")?;
+                break;
+            }
+        }
+    }
+
+    Ok((
+        i,
+        Type::Record(RecordType {
+            span: span(lo, i),
+            fields,
+        }),
+    ))
+}
+
+fn parse_record_field(i: Input) -> IResult<Input, RecordField> {
+    let i = skip_ws(i);
+    let lo = i.span().lo;
+
+    let (i, key) = match i.chars().next() {
+        Some('"' | '\'') => parse_str_lit(i)?,
+        Some('0'..='9') => {
+            let (rest, _) = parse_num_lit(i)?;
+            let key = i.slice(..i.len() - rest.len());
+            (rest, key.into())
+        }
+        _ => {
+            let (rest, key) = parse_ident(i)?;
+            (rest, key.into())
+        }
+    };
+
+    let (i, optional) = match eat(i, "?") {
+        Some(rest) => (rest, true),
+        None => (i, false),
+    };
+
+    let (i, ty) = match eat(i, ":") {
+        Some(rest) => {
+            let (rest, ty) = parse_type(rest)?;
+            (rest, Some(ty))
+        }
+        None => (i, None),
+    };
+
+    Ok((
+        i,
+        RecordField {
+            span: span(lo, i),
+            key,
+            optional,
+            ty,
+        },
+    ))
+}
+
+fn parse_name_path_type(i: Input) -> IResult<Input, Type> {
+    let i = skip_ws(i);
+    let lo = i.span().lo;
+
+    let (i, name) = parse_name_path(i)?;
+
+    // `Array.<T>` or `Array<T>`
+    let args_start = if i.starts_with(".<") {
+        Some(i.slice(1..))
+    } else if skip_ws(i).starts_with('<') {
+        Some(i)
+    } else {
+        None
+    };
+
+    match args_start {
+        Some(args_start) => {
+            let (i, args) = parse_list(args_start, "<", ">", parse_type)?;
+            Ok((
+                i,
+                Type::Generic(GenericType {
+                    span: span(lo, i),
+                    name,
+                    args,
+                }),
+            ))
+        }
+        None => Ok((
+            i,
+            Type::NamePath(NamePathType {
+                span: span(lo, i),
+                name,
+            }),
+        )),
+    }
+}
+
+/// `foo.bar#baz~qux` or `module:foo/bar.baz`
+fn parse_name_path(i: Input) -> IResult<Input, NamePath> {
+    let i = skip_ws(i);
+    let lo = i.span().lo;
+
+    let start = i;
+    let (mut i, first) = parse_ident(i)?;
+    let first = if &*first == "module" && i.starts_with(':') {
+        // Module paths may contain `/`, `-` and `@`.
+        let len = i
+            .find(|c: char| !(is_ident_char(c) || matches!(c, ':' | '/' | '-' | '@')))
+            .unwrap_or(i.len());
+        i = i.slice(len..);
+        start.slice(..first.len() + len)
+    } else {
+        first
+    };
+
+    let mut components = vec![first.into()];
+
+    while i.starts_with(|c| matches!(c, '.' | '#' | '~')) && !i.starts_with(".<") {
+        let (rest, component) = parse_ident(i.slice(1..))?;
+        i = rest;
+        components.push(component.into());
+    }
+
+    Ok((
+        i,
+        NamePath {
+            span: span(lo, i),
+            components,
+        },
+    ))
+}
+
+/// Parses `open item (, item)* ,? close`.
+fn parse_list<'i, T>(
+    i: Input<'i>,
+    open: &'static str,
+    close: &'static str,
+    mut item: impl FnMut(Input<'i>) -> IResult<Input<'i>, T>,
+) -> IResult<Input<'i>, Vec<T>> {
+    let mut items = vec![];
+    let mut i = expect(i, open)?;
+
+    loop {
+        if let Some(rest) = eat(i, close) {
+            return Ok((rest, items));
+        }
+
+        let (rest, v) = item(i)?;
+        i = rest;
+        items.push(v);
+
+        match eat(i, ",") {
+            Some(rest) => i = rest,
+            None => {
+                let i = expect(i, close)?;
+                return Ok((i, items));
+            }
+        }
+    }
+}
+
+/// Returns the content of the string literal, without quotes.
+///
+/// `i` should start with the quote.
+fn parse_str_lit(i: Input) -> IResult<Input, Text> {
+    let quote = match i.chars().next() {
+        Some(c @ ('"' | '\'')) => c,
+        _ => return Err(nom::Err::Error((i, ErrorKind::Char))),
+    };
+
+    let mut escaped = false;
+    for (idx, c) in i.char_indices().skip(1) {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match c {
+            '\\' => escaped = true,
+            c if c == quote => {
+                let value = i.slice(1..idx);
+                return Ok((i.slice(idx + 1..), value.into()));
+            }
+            _ => {}
+        }
+    }
+
+    Err(nom::Err::Error((i, ErrorKind::Char)))
+}
+
+fn parse_num_lit(i: Input) -> IResult<Input, f64> {
+    let len = i
+        .char_indices()
+        .find(|&(idx, c)| !(c.is_ascii_digit() || c == '.' || (idx == 0 && c == '-')))
+        .map(|(idx, _)| idx)
+        .unwrap_or(i.len());
+
+    match i[..len].parse() {
+        Ok(v) => Ok((i.slice(len..), v)),
+        Err(..) => Err(nom::Err::Error((i, ErrorKind::Digit))),
+    }
+}
+
+fn parse_ident(i: Input) -> IResult<Input, Input> {
+    let len = i.find(|c| !is_ident_char(c)).unwrap_or(i.len());
+    if len == 0 {
+        return Err(nom::Err::Error((i, ErrorKind::AlphaNumeric)));
+    }
+
+    Ok((i.slice(len..), i.slice(..len)))
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Returns true if `i` starts with something which can be an operand of `?`.
+fn starts_type(i: Input) -> bool {
+    match skip_ws(i).chars().next() {
+        Some(c) => !matches!(c, ',' | ')' | '}' | ']' | '>' | '|' | '&' | '=' | ':' | ';'),
+        None => false,
+    }
+}
+
+/// Skips whitespaces, including newlines and `*` at the start of a line.
+fn skip_ws(i: Input) -> Input {
+    let mut at_line_start = false;
+    let mut index = 0;
+
+    for (idx, c) in i.char_indices() {
+        match c {
+            '\r' | '\n' => at_line_start = true,
+            '*' if at_line_start => at_line_start = false,
+            c if c.is_whitespace() => {}
+            _ => break,
+        }
+
+        index = idx + c.len_utf8();
+    }
+
+    i.slice(index..)
+}
+
+fn eat<'i>(i: Input<'i>, s: &str) -> Option<Input<'i>> {
+    let i = skip_ws(i);
+    if i.starts_with(s) {
+        Some(i.slice(s.len()..))
+    } else {
+        None
+    }
+}
+
+/// Same as [eat], but `s` should not be followed by an identifier character.
+fn eat_keyword<'i>(i: Input<'i>, s: &str) -> Option<Input<'i>> {
+    eat(i, s).filter(|rest| !rest.starts_with(is_ident_char))
+}
+
+fn expect<'i>(i: Input<'i>, s: &str) -> Result<Input<'i>, nom::Err<(Input<'i>, ErrorKind)>> {
+    eat(i, s).ok_or(nom::Err::Error((i, ErrorKind::Tag)))
+}
+
+fn span(lo: BytePos, rest: Input) -> Span {
+    Span::new(lo, rest.span().lo, Default::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_common::Spanned;
+
+    fn input(s: &str) -> Input {
+        Input::new(BytePos(0), BytePos(s.as_bytes().len() as _), s)
+    }
+
+    fn ty(s: &str) -> Type {
+        let (rest, ty) = parse_type(input(s)).unwrap();
+        assert_eq!(&*skip_ws(rest), "", "failed to parse all of {:?}", s);
+        ty
+    }
+
+    fn name_path(ty: &Type) -> Vec<&str> {
+        match ty {
+            Type::NamePath(ty) => ty.name.components.iter().map(|c| &*c.value).collect(),
+            _ => panic!("expected a name path, got {:?}", ty),
+        }
+    }
+
+    #[test]
+    fn union() {
+        match ty("string|Array<string>") {
+            Type::Union(ty) => {
+                assert_eq!(ty.types.len(), 2);
+                assert_eq!(name_path(&ty.types[0]), ["string"]);
+                match &ty.types[1] {
+                    Type::Generic(g) => {
+                        assert_eq!(&*g.name.components[0].value, "Array");
+                        assert_eq!(name_path(&g.args[0]), ["string"]);
+                    }
+                    ty => panic!("Invalid type: {:?}", ty),
+                }
+            }
+            ty => panic!("Invalid type: {:?}", ty),
+        }
+    }
+
+    #[test]
+    fn multiline_union() {
+        match ty("(!Array.<number>|\n *         !Array.<!Array.<number>>)") {
+            Type::Paren(ty) => match *ty.ty {
+                Type::Union(u) => {
+                    assert_eq!(u.types.len(), 2);
+                    assert!(matches!(u.types[1], Type::NonNullable(..)));
+                }
+                ty => panic!("Invalid type: {:?}", ty),
+            },
+            ty => panic!("Invalid type: {:?}", ty),
+        }
+    }
+
+    #[test]
+    fn nullable_and_unknown() {
+        assert!(matches!(ty("?string"), Type::Nullable(..)));
+        assert!(matches!(ty("?"), Type::Unknown(..)));
+        assert!(matches!(ty("*"), Type::All(..)));
+
+        match ty("function(?, ?=)") {
+            Type::Function(f) => {
+                assert!(matches!(f.params[0].ty, Some(Type::Unknown(..))));
+                assert!(matches!(f.params[1].ty, Some(Type::Optional(..))));
+            }
+            ty => panic!("Invalid type: {:?}", ty),
+        }
+    }
+
+    #[test]
+    fn closure_function() {
+        match ty("function(this:Foo, string, ...number): boolean") {
+            Type::Function(f) => {
+                assert_eq!(name_path(f.this_ty.as_deref().unwrap()), ["Foo"]);
+                assert_eq!(f.params.len(), 2);
+                assert!(matches!(f.params[1].ty, Some(Type::Variadic(..))));
+                assert_eq!(name_path(f.ret_ty.as_deref().unwrap()), ["boolean"]);
+            }
+            ty => panic!("Invalid type: {:?}", ty),
+        }
+
+        assert_eq!(name_path(&ty("function")), ["function"]);
+    }
+
+    #[test]
+    fn arrow_function() {
+        match ty("(a: string, b?: number, ...c) => void") {
+            Type::Function(f) => {
+                assert_eq!(f.params.len(), 3);
+                assert_eq!(&*f.params[0].name.as_ref().unwrap().value, "a");
+                assert!(matches!(f.params[1].ty, Some(Type::Optional(..))));
+                assert!(f.params[2].ty.is_none());
+            }
+            ty => panic!("Invalid type: {:?}", ty),
+        }
+
+        assert!(matches!(ty("(string)"), Type::Paren(..)));
+    }
+
+    #[test]
+    fn record() {
+        match ty("{a: number, 'b'?: string; c}") {
+            Type::Record(r) => {
+                let keys: Vec<_> = r.fields.iter().map(|f| &*f.key.value).collect();
+                assert_eq!(keys, ["a", "b", "c"]);
+                assert!(r.fields[1].optional);
+                assert!(r.fields[2].ty.is_none());
+            }
+            ty => panic!("Invalid type: {:?}", ty),
+        }
+    }
+
+    #[test]
+    fn array_and_tuple() {
+        match ty("string[][]") {
+            Type::Array(a) => assert!(matches!(*a.elem_ty, Type::Array(..))),
+            ty => panic!("Invalid type: {:?}", ty),
+        }
+        match ty("[string, 1, 'a']") {
+            Type::Tuple(t) => {
+                assert!(
+                    matches!(t.types[1], Type::NumLit(NumLitType { value, .. }) if value == 1.0)
+                );
+                assert!(matches!(&t.types[2], Type::StrLit(s) if &*s.value.value == "a"));
+            }
+            ty => panic!("Invalid type: {:?}", ty),
+        }
+    }
+
+    #[test]
+    fn name_paths() {
+        assert_eq!(
+            name_path(&ty("Person~sayCallback")),
+            ["Person", "sayCallback"]
+        );
+        assert_eq!(name_path(&ty("Data#point")), ["Data", "point"]);
+        assert_eq!(
+            name_path(&ty("module:foo/bar-baz.Qux")),
+            ["module:foo/bar-baz", "Qux"]
+        );
+    }
+
+    #[test]
+    fn import() {
+        match ty("import(\"./foo\").Bar.Baz") {
+            Type::Import(i) => {
+                assert_eq!(&*i.src.value, "./foo");
+                assert_eq!(i.qualifier.unwrap().components.len(), 2);
+            }
+            ty => panic!("Invalid type: {:?}", ty),
+        }
+    }
+
+    #[test]
+    fn type_expr_span() {
+        let text = Text {
+            span: Span::new(BytePos(10), BytePos(18), Default::default()),
+            value: "{?Foo}  ".into(),
+        };
+        let expr = TypeExpr::from_text(&text).unwrap();
+        assert_eq!(
+            expr.span,
+            Span::new(BytePos(10), BytePos(16), Default::default())
+        );
+        assert_eq!(
+            expr.ty.span(),
+            Span::new(BytePos(11), BytePos(15), Default::default())
+        );
+
+        assert!(TypeExpr::from_text(&Text {
+            span: Default::default(),
+            value: "{Foo".into(),
+        })
+        .is_none());
+    }
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "ecma")]
+
+use jsdoc::ast::Tag;
+use std::path::PathBuf;
+use swc_common::{
+    comments::SingleThreadedComments, errors::Handler, sync::Lrc, FileName, SourceFile,
+};
+use swc_ecma_ast::Module;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::{lexer::Lexer, EsConfig, Parser, StringInput, Syntax};
+use swc_ecma_visit::VisitMutWith;
+use testing::NormalizedOutput;
+
+fn parse_module(
+    handler: &Handler,
+    fm: &SourceFile,
+    comments: &SingleThreadedComments,
+) -> Result<Module, ()> {
+    let lexer = Lexer::new(
+        Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+        Default::default(),
+        StringInput::from(fm),
+        Some(comments),
+    );
+    let mut p = Parser::new_from(lexer);
+
+    p.parse_module().map_err(|err| {
+        err.into_diagnostic(handler).emit();
+    })
+}
+
+#[testing::fixture("tests/ecma/**/*.js")]
+fn annotate(path: PathBuf) {
+    let output = testing::run_test2(false, |cm, handler| {
+        let comments = SingleThreadedComments::default();
+
+        let fm = cm.load_file(&path).expect("failed to load fixture file");
+        let mut m = parse_module(&handler, &fm, &comments)?;
+
+        m.visit_mut_with(&mut jsdoc::ecma::annotate(&comments));
+
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: Default::default(),
+                cm: cm.clone(),
+                comments: None,
+                wr: Box::new(JsWriter::new(cm, "\n", &mut buf, None)),
+            };
+
+            emitter.emit_module(&m).unwrap();
+        }
+
+        Ok(String::from_utf8(buf).unwrap())
+    })
+    .unwrap();
+
+    NormalizedOutput::from(output)
+        .compare_to_file(path.with_extension("ts"))
+        .unwrap();
+}
+
+#[test]
+fn collect_docs() {
+    testing::run_test2(false, |cm, handler| {
+        let comments = SingleThreadedComments::default();
+
+        let fm: Lrc<SourceFile> = cm.new_source_file(
+            FileName::Anon,
+            r#"
+/** Adds numbers. */
+export function add(a, b) {}
+
+/** Old API. @deprecated Use `add` instead. */
+const plus = add, sum = add;
+
+class Calculator {
+    /** Clears the state. */
+    clear() {}
+}
+
+/** Not a declaration. */
+Calculator.prototype.x = 1;
+
+// Not a jsdoc
+function undocumented() {}
+"#
+            .into(),
+        );
+        let m = parse_module(&handler, &fm, &comments)?;
+
+        let docs = jsdoc::ecma::collect_docs(&m, &comments);
+        let desc_of = |sym: &str| {
+            docs.ids
+                .iter()
+                .find(|(id, _)| &*id.0 == sym)
+                .map(|(_, doc)| &*doc.description.value)
+        };
+
+        assert_eq!(desc_of("add"), Some("Adds numbers."));
+        assert_eq!(desc_of("plus"), Some("Old API."));
+        assert_eq!(desc_of("sum"), Some("Old API."));
+        assert_eq!(desc_of("Calculator"), None);
+        assert_eq!(desc_of("undocumented"), None);
+
+        let (_, plus) = docs.ids.iter().find(|(id, _)| &*id.0 == "plus").unwrap();
+        assert!(matches!(plus.tags[0].tag, Tag::Deprecated(..)));
+
+        let mut descs: Vec<_> = docs
+            .spans
+            .values()
+            .map(|doc| &*doc.description.value)
+            .collect();
+        descs.sort_unstable();
+        assert_eq!(
+            descs,
+            [
+                "Adds numbers.",
+                "Clears the state.",
+                "Not a declaration.",
+                "Old API."
+            ]
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
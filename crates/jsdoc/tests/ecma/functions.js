@@ -0,0 +1,39 @@
+/**
+ * Adds two numbers.
+ *
+ * @param {number} a The first number.
+ * @param {number=} b The second number.
+ * @param {...string} rest
+ * @returns {number}
+ */
+export function add(a, b, ...rest) {
+    return a + (b || 0);
+}
+
+/**
+ * @param {?string} name
+ * @param {Object.<string, number>} [counts]
+ * @return {Promise<Array<string>>}
+ */
+export const load = async function (name, counts) {
+    return [];
+};
+
+/**
+ * @param {function(string, number=): boolean} cb
+ * @param {Array} [items]
+ */
+const each = (cb, items) => {};
+
+/**
+ * @param {{ a: number, b?: string }} options
+ * @param {boolean} [verbose=false]
+ */
+export default function run(options, verbose = false) {}
+
+/**
+ * @param {import("./foo").Bar} bar
+ * @param {module:lib/baz.Qux} qux
+ * @returns {(a: string) => void}
+ */
+export function imports(bar, qux) {}
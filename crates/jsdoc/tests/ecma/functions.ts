@@ -0,0 +1,14 @@
+export function add(a: number, b?: number, ...rest: string[]): number {
+    return a + (b || 0);
+}
+export const load = async function(name: string | null, counts?: {
+    [x: string]: number;
+}): Promise<string[]> {
+    return [];
+};
+const each = (cb: (arg0: string, arg1?: number) => boolean, items?: any[])=>{};
+export default function run(options: {
+    a: number;
+    b?: string;
+}, verbose: boolean = false) {};
+export function imports(bar: import("./foo").Bar, qux: import("lib/baz").Qux): (a: string) => void {}
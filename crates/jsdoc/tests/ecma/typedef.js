@@ -0,0 +1,15 @@
+/**
+ * @typedef {(string|number)} NumberLike
+ */
+
+/**
+ * @typedef {Object} Options
+ * @property {string} name
+ * @property {number=} size
+ * @property {NumberLike} value
+ */
+
+/**
+ * @param {Options} options
+ */
+export function create(options) {}
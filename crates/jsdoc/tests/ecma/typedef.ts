@@ -0,0 +1,7 @@
+export type NumberLike = (string | number);
+export type Options = {
+    name: string;
+    size?: number;
+    value: NumberLike;
+};
+export function create(options: Options) {}
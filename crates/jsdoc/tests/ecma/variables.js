@@ -0,0 +1,27 @@
+/**
+ * @type {string|Array<string>}
+ */
+export let names;
+
+/** @type {!Array.<number>} */
+const numbers = [1, 2, 3];
+
+class Dispenser {
+    /**
+     * @type {number}
+     */
+    count = 0;
+
+    /**
+     * @param {string} candy
+     */
+    constructor(candy) {}
+
+    /**
+     * @param {*} item
+     * @returns {this}
+     */
+    restock(item) {
+        return this;
+    }
+}
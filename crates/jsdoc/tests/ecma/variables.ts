@@ -0,0 +1,13 @@
+export let names: string | string[];
+const numbers: number[] = [
+    1,
+    2,
+    3
+];
+class Dispenser {
+    count: number = 0;
+    constructor(candy: string){}
+    restock(item: any): this {
+        return this;
+    }
+}
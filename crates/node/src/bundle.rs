@@ -2,14 +2,16 @@ use crate::{
     get_compiler,
     util::{get_deserialized, MapErr},
 };
-use anyhow::{bail, Error};
+use anyhow::{bail, Context, Error};
 use napi::{
     bindgen_prelude::{AbortSignal, AsyncTask, Buffer},
     Env, Status, Task,
 };
 use serde::Deserialize;
 use std::{
+    fs,
     panic::{catch_unwind, AssertUnwindSafe},
+    path::Path,
     sync::Arc,
 };
 use swc::{
@@ -25,10 +27,12 @@ use swc_ecma_ast::{
     PropName, Str,
 };
 use swc_ecma_loader::{TargetEnv, NODE_BUILTINS};
+use swc_node_bundler::{loaders::assets::Assets, v1::ChunkMatcher};
 
 struct ConfigItem {
     loader: Box<dyn Load>,
     resolver: Box<dyn Resolve>,
+    assets: Arc<Assets>,
     static_items: StaticConfigItem,
 }
 
@@ -70,6 +74,31 @@ impl Task for BundleTask {
             .codegen_target()
             .unwrap_or_default();
 
+        let module = self
+            .config
+            .static_items
+            .config
+            .output
+            .as_ref()
+            .map(|output| output.module_type())
+            .transpose()
+            .convert_err()?
+            .unwrap_or_default();
+
+        let split_chunks = self
+            .config
+            .static_items
+            .config
+            .optimization
+            .as_ref()
+            .and_then(|optimization| optimization.split_chunks.as_ref());
+        let chunks = split_chunks.map(|split_chunks| split_chunks.chunk_config());
+        let chunk_matcher = split_chunks
+            .map(|split_chunks| split_chunks.matcher())
+            .transpose()
+            .convert_err()?
+            .unwrap_or_default();
+
         let res = catch_unwind(AssertUnwindSafe(|| {
             let mut bundler = Bundler::new(
                 self.swc.globals(),
@@ -89,16 +118,18 @@ impl Task for BundleTask {
                                 .cloned(),
                         )
                         .collect(),
+                    module,
+                    chunks,
                     ..Default::default()
                 },
-                Box::new(Hook),
+                Box::new(Hook { chunk_matcher }),
             );
 
             let result = bundler
                 .bundle(self.config.static_items.config.entry.clone().into())
                 .convert_err()?;
 
-            let result = result
+            let result: AHashMap<_, _> = result
                 .into_iter()
                 .map(|bundle| match bundle.kind {
                     BundleKind::Named { name } | BundleKind::Lib { name } => {
@@ -107,7 +138,9 @@ impl Task for BundleTask {
                     BundleKind::Dynamic => bail!("unimplemented: dynamic code splitting"),
                 })
                 .map(|res| {
-                    res.and_then(|(k, m)| {
+                    res.and_then(|(k, mut m)| {
+                        let styles = self.config.assets.extract_styles(&mut m);
+
                         // TODO: Source map
                         let minify = self
                             .config
@@ -132,11 +165,42 @@ impl Task for BundleTask {
                             None,
                         )?;
 
-                        Ok((k, output))
+                        let mut outputs = vec![];
+                        if let Some(code) = styles {
+                            let name = Path::new(&k).with_extension("css");
+                            outputs.push((
+                                name.to_string_lossy().into_owned(),
+                                TransformOutput {
+                                    code,
+                                    map: None,
+                                    name_cache: None,
+                                },
+                            ));
+                        }
+                        outputs.push((k, output));
+
+                        Ok(outputs)
                     })
                 })
-                .collect::<Result<_, _>>()
-                .convert_err()?;
+                .collect::<Result<Vec<_>, _>>()
+                .convert_err()?
+                .into_iter()
+                .flatten()
+                .collect();
+
+            let files = self.config.assets.take_files();
+            if !files.is_empty() {
+                let dir = match &self.config.static_items.config.output {
+                    Some(output) => &output.path,
+                    None => {
+                        return Err(napi::Error::new(
+                            Status::GenericFailure,
+                            "`output.path` is required to emit assets".to_string(),
+                        ))
+                    }
+                };
+                emit_files(dir, files).convert_err()?;
+            }
 
             Ok(result)
         }));
@@ -188,7 +252,9 @@ pub(crate) fn bundle(
 
     let static_items: StaticConfigItem = get_deserialized(&conf_items)?;
 
-    let loader = Box::new(swc_node_bundler::loaders::swc::SwcLoader::new(
+    let assets = Arc::new(Assets::new(static_items.config.asset_config()));
+
+    let mut loader = swc_node_bundler::loaders::swc::SwcLoader::new(
         c.clone(),
         static_items
             .config
@@ -198,7 +264,13 @@ pub(crate) fn bundle(
             .unwrap_or_else(|| {
                 serde_json::from_value(serde_json::Value::Object(Default::default())).unwrap()
             }),
-    ));
+    )
+    .with_assets(assets.clone());
+    if let Some(cache) = &static_items.config.cache {
+        // The whole config is used as the key because options can't be serialized.
+        loader = loader.with_cache(&cache.dir, &String::from_utf8_lossy(&conf_items));
+    }
+    let loader = Box::new(loader);
 
     let target_env = static_items.config.target;
 
@@ -232,6 +304,7 @@ pub(crate) fn bundle(
             config: ConfigItem {
                 loader,
                 resolver,
+                assets,
                 static_items,
             },
         },
@@ -245,7 +318,23 @@ pub(crate) fn bundle() -> napi::Result<AsyncTask<BundleTask>> {
     todo!()
 }
 
-struct Hook;
+/// Writes files emitted by loaders to `dir`.
+fn emit_files(dir: &Path, files: Vec<(String, Vec<u8>)>) -> Result<(), Error> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("failed to create output directory `{}`", dir.display()))?;
+
+    for (name, content) in files {
+        let path = dir.join(name);
+        fs::write(&path, content)
+            .with_context(|| format!("failed to write asset to `{}`", path.display()))?;
+    }
+
+    Ok(())
+}
+
+struct Hook {
+    chunk_matcher: ChunkMatcher,
+}
 
 impl swc_bundler::Hook for Hook {
     fn get_import_meta_props(
@@ -280,4 +369,8 @@ impl swc_bundler::Hook for Hook {
             },
         ])
     }
+
+    fn get_chunk_name(&self, module_record: &ModuleRecord) -> Result<Option<String>, Error> {
+        Ok(self.chunk_matcher.chunk_name(&module_record.file_name))
+    }
 }
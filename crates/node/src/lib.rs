@@ -69,4 +69,5 @@ pub type ArcCompiler = Arc<Compiler>;
 pub struct TransformOutput {
     pub code: String,
     pub map: Option<String>,
+    pub name_cache: Option<serde_json::Value>,
 }
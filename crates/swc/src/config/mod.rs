@@ -35,7 +35,7 @@ use swc_ecma_loader::resolvers::{
 };
 use swc_ecma_minifier::option::{
     terser::{TerserCompressorOptions, TerserEcmaVersion, TerserTopLevelOptions},
-    MangleOptions, ManglePropertiesOptions,
+    MangleOptions, ManglePropertiesOptions, NameCache,
 };
 #[allow(deprecated)]
 pub use swc_ecma_parser::JscTarget;
@@ -47,7 +47,10 @@ use swc_ecma_transforms::{
     },
     optimization::{const_modules, json_parse, simplifier},
     pass::{noop, Optional},
-    proposals::{decorators, export_default_from, import_assertions},
+    proposals::{
+        decorators, do_expressions, export_default_from, import_assertions, pipeline_operator,
+        record_and_tuple,
+    },
     react, resolver_with_mark, typescript, Assumptions,
 };
 use swc_ecma_transforms_compat::es2015::regenerator;
@@ -330,6 +333,7 @@ impl Options {
         }
 
         let regenerator = transform.regenerator.clone();
+        let record_and_tuple_config = transform.record_and_tuple.clone();
 
         let preserve_comments = js_minify.as_ref().map(|v| v.format.comments.clone());
 
@@ -372,6 +376,12 @@ impl Options {
             const_modules,
             optimization,
             Optional::new(export_default_from(), syntax.export_default_from()),
+            Optional::new(pipeline_operator(), syntax.pipeline_operator().is_some()),
+            Optional::new(do_expressions(), syntax.do_expressions()),
+            Optional::new(
+                record_and_tuple(record_and_tuple_config),
+                syntax.record_and_tuple()
+            ),
             Optional::new(simplifier(Default::default()), enable_simplifier),
             json_parse_pass
         );
@@ -420,6 +430,7 @@ impl Options {
                     typescript::Config {
                         pragma: Some(transform.react.pragma.clone()),
                         pragma_frag: Some(transform.react.pragma_frag.clone()),
+                        inline_const_enums: transform.inline_const_enums,
                         ..Default::default()
                     },
                     comments,
@@ -427,6 +438,7 @@ impl Options {
                 ),
                 syntax.typescript()
             ),
+            Optional::new(typescript::strip_flow(top_level_mark), syntax.flow()),
             lint_to_fold(swc_ecma_lints::rules::all()),
             crate::plugin::plugins(experimental),
             custom_before_pass(&program),
@@ -690,6 +702,11 @@ pub struct JsMinifyOptions {
 
     #[serde(default = "true_by_default")]
     pub inline_sources_content: bool,
+
+    /// Mangled names of previous runs. If this is specified, the updated name
+    /// cache is returned as [crate::TransformOutput::name_cache].
+    #[serde(default)]
+    pub name_cache: Option<NameCache>,
 }
 
 fn true_by_default() -> bool {
@@ -1102,6 +1119,12 @@ pub struct TransformConfig {
 
     #[serde(default)]
     pub regenerator: regenerator::Config,
+
+    #[serde(default)]
+    pub record_and_tuple: record_and_tuple::Config,
+
+    #[serde(default)]
+    pub inline_const_enums: bool,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -1532,6 +1555,11 @@ impl Merge for swc_ecma_parser::EsConfig {
         self.import_assertions |= from.import_assertions;
         self.static_blocks |= from.static_blocks;
         self.private_in_object |= from.private_in_object;
+        if from.pipeline_operator.is_some() {
+            self.pipeline_operator = from.pipeline_operator;
+        }
+        self.do_expressions |= from.do_expressions;
+        self.record_and_tuple |= from.record_and_tuple;
     }
 }
 
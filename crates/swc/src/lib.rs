@@ -160,6 +160,9 @@ use swc_ecma_transforms::{
 use swc_ecma_visit::{noop_visit_type, FoldWith, Visit, VisitMutWith, VisitWith};
 pub use swc_node_comments::SwcComments;
 
+/// Version of `swc`, which can be used to invalidate cached outputs.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
 mod builder;
 pub mod config;
 mod plugin;
@@ -270,6 +273,10 @@ pub struct TransformOutput {
     pub code: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub map: Option<String>,
+    /// [swc_ecma_minifier::option::NameCache] returned only by `minify` if the
+    /// name cache is enabled.
+    #[serde(rename = "nameCache", skip_serializing_if = "Option::is_none")]
+    pub name_cache: Option<serde_json::Value>,
 }
 
 #[cfg(not(feature = "node"))]
@@ -278,6 +285,10 @@ pub struct TransformOutput {
     pub code: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub map: Option<String>,
+    /// [swc_ecma_minifier::option::NameCache] returned only by `minify` if the
+    /// name cache is enabled.
+    #[serde(rename = "nameCache", skip_serializing_if = "Option::is_none")]
+    pub name_cache: Option<serde_json::Value>,
 }
 
 /// These are **low-level** apis.
@@ -605,7 +616,11 @@ impl Compiler {
                 }
             };
 
-            Ok(TransformOutput { code, map })
+            Ok(TransformOutput {
+                code,
+                map,
+                name_cache: None,
+            })
         })
     }
 }
@@ -1001,16 +1016,19 @@ impl Compiler {
 
             let is_mangler_enabled = min_opts.mangle.is_some();
 
+            let mut name_cache = opts.name_cache.clone().unwrap_or_default();
+
             let module = self.run_transform(handler, false, || {
                 let module = module.fold_with(&mut resolver_with_mark(top_level_mark));
 
-                let mut module = swc_ecma_minifier::optimize(
+                let mut module = swc_ecma_minifier::optimize_with_name_cache(
                     module,
                     self.cm.clone(),
                     Some(&self.comments),
                     None,
                     &min_opts,
                     &swc_ecma_minifier::option::ExtraOptions { top_level_mark },
+                    &mut name_cache,
                 );
 
                 if !is_mangler_enabled {
@@ -1031,6 +1049,16 @@ impl Compiler {
                 true,
                 Some(opts.format.comments.clone()),
             )
+            .and_then(|mut output| {
+                if opts.name_cache.is_some() {
+                    output.name_cache = Some(
+                        serde_json::to_value(&name_cache)
+                            .context("failed to serialize name cache")?,
+                    );
+                }
+
+                Ok(output)
+            })
         })
     }
 
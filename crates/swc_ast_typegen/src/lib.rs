@@ -0,0 +1,132 @@
+//! Generates typescript definitions and json schemas of the ast crates.
+//!
+//! Source files of an ast crate are parsed with `syn`, and
+//! `#[ast_node]`, `#[ast_serde]`, `#[tag]`, `#[derive(StringEnum)]` and serde
+//! attributes are interpreted in the same way as the serializer does. So the
+//! generated types describe the json produced by `serde_json`. For example, a
+//! field with `#[serde(default)]` is required, as it's always serialized.
+//!
+//! The deserializer also accepts json without such fields. The json schema and
+//! the `Input` namespace of the typescript definitions describe this input.
+//!
+//! Run `cargo run -p swc_ast_typegen` to update the files in `node-swc`.
+
+#![deny(clippy::all)]
+
+use anyhow::{Context, Error};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub use self::model::{Decl, DeclKind, Field, Ty};
+
+mod model;
+mod parse;
+mod schema;
+mod typescript;
+
+/// An ast crate to generate types for.
+#[derive(Debug, Clone, Copy)]
+pub struct Target {
+    /// Used as the name of the generated files.
+    pub name: &'static str,
+    /// Directory of the crate, relative to `crates`.
+    pub crate_dir: &'static str,
+    /// The type used as the root of the json schema.
+    pub root: &'static str,
+}
+
+pub const TARGETS: &[Target] = &[
+    Target {
+        name: "ecmascript",
+        crate_dir: "swc_ecma_ast",
+        root: "Program",
+    },
+    Target {
+        name: "css",
+        crate_dir: "swc_css_ast",
+        root: "Stylesheet",
+    },
+];
+
+/// Types of an ast crate.
+#[derive(Debug, Clone)]
+pub struct Ast {
+    pub root: String,
+    pub decls: Vec<Decl>,
+}
+
+impl Ast {
+    /// Loads all serializable types from `src_dir`.
+    pub fn load(src_dir: &Path, root: &str) -> Result<Self, Error> {
+        let decls = parse::parse_dir(src_dir)
+            .with_context(|| format!("failed to load types from {}", src_dir.display()))?;
+
+        if !decls.iter().any(|decl| decl.name == root) {
+            anyhow::bail!("the root type `{}` does not exist", root);
+        }
+
+        Ok(Ast {
+            root: root.into(),
+            decls,
+        })
+    }
+
+    pub fn to_typescript(&self) -> String {
+        typescript::print(self)
+    }
+
+    pub fn to_json_schema(&self) -> String {
+        let mut s = serde_json::to_string_pretty(&schema::build(self)).unwrap();
+        s.push('\n');
+        s
+    }
+}
+
+impl Target {
+    /// `root` is the root directory of the repository.
+    pub fn load(&self, root: &Path) -> Result<Ast, Error> {
+        Ast::load(
+            &root.join("crates").join(self.crate_dir).join("src"),
+            self.root,
+        )
+    }
+
+    pub fn typescript_path(&self, root: &Path) -> PathBuf {
+        root.join("node-swc")
+            .join("src")
+            .join("ast")
+            .join(format!("{}.ts", self.name))
+    }
+
+    pub fn json_schema_path(&self, root: &Path) -> PathBuf {
+        root.join("node-swc")
+            .join("src")
+            .join("ast")
+            .join(format!("{}.schema.json", self.name))
+    }
+
+    /// Writes generated files to `node-swc`.
+    pub fn write(&self, root: &Path) -> Result<(), Error> {
+        let ast = self.load(root)?;
+
+        let ts_path = self.typescript_path(root);
+        if let Some(dir) = ts_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&ts_path, ast.to_typescript())
+            .with_context(|| format!("failed to write {}", ts_path.display()))?;
+
+        let schema_path = self.json_schema_path(root);
+        fs::write(&schema_path, ast.to_json_schema())
+            .with_context(|| format!("failed to write {}", schema_path.display()))?;
+
+        Ok(())
+    }
+}
+
+/// Root directory of the repository.
+pub fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("..")
+}
@@ -0,0 +1,12 @@
+use anyhow::Error;
+use swc_ast_typegen::{repo_root, TARGETS};
+
+fn main() -> Result<(), Error> {
+    let root = repo_root();
+
+    for target in TARGETS {
+        target.write(&root)?;
+    }
+
+    Ok(())
+}
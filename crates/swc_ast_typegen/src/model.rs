@@ -0,0 +1,49 @@
+use serde_json::Value;
+
+/// A named type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decl {
+    pub name: String,
+    pub doc: Option<String>,
+    pub kind: DeclKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeclKind {
+    /// A json object.
+    Interface {
+        /// Interfaces flattened into this interface.
+        extends: Vec<String>,
+        fields: Vec<Field>,
+    },
+    Alias(Ty),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    /// Name of the json property.
+    pub name: String,
+    pub ty: Ty,
+    /// `true` if the property can be omitted by the serializer.
+    pub optional: bool,
+    /// `true` if the deserializer accepts json without the property.
+    pub default: bool,
+    pub doc: Option<String>,
+}
+
+/// Type of a json value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Ty {
+    String,
+    Number,
+    Boolean,
+    Null,
+    Literal(Value),
+    /// Reference to a [Decl].
+    Ref(String),
+    Array(Box<Ty>),
+    Tuple(Vec<Ty>),
+    Nullable(Box<Ty>),
+    Union(Vec<Ty>),
+    Object(Vec<Field>),
+}
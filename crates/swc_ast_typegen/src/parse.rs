@@ -0,0 +1,732 @@
+use crate::model::{Decl, DeclKind, Field, Ty};
+use anyhow::{bail, Context, Error};
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::ToTokens;
+use serde_json::Value;
+use std::{collections::HashMap, fs, path::Path};
+use syn::{
+    punctuated::Punctuated, token::Comma, Attribute, Fields, GenericArgument, Item, ItemEnum,
+    ItemMacro, ItemStruct, Lit, LitStr, Meta, NestedMeta, PathArguments, PathSegment, Type,
+    Variant,
+};
+use walkdir::WalkDir;
+
+/// Parses all rust files in `dir` and returns serializable types.
+pub(crate) fn parse_dir(dir: &Path) -> Result<Vec<Decl>, Error> {
+    let mut items = vec![];
+
+    for entry in WalkDir::new(dir).sort_by_file_name() {
+        let entry = entry?;
+        let path = entry.path();
+        if path.extension().map_or(true, |ext| ext != "rs") {
+            continue;
+        }
+
+        let src = fs::read_to_string(path)?;
+        let file =
+            syn::parse_file(&src).with_context(|| format!("failed to parse {}", path.display()))?;
+        collect_items(file.items, &mut items)
+            .with_context(|| format!("failed to process {}", path.display()))?;
+    }
+
+    let converter = Converter::new(&items)?;
+
+    let mut decls = vec![span()];
+    for item in &items {
+        let decl = match item {
+            Item::Struct(s) => match converter.names.get(&s.ident.to_string()) {
+                Some(name) => converter
+                    .struct_decl(s, name.clone())
+                    .with_context(|| format!("failed to convert `{}`", s.ident))?,
+                None => continue,
+            },
+            Item::Enum(e) => match converter.names.get(&e.ident.to_string()) {
+                Some(name) => converter
+                    .enum_decl(e, name.clone())
+                    .with_context(|| format!("failed to convert `{}`", e.ident))?,
+                None => continue,
+            },
+            _ => continue,
+        };
+        decls.push(decl);
+    }
+
+    Ok(decls)
+}
+
+/// [swc_common::Span] is serialized by all ast nodes.
+fn span() -> Decl {
+    let field = |name: &str| Field {
+        name: name.into(),
+        ty: Ty::Number,
+        optional: false,
+        default: false,
+        doc: None,
+    };
+
+    Decl {
+        name: "Span".into(),
+        doc: None,
+        kind: DeclKind::Interface {
+            extends: vec![],
+            fields: vec![field("start"), field("end"), field("ctxt")],
+        },
+    }
+}
+
+/// Collects types, including ones declared by `macro_rules!` defined in the
+/// same file.
+fn collect_items(items: Vec<Item>, out: &mut Vec<Item>) -> Result<(), Error> {
+    let mut macros = HashMap::new();
+
+    for item in items {
+        match item {
+            Item::Macro(m) => {
+                if let Some((name, def)) = MacroDef::parse(&m) {
+                    macros.insert(name, def);
+                    continue;
+                }
+
+                let name = match m.mac.path.get_ident() {
+                    Some(v) => v.to_string(),
+                    None => continue,
+                };
+                if let Some(def) = macros.get(&name) {
+                    let file: syn::File = syn::parse2(def.expand(m.mac.tokens))
+                        .with_context(|| format!("failed to expand `{}!`", name))?;
+                    collect_items(file.items, out)?;
+                }
+            }
+
+            Item::Mod(m) => {
+                if let Some((_, items)) = m.content {
+                    collect_items(items, out)?;
+                }
+            }
+
+            Item::Struct(..) | Item::Enum(..) | Item::Type(..) => out.push(item),
+
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// A `macro_rules!` with a single rule, which does not use repetitions.
+struct MacroDef {
+    params: Vec<String>,
+    body: TokenStream,
+}
+
+impl MacroDef {
+    fn parse(m: &ItemMacro) -> Option<(String, Self)> {
+        if !m.mac.path.is_ident("macro_rules") {
+            return None;
+        }
+        let name = m.ident.as_ref()?.to_string();
+
+        let tokens = m.mac.tokens.clone().into_iter().collect::<Vec<_>>();
+        let (matcher, body) = match &*tokens {
+            [TokenTree::Group(matcher), TokenTree::Punct(eq), TokenTree::Punct(gt), TokenTree::Group(body)]
+            | [TokenTree::Group(matcher), TokenTree::Punct(eq), TokenTree::Punct(gt), TokenTree::Group(body), TokenTree::Punct(_)]
+                if eq.as_char() == '=' && gt.as_char() == '>' =>
+            {
+                (matcher, body)
+            }
+            _ => return None,
+        };
+
+        let mut params = vec![];
+        let mut matcher = matcher.stream().into_iter();
+        while let Some(tt) = matcher.next() {
+            if let TokenTree::Punct(p) = tt {
+                if p.as_char() == '$' {
+                    match matcher.next()? {
+                        TokenTree::Ident(i) => params.push(i.to_string()),
+                        _ => return None,
+                    }
+                }
+            }
+        }
+
+        Some((
+            name,
+            MacroDef {
+                params,
+                body: body.stream(),
+            },
+        ))
+    }
+
+    /// Arguments are split by top-level commas, so a type argument should
+    /// not contain a comma.
+    fn expand(&self, args: TokenStream) -> TokenStream {
+        let mut split = vec![TokenStream::new()];
+        for tt in args {
+            match tt {
+                TokenTree::Punct(p) if p.as_char() == ',' => split.push(TokenStream::new()),
+                _ => split.last_mut().unwrap().extend(Some(tt)),
+            }
+        }
+
+        let args = self.params.iter().cloned().zip(split).collect();
+        substitute(self.body.clone(), &args)
+    }
+}
+
+fn substitute(tokens: TokenStream, args: &HashMap<String, TokenStream>) -> TokenStream {
+    let mut out = TokenStream::new();
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(tt) = tokens.next() {
+        match tt {
+            TokenTree::Punct(ref p) if p.as_char() == '$' => {
+                let arg = match tokens.peek() {
+                    Some(TokenTree::Ident(i)) if i == "crate" => {
+                        out.extend(tokens.next());
+                        continue;
+                    }
+                    Some(TokenTree::Ident(i)) => args.get(&i.to_string()),
+                    _ => None,
+                };
+
+                match arg {
+                    Some(arg) => {
+                        tokens.next();
+                        if arg.clone().into_iter().count() == 1 {
+                            out.extend(arg.clone());
+                        } else {
+                            out.extend(Some(TokenTree::Group(Group::new(
+                                Delimiter::None,
+                                arg.clone(),
+                            ))));
+                        }
+                    }
+                    None => out.extend(Some(tt)),
+                }
+            }
+
+            TokenTree::Group(g) => {
+                let mut new = Group::new(g.delimiter(), substitute(g.stream(), args));
+                new.set_span(g.span());
+                out.extend(Some(TokenTree::Group(new)));
+            }
+
+            _ => out.extend(Some(tt)),
+        }
+    }
+
+    out
+}
+
+struct Converter {
+    /// Rust name to the name of the generated type.
+    names: HashMap<String, String>,
+    aliases: HashMap<String, Type>,
+}
+
+impl Converter {
+    /// Types are named after the `type` of json, unless it conflicts with
+    /// another type. e.g. `ImportNamedSpecifier` is not named
+    /// `ImportSpecifier`, and both of `Tpl` and `TsTplLitType` are not named
+    /// `TemplateLiteral`.
+    fn new(items: &[Item]) -> Result<Self, Error> {
+        let mut tags = vec![];
+        let mut aliases = HashMap::new();
+
+        for item in items {
+            let (ident, attrs) = match item {
+                Item::Struct(s) => (&s.ident, &s.attrs),
+                Item::Enum(e) => (&e.ident, &e.attrs),
+                Item::Type(t) => {
+                    aliases.insert(t.ident.to_string(), (*t.ty).clone());
+                    continue;
+                }
+                _ => continue,
+            };
+
+            let tag = match node_attr(attrs)? {
+                Some(tag) => tag,
+                None if derives(attrs, "Serialize") || derives(attrs, "StringEnum") => None,
+                None => continue,
+            };
+            tags.push((ident.to_string(), tag));
+        }
+
+        let mut names = HashMap::new();
+        let mut rust_names = HashMap::new();
+
+        for (ident, tag) in &tags {
+            let name = match tag {
+                Some(tag)
+                    if tags.iter().all(|(other, other_tag)| {
+                        other == ident || (other != tag && other_tag.as_ref() != Some(tag))
+                    }) =>
+                {
+                    tag.clone()
+                }
+                _ => ident.clone(),
+            };
+
+            if name == "Span" {
+                bail!("`{}` conflicts with the builtin type `Span`", ident);
+            }
+            if let Some(prev) = rust_names.insert(name.clone(), ident.clone()) {
+                bail!("both of `{}` and `{}` are named `{}`", prev, ident, name);
+            }
+            names.insert(ident.clone(), name);
+        }
+
+        Ok(Converter { names, aliases })
+    }
+
+    fn struct_decl(&self, s: &ItemStruct, name: String) -> Result<Decl, Error> {
+        let attrs = serde_attrs(&s.attrs)?;
+        let node = node_attr(&s.attrs)?;
+        let rename_all = match node {
+            Some(..) => Some("camelCase".into()),
+            None => attrs.rename_all,
+        };
+
+        let kind = match &s.fields {
+            Fields::Named(fields) => {
+                let mut extends = vec![];
+                let mut props = vec![];
+
+                if let Some(Some(tag)) = node {
+                    props.push(field("type", Ty::Literal(tag.into())));
+                }
+                self.fields(
+                    &fields.named,
+                    rename_all.as_deref(),
+                    &mut extends,
+                    &mut props,
+                )?;
+
+                DeclKind::Interface {
+                    extends,
+                    fields: props,
+                }
+            }
+            Fields::Unnamed(fields) => DeclKind::Alias(self.tuple(&fields.unnamed)?),
+            Fields::Unit => DeclKind::Alias(Ty::Null),
+        };
+
+        Ok(Decl {
+            name,
+            doc: doc(&s.attrs),
+            kind,
+        })
+    }
+
+    fn enum_decl(&self, e: &ItemEnum, name: String) -> Result<Decl, Error> {
+        let mut types = vec![];
+
+        if node_attr(&e.attrs)?.is_some() {
+            for v in &e.variants {
+                types.push(self.variant(v, &Repr::Untagged, "")?);
+            }
+        } else if derives(&e.attrs, "StringEnum") {
+            for v in &e.variants {
+                types.push(Ty::Literal(str_value(v)?.into()));
+            }
+        } else {
+            let attrs = serde_attrs(&e.attrs)?;
+            let repr = match attrs.tag {
+                _ if attrs.untagged => Repr::Untagged,
+                Some(tag) => Repr::Internal(tag),
+                None => Repr::External,
+            };
+
+            for v in &e.variants {
+                let v_attrs = serde_attrs(&v.attrs)?;
+                if v_attrs.skip {
+                    continue;
+                }
+
+                let name = match v_attrs.rename {
+                    Some(name) => name,
+                    None => rename_variant(attrs.rename_all.as_deref(), &v.ident.to_string())?,
+                };
+                types.push(self.variant(v, &repr, &name)?);
+            }
+        }
+
+        Ok(Decl {
+            name,
+            doc: doc(&e.attrs),
+            kind: DeclKind::Alias(Ty::Union(types)),
+        })
+    }
+
+    fn variant(&self, v: &Variant, repr: &Repr, name: &str) -> Result<Ty, Error> {
+        let content = match &v.fields {
+            Fields::Unit => None,
+            Fields::Unnamed(fields) => Some(self.tuple(&fields.unnamed)?),
+            Fields::Named(fields) => {
+                let rename_all = serde_attrs(&v.attrs)?.rename_all;
+                let mut extends = vec![];
+                let mut props = vec![];
+                self.fields(
+                    &fields.named,
+                    rename_all.as_deref(),
+                    &mut extends,
+                    &mut props,
+                )?;
+                if !extends.is_empty() {
+                    bail!("flattening a field of an enum variant is not supported");
+                }
+
+                Some(Ty::Object(props))
+            }
+        };
+
+        Ok(match (repr, content) {
+            (Repr::Untagged, None) => Ty::Null,
+            (Repr::Untagged, Some(ty)) => ty,
+            (Repr::External, None) => Ty::Literal(name.into()),
+            (Repr::External, Some(ty)) => Ty::Object(vec![field(name, ty)]),
+            (Repr::Internal(tag), None) => Ty::Object(vec![field(tag, Ty::Literal(name.into()))]),
+            (Repr::Internal(tag), Some(Ty::Object(mut props))) => {
+                props.insert(0, field(tag, Ty::Literal(name.into())));
+                Ty::Object(props)
+            }
+            (Repr::Internal(..), Some(..)) => {
+                bail!("internally tagged enum can't contain a tuple variant")
+            }
+        })
+    }
+
+    fn fields(
+        &self,
+        fields: &Punctuated<syn::Field, Comma>,
+        rename_all: Option<&str>,
+        extends: &mut Vec<String>,
+        props: &mut Vec<Field>,
+    ) -> Result<(), Error> {
+        for f in fields {
+            let attrs = serde_attrs(&f.attrs)?;
+            if attrs.skip {
+                continue;
+            }
+
+            let ty = self.ty(&f.ty)?;
+            if attrs.flatten {
+                match ty {
+                    Ty::Ref(name) => extends.push(name),
+                    _ => bail!("only a struct can be flattened"),
+                }
+                continue;
+            }
+
+            let ident = f.ident.as_ref().unwrap().to_string();
+            let name = match attrs.rename {
+                Some(name) => name,
+                None => rename_field(rename_all, ident.trim_start_matches("r#"))?,
+            };
+
+            // serde deserializes a missing `Option` as `None`.
+            let default = attrs.default || matches!(ty, Ty::Nullable(..));
+
+            props.push(Field {
+                name,
+                ty,
+                // The serializer only omits a field if `skip_serializing_if` is used.
+                optional: attrs.skip_serializing_if,
+                default,
+                doc: doc(&f.attrs),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn tuple(&self, fields: &Punctuated<syn::Field, Comma>) -> Result<Ty, Error> {
+        if fields.len() == 1 {
+            return self.ty(&fields[0].ty);
+        }
+
+        Ok(Ty::Tuple(
+            fields
+                .iter()
+                .map(|f| self.ty(&f.ty))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+
+    fn ty(&self, ty: &Type) -> Result<Ty, Error> {
+        let path = match ty {
+            Type::Path(p) if p.qself.is_none() => &p.path,
+            Type::Group(g) => return self.ty(&g.elem),
+            Type::Paren(p) => return self.ty(&p.elem),
+            Type::Tuple(t) if t.elems.is_empty() => return Ok(Ty::Null),
+            Type::Tuple(t) => {
+                return Ok(Ty::Tuple(
+                    t.elems
+                        .iter()
+                        .map(|ty| self.ty(ty))
+                        .collect::<Result<_, _>>()?,
+                ))
+            }
+            _ => bail!("unsupported type: {}", ty.to_token_stream()),
+        };
+        let seg = path.segments.last().unwrap();
+
+        Ok(match &*seg.ident.to_string() {
+            "Box" | "Rc" | "Arc" | "Lrc" => self.ty(generic_arg(seg)?)?,
+            "Vec" => Ty::Array(Box::new(self.ty(generic_arg(seg)?)?)),
+            "Option" => Ty::Nullable(Box::new(self.ty(generic_arg(seg)?)?)),
+
+            "String" | "str" | "char" | "JsWord" | "Atom" => Ty::String,
+            "bool" => Ty::Boolean,
+            "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize"
+            | "f32" | "f64" | "BytePos" | "SyntaxContext" => Ty::Number,
+            "Span" => Ty::Ref("Span".into()),
+
+            // `num_bigint::BigInt` is serialized as `(sign, digits)`.
+            "BigIntValue" => Ty::Tuple(vec![Ty::Number, Ty::Array(Box::new(Ty::Number))]),
+            // Implements `Serialize` manually.
+            "TruePlusMinus" => Ty::Union(vec![
+                Ty::Literal(Value::Bool(true)),
+                Ty::Literal("+".into()),
+                Ty::Literal("-".into()),
+            ]),
+
+            name => {
+                if let Some(ty) = self.aliases.get(name) {
+                    return self.ty(ty);
+                }
+
+                match self.names.get(name) {
+                    Some(name) => Ty::Ref(name.clone()),
+                    None => bail!("unknown type: {}", ty.to_token_stream()),
+                }
+            }
+        })
+    }
+}
+
+enum Repr {
+    External,
+    Internal(String),
+    Untagged,
+}
+
+fn field(name: &str, ty: Ty) -> Field {
+    Field {
+        name: name.into(),
+        ty,
+        optional: false,
+        default: false,
+        doc: None,
+    }
+}
+
+fn generic_arg(seg: &PathSegment) -> Result<&Type, Error> {
+    if let PathArguments::AngleBracketed(args) = &seg.arguments {
+        for arg in &args.args {
+            if let GenericArgument::Type(ty) = arg {
+                return Ok(ty);
+            }
+        }
+    }
+
+    bail!("`{}` requires a type argument", seg.ident)
+}
+
+/// Returns `Some(tag)` if the type is annotated with `#[ast_node]` or
+/// `#[ast_serde]`.
+fn node_attr(attrs: &[Attribute]) -> Result<Option<Option<String>>, Error> {
+    for attr in attrs {
+        if attr.path.is_ident("ast_node") || attr.path.is_ident("ast_serde") {
+            if attr.tokens.is_empty() {
+                return Ok(Some(None));
+            }
+            return Ok(Some(Some(attr.parse_args::<LitStr>()?.value())));
+        }
+    }
+
+    Ok(None)
+}
+
+fn derives(attrs: &[Attribute], name: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("derive"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .any(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) => {
+                path.segments.last().map_or(false, |seg| seg.ident == name)
+            }
+            _ => false,
+        })
+}
+
+fn doc(attrs: &[Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(nv)) => match nv.lit {
+                Lit::Str(s) => Some(s.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|line| {
+            line.strip_prefix(' ')
+                .unwrap_or(&line)
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+
+    let doc = lines.join("\n").trim().to_string();
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc)
+    }
+}
+
+/// Same as `string_enum`.
+fn str_value(v: &Variant) -> Result<String, Error> {
+    let doc = doc(&v.attrs).unwrap_or_default();
+    for line in doc.lines() {
+        let line = line.trim();
+        if line.len() >= 2 && line.starts_with('`') && line.ends_with('`') {
+            return Ok(line[1..line.len() - 1].into());
+        }
+    }
+
+    bail!("cannot determine the string value of `{}`", v.ident)
+}
+
+#[derive(Default)]
+struct SerdeAttrs {
+    rename: Option<String>,
+    rename_all: Option<String>,
+    tag: Option<String>,
+    untagged: bool,
+    default: bool,
+    flatten: bool,
+    skip: bool,
+    skip_serializing_if: bool,
+}
+
+fn serde_attrs(attrs: &[Attribute]) -> Result<SerdeAttrs, Error> {
+    let mut out = SerdeAttrs::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("serde")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            _ => continue,
+        };
+
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::Path(path)) => {
+                    let name = path.to_token_stream().to_string();
+                    match &*name {
+                        "default" => out.default = true,
+                        "flatten" => out.flatten = true,
+                        "untagged" => out.untagged = true,
+                        "skip" | "skip_serializing" => out.skip = true,
+                        _ => {}
+                    }
+                }
+
+                NestedMeta::Meta(Meta::NameValue(nv)) => {
+                    let name = nv.path.to_token_stream().to_string();
+                    let value = match nv.lit {
+                        Lit::Str(s) => s.value(),
+                        _ => continue,
+                    };
+                    match &*name {
+                        "rename" => out.rename = Some(value),
+                        "rename_all" => out.rename_all = Some(value),
+                        "tag" => out.tag = Some(value),
+                        "default" => out.default = true,
+                        "skip_serializing_if" => out.skip_serializing_if = true,
+                        "content" => bail!("adjacently tagged enums are not supported"),
+                        _ => {}
+                    }
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+/// Applies `rename_all` of serde to a field.
+fn rename_field(rule: Option<&str>, name: &str) -> Result<String, Error> {
+    let pascal = || {
+        let mut s = String::new();
+        let mut capitalize = true;
+        for c in name.chars() {
+            if c == '_' {
+                capitalize = true;
+            } else if capitalize {
+                s.push(c.to_ascii_uppercase());
+                capitalize = false;
+            } else {
+                s.push(c);
+            }
+        }
+        s
+    };
+
+    Ok(match rule {
+        None | Some("lowercase") | Some("snake_case") => name.into(),
+        Some("UPPERCASE") | Some("SCREAMING_SNAKE_CASE") => name.to_ascii_uppercase(),
+        Some("PascalCase") => pascal(),
+        Some("camelCase") => {
+            let pascal = pascal();
+            match pascal.chars().next() {
+                Some(c) => c.to_ascii_lowercase().to_string() + &pascal[c.len_utf8()..],
+                None => pascal,
+            }
+        }
+        Some("kebab-case") => name.replace('_', "-"),
+        Some(rule) => bail!("unsupported rename rule: {}", rule),
+    })
+}
+
+/// Applies `rename_all` of serde to a variant.
+fn rename_variant(rule: Option<&str>, name: &str) -> Result<String, Error> {
+    let snake = || {
+        let mut s = String::new();
+        for (i, c) in name.char_indices() {
+            if c.is_uppercase() && i != 0 {
+                s.push('_');
+            }
+            s.push(c.to_ascii_lowercase());
+        }
+        s
+    };
+
+    Ok(match rule {
+        None | Some("PascalCase") => name.into(),
+        Some("lowercase") => name.to_ascii_lowercase(),
+        Some("UPPERCASE") => name.to_ascii_uppercase(),
+        Some("camelCase") => {
+            let mut chars = name.chars();
+            match chars.next() {
+                Some(c) => c.to_ascii_lowercase().to_string() + chars.as_str(),
+                None => name.into(),
+            }
+        }
+        Some("snake_case") => snake(),
+        Some("SCREAMING_SNAKE_CASE") => snake().to_ascii_uppercase(),
+        Some("kebab-case") => snake().replace('_', "-"),
+        Some(rule) => bail!("unsupported rename rule: {}", rule),
+    })
+}
@@ -0,0 +1,99 @@
+use crate::{
+    model::{DeclKind, Field, Ty},
+    Ast,
+};
+use serde_json::{json, Map, Value};
+
+/// Builds a json schema (draft 7) which validates [Ast::root].
+///
+/// The schema describes the json accepted by the deserializer, so properties
+/// with default values are not required.
+pub(crate) fn build(ast: &Ast) -> Value {
+    let mut definitions = Map::new();
+
+    for decl in &ast.decls {
+        let mut schema = match &decl.kind {
+            DeclKind::Interface { extends, fields } if extends.is_empty() => object(fields),
+            DeclKind::Interface { extends, fields } => {
+                let mut all_of = extends
+                    .iter()
+                    .map(|name| reference(name))
+                    .collect::<Vec<_>>();
+                all_of.push(object(fields));
+                json!({ "allOf": all_of })
+            }
+            DeclKind::Alias(ty) => schema(ty),
+        };
+
+        if let Some(doc) = &decl.doc {
+            schema["description"] = doc.clone().into();
+        }
+        definitions.insert(decl.name.clone(), schema);
+    }
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "$ref": format!("#/definitions/{}", ast.root),
+        "definitions": definitions,
+    })
+}
+
+fn reference(name: &str) -> Value {
+    json!({ "$ref": format!("#/definitions/{}", name) })
+}
+
+fn object(fields: &[Field]) -> Value {
+    let mut properties = Map::new();
+    let mut required = vec![];
+
+    for f in fields {
+        let mut schema = schema(&f.ty);
+        if let Some(doc) = &f.doc {
+            // `$ref` ignores sibling keywords.
+            schema = match schema.get("$ref") {
+                Some(..) => json!({ "allOf": [schema] }),
+                None => schema,
+            };
+            schema["description"] = doc.clone().into();
+        }
+
+        properties.insert(f.name.clone(), schema);
+        if !f.optional && !f.default {
+            required.push(Value::String(f.name.clone()));
+        }
+    }
+
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+fn schema(ty: &Ty) -> Value {
+    match ty {
+        Ty::String => json!({ "type": "string" }),
+        Ty::Number => json!({ "type": "number" }),
+        Ty::Boolean => json!({ "type": "boolean" }),
+        Ty::Null => json!({ "type": "null" }),
+        Ty::Literal(v) => json!({ "const": v }),
+        Ty::Ref(name) => reference(name),
+        Ty::Array(elem) => json!({
+            "type": "array",
+            "items": schema(elem),
+        }),
+        Ty::Tuple(elems) => json!({
+            "type": "array",
+            "items": elems.iter().map(schema).collect::<Vec<_>>(),
+            "minItems": elems.len(),
+            "maxItems": elems.len(),
+        }),
+        Ty::Nullable(ty) => json!({
+            "anyOf": [schema(ty), { "type": "null" }],
+        }),
+        Ty::Union(types) => json!({
+            "anyOf": types.iter().map(schema).collect::<Vec<_>>(),
+        }),
+        Ty::Object(fields) => object(fields),
+    }
+}
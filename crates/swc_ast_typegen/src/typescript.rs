@@ -0,0 +1,231 @@
+use crate::{
+    model::{Decl, DeclKind, Field, Ty},
+    Ast,
+};
+use std::{collections::HashSet, fmt::Write};
+
+pub(crate) fn print(ast: &Ast) -> String {
+    let mut s = String::new();
+    s.push_str("// This file is generated by `swc_ast_typegen`. Do not edit it manually.\n");
+    s.push_str("// Run `cargo run -p swc_ast_typegen` to update it.\n");
+
+    for decl in &ast.decls {
+        s.push('\n');
+        print_decl(&mut s, decl, Mode::Output);
+    }
+
+    let input = input_decls(ast);
+    if !input.is_empty() {
+        s.push_str(
+            "\n/**\n * Types accepted by the deserializer, which fills in omitted properties \
+             with\n * default values. Other types are the same as the output types.\n */\n",
+        );
+        s.push_str("export namespace Input {\n");
+        for (i, decl) in ast
+            .decls
+            .iter()
+            .filter(|d| input.contains(&*d.name))
+            .enumerate()
+        {
+            if i != 0 {
+                s.push('\n');
+            }
+
+            let mut buf = String::new();
+            print_decl(&mut buf, decl, Mode::Input);
+            for line in buf.lines() {
+                if line.is_empty() {
+                    s.push('\n');
+                } else {
+                    writeln!(s, "  {}", line).unwrap();
+                }
+            }
+        }
+        s.push_str("}\n");
+    }
+
+    s
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// Json produced by the serializer.
+    Output,
+    /// Json accepted by the deserializer.
+    Input,
+}
+
+fn print_decl(s: &mut String, decl: &Decl, mode: Mode) {
+    if mode == Mode::Output {
+        print_doc(s, decl.doc.as_deref(), 0);
+    }
+
+    match &decl.kind {
+        DeclKind::Interface { extends, fields } => {
+            write!(s, "export interface {}", decl.name).unwrap();
+            if !extends.is_empty() {
+                write!(s, " extends {}", extends.join(", ")).unwrap();
+            }
+            s.push_str(" {\n");
+            for f in fields {
+                print_field(s, f, 1, mode);
+            }
+            s.push_str("}\n");
+        }
+
+        DeclKind::Alias(Ty::Union(types)) if types.len() > 1 => {
+            writeln!(s, "export type {} =", decl.name).unwrap();
+            for (i, ty) in types.iter().enumerate() {
+                let end = if i == types.len() - 1 { ";" } else { "" };
+                writeln!(s, "  | {}{}", ty_str(ty, 1, mode), end).unwrap();
+            }
+        }
+
+        DeclKind::Alias(ty) => {
+            writeln!(s, "export type {} = {};", decl.name, ty_str(ty, 0, mode)).unwrap();
+        }
+    }
+}
+
+/// Returns the names of types whose input differs from the output, because
+/// they have a property with a default value or refer to such a type.
+///
+/// Other types are not declared in `Input`, so references to them resolve to
+/// the output types.
+fn input_decls(ast: &Ast) -> HashSet<&str> {
+    let mut set = HashSet::new();
+
+    loop {
+        let mut changed = false;
+        for decl in &ast.decls {
+            if set.contains(&*decl.name) {
+                continue;
+            }
+
+            let differs = match &decl.kind {
+                DeclKind::Interface { extends, fields } => {
+                    extends.iter().any(|name| set.contains(&**name))
+                        || fields.iter().any(|f| field_differs(f, &set))
+                }
+                DeclKind::Alias(ty) => ty_differs(ty, &set),
+            };
+            if differs {
+                set.insert(&*decl.name);
+                changed = true;
+            }
+        }
+
+        if !changed {
+            return set;
+        }
+    }
+}
+
+fn field_differs(f: &Field, set: &HashSet<&str>) -> bool {
+    (f.default && !f.optional) || ty_differs(&f.ty, set)
+}
+
+fn ty_differs(ty: &Ty, set: &HashSet<&str>) -> bool {
+    match ty {
+        Ty::String | Ty::Number | Ty::Boolean | Ty::Null | Ty::Literal(..) => false,
+        Ty::Ref(name) => set.contains(&**name),
+        Ty::Array(ty) | Ty::Nullable(ty) => ty_differs(ty, set),
+        Ty::Tuple(types) | Ty::Union(types) => types.iter().any(|ty| ty_differs(ty, set)),
+        Ty::Object(fields) => fields.iter().any(|f| field_differs(f, set)),
+    }
+}
+
+fn print_doc(s: &mut String, doc: Option<&str>, indent: usize) {
+    let doc = match doc {
+        Some(v) => v.replace("*/", "*\\/"),
+        None => return,
+    };
+    let indent = "  ".repeat(indent);
+
+    if !doc.contains('\n') {
+        writeln!(s, "{}/** {} */", indent, doc).unwrap();
+        return;
+    }
+
+    writeln!(s, "{}/**", indent).unwrap();
+    for line in doc.lines() {
+        if line.is_empty() {
+            writeln!(s, "{} *", indent).unwrap();
+        } else {
+            writeln!(s, "{} * {}", indent, line).unwrap();
+        }
+    }
+    writeln!(s, "{} */", indent).unwrap();
+}
+
+fn print_field(s: &mut String, f: &Field, indent: usize, mode: Mode) {
+    if mode == Mode::Output {
+        print_doc(s, f.doc.as_deref(), indent);
+    }
+    let optional = match mode {
+        Mode::Output => f.optional,
+        Mode::Input => f.optional || f.default,
+    };
+
+    let name = if is_ident(&f.name) {
+        f.name.clone()
+    } else {
+        serde_json::to_string(&f.name).unwrap()
+    };
+    writeln!(
+        s,
+        "{}{}{}: {};",
+        "  ".repeat(indent),
+        name,
+        if optional { "?" } else { "" },
+        ty_str(&f.ty, indent, mode)
+    )
+    .unwrap();
+}
+
+fn is_ident(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// `indent` is the indentation of the line where the type starts.
+fn ty_str(ty: &Ty, indent: usize, mode: Mode) -> String {
+    match ty {
+        Ty::String => "string".into(),
+        Ty::Number => "number".into(),
+        Ty::Boolean => "boolean".into(),
+        Ty::Null => "null".into(),
+        Ty::Literal(v) => v.to_string(),
+        Ty::Ref(name) => name.clone(),
+        Ty::Array(elem) => match **elem {
+            Ty::Union(..) | Ty::Nullable(..) => format!("({})[]", ty_str(elem, indent, mode)),
+            _ => format!("{}[]", ty_str(elem, indent, mode)),
+        },
+        Ty::Tuple(elems) => format!(
+            "[{}]",
+            elems
+                .iter()
+                .map(|ty| ty_str(ty, indent, mode))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Ty::Nullable(ty) => format!("{} | null", ty_str(ty, indent, mode)),
+        Ty::Union(types) => types
+            .iter()
+            .map(|ty| ty_str(ty, indent, mode))
+            .collect::<Vec<_>>()
+            .join(" | "),
+        Ty::Object(fields) if fields.is_empty() => "{}".into(),
+        Ty::Object(fields) => {
+            let mut s = String::from("{\n");
+            for f in fields {
+                print_field(&mut s, f, indent + 1, mode);
+            }
+            write!(s, "{}}}", "  ".repeat(indent)).unwrap();
+            s
+        }
+    }
+}
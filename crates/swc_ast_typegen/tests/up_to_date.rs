@@ -0,0 +1,24 @@
+use std::fs;
+use swc_ast_typegen::{repo_root, TARGETS};
+
+/// Fails if an ast node is modified without regenerating the types.
+#[test]
+fn generated_files_are_up_to_date() {
+    let root = repo_root();
+
+    for target in TARGETS {
+        let ast = target.load(&root).unwrap();
+
+        for (path, expected) in [
+            (target.typescript_path(&root), ast.to_typescript()),
+            (target.json_schema_path(&root), ast.to_json_schema()),
+        ] {
+            let actual = fs::read_to_string(&path).unwrap_or_default();
+            assert!(
+                actual == expected,
+                "{} is outdated. Run `cargo run -p swc_ast_typegen` to update it.",
+                path.display()
+            );
+        }
+    }
+}
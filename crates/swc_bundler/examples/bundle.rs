@@ -90,6 +90,8 @@ fn do_test(_entry: &Path, entries: HashMap<String, FileName>, inline: bool, mini
                 disable_hygiene: minify,
                 disable_dce: false,
                 module: Default::default(),
+                chunks: None,
+                hmr: None,
             },
             Box::new(Hook),
         );
@@ -426,7 +426,7 @@ where
         entry.retain_mut(|_, item| {
             match item {
                 ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export)) => {
-                    if self.config.external_modules.contains(&export.src.value) {
+                    if self.is_external(&export.src.value) {
                         return true;
                     }
 
@@ -435,7 +435,7 @@ where
 
                 ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) => {
                     if let Some(src) = &export.src {
-                        if self.config.external_modules.contains(&src.value) {
+                        if self.is_external(&src.value) {
                             return true;
                         }
                     }
@@ -448,7 +448,7 @@ where
                 }
 
                 ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
-                    if self.config.external_modules.contains(&import.src.value) {
+                    if self.is_external(&import.src.value) {
                         return true;
                     }
 
@@ -566,7 +566,7 @@ where
                 match item {
                     ModuleItem::ModuleDecl(ModuleDecl::Import(mut import)) => {
                         // Preserve imports from node.js builtin modules.
-                        if self.config.external_modules.contains(&import.src.value) {
+                        if self.is_external(&import.src.value) {
                             new.push(ModuleItem::ModuleDecl(ModuleDecl::Import(import)));
                             continue;
                         }
@@ -1187,7 +1187,7 @@ where
 
             for stmt in stmts {
                 if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = &stmt {
-                    if self.config.external_modules.contains(&import.src.value) {
+                    if self.is_external(&import.src.value) {
                         new.push(stmt);
                         continue;
                     }
@@ -16,7 +16,7 @@ struct PlanBuilder {
 }
 
 #[derive(Debug, Default)]
-pub(super) struct Plan {
+pub(crate) struct Plan {
     pub entries: AHashMap<ModuleId, BundleKind>,
 
     /// Id of all modules.
@@ -43,7 +43,7 @@ where
     L: Load,
     R: Resolve,
 {
-    pub(super) fn determine_entries(
+    pub(crate) fn determine_entries(
         &self,
         entries: AHashMap<String, TransformedModule>,
     ) -> Result<(Plan, ModuleGraph, Vec<Vec<ModuleId>>), Error> {
@@ -51,7 +51,17 @@ where
         let mut analyzer = GraphAnalyzer::new(&self.scope);
 
         for (name, module) in entries {
-            if let Some(v) = builder.kinds.insert(module.id, BundleKind::Named { name }) {
+            let is_chunk = self
+                .split
+                .as_ref()
+                .map_or(false, |split| split.is_chunk_entry(&module.fm.name));
+            let kind = if is_chunk {
+                BundleKind::Lib { name }
+            } else {
+                BundleKind::Named { name }
+            };
+
+            if let Some(v) = builder.kinds.insert(module.id, kind) {
                 bail!("Multiple entries with same input path detected: {:?}", v)
             }
 
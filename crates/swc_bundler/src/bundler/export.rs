@@ -69,13 +69,7 @@ where
     /// Returns `(local, export)`.
     fn ctxt_for(&self, src: &JsWord) -> Option<(SyntaxContext, SyntaxContext)> {
         // Don't apply mark if it's a core module.
-        if self
-            .bundler
-            .config
-            .external_modules
-            .iter()
-            .any(|v| v == src)
-        {
+        if self.bundler.is_external(src) {
             return None;
         }
         let path = self.bundler.resolve(self.file_name, src).ok()?;
@@ -89,13 +83,7 @@ where
 
     fn mark_as_wrapping_required(&self, src: &JsWord) {
         // Don't apply mark if it's a core module.
-        if self
-            .bundler
-            .config
-            .external_modules
-            .iter()
-            .any(|v| v == src)
-        {
+        if self.bundler.is_external(src) {
             return;
         }
         let path = self.bundler.resolve(self.file_name, src);
@@ -1,18 +1,28 @@
+use super::split::ChunkRenamer;
 use crate::{hash::calc_hash, Bundle, BundleKind, Bundler, Load, ModuleType, Resolve};
 use ahash::AHashMap;
-use anyhow::Error;
+use anyhow::{bail, Error};
+use indexmap::IndexMap;
 use relative_path::RelativePath;
-use std::path::{Path, PathBuf};
-use swc_atoms::js_word;
-use swc_common::{util::move_map::MoveMap, FileName, DUMMY_SP};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+use swc_atoms::{js_word, JsWord};
+use swc_common::{util::move_map::MoveMap, FileName, Span, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::{
     fixer::fixer,
     helpers::{inject_helpers, HELPERS},
     hygiene::hygiene,
 };
-use swc_ecma_utils::{find_ids, private_ident, ExprFactory};
-use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, FoldWith, Visit, VisitWith};
+use swc_ecma_utils::{
+    find_ids, ident::IdentLike, member_expr, private_ident, quote_ident, ExprFactory,
+};
+use swc_ecma_visit::{
+    noop_fold_type, noop_visit_mut_type, noop_visit_type, Fold, FoldWith, Visit, VisitMut,
+    VisitMutWith, VisitWith,
+};
 
 impl<L, R> Bundler<'_, L, R>
 where
@@ -21,6 +31,7 @@ where
 {
     /// This method do
     ///
+    /// - wrap modules if required by [ModuleType]
     /// - inject helpers
     /// - rename chunks
     /// - invoke fixer
@@ -32,12 +43,12 @@ where
             for mut bundle in bundles {
                 bundle.module = self.optimize(bundle.module);
 
+                bundle.module = self.wrap_module(bundle.module)?;
+
                 if !self.config.disable_hygiene {
                     bundle.module = bundle.module.fold_with(&mut hygiene());
                 }
 
-                bundle.module = self.may_wrap_with_iife(bundle.module);
-
                 if !self.config.disable_fixer {
                     bundle.module = bundle.module.fold_with(&mut fixer(None));
                 }
@@ -115,9 +126,21 @@ where
                 return Ok(new);
             }
 
+            if let Some(split) = &self.split {
+                let renamed = split.renamed_specifiers(&renamed);
+
+                for bundle in &mut new {
+                    bundle
+                        .module
+                        .visit_mut_with(&mut ChunkRenamer { renamed: &renamed });
+                }
+            }
+
             new = new.move_map(|bundle| {
                 let path = match self.scope.get_module(bundle.id).unwrap().fm.name {
                     FileName::Real(ref v) => v.clone(),
+                    // Synthesized entry of a shared chunk.
+                    FileName::Custom(..) if self.split.is_some() => return bundle,
                     _ => {
                         tracing::error!("Cannot rename: not a real file");
                         return bundle;
@@ -141,231 +164,1049 @@ where
         })
     }
 
-    fn may_wrap_with_iife(&self, module: Module) -> Module {
-        if self.config.module != ModuleType::Iife {
-            return module;
+    /// Converts the bundled module to the module type specified by
+    /// [Config](crate::Config).
+    fn wrap_module(&self, module: Module) -> Result<Module, Error> {
+        match &self.config.module {
+            ModuleType::Es => Ok(module),
+            ModuleType::Iife => self.wrap_with_iife(module),
+            ModuleType::Cjs => self.wrap_with_cjs(module),
+            ModuleType::Umd { name, globals } => self.wrap_with_umd(module, name, globals),
         }
+    }
 
-        let mut top_level_await_finder = TopLevelAwaitFinder::default();
-        module.visit_with(&mut top_level_await_finder);
+    fn wrap_with_iife(&self, module: Module) -> Result<Module, Error> {
+        let SplitModule {
+            span,
+            is_async,
+            mut stmts,
+            props,
+            ..
+        } = split_module(module)?;
 
-        let is_async = top_level_await_finder.found;
+        stmts.push(Stmt::Return(ReturnStmt {
+            span: DUMMY_SP,
+            arg: Some(Box::new(Expr::Object(ObjectLit {
+                span: DUMMY_SP,
+                props,
+            }))),
+        }));
 
-        // Properties of returned object
-        let mut props = vec![];
+        let f = Function {
+            is_generator: false,
+            is_async,
+            params: Default::default(),
+            decorators: Default::default(),
+            span: DUMMY_SP,
+            body: Some(BlockStmt { span, stmts }),
+            type_params: Default::default(),
+            return_type: Default::default(),
+        };
 
-        let mut body = BlockStmt {
-            span: module.span,
-            stmts: module
-                .body
-                .into_iter()
-                .filter_map(|item| {
-                    let decl = match item {
-                        ModuleItem::ModuleDecl(v) => v,
-                        ModuleItem::Stmt(stmt) => return Some(stmt),
-                    };
+        let invoked_fn_expr = FnExpr {
+            ident: None,
+            function: f,
+        };
 
-                    match decl {
-                        ModuleDecl::ExportNamed(NamedExport { src: Some(..), .. })
-                        | ModuleDecl::TsImportEquals(_)
-                        | ModuleDecl::TsExportAssignment(_)
-                        | ModuleDecl::TsNamespaceExport(_)
-                        | ModuleDecl::Import(_) => None,
-
-                        ModuleDecl::ExportDecl(export) => {
-                            match &export.decl {
-                                Decl::Class(ClassDecl { ident, .. })
-                                | Decl::Fn(FnDecl { ident, .. }) => {
-                                    props.push(PropOrSpread::Prop(Box::new(Prop::Shorthand(
-                                        ident.clone(),
-                                    ))));
-                                }
-                                Decl::Var(decl) => {
-                                    let ids: Vec<Ident> = find_ids(decl);
-                                    props.extend(
-                                        ids.into_iter()
-                                            .map(Prop::Shorthand)
-                                            .map(Box::new)
-                                            .map(PropOrSpread::Prop),
-                                    );
-                                }
-                                _ => unreachable!(),
-                            }
+        let iife = Box::new(Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: invoked_fn_expr.as_callee(),
+            args: Default::default(),
+            type_args: Default::default(),
+        }));
+
+        Ok(Module {
+            span: DUMMY_SP,
+            shebang: None,
+            body: vec![ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+                span: DUMMY_SP,
+                expr: iife,
+            }))],
+        })
+    }
+
+    /// Emits
+    ///
+    /// ```js
+    /// const _foo = require('foo');
+    ///
+    /// // body, with `a` replaced by `_foo.a`
+    ///
+    /// module.exports = { __esModule: true, b };
+    /// Object.keys(_bar).forEach(function (key) {
+    ///     // Copies `_bar[key]` to `module.exports`
+    /// });
+    /// ```
+    fn wrap_with_cjs(&self, module: Module) -> Result<Module, Error> {
+        let SplitModule {
+            span,
+            shebang,
+            is_async,
+            stmts,
+            props,
+            imports,
+            reexports,
+        } = split_module(module)?;
+
+        if is_async {
+            bail!("top-level await cannot be used while bundling as a commonjs module")
+        }
+
+        let externals = Externals::new(imports, reexports);
+        let (stmts, props) = externals.rewrite(stmts, props);
+
+        let mut body = externals
+            .modules
+            .iter()
+            .map(|(src, var)| {
+                let require = quote_ident!("require")
+                    .as_call(DUMMY_SP, vec![Str::from(src.clone()).as_arg()]);
+
+                const_decl(var.clone(), require)
+            })
+            .chain(externals.stmts)
+            .chain(stmts)
+            .map(ModuleItem::Stmt)
+            .collect::<Vec<_>>();
+
+        let exports = exports_obj(
+            externals.props.into_iter().chain(props).collect(),
+            !externals.export_all.is_empty(),
+        );
+        if let Some(exports) = exports {
+            body.push(ModuleItem::Stmt(
+                Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    op: op!("="),
+                    left: PatOrExpr::Expr(member_expr!(DUMMY_SP, module.exports)),
+                    right: Box::new(exports),
+                })
+                .into_stmt(),
+            ));
+            body.extend(
+                externals
+                    .export_all
+                    .into_iter()
+                    .map(|module| export_all(*member_expr!(DUMMY_SP, module.exports), module))
+                    .map(ModuleItem::Stmt),
+            );
+        }
+
+        Ok(Module {
+            span,
+            shebang,
+            body,
+        })
+    }
+
+    /// Emits
+    ///
+    /// ```js
+    /// (function (global, factory) {
+    ///     typeof exports === "object" && typeof module !== "undefined"
+    ///         ? module.exports = factory(require("foo"))
+    ///         : typeof define === "function" && define.amd
+    ///             ? define(["foo"], factory)
+    ///             : (global = typeof globalThis !== "undefined" ? globalThis : global || self,
+    ///                 global.name = factory(global.foo));
+    /// })(this, function (_foo) {
+    ///     // body, with `a` replaced by `_foo.a`
+    ///
+    ///     return { __esModule: true, b };
+    /// });
+    /// ```
+    ///
+    /// `globals` maps module specifiers of external modules to the names of
+    /// global variables.
+    fn wrap_with_umd(
+        &self,
+        module: Module,
+        name: &str,
+        globals: &BTreeMap<String, String>,
+    ) -> Result<Module, Error> {
+        let SplitModule {
+            span,
+            shebang,
+            is_async,
+            stmts,
+            props,
+            imports,
+            reexports,
+        } = split_module(module)?;
+
+        if is_async {
+            bail!("top-level await cannot be used while bundling as an umd module")
+        }
+
+        let externals = Externals::new(imports, reexports);
+        let (stmts, props) = externals.rewrite(stmts, props);
+
+        let mut stmts = externals.stmts.into_iter().chain(stmts).collect::<Vec<_>>();
+
+        let exports = exports_obj(
+            externals.props.into_iter().chain(props).collect(),
+            !externals.export_all.is_empty(),
+        );
+        if let Some(mut exports) = exports {
+            if !externals.export_all.is_empty() {
+                let var = private_ident!("_exports");
+                stmts.push(const_decl(var.clone(), exports));
+                stmts.extend(
+                    externals
+                        .export_all
+                        .into_iter()
+                        .map(|module| export_all(Expr::Ident(var.clone()), module)),
+                );
+                exports = Expr::Ident(var);
+            }
+
+            stmts.push(Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(Box::new(exports)),
+            }));
+        }
 
-                            Some(Stmt::Decl(export.decl))
+        let factory_fn = FnExpr {
+            ident: None,
+            function: Function {
+                params: externals
+                    .modules
+                    .values()
+                    .cloned()
+                    .map(|var| Param::from(Pat::Ident(var.into())))
+                    .collect(),
+                decorators: Default::default(),
+                span: DUMMY_SP,
+                body: Some(BlockStmt { span, stmts }),
+                is_generator: false,
+                is_async: false,
+                type_params: Default::default(),
+                return_type: Default::default(),
+            },
+        };
+
+        let global = private_ident!("global");
+        let factory = private_ident!("factory");
+
+        let cjs = {
+            let args = externals
+                .modules
+                .keys()
+                .map(|src| {
+                    quote_ident!("require")
+                        .as_call(DUMMY_SP, vec![Str::from(src.clone()).as_arg()])
+                        .as_arg()
+                })
+                .collect();
+
+            Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Expr(member_expr!(DUMMY_SP, module.exports)),
+                right: Box::new(factory.clone().as_call(DUMMY_SP, args)),
+            })
+        };
+
+        let amd = {
+            let deps = ArrayLit {
+                span: DUMMY_SP,
+                elems: externals
+                    .modules
+                    .keys()
+                    .map(|src| Some(Str::from(src.clone()).as_arg()))
+                    .collect(),
+            };
+
+            quote_ident!("define").as_call(DUMMY_SP, vec![deps.as_arg(), factory.clone().as_arg()])
+        };
+
+        let global_var = {
+            let global_this = Expr::Cond(CondExpr {
+                span: DUMMY_SP,
+                test: Box::new(is_defined(quote_ident!("globalThis").into())),
+                cons: Box::new(quote_ident!("globalThis").into()),
+                alt: Box::new(global.clone().make_bin(op!("||"), quote_ident!("self"))),
+            });
+            let init_global = Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Pat(Box::new(Pat::Ident(global.clone().into()))),
+                right: Box::new(global_this),
+            });
+
+            let args = externals
+                .modules
+                .keys()
+                .map(|src| {
+                    let name = match globals.get(&**src) {
+                        Some(name) => name.clone(),
+                        None => {
+                            let name = global_name_of(src);
+                            tracing::warn!(
+                                "No global name is provided for `{}`; using `{}`",
+                                src,
+                                name
+                            );
+                            name
                         }
+                    };
 
-                        ModuleDecl::ExportNamed(NamedExport {
-                            specifiers,
-                            src: None,
-                            ..
-                        }) => {
-                            for s in specifiers {
-                                match s {
-                                    ExportSpecifier::Namespace(..) => {
-                                        // unreachable
-                                    }
-                                    ExportSpecifier::Default(s) => {
-                                        props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(
-                                            KeyValueProp {
-                                                key: PropName::Ident(Ident::new(
-                                                    js_word!("default"),
-                                                    DUMMY_SP,
-                                                )),
-                                                value: Box::new(Expr::Ident(s.exported)),
-                                            },
-                                        ))));
-                                    }
-                                    ExportSpecifier::Named(s) => match s.exported {
-                                        Some(ModuleExportName::Ident(exported)) => {
-                                            let orig = match s.orig {
-                                                ModuleExportName::Ident(ident) => ident,
-                                                ModuleExportName::Str(..) => unimplemented!(
-                                                    "module string names unimplemented"
-                                                ),
-                                            };
-                                            props.push(PropOrSpread::Prop(Box::new(
-                                                Prop::KeyValue(KeyValueProp {
-                                                    key: PropName::Ident(exported),
-                                                    value: Box::new(Expr::Ident(orig)),
-                                                }),
-                                            )));
-                                        }
-                                        Some(ModuleExportName::Str(..)) => {
-                                            unimplemented!("module string names unimplemented")
-                                        }
-                                        None => {
-                                            let orig = match s.orig {
-                                                ModuleExportName::Ident(ident) => ident,
-                                                ModuleExportName::Str(..) => unimplemented!(
-                                                    "module string names unimplemented"
-                                                ),
-                                            };
-                                            props.push(PropOrSpread::Prop(Box::new(
-                                                Prop::Shorthand(orig),
-                                            )));
-                                        }
-                                    },
-                                }
-                            }
+                    global_path(global.clone(), &name).as_arg()
+                })
+                .collect();
+            let assign = Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Expr(Box::new(global_member(global.clone(), name))),
+                right: Box::new(factory.clone().as_call(DUMMY_SP, args)),
+            });
+
+            Expr::Seq(SeqExpr {
+                span: DUMMY_SP,
+                exprs: vec![Box::new(init_global), Box::new(assign)],
+            })
+        };
+
+        let is_cjs = typeof_eq(quote_ident!("exports").into(), "object")
+            .make_bin(op!("&&"), is_defined(quote_ident!("module").into()));
+        let is_amd = typeof_eq(quote_ident!("define").into(), "function")
+            .make_bin(op!("&&"), *member_expr!(DUMMY_SP, define.amd));
+
+        let wrapper = FnExpr {
+            ident: None,
+            function: Function {
+                params: vec![
+                    Param::from(Pat::Ident(global.into())),
+                    Param::from(Pat::Ident(factory.into())),
+                ],
+                decorators: Default::default(),
+                span: DUMMY_SP,
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![Expr::Cond(CondExpr {
+                        span: DUMMY_SP,
+                        test: Box::new(is_cjs),
+                        cons: Box::new(cjs),
+                        alt: Box::new(Expr::Cond(CondExpr {
+                            span: DUMMY_SP,
+                            test: Box::new(is_amd),
+                            cons: Box::new(amd),
+                            alt: Box::new(global_var),
+                        })),
+                    })
+                    .into_stmt()],
+                }),
+                is_generator: false,
+                is_async: false,
+                type_params: Default::default(),
+                return_type: Default::default(),
+            },
+        };
+
+        let umd = wrapper.as_call(
+            DUMMY_SP,
+            vec![ThisExpr { span: DUMMY_SP }.as_arg(), factory_fn.as_arg()],
+        );
 
-                            None
+        Ok(Module {
+            span: DUMMY_SP,
+            shebang,
+            body: vec![ModuleItem::Stmt(umd.into_stmt())],
+        })
+    }
+}
+
+/// A bundled module, split into parts required to wrap it.
+struct SplitModule {
+    span: Span,
+    shebang: Option<JsWord>,
+    is_async: bool,
+    /// Statements of the module, including declarations which were exported.
+    stmts: Vec<Stmt>,
+    /// Properties of the object containing local exports.
+    props: Vec<PropOrSpread>,
+    /// Imports of external modules.
+    imports: Vec<ImportDecl>,
+    /// `export * from 'foo'` and `export { a } from 'foo'`.
+    reexports: Vec<ModuleDecl>,
+}
+
+fn split_module(module: Module) -> Result<SplitModule, Error> {
+    let mut top_level_await_finder = TopLevelAwaitFinder::default();
+    module.visit_with(&mut top_level_await_finder);
+
+    let is_async = top_level_await_finder.found;
+
+    // Properties of returned object
+    let mut props = vec![];
+    let mut imports = vec![];
+    let mut reexports = vec![];
+
+    let stmts = module
+        .body
+        .into_iter()
+        .map(|item| {
+            let decl = match item {
+                ModuleItem::ModuleDecl(v) => v,
+                ModuleItem::Stmt(stmt) => return Ok(Some(stmt)),
+            };
+
+            Ok(match decl {
+                ModuleDecl::Import(import) => {
+                    if !import.type_only {
+                        imports.push(import);
+                    }
+                    None
+                }
+
+                ModuleDecl::ExportNamed(NamedExport {
+                    src: Some(..),
+                    type_only: false,
+                    ..
+                })
+                | ModuleDecl::ExportAll(_) => {
+                    reexports.push(decl);
+                    None
+                }
+
+                ModuleDecl::ExportNamed(NamedExport { src: Some(..), .. })
+                | ModuleDecl::TsImportEquals(_)
+                | ModuleDecl::TsExportAssignment(_)
+                | ModuleDecl::TsNamespaceExport(_) => None,
+
+                ModuleDecl::ExportDecl(export) => {
+                    match &export.decl {
+                        Decl::Class(ClassDecl { ident, .. }) | Decl::Fn(FnDecl { ident, .. }) => {
+                            props
+                                .push(PropOrSpread::Prop(Box::new(Prop::Shorthand(ident.clone()))));
+                        }
+                        Decl::Var(decl) => {
+                            let ids: Vec<Ident> = find_ids(decl);
+                            props.extend(
+                                ids.into_iter()
+                                    .map(Prop::Shorthand)
+                                    .map(Box::new)
+                                    .map(PropOrSpread::Prop),
+                            );
                         }
+                        _ => unreachable!(),
+                    }
 
-                        ModuleDecl::ExportDefaultDecl(export) => match export.decl {
-                            DefaultDecl::Class(expr) => {
-                                let ident = expr.ident;
-                                let ident =
-                                    ident.unwrap_or_else(|| private_ident!("_default_decl"));
+                    Some(Stmt::Decl(export.decl))
+                }
 
+                ModuleDecl::ExportNamed(NamedExport {
+                    specifiers,
+                    src: None,
+                    ..
+                }) => {
+                    for s in specifiers {
+                        match s {
+                            ExportSpecifier::Namespace(..) => {
+                                // unreachable
+                            }
+                            ExportSpecifier::Default(s) => {
                                 props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(
                                     KeyValueProp {
                                         key: PropName::Ident(Ident::new(
                                             js_word!("default"),
-                                            export.span,
+                                            DUMMY_SP,
                                         )),
-                                        value: Box::new(Expr::Ident(ident.clone())),
+                                        value: Box::new(Expr::Ident(s.exported)),
                                     },
                                 ))));
-
-                                Some(Stmt::Decl(Decl::Class(ClassDecl {
-                                    ident,
-                                    class: expr.class,
-                                    declare: false,
-                                })))
                             }
-                            DefaultDecl::Fn(expr) => {
-                                let ident = expr.ident;
-                                let ident =
-                                    ident.unwrap_or_else(|| private_ident!("_default_decl"));
+                            ExportSpecifier::Named(s) => {
+                                // `export { "a" as b }` is only valid for reexports.
+                                let orig = match s.orig {
+                                    ModuleExportName::Ident(ident) => ident,
+                                    ModuleExportName::Str(s) => bail!(
+                                        "a string literal cannot be exported without `from`: {}",
+                                        s.value
+                                    ),
+                                };
+                                let prop = match s.exported {
+                                    Some(exported) => Prop::KeyValue(KeyValueProp {
+                                        key: prop_name(exported),
+                                        value: Box::new(Expr::Ident(orig)),
+                                    }),
+                                    None => Prop::Shorthand(orig),
+                                };
+                                props.push(PropOrSpread::Prop(Box::new(prop)));
+                            }
+                        }
+                    }
 
-                                props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(
-                                    KeyValueProp {
-                                        key: PropName::Ident(Ident::new(
-                                            js_word!("default"),
-                                            export.span,
-                                        )),
-                                        value: Box::new(Expr::Ident(ident.clone())),
-                                    },
-                                ))));
+                    None
+                }
+
+                ModuleDecl::ExportDefaultDecl(export) => match export.decl {
+                    DefaultDecl::Class(expr) => {
+                        let ident = expr.ident;
+                        let ident = ident.unwrap_or_else(|| private_ident!("_default_decl"));
+
+                        props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                            key: PropName::Ident(Ident::new(js_word!("default"), export.span)),
+                            value: Box::new(Expr::Ident(ident.clone())),
+                        }))));
 
-                                Some(Stmt::Decl(Decl::Fn(FnDecl {
-                                    ident,
-                                    function: expr.function,
-                                    declare: false,
-                                })))
+                        Some(Stmt::Decl(Decl::Class(ClassDecl {
+                            ident,
+                            class: expr.class,
+                            declare: false,
+                        })))
+                    }
+                    DefaultDecl::Fn(expr) => {
+                        let ident = expr.ident;
+                        let ident = ident.unwrap_or_else(|| private_ident!("_default_decl"));
+
+                        props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                            key: PropName::Ident(Ident::new(js_word!("default"), export.span)),
+                            value: Box::new(Expr::Ident(ident.clone())),
+                        }))));
+
+                        Some(Stmt::Decl(Decl::Fn(FnDecl {
+                            ident,
+                            function: expr.function,
+                            declare: false,
+                        })))
+                    }
+                    DefaultDecl::TsInterfaceDecl(_) => None,
+                },
+                ModuleDecl::ExportDefaultExpr(export) => {
+                    let default_var = private_ident!("default");
+                    props.push(PropOrSpread::Prop(Box::new(Prop::Shorthand(
+                        default_var.clone(),
+                    ))));
+                    let var = VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(default_var.into()),
+                        init: Some(export.expr),
+                        definite: false,
+                    };
+                    Some(Stmt::Decl(Decl::Var(VarDecl {
+                        span: DUMMY_SP,
+                        kind: VarDeclKind::Const,
+                        declare: false,
+                        decls: vec![var],
+                    })))
+                }
+            })
+        })
+        .filter_map(Result::transpose)
+        .collect::<Result<_, Error>>()?;
+
+    Ok(SplitModule {
+        span: module.span,
+        shebang: module.shebang,
+        is_async,
+        stmts,
+        props,
+        imports,
+        reexports,
+    })
+}
+
+/// External modules used by a bundle, converted to reads from module objects.
+#[derive(Default)]
+struct Externals {
+    /// Module specifier to the variable which holds the module object.
+    modules: IndexMap<JsWord, Ident, ahash::RandomState>,
+    /// Imported bindings to reads from module objects, which replace
+    /// references to the bindings.
+    imported: AHashMap<Id, Expr>,
+    /// Declarations of objects used to read default exports.
+    stmts: Vec<Stmt>,
+    /// Re-exported values.
+    props: Vec<PropOrSpread>,
+    /// Modules re-exported using `export *`.
+    export_all: Vec<Ident>,
+}
+
+impl Externals {
+    fn new(imports: Vec<ImportDecl>, reexports: Vec<ModuleDecl>) -> Self {
+        let mut externals = Externals::default();
+
+        for import in imports {
+            let module = externals.module_var(&import.src.value);
+
+            for s in import.specifiers {
+                let (local, value) = match s {
+                    ImportSpecifier::Namespace(s) => (s.local, Expr::Ident(module.clone())),
+                    ImportSpecifier::Default(s) => (s.local, externals.default_of(&module)),
+                    ImportSpecifier::Named(s) => {
+                        if s.is_type_only {
+                            continue;
+                        }
+                        let value = match s.imported {
+                            Some(imported) => export_member(module.clone(), imported),
+                            None => module
+                                .clone()
+                                .make_member(quote_ident!(s.local.sym.clone())),
+                        };
+
+                        (s.local, value)
+                    }
+                };
+
+                externals.imported.insert(local.to_id(), value);
+            }
+        }
+
+        for decl in reexports {
+            match decl {
+                ModuleDecl::ExportAll(export) => {
+                    let module = externals.module_var(&export.src.value);
+
+                    if !externals.export_all.contains(&module) {
+                        externals.export_all.push(module);
+                    }
+                }
+                ModuleDecl::ExportNamed(NamedExport {
+                    specifiers,
+                    src: Some(src),
+                    ..
+                }) => {
+                    let module = externals.module_var(&src.value);
+
+                    for s in specifiers {
+                        let (key, value) = match s {
+                            ExportSpecifier::Namespace(s) => {
+                                (prop_name(s.name), Expr::Ident(module.clone()))
+                            }
+                            ExportSpecifier::Default(s) => {
+                                (PropName::Ident(s.exported), externals.default_of(&module))
                             }
-                            DefaultDecl::TsInterfaceDecl(_) => None,
+                            ExportSpecifier::Named(s) => {
+                                if s.is_type_only {
+                                    continue;
+                                }
+                                let exported = s.exported.unwrap_or_else(|| s.orig.clone());
+
+                                (prop_name(exported), export_member(module.clone(), s.orig))
+                            }
+                        };
+
+                        externals.props.push(getter(key, value));
+                    }
+                }
+                _ => unreachable!("not a re-export"),
+            }
+        }
+
+        externals
+    }
+
+    fn module_var(&mut self, src: &JsWord) -> Ident {
+        self.modules
+            .entry(src.clone())
+            .or_insert_with(|| private_ident!(format!("_{}", global_name_of(src))))
+            .clone()
+    }
+
+    /// Returns `_foo_default.default`, after declaring
+    ///
+    /// ```js
+    /// const _foo_default = _foo && _foo.__esModule ? _foo : { default: _foo };
+    /// ```
+    fn default_of(&mut self, module: &Ident) -> Expr {
+        let var = Ident::new(format!("{}_default", module.sym).into(), module.span);
+
+        if !self.stmts.iter().any(|stmt| match stmt {
+            Stmt::Decl(Decl::Var(VarDecl { decls, .. })) => decls.iter().any(
+                |decl| matches!(&decl.name, Pat::Ident(name) if name.id.to_id() == var.to_id()),
+            ),
+            _ => false,
+        }) {
+            self.stmts
+                .push(const_decl(var.clone(), interop_default(module.clone())));
+        }
+
+        var.make_member(quote_ident!("default"))
+    }
+
+    /// Replaces references to imported bindings, so they are live.
+    fn rewrite(
+        &self,
+        mut stmts: Vec<Stmt>,
+        props: Vec<PropOrSpread>,
+    ) -> (Vec<Stmt>, Vec<PropOrSpread>) {
+        let mut v = ImportRewriter {
+            imported: &self.imported,
+        };
+        stmts.visit_mut_with(&mut v);
+
+        let props = props
+            .into_iter()
+            .map(|prop| match prop {
+                // Imported bindings are exported as getters, too.
+                PropOrSpread::Prop(prop) => match *prop {
+                    Prop::Shorthand(i) => match v.get(&i) {
+                        Some(value) => getter(PropName::Ident(quote_ident!(i.span, i.sym)), value),
+                        None => PropOrSpread::Prop(Box::new(Prop::Shorthand(i))),
+                    },
+                    Prop::KeyValue(KeyValueProp { key, value }) => match *value {
+                        Expr::Ident(i) => match v.get(&i) {
+                            Some(value) => getter(key, value),
+                            None => PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                key,
+                                value: Box::new(Expr::Ident(i)),
+                            }))),
                         },
-                        ModuleDecl::ExportDefaultExpr(export) => {
-                            let default_var = private_ident!("default");
-                            props.push(PropOrSpread::Prop(Box::new(Prop::Shorthand(
-                                default_var.clone(),
-                            ))));
-                            let var = VarDeclarator {
-                                span: DUMMY_SP,
-                                name: Pat::Ident(default_var.into()),
-                                init: Some(export.expr),
-                                definite: false,
-                            };
-                            Some(Stmt::Decl(Decl::Var(VarDecl {
-                                span: DUMMY_SP,
-                                kind: VarDeclKind::Const,
-                                declare: false,
-                                decls: vec![var],
+                        mut value => {
+                            value.visit_mut_with(&mut v);
+                            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                key,
+                                value: Box::new(value),
                             })))
                         }
-
-                        ModuleDecl::ExportAll(_) => None,
+                    },
+                    mut prop => {
+                        prop.visit_mut_with(&mut v);
+                        PropOrSpread::Prop(Box::new(prop))
                     }
-                })
-                .collect(),
+                },
+                mut prop => {
+                    prop.visit_mut_with(&mut v);
+                    prop
+                }
+            })
+            .collect();
+
+        (stmts, props)
+    }
+}
+
+/// Replaces references to imported bindings with reads from module objects.
+struct ImportRewriter<'a> {
+    imported: &'a AHashMap<Id, Expr>,
+}
+
+impl ImportRewriter<'_> {
+    fn get(&self, i: &Ident) -> Option<Expr> {
+        self.imported.get(&i.to_id()).cloned()
+    }
+
+    /// `(0, _foo.a)`, so `this` is not the module object if it's called.
+    fn get_unbound(&self, e: &Expr) -> Option<Expr> {
+        let i = match e {
+            Expr::Ident(i) => i,
+            _ => return None,
         };
-        body.stmts.push(Stmt::Return(ReturnStmt {
+
+        self.get(i).map(|v| match v {
+            Expr::Member(..) => Expr::Seq(SeqExpr {
+                span: DUMMY_SP,
+                exprs: vec![Box::new(Expr::Lit(Lit::Num(0.0.into()))), Box::new(v)],
+            }),
+            _ => v,
+        })
+    }
+}
+
+impl VisitMut for ImportRewriter<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_callee(&mut self, n: &mut Callee) {
+        if let Callee::Expr(callee) = n {
+            if let Some(v) = self.get_unbound(callee) {
+                **callee = v;
+                return;
+            }
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_expr(&mut self, n: &mut Expr) {
+        n.visit_mut_children_with(self);
+
+        if let Expr::Ident(i) = n {
+            if let Some(v) = self.get(i) {
+                *n = v;
+            }
+        }
+    }
+
+    fn visit_mut_prop(&mut self, n: &mut Prop) {
+        n.visit_mut_children_with(self);
+
+        if let Prop::Shorthand(i) = n {
+            if let Some(v) = self.get(i) {
+                *n = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(quote_ident!(i.span, i.sym.clone())),
+                    value: Box::new(v),
+                });
+            }
+        }
+    }
+
+    fn visit_mut_tagged_tpl(&mut self, n: &mut TaggedTpl) {
+        if let Some(v) = self.get_unbound(&n.tag) {
+            *n.tag = v;
+        }
+
+        n.visit_mut_children_with(self);
+    }
+}
+
+/// A getter, so the exported binding is live.
+fn getter(key: PropName, value: Expr) -> PropOrSpread {
+    PropOrSpread::Prop(Box::new(Prop::Getter(GetterProp {
+        span: DUMMY_SP,
+        key,
+        type_ann: None,
+        body: Some(BlockStmt {
             span: DUMMY_SP,
-            arg: Some(Box::new(Expr::Object(ObjectLit {
+            stmts: vec![Stmt::Return(ReturnStmt {
                 span: DUMMY_SP,
-                props,
+                arg: Some(Box::new(value)),
+            })],
+        }),
+    })))
+}
+
+/// Creates an object literal for exports, or returns [None] if the module
+/// does not export anything.
+fn exports_obj(mut props: Vec<PropOrSpread>, has_export_all: bool) -> Option<Expr> {
+    if props.is_empty() && !has_export_all {
+        return None;
+    }
+
+    props.insert(
+        0,
+        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+            key: PropName::Ident(quote_ident!("__esModule")),
+            value: Box::new(Expr::Lit(Lit::Bool(Bool {
+                span: DUMMY_SP,
+                value: true,
             }))),
-        }));
+        }))),
+    );
 
-        let f = Function {
-            is_generator: false,
-            is_async,
-            params: Default::default(),
+    Some(Expr::Object(ObjectLit {
+        span: DUMMY_SP,
+        props,
+    }))
+}
+
+/// `module && module.__esModule ? module : { default: module }`
+fn interop_default(module: Ident) -> Expr {
+    Expr::Cond(CondExpr {
+        span: DUMMY_SP,
+        test: Box::new(module.clone().make_bin(
+            op!("&&"),
+            module.clone().make_member(quote_ident!("__esModule")),
+        )),
+        cons: Box::new(Expr::Ident(module.clone())),
+        alt: Box::new(Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(quote_ident!("default")),
+                value: Box::new(Expr::Ident(module)),
+            })))],
+        })),
+    })
+}
+
+/// Copies exports of `module` to `exports`, except `default`, `__esModule`
+/// and names which are already exported.
+///
+/// ```js
+/// Object.keys(module).forEach(function (key) {
+///     if (key === "default" || key === "__esModule" || key in exports) return;
+///     Object.defineProperty(exports, key, {
+///         enumerable: true,
+///         get: function () {
+///             return module[key];
+///         }
+///     });
+/// });
+/// ```
+fn export_all(exports: Expr, module: Ident) -> Stmt {
+    let key = private_ident!("key");
+
+    let skip = Stmt::If(IfStmt {
+        span: DUMMY_SP,
+        test: Box::new(
+            key.clone()
+                .make_eq(Str::from("default"))
+                .make_bin(op!("||"), key.clone().make_eq(Str::from("__esModule")))
+                .make_bin(op!("||"), key.clone().make_bin(op!("in"), exports.clone())),
+        ),
+        cons: Box::new(Stmt::Return(ReturnStmt {
+            span: DUMMY_SP,
+            arg: None,
+        })),
+        alt: None,
+    });
+
+    let getter = Function {
+        params: Default::default(),
+        decorators: Default::default(),
+        span: DUMMY_SP,
+        body: Some(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(Box::new(module.clone().computed_member(key.clone()))),
+            })],
+        }),
+        is_generator: false,
+        is_async: false,
+        type_params: Default::default(),
+        return_type: Default::default(),
+    };
+    let descriptor = ObjectLit {
+        span: DUMMY_SP,
+        props: vec![
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(quote_ident!("enumerable")),
+                value: Box::new(Expr::Lit(Lit::Bool(Bool {
+                    span: DUMMY_SP,
+                    value: true,
+                }))),
+            }))),
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(quote_ident!("get")),
+                value: Box::new(Expr::Fn(FnExpr {
+                    ident: None,
+                    function: getter,
+                })),
+            }))),
+        ],
+    };
+    let define = member_expr!(DUMMY_SP, Object.defineProperty).as_call(
+        DUMMY_SP,
+        vec![exports.as_arg(), key.clone().as_arg(), descriptor.as_arg()],
+    );
+
+    let callback = FnExpr {
+        ident: None,
+        function: Function {
+            params: vec![Param::from(Pat::Ident(key.into()))],
             decorators: Default::default(),
             span: DUMMY_SP,
-            body: Some(body),
+            body: Some(BlockStmt {
+                span: DUMMY_SP,
+                stmts: vec![skip, define.into_stmt()],
+            }),
+            is_generator: false,
+            is_async: false,
             type_params: Default::default(),
             return_type: Default::default(),
-        };
+        },
+    };
 
-        let invoked_fn_expr = FnExpr {
-            ident: None,
-            function: f,
+    member_expr!(DUMMY_SP, Object.keys)
+        .as_call(DUMMY_SP, vec![module.as_arg()])
+        .make_member(quote_ident!("forEach"))
+        .as_call(DUMMY_SP, vec![callback.as_arg()])
+        .into_stmt()
+}
+
+pub(super) fn export_member(module: Ident, name: ModuleExportName) -> Expr {
+    match name {
+        ModuleExportName::Ident(i) => module.make_member(quote_ident!(i.sym)),
+        ModuleExportName::Str(s) => module.computed_member(s),
+    }
+}
+
+pub(super) fn prop_name(name: ModuleExportName) -> PropName {
+    match name {
+        ModuleExportName::Ident(i) => PropName::Ident(quote_ident!(i.sym)),
+        ModuleExportName::Str(s) => PropName::Str(s),
+    }
+}
+
+/// `global.name`, or `global["name"]` if `name` is not a valid identifier.
+fn global_member(global: Ident, name: &str) -> Expr {
+    if Ident::verify_symbol(name).is_ok() {
+        global.make_member(quote_ident!(name))
+    } else {
+        global.computed_member(Str::from(name))
+    }
+}
+
+/// Like [global_member], but `name` may be a path like `a.b`.
+fn global_path(global: Ident, name: &str) -> Expr {
+    let mut parts = name.split('.');
+    let mut expr = global_member(global, parts.next().unwrap_or_default());
+
+    for part in parts {
+        expr = if Ident::verify_symbol(part).is_ok() {
+            expr.make_member(quote_ident!(part))
+        } else {
+            expr.computed_member(Str::from(part))
         };
+    }
 
-        let iife = Box::new(Expr::Call(CallExpr {
-            span: DUMMY_SP,
-            callee: invoked_fn_expr.as_callee(),
-            args: Default::default(),
-            type_args: Default::default(),
-        }));
+    expr
+}
 
-        Module {
-            span: DUMMY_SP,
-            shebang: None,
-            body: vec![ModuleItem::Stmt(Stmt::Expr(ExprStmt {
-                span: DUMMY_SP,
-                expr: iife,
-            }))],
+/// Derives the name of a global variable from a module specifier, like
+/// `react-dom/server` => `reactDomServer`.
+pub(super) fn global_name_of(src: &str) -> String {
+    let mut name = String::with_capacity(src.len());
+    let mut upper = false;
+
+    for c in src.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+            if upper && !name.is_empty() {
+                name.extend(c.to_uppercase());
+            } else {
+                name.push(c);
+            }
+            upper = false;
+        } else {
+            upper = true;
         }
     }
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    name
+}
+
+pub(super) fn const_decl(name: Ident, init: Expr) -> Stmt {
+    Stmt::Decl(Decl::Var(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Const,
+        declare: false,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(name.into()),
+            init: Some(Box::new(init)),
+            definite: false,
+        }],
+    }))
+}
+
+/// `typeof expr === ty`
+fn typeof_eq(expr: Expr, ty: &str) -> Expr {
+    Expr::Unary(UnaryExpr {
+        span: DUMMY_SP,
+        op: op!("typeof"),
+        arg: Box::new(expr),
+    })
+    .make_eq(Str::from(ty))
+}
+
+/// `typeof expr !== "undefined"`
+fn is_defined(expr: Expr) -> Expr {
+    Expr::Unary(UnaryExpr {
+        span: DUMMY_SP,
+        op: op!("typeof"),
+        arg: Box::new(expr),
+    })
+    .make_bin(op!("!=="), Str::from("undefined"))
 }
 
 #[derive(Default)]
-struct TopLevelAwaitFinder {
-    found: bool,
+pub(super) struct TopLevelAwaitFinder {
+    pub found: bool,
 }
 
 impl Visit for TopLevelAwaitFinder {
@@ -437,3 +1278,6 @@ where
         import
     }
 }
+
+#[cfg(test)]
+mod tests;
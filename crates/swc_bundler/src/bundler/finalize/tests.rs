@@ -0,0 +1,273 @@
+use crate::bundler::tests::{suite, Tester};
+use swc_ecma_ast::Module;
+use swc_ecma_transforms_base::resolver::resolver;
+use swc_ecma_visit::FoldWith;
+
+fn assert_printed(t: &Tester, actual: &Module, expected: &str) {
+    let expected = t.parse(expected);
+
+    assert_eq!(t.print(actual), t.print(&expected));
+}
+
+#[test]
+fn cjs_exports() {
+    suite().run(|t| {
+        let m = t.parse(
+            "
+            const a = 1;
+            export const b = a;
+            export function c() {}
+            export { a as default };
+            ",
+        );
+        let m = t.bundler.wrap_with_cjs(m)?;
+
+        assert_printed(
+            t,
+            &m,
+            "
+            const a = 1;
+            const b = a;
+            function c() {}
+            module.exports = {
+                __esModule: true,
+                b,
+                c,
+                default: a
+            };
+            ",
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn cjs_externals() {
+    suite().run(|t| {
+        let m = t.parse(
+            "
+            import fs, { readFile as read } from 'fs';
+            import * as path from 'path';
+            export * from 'events';
+            export { join } from 'path';
+            console.log(fs, read, path);
+            ",
+        );
+        let m = t.bundler.wrap_with_cjs(m)?;
+
+        assert_printed(
+            t,
+            &m,
+            r#"
+            const _fs = require("fs");
+            const _path = require("path");
+            const _events = require("events");
+            const _fs_default = _fs && _fs.__esModule ? _fs : {
+                default: _fs
+            };
+            console.log(_fs_default.default, _fs.readFile, _path);
+            module.exports = {
+                __esModule: true,
+                get join () {
+                    return _path.join;
+                }
+            };
+            Object.keys(_events).forEach(function(key) {
+                if (key === "default" || key === "__esModule" || key in module.exports) return;
+                Object.defineProperty(module.exports, key, {
+                    enumerable: true,
+                    get: function() {
+                        return _events[key];
+                    }
+                });
+            });
+            "#,
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn cjs_top_level_await() {
+    suite().run(|t| {
+        let m = t.parse("await foo();");
+
+        assert!(t.bundler.wrap_with_cjs(m).is_err());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn cjs_string_export_names() {
+    suite().run(|t| {
+        let m = t.parse(
+            r#"
+            const a = 1;
+            export { a as "a-b" };
+            "#,
+        );
+        let m = t.bundler.wrap_with_cjs(m)?;
+
+        assert_printed(
+            t,
+            &m,
+            r#"
+            const a = 1;
+            module.exports = {
+                __esModule: true,
+                "a-b": a
+            };
+            "#,
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn umd() {
+    suite().run(|t| {
+        let m = t.parse(
+            "
+            import React from 'react';
+            import { render } from 'react-dom';
+            export const a = render(React);
+            ",
+        );
+        let m = t.bundler.wrap_with_umd(m, "MyLib", &Default::default())?;
+
+        assert_printed(
+            t,
+            &m,
+            r#"
+            (function(global, factory) {
+                typeof exports === "object" && typeof module !== "undefined"
+                    ? module.exports = factory(require("react"), require("react-dom"))
+                    : typeof define === "function" && define.amd
+                        ? define(["react", "react-dom"], factory)
+                        : (global = typeof globalThis !== "undefined" ? globalThis : global || self,
+                            global.MyLib = factory(global.react, global.reactDom));
+            })(this, function(_react, _reactDom) {
+                const _react_default = _react && _react.__esModule ? _react : {
+                    default: _react
+                };
+                const a = (0, _reactDom.render)(_react_default.default);
+                return {
+                    __esModule: true,
+                    a
+                };
+            });
+            "#,
+        );
+
+        Ok(())
+    });
+}
+
+/// Imported bindings should be live, so they are read from module objects.
+#[test]
+fn cjs_live_bindings() {
+    suite().run(|t| {
+        let m = t.parse(
+            "
+            import { count, inc } from 'counter';
+            import { format } from 'util';
+            inc();
+            console.log({ count }, format`${count}`);
+            function count2() {
+                const count = 2;
+                return count;
+            }
+            export { count, count2 };
+            ",
+        );
+        let m = m.fold_with(&mut resolver());
+        let m = t.bundler.wrap_with_cjs(m)?;
+
+        assert_printed(
+            t,
+            &m,
+            r#"
+            const _counter = require("counter");
+            const _util = require("util");
+            (0, _counter.inc)();
+            console.log({
+                count: _counter.count
+            }, (0, _util.format)`${_counter.count}`);
+            function count2() {
+                const count = 2;
+                return count;
+            }
+            module.exports = {
+                __esModule: true,
+                get count () {
+                    return _counter.count;
+                },
+                count2
+            };
+            "#,
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn umd_globals() {
+    suite().run(|t| {
+        let m = t.parse(
+            "
+            import React from 'react';
+            import { render } from 'react-dom';
+            export * from 'react-dom/server';
+            render(React);
+            ",
+        );
+        let globals = vec![
+            ("react".to_string(), "React".to_string()),
+            ("react-dom".to_string(), "ReactDOM".to_string()),
+            ("react-dom/server".to_string(), "ReactDOM.server".to_string()),
+        ]
+        .into_iter()
+        .collect();
+        let m = t.bundler.wrap_with_umd(m, "MyLib", &globals)?;
+
+        assert_printed(
+            t,
+            &m,
+            r#"
+            (function(global, factory) {
+                typeof exports === "object" && typeof module !== "undefined"
+                    ? module.exports = factory(require("react"), require("react-dom"), require("react-dom/server"))
+                    : typeof define === "function" && define.amd
+                        ? define(["react", "react-dom", "react-dom/server"], factory)
+                        : (global = typeof globalThis !== "undefined" ? globalThis : global || self,
+                            global.MyLib = factory(global.React, global.ReactDOM, global.ReactDOM.server));
+            })(this, function(_react, _reactDom, _reactDomServer) {
+                const _react_default = _react && _react.__esModule ? _react : {
+                    default: _react
+                };
+                (0, _reactDom.render)(_react_default.default);
+                const _exports = {
+                    __esModule: true
+                };
+                Object.keys(_reactDomServer).forEach(function(key) {
+                    if (key === "default" || key === "__esModule" || key in _exports) return;
+                    Object.defineProperty(_exports, key, {
+                        enumerable: true,
+                        get: function() {
+                            return _reactDomServer[key];
+                        }
+                    });
+                });
+                return _exports;
+            });
+            "#,
+        );
+
+        Ok(())
+    });
+}
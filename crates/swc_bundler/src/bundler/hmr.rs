@@ -0,0 +1,874 @@
+use super::{
+    finalize::{const_decl, export_member, global_name_of, prop_name, TopLevelAwaitFinder},
+    load::ClearMark,
+};
+use crate::{Bundle, BundleKind, Bundler, Load, ModuleId, ModuleRecord, Resolve};
+use ahash::{AHashMap, AHashSet};
+use anyhow::{bail, Context, Error};
+use once_cell::sync::Lazy;
+use swc_atoms::{js_word, JsWord};
+use swc_common::{FileName, FilePathMapping, Mark, SourceMap, DUMMY_SP, GLOBALS};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput};
+use swc_ecma_transforms_base::{
+    fixer::fixer,
+    helpers::{inject_helpers, Helpers, HELPERS},
+    hygiene::hygiene,
+    resolver::resolver_with_mark,
+};
+use swc_ecma_utils::{
+    drop_span, find_ids, ident::IdentLike, private_ident, quote_ident, quote_str, ExprFactory,
+};
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, FoldWith, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Configuration for development builds with hot module replacement.
+///
+/// External modules are not part of the registry. They are loaded using the
+/// `require` of the environment, if there's one.
+#[derive(Debug, Default, Clone)]
+pub struct HmrConfig {
+    /// If it's [Some], the runtime connects to this url using `WebSocket` and
+    /// evaluates received messages, which should be scripts created by
+    /// [Bundler::hmr_update].
+    pub websocket: Option<String>,
+}
+
+/// Modules which are sent to the client.
+#[derive(Debug, Default)]
+pub(super) struct HmrState {
+    entries: AHashSet<FileName>,
+    loaded: AHashSet<FileName>,
+}
+
+impl<L, R> Bundler<'_, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    /// Creates a bundle for each entry, which contains the runtime and all
+    /// dependencies of the entry.
+    ///
+    /// Modules are not merged. Instead, each module is wrapped with a function
+    /// and stored in a module registry, so it can be replaced at runtime.
+    pub(super) fn bundle_hmr(
+        &mut self,
+        entries: Vec<(String, FileName)>,
+    ) -> Result<Vec<Bundle>, Error> {
+        let globals = self.globals;
+        GLOBALS.set(globals, || {
+            let mut bundles = vec![];
+
+            for (name, path) in entries {
+                self.hmr.entries.insert(path.clone());
+
+                let helpers = Helpers::new(false);
+                let mut done = AHashSet::default();
+                let mut factories = vec![];
+                self.load_factories(&path, &mut done, &mut factories, &helpers)?;
+                self.hmr.loaded.extend(done);
+
+                let (id, _, _) = self.scope.module_id_gen.gen(&path);
+
+                let call = runtime().as_call(
+                    DUMMY_SP,
+                    vec![
+                        factories_obj(factories).as_arg(),
+                        ArrayLit {
+                            span: DUMMY_SP,
+                            elems: vec![Some(quote_str!(id.to_string()).as_arg())],
+                        }
+                        .as_arg(),
+                        self.hmr_options().as_arg(),
+                    ],
+                );
+
+                bundles.push(Bundle {
+                    kind: BundleKind::Named { name },
+                    id,
+                    module: self.finalize_hmr(call, &helpers),
+                });
+            }
+
+            Ok(bundles)
+        })
+    }
+
+    /// Creates a script which replaces `changed` modules, for bundles created
+    /// in hot module replacement mode.
+    ///
+    /// The script also contains dependencies of `changed` which are not sent
+    /// to the client yet.
+    pub fn hmr_update(&mut self, changed: &[FileName]) -> Result<Module, Error> {
+        if self.config.hmr.is_none() {
+            bail!("Bundler.hmr_update() requires `Config.hmr`")
+        }
+
+        let globals = self.globals;
+        GLOBALS.set(globals, || {
+            let helpers = Helpers::new(false);
+            let mut done = self.hmr.loaded.clone();
+            for file_name in changed {
+                done.remove(file_name);
+            }
+
+            let mut factories = vec![];
+            for file_name in changed {
+                self.load_factories(file_name, &mut done, &mut factories, &helpers)?;
+            }
+            self.hmr.loaded = done;
+
+            let call = quote_ident!("__swcpack_hmr__")
+                .make_member(quote_ident!("apply"))
+                .as_call(DUMMY_SP, vec![factories_obj(factories).as_arg()]);
+
+            Ok(self.finalize_hmr(call, &helpers))
+        })
+    }
+
+    /// Loads `file_name` and dependencies of it which are not in `done`.
+    fn load_factories(
+        &self,
+        file_name: &FileName,
+        done: &mut AHashSet<FileName>,
+        factories: &mut Vec<(ModuleId, Function)>,
+        helpers: &Helpers,
+    ) -> Result<(), Error> {
+        if !done.insert(file_name.clone()) {
+            return Ok(());
+        }
+
+        let (id, _, _) = self.scope.module_id_gen.gen(file_name);
+        let data = self
+            .loader
+            .load(file_name)
+            .with_context(|| format!("Bundler.loader.load({}) failed", file_name))?;
+        helpers.extend_from(&data.helpers);
+
+        let (factory, deps) = self
+            .hmr_factory(file_name, data.module)
+            .with_context(|| format!("failed to create factory for {}", file_name))?;
+        factories.push((id, factory));
+
+        for dep in deps {
+            self.load_factories(&dep, done, factories, helpers)?;
+        }
+
+        Ok(())
+    }
+
+    /// Converts a module to `function (module, exports, require) {}`.
+    ///
+    /// Returns the function and dependencies of the module.
+    pub(super) fn hmr_factory(
+        &self,
+        file_name: &FileName,
+        mut module: Module,
+    ) -> Result<(Function, Vec<FileName>), Error> {
+        let mut finder = TopLevelAwaitFinder::default();
+        module.visit_with(&mut finder);
+        if finder.found {
+            bail!("top level await is not supported in hot module replacement mode")
+        }
+
+        module.visit_mut_with(&mut ClearMark);
+        let module = module.fold_with(&mut resolver_with_mark(Mark::fresh(Mark::root())));
+
+        let mut collector = SpecifierCollector {
+            require: self.config.require,
+            srcs: Default::default(),
+        };
+        module.visit_with(&mut collector);
+
+        let mut ids = AHashMap::default();
+        let mut deps = vec![];
+        for src in collector.srcs {
+            if ids.contains_key(&src) {
+                continue;
+            }
+
+            let id: JsWord = if self.is_external(&src) {
+                src.clone()
+            } else {
+                let path = self.resolve(file_name, &src)?;
+                let (id, _, _) = self.scope.module_id_gen.gen(&path);
+                deps.push((*path).clone());
+                id.to_string().into()
+            };
+            ids.insert(src, id);
+        }
+
+        let mut converter = Converter {
+            ids: &ids,
+            modules: Default::default(),
+            requires: Default::default(),
+            getters: Default::default(),
+            bindings: Default::default(),
+        };
+        let mut stmts = converter.convert(module.body)?;
+
+        let mut prepended = converter.requires;
+        if !converter.getters.is_empty() {
+            prepended.push(
+                quote_ident!("require")
+                    .make_member(quote_ident!("d"))
+                    .as_call(
+                        DUMMY_SP,
+                        vec![
+                            quote_ident!("exports").as_arg(),
+                            ObjectLit {
+                                span: DUMMY_SP,
+                                props: converter
+                                    .getters
+                                    .into_iter()
+                                    .map(|(key, value)| {
+                                        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                            key,
+                                            value: Box::new(getter(value)),
+                                        })))
+                                    })
+                                    .collect(),
+                            }
+                            .as_arg(),
+                        ],
+                    )
+                    .into_stmt(),
+            );
+        }
+        stmts.splice(0..0, prepended);
+
+        let mut rewriter = Rewriter {
+            ids: &ids,
+            bindings: &converter.bindings,
+            import_meta: None,
+        };
+        stmts.visit_mut_with(&mut rewriter);
+
+        if let Some(import_meta) = rewriter.import_meta {
+            let mut props = self
+                .hook
+                .get_import_meta_props(
+                    DUMMY_SP,
+                    &ModuleRecord {
+                        file_name: file_name.clone(),
+                        is_entry: self.hmr.entries.contains(file_name),
+                    },
+                )?
+                .into_iter()
+                .map(|kv| PropOrSpread::Prop(Box::new(Prop::KeyValue(kv))))
+                .collect::<Vec<_>>();
+            props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(quote_ident!("hot")),
+                value: Box::new(module_hot()),
+            }))));
+
+            stmts.insert(
+                0,
+                const_decl(
+                    import_meta,
+                    Expr::Object(ObjectLit {
+                        span: DUMMY_SP,
+                        props,
+                    }),
+                ),
+            );
+        }
+
+        let params = ["module", "exports", "require"]
+            .iter()
+            .map(|name| Param {
+                span: DUMMY_SP,
+                decorators: Default::default(),
+                pat: Pat::Ident(quote_ident!(*name).into()),
+            })
+            .collect();
+
+        Ok((
+            Function {
+                params,
+                decorators: Default::default(),
+                span: DUMMY_SP,
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts,
+                }),
+                is_generator: false,
+                is_async: false,
+                type_params: Default::default(),
+                return_type: Default::default(),
+            },
+            deps,
+        ))
+    }
+
+    fn hmr_options(&self) -> Expr {
+        let websocket = self
+            .config
+            .hmr
+            .as_ref()
+            .and_then(|hmr| hmr.websocket.clone());
+
+        Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: websocket
+                .map(|url| {
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(quote_ident!("websocket")),
+                        value: Box::new(Expr::Lit(Lit::Str(quote_str!(url)))),
+                    })))
+                })
+                .into_iter()
+                .collect(),
+        })
+    }
+
+    /// Injects helpers and wraps `expr` with an iife, so helpers are not
+    /// exposed as globals.
+    fn finalize_hmr(&self, expr: Expr, helpers: &Helpers) -> Module {
+        let module = Module {
+            span: DUMMY_SP,
+            body: vec![ModuleItem::Stmt(expr.into_stmt())],
+            shebang: None,
+        };
+        let module = HELPERS.set(helpers, || module.fold_with(&mut inject_helpers()));
+
+        let stmts = module
+            .body
+            .into_iter()
+            .filter_map(|item| match item {
+                ModuleItem::Stmt(stmt) => Some(stmt),
+                _ => None,
+            })
+            .collect();
+
+        let iife = Expr::Fn(FnExpr {
+            ident: None,
+            function: Function {
+                params: Default::default(),
+                decorators: Default::default(),
+                span: DUMMY_SP,
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts,
+                }),
+                is_generator: false,
+                is_async: false,
+                type_params: Default::default(),
+                return_type: Default::default(),
+            },
+        })
+        .as_iife();
+
+        let mut module = Module {
+            span: DUMMY_SP,
+            body: vec![ModuleItem::Stmt(iife.into_stmt())],
+            shebang: None,
+        };
+
+        if !self.config.disable_hygiene {
+            module = module.fold_with(&mut hygiene());
+        }
+
+        if !self.config.disable_fixer {
+            module = module.fold_with(&mut fixer(None));
+        }
+
+        module
+    }
+}
+
+/// The function expression in `_runtime.js`.
+fn runtime() -> Expr {
+    static RUNTIME: Lazy<Expr> = Lazy::new(|| {
+        let cm = SourceMap::new(FilePathMapping::empty());
+        let fm = cm.new_source_file(
+            FileName::Custom("hmr-runtime".into()),
+            include_str!("hmr/_runtime.js").into(),
+        );
+        let lexer = Lexer::new(
+            Default::default(),
+            Default::default(),
+            StringInput::from(&*fm),
+            None,
+        );
+        let module = Parser::new_from(lexer)
+            .parse_module()
+            .map(drop_span)
+            .expect("failed to parse hmr runtime");
+
+        match module.body.into_iter().next() {
+            Some(ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. }))) => *expr,
+            _ => unreachable!("hmr runtime should be an expression"),
+        }
+    });
+
+    RUNTIME.clone()
+}
+
+/// `{ "id": function (module, exports, require) {} }`
+fn factories_obj(factories: Vec<(ModuleId, Function)>) -> Expr {
+    Expr::Object(ObjectLit {
+        span: DUMMY_SP,
+        props: factories
+            .into_iter()
+            .map(|(id, function)| {
+                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: PropName::Str(quote_str!(id.to_string())),
+                    value: Box::new(Expr::Fn(FnExpr {
+                        ident: None,
+                        function,
+                    })),
+                })))
+            })
+            .collect(),
+    })
+}
+
+/// `function () { return value; }`
+fn getter(value: Expr) -> Expr {
+    Expr::Fn(FnExpr {
+        ident: None,
+        function: Function {
+            params: Default::default(),
+            decorators: Default::default(),
+            span: DUMMY_SP,
+            body: Some(BlockStmt {
+                span: DUMMY_SP,
+                stmts: vec![Stmt::Return(ReturnStmt {
+                    span: DUMMY_SP,
+                    arg: Some(Box::new(value)),
+                })],
+            }),
+            is_generator: false,
+            is_async: false,
+            type_params: Default::default(),
+            return_type: Default::default(),
+        },
+    })
+}
+
+/// `module.hot`
+fn module_hot() -> Expr {
+    quote_ident!("module").make_member(quote_ident!("hot"))
+}
+
+/// `require("id")`
+fn require_call(id: &JsWord) -> Expr {
+    quote_ident!("require").as_call(DUMMY_SP, vec![quote_str!(id.clone()).as_arg()])
+}
+
+fn is_hot(e: &Expr) -> bool {
+    match e {
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) if &*prop.sym == "hot" => match &**obj {
+            Expr::Ident(obj) => &*obj.sym == "module",
+            Expr::MetaProp(MetaPropExpr {
+                kind: MetaPropKind::ImportMeta,
+                ..
+            }) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns true for `module.hot.accept(deps)`.
+fn is_hot_accept(call: &CallExpr) -> bool {
+    match &call.callee {
+        Callee::Expr(callee) => match &**callee {
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => &*prop.sym == "accept" && is_hot(obj),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns the string literals in `"./dep"` or `["./dep"]`.
+fn accepted_deps(deps: &mut Expr) -> Vec<&mut Str> {
+    match deps {
+        Expr::Lit(Lit::Str(s)) => vec![s],
+        Expr::Array(arr) => arr
+            .elems
+            .iter_mut()
+            .flatten()
+            .filter_map(|elem| match &mut *elem.expr {
+                Expr::Lit(Lit::Str(s)) => Some(s),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Returns the source of `import('src')` and `require('src')`.
+fn import_src(call: &CallExpr, require: bool) -> Option<&JsWord> {
+    if call.args.len() != 1 {
+        return None;
+    }
+
+    let is_import = match &call.callee {
+        Callee::Import(..) => true,
+        Callee::Expr(callee) => {
+            require
+                && matches!(
+                    &**callee,
+                    Expr::Ident(Ident {
+                        sym: js_word!("require"),
+                        ..
+                    })
+                )
+        }
+        _ => false,
+    };
+    if !is_import {
+        return None;
+    }
+
+    match &*call.args[0].expr {
+        Expr::Lit(Lit::Str(s)) => Some(&s.value),
+        _ => None,
+    }
+}
+
+/// Collects module specifiers which should be resolved.
+struct SpecifierCollector {
+    require: bool,
+    srcs: Vec<JsWord>,
+}
+
+impl Visit for SpecifierCollector {
+    noop_visit_type!();
+
+    fn visit_module_decl(&mut self, decl: &ModuleDecl) {
+        decl.visit_children_with(self);
+
+        match decl {
+            ModuleDecl::Import(ImportDecl { src, .. })
+            | ModuleDecl::ExportAll(ExportAll { src, .. })
+            | ModuleDecl::ExportNamed(NamedExport { src: Some(src), .. }) => {
+                self.srcs.push(src.value.clone())
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        call.visit_children_with(self);
+
+        if let Some(src) = import_src(call, self.require) {
+            self.srcs.push(src.clone());
+        }
+
+        if is_hot_accept(call) {
+            if let Some(deps) = call.args.first() {
+                let mut deps = (*deps.expr).clone();
+                for s in accepted_deps(&mut deps) {
+                    self.srcs.push(s.value.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Converts module declarations to statements.
+struct Converter<'a> {
+    /// Module specifier to module id.
+    ids: &'a AHashMap<JsWord, JsWord>,
+    /// Module specifier to the variable holding exports of the module.
+    modules: AHashMap<JsWord, Ident>,
+    /// Calls to `require`, which should be placed at the start of the module.
+    requires: Vec<Stmt>,
+    getters: Vec<(PropName, Expr)>,
+    /// Imported bindings.
+    bindings: AHashMap<Id, Expr>,
+}
+
+impl Converter<'_> {
+    /// Returns the variable holding exports of `src`.
+    fn require(&mut self, src: &JsWord) -> Ident {
+        if let Some(module) = self.modules.get(src) {
+            return module.clone();
+        }
+
+        let module = private_ident!(global_name_of(src));
+        let init = quote_ident!("require")
+            .make_member(quote_ident!("i"))
+            .as_call(DUMMY_SP, vec![require_call(&self.ids[src]).as_arg()]);
+        self.requires.push(const_decl(module.clone(), init));
+        self.modules.insert(src.clone(), module.clone());
+
+        module
+    }
+
+    fn export(&mut self, name: ModuleExportName, value: Expr) {
+        self.getters.push((prop_name(name), value));
+    }
+
+    fn convert(&mut self, items: Vec<ModuleItem>) -> Result<Vec<Stmt>, Error> {
+        let mut stmts = vec![];
+
+        for item in items {
+            let decl = match item {
+                ModuleItem::Stmt(stmt) => {
+                    stmts.push(stmt);
+                    continue;
+                }
+                ModuleItem::ModuleDecl(decl) => decl,
+            };
+
+            match decl {
+                ModuleDecl::Import(import) => {
+                    if import.specifiers.is_empty() {
+                        self.requires
+                            .push(require_call(&self.ids[&import.src.value]).into_stmt());
+                        continue;
+                    }
+
+                    let module = self.require(&import.src.value);
+                    for specifier in import.specifiers {
+                        match specifier {
+                            ImportSpecifier::Named(ImportNamedSpecifier {
+                                local,
+                                imported,
+                                ..
+                            }) => {
+                                let imported = imported
+                                    .unwrap_or_else(|| ModuleExportName::Ident(local.clone()));
+                                self.bindings
+                                    .insert(local.to_id(), export_member(module.clone(), imported));
+                            }
+                            ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                                self.bindings.insert(
+                                    local.to_id(),
+                                    module.clone().make_member(quote_ident!("default")),
+                                );
+                            }
+                            ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                                self.bindings
+                                    .insert(local.to_id(), Expr::Ident(module.clone()));
+                            }
+                        }
+                    }
+                }
+
+                ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => {
+                    let ids: Vec<Ident> = match &decl {
+                        Decl::Class(ClassDecl { ident, .. }) | Decl::Fn(FnDecl { ident, .. }) => {
+                            vec![ident.clone()]
+                        }
+                        Decl::Var(var) => find_ids(&var.decls),
+                        _ => vec![],
+                    };
+                    for id in ids {
+                        self.export(
+                            ModuleExportName::Ident(quote_ident!(id.sym.clone())),
+                            Expr::Ident(id),
+                        );
+                    }
+                    stmts.push(Stmt::Decl(decl));
+                }
+
+                ModuleDecl::ExportNamed(NamedExport {
+                    specifiers, src, ..
+                }) => {
+                    let module = src.map(|src| self.require(&src.value));
+
+                    for specifier in specifiers {
+                        match specifier {
+                            ExportSpecifier::Named(ExportNamedSpecifier {
+                                orig, exported, ..
+                            }) => {
+                                let exported = exported.unwrap_or_else(|| orig.clone());
+                                let value = match (&module, orig) {
+                                    (Some(module), orig) => export_member(module.clone(), orig),
+                                    (None, ModuleExportName::Ident(orig)) => Expr::Ident(orig),
+                                    (None, ModuleExportName::Str(..)) => {
+                                        bail!("exporting a string without `from` is invalid")
+                                    }
+                                };
+                                self.export(exported, value);
+                            }
+                            ExportSpecifier::Namespace(ExportNamespaceSpecifier {
+                                name, ..
+                            }) => {
+                                if let Some(module) = &module {
+                                    self.export(name, Expr::Ident(module.clone()));
+                                }
+                            }
+                            ExportSpecifier::Default(ExportDefaultSpecifier { exported }) => {
+                                if let Some(module) = &module {
+                                    self.export(
+                                        ModuleExportName::Ident(exported),
+                                        module.clone().make_member(quote_ident!("default")),
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+
+                ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { decl, .. }) => {
+                    let decl = match decl {
+                        DefaultDecl::Class(ClassExpr { ident, class }) => {
+                            let ident = ident.unwrap_or_else(|| private_ident!("_default"));
+                            Decl::Class(ClassDecl {
+                                ident,
+                                declare: false,
+                                class,
+                            })
+                        }
+                        DefaultDecl::Fn(FnExpr { ident, function }) => {
+                            let ident = ident.unwrap_or_else(|| private_ident!("_default"));
+                            Decl::Fn(FnDecl {
+                                ident,
+                                declare: false,
+                                function,
+                            })
+                        }
+                        DefaultDecl::TsInterfaceDecl(..) => continue,
+                    };
+                    let ident = match &decl {
+                        Decl::Class(ClassDecl { ident, .. }) | Decl::Fn(FnDecl { ident, .. }) => {
+                            ident.clone()
+                        }
+                        _ => unreachable!(),
+                    };
+
+                    self.export(
+                        ModuleExportName::Ident(quote_ident!("default")),
+                        Expr::Ident(ident),
+                    );
+                    stmts.push(Stmt::Decl(decl));
+                }
+
+                ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. }) => {
+                    let ident = private_ident!("_default");
+                    self.export(
+                        ModuleExportName::Ident(quote_ident!("default")),
+                        Expr::Ident(ident.clone()),
+                    );
+                    stmts.push(const_decl(ident, *expr));
+                }
+
+                ModuleDecl::ExportAll(ExportAll { src, .. }) => {
+                    let module = self.require(&src.value);
+                    self.requires.push(
+                        quote_ident!("require")
+                            .make_member(quote_ident!("e"))
+                            .as_call(
+                                DUMMY_SP,
+                                vec![quote_ident!("exports").as_arg(), module.as_arg()],
+                            )
+                            .into_stmt(),
+                    );
+                }
+
+                _ => bail!(
+                    "typescript module syntax is not supported in hot module replacement mode"
+                ),
+            }
+        }
+
+        Ok(stmts)
+    }
+}
+
+/// Replaces usages of imported bindings, module specifiers and `import.meta`.
+struct Rewriter<'a> {
+    ids: &'a AHashMap<JsWord, JsWord>,
+    bindings: &'a AHashMap<Id, Expr>,
+    /// Variable used to replace `import.meta`.
+    import_meta: Option<Ident>,
+}
+
+impl VisitMut for Rewriter<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
+        if is_hot_accept(call) {
+            if let Some(deps) = call.args.first_mut() {
+                for s in accepted_deps(&mut deps.expr) {
+                    if let Some(id) = self.ids.get(&s.value) {
+                        *s = quote_str!(id.clone());
+                    }
+                }
+            }
+        }
+
+        call.visit_mut_children_with(self);
+
+        if let Some(src) = import_src(call, true) {
+            if let Some(id) = self.ids.get(src) {
+                call.args[0] = quote_str!(id.clone()).as_arg();
+            }
+        }
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        if is_hot(e) {
+            *e = module_hot();
+            return;
+        }
+
+        e.visit_mut_children_with(self);
+
+        match e {
+            Expr::Ident(i) => {
+                if let Some(value) = self.bindings.get(&i.to_id()) {
+                    *e = value.clone();
+                }
+            }
+
+            Expr::MetaProp(MetaPropExpr {
+                kind: MetaPropKind::ImportMeta,
+                ..
+            }) => {
+                let import_meta = self
+                    .import_meta
+                    .get_or_insert_with(|| private_ident!("importMeta"));
+                *e = Expr::Ident(import_meta.clone());
+            }
+
+            // `import('src')` => `Promise.resolve().then(() => require('id'))`
+            Expr::Call(CallExpr {
+                callee: Callee::Import(..),
+                args,
+                ..
+            }) if args.len() == 1 && matches!(&*args[0].expr, Expr::Lit(Lit::Str(..))) => {
+                let required = quote_ident!("require").as_call(DUMMY_SP, args.clone());
+
+                *e = quote_ident!("Promise")
+                    .make_member(quote_ident!("resolve"))
+                    .as_call(DUMMY_SP, Default::default())
+                    .make_member(quote_ident!("then"))
+                    .as_call(DUMMY_SP, vec![getter(required).as_arg()]);
+            }
+
+            _ => {}
+        }
+    }
+
+    fn visit_mut_prop(&mut self, p: &mut Prop) {
+        p.visit_mut_children_with(self);
+
+        if let Prop::Shorthand(i) = p {
+            if let Some(value) = self.bindings.get(&i.to_id()) {
+                *p = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(quote_ident!(i.sym.clone())),
+                    value: Box::new(value.clone()),
+                });
+            }
+        }
+    }
+}
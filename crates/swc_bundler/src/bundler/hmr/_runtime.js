@@ -0,0 +1,252 @@
+(function (factories, entries, options) {
+    var root = typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this;
+
+    function createRuntime() {
+        var registry = {};
+        var cache = {};
+        // Exports objects are reused while replacing modules, so bindings imported by
+        // other modules are updated.
+        var exportsById = {};
+        var dataById = {};
+        var parentsById = {};
+        var externalRequire = typeof require === "function" ? require : undefined;
+
+        function reload() {
+            if (root.location) {
+                root.location.reload();
+            }
+        }
+
+        function createHot(id) {
+            var hot = {
+                data: dataById[id],
+                _selfAccepted: false,
+                _declined: false,
+                _acceptedDependencies: {},
+                _disposeHandlers: [],
+
+                accept: function (deps, callback) {
+                    if (deps === undefined || typeof deps === "function") {
+                        hot._selfAccepted = deps || true;
+                        return;
+                    }
+
+                    if (!Array.isArray(deps)) {
+                        deps = [deps];
+                    }
+                    for (var i = 0; i < deps.length; i++) {
+                        hot._acceptedDependencies[deps[i]] = callback || function () { };
+                    }
+                },
+
+                decline: function () {
+                    hot._declined = true;
+                },
+
+                dispose: function (callback) {
+                    hot._disposeHandlers.push(callback);
+                },
+
+                invalidate: reload,
+            };
+
+            return hot;
+        }
+
+        function load(id) {
+            var module = cache[id];
+            if (module) {
+                return module.exports;
+            }
+
+            var factory = registry[id];
+            if (!factory) {
+                if (externalRequire) {
+                    return externalRequire(id);
+                }
+                throw new Error("Cannot find module '" + id + "'");
+            }
+
+            var exports = exportsById[id] || (exportsById[id] = {});
+            Object.keys(exports).forEach(function (key) {
+                delete exports[key];
+            });
+            delete exports.__esModule;
+
+            module = cache[id] = {
+                id: id,
+                exports: exports,
+                hot: createHot(id),
+            };
+            if (!parentsById[id]) {
+                parentsById[id] = [];
+            }
+
+            try {
+                factory.call(exports, module, exports, createRequire(id));
+            } catch (err) {
+                delete cache[id];
+                throw err;
+            }
+
+            return module.exports;
+        }
+
+        function createRequire(parentId) {
+            function require(id) {
+                var exports = load(id);
+                var parents = parentsById[id];
+                if (parents && parents.indexOf(parentId) === -1) {
+                    parents.push(parentId);
+                }
+                return exports;
+            }
+
+            // Defines exports of an es module using getters.
+            require.d = function (exports, getters) {
+                Object.defineProperty(exports, "__esModule", { value: true, configurable: true });
+                Object.keys(getters).forEach(function (key) {
+                    Object.defineProperty(exports, key, {
+                        enumerable: true,
+                        configurable: true,
+                        get: getters[key],
+                    });
+                });
+            };
+
+            // `export * from 'mod'`
+            require.e = function (exports, mod) {
+                Object.keys(mod).forEach(function (key) {
+                    if (key === "default" || key === "__esModule" || Object.prototype.hasOwnProperty.call(exports, key)) {
+                        return;
+                    }
+                    Object.defineProperty(exports, key, {
+                        enumerable: true,
+                        configurable: true,
+                        get: function () {
+                            return mod[key];
+                        },
+                    });
+                });
+            };
+
+            // Interop for commonjs modules.
+            require.i = function (mod) {
+                if (mod && mod.__esModule) {
+                    return mod;
+                }
+
+                var ns = {};
+                if (mod != null) {
+                    Object.keys(mod).forEach(function (key) {
+                        ns[key] = mod[key];
+                    });
+                }
+                ns.default = mod;
+                return ns;
+            };
+
+            return require;
+        }
+
+        function register(factories) {
+            Object.keys(factories).forEach(function (id) {
+                registry[id] = factories[id];
+            });
+        }
+
+        function apply(updates) {
+            var queue = Object.keys(updates);
+            var outdated = [];
+            var selfAccepted = [];
+            var accepted = [];
+
+            while (queue.length) {
+                var id = queue.shift();
+                if (outdated.indexOf(id) !== -1) {
+                    continue;
+                }
+
+                var module = cache[id];
+                // Not executed yet.
+                if (!module) {
+                    continue;
+                }
+                outdated.push(id);
+
+                if (module.hot._declined) {
+                    return reload();
+                }
+                if (module.hot._selfAccepted) {
+                    selfAccepted.push({ id: id, handler: module.hot._selfAccepted });
+                    continue;
+                }
+
+                var parents = parentsById[id] || [];
+                if (!parents.length) {
+                    return reload();
+                }
+                for (var i = 0; i < parents.length; i++) {
+                    var parent = cache[parents[i]];
+                    if (!parent) {
+                        continue;
+                    }
+
+                    var callback = parent.hot._acceptedDependencies[id];
+                    if (callback) {
+                        accepted.push({ id: id, callback: callback });
+                    } else {
+                        queue.push(parents[i]);
+                    }
+                }
+            }
+
+            register(updates);
+
+            outdated.forEach(function (id) {
+                var data = {};
+                cache[id].hot._disposeHandlers.forEach(function (handler) {
+                    handler(data);
+                });
+                dataById[id] = data;
+                delete cache[id];
+            });
+
+            selfAccepted.forEach(function (item) {
+                try {
+                    load(item.id);
+                } catch (err) {
+                    if (typeof item.handler !== "function") {
+                        throw err;
+                    }
+                    item.handler(err);
+                }
+            });
+
+            accepted.forEach(function (item) {
+                load(item.id);
+                item.callback([item.id]);
+            });
+        }
+
+        if (options.websocket && typeof WebSocket !== "undefined") {
+            var socket = new WebSocket(options.websocket);
+            socket.onmessage = function (event) {
+                (0, eval)(event.data);
+            };
+        }
+
+        return {
+            register: register,
+            require: load,
+            apply: apply,
+        };
+    }
+
+    var runtime = root.__swcpack_hmr__ || (root.__swcpack_hmr__ = createRuntime());
+
+    runtime.register(factories);
+    entries.forEach(function (id) {
+        runtime.require(id);
+    });
+});
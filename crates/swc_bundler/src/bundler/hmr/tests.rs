@@ -0,0 +1,138 @@
+use super::HmrConfig;
+use crate::bundler::tests::{suite, Tester};
+use std::collections::HashMap;
+use swc_common::{FileName, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::ExprFactory;
+
+fn id(t: &Tester, name: &str) -> String {
+    let (id, _, _) = t
+        .bundler
+        .scope
+        .module_id_gen
+        .gen(&FileName::Real(name.into()));
+
+    id.to_string()
+}
+
+#[test]
+fn factory() {
+    suite()
+        .file(
+            "a.js",
+            "
+            import b, { c as d } from './b';
+            export const e = b + d;
+            export default function () {}
+            module.hot.accept('./b', function () {});
+            ",
+        )
+        .file("b.js", "export const c = 1; export default 2;")
+        .run(|t| {
+            let module = t.parse(
+                "
+                import b, { c as d } from './b';
+                export const e = b + d;
+                export default function () {}
+                module.hot.accept('./b', function () {});
+                ",
+            );
+            let (factory, deps) = t
+                .bundler
+                .hmr_factory(&FileName::Real("a.js".into()), module)?;
+            assert_eq!(deps, vec![FileName::Real("b.js".into())]);
+
+            let actual = Module {
+                span: DUMMY_SP,
+                body: vec![ModuleItem::Stmt(
+                    Expr::Fn(FnExpr {
+                        ident: None,
+                        function: factory,
+                    })
+                    .into_stmt(),
+                )],
+                shebang: None,
+            };
+            let expected = t.parse(&format!(
+                r#"
+                (function (module, exports, require) {{
+                    const b = require.i(require("{b}"));
+                    require.d(exports, {{
+                        e: function () {{ return e; }},
+                        default: function () {{ return _default; }}
+                    }});
+                    const e = b.default + b.c;
+                    function _default() {{}}
+                    module.hot.accept("{b}", function () {{}});
+                }});
+                "#,
+                b = id(t, "b.js")
+            ));
+
+            assert_eq!(t.print(&actual), t.print(&expected));
+
+            Ok(())
+        });
+}
+
+#[test]
+fn bundle_and_update() {
+    suite()
+        .file("a.js", "import { c } from './b'; console.log(c);")
+        .file("b.js", "export const c = 1;")
+        .run(|t| {
+            t.bundler.config.hmr = Some(HmrConfig {
+                websocket: Some("ws://localhost:8080".into()),
+            });
+
+            let mut entries = HashMap::default();
+            entries.insert("a.js".to_string(), FileName::Real("a.js".into()));
+
+            let bundles = t.bundler.bundle(entries)?;
+            assert_eq!(bundles.len(), 1);
+
+            let code = t.print(&bundles[0].module);
+            assert!(code.contains("__swcpack_hmr__"));
+            assert!(code.contains("ws://localhost:8080"));
+            assert!(code.contains(&format!("\"{}\": function", id(t, "a.js"))));
+            assert!(code.contains(&format!("\"{}\": function", id(t, "b.js"))));
+
+            let update = t.bundler.hmr_update(&[FileName::Real("b.js".into())])?;
+            let code = t.print(&update);
+            assert!(code.contains("__swcpack_hmr__.apply"));
+            assert!(!code.contains(&format!("\"{}\": function", id(t, "a.js"))));
+            assert!(code.contains(&format!("\"{}\": function", id(t, "b.js"))));
+
+            Ok(())
+        });
+}
+
+#[test]
+fn external_module() {
+    suite().file("b.js", "export const c = 1;").run(|t| {
+        t.bundler.config.external_modules = vec!["react".into()];
+
+        let module = t.parse("import React from 'react'; import { c } from './b';");
+        let (factory, deps) = t
+            .bundler
+            .hmr_factory(&FileName::Real("a.js".into()), module)?;
+        assert_eq!(deps, vec![FileName::Real("b.js".into())]);
+
+        let actual = Module {
+            span: DUMMY_SP,
+            body: vec![ModuleItem::Stmt(
+                Expr::Fn(FnExpr {
+                    ident: None,
+                    function: factory,
+                })
+                .into_stmt(),
+            )],
+            shebang: None,
+        };
+        let code = t.print(&actual);
+        assert!(code.contains("require(\"react\")"));
+        assert!(code.contains(&format!("require(\"{}\")", id(t, "b.js"))));
+
+        Ok(())
+    });
+}
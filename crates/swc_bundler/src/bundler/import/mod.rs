@@ -54,6 +54,14 @@ where
         module_specifier: &str,
     ) -> Result<Lrc<FileName>, Error> {
         self.run(|| {
+            if let Some(path) = self
+                .split
+                .as_ref()
+                .and_then(|split| split.resolve(base, module_specifier))
+            {
+                return Ok(Lrc::new(path));
+            }
+
             let path = self
                 .resolver
                 .resolve(base, module_specifier)
@@ -552,11 +560,7 @@ where
             let mut wrapping_required = vec![];
             for import in self.info.imports.iter_mut() {
                 let use_ns = self.info.forced_ns.contains(&import.src.value)
-                    || self
-                        .bundler
-                        .config
-                        .external_modules
-                        .contains(&import.src.value);
+                    || self.bundler.is_external(&import.src.value);
 
                 if use_ns {
                     wrapping_required.push(import.src.value.clone());
@@ -628,7 +632,7 @@ where
                         _ => return,
                     };
                     // Ignore core modules.
-                    if self.bundler.config.external_modules.contains(&src.value) {
+                    if self.bundler.is_external(&src.value) {
                         return;
                     }
 
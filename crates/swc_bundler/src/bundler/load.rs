@@ -115,10 +115,13 @@ where
         self.run(|| {
             let (module_id, _, _) = self.scope.module_id_gen.gen(file_name);
 
-            let data = self
-                .loader
-                .load(file_name)
-                .with_context(|| format!("Bundler.loader.load({}) failed", file_name))?;
+            let data = match self.load_chunk_entry(file_name) {
+                Some(data) => data,
+                None => self
+                    .loader
+                    .load(file_name)
+                    .with_context(|| format!("Bundler.loader.load({}) failed", file_name))?,
+            };
             self.scope.mark_as_loaded(module_id);
             Ok((module_id, data))
         })
@@ -134,6 +137,9 @@ where
             tracing::trace!("transform_module({})", data.fm.name);
             let (id, local_mark, export_mark) = self.scope.module_id_gen.gen(file_name);
 
+            self.import_shared_chunks(file_name, &mut data.module)
+                .context("failed to import shared chunks")?;
+
             data.module.visit_mut_with(&mut ClearMark);
 
             let mut module = data.module.fold_with(&mut resolver_with_mark(local_mark));
@@ -230,6 +236,11 @@ where
             let items = raw
                 .items
                 .into_par_iter()
+                // Re-exports of external modules are kept as-is.
+                .filter(|(src, _)| {
+                    src.as_ref()
+                        .map_or(true, |src| !self.is_external(&src.value))
+                })
                 .map(|(src, ss)| -> Result<_, Error> {
                     self.run(|| {
                         let info = match src {
@@ -485,7 +496,7 @@ impl Visit for Es6ModuleDetector {
 }
 
 #[derive(Clone, Copy)]
-struct ClearMark;
+pub(super) struct ClearMark;
 impl VisitMut for ClearMark {
     noop_visit_mut_type!();
 
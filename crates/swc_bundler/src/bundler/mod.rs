@@ -1,7 +1,8 @@
-use self::scope::Scope;
+pub use self::{hmr::HmrConfig, split::ChunkConfig};
+use self::{hmr::HmrState, scope::Scope, split::SplitPlan};
 use crate::{Hook, Load, ModuleId, Resolve};
-use anyhow::{Context, Error};
-use std::collections::HashMap;
+use anyhow::{bail, Context, Error};
+use std::collections::{BTreeMap, HashMap};
 use swc_atoms::JsWord;
 use swc_common::{
     collections::AHashMap, sync::Lrc, FileName, Globals, Mark, SourceMap, SyntaxContext, GLOBALS,
@@ -12,11 +13,13 @@ mod chunk;
 mod export;
 mod finalize;
 mod helpers;
+mod hmr;
 mod import;
 mod keywords;
 mod load;
 mod optimize;
 mod scope;
+mod split;
 #[cfg(test)]
 pub(crate) mod tests;
 
@@ -44,12 +47,37 @@ pub struct Config {
 
     /// Type of emitted module
     pub module: ModuleType,
+
+    /// If it's [Some], modules shared by entries are moved to separate chunks.
+    ///
+    /// This can't be used with [ModuleType::Iife], which can't import other
+    /// chunks, or with `hmr`.
+    pub chunks: Option<ChunkConfig>,
+
+    /// If it's [Some], modules are not merged and bundles are built for hot
+    /// module replacement. See [Bundler::hmr_update].
+    pub hmr: Option<HmrConfig>,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub enum ModuleType {
     Es,
     Iife,
+    /// Exports are assigned to `module.exports` and external modules are
+    /// loaded using `require`.
+    Cjs,
+    /// Works as a commonjs module, an amd module or a global variable.
+    Umd {
+        /// Name of the global variable used if neither commonjs nor amd is
+        /// available.
+        name: String,
+        /// Names of global variables for external modules, by module
+        /// specifier.
+        ///
+        /// If an external module is not in this map, the name is derived from
+        /// the specifier, like `react-dom` => `reactDom`.
+        globals: BTreeMap<String, String>,
+    },
 }
 
 impl Default for ModuleType {
@@ -101,6 +129,11 @@ where
 
     scope: Scope,
 
+    /// Modules moved to shared chunks.
+    split: Option<SplitPlan>,
+
+    hmr: HmrState,
+
     hook: Box<dyn 'a + Hook>,
 }
 
@@ -135,12 +168,18 @@ where
                 synthesized_ctxt,
                 injected_ctxt,
                 scope: Default::default(),
+                split: None,
+                hmr: Default::default(),
                 hook,
             }
         })
     }
     pub(crate) fn is_external(&self, src: &JsWord) -> bool {
-        return self.config.external_modules.iter().any(|v| v == src);
+        self.config.external_modules.iter().any(|v| v == src)
+            || self
+                .split
+                .as_ref()
+                .map_or(false, |split| split.is_chunk_specifier(src))
     }
     ///
     ///
@@ -149,7 +188,7 @@ where
     /// circular manner. However, it applies only to the provided `entries`, and
     /// dependencies with circular reference is ok.
     pub fn bundle(&mut self, entries: HashMap<String, FileName>) -> Result<Vec<Bundle>, Error> {
-        let results = entries
+        let mut entries = entries
             .into_iter()
             .map(|(name, path)| -> Result<_, Error> {
                 let path = match path {
@@ -166,6 +205,35 @@ where
                     _ => path,
                 };
 
+                Ok((name, path))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if self.config.chunks.is_some() {
+            if self.config.hmr.is_some() {
+                bail!("`Config.chunks` cannot be used with `Config.hmr`")
+            }
+            if self.config.module == ModuleType::Iife {
+                bail!("`Config.chunks` cannot be used while bundling as an iife")
+            }
+        }
+
+        if self.config.hmr.is_some() {
+            return self.bundle_hmr(entries);
+        }
+
+        self.split = self.plan_split(&entries)?;
+        if let Some(split) = &self.split {
+            // Modules are loaded again because imports of shared chunks should be
+            // rewritten before analyzing modules.
+            self.scope = Default::default();
+
+            entries.extend(split.entries());
+        }
+
+        let results = entries
+            .into_iter()
+            .map(|(name, path)| -> Result<_, Error> {
                 let res = self
                     .load_transformed(&path)
                     .context("load_transformed failed")?;
@@ -0,0 +1,714 @@
+use super::Bundler;
+use crate::{load::ModuleData, BundleKind, Load, ModuleId, ModuleRecord, Resolve};
+use anyhow::{bail, Context, Error};
+use petgraph::EdgeDirection::Outgoing;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+use swc_atoms::{js_word, JsWord};
+use swc_common::{
+    collections::{AHashMap, AHashSet},
+    FileName, DUMMY_SP,
+};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{quote_ident, quote_str};
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+#[cfg(test)]
+mod tests;
+
+/// Configures how modules shared by entries are split into separate chunks.
+///
+/// Modules can be assigned to a chunk manually by implementing
+/// [Hook::get_chunk_name](crate::Hook::get_chunk_name). Other modules are
+/// grouped by the set of entries importing them.
+#[derive(Debug, Clone)]
+pub struct ChunkConfig {
+    /// A module imported by at least this number of entries is moved to a
+    /// chunk shared by those entries.
+    ///
+    /// Values less than `2` disable automatic extraction of shared chunks.
+    pub min_shared: usize,
+
+    /// An automatically extracted chunk is dropped (and its modules are
+    /// merged into each entry) if its total source size is less than this
+    /// value, in bytes.
+    pub min_size: usize,
+}
+
+impl Default for ChunkConfig {
+    fn default() -> Self {
+        ChunkConfig {
+            min_shared: 2,
+            min_size: 20_000,
+        }
+    }
+}
+
+/// Modules to move to shared chunks.
+#[derive(Debug, Default)]
+pub(super) struct SplitPlan {
+    chunks: Vec<Chunk>,
+    modules: AHashMap<FileName, ChunkModule>,
+}
+
+/// A module moved to a shared chunk.
+#[derive(Debug)]
+struct ChunkModule {
+    /// Index of the chunk containing the module.
+    chunk: usize,
+    /// Name of the namespace object of the module, exported by the chunk.
+    ns: JsWord,
+    /// Exports of the module imported by other chunks, to the names the
+    /// chunk exports them as.
+    exports: BTreeMap<JsWord, JsWord>,
+}
+
+impl ChunkModule {
+    fn export_of(&self, path: &FileName, name: &JsWord) -> Result<Ident, Error> {
+        match self.exports.get(name) {
+            Some(v) => Ok(quote_ident!(v.clone())),
+            None => bail!("`{}` of `{}` is not exported by the chunk", name, path),
+        }
+    }
+
+    /// Records an import of `name`, from a module in another chunk.
+    fn add_export(&mut self, name: &JsWord) {
+        if self.exports.contains_key(name) {
+            return;
+        }
+
+        let base = format!(
+            "{}_{}",
+            self.ns,
+            name.chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect::<String>()
+        );
+        let mut exported = base.clone();
+        // Names like `a-b` and `a_b` are same after replacing invalid characters.
+        for i in 1.. {
+            if !self.exports.values().any(|v| *v == *exported) {
+                break;
+            }
+            exported = format!("{}_{}", base, i);
+        }
+
+        self.exports.insert(name.clone(), exported.into());
+    }
+}
+
+#[derive(Debug)]
+struct Chunk {
+    name: String,
+    /// Used as a module specifier while importing the chunk.
+    specifier: JsWord,
+    /// Synthesized module which exports all modules in the chunk.
+    entry: FileName,
+    modules: Vec<FileName>,
+}
+
+impl Chunk {
+    fn file_name(&self) -> String {
+        format!("{}.js", self.name)
+    }
+}
+
+impl SplitPlan {
+    /// Returns entries for shared chunks.
+    pub fn entries(&self) -> impl '_ + Iterator<Item = (String, FileName)> {
+        self.chunks
+            .iter()
+            .map(|chunk| (chunk.file_name(), chunk.entry.clone()))
+    }
+
+    pub fn is_chunk_specifier(&self, src: &JsWord) -> bool {
+        self.chunks.iter().any(|chunk| chunk.specifier == *src)
+    }
+
+    pub fn is_chunk_entry(&self, file_name: &FileName) -> bool {
+        self.chunks.iter().any(|chunk| chunk.entry == *file_name)
+    }
+
+    /// Resolves imports of the synthesized entry of a chunk.
+    pub fn resolve(&self, base: &FileName, module_specifier: &str) -> Option<FileName> {
+        let chunk = self.chunks.iter().find(|chunk| chunk.entry == *base)?;
+
+        chunk
+            .modules
+            .iter()
+            .find(|path| path.to_string() == module_specifier)
+            .cloned()
+    }
+
+    /// Creates the synthesized entry of a chunk, like
+    ///
+    /// ```js
+    /// import * as __swcpack_module_0__ from './a.js';
+    /// import * as __swcpack_module_1__ from './b.js';
+    /// export { foo as __swcpack_module_0___foo } from './a.js';
+    /// export { __swcpack_module_0__, __swcpack_module_1__ };
+    /// ```
+    pub fn load_chunk_entry(&self, file_name: &FileName) -> Option<Module> {
+        let chunk = self.chunks.iter().find(|chunk| chunk.entry == *file_name)?;
+
+        let mut body = vec![];
+        let mut specifiers = vec![];
+
+        for path in &chunk.modules {
+            let ChunkModule { ns, exports, .. } = &self.modules[path];
+
+            body.push(ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                span: DUMMY_SP,
+                specifiers: vec![ImportSpecifier::Namespace(ImportStarAsSpecifier {
+                    span: DUMMY_SP,
+                    local: quote_ident!(ns.clone()),
+                })],
+                src: quote_str!(path.to_string()),
+                type_only: false,
+                asserts: None,
+            })));
+
+            if !exports.is_empty() {
+                body.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
+                    NamedExport {
+                        span: DUMMY_SP,
+                        specifiers: exports
+                            .iter()
+                            .map(|(orig, exported)| {
+                                ExportSpecifier::Named(ExportNamedSpecifier {
+                                    span: DUMMY_SP,
+                                    orig: export_name(orig.clone()),
+                                    exported: Some(ModuleExportName::Ident(quote_ident!(
+                                        exported.clone()
+                                    ))),
+                                    is_type_only: false,
+                                })
+                            })
+                            .collect(),
+                        src: Some(quote_str!(path.to_string())),
+                        type_only: false,
+                        asserts: None,
+                    },
+                )));
+            }
+
+            specifiers.push(ExportSpecifier::Named(ExportNamedSpecifier {
+                span: DUMMY_SP,
+                orig: ModuleExportName::Ident(quote_ident!(ns.clone())),
+                exported: None,
+                is_type_only: false,
+            }));
+        }
+
+        body.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
+            NamedExport {
+                span: DUMMY_SP,
+                specifiers,
+                src: None,
+                type_only: false,
+                asserts: None,
+            },
+        )));
+
+        Some(Module {
+            span: DUMMY_SP,
+            body,
+            shebang: None,
+        })
+    }
+
+    /// Returns the map from the specifier of a chunk to the specifier of the
+    /// renamed chunk.
+    pub fn renamed_specifiers(
+        &self,
+        renamed: &AHashMap<std::path::PathBuf, String>,
+    ) -> AHashMap<JsWord, JsWord> {
+        self.chunks
+            .iter()
+            .filter_map(|chunk| {
+                let new_name = renamed.get(Path::new(&chunk.file_name()))?;
+
+                Some((chunk.specifier.clone(), format!("./{}", new_name).into()))
+            })
+            .collect()
+    }
+
+    fn chunk_of(&self, file_name: &FileName) -> Option<usize> {
+        if let Some(m) = self.modules.get(file_name) {
+            return Some(m.chunk);
+        }
+
+        self.chunks
+            .iter()
+            .position(|chunk| chunk.entry == *file_name)
+    }
+}
+
+impl<L, R> Bundler<'_, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    /// Phase 0 (splitting)
+    ///
+    /// Loads all dependencies of `entries` and determines modules which should
+    /// be moved to shared chunks.
+    ///
+    /// Modules loaded while planning are not split, so the caller should clear
+    /// [Scope](super::scope::Scope) after calling this method.
+    pub(super) fn plan_split(
+        &self,
+        entries: &[(String, FileName)],
+    ) -> Result<Option<SplitPlan>, Error> {
+        let config = match &self.config.chunks {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        self.run(|| {
+            let mut loaded = AHashMap::default();
+            for (name, path) in entries {
+                let module = self
+                    .load_transformed(path)
+                    .context("load_transformed failed")?
+                    .unwrap();
+                loaded.insert(name.clone(), module);
+            }
+
+            let (plan, graph, _) = self.determine_entries(loaded).context("failed to plan")?;
+
+            // Modules which can't be moved to a shared chunk.
+            let mut pinned = AHashSet::default();
+            // Names of entries depending on a module.
+            let mut users: AHashMap<ModuleId, BTreeSet<&str>> = AHashMap::default();
+
+            for (&entry, kind) in &plan.entries {
+                let name = match kind {
+                    BundleKind::Named { name } => &**name,
+                    _ => unreachable!("entry should be named"),
+                };
+                pinned.insert(entry);
+
+                let mut visited = AHashSet::default();
+                let mut queue = vec![entry];
+                while let Some(id) = queue.pop() {
+                    for dep in graph.neighbors_directed(id, Outgoing) {
+                        if visited.insert(dep) {
+                            users.entry(dep).or_default().insert(name);
+                            queue.push(dep);
+                        }
+                    }
+                }
+            }
+
+            for &id in &plan.all {
+                let info = self.scope.get_module(id).unwrap();
+
+                // We can't use a common js module as a namespace object.
+                if !info.is_es6 || self.scope.is_cjs(id) {
+                    pinned.insert(id);
+                }
+
+                // `export *` requires all exported names, which we don't know while
+                // rewriting imports.
+                for item in &info.module.body {
+                    if let ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export)) = item {
+                        if self.is_external(&export.src.value) {
+                            continue;
+                        }
+
+                        let path = self.resolve(&info.fm.name, &export.src.value)?;
+                        let (dep, _, _) = self.scope.module_id_gen.gen(&path);
+                        pinned.insert(dep);
+                    }
+                }
+            }
+
+            let mut manual: AHashMap<String, Vec<ModuleId>> = AHashMap::default();
+            let mut shared: AHashMap<&BTreeSet<&str>, Vec<ModuleId>> = AHashMap::default();
+
+            for &id in &plan.all {
+                if pinned.contains(&id) {
+                    continue;
+                }
+                let info = self.scope.get_module(id).unwrap();
+
+                let record = ModuleRecord {
+                    file_name: info.fm.name.clone(),
+                    is_entry: false,
+                };
+                if let Some(name) = self
+                    .hook
+                    .get_chunk_name(&record)
+                    .context("failed to get name of chunk")?
+                {
+                    manual.entry(name).or_default().push(id);
+                    continue;
+                }
+
+                if config.min_shared < 2 {
+                    continue;
+                }
+                if let Some(users) = users.get(&id) {
+                    if users.len() >= config.min_shared {
+                        shared.entry(users).or_default().push(id);
+                    }
+                }
+            }
+
+            for (users, ids) in shared {
+                let size: usize = ids
+                    .iter()
+                    .map(|id| self.scope.get_module(*id).unwrap().fm.src.len())
+                    .sum();
+                if size < config.min_size {
+                    continue;
+                }
+
+                let name = users
+                    .iter()
+                    .map(|name| {
+                        Path::new(name)
+                            .file_stem()
+                            .map(|v| v.to_string_lossy().to_string())
+                            .unwrap_or_else(|| name.to_string())
+                    })
+                    .collect::<Vec<_>>()
+                    .join("~");
+
+                manual.entry(name).or_default().extend(ids);
+            }
+
+            if manual.is_empty() {
+                return Ok(None);
+            }
+
+            let mut chunks = manual.into_iter().collect::<Vec<_>>();
+            chunks.sort_by(|a, b| a.0.cmp(&b.0));
+
+            let mut split = SplitPlan::default();
+            for (idx, (name, mut ids)) in chunks.into_iter().enumerate() {
+                ids.sort();
+
+                let modules = ids
+                    .into_iter()
+                    .map(|id| self.scope.get_module(id).unwrap().fm.name.clone())
+                    .collect::<Vec<_>>();
+
+                for path in &modules {
+                    let ns = format!("__swcpack_module_{}__", split.modules.len()).into();
+                    split.modules.insert(
+                        path.clone(),
+                        ChunkModule {
+                            chunk: idx,
+                            ns,
+                            exports: Default::default(),
+                        },
+                    );
+                }
+
+                split.chunks.push(Chunk {
+                    specifier: format!("./{}.js", name).into(),
+                    entry: FileName::Custom(format!("swcpack-chunk:{}", name)),
+                    name,
+                    modules,
+                });
+            }
+
+            // Exports used by other chunks are exported by the chunk, so we can keep
+            // import specifiers (and live bindings) while importing a chunk.
+            for &id in &plan.all {
+                let info = self.scope.get_module(id).unwrap();
+                let current = split.chunk_of(&info.fm.name);
+
+                for item in &info.module.body {
+                    let (src, names) = match item {
+                        ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                            src,
+                            specifiers,
+                            type_only: false,
+                            ..
+                        })) => (
+                            src,
+                            specifiers
+                                .iter()
+                                .filter_map(|s| match s {
+                                    ImportSpecifier::Namespace(..)
+                                    | ImportSpecifier::Named(ImportNamedSpecifier {
+                                        is_type_only: true,
+                                        ..
+                                    }) => None,
+                                    ImportSpecifier::Default(..) => Some(js_word!("default")),
+                                    ImportSpecifier::Named(s) => Some(match &s.imported {
+                                        Some(imported) => name_of(imported).clone(),
+                                        None => s.local.sym.clone(),
+                                    }),
+                                })
+                                .collect::<Vec<_>>(),
+                        ),
+                        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                            src: Some(src),
+                            specifiers,
+                            type_only: false,
+                            ..
+                        })) => (
+                            src,
+                            specifiers
+                                .iter()
+                                .filter_map(|s| match s {
+                                    ExportSpecifier::Namespace(..)
+                                    | ExportSpecifier::Named(ExportNamedSpecifier {
+                                        is_type_only: true,
+                                        ..
+                                    }) => None,
+                                    ExportSpecifier::Default(..) => Some(js_word!("default")),
+                                    ExportSpecifier::Named(s) => Some(name_of(&s.orig).clone()),
+                                })
+                                .collect(),
+                        ),
+                        _ => continue,
+                    };
+                    if self.is_external(&src.value) {
+                        continue;
+                    }
+
+                    let path = self.resolve(&info.fm.name, &src.value)?;
+                    if let Some(m) = split.modules.get_mut(&*path) {
+                        if Some(m.chunk) == current {
+                            continue;
+                        }
+                        for name in &names {
+                            m.add_export(name);
+                        }
+                    }
+                }
+            }
+
+            tracing::debug!("Split plan: {:?}", split);
+
+            Ok(Some(split))
+        })
+    }
+
+    /// Replaces imports of modules in other chunks with imports of the chunks.
+    ///
+    /// ```js
+    /// import * as ns from './foo';
+    /// import foo, { bar as baz } from './foo';
+    /// export { a } from './foo';
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// import { __swcpack_module_0__ as ns } from './shared.js';
+    /// import { __swcpack_module_0___default as foo, __swcpack_module_0___bar as baz } from './shared.js';
+    /// export { __swcpack_module_0___a as a } from './shared.js';
+    /// ```
+    pub(super) fn import_shared_chunks(
+        &self,
+        base: &FileName,
+        module: &mut Module,
+    ) -> Result<(), Error> {
+        let split = match &self.split {
+            Some(v) => v,
+            None => return Ok(()),
+        };
+        let current = split.chunk_of(base);
+
+        for item in &mut module.body {
+            let src = match &*item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    src,
+                    type_only: false,
+                    ..
+                }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    src: Some(src),
+                    type_only: false,
+                    ..
+                })) => &src.value,
+                _ => continue,
+            };
+            if self.is_external(src) {
+                continue;
+            }
+
+            let path = self.resolve(base, src)?;
+            let m = match split.modules.get(&*path) {
+                Some(m) if Some(m.chunk) != current => m,
+                _ => continue,
+            };
+            let chunk_src = quote_str!(split.chunks[m.chunk].specifier.clone());
+
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                    import.src = chunk_src;
+
+                    for s in &mut import.specifiers {
+                        let (span, local, imported) = match s {
+                            ImportSpecifier::Namespace(s) => {
+                                (s.span, s.local.clone(), quote_ident!(m.ns.clone()))
+                            }
+                            ImportSpecifier::Default(s) => (
+                                s.span,
+                                s.local.clone(),
+                                m.export_of(&path, &js_word!("default"))?,
+                            ),
+                            ImportSpecifier::Named(ImportNamedSpecifier {
+                                is_type_only: true,
+                                ..
+                            }) => continue,
+                            ImportSpecifier::Named(s) => {
+                                let name = match &s.imported {
+                                    Some(imported) => name_of(imported).clone(),
+                                    None => s.local.sym.clone(),
+                                };
+
+                                (s.span, s.local.clone(), m.export_of(&path, &name)?)
+                            }
+                        };
+
+                        *s = ImportSpecifier::Named(ImportNamedSpecifier {
+                            span,
+                            local,
+                            imported: Some(ModuleExportName::Ident(imported)),
+                            is_type_only: false,
+                        });
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) => {
+                    export.src = Some(chunk_src);
+
+                    for s in &mut export.specifiers {
+                        let (span, orig, exported) = match s {
+                            ExportSpecifier::Namespace(s) => {
+                                (s.span, quote_ident!(m.ns.clone()), s.name.clone())
+                            }
+                            ExportSpecifier::Default(s) => (
+                                DUMMY_SP,
+                                m.export_of(&path, &js_word!("default"))?,
+                                ModuleExportName::Ident(s.exported.clone()),
+                            ),
+                            ExportSpecifier::Named(ExportNamedSpecifier {
+                                is_type_only: true,
+                                ..
+                            }) => continue,
+                            ExportSpecifier::Named(s) => (
+                                s.span,
+                                m.export_of(&path, name_of(&s.orig))?,
+                                s.exported.clone().unwrap_or_else(|| s.orig.clone()),
+                            ),
+                        };
+
+                        *s = ExportSpecifier::Named(ExportNamedSpecifier {
+                            span,
+                            orig: ModuleExportName::Ident(orig),
+                            exported: Some(exported),
+                            is_type_only: false,
+                        });
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Loads the synthesized entry of a chunk.
+    pub(super) fn load_chunk_entry(&self, file_name: &FileName) -> Option<ModuleData> {
+        let module = self.split.as_ref()?.load_chunk_entry(file_name)?;
+
+        Some(ModuleData {
+            fm: self.cm.new_source_file(file_name.clone(), String::new()),
+            module,
+            helpers: Default::default(),
+        })
+    }
+}
+
+fn name_of(name: &ModuleExportName) -> &JsWord {
+    match name {
+        ModuleExportName::Ident(i) => &i.sym,
+        ModuleExportName::Str(s) => &s.value,
+    }
+}
+
+/// Exported names which are not valid identifiers are printed as strings.
+fn export_name(name: JsWord) -> ModuleExportName {
+    if Ident::verify_symbol(&name).is_ok() || name == js_word!("default") {
+        ModuleExportName::Ident(quote_ident!(name))
+    } else {
+        ModuleExportName::Str(quote_str!(name))
+    }
+}
+
+/// Changes the specifiers of chunks to the specifiers of renamed chunks.
+pub(super) struct ChunkRenamer<'a> {
+    pub renamed: &'a AHashMap<JsWord, JsWord>,
+}
+
+impl ChunkRenamer<'_> {
+    fn rename(&self, s: &mut Str) {
+        if let Some(v) = self.renamed.get(&s.value) {
+            *s = quote_str!(s.span, v.clone());
+        }
+    }
+}
+
+/// Chunks are imported by module declarations, or by `require` calls and amd
+/// dependencies if the bundle is wrapped.
+impl VisitMut for ChunkRenamer<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_import_decl(&mut self, n: &mut ImportDecl) {
+        self.rename(&mut n.src);
+    }
+
+    fn visit_mut_export_all(&mut self, n: &mut ExportAll) {
+        self.rename(&mut n.src);
+    }
+
+    fn visit_mut_named_export(&mut self, n: &mut NamedExport) {
+        if let Some(src) = &mut n.src {
+            self.rename(src);
+        }
+    }
+
+    fn visit_mut_call_expr(&mut self, n: &mut CallExpr) {
+        n.visit_mut_children_with(self);
+
+        let callee = match &n.callee {
+            Callee::Expr(callee) => match &**callee {
+                Expr::Ident(callee) => &callee.sym,
+                _ => return,
+            },
+            _ => return,
+        };
+
+        match &**callee {
+            "require" => {
+                if let Some(ExprOrSpread { spread: None, expr }) = n.args.first_mut() {
+                    if let Expr::Lit(Lit::Str(s)) = &mut **expr {
+                        self.rename(s);
+                    }
+                }
+            }
+            "define" => {
+                for arg in &mut n.args {
+                    if let Expr::Array(deps) = &mut *arg.expr {
+                        for dep in deps.elems.iter_mut().flatten() {
+                            if let Expr::Lit(Lit::Str(s)) = &mut *dep.expr {
+                                self.rename(s);
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
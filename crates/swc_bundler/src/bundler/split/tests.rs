@@ -0,0 +1,285 @@
+use super::ChunkConfig;
+use crate::{bundler::tests::suite, Bundle, BundleKind, ModuleType};
+use std::collections::HashMap;
+use swc_common::FileName;
+use swc_ecma_ast::*;
+
+fn entries() -> HashMap<String, FileName> {
+    let mut entries = HashMap::default();
+    entries.insert("a.js".to_string(), FileName::Real("a.js".into()));
+    entries.insert("b.js".to_string(), FileName::Real("b.js".into()));
+    entries
+}
+
+fn name_of(bundle: &Bundle) -> &str {
+    match &bundle.kind {
+        BundleKind::Named { name } | BundleKind::Lib { name } => name,
+        BundleKind::Dynamic => unreachable!(),
+    }
+}
+
+fn imported_srcs(bundle: &Bundle) -> Vec<String> {
+    bundle
+        .module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                Some(import.src.value.to_string())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn chunk_of(bundles: &[Bundle]) -> &Bundle {
+    bundles
+        .iter()
+        .find(|b| name_of(b).starts_with("a~b-"))
+        .expect("shared chunk should be created")
+}
+
+fn config() -> Option<ChunkConfig> {
+    Some(ChunkConfig {
+        min_shared: 2,
+        min_size: 0,
+    })
+}
+
+#[test]
+fn shared_chunk() {
+    suite()
+        .file(
+            "a.js",
+            "import { foo } from './shared'; console.log(foo, 'a');",
+        )
+        .file(
+            "b.js",
+            "import { foo } from './shared'; console.log(foo, 'b');",
+        )
+        .file("shared.js", "export const foo = Math.random();")
+        .run(|t| {
+            t.bundler.config.chunks = Some(ChunkConfig {
+                min_shared: 2,
+                min_size: 0,
+            });
+
+            let bundles = t.bundler.bundle(entries())?;
+            assert_eq!(bundles.len(), 3);
+
+            let chunk = bundles
+                .iter()
+                .find(|b| name_of(b).starts_with("a~b-"))
+                .expect("shared chunk should be created");
+            let chunk_name = name_of(chunk);
+
+            for bundle in &bundles {
+                if bundle.id == chunk.id {
+                    continue;
+                }
+
+                assert_eq!(imported_srcs(bundle), vec![format!("./{}", chunk_name)]);
+            }
+
+            Ok(())
+        });
+}
+
+#[test]
+fn min_size() {
+    suite()
+        .file(
+            "a.js",
+            "import { foo } from './shared'; console.log(foo, 'a');",
+        )
+        .file(
+            "b.js",
+            "import { foo } from './shared'; console.log(foo, 'b');",
+        )
+        .file("shared.js", "export const foo = Math.random();")
+        .run(|t| {
+            t.bundler.config.chunks = Some(ChunkConfig {
+                min_shared: 2,
+                min_size: 1024,
+            });
+
+            let bundles = t.bundler.bundle(entries())?;
+            assert_eq!(bundles.len(), 2);
+
+            for bundle in &bundles {
+                assert_eq!(imported_srcs(bundle), Vec::<String>::new());
+            }
+
+            Ok(())
+        });
+}
+
+/// Modules in a shared chunk should not be included in entries.
+#[test]
+fn chunk_contents() {
+    suite()
+        .file(
+            "a.js",
+            "import { foo } from './shared'; console.log(foo, 'entry a');",
+        )
+        .file(
+            "b.js",
+            "import { foo } from './shared'; console.log(foo, 'entry b');",
+        )
+        .file("shared.js", "export const foo = 'shared module';")
+        .run(|t| {
+            t.bundler.config.chunks = config();
+
+            let bundles = t.bundler.bundle(entries())?;
+            let chunk = chunk_of(&bundles);
+
+            for bundle in &bundles {
+                let code = t.print(&bundle.module);
+
+                if bundle.id == chunk.id {
+                    assert_eq!(code.matches("shared module").count(), 1, "{}", code);
+                    assert!(!code.contains("entry"), "{}", code);
+                } else {
+                    assert!(!code.contains("shared module"), "{}", code);
+                    assert_eq!(code.matches("entry").count(), 1, "{}", code);
+                }
+            }
+
+            Ok(())
+        });
+}
+
+/// Imports of a shared chunk should be imports, not copies of the values.
+#[test]
+fn live_bindings() {
+    suite()
+        .file(
+            "a.js",
+            "
+            import { count, inc } from './shared';
+            export { count as total } from './shared';
+            inc();
+            console.log(count);
+            ",
+        )
+        .file(
+            "b.js",
+            "
+            import * as shared from './shared';
+            shared.inc();
+            ",
+        )
+        .file(
+            "shared.js",
+            "
+            export let count = 0;
+            export function inc() {
+                count++;
+            }
+            ",
+        )
+        .run(|t| {
+            t.bundler.config.chunks = config();
+
+            let bundles = t.bundler.bundle(entries())?;
+            let chunk = chunk_of(&bundles);
+            let chunk_src = format!("./{}", name_of(chunk));
+
+            for bundle in &bundles {
+                if bundle.id == chunk.id {
+                    continue;
+                }
+                let code = t.print(&bundle.module);
+                assert!(!code.contains("const"), "{}", code);
+
+                for item in &bundle.module.body {
+                    match item {
+                        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                            assert_eq!(import.src.value, *chunk_src);
+                            assert!(!import.specifiers.is_empty(), "{}", code);
+                            for s in &import.specifiers {
+                                assert!(matches!(s, ImportSpecifier::Named(..)), "{}", code);
+                            }
+                        }
+                        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) => {
+                            assert_eq!(
+                                export.src.as_ref().map(|src| &*src.value),
+                                Some(&*chunk_src),
+                                "{}",
+                                code
+                            );
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            Ok(())
+        });
+}
+
+/// Only module specifiers should be renamed.
+#[test]
+fn strings_are_not_renamed() {
+    suite()
+        .file(
+            "a.js",
+            "import { foo } from './shared'; console.log(foo, './a~b.js');",
+        )
+        .file(
+            "b.js",
+            "import { foo } from './shared'; console.log(foo, 'b');",
+        )
+        .file("shared.js", "export const foo = './a~b.js';")
+        .run(|t| {
+            t.bundler.config.chunks = config();
+
+            let bundles = t.bundler.bundle(entries())?;
+            let chunk = chunk_of(&bundles);
+            assert_ne!(name_of(chunk), "a~b.js");
+
+            for bundle in &bundles {
+                let code = t.print(&bundle.module);
+                if bundle.id == chunk.id || name_of(bundle).starts_with('a') {
+                    assert_eq!(code.matches("'./a~b.js'").count(), 1, "{}", code);
+                }
+            }
+
+            Ok(())
+        });
+}
+
+/// Iife bundles can't import shared chunks.
+#[test]
+fn iife_is_rejected() {
+    suite()
+        .file("a.js", "import { foo } from './shared'; console.log(foo);")
+        .file("b.js", "import { foo } from './shared'; console.log(foo);")
+        .file("shared.js", "export const foo = 1;")
+        .run(|t| {
+            t.bundler.config.chunks = config();
+            t.bundler.config.module = ModuleType::Iife;
+
+            let err = t.bundler.bundle(entries()).unwrap_err();
+            assert!(err.to_string().contains("iife"), "{}", err);
+
+            Ok(())
+        });
+}
+
+#[test]
+fn hmr_is_rejected() {
+    suite()
+        .file("a.js", "import { foo } from './shared'; console.log(foo);")
+        .file("b.js", "import { foo } from './shared'; console.log(foo);")
+        .file("shared.js", "export const foo = 1;")
+        .run(|t| {
+            t.bundler.config.chunks = config();
+            t.bundler.config.hmr = Some(Default::default());
+
+            let err = t.bundler.bundle(entries()).unwrap_err();
+            assert!(err.to_string().contains("Config.hmr"), "{}", err);
+
+            Ok(())
+        });
+}
@@ -6,9 +6,11 @@ use indexmap::IndexMap;
 use std::path::PathBuf;
 use swc_common::{sync::Lrc, FileName, SourceMap, Span, GLOBALS};
 use swc_ecma_ast::*;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput};
+use swc_ecma_transforms_base::fixer::fixer;
 use swc_ecma_utils::drop_span;
-use swc_ecma_visit::VisitMutWith;
+use swc_ecma_visit::{FoldWith, VisitMutWith};
 
 pub(crate) struct Tester<'a> {
     pub cm: Lrc<SourceMap>,
@@ -87,6 +89,24 @@ impl<'a> Tester<'a> {
         parser.parse_module().unwrap()
     }
 
+    /// Prints `m` after applying the fixer.
+    pub fn print(&self, m: &Module) -> String {
+        let m = m.clone().fold_with(&mut fixer(None));
+
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: Default::default(),
+                cm: self.cm.clone(),
+                comments: None,
+                wr: Box::new(JsWriter::new(self.cm.clone(), "\n", &mut buf, None)),
+            };
+            emitter.emit_module(&m).unwrap();
+        }
+
+        String::from_utf8(buf).unwrap()
+    }
+
     #[allow(dead_code)]
     pub fn assert_eq(&self, m: &Module, expected: &str) {
         let expected = self.parse(expected);
@@ -137,6 +157,8 @@ impl TestBuilder {
                         disable_dce: false,
                         external_modules: vec![],
                         module: Default::default(),
+                        chunks: None,
+                        hmr: None,
                     },
                     Box::new(Hook),
                 );
@@ -0,0 +1,363 @@
+//! Persistent cache for [ModuleData].
+
+use crate::load::ModuleData;
+use anyhow::{Context, Error};
+use crc::{crc32, crc64, Hasher64};
+use rkyv::{AlignedVec, Archived, Deserialize};
+use std::{
+    fs,
+    io::ErrorKind,
+    mem::size_of,
+    path::{Path, PathBuf},
+    process,
+};
+use swc_common::{
+    comments::{Comment, CommentKind, Comments},
+    sync::Lrc,
+    BytePos, SourceFile, Span, SyntaxContext, DUMMY_SP,
+};
+use swc_ecma_ast::Module;
+use swc_ecma_transforms_base::helpers::Helpers;
+use swc_ecma_visit::{noop_visit_type, Visit, VisitMut, VisitMutWith, VisitWith};
+
+/// Stores loaded modules on disk, so modules are not processed again if the
+/// source file is not changed.
+///
+/// Entries are keyed by the name and content of the source file, the versions
+/// of the bundler and the ast, and the `key` passed to [Cache::new]. Stale
+/// entries are not removed, so it's safe to share a directory between multiple
+/// configs.
+///
+/// Entries are validated before use, so a broken or incompatible entry is
+/// ignored instead of causing undefined behavior.
+///
+/// Comments attached to the spans of the module are stored if `comments` is
+/// passed to [Cache::put], and restored by [Cache::get].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// impl Load for Loader {
+///     fn load(&self, name: &FileName) -> Result<ModuleData, Error> {
+///         let fm = self.load_file(name)?;
+///         if let Some(data) = self.cache.get(&fm, Some(&self.comments))? {
+///             return Ok(data);
+///         }
+///
+///         let data = self.transform(fm)?;
+///         self.cache.put(&data, Some(&self.comments))?;
+///         Ok(data)
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+    key: String,
+}
+
+#[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+#[archive_attr(derive(bytecheck::CheckBytes))]
+struct Entry {
+    /// [format] and the key of the cache, used to detect collisions of paths.
+    key: String,
+    /// `start_pos` of the source file used to create the entry.
+    start_pos: u32,
+    /// Checksum of the source file, used to detect collisions of keys.
+    src_hash: u32,
+    module: Module,
+    comments: Vec<CachedComment>,
+    helpers: Vec<String>,
+    external_helpers: bool,
+}
+
+#[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+#[archive_attr(derive(bytecheck::CheckBytes))]
+struct CachedComment {
+    /// The position the comment is attached to.
+    pos: u32,
+    leading: bool,
+    is_block: bool,
+    lo: u32,
+    hi: u32,
+    text: String,
+}
+
+impl Cache {
+    /// `key` should be changed if the output of the loader can change for the
+    /// same input, e.g. if config of the loader is changed.
+    pub fn new(dir: impl Into<PathBuf>, key: impl Into<String>) -> Self {
+        Cache {
+            dir: dir.into(),
+            key: key.into(),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn key(&self) -> String {
+        format!("{}/{}", format(), self.key)
+    }
+
+    fn path_of(&self, fm: &SourceFile) -> PathBuf {
+        let mut digest = crc64::Digest::new(crc64::ECMA);
+        for s in [
+            &*self.key(),
+            &*fm.name.to_string(),
+            &**fm.src,
+        ] {
+            digest.write(s.as_bytes());
+            // Prevent collisions like ("ab", "c") and ("a", "bc")
+            digest.write(&(s.len() as u64).to_le_bytes());
+        }
+
+        self.dir
+            .join(format!("{}.bin", radix_fmt::radix(digest.sum64(), 36)))
+    }
+
+    /// Returns the stored module for `fm`, with spans adjusted for `fm`.
+    ///
+    /// Returns [None] if there's no entry for the current content of `fm` or
+    /// the entry is broken.
+    pub fn get(
+        &self,
+        fm: &Lrc<SourceFile>,
+        comments: Option<&dyn Comments>,
+    ) -> Result<Option<ModuleData>, Error> {
+        let path = self.path_of(fm);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("failed to read cache entry at `{}`", path.display()))
+            }
+        };
+
+        // The first 4 bytes are the checksum of the entry.
+        if bytes.len() < 4 {
+            return Ok(None);
+        }
+        let (checksum, payload) = bytes.split_at(4);
+        if checksum != crc32::checksum_ieee(payload).to_le_bytes() {
+            tracing::warn!("Ignoring broken cache entry at `{}`", path.display());
+            return Ok(None);
+        }
+
+        let mut aligned = AlignedVec::with_capacity(payload.len());
+        aligned.extend_from_slice(payload);
+        let archived = match rkyv::check_archived_root::<Entry>(&aligned) {
+            Ok(archived) => archived,
+            Err(err) => {
+                tracing::warn!(
+                    "Ignoring invalid cache entry at `{}`: {}",
+                    path.display(),
+                    err
+                );
+                return Ok(None);
+            }
+        };
+
+        if archived.key.as_str() != self.key()
+            || archived.src_hash != crc32::checksum_ieee(fm.src.as_bytes())
+        {
+            return Ok(None);
+        }
+
+        let entry: Entry = archived
+            .deserialize(&mut rkyv::Infallible)
+            .with_context(|| {
+                format!("failed to deserialize cache entry at `{}`", path.display())
+            })?;
+
+        let helpers = Helpers::new(entry.external_helpers);
+        for name in &entry.helpers {
+            if !helpers.enable_by_name(name) {
+                // The entry is created by an incompatible version.
+                return Ok(None);
+            }
+        }
+
+        let mut relocator = SpanRelocator {
+            from: BytePos(entry.start_pos),
+            to: fm.start_pos,
+            len: fm.end_pos.0 - fm.start_pos.0,
+        };
+        let mut module = entry.module;
+        module.visit_mut_with(&mut relocator);
+
+        if let Some(comments) = comments {
+            for c in entry.comments {
+                let pos = relocator.relocate(Span::new(
+                    BytePos(c.pos),
+                    BytePos(c.pos),
+                    Default::default(),
+                ));
+                if pos.is_dummy() {
+                    continue;
+                }
+                let comment = Comment {
+                    kind: if c.is_block {
+                        CommentKind::Block
+                    } else {
+                        CommentKind::Line
+                    },
+                    span: relocator.relocate(Span::new(
+                        BytePos(c.lo),
+                        BytePos(c.hi),
+                        Default::default(),
+                    )),
+                    text: c.text,
+                };
+
+                if c.leading {
+                    comments.add_leading(pos.lo, comment);
+                } else {
+                    comments.add_trailing(pos.lo, comment);
+                }
+            }
+        }
+
+        Ok(Some(ModuleData {
+            fm: fm.clone(),
+            module,
+            helpers,
+        }))
+    }
+
+    /// Stores `data`, so it can be used by the next build.
+    pub fn put(&self, data: &ModuleData, comments: Option<&dyn Comments>) -> Result<(), Error> {
+        let entry = Entry {
+            key: self.key(),
+            start_pos: data.fm.start_pos.0,
+            src_hash: crc32::checksum_ieee(data.fm.src.as_bytes()),
+            module: data.module.clone(),
+            comments: comments
+                .map(|comments| collect_comments(&data.module, comments))
+                .unwrap_or_default(),
+            helpers: data
+                .helpers
+                .used_names()
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            external_helpers: data.helpers.external(),
+        };
+
+        let payload = rkyv::to_bytes::<_, 512>(&entry)
+            .map_err(|err| Error::msg(format!("{:?}", err)))
+            .with_context(|| format!("failed to serialize `{}`", data.fm.name))?;
+
+        let mut bytes = Vec::with_capacity(4 + payload.len());
+        bytes.extend_from_slice(&crc32::checksum_ieee(&payload).to_le_bytes());
+        bytes.extend_from_slice(&payload);
+
+        fs::create_dir_all(&self.dir).with_context(|| {
+            format!("failed to create cache directory `{}`", self.dir.display())
+        })?;
+
+        // Write to a temporary file first, so other processes don't see a
+        // partially written entry.
+        let path = self.path_of(&data.fm);
+        let tmp = path.with_extension(format!("{}.tmp", process::id()));
+        fs::write(&tmp, &bytes)
+            .with_context(|| format!("failed to write cache entry at `{}`", tmp.display()))?;
+        fs::rename(&tmp, &path)
+            .with_context(|| format!("failed to write cache entry at `{}`", path.display()))?;
+
+        Ok(())
+    }
+}
+
+/// Describes the format of entries.
+///
+/// The layout of archived types depends on the versions of the crates and the
+/// target, so it's part of the key.
+fn format() -> String {
+    format!(
+        "{}/{}/{}/{}",
+        env!("CARGO_PKG_VERSION"),
+        swc_ecma_ast::VERSION,
+        size_of::<Archived<usize>>(),
+        size_of::<Archived<Entry>>(),
+    )
+}
+
+/// Moves spans of a cached module to the new source file.
+///
+/// Syntax contexts are dropped because marks are not valid across processes.
+struct SpanRelocator {
+    from: BytePos,
+    to: BytePos,
+    len: u32,
+}
+
+impl SpanRelocator {
+    fn relocate(&self, span: Span) -> Span {
+        let in_file = |pos: BytePos| pos >= self.from && pos.0 - self.from.0 <= self.len;
+
+        if !span.is_dummy() && in_file(span.lo) && in_file(span.hi) {
+            Span::new(
+                self.to + (span.lo - self.from),
+                self.to + (span.hi - self.from),
+                SyntaxContext::empty(),
+            )
+        } else {
+            DUMMY_SP
+        }
+    }
+}
+
+impl VisitMut for SpanRelocator {
+    fn visit_mut_span(&mut self, span: &mut Span) {
+        *span = self.relocate(*span);
+    }
+}
+
+/// Collects comments attached to the spans of `module`, which are the only
+/// positions the code generator looks up.
+fn collect_comments(module: &Module, comments: &dyn Comments) -> Vec<CachedComment> {
+    let mut v = PosCollector::default();
+    module.visit_with(&mut v);
+    v.pos.sort_unstable();
+    v.pos.dedup();
+
+    let mut cached = vec![];
+    for pos in v.pos {
+        for (leading, list) in [
+            (true, comments.get_leading(pos)),
+            (false, comments.get_trailing(pos)),
+        ] {
+            cached.extend(list.into_iter().flatten().map(|c| CachedComment {
+                pos: pos.0,
+                leading,
+                is_block: c.kind == CommentKind::Block,
+                lo: c.span.lo.0,
+                hi: c.span.hi.0,
+                text: c.text,
+            }));
+        }
+    }
+    cached
+}
+
+#[derive(Default)]
+struct PosCollector {
+    pos: Vec<BytePos>,
+}
+
+impl Visit for PosCollector {
+    noop_visit_type!();
+
+    fn visit_span(&mut self, span: &Span) {
+        if !span.is_dummy() {
+            self.pos.push(span.lo);
+            self.pos.push(span.hi);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,243 @@
+use super::Cache;
+use crate::load::ModuleData;
+use crc::crc32;
+use std::{fs, path::PathBuf};
+use swc_common::{
+    comments::{Comments, SingleThreadedComments},
+    sync::Lrc,
+    FileName, Globals, SourceMap, Spanned, GLOBALS,
+};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput};
+use swc_ecma_transforms_base::helpers::Helpers;
+
+fn load(cm: &Lrc<SourceMap>, src: &str) -> ModuleData {
+    load_with_comments(cm, src, None)
+}
+
+fn load_with_comments(
+    cm: &Lrc<SourceMap>,
+    src: &str,
+    comments: Option<&dyn Comments>,
+) -> ModuleData {
+    let fm = cm.new_source_file(FileName::Real("a.js".into()), src.into());
+    let lexer = Lexer::new(
+        Default::default(),
+        Default::default(),
+        StringInput::from(&*fm),
+        comments,
+    );
+    let module = Parser::new_from(lexer).parse_module().unwrap();
+
+    ModuleData {
+        fm,
+        module,
+        helpers: Helpers::new(false),
+    }
+}
+
+#[test]
+fn roundtrip() {
+    GLOBALS.set(&Globals::new(), || {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path(), "config");
+
+        let cm = Lrc::new(SourceMap::default());
+        // Occupy some space in the source map, so start positions differ.
+        cm.new_source_file(FileName::Anon, "const padding = 1;".into());
+
+        let data = load(&cm, "export const a = 1;");
+        data.helpers.class_call_check();
+        cache.put(&data, None).unwrap();
+
+        let cm = Lrc::new(SourceMap::default());
+        let fm = cm.new_source_file(FileName::Real("a.js".into()), "export const a = 1;".into());
+        let cached = cache.get(&fm, None).unwrap().expect("should be cached");
+
+        assert_eq!(cached.module.body.len(), 1);
+        assert_eq!(cached.module.body[0].span().lo, fm.start_pos);
+        assert_eq!(cached.module.body[0].span().hi, fm.end_pos);
+        assert_eq!(cached.helpers.used_names(), vec!["class_call_check"]);
+    })
+}
+
+fn print(cm: &Lrc<SourceMap>, data: &ModuleData, comments: &dyn Comments) -> String {
+    let mut buf = vec![];
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: Some(comments),
+            wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+        };
+        emitter.emit_module(&data.module).unwrap();
+    }
+
+    String::from_utf8(buf).unwrap()
+}
+
+/// A cached module should be printed exactly like a freshly loaded one.
+#[test]
+fn cold_and_warm() {
+    GLOBALS.set(&Globals::new(), || {
+        const SRC: &str = "
+    /** @license MIT */
+    import { b } from './b';
+    // Leading comment
+    export const a = /* inline */ b + 1; // Trailing comment
+    /*#__PURE__*/ foo();
+    ";
+
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path(), "config");
+
+        let cold = {
+            let cm = Lrc::new(SourceMap::default());
+            let comments = SingleThreadedComments::default();
+            let data = load_with_comments(&cm, SRC, Some(&comments));
+            cache.put(&data, Some(&comments)).unwrap();
+
+            print(&cm, &data, &comments)
+        };
+
+        let warm = {
+            let cm = Lrc::new(SourceMap::default());
+            cm.new_source_file(FileName::Anon, "const padding = 1;".into());
+            let comments = SingleThreadedComments::default();
+            let fm = cm.new_source_file(FileName::Real("a.js".into()), SRC.into());
+            let data = cache
+                .get(&fm, Some(&comments))
+                .unwrap()
+                .expect("should be cached");
+
+            print(&cm, &data, &comments)
+        };
+
+        assert!(cold.contains("@license MIT"), "{}", cold);
+        assert!(cold.contains("#__PURE__"), "{}", cold);
+        assert_eq!(cold, warm);
+    })
+}
+
+#[test]
+fn invalidation() {
+    GLOBALS.set(&Globals::new(), || {
+        let dir = tempfile::tempdir().unwrap();
+        let cm = Lrc::new(SourceMap::default());
+
+        let data = load(&cm, "export const a = 1;");
+        Cache::new(dir.path(), "config").put(&data, None).unwrap();
+
+        let fm = cm.new_source_file(FileName::Real("a.js".into()), "export const a = 2;".into());
+        assert!(Cache::new(dir.path(), "config")
+            .get(&fm, None)
+            .unwrap()
+            .is_none());
+
+        let fm = cm.new_source_file(FileName::Real("a.js".into()), "export const a = 1;".into());
+        assert!(Cache::new(dir.path(), "other")
+            .get(&fm, None)
+            .unwrap()
+            .is_none());
+        assert!(Cache::new(dir.path(), "config")
+            .get(&fm, None)
+            .unwrap()
+            .is_some());
+    })
+}
+
+#[test]
+fn broken_entry() {
+    GLOBALS.set(&Globals::new(), || {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path(), "config");
+        let cm = Lrc::new(SourceMap::default());
+
+        let data = load(&cm, "export const a = 1;");
+        cache.put(&data, None).unwrap();
+
+        for entry in fs::read_dir(dir.path()).unwrap() {
+            let path = entry.unwrap().path();
+            let mut bytes = fs::read(&path).unwrap();
+            let last = bytes.len() - 1;
+            bytes[last] ^= 0xff;
+            fs::write(&path, bytes).unwrap();
+        }
+
+        assert!(cache.get(&data.fm, None).unwrap().is_none());
+    })
+}
+
+/// Replaces the payload of all entries in `cache`, with a valid checksum.
+fn rewrite_entries(cache: &Cache, op: impl Fn(&mut Vec<u8>)) -> Vec<PathBuf> {
+    let mut paths = vec![];
+    for entry in fs::read_dir(cache.dir()).unwrap() {
+        let path = entry.unwrap().path();
+        let bytes = fs::read(&path).unwrap();
+        let mut payload = bytes[4..].to_vec();
+        op(&mut payload);
+
+        let mut bytes = crc32::checksum_ieee(&payload).to_le_bytes().to_vec();
+        bytes.extend_from_slice(&payload);
+        fs::write(&path, bytes).unwrap();
+        paths.push(path);
+    }
+    paths
+}
+
+#[test]
+fn truncated_entry() {
+    GLOBALS.set(&Globals::new(), || {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path(), "config");
+        let cm = Lrc::new(SourceMap::default());
+
+        let data = load(&cm, "export const a = 1;");
+        cache.put(&data, None).unwrap();
+
+        let paths = rewrite_entries(&cache, |payload| payload.truncate(payload.len() / 2));
+        assert_eq!(paths.len(), 1);
+
+        assert!(cache.get(&data.fm, None).unwrap().is_none());
+    })
+}
+
+#[test]
+fn corrupted_entry() {
+    GLOBALS.set(&Globals::new(), || {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path(), "config");
+        let cm = Lrc::new(SourceMap::default());
+
+        let data = load(&cm, "export const a = 1;");
+        cache.put(&data, None).unwrap();
+
+        let paths = rewrite_entries(&cache, |payload| {
+            for b in payload.iter_mut() {
+                *b = 0xff;
+            }
+        });
+        assert_eq!(paths.len(), 1);
+
+        assert!(cache.get(&data.fm, None).unwrap().is_none());
+    })
+}
+
+/// An entry written with another key should not be used even if it's stored at
+/// the path of the current key.
+#[test]
+fn entry_of_other_key() {
+    GLOBALS.set(&Globals::new(), || {
+        let dir = tempfile::tempdir().unwrap();
+        let cm = Lrc::new(SourceMap::default());
+
+        let data = load(&cm, "export const a = 1;");
+        let other = Cache::new(dir.path(), "other");
+        other.put(&data, None).unwrap();
+
+        let cache = Cache::new(dir.path(), "config");
+        fs::rename(other.path_of(&data.fm), cache.path_of(&data.fm)).unwrap();
+
+        assert!(cache.get(&data.fm, None).unwrap().is_none());
+    })
+}
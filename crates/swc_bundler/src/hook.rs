@@ -14,4 +14,13 @@ pub trait Hook: swc_common::sync::Sync + swc_common::sync::Send {
         span: Span,
         module_record: &ModuleRecord,
     ) -> Result<Vec<KeyValueProp>, Error>;
+
+    /// Returns the name of the chunk which should contain the module.
+    ///
+    /// This is called only if [Config::chunks](crate::Config::chunks) is
+    /// [Some], and a module which is not assigned to a chunk is handled
+    /// according to [ChunkConfig](crate::ChunkConfig).
+    fn get_chunk_name(&self, _module_record: &ModuleRecord) -> Result<Option<String>, Error> {
+        Ok(None)
+    }
 }
@@ -1,5 +1,7 @@
+#[cfg(feature = "cache")]
+pub use self::cache::Cache;
 pub use self::{
-    bundler::{Bundle, BundleKind, Bundler, Config, ModuleType},
+    bundler::{Bundle, BundleKind, Bundler, ChunkConfig, Config, HmrConfig, ModuleType},
     hook::{Hook, ModuleRecord},
     id::ModuleId,
     load::{Load, ModuleData},
@@ -7,6 +9,8 @@ pub use self::{
 };
 
 mod bundler;
+#[cfg(feature = "cache")]
+mod cache;
 mod debug;
 mod dep_graph;
 mod hash;
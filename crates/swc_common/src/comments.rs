@@ -81,6 +81,35 @@ pub trait Comments {
 
         ret
     }
+
+    /// Moves comments of `from` to `to`, and returns the span which should be
+    /// used for the new node.
+    ///
+    /// This is used while replacing a node. If `to` is a dummy span, a new
+    /// span is created using [Span::dummy_with_cmt] so the comments are not
+    /// lost.
+    fn move_comments(&self, from: Span, to: Span) -> Span {
+        if from.is_dummy_ignoring_cmt()
+            || (!self.has_leading(from.lo) && !self.has_trailing(from.hi))
+        {
+            return to;
+        }
+
+        let to = if to.is_dummy_ignoring_cmt() {
+            Span::dummy_with_cmt().with_ctxt(to.ctxt)
+        } else {
+            to
+        };
+
+        if from.lo != to.lo {
+            self.move_leading(from.lo, to.lo);
+        }
+        if from.hi != to.hi {
+            self.move_trailing(from.hi, to.hi);
+        }
+
+        to
+    }
 }
 
 macro_rules! delegate {
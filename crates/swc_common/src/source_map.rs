@@ -1121,7 +1121,7 @@ impl SourceMap {
             let lc = *lc;
 
             // TODO: Use correct algorithm
-            if pos >= BytePos(4294967295) {
+            if pos.is_reserved_for_comments() {
                 continue;
             }
 
@@ -11,6 +11,7 @@ use std::{
     hash::{Hash, Hasher},
     ops::{Add, Sub},
     path::PathBuf,
+    sync::atomic::{AtomicU32, Ordering},
 };
 use url::Url;
 
@@ -30,6 +31,7 @@ pub mod hygiene;
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
 )]
+#[cfg_attr(feature = "rkyv", archive_attr(derive(bytecheck::CheckBytes)))]
 pub struct Span {
     #[serde(rename = "start")]
     #[cfg_attr(feature = "rkyv", omit_bounds)]
@@ -62,19 +64,27 @@ pub const DUMMY_SP: Span = Span {
     ctxt: SyntaxContext::empty(),
 };
 
-#[derive(Default)]
 pub struct Globals {
     hygiene_data: Mutex<hygiene::HygieneData>,
+    /// Next position returned by [Span::dummy_with_cmt].
+    dummy_cnt: AtomicU32,
 }
 
 impl Globals {
     pub fn new() -> Globals {
         Globals {
             hygiene_data: Mutex::new(hygiene::HygieneData::new()),
+            dummy_cnt: AtomicU32::new(BytePos::MIN_RESERVED.0),
         }
     }
 }
 
+impl Default for Globals {
+    fn default() -> Self {
+        Globals::new()
+    }
+}
+
 /// Storage for span hygiene data.
 ///
 /// This variable is used to manage identifiers or to identify nodes.
@@ -230,11 +240,42 @@ impl Span {
     }
 
     /// Returns `true` if this is a dummy span with any hygienic context.
+    ///
+    /// Spans created by [Span::dummy_with_cmt] are also considered as dummy.
     #[inline]
     pub fn is_dummy(self) -> bool {
+        self.is_dummy_ignoring_cmt() || self.lo.is_reserved_for_comments()
+    }
+
+    /// Returns `true` only if this is [DUMMY_SP] with any hygienic context.
+    #[inline]
+    pub fn is_dummy_ignoring_cmt(self) -> bool {
         self.lo.0 == 0 && self.hi.0 == 0
     }
 
+    /// Creates a new dummy span which can be used as a key of
+    /// [crate::comments::Comments].
+    ///
+    /// Synthesized nodes don't have a position in the source file, so comments
+    /// can't be attached to them using [DUMMY_SP]. Each call returns a unique
+    /// position which does not belong to any source file, so comments added
+    /// to it are emitted along with the node.
+    pub fn dummy_with_cmt() -> Self {
+        GLOBALS.with(|globals| {
+            let lo = BytePos(globals.dummy_cnt.fetch_add(1, Ordering::SeqCst));
+            assert!(
+                lo.is_reserved_for_comments(),
+                "too many dummy spans were created"
+            );
+
+            Span {
+                lo,
+                hi: lo,
+                ctxt: SyntaxContext::empty(),
+            }
+        })
+    }
+
     /// Returns a new span representing an empty span at the beginning of this
     /// span
     #[inline]
@@ -806,8 +847,23 @@ pub trait Pos {
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
 )]
+#[cfg_attr(feature = "rkyv", archive_attr(derive(bytecheck::CheckBytes)))]
 pub struct BytePos(#[cfg_attr(feature = "rkyv", omit_bounds)] pub u32);
 
+impl BytePos {
+    /// Dummy position. This is reserved for synthesized spans.
+    pub const DUMMY: Self = BytePos(0);
+
+    /// Positions greater than or equal to this are used by
+    /// [Span::dummy_with_cmt] and never belong to a source file.
+    const MIN_RESERVED: Self = BytePos(u32::MAX - (1 << 24));
+
+    /// Returns `true` if this position was created by [Span::dummy_with_cmt].
+    pub const fn is_reserved_for_comments(self) -> bool {
+        self.0 >= Self::MIN_RESERVED.0
+    }
+}
+
 /// A character offset. Because of multibyte utf8 characters, a byte offset
 /// is not equivalent to a character offset. The SourceMap will convert BytePos
 /// values to CharPos values as necessary.
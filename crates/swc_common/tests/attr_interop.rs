@@ -22,6 +22,7 @@ pub struct Tuple(#[span] HasSpan, usize, usize);
     feature = "rkyv",
     archive(bound(serialize = "__S: rkyv::ser::Serializer + rkyv::ser::ScratchSpace"))
 )]
+#[cfg_attr(feature = "rkyv", archive_attr(derive(bytecheck::CheckBytes)))]
 pub struct HasSpan {
     #[cfg_attr(feature = "rkyv", omit_bounds)]
     pub span: Span,
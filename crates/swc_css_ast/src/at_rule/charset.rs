@@ -1,7 +1,8 @@
 use crate::Str;
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 #[ast_node("CharsetRule")]
+#[derive(EqIgnoreSpan)]
 pub struct CharsetRule {
     pub span: Span,
     pub charset: Str,
@@ -1,7 +1,8 @@
 use crate::{Function, Rule};
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 #[ast_node("DocumentRule")]
+#[derive(EqIgnoreSpan)]
 pub struct DocumentRule {
     pub span: Span,
     pub selectors: Vec<Function>,
@@ -1,7 +1,8 @@
 use crate::{Function, Ident, MediaQueryList, Str, UrlValue};
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum ImportHref {
     #[tag("Function")]
     Function(Function),
@@ -14,6 +15,7 @@ pub enum ImportHref {
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum ImportLayerName {
     #[tag("Ident")]
     Ident(Ident),
@@ -23,6 +25,7 @@ pub enum ImportLayerName {
 }
 
 #[ast_node("ImportRule")]
+#[derive(EqIgnoreSpan)]
 pub struct ImportRule {
     pub span: Span,
     pub href: ImportHref,
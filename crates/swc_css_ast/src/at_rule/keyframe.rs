@@ -1,7 +1,8 @@
 use crate::{AtRule, Block, Ident, PercentValue, Str};
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum KeyframesName {
     #[tag("Ident")]
     Ident(Ident),
@@ -10,6 +11,7 @@ pub enum KeyframesName {
 }
 
 #[ast_node("KeyframesRule")]
+#[derive(EqIgnoreSpan)]
 pub struct KeyframesRule {
     pub span: Span,
     pub name: KeyframesName,
@@ -17,6 +19,7 @@ pub struct KeyframesRule {
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub struct KeyframeBlock {
     pub span: Span,
     pub selector: Vec<KeyframeSelector>,
@@ -24,6 +27,7 @@ pub struct KeyframeBlock {
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum KeyframeSelector {
     #[tag("Ident")]
     Ident(Ident),
@@ -32,6 +36,7 @@ pub enum KeyframeSelector {
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum KeyframeBlockRule {
     #[tag("Block")]
     Block(Box<Block>),
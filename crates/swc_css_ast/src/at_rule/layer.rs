@@ -1,19 +1,22 @@
 use crate::{Ident, Rule};
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 #[ast_node("LayerName")]
+#[derive(EqIgnoreSpan)]
 pub struct LayerName {
     pub span: Span,
     pub name: Vec<Ident>,
 }
 
 #[ast_node("LayerNameList")]
+#[derive(EqIgnoreSpan)]
 pub struct LayerNameList {
     pub span: Span,
     pub name_list: Vec<LayerName>,
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum LayerPrelude {
     #[tag("LayerName")]
     Name(LayerName),
@@ -22,6 +25,7 @@ pub enum LayerPrelude {
 }
 
 #[ast_node("LayerRule")]
+#[derive(EqIgnoreSpan)]
 pub struct LayerRule {
     pub span: Span,
     pub prelude: Option<LayerPrelude>,
@@ -3,6 +3,7 @@ use string_enum::StringEnum;
 use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 #[ast_node("MediaRule")]
+#[derive(EqIgnoreSpan)]
 pub struct MediaRule {
     pub span: Span,
     pub media: MediaQueryList,
@@ -10,12 +11,14 @@ pub struct MediaRule {
 }
 
 #[ast_node("MediaQueryList")]
+#[derive(EqIgnoreSpan)]
 pub struct MediaQueryList {
     pub span: Span,
     pub queries: Vec<MediaQuery>,
 }
 
 #[ast_node("MediaQuery")]
+#[derive(EqIgnoreSpan)]
 pub struct MediaQuery {
     pub span: Span,
     pub modifier: Option<Ident>,
@@ -24,6 +27,7 @@ pub struct MediaQuery {
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum MediaConditionType {
     #[tag("MediaCondition")]
     All(MediaCondition),
@@ -33,18 +37,21 @@ pub enum MediaConditionType {
 }
 
 #[ast_node("MediaCondition")]
+#[derive(EqIgnoreSpan)]
 pub struct MediaCondition {
     pub span: Span,
     pub conditions: Vec<MediaConditionAllType>,
 }
 
 #[ast_node("MediaConditionWithoutOr")]
+#[derive(EqIgnoreSpan)]
 pub struct MediaConditionWithoutOr {
     pub span: Span,
     pub conditions: Vec<MediaConditionWithoutOrType>,
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum MediaConditionAllType {
     #[tag("MediaNot")]
     Not(MediaNot),
@@ -60,6 +67,7 @@ pub enum MediaConditionAllType {
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum MediaConditionWithoutOrType {
     #[tag("MediaNot")]
     Not(MediaNot),
@@ -72,24 +80,28 @@ pub enum MediaConditionWithoutOrType {
 }
 
 #[ast_node("MediaNot")]
+#[derive(EqIgnoreSpan)]
 pub struct MediaNot {
     pub span: Span,
     pub condition: MediaInParens,
 }
 
 #[ast_node("MediaAnd")]
+#[derive(EqIgnoreSpan)]
 pub struct MediaAnd {
     pub span: Span,
     pub condition: MediaInParens,
 }
 
 #[ast_node("MediaOr")]
+#[derive(EqIgnoreSpan)]
 pub struct MediaOr {
     pub span: Span,
     pub condition: MediaInParens,
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum MediaInParens {
     #[tag("MediaCondition")]
     MediaCondition(MediaCondition),
@@ -100,6 +112,7 @@ pub enum MediaInParens {
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum MediaFeature {
     #[tag("MediaFeaturePlain")]
     Plain(MediaFeaturePlain),
@@ -115,12 +128,14 @@ pub enum MediaFeature {
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum MediaFeatureName {
     #[tag("Ident")]
     Ident(Ident),
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum MediaFeatureValue {
     #[tag("Number")]
     Number(Num),
@@ -136,6 +151,7 @@ pub enum MediaFeatureValue {
 }
 
 #[ast_node("MediaFeaturePlain")]
+#[derive(EqIgnoreSpan)]
 pub struct MediaFeaturePlain {
     pub span: Span,
     pub name: MediaFeatureName,
@@ -143,6 +159,7 @@ pub struct MediaFeaturePlain {
 }
 
 #[ast_node("MediaFeatureBoolean")]
+#[derive(EqIgnoreSpan)]
 pub struct MediaFeatureBoolean {
     pub span: Span,
     pub name: MediaFeatureName,
@@ -167,6 +184,7 @@ pub enum MediaFeatureRangeComparison {
 }
 
 #[ast_node("MediaFeatureRange")]
+#[derive(EqIgnoreSpan)]
 pub struct MediaFeatureRange {
     pub span: Span,
     pub left: MediaFeatureValue,
@@ -175,6 +193,7 @@ pub struct MediaFeatureRange {
 }
 
 #[ast_node("MediaFeatureRangeInterval")]
+#[derive(EqIgnoreSpan)]
 pub struct MediaFeatureRangeInterval {
     pub span: Span,
     pub left: MediaFeatureValue,
@@ -3,7 +3,7 @@ pub use self::{
 };
 use crate::{Block, Ident, SimpleBlock, Str, UrlValue, Value};
 use is_macro::Is;
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 mod charset;
 mod document;
@@ -15,7 +15,7 @@ mod page;
 mod support;
 
 #[ast_node]
-#[derive(Is)]
+#[derive(Is, EqIgnoreSpan)]
 pub enum AtRule {
     #[tag("CharsetRule")]
     Charset(CharsetRule),
@@ -55,12 +55,14 @@ pub enum AtRule {
 }
 
 #[ast_node("FontFaceRule")]
+#[derive(EqIgnoreSpan)]
 pub struct FontFaceRule {
     pub span: Span,
     pub block: Block,
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum NamespaceUri {
     #[tag("UrlValue")]
     Url(UrlValue),
@@ -70,6 +72,7 @@ pub enum NamespaceUri {
 }
 
 #[ast_node("NamespaceRule")]
+#[derive(EqIgnoreSpan)]
 pub struct NamespaceRule {
     pub span: Span,
     pub prefix: Option<Ident>,
@@ -77,12 +80,14 @@ pub struct NamespaceRule {
 }
 
 #[ast_node("ViewportRule")]
+#[derive(EqIgnoreSpan)]
 pub struct ViewportRule {
     pub span: Span,
     pub block: Block,
 }
 
 #[ast_node("UnknownAtRule")]
+#[derive(EqIgnoreSpan)]
 pub struct UnknownAtRule {
     pub span: Span,
     pub name: Ident,
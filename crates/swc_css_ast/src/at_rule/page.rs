@@ -1,7 +1,8 @@
 use crate::{Declaration, Ident, SelectorList};
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 #[ast_node("PageRule")]
+#[derive(EqIgnoreSpan)]
 pub struct PageRule {
     pub span: Span,
 
@@ -11,6 +12,7 @@ pub struct PageRule {
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub struct PageSelector {
     pub span: Span,
 
@@ -20,12 +22,14 @@ pub struct PageSelector {
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub struct PageRuleBlock {
     pub span: Span,
     pub items: Vec<PageRuleBlockItem>,
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum PageRuleBlockItem {
     #[tag("DeclBlock")]
     Declaration(Box<Declaration>),
@@ -35,6 +39,7 @@ pub enum PageRuleBlockItem {
 }
 
 #[ast_node("NestedPageRule")]
+#[derive(EqIgnoreSpan)]
 pub struct NestedPageRule {
     pub span: Span,
     pub prelude: SelectorList,
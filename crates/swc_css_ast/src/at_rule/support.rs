@@ -1,7 +1,8 @@
 use crate::{Declaration, Rule};
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 #[ast_node("SupportsRule")]
+#[derive(EqIgnoreSpan)]
 pub struct SupportsRule {
     pub span: Span,
 
@@ -11,6 +12,7 @@ pub struct SupportsRule {
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum SupportQuery {
     #[tag("NotSupportQuery")]
     Not(NotSupportQuery),
@@ -29,12 +31,14 @@ pub enum SupportQuery {
 }
 
 #[ast_node("NotSupportQuery")]
+#[derive(EqIgnoreSpan)]
 pub struct NotSupportQuery {
     pub span: Span,
     pub query: Box<SupportQuery>,
 }
 
 #[ast_node("AndSupportQuery")]
+#[derive(EqIgnoreSpan)]
 pub struct AndSupportQuery {
     pub span: Span,
     pub left: Box<SupportQuery>,
@@ -42,6 +46,7 @@ pub struct AndSupportQuery {
 }
 
 #[ast_node("OrSupportQuery")]
+#[derive(EqIgnoreSpan)]
 pub struct OrSupportQuery {
     pub span: Span,
     pub left: Box<SupportQuery>,
@@ -49,6 +54,7 @@ pub struct OrSupportQuery {
 }
 
 #[ast_node("ParenSupportQuery")]
+#[derive(EqIgnoreSpan)]
 pub struct ParenSupportQuery {
     pub span: Span,
     pub query: Box<SupportQuery>,
@@ -1,7 +1,8 @@
 use swc_atoms::JsWord;
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 #[ast_node("Identifier")]
+#[derive(EqIgnoreSpan)]
 pub struct Ident {
     pub span: Span,
     pub value: JsWord,
@@ -10,6 +11,7 @@ pub struct Ident {
 
 /// Quoted string.
 #[ast_node("String")]
+#[derive(EqIgnoreSpan)]
 pub struct Str {
     pub span: Span,
     pub value: JsWord,
@@ -17,6 +19,7 @@ pub struct Str {
 }
 
 #[ast_node("Number")]
+#[derive(EqIgnoreSpan)]
 pub struct Num {
     pub span: Span,
     pub value: f64,
@@ -3,7 +3,7 @@
 //! AST definitions for CSS.
 pub use self::{at_rule::*, base::*, selector::*, style_rule::*, token::*, value::*};
 use is_macro::Is;
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 mod at_rule;
 mod base;
@@ -13,13 +13,14 @@ mod token;
 mod value;
 
 #[ast_node("Stylesheet")]
+#[derive(EqIgnoreSpan)]
 pub struct Stylesheet {
     pub span: Span,
     pub rules: Vec<Rule>,
 }
 
 #[ast_node]
-#[derive(Is)]
+#[derive(Is, EqIgnoreSpan)]
 pub enum Rule {
     #[tag("QualifiedRule")]
     QualifiedRule(QualifiedRule),
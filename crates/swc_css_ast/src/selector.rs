@@ -5,19 +5,21 @@ use swc_atoms::JsWord;
 use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 #[ast_node("SelectorList")]
+#[derive(EqIgnoreSpan)]
 pub struct SelectorList {
     pub span: Span,
     pub children: Vec<ComplexSelector>,
 }
 
 #[ast_node("ComplexSelector")]
+#[derive(EqIgnoreSpan)]
 pub struct ComplexSelector {
     pub span: Span,
     pub children: Vec<ComplexSelectorChildren>,
 }
 
 #[ast_node]
-#[derive(Is)]
+#[derive(Is, EqIgnoreSpan)]
 pub enum ComplexSelectorChildren {
     #[tag("CompoundSelector")]
     CompoundSelector(CompoundSelector),
@@ -27,6 +29,7 @@ pub enum ComplexSelectorChildren {
 
 /// e.g. `foo.c1.c2`
 #[ast_node("CompoundSelector")]
+#[derive(EqIgnoreSpan)]
 pub struct CompoundSelector {
     pub span: Span,
     /// "&"
@@ -36,6 +39,7 @@ pub struct CompoundSelector {
 }
 
 #[ast_node("Combinator")]
+#[derive(EqIgnoreSpan)]
 pub struct Combinator {
     pub span: Span,
     pub value: CombinatorValue,
@@ -57,11 +61,13 @@ pub enum CombinatorValue {
 }
 
 #[ast_node("NestingSelector")]
+#[derive(EqIgnoreSpan)]
 pub struct NestingSelector {
     pub span: Span,
 }
 
 #[ast_node("TypeSelector")]
+#[derive(EqIgnoreSpan)]
 pub struct TypeSelector {
     pub span: Span,
     ///	If present, this is an identifier or "*" and is followed by a "|"
@@ -72,7 +78,7 @@ pub struct TypeSelector {
 }
 
 #[ast_node]
-#[derive(Is)]
+#[derive(Is, EqIgnoreSpan)]
 pub enum SubclassSelector {
     #[tag("IdSelector")]
     Id(IdSelector),
@@ -115,7 +121,7 @@ pub enum AttrSelectorMatcher {
 }
 
 #[ast_node]
-#[derive(Is)]
+#[derive(Is, EqIgnoreSpan)]
 pub enum AttrSelectorValue {
     #[tag("String")]
     Str(Str),
@@ -125,6 +131,7 @@ pub enum AttrSelectorValue {
 }
 
 #[ast_node("AttributeSelector")]
+#[derive(EqIgnoreSpan)]
 pub struct AttrSelector {
     pub span: Span,
     pub prefix: Option<Ident>,
@@ -135,7 +142,7 @@ pub struct AttrSelector {
 }
 
 #[ast_node]
-#[derive(Is)]
+#[derive(Is, EqIgnoreSpan)]
 pub enum PseudoSelectorChildren {
     #[tag("Nth")]
     Nth(Nth),
@@ -145,6 +152,7 @@ pub enum PseudoSelectorChildren {
 }
 
 #[ast_node("Nth")]
+#[derive(EqIgnoreSpan)]
 pub struct Nth {
     pub span: Span,
     pub nth: NthValue,
@@ -152,6 +160,7 @@ pub struct Nth {
 }
 
 #[ast_node("AnPlusB")]
+#[derive(EqIgnoreSpan)]
 pub struct AnPlusB {
     pub span: Span,
     pub a: Option<i32>,
@@ -161,7 +170,7 @@ pub struct AnPlusB {
 }
 
 #[ast_node]
-#[derive(Is)]
+#[derive(Is, EqIgnoreSpan)]
 pub enum NthValue {
     #[tag("AnPlusB")]
     AnPlusB(AnPlusB),
@@ -171,6 +180,7 @@ pub enum NthValue {
 }
 
 #[ast_node("PseudoClassSelector")]
+#[derive(EqIgnoreSpan)]
 pub struct PseudoClassSelector {
     pub span: Span,
     pub name: Ident,
@@ -178,6 +188,7 @@ pub struct PseudoClassSelector {
 }
 
 #[ast_node("PseudoElementSelector")]
+#[derive(EqIgnoreSpan)]
 pub struct PseudoElementSelector {
     pub span: Span,
     pub name: Ident,
@@ -185,6 +196,7 @@ pub struct PseudoElementSelector {
 }
 
 #[ast_node("IdSelector")]
+#[derive(EqIgnoreSpan)]
 pub struct IdSelector {
     pub span: Span,
     /// Does not include `#`
@@ -192,6 +204,7 @@ pub struct IdSelector {
 }
 
 #[ast_node("ClassSelector")]
+#[derive(EqIgnoreSpan)]
 pub struct ClassSelector {
     pub span: Span,
     /// Does not include `.`
@@ -199,6 +212,7 @@ pub struct ClassSelector {
 }
 
 #[ast_node("TagSelector")]
+#[derive(EqIgnoreSpan)]
 pub struct TagSelector {
     pub span: Span,
     pub text: Ident,
@@ -206,6 +220,7 @@ pub struct TagSelector {
 
 /// Type for `@top-center`. Allowwed in only some contexts.
 #[ast_node("AtSelector")]
+#[derive(EqIgnoreSpan)]
 pub struct AtSelector {
     pub span: Span,
     pub text: Ident,
@@ -1,7 +1,8 @@
 use crate::{AtRule, Ident, SelectorList, Tokens, Value};
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 #[ast_node("QualifiedRule")]
+#[derive(EqIgnoreSpan)]
 pub struct QualifiedRule {
     pub span: Span,
     pub prelude: SelectorList,
@@ -9,12 +10,14 @@ pub struct QualifiedRule {
 }
 
 #[ast_node("Block")]
+#[derive(EqIgnoreSpan)]
 pub struct Block {
     pub span: Span,
     pub value: Vec<DeclarationBlockItem>,
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum DeclarationBlockItem {
     #[tag("Tokens")]
     Invalid(Tokens),
@@ -25,6 +28,7 @@ pub enum DeclarationBlockItem {
 }
 
 #[ast_node("Declaration")]
+#[derive(EqIgnoreSpan)]
 pub struct Declaration {
     pub span: Span,
     pub property: Ident,
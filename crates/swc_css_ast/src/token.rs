@@ -1,21 +1,22 @@
 use serde::{Deserialize, Serialize};
 use swc_atoms::JsWord;
-use swc_common::{ast_node, Span};
+use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 #[ast_node("Tokens")]
-#[derive(Default)]
+#[derive(Default, EqIgnoreSpan)]
 pub struct Tokens {
     pub span: Span,
     pub tokens: Vec<TokenAndSpan>,
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub struct TokenAndSpan {
     pub span: Span,
     pub token: Token,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, EqIgnoreSpan)]
 pub enum NumberType {
     #[serde(rename = "integer")]
     Integer,
@@ -23,7 +24,7 @@ pub enum NumberType {
     Number,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, EqIgnoreSpan)]
 pub enum Token {
     Ident {
         value: JsWord,
@@ -4,6 +4,7 @@ use swc_atoms::JsWord;
 use swc_common::{ast_node, EqIgnoreSpan, Span};
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub enum Value {
     #[tag("SimpleBlock")]
     SimpleBlock(SimpleBlock),
@@ -59,7 +60,7 @@ pub enum Value {
 
 /// List of values separated by a space.
 #[ast_node("SpaceValues")]
-#[derive(Default)]
+#[derive(Default, EqIgnoreSpan)]
 pub struct SpaceValues {
     pub span: Span,
     pub values: Vec<Value>,
@@ -67,13 +68,14 @@ pub struct SpaceValues {
 
 /// List of values separated by a space.
 #[ast_node("CommaValues")]
-#[derive(Default)]
+#[derive(Default, EqIgnoreSpan)]
 pub struct CommaValues {
     pub span: Span,
     pub values: Vec<Value>,
 }
 
 #[ast_node("BinValue")]
+#[derive(EqIgnoreSpan)]
 pub struct BinValue {
     pub span: Span,
 
@@ -85,6 +87,7 @@ pub struct BinValue {
 }
 
 #[ast_node("Function")]
+#[derive(EqIgnoreSpan)]
 pub struct Function {
     /// Span starting from the `lo` of identifier and to the end of `)`.
     pub span: Span,
@@ -93,6 +96,7 @@ pub struct Function {
 }
 
 #[ast_node("RoundBracketBlock")]
+#[derive(EqIgnoreSpan)]
 pub struct RoundBracketBlock {
     /// Includes `(` and `)`.
     pub span: Span,
@@ -100,6 +104,7 @@ pub struct RoundBracketBlock {
 }
 
 #[ast_node("SquareBracketBlock")]
+#[derive(EqIgnoreSpan)]
 pub struct SquareBracketBlock {
     /// Includes `[` and `]`.
     pub span: Span,
@@ -107,6 +112,7 @@ pub struct SquareBracketBlock {
 }
 
 #[ast_node("HashValue")]
+#[derive(EqIgnoreSpan)]
 pub struct HashValue {
     /// Includes `#`
     pub span: Span,
@@ -117,6 +123,7 @@ pub struct HashValue {
 }
 
 #[ast_node]
+#[derive(EqIgnoreSpan)]
 pub struct Unit {
     pub span: Span,
     pub value: JsWord,
@@ -124,6 +131,7 @@ pub struct Unit {
 }
 
 #[ast_node("UnitValue")]
+#[derive(EqIgnoreSpan)]
 pub struct UnitValue {
     pub span: Span,
     pub value: Num,
@@ -131,6 +139,7 @@ pub struct UnitValue {
 }
 
 #[ast_node("PercentValue")]
+#[derive(EqIgnoreSpan)]
 pub struct PercentValue {
     pub span: Span,
     pub value: Num,
@@ -150,12 +159,14 @@ pub enum BinOp {
 
 /// Values starting with `{` and ending with `}`.
 #[ast_node("BraceValue")]
+#[derive(EqIgnoreSpan)]
 pub struct BraceValue {
     pub span: Span,
     pub value: Box<Value>,
 }
 
 #[ast_node("AtTextValue")]
+#[derive(EqIgnoreSpan)]
 pub struct AtTextValue {
     pub span: Span,
     /// Includes `@`.
@@ -164,6 +175,7 @@ pub struct AtTextValue {
 }
 
 #[ast_node("UrlValue")]
+#[derive(EqIgnoreSpan)]
 pub struct UrlValue {
     pub span: Span,
     pub url: JsWord,
@@ -171,6 +183,7 @@ pub struct UrlValue {
 }
 
 #[ast_node("SimpleBlock")]
+#[derive(EqIgnoreSpan)]
 pub struct SimpleBlock {
     pub span: Span,
     pub name: char,
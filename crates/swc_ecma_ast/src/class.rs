@@ -254,6 +254,7 @@ pub struct Decorator {
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
 )]
+#[cfg_attr(feature = "rkyv", archive_attr(derive(bytecheck::CheckBytes)))]
 pub enum MethodKind {
     #[serde(rename = "method")]
     Method,
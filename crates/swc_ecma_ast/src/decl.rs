@@ -109,6 +109,7 @@ impl Take for VarDecl {
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
 )]
+#[cfg_attr(feature = "rkyv", archive_attr(derive(bytecheck::CheckBytes)))]
 pub enum VarDeclKind {
     /// `var`
     Var,
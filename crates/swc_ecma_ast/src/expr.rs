@@ -152,6 +152,22 @@ pub enum Expr {
     #[tag("OptionalChainingExpression")]
     OptChain(OptChainExpr),
 
+    #[tag("PipelineExpression")]
+    Pipeline(PipelineExpr),
+
+    #[tag("TopicReference")]
+    TopicRef(TopicRef),
+
+    #[tag("DoExpression")]
+    #[is(name = "do_expr")]
+    Do(DoExpr),
+
+    #[tag("RecordExpression")]
+    Record(RecordLit),
+
+    #[tag("TupleExpression")]
+    Tuple(TupleLit),
+
     #[tag("Invalid")]
     Invalid(Invalid),
 }
@@ -627,6 +643,7 @@ pub struct MetaPropExpr {
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
 )]
+#[cfg_attr(feature = "rkyv", archive_attr(derive(bytecheck::CheckBytes)))]
 pub enum MetaPropKind {
     /// `new.target`
     NewTarget,
@@ -644,6 +661,64 @@ pub struct AwaitExpr {
     pub arg: Box<Expr>,
 }
 
+/// Hack-style pipeline expression, `left |> right`.
+///
+/// `right` refers to the value of `left` using [TopicRef].
+#[ast_node("PipelineExpression")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct PipelineExpr {
+    pub span: Span,
+
+    pub left: Box<Expr>,
+
+    pub right: Box<Expr>,
+}
+
+/// The topic reference of a pipeline expression, like `%` in `a |> f(%)`.
+#[ast_node("TopicReference")]
+#[derive(Eq, Hash, Copy, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct TopicRef {
+    pub span: Span,
+}
+
+/// `do { ... }`
+///
+/// The value of the expression is the completion value of `body`.
+#[ast_node("DoExpression")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DoExpr {
+    pub span: Span,
+
+    pub body: BlockStmt,
+}
+
+/// Record literal, `#{ a: 1 }`.
+///
+/// Properties are always key-value pairs, shorthands or spreads.
+#[ast_node("RecordExpression")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RecordLit {
+    pub span: Span,
+
+    #[serde(default, rename = "properties")]
+    pub props: Vec<PropOrSpread>,
+}
+
+/// Tuple literal, `#[1, 2]`.
+#[ast_node("TupleExpression")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct TupleLit {
+    pub span: Span,
+
+    #[serde(default, rename = "elements")]
+    pub elems: Vec<ExprOrSpread>,
+}
+
 #[ast_node("TemplateLiteral")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -795,13 +870,25 @@ impl Take for Import {
     feature = "rkyv",
     archive(bound(serialize = "__S: rkyv::ser::Serializer + rkyv::ser::ScratchSpace"))
 )]
+#[cfg_attr(
+    feature = "rkyv",
+    archive_attr(
+        derive(bytecheck::CheckBytes),
+        check_bytes(
+            bound = "__C: rkyv::validation::ArchiveContext, <__C as rkyv::Fallible>::Error: \
+                     std::error::Error"
+        )
+    )
+)]
 pub struct ExprOrSpread {
     #[serde(default)]
     #[cfg_attr(feature = "rkyv", omit_bounds)]
+    #[cfg_attr(feature = "rkyv", archive_attr(omit_bounds))]
     pub spread: Option<Span>,
 
     #[serde(rename = "expression")]
     #[cfg_attr(feature = "rkyv", omit_bounds)]
+    #[cfg_attr(feature = "rkyv", archive_attr(omit_bounds))]
     pub expr: Box<Expr>,
 }
 
@@ -18,13 +18,25 @@ use unicode_xid::UnicodeXID;
     feature = "rkyv",
     archive(bound(serialize = "__S: rkyv::ser::Serializer + rkyv::ser::ScratchSpace"))
 )]
+#[cfg_attr(
+    feature = "rkyv",
+    archive_attr(
+        derive(bytecheck::CheckBytes),
+        check_bytes(
+            bound = "__C: rkyv::validation::ArchiveContext, <__C as rkyv::Fallible>::Error: \
+                     std::error::Error"
+        )
+    )
+)]
 pub struct BindingIdent {
     #[span]
     #[serde(flatten)]
     #[cfg_attr(feature = "rkyv", omit_bounds)]
+    #[cfg_attr(feature = "rkyv", archive_attr(omit_bounds))]
     pub id: Ident,
     #[serde(default, rename = "typeAnnotation")]
     #[cfg_attr(feature = "rkyv", omit_bounds)]
+    #[cfg_attr(feature = "rkyv", archive_attr(omit_bounds))]
     pub type_ann: Option<TsTypeAnn>,
 }
 
@@ -17,10 +17,11 @@ pub use self::{
     decl::{ClassDecl, Decl, FnDecl, VarDecl, VarDeclKind, VarDeclarator},
     expr::{
         ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BlockStmtOrExpr, CallExpr, Callee,
-        ClassExpr, CondExpr, Expr, ExprOrSpread, FnExpr, Import, MemberExpr, MemberProp,
+        ClassExpr, CondExpr, DoExpr, Expr, ExprOrSpread, FnExpr, Import, MemberExpr, MemberProp,
         MetaPropExpr, MetaPropKind, NewExpr, ObjectLit, OptChainExpr, ParenExpr, PatOrExpr,
-        PropOrSpread, SeqExpr, SpreadElement, Super, SuperProp, SuperPropExpr, TaggedTpl, ThisExpr,
-        Tpl, TplElement, UnaryExpr, UpdateExpr, YieldExpr,
+        PipelineExpr, PropOrSpread, RecordLit, SeqExpr, SpreadElement, Super, SuperProp,
+        SuperPropExpr, TaggedTpl, ThisExpr, TopicRef, Tpl, TplElement, TupleLit, UnaryExpr,
+        UpdateExpr, YieldExpr,
     },
     function::{Function, Param, ParamOrTsParamProp},
     ident::{BindingIdent, Id, Ident, IdentExt, PrivateName},
@@ -88,6 +89,10 @@ mod prop;
 mod stmt;
 mod typescript;
 
+/// Version of `swc_ecma_ast`, which can be used to invalidate serialized
+/// asts.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
 /// Represents a invalid node.
 #[ast_node("Invalid")]
 #[derive(Eq, Hash, Copy, EqIgnoreSpan)]
@@ -174,6 +174,7 @@ impl Take for Str {
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
 )]
+#[cfg_attr(feature = "rkyv", archive_attr(derive(bytecheck::CheckBytes)))]
 pub enum StrKind {
     /// Span of string points to original source code, and codegen should use
     /// it.
@@ -7,6 +7,7 @@ use swc_common::EqIgnoreSpan;
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
 )]
+#[cfg_attr(feature = "rkyv", archive_attr(derive(bytecheck::CheckBytes)))]
 pub enum BinaryOp {
     /// `==`
     EqEq,
@@ -112,6 +113,7 @@ impl BinaryOp {
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
 )]
+#[cfg_attr(feature = "rkyv", archive_attr(derive(bytecheck::CheckBytes)))]
 pub enum AssignOp {
     /// `=`
     Assign,
@@ -181,6 +183,7 @@ impl AssignOp {
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
 )]
+#[cfg_attr(feature = "rkyv", archive_attr(derive(bytecheck::CheckBytes)))]
 pub enum UpdateOp {
     /// `++`
     PlusPlus,
@@ -194,6 +197,7 @@ pub enum UpdateOp {
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
 )]
+#[cfg_attr(feature = "rkyv", archive_attr(derive(bytecheck::CheckBytes)))]
 pub enum UnaryOp {
     /// `-`
     Minus,
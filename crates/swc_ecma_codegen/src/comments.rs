@@ -65,14 +65,29 @@ where
         };
 
         if is_hi {
+            // Positions of synthesized spans are not related to each other.
+            if pos.is_reserved_for_comments() {
+                return Ok(());
+            }
             pos = pos - BytePos(1)
         }
 
         write_comments!(self, false, comments.take_leading(pos))
     }
 
+    /// Emits leading comments of a punctuation located `offset` bytes before
+    /// `pos`.
+    pub(super) fn emit_leading_comments_of_punct(&mut self, pos: BytePos, offset: u32) -> Result {
+        if pos < BytePos(offset) || pos.is_reserved_for_comments() {
+            return Ok(());
+        }
+
+        self.emit_leading_comments(pos - BytePos(offset), false)
+    }
+
     pub(super) fn emit_leading_comments_of_span(&mut self, span: Span, is_hi: bool) -> Result {
-        if span.is_dummy() {
+        // Spans created by `Span::dummy_with_cmt` may have comments.
+        if span.is_dummy_ignoring_cmt() {
             return Ok(());
         }
 
@@ -37,6 +37,12 @@ where
         for dec in &node.class.decorators {
             emit!(dec);
         }
+
+        if node.class.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
         keyword!("class");
         space!();
         emit!(node.ident);
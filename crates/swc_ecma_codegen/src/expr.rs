@@ -195,4 +195,26 @@ mod tests {
         assert_min("(class C { 1n = 1 });", "(class C{1n=1})");
         assert_min("(class C { 1n () { } });", "(class C{1n(){}})");
     }
+
+    #[test]
+    fn pipeline_expr() {
+        assert_min("x |> f(%)", "x|>f(%)");
+        assert_min("x |> % + 1 |> g(%, %)", "x|>%+1|>g(%,%)");
+        assert_min("x |> (% ? a : b)", "x|>(%?a:b)");
+    }
+
+    #[test]
+    fn do_expr() {
+        assert_min("x = do { 1 }", "x=do{1}");
+        assert_min(
+            "x = do { if (a) { 1 } else { 2 } }",
+            "x=do{if(a){1}else{2}}",
+        );
+    }
+
+    #[test]
+    fn record_and_tuple() {
+        assert_min("x = #{ a: 1, b, ...c }", "x=#{a:1,b,...c}");
+        assert_min("x = #[1, #[2], ...a]", "x=#[1,#[2],...a]");
+    }
 }
@@ -1,7 +1,7 @@
 #![recursion_limit = "1024"]
 #![allow(unused_variables)]
 
-pub use self::config::Config;
+pub use self::{config::Config, reprint::Reprint};
 use self::{
     list::ListFormat,
     text_writer::WriteJs,
@@ -24,6 +24,7 @@ mod decl;
 mod expr;
 mod jsx;
 pub mod list;
+mod reprint;
 mod stmt;
 #[cfg(test)]
 mod tests;
@@ -81,26 +82,44 @@ where
 
     #[emitter]
     pub fn emit_module(&mut self, node: &Module) -> Result {
+        self.start_reprint(node)?;
+
         if let Some(ref shebang) = node.shebang {
             punct!("#!");
             self.wr.write_str_lit(DUMMY_SP, &*shebang)?;
             self.wr.write_line()?;
         }
+        let mut prev: Option<Span> = None;
         for stmt in &node.body {
+            if let Some(prev) = prev {
+                self.emit_blank_line_between(prev, stmt.span())?;
+            }
             emit!(stmt);
+            prev = Some(stmt.span());
         }
+
+        self.end_reprint();
     }
 
     #[emitter]
     pub fn emit_script(&mut self, node: &Script) -> Result {
+        self.start_reprint(node)?;
+
         if let Some(ref shebang) = node.shebang {
             punct!("#!");
             self.wr.write_str_lit(DUMMY_SP, &*shebang)?;
             self.wr.write_line()?;
         }
+        let mut prev: Option<Span> = None;
         for stmt in &node.body {
+            if let Some(prev) = prev {
+                self.emit_blank_line_between(prev, stmt.span())?;
+            }
             emit!(stmt);
+            prev = Some(stmt.span());
         }
+
+        self.end_reprint();
     }
 
     #[emitter]
@@ -115,16 +134,19 @@ where
     fn emit_module_decl(&mut self, node: &ModuleDecl) -> Result {
         self.emit_leading_comments_of_span(node.span(), false)?;
 
-        match *node {
-            ModuleDecl::Import(ref d) => emit!(d),
-            ModuleDecl::ExportDecl(ref d) => emit!(d),
-            ModuleDecl::ExportNamed(ref d) => emit!(d),
-            ModuleDecl::ExportDefaultDecl(ref d) => emit!(d),
-            ModuleDecl::ExportDefaultExpr(ref n) => emit!(n),
-            ModuleDecl::ExportAll(ref d) => emit!(d),
-            ModuleDecl::TsExportAssignment(ref n) => emit!(n),
-            ModuleDecl::TsImportEquals(ref n) => emit!(n),
-            ModuleDecl::TsNamespaceExport(ref n) => emit!(n),
+        if !self.emit_original(node, reprint::module_decl_may_end_with_asi(node))? {
+            match *node {
+                ModuleDecl::Import(ref d) => emit!(d),
+                ModuleDecl::ExportDecl(ref d) => emit!(d),
+                ModuleDecl::ExportNamed(ref d) => emit!(d),
+                ModuleDecl::ExportDefaultDecl(ref d) => emit!(d),
+                ModuleDecl::ExportDefaultExpr(ref n) => emit!(n),
+                ModuleDecl::ExportAll(ref d) => emit!(d),
+                ModuleDecl::TsExportAssignment(ref n) => emit!(n),
+                ModuleDecl::TsImportEquals(ref n) => emit!(n),
+                ModuleDecl::TsNamespaceExport(ref n) => emit!(n),
+            }
+            self.end_original();
         }
 
         self.emit_trailing_comments_of_pos(node.span().hi, true, true)?;
@@ -594,6 +616,10 @@ where
 
     #[emitter]
     fn emit_expr(&mut self, node: &Expr) -> Result {
+        if self.emit_original(node, false)? {
+            return Ok(());
+        }
+
         match *node {
             Expr::Array(ref n) => emit!(n),
             Expr::Arrow(ref n) => emit!(n),
@@ -632,8 +658,14 @@ where
             Expr::TsTypeAssertion(ref n) => emit!(n),
             Expr::TsConstAssertion(ref n) => emit!(n),
             Expr::OptChain(ref n) => emit!(n),
+            Expr::Pipeline(ref n) => emit!(n),
+            Expr::TopicRef(ref n) => emit!(n),
+            Expr::Do(ref n) => emit!(n),
+            Expr::Record(ref n) => emit!(n),
+            Expr::Tuple(ref n) => emit!(n),
             Expr::Invalid(ref n) => emit!(n),
         }
+        self.end_original();
 
         if self.comments.is_some() {
             self.emit_trailing_comments_of_pos(node.span().hi, true, true)?;
@@ -724,27 +756,19 @@ where
             MemberProp::Computed(computed) => emit!(computed),
             MemberProp::Ident(ident) => {
                 if self.needs_2dots_for_property_access(&node.obj) {
-                    if node.prop.span().lo() >= BytePos(2) {
-                        self.emit_leading_comments(node.prop.span().lo() - BytePos(2), false)?;
-                    }
+                    self.emit_leading_comments_of_punct(node.prop.span().lo(), 2)?;
                     punct!(".");
                 }
-                if node.prop.span().lo() >= BytePos(1) {
-                    self.emit_leading_comments(node.prop.span().lo() - BytePos(1), false)?;
-                }
+                self.emit_leading_comments_of_punct(node.prop.span().lo(), 1)?;
                 punct!(".");
                 emit!(ident);
             }
             MemberProp::PrivateName(private) => {
                 if self.needs_2dots_for_property_access(&node.obj) {
-                    if node.prop.span().lo() >= BytePos(2) {
-                        self.emit_leading_comments(node.prop.span().lo() - BytePos(2), false)?;
-                    }
+                    self.emit_leading_comments_of_punct(node.prop.span().lo(), 2)?;
                     punct!(".");
                 }
-                if node.prop.span().lo() >= BytePos(1) {
-                    self.emit_leading_comments(node.prop.span().lo() - BytePos(1), false)?;
-                }
+                self.emit_leading_comments_of_punct(node.prop.span().lo(), 1)?;
                 punct!(".");
                 emit!(private);
             }
@@ -785,9 +809,7 @@ where
         match &node.prop {
             SuperProp::Computed(computed) => emit!(computed),
             SuperProp::Ident(i) => {
-                if node.prop.span().lo() >= BytePos(1) {
-                    self.emit_leading_comments(node.prop.span().lo() - BytePos(1), false)?;
-                }
+                self.emit_leading_comments_of_punct(node.prop.span().lo(), 1)?;
                 punct!(".");
                 emit!(i);
             }
@@ -1038,6 +1060,18 @@ where
                 }
             }
             emit!(node.super_class);
+            emit!(node.super_type_params);
+        }
+
+        if !node.implements.is_empty() {
+            space!();
+            keyword!("implements");
+            space!();
+            self.emit_list(
+                node.span,
+                Some(&node.implements),
+                ListFormat::HeritageClauseTypes,
+            )?;
         }
 
         formatting_space!();
@@ -1048,6 +1082,16 @@ where
 
     #[emitter]
     fn emit_class_memeber(&mut self, node: &ClassMember) -> Result {
+        let semi = match node {
+            ClassMember::ClassProp(..)
+            | ClassMember::PrivateProp(..)
+            | ClassMember::TsIndexSignature(..) => true,
+            _ => false,
+        };
+        if self.emit_original(node, semi)? {
+            return Ok(());
+        }
+
         match *node {
             ClassMember::Constructor(ref n) => emit!(n),
             ClassMember::ClassProp(ref n) => emit!(n),
@@ -1058,6 +1102,7 @@ where
             ClassMember::Empty(ref n) => emit!(n),
             ClassMember::StaticBlock(ref n) => emit!(n),
         }
+        self.end_original();
     }
 
     #[emitter]
@@ -1114,9 +1159,24 @@ where
 
         self.emit_accesibility(n.accessibility)?;
 
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
+        if n.is_override && !n.is_static {
+            keyword!("override");
+            space!();
+        }
+
         if n.is_static {
             keyword!("static");
 
+            if n.is_override {
+                space!();
+                keyword!("override");
+            }
+
             let starts_with_alpha_num = match n.kind {
                 MethodKind::Method => {
                     if n.function.is_async {
@@ -1173,6 +1233,10 @@ where
             }
         }
 
+        if n.is_optional && !is_optional_ident(&n.key) {
+            punct!("?");
+        }
+
         if let Some(type_params) = &n.function.type_params {
             emit!(type_params);
         }
@@ -1245,15 +1309,30 @@ where
     fn emit_class_prop(&mut self, n: &ClassProp) -> Result {
         self.emit_leading_comments_of_span(n.span(), false)?;
 
+        if n.declare {
+            keyword!("declare");
+            space!();
+        }
+
         if n.accessibility != Some(Accessibility::Public) {
             self.emit_accesibility(n.accessibility)?;
         }
 
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
         if n.is_static {
             keyword!("static");
             space!();
         }
 
+        if n.is_override {
+            keyword!("override");
+            space!();
+        }
+
         if n.readonly {
             keyword!("readonly");
             space!()
@@ -1261,6 +1340,14 @@ where
 
         emit!(n.key);
 
+        if n.is_optional && !is_optional_ident(&n.key) {
+            punct!("?");
+        }
+
+        if n.definite {
+            punct!("!");
+        }
+
         if let Some(ty) = &n.type_ann {
             punct!(":");
             space!();
@@ -1355,6 +1442,26 @@ where
         emit!(node.alt);
     }
 
+    #[emitter]
+    fn emit_pipeline_expr(&mut self, node: &PipelineExpr) -> Result {
+        self.emit_leading_comments_of_span(node.span(), false)?;
+
+        emit!(node.left);
+        formatting_space!();
+        punct!("|>");
+        formatting_space!();
+        emit!(node.right);
+    }
+
+    /// The topic token is not stored in the ast, so topic references are
+    /// always printed as `%`.
+    #[emitter]
+    fn emit_topic_ref(&mut self, node: &TopicRef) -> Result {
+        self.emit_leading_comments_of_span(node.span(), false)?;
+
+        punct!(node.span, "%");
+    }
+
     #[emitter]
     fn emit_fn_expr(&mut self, n: &FnExpr) -> Result {
         self.emit_leading_comments_of_span(n.span(), false)?;
@@ -1652,8 +1759,63 @@ where
         };
     }
 
+    #[emitter]
+    fn emit_record_lit(&mut self, node: &RecordLit) -> Result {
+        self.emit_leading_comments_of_span(node.span(), false)?;
+
+        punct!("#{");
+
+        let emit_new_line = !self.cfg.minify
+            && !(node.props.is_empty() && is_empty_comments(&node.span(), &self.comments));
+
+        if emit_new_line {
+            self.wr.write_line()?;
+        }
+
+        let mut list_format =
+            ListFormat::ObjectLiteralExpressionProperties | ListFormat::CanSkipTrailingComma;
+
+        if !emit_new_line {
+            list_format -= ListFormat::MultiLine | ListFormat::Indented;
+        }
+
+        self.emit_list(node.span(), Some(&node.props), list_format)?;
+
+        if emit_new_line {
+            self.wr.write_line()?;
+        }
+
+        punct!("}");
+    }
+
+    #[emitter]
+    fn emit_tuple_lit(&mut self, node: &TupleLit) -> Result {
+        self.emit_leading_comments_of_span(node.span(), false)?;
+
+        punct!("#[");
+        self.emit_list(
+            node.span(),
+            Some(&node.elems),
+            ListFormat::ArrayLiteralExpressionElements,
+        )?;
+        punct!("]");
+    }
+
+    #[emitter]
+    fn emit_do_expr(&mut self, node: &DoExpr) -> Result {
+        self.emit_leading_comments_of_span(node.span(), false)?;
+
+        keyword!("do");
+        formatting_space!();
+        emit!(node.body);
+    }
+
     #[emitter]
     fn emit_prop(&mut self, node: &Prop) -> Result {
+        if self.emit_original(node, false)? {
+            return Ok(());
+        }
+
         match *node {
             Prop::Shorthand(ref n) => emit!(n),
             Prop::KeyValue(ref n) => emit!(n),
@@ -1662,6 +1824,7 @@ where
             Prop::Setter(ref n) => emit!(n),
             Prop::Method(ref n) => emit!(n),
         }
+        self.end_original();
     }
 
     #[emitter]
@@ -1942,6 +2105,7 @@ where
                         if !self.cfg.minify {
                             self.wr.write_line()?;
                         }
+                        self.emit_blank_line_between(previous_sibling, child.span())?;
                         should_emit_intervening_comments = false;
                     } else if format.contains(ListFormat::SpaceBetweenSiblings) {
                         formatting_space!(self);
@@ -2075,14 +2239,17 @@ where
 
     #[emitter]
     fn emit_pat(&mut self, node: &Pat) -> Result {
-        match node {
-            Pat::Array(ref n) => emit!(n),
-            Pat::Assign(ref n) => emit!(n),
-            Pat::Expr(ref n) => emit!(n),
-            Pat::Ident(ref n) => emit!(n),
-            Pat::Object(ref n) => emit!(n),
-            Pat::Rest(ref n) => emit!(n),
-            Pat::Invalid(n) => emit!(n),
+        if !self.emit_original(node, false)? {
+            match node {
+                Pat::Array(ref n) => emit!(n),
+                Pat::Assign(ref n) => emit!(n),
+                Pat::Expr(ref n) => emit!(n),
+                Pat::Ident(ref n) => emit!(n),
+                Pat::Object(ref n) => emit!(n),
+                Pat::Rest(ref n) => emit!(n),
+                Pat::Invalid(n) => emit!(n),
+            }
+            self.end_original();
         }
 
         if self.comments.is_some() {
@@ -2258,30 +2425,39 @@ where
 {
     #[emitter]
     fn emit_stmt(&mut self, node: &Stmt) -> Result {
-        match *node {
-            Stmt::Expr(ref e) => emit!(e),
-            Stmt::Block(ref e) => {
-                emit!(e);
+        if self.emit_original(node, reprint::may_end_with_asi(node))? {
+            if let Stmt::Block(..) = node {
                 return Ok(());
             }
-            Stmt::Empty(ref e) => emit!(e),
-            Stmt::Debugger(ref e) => emit!(e),
-            Stmt::With(ref e) => emit!(e),
-            Stmt::Return(ref e) => emit!(e),
-            Stmt::Labeled(ref e) => emit!(e),
-            Stmt::Break(ref e) => emit!(e),
-            Stmt::Continue(ref e) => emit!(e),
-            Stmt::If(ref e) => emit!(e),
-            Stmt::Switch(ref e) => emit!(e),
-            Stmt::Throw(ref e) => emit!(e),
-            Stmt::Try(ref e) => emit!(e),
-            Stmt::While(ref e) => emit!(e),
-            Stmt::DoWhile(ref e) => emit!(e),
-            Stmt::For(ref e) => emit!(e),
-            Stmt::ForIn(ref e) => emit!(e),
-            Stmt::ForOf(ref e) => emit!(e),
-            Stmt::Decl(ref e) => emit!(e),
+        } else {
+            match *node {
+                Stmt::Expr(ref e) => emit!(e),
+                Stmt::Block(ref e) => {
+                    emit!(e);
+                    self.end_original();
+                    return Ok(());
+                }
+                Stmt::Empty(ref e) => emit!(e),
+                Stmt::Debugger(ref e) => emit!(e),
+                Stmt::With(ref e) => emit!(e),
+                Stmt::Return(ref e) => emit!(e),
+                Stmt::Labeled(ref e) => emit!(e),
+                Stmt::Break(ref e) => emit!(e),
+                Stmt::Continue(ref e) => emit!(e),
+                Stmt::If(ref e) => emit!(e),
+                Stmt::Switch(ref e) => emit!(e),
+                Stmt::Throw(ref e) => emit!(e),
+                Stmt::Try(ref e) => emit!(e),
+                Stmt::While(ref e) => emit!(e),
+                Stmt::DoWhile(ref e) => emit!(e),
+                Stmt::For(ref e) => emit!(e),
+                Stmt::ForIn(ref e) => emit!(e),
+                Stmt::ForOf(ref e) => emit!(e),
+                Stmt::Decl(ref e) => emit!(e),
+            }
+            self.end_original();
         }
+
         if self.comments.is_some() {
             self.emit_trailing_comments_of_pos(node.span().hi(), true, true)?;
         }
@@ -2433,7 +2609,7 @@ where
         }
 
         if let Some(ref arg) = n.arg {
-            let need_paren = !n.arg.span().is_dummy()
+            let need_paren = !n.arg.span().is_dummy_ignoring_cmt()
                 && n.arg
                     .as_deref()
                     .map(|expr| self.has_leading_comment(expr))
@@ -3309,6 +3485,11 @@ fn is_space_require_before_rhs(rhs: &Expr) -> bool {
     }
 }
 
+/// The parser marks both of the class member and its key as optional.
+fn is_optional_ident(key: &PropName) -> bool {
+    matches!(key, PropName::Ident(Ident { optional: true, .. }))
+}
+
 fn is_empty_comments(span: &Span, comments: &Option<&dyn Comments>) -> bool {
     span.is_dummy() || comments.map_or(true, |c| !c.has_leading(span.hi() - BytePos(1)))
 }
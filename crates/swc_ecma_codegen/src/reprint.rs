@@ -0,0 +1,483 @@
+//! Format-preserving reprinting.
+
+use super::*;
+use crate::text_writer::JsWriter;
+use std::{any::TypeId, cell::RefCell, mem::take, rc::Rc};
+use swc_common::{collections::AHashMap, SourceFile};
+use swc_ecma_parser::{PResult, Parser, StringInput, Syntax};
+
+/// The original source code of a program.
+///
+/// If an [Emitter] uses a writer created by [Reprint::writer], statements,
+/// module declarations, class members, expressions, properties and patterns
+/// are printed using the original source code if they are not modified, and
+/// blank lines between statements are preserved. Nodes with [DUMMY_SP] are
+/// always regenerated.
+///
+/// Unmodified nodes are detected while printing a [Module] or a [Script], so
+/// other nodes are always regenerated if they are printed directly.
+///
+/// This is useful for codemods, as only the modified parts of a file are
+/// reformatted.
+pub struct Reprint {
+    fm: Lrc<SourceFile>,
+    /// Nodes of the original program.
+    original: PrintedNodes,
+}
+
+/// State of [Emitter] related to [Reprint], which is stored in the writer.
+pub struct ReprintState(State);
+
+enum State {
+    /// Printing a program using the original source code.
+    Print {
+        reprint: Lrc<Reprint>,
+        /// Nodes of the program being printed.
+        current: Option<PrintedNodes>,
+    },
+    /// Printing nodes to compare them.
+    Normalize(Rc<RefCell<Normalizer>>),
+}
+
+/// Nodes of the original program are identified by their spans, while nodes
+/// of the program being printed are identified by their addresses, as spans
+/// of modified nodes may be reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum NodeKey {
+    Span(BytePos, BytePos, TypeId),
+    Ptr(usize, TypeId),
+}
+
+/// Range of a node in [PrintedNodes::code].
+#[derive(Debug, Clone, Copy)]
+struct CodeRange {
+    start: usize,
+    len: usize,
+    hash: u64,
+}
+
+/// A program printed in a form which does not depend on the original
+/// formatting.
+#[derive(Default)]
+struct PrintedNodes {
+    code: Vec<u8>,
+    nodes: AHashMap<NodeKey, CodeRange>,
+}
+
+impl PrintedNodes {
+    fn code_of(&self, range: CodeRange) -> &[u8] {
+        &self.code[range.start..range.start + range.len]
+    }
+
+    /// Returns `true` if `range` of `self` and `other_range` of `other` have
+    /// the same code.
+    ///
+    /// Hashes are compared first, and the code is compared only if the hashes
+    /// are equal, so a hash collision does not make a modified node
+    /// unmodified.
+    fn has_same_code(
+        &self,
+        range: CodeRange,
+        other: &PrintedNodes,
+        other_range: CodeRange,
+    ) -> bool {
+        range.len == other_range.len
+            && range.hash == other_range.hash
+            && self.code_of(range) == other.code_of(other_range)
+    }
+}
+
+/// Polynomial hash modulo `2^61 - 1`.
+const MOD: u64 = (1 << 61) - 1;
+const BASE: u64 = 1_000_003;
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    let v = a as u128 * b as u128;
+    let v = (v & MOD as u128) + (v >> 61);
+
+    (v % MOD as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: usize) -> u64 {
+    let mut v = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            v = mul_mod(v, base);
+        }
+        base = mul_mod(base, base);
+        exp >>= 1;
+    }
+    v
+}
+
+/// Records the code of nodes from a single emission.
+///
+/// As the hash of code written so far is a rolling hash, the hash of a node
+/// is computed from the hashes at the start and the end of the node.
+struct Normalizer {
+    by_ptr: bool,
+    hash: u64,
+    stack: Vec<(Option<NodeKey>, usize, u64)>,
+    printed: PrintedNodes,
+}
+
+impl Normalizer {
+    fn update(&mut self, buf: &[u8]) {
+        for &b in buf {
+            self.hash = (mul_mod(self.hash, BASE) + b as u64 + 1) % MOD;
+        }
+        self.printed.code.extend_from_slice(buf);
+    }
+
+    fn start<N>(&mut self, node: &N)
+    where
+        N: Spanned + 'static,
+    {
+        let span = node.span();
+        let key = if span.is_dummy() {
+            None
+        } else if self.by_ptr {
+            Some(NodeKey::Ptr(node as *const N as usize, TypeId::of::<N>()))
+        } else {
+            Some(NodeKey::Span(span.lo, span.hi, TypeId::of::<N>()))
+        };
+
+        self.stack.push((key, self.printed.code.len(), self.hash));
+    }
+
+    fn end(&mut self) {
+        let (key, start, start_hash) = self
+            .stack
+            .pop()
+            .expect("Normalizer::end() is called without Normalizer::start()");
+
+        if let Some(key) = key {
+            let len = self.printed.code.len() - start;
+            let hash = (self.hash + MOD - mul_mod(start_hash, pow_mod(BASE, len))) % MOD;
+
+            self.printed
+                .nodes
+                .insert(key, CodeRange { start, len, hash });
+        }
+    }
+}
+
+struct NormalizerWriter(Rc<RefCell<Normalizer>>);
+
+impl io::Write for NormalizerWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Prints `node` in a form which does not depend on the original formatting,
+/// and records the code of its descendants.
+fn print_nodes<N>(cm: &Lrc<SourceMap>, node: &N, by_ptr: bool) -> io::Result<PrintedNodes>
+where
+    N: Node,
+{
+    let normalizer = Rc::new(RefCell::new(Normalizer {
+        by_ptr,
+        hash: 0,
+        stack: Default::default(),
+        printed: Default::default(),
+    }));
+
+    {
+        let mut emitter = Emitter {
+            cfg: Config { minify: true },
+            cm: cm.clone(),
+            comments: None,
+            wr: ReprintWriter {
+                // `Es5` prevents reusing the original source code of string literals.
+                inner: JsWriter::with_target(
+                    cm.clone(),
+                    "\n",
+                    NormalizerWriter(normalizer.clone()),
+                    None,
+                    EsVersion::Es5,
+                ),
+                state: ReprintState(State::Normalize(normalizer.clone())),
+            },
+        };
+
+        node.emit_with(&mut emitter)?;
+    }
+
+    let printed = take(&mut normalizer.borrow_mut().printed);
+    Ok(printed)
+}
+
+impl Reprint {
+    /// Parses `fm` to record the original program.
+    ///
+    /// `syntax` should be same as the one used to parse the program which is
+    /// going to be printed.
+    pub fn new(cm: Lrc<SourceMap>, fm: Lrc<SourceFile>, syntax: Syntax) -> PResult<Self> {
+        let program = Parser::new(syntax, StringInput::from(&*fm), None).parse_program()?;
+
+        let original =
+            print_nodes(&cm, &program, false).expect("printing to memory should not fail");
+
+        Ok(Reprint { fm, original })
+    }
+
+    /// Wraps `wr`, so an [Emitter] using it prints unmodified nodes using the
+    /// original source code.
+    pub fn writer<W>(self: Lrc<Self>, wr: W) -> impl WriteJs
+    where
+        W: WriteJs,
+    {
+        ReprintWriter {
+            inner: wr,
+            state: ReprintState(State::Print {
+                reprint: self,
+                current: None,
+            }),
+        }
+    }
+
+    fn source_of(&self, span: Span) -> Option<&str> {
+        if span.is_dummy()
+            || span.lo > span.hi
+            || span.lo < self.fm.start_pos
+            || span.hi > self.fm.end_pos
+        {
+            return None;
+        }
+
+        let lo = (span.lo - self.fm.start_pos).0 as usize;
+        let hi = (span.hi - self.fm.start_pos).0 as usize;
+
+        self.fm.src.get(lo..hi)
+    }
+
+    fn has_blank_line_between(&self, prev: Span, next: Span) -> bool {
+        if prev.is_dummy() || next.is_dummy() || prev.hi > next.lo {
+            return false;
+        }
+
+        let between = match self.source_of(Span::new(prev.hi, next.lo, Default::default())) {
+            Some(v) => v,
+            None => return false,
+        };
+
+        let lines: Vec<_> = between.split('\n').collect();
+        lines.len() > 2
+            && lines[1..lines.len() - 1]
+                .iter()
+                .any(|l| l.trim().is_empty())
+    }
+}
+
+/// Writer created by [Reprint::writer].
+struct ReprintWriter<W: WriteJs> {
+    inner: W,
+    state: ReprintState,
+}
+
+macro_rules! forward {
+    (
+        $fn_name:ident
+        (
+            $(
+                $arg_name:ident
+                :
+                $arg_ty:ty
+            ),*
+        )
+    ) => {
+        #[inline]
+        fn $fn_name(&mut self, $($arg_name: $arg_ty),* ) -> Result {
+            self.inner.$fn_name( $($arg_name),* )
+        }
+    };
+}
+
+impl<W: WriteJs> WriteJs for ReprintWriter<W> {
+    forward!(increase_indent());
+    forward!(decrease_indent());
+    forward!(write_semi(span: Option<Span>));
+    forward!(write_space());
+    forward!(write_keyword(span: Option<Span>, s: &'static str));
+    forward!(write_operator(span: Option<Span>, s: &str));
+    forward!(write_param(s: &str));
+    forward!(write_property(s: &str));
+    forward!(write_line());
+    forward!(write_blank_line());
+    forward!(write_lit(span: Span, s: &str));
+    forward!(write_comment(span: Span, s: &str));
+    forward!(write_str_lit(span: Span, s: &str));
+    forward!(write_str(s: &str));
+    forward!(write_symbol(span: Span, s: &str));
+    forward!(write_punct(span: Option<Span>, s: &'static str));
+
+    #[inline]
+    fn target(&self) -> EsVersion {
+        self.inner.target()
+    }
+
+    #[inline]
+    fn care_about_srcmap(&self) -> bool {
+        self.inner.care_about_srcmap()
+    }
+
+    #[inline]
+    fn reprint_state(&mut self) -> Option<&mut ReprintState> {
+        Some(&mut self.state)
+    }
+}
+
+impl<'a, W> Emitter<'a, W>
+where
+    W: WriteJs,
+{
+    /// Records nodes in `node`, which is the root of the program being
+    /// printed.
+    pub(super) fn start_reprint<N>(&mut self, node: &N) -> Result
+    where
+        N: Node,
+    {
+        if let Some(ReprintState(State::Print { current: None, .. })) = self.wr.reprint_state() {
+            let printed = print_nodes(&self.cm, node, true)?;
+            if let Some(ReprintState(State::Print { current, .. })) = self.wr.reprint_state() {
+                *current = Some(printed);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Drops nodes recorded by [Emitter::start_reprint], as they are
+    /// identified by addresses of nodes.
+    pub(super) fn end_reprint(&mut self) {
+        if let Some(ReprintState(State::Print { current, .. })) = self.wr.reprint_state() {
+            *current = None;
+        }
+    }
+
+    /// Emits `node` using the original source code if it's not modified.
+    ///
+    /// If `semi` is true, a semicolon is appended if the original source code
+    /// does not have one, as it may be inserted automatically.
+    ///
+    /// Returns `true` if `node` is emitted. [Emitter::end_original] should be
+    /// called when `node` is emitted by the caller.
+    pub(super) fn emit_original<N>(&mut self, node: &N, semi: bool) -> io::Result<bool>
+    where
+        N: Node + 'static,
+    {
+        let span = node.span();
+        let reprint = match self.wr.reprint_state() {
+            Some(ReprintState(State::Print {
+                reprint,
+                current: Some(current),
+            })) => {
+                let original =
+                    reprint
+                        .original
+                        .nodes
+                        .get(&NodeKey::Span(span.lo, span.hi, TypeId::of::<N>()));
+                let modified = current
+                    .nodes
+                    .get(&NodeKey::Ptr(node as *const N as usize, TypeId::of::<N>()));
+
+                match (original, modified) {
+                    (Some(&original), Some(&modified))
+                        if reprint.original.has_same_code(original, current, modified) =>
+                    {
+                        reprint.clone()
+                    }
+                    _ => return Ok(false),
+                }
+            }
+            Some(ReprintState(State::Normalize(normalizer))) => {
+                normalizer.borrow_mut().start(node);
+                return Ok(false);
+            }
+            _ => return Ok(false),
+        };
+
+        let src = match reprint.source_of(span) {
+            Some(v) => v,
+            None => return Ok(false),
+        };
+
+        self.emit_leading_comments_of_span(span, false)?;
+        self.wr.write_lit(span, src)?;
+        if semi && !src.ends_with(';') {
+            self.wr.write_semi(None)?;
+        }
+
+        Ok(true)
+    }
+
+    /// Should be called after emitting `node` if [Emitter::emit_original]
+    /// returned `false`.
+    pub(super) fn end_original(&mut self) {
+        if let Some(ReprintState(State::Normalize(normalizer))) = self.wr.reprint_state() {
+            normalizer.borrow_mut().end();
+        }
+    }
+
+    /// Emits a blank line if there was one between `prev` and `next`.
+    pub(super) fn emit_blank_line_between(&mut self, prev: Span, next: Span) -> Result {
+        if self.cfg.minify {
+            return Ok(());
+        }
+
+        let has_blank_line = match self.wr.reprint_state() {
+            Some(ReprintState(State::Print { reprint, .. })) => {
+                reprint.has_blank_line_between(prev, next)
+            }
+            _ => false,
+        };
+        if has_blank_line {
+            self.wr.write_blank_line()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `true` if `stmt` may be terminated by an automatically inserted
+/// semicolon.
+pub(super) fn may_end_with_asi(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Expr(..)
+        | Stmt::Decl(Decl::Var(..))
+        | Stmt::Decl(Decl::TsTypeAlias(..))
+        | Stmt::Return(..)
+        | Stmt::Throw(..)
+        | Stmt::Break(..)
+        | Stmt::Continue(..)
+        | Stmt::Debugger(..)
+        | Stmt::DoWhile(..) => true,
+
+        Stmt::If(IfStmt { cons, alt, .. }) => may_end_with_asi(alt.as_ref().unwrap_or(cons)),
+        Stmt::While(WhileStmt { body, .. })
+        | Stmt::For(ForStmt { body, .. })
+        | Stmt::ForIn(ForInStmt { body, .. })
+        | Stmt::ForOf(ForOfStmt { body, .. })
+        | Stmt::With(WithStmt { body, .. })
+        | Stmt::Labeled(LabeledStmt { body, .. }) => may_end_with_asi(body),
+
+        _ => false,
+    }
+}
+
+/// Same as [may_end_with_asi], but for module declarations.
+pub(super) fn module_decl_may_end_with_asi(decl: &ModuleDecl) -> bool {
+    match decl {
+        ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => {
+            matches!(decl, Decl::Var(..) | Decl::TsTypeAlias(..))
+        }
+        ModuleDecl::ExportDefaultDecl(..) => false,
+        _ => true,
+    }
+}
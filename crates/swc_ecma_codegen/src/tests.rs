@@ -6,7 +6,10 @@ use std::{
     io::Write,
     sync::{Arc, RwLock},
 };
-use swc_common::{comments::SingleThreadedComments, FileName, SourceMap};
+use swc_common::{
+    comments::{Comment, CommentKind, SingleThreadedComments},
+    FileName, SourceMap,
+};
 use swc_ecma_parser;
 use testing::DebugUsingDisplay;
 
@@ -95,6 +98,9 @@ pub(crate) fn assert_min(from: &str, to: &str) {
         Config { minify: true },
         Syntax::Es(EsConfig {
             static_blocks: true,
+            pipeline_operator: Some(Default::default()),
+            do_expressions: true,
+            record_and_tuple: true,
             ..Default::default()
         }),
         EsVersion::latest(),
@@ -654,3 +660,207 @@ impl Write for Buf {
 fn issue_2213() {
     assert_min("a - -b * c", "a- -b*c")
 }
+
+fn reprint<F>(src: &str, op: F) -> String
+where
+    F: FnOnce(&mut Module),
+{
+    ::testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let syntax = Syntax::default();
+
+        let mut module = Parser::new(syntax, StringInput::from(&*fm), None)
+            .parse_module()
+            .unwrap();
+        let reprint = Lrc::new(Reprint::new(cm.clone(), fm, syntax).unwrap());
+
+        op(&mut module);
+
+        let mut buf = vec![];
+        {
+            let mut e = Emitter {
+                cfg: Default::default(),
+                cm: cm.clone(),
+                comments: None,
+                wr: reprint.writer(text_writer::JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+            };
+
+            e.emit_module(&module).unwrap();
+        }
+
+        Ok(String::from_utf8(buf).unwrap())
+    })
+    .unwrap()
+}
+
+fn call_stmt(callee: &str) -> Stmt {
+    Stmt::Expr(ExprStmt {
+        span: DUMMY_SP,
+        expr: Box::new(Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: Callee::Expr(Box::new(Expr::Ident(Ident::new(callee.into(), DUMMY_SP)))),
+            args: vec![],
+            type_args: None,
+        })),
+    })
+}
+
+#[test]
+fn reprint_unmodified() {
+    assert_eq!(
+        reprint("let  a = (1 +   2);\n\n\nfoo( a );\n", |_| {}),
+        "let  a = (1 +   2);\n\nfoo( a );\n"
+    );
+}
+
+#[test]
+fn reprint_synthesized() {
+    assert_eq!(
+        reprint("foo( 1 );\n", |m| m
+            .body
+            .push(ModuleItem::Stmt(call_stmt("bar")))),
+        "foo( 1 );\nbar();\n"
+    );
+}
+
+#[test]
+fn reprint_nested() {
+    assert_eq!(
+        reprint("function foo() {\n    a( 1 );\n\n    b( 2 );\n}\n", |m| {
+            if let ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f))) = &mut m.body[0] {
+                let body = f.function.body.as_mut().unwrap();
+                body.stmts[1] = call_stmt("c");
+            }
+        }),
+        "function foo() {\n    a( 1 );\n    c();\n}\n"
+    );
+}
+
+#[test]
+fn reprint_renamed() {
+    assert_eq!(
+        reprint("foo( a );\n", |m| {
+            if let ModuleItem::Stmt(Stmt::Expr(e)) = &mut m.body[0] {
+                if let Expr::Call(call) = &mut *e.expr {
+                    if let Expr::Ident(i) = &mut *call.args[0].expr {
+                        i.sym = "b".into();
+                    }
+                }
+            }
+        }),
+        "foo(b);\n"
+    );
+}
+
+#[test]
+fn reprint_asi() {
+    assert_eq!(
+        reprint("foo()\n", |m| {
+            m.body.push(ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+                span: DUMMY_SP,
+                expr: Box::new(Expr::Paren(ParenExpr {
+                    span: DUMMY_SP,
+                    expr: Box::new(Expr::Ident(Ident::new("bar".into(), DUMMY_SP))),
+                })),
+            })))
+        }),
+        "foo();\n(bar);\n"
+    );
+}
+
+#[test]
+fn blank_line_resets_column() {
+    let cm = Lrc::new(SourceMap::default());
+    let mut buf = vec![];
+    let mut srcmap = vec![];
+
+    {
+        let mut wr = text_writer::JsWriter::new(cm, "\n", &mut buf, Some(&mut srcmap));
+        wr.write_lit(DUMMY_SP, "foo;").unwrap();
+        wr.write_blank_line().unwrap();
+        wr.write_lit(Span::new(BytePos(1), BytePos(2), Default::default()), "a")
+            .unwrap();
+    }
+
+    assert_eq!(String::from_utf8(buf).unwrap(), "foo;\n\na");
+    assert_eq!(srcmap[0].1.line, 2);
+    assert_eq!(srcmap[0].1.col, 0);
+}
+
+fn emit_with_comments<F>(src: &str, op: F) -> String
+where
+    F: FnOnce(&mut Module, &SingleThreadedComments),
+{
+    ::testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+        let comments = SingleThreadedComments::default();
+
+        let mut module = Parser::new(Syntax::default(), StringInput::from(&*fm), Some(&comments))
+            .parse_module()
+            .unwrap();
+
+        op(&mut module, &comments);
+
+        let mut buf = vec![];
+        {
+            let mut e = Emitter {
+                cfg: Default::default(),
+                cm: cm.clone(),
+                comments: Some(&comments),
+                wr: text_writer::JsWriter::new(cm.clone(), "\n", &mut buf, None),
+            };
+
+            e.emit_module(&module).unwrap();
+        }
+
+        Ok(String::from_utf8(buf).unwrap())
+    })
+    .unwrap()
+}
+
+fn comment(text: &str) -> Comment {
+    Comment {
+        kind: CommentKind::Block,
+        span: DUMMY_SP,
+        text: text.into(),
+    }
+}
+
+#[test]
+fn synthesized_node_comment() {
+    assert_eq!(
+        emit_with_comments("foo();\n", |m, comments| {
+            let span = Span::dummy_with_cmt();
+            comments.add_leading(span.lo, comment(" synthesized "));
+
+            let mut stmt = call_stmt("bar");
+            if let Stmt::Expr(ExprStmt { expr, .. }) = &mut stmt {
+                if let Expr::Call(call) = &mut **expr {
+                    call.span = span;
+                }
+            }
+            m.body.push(ModuleItem::Stmt(stmt));
+        }),
+        "foo();\n/* synthesized */ bar();\n"
+    );
+}
+
+#[test]
+fn synthesized_node_moved_comment() {
+    assert_eq!(
+        emit_with_comments("/* original */ foo();\n", |m, comments| {
+            if let ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) = &mut m.body[0] {
+                let span = comments.move_comments(expr.span(), DUMMY_SP);
+                assert!(span.is_dummy());
+
+                *expr = Box::new(Expr::Call(CallExpr {
+                    span,
+                    callee: Callee::Expr(Box::new(Expr::Ident(Ident::new("bar".into(), DUMMY_SP)))),
+                    args: vec![],
+                    type_args: None,
+                }));
+            }
+        }),
+        "/* original */ bar();\n"
+    );
+}
@@ -1,5 +1,6 @@
 pub use self::{basic_impl::JsWriter, semicolon::omit_trailing_semi};
 use super::*;
+use crate::reprint::ReprintState;
 use swc_common::Span;
 
 mod basic_impl;
@@ -35,6 +36,13 @@ pub trait WriteJs {
 
     fn write_line(&mut self) -> Result;
 
+    /// Writes an empty line even if the writer is at the start of a line.
+    ///
+    /// This is used to preserve blank lines of the original source code.
+    fn write_blank_line(&mut self) -> Result {
+        self.write_line()
+    }
+
     fn write_lit(&mut self, span: Span, s: &str) -> Result;
     fn write_comment(&mut self, span: Span, s: &str) -> Result;
 
@@ -46,6 +54,16 @@ pub trait WriteJs {
     fn write_punct(&mut self, span: Option<Span>, s: &'static str) -> Result;
 
     fn care_about_srcmap(&self) -> bool;
+
+    /// Returns the state used to print unmodified nodes using the original
+    /// source code.
+    ///
+    /// Only writers created by [crate::Reprint::writer] return [Some], and
+    /// wrappers of other writers should forward this.
+    #[doc(hidden)]
+    fn reprint_state(&mut self) -> Option<&mut ReprintState> {
+        None
+    }
 }
 
 impl<W> WriteJs for Box<W>
@@ -102,6 +120,11 @@ where
         (**self).write_line()
     }
 
+    #[inline]
+    fn write_blank_line(&mut self) -> Result {
+        (**self).write_blank_line()
+    }
+
     #[inline]
     fn write_lit(&mut self, span: Span, s: &str) -> Result {
         (**self).write_lit(span, s)
@@ -136,4 +159,9 @@ where
     fn care_about_srcmap(&self) -> bool {
         (**self).care_about_srcmap()
     }
+
+    #[inline]
+    fn reprint_state(&mut self) -> Option<&mut ReprintState> {
+        (**self).reprint_state()
+    }
 }
@@ -160,6 +160,16 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
         Ok(())
     }
 
+    fn write_blank_line(&mut self) -> Result {
+        self.write_line()?;
+
+        self.raw_write(self.new_line.as_bytes())?;
+        self.line_count += 1;
+        self.line_pos = 0;
+
+        Ok(())
+    }
+
     fn write_lit(&mut self, span: Span, s: &str) -> Result {
         if !s.is_empty() {
             if !span.is_dummy() {
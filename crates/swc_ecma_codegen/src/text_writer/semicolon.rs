@@ -1,4 +1,5 @@
 use super::{Result, WriteJs};
+use crate::reprint::ReprintState;
 use swc_common::Span;
 
 pub fn omit_trailing_semi<W: WriteJs>(w: W) -> impl WriteJs {
@@ -49,6 +50,7 @@ impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
     with_semi!(write_param(s: &str));
     with_semi!(write_property(s: &str));
     with_semi!(write_line());
+    with_semi!(write_blank_line());
     with_semi!(write_lit(span: Span, s: &str));
     with_semi!(write_str_lit(span: Span, s: &str));
     with_semi!(write_str(s: &str));
@@ -80,6 +82,11 @@ impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
     fn care_about_srcmap(&self) -> bool {
         self.inner.care_about_srcmap()
     }
+
+    #[inline]
+    fn reprint_state(&mut self) -> Option<&mut ReprintState> {
+        self.inner.reprint_state()
+    }
 }
 
 impl<W: WriteJs> OmitTrailingSemi<W> {
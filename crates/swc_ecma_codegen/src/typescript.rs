@@ -1062,4 +1062,18 @@ mod tests {
             "var memory:WebAssembly.Memory",
         );
     }
+
+    #[test]
+    fn class_modifiers() {
+        assert_min_typescript(
+            "abstract class A<T> extends B<T> implements I {
+                abstract x?: number;
+                static override readonly y!: string;
+                protected abstract m?(): void;
+                declare z: number;
+            }",
+            "abstract class A<T> extends B<T> implements I{abstract x?: number;static override \
+             readonly y!: string;protected abstract m?():void;declare z: number}",
+        );
+    }
 }
@@ -295,6 +295,14 @@ impl StartsWithAlphaNum for Expr {
 
             Expr::OptChain(ref e) => e.expr.starts_with_alpha_num(),
 
+            Expr::Pipeline(PipelineExpr { ref left, .. }) => left.starts_with_alpha_num(),
+            // `%`
+            Expr::TopicRef(..) => false,
+
+            Expr::Do(..) => true,
+            // `#{` or `#[`
+            Expr::Record(..) | Expr::Tuple(..) => false,
+
             Expr::Invalid(..) => true,
         }
     }
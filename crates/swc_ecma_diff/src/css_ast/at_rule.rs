@@ -0,0 +1,70 @@
+use swc_css_ast::*;
+
+diff_enum!(
+    AtRule,
+    [
+        Charset, Import, FontFace, Keyframes, Layer, Media, Supports, Page, Namespace, Viewport,
+        Document, Unknown
+    ]
+);
+
+diff_struct!(CharsetRule, [span, charset]);
+diff_struct!(FontFaceRule, [span, block]);
+diff_struct!(ViewportRule, [span, block]);
+diff_struct!(DocumentRule, [span, selectors, block]);
+diff_struct!(UnknownAtRule, [span, name, prelude, block]);
+
+diff_struct!(NamespaceRule, [span, prefix, uri]);
+diff_enum!(NamespaceUri, [Url, Str]);
+
+diff_struct!(ImportRule, [span, href, layer_name, media]);
+diff_enum!(ImportHref, [Function, Url, Str]);
+diff_enum!(ImportLayerName, [Ident, Function]);
+
+diff_struct!(KeyframesRule, [span, name, blocks]);
+diff_enum!(KeyframesName, [Ident, Str]);
+diff_struct!(KeyframeBlock, [span, selector, rule]);
+diff_enum!(KeyframeSelector, [Ident, Percent]);
+diff_enum!(KeyframeBlockRule, [Block, AtRule]);
+
+diff_struct!(LayerRule, [span, prelude, rules]);
+diff_enum!(LayerPrelude, [Name, NameList]);
+diff_struct!(LayerName, [span, name]);
+diff_struct!(LayerNameList, [span, name_list]);
+
+diff_struct!(MediaRule, [span, media, rules]);
+diff_struct!(MediaQueryList, [span, queries]);
+diff_struct!(MediaQuery, [span, modifier, media_type, condition]);
+diff_enum!(MediaConditionType, [All, WithoutOr]);
+diff_struct!(MediaCondition, [span, conditions]);
+diff_struct!(MediaConditionWithoutOr, [span, conditions]);
+diff_enum!(MediaConditionAllType, [Not, And, Or, MediaInParens]);
+diff_enum!(MediaConditionWithoutOrType, [Not, And, MediaInParens]);
+diff_struct!(MediaNot, [span, condition]);
+diff_struct!(MediaAnd, [span, condition]);
+diff_struct!(MediaOr, [span, condition]);
+diff_enum!(MediaInParens, [MediaCondition, Feature]);
+diff_enum!(MediaFeature, [Plain, Boolean, Range, RangeInterval]);
+diff_enum!(MediaFeatureName, [Ident]);
+diff_enum!(MediaFeatureValue, [Number, Dimension, Ident, Ratio]);
+diff_struct!(MediaFeaturePlain, [span, name, value]);
+diff_struct!(MediaFeatureBoolean, [span, name]);
+diff_string_enum!(MediaFeatureRangeComparison);
+diff_struct!(MediaFeatureRange, [span, left, comparison, right]);
+diff_struct!(
+    MediaFeatureRangeInterval,
+    [span, left, left_comparison, name, right_comparison, right]
+);
+
+diff_struct!(SupportsRule, [span, query, rules]);
+diff_enum!(SupportQuery, [Not, And, Or, Declaration, Paren]);
+diff_struct!(NotSupportQuery, [span, query]);
+diff_struct!(AndSupportQuery, [span, left, right]);
+diff_struct!(OrSupportQuery, [span, left, right]);
+diff_struct!(ParenSupportQuery, [span, query]);
+
+diff_struct!(PageRule, [span, prelude, block]);
+diff_struct!(PageSelector, [span, ident, pseudo]);
+diff_struct!(PageRuleBlock, [span, items]);
+diff_enum!(PageRuleBlockItem, [Declaration, Nested]);
+diff_struct!(NestedPageRule, [span, prelude, block]);
@@ -0,0 +1,23 @@
+//! Implementation of [crate::Diff] for types in [swc_css_ast].
+
+use swc_css_ast::*;
+
+mod at_rule;
+mod selector;
+mod value;
+
+diff_struct!(Stylesheet, [span, rules]);
+diff_enum!(Rule, [QualifiedRule, Invalid, AtRule]);
+
+diff_struct!(Ident, [span, value, raw]);
+diff_struct!(Str, [span, value, raw]);
+diff_struct!(Num, [span, value, raw]);
+
+diff_struct!(QualifiedRule, [span, prelude, block]);
+diff_struct!(Block, [span, value]);
+diff_enum!(DeclarationBlockItem, [Invalid, Declaration, AtRule]);
+diff_struct!(Declaration, [span, property, value, important]);
+
+diff_struct!(Tokens, [span, tokens]);
+diff_struct!(TokenAndSpan, [span, token]);
+trivial!(Token, NumberType);
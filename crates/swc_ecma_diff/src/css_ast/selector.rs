@@ -0,0 +1,33 @@
+use swc_css_ast::*;
+
+diff_struct!(SelectorList, [span, children]);
+diff_struct!(ComplexSelector, [span, children]);
+diff_enum!(ComplexSelectorChildren, [CompoundSelector, Combinator]);
+diff_struct!(
+    CompoundSelector,
+    [span, nesting_selector, type_selector, subclass_selectors]
+);
+diff_struct!(Combinator, [span, value]);
+diff_string_enum!(CombinatorValue);
+diff_struct!(NestingSelector, [span]);
+diff_struct!(TypeSelector, [span, prefix, name]);
+
+diff_enum!(
+    SubclassSelector,
+    [Id, Class, Attr, PseudoClass, PseudoElement, At]
+);
+diff_struct!(IdSelector, [span, text]);
+diff_struct!(ClassSelector, [span, text]);
+diff_struct!(TagSelector, [span, text]);
+diff_struct!(AtSelector, [span, text]);
+
+diff_struct!(AttrSelector, [span, prefix, name, matcher, value, modifier]);
+diff_string_enum!(AttrSelectorMatcher);
+diff_enum!(AttrSelectorValue, [Str, Ident]);
+
+diff_struct!(PseudoClassSelector, [span, name, children]);
+diff_struct!(PseudoElementSelector, [span, name, children]);
+diff_enum!(PseudoSelectorChildren, [Nth, Tokens]);
+diff_struct!(Nth, [span, nth, selector_list]);
+diff_enum!(NthValue, [AnPlusB, Ident]);
+diff_struct!(AnPlusB, [span, a, a_raw, b, b_raw]);
@@ -0,0 +1,40 @@
+use swc_css_ast::*;
+
+diff_enum!(
+    Value,
+    [
+        SimpleBlock,
+        SquareBracketBlock,
+        RoundBracketBlock,
+        Unit,
+        Number,
+        Percent,
+        Hash,
+        Ident,
+        Str,
+        Function,
+        Bin,
+        Space,
+        Comma,
+        Brace,
+        Lazy,
+        AtText,
+        Url
+    ]
+);
+
+diff_struct!(SpaceValues, [span, values]);
+diff_struct!(CommaValues, [span, values]);
+diff_struct!(BinValue, [span, op, left, right]);
+diff_string_enum!(BinOp);
+diff_struct!(Function, [span, name, value]);
+diff_struct!(RoundBracketBlock, [span, children]);
+diff_struct!(SquareBracketBlock, [span, children]);
+diff_struct!(SimpleBlock, [span, name, value]);
+diff_struct!(HashValue, [span, value, raw]);
+diff_struct!(Unit, [span, value, raw]);
+diff_struct!(UnitValue, [span, value, unit]);
+diff_struct!(PercentValue, [span, value]);
+diff_struct!(BraceValue, [span, value]);
+diff_struct!(AtTextValue, [span, name, block]);
+diff_struct!(UrlValue, [span, url, raw]);
@@ -0,0 +1,521 @@
+//! Edit scripts, which describe how to transform a tree into another one.
+//!
+//! The mapping between nodes is computed in the way of GumTree: identical
+//! subtrees are matched top-down, and the remaining nodes are matched
+//! bottom-up by the ratio of common descendants.
+
+use crate::{
+    tree::{PathSegment, ToTree, Tree},
+    Node,
+};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+};
+use swc_atoms::JsWord;
+use swc_common::Span;
+
+/// Minimum ratio of common descendants to match two nodes bottom-up.
+const MIN_DICE: f64 = 0.5;
+
+/// A node in the left or right tree.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NodeRef {
+    pub path: Vec<PathSegment>,
+    /// Name of the type, or `Struct.field` for lists.
+    pub kind: JsWord,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Edit {
+    /// `node` in the right tree does not exist in the left tree.
+    Insert { node: NodeRef },
+
+    /// `node` in the left tree does not exist in the right tree.
+    Delete { node: NodeRef },
+
+    /// A node is moved to another parent, or reordered in a list.
+    Move { from: NodeRef, to: NodeRef },
+
+    /// The value of a leaf node is changed.
+    Update {
+        from: NodeRef,
+        to: NodeRef,
+        left: Node,
+        right: Node,
+    },
+}
+
+impl Display for NodeRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for c in &self.path {
+            write!(f, "{}", c)?;
+        }
+        write!(f, " {} @ {}..{}", self.kind, self.span.lo.0, self.span.hi.0)
+    }
+}
+
+impl Display for Edit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Edit::Insert { node } => writeln!(f, "Insert: {}", node),
+            Edit::Delete { node } => writeln!(f, "Delete: {}", node),
+            Edit::Move { from, to } => {
+                writeln!(f, "Move: {}", from)?;
+                writeln!(f, "   -> {}", to)
+            }
+            Edit::Update {
+                from,
+                to,
+                left,
+                right,
+            } => {
+                writeln!(f, "Update: {}", from)?;
+                writeln!(f, "     -> {}", to)?;
+                writeln!(f, "Left: {}", left.0)?;
+                writeln!(f, "Right: {}", right.0)
+            }
+        }
+    }
+}
+
+/// Computes an edit script which transforms `left` into `right`.
+///
+/// Spans are ignored while comparing nodes, so the result is empty if only
+/// formatting is changed.
+pub fn edit_script<T>(left: &T, right: &T) -> Vec<Edit>
+where
+    T: ?Sized + ToTree,
+{
+    let l = Tree::build(left);
+    let r = Tree::build(right);
+
+    let mut m = Matcher {
+        l: &l,
+        r: &r,
+        l2r: vec![None; l.nodes.len()],
+        r2l: vec![None; r.nodes.len()],
+    };
+    m.l2r[0] = Some(0);
+    m.r2l[0] = Some(0);
+
+    m.match_identical();
+    m.match_bottom_up();
+    m.generate()
+}
+
+struct Matcher<'a> {
+    l: &'a Tree,
+    r: &'a Tree,
+    l2r: Vec<Option<usize>>,
+    r2l: Vec<Option<usize>>,
+}
+
+impl Matcher<'_> {
+    fn add(&mut self, l: usize, r: usize) {
+        self.l2r[l] = Some(r);
+        self.r2l[r] = Some(l);
+    }
+
+    /// Maps all nodes of two identical subtrees.
+    fn add_subtree(&mut self, l: usize, r: usize) {
+        for i in 0..self.l.nodes[l].size {
+            self.add(l + i, r + i);
+        }
+    }
+
+    fn is_identical(&self, l: usize, r: usize) -> bool {
+        self.l.nodes[l].hash == self.r.nodes[r].hash && self.l.nodes[l].size == self.r.nodes[r].size
+    }
+
+    /// Matches identical subtrees, starting from the largest ones.
+    ///
+    /// Leaves are not matched here, as they are too common to be matched
+    /// without context.
+    fn match_identical(&mut self) {
+        let mut candidates: HashMap<u64, Vec<usize>> = HashMap::default();
+        for (idx, node) in self.r.nodes.iter().enumerate().skip(1) {
+            if node.size > 1 {
+                candidates.entry(node.hash).or_default().push(idx);
+            }
+        }
+
+        let mut order = (1..self.l.nodes.len())
+            .filter(|&idx| self.l.nodes[idx].size > 1)
+            .collect::<Vec<_>>();
+        order.sort_by_key(|&idx| std::cmp::Reverse(self.l.nodes[idx].size));
+
+        for l in order {
+            if self.l2r[l].is_some() {
+                continue;
+            }
+
+            let best = candidates
+                .get(&self.l.nodes[l].hash)
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(|&r| self.r2l[r].is_none() && self.is_identical(l, r))
+                .min_by_key(|&r| self.distance(l, r));
+
+            if let Some(r) = best {
+                self.add_subtree(l, r);
+            }
+        }
+    }
+
+    /// Used to select one of multiple identical subtrees. Nodes at the same
+    /// path are preferred, and otherwise the closest one is selected.
+    fn distance(&self, l: usize, r: usize) -> (bool, usize) {
+        let same_path = self.l.path(l) == self.r.path(r);
+        let l_pos = l * self.r.nodes.len();
+        let r_pos = r * self.l.nodes.len();
+
+        let dist = if l_pos > r_pos {
+            l_pos - r_pos
+        } else {
+            r_pos - l_pos
+        };
+
+        (!same_path, dist)
+    }
+
+    /// Matches remaining nodes in postorder, if they share enough matched
+    /// descendants. Children of matched nodes are matched using
+    /// [Self::recover].
+    fn match_bottom_up(&mut self) {
+        let postorder = postorder(self.l);
+
+        for l in postorder {
+            if self.l2r[l].is_some() {
+                continue;
+            }
+            if self.l.nodes[l].parent == Some(0) {
+                // Roots
+                if let Some(r) = self.r.root() {
+                    if self.r2l[r].is_none() && self.l.nodes[l].kind == self.r.nodes[r].kind {
+                        self.add(l, r);
+                        self.recover(l, r);
+                    }
+                }
+                continue;
+            }
+            if self.l.nodes[l].children.is_empty() {
+                continue;
+            }
+
+            if let Some(r) = self.find_container(l) {
+                self.add(l, r);
+                self.recover(l, r);
+            }
+        }
+    }
+
+    /// Finds an unmatched node in the right tree, which contains most of the
+    /// matched descendants of `l`.
+    fn find_container(&self, l: usize) -> Option<usize> {
+        let l_node = &self.l.nodes[l];
+
+        let mut common: HashMap<usize, usize> = HashMap::default();
+        for desc in l + 1..l + l_node.size {
+            let mut r = match self.l2r[desc] {
+                Some(r) => r,
+                None => continue,
+            };
+
+            while let Some(parent) = self.r.nodes[r].parent {
+                if self.r2l[parent].is_none() && self.r.nodes[parent].kind == l_node.kind {
+                    *common.entry(parent).or_default() += 1;
+                }
+                r = parent;
+            }
+        }
+
+        let mut best = None;
+        let mut best_dice = MIN_DICE;
+        let mut candidates = common.into_iter().collect::<Vec<_>>();
+        candidates.sort_unstable();
+
+        for (r, common) in candidates {
+            let total = (l_node.size - 1) + (self.r.nodes[r].size - 1);
+            let dice = 2.0 * common as f64 / total as f64;
+            if dice > best_dice {
+                best = Some(r);
+                best_dice = dice;
+            }
+        }
+
+        best
+    }
+
+    /// Matches unmatched children of `l` and `r`, which are matched to each
+    /// other.
+    fn recover(&mut self, l: usize, r: usize) {
+        let l_children = self.unmatched_children(self.l, &self.l2r, l);
+        let r_children = self.unmatched_children(self.r, &self.r2l, r);
+        if l_children.is_empty() || r_children.is_empty() {
+            return;
+        }
+
+        let mut pairs = vec![];
+
+        // Fields with the same name.
+        for &lc in &l_children {
+            if let Some(PathSegment::Field { key, .. }) = &self.l.nodes[lc].edge {
+                let rc = r_children.iter().copied().find(|&rc| {
+                    matches!(&self.r.nodes[rc].edge, Some(PathSegment::Field { key: r_key, .. }) if r_key == key)
+                });
+                if let Some(rc) = rc {
+                    if self.l.nodes[lc].kind == self.r.nodes[rc].kind {
+                        pairs.push((lc, rc));
+                    }
+                }
+            }
+        }
+
+        // Items of lists, aligned by identical subtrees, then by equal labels, and
+        // then by kinds.
+        let l_items = l_children
+            .iter()
+            .copied()
+            .filter(|&c| matches!(self.l.nodes[c].edge, Some(PathSegment::Index(..))))
+            .collect::<Vec<_>>();
+        let r_items = r_children
+            .iter()
+            .copied()
+            .filter(|&c| matches!(self.r.nodes[c].edge, Some(PathSegment::Index(..))))
+            .collect::<Vec<_>>();
+        if !l_items.is_empty() && !r_items.is_empty() {
+            let mut l_used = vec![false; l_items.len()];
+            let mut r_used = vec![false; r_items.len()];
+
+            let passes: [&dyn Fn(usize, usize) -> bool; 3] = [
+                &|lc, rc| self.is_identical(lc, rc),
+                &|lc, rc| {
+                    self.l.nodes[lc].kind == self.r.nodes[rc].kind
+                        && self.l.nodes[lc].label == self.r.nodes[rc].label
+                },
+                &|lc, rc| self.l.nodes[lc].kind == self.r.nodes[rc].kind,
+            ];
+
+            for eq in passes {
+                let li = (0..l_items.len())
+                    .filter(|&i| !l_used[i])
+                    .collect::<Vec<_>>();
+                let ri = (0..r_items.len())
+                    .filter(|&i| !r_used[i])
+                    .collect::<Vec<_>>();
+
+                for (i, j) in lcs(&li, &ri, |&i, &j| eq(l_items[i], r_items[j])) {
+                    l_used[i] = true;
+                    r_used[j] = true;
+                    pairs.push((l_items[i], r_items[j]));
+                }
+            }
+        }
+
+        for (lc, rc) in pairs {
+            if self.is_identical(lc, rc) {
+                self.add_subtree(lc, rc);
+            } else {
+                self.add(lc, rc);
+                self.recover(lc, rc);
+            }
+        }
+    }
+
+    fn unmatched_children(
+        &self,
+        tree: &Tree,
+        mapping: &[Option<usize>],
+        parent: usize,
+    ) -> Vec<usize> {
+        tree.nodes[parent]
+            .children
+            .iter()
+            .copied()
+            .filter(|&c| mapping[c].is_none())
+            .collect()
+    }
+
+    fn node_ref(&self, tree: &Tree, idx: usize) -> NodeRef {
+        NodeRef {
+            path: tree.path(idx),
+            kind: tree.nodes[idx].kind.clone(),
+            span: tree.nodes[idx].span,
+        }
+    }
+
+    fn generate(&self) -> Vec<Edit> {
+        let mut edits = vec![];
+
+        for l in 1..self.l.nodes.len() {
+            let l_parent = self.l.nodes[l].parent.unwrap();
+
+            match self.l2r[l] {
+                None => {
+                    // Descendants of deleted nodes are not reported.
+                    if self.l2r[l_parent].is_some() {
+                        edits.push(Edit::Delete {
+                            node: self.node_ref(self.l, l),
+                        });
+                    }
+                }
+                Some(r) => {
+                    let r_parent = self.r.nodes[r].parent.unwrap();
+
+                    let moved = self.l2r[l_parent] != Some(r_parent)
+                        || match (&self.l.nodes[l].edge, &self.r.nodes[r].edge) {
+                            (
+                                Some(PathSegment::Field { key: l_key, .. }),
+                                Some(PathSegment::Field { key: r_key, .. }),
+                            ) => l_key != r_key,
+                            (Some(PathSegment::Index(..)), Some(PathSegment::Index(..))) => false,
+                            (None, None) => false,
+                            _ => true,
+                        };
+                    if moved {
+                        edits.push(Edit::Move {
+                            from: self.node_ref(self.l, l),
+                            to: self.node_ref(self.r, r),
+                        });
+                    }
+
+                    if self.l.nodes[l].label != self.r.nodes[r].label {
+                        edits.push(Edit::Update {
+                            from: self.node_ref(self.l, l),
+                            to: self.node_ref(self.r, r),
+                            left: Node(self.l.nodes[l].label.clone().unwrap_or_default()),
+                            right: Node(self.r.nodes[r].label.clone().unwrap_or_default()),
+                        });
+                    }
+                }
+            }
+
+            // Reordered items of a list
+            if let Some(r) = self.l2r[l] {
+                let items = self.l.nodes[l]
+                    .children
+                    .iter()
+                    .copied()
+                    .filter_map(|lc| {
+                        let rc = self.l2r[lc]?;
+                        if self.r.nodes[rc].parent != Some(r) {
+                            return None;
+                        }
+                        match (&self.l.nodes[lc].edge, &self.r.nodes[rc].edge) {
+                            (Some(PathSegment::Index(..)), Some(PathSegment::Index(..))) => {
+                                Some((lc, rc))
+                            }
+                            _ => None,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                // Items in the longest increasing subsequence are not moved.
+                let mut kept = vec![false; items.len()];
+                for i in longest_increasing(&items.iter().map(|v| v.1).collect::<Vec<_>>()) {
+                    kept[i] = true;
+                }
+
+                for (i, &(lc, rc)) in items.iter().enumerate() {
+                    if !kept[i] {
+                        edits.push(Edit::Move {
+                            from: self.node_ref(self.l, lc),
+                            to: self.node_ref(self.r, rc),
+                        });
+                    }
+                }
+            }
+        }
+
+        for r in 1..self.r.nodes.len() {
+            let r_parent = self.r.nodes[r].parent.unwrap();
+            if self.r2l[r].is_none() && self.r2l[r_parent].is_some() {
+                edits.push(Edit::Insert {
+                    node: self.node_ref(self.r, r),
+                });
+            }
+        }
+
+        edits
+    }
+}
+
+/// Returns pairs of the longest common subsequence of `a` and `b`.
+fn lcs<T, F>(a: &[T], b: &[T], eq: F) -> Vec<(T, T)>
+where
+    T: Copy,
+    F: Fn(&T, &T) -> bool,
+{
+    // table[i][j] = length of lcs of a[i..] and b[j..]
+    let mut table = vec![vec![0_usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            table[i][j] = if eq(&a[i], &b[j]) {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+
+    let mut pairs = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if eq(&a[i], &b[j]) {
+            pairs.push((a[i], b[j]));
+            i += 1;
+            j += 1;
+        } else if table[i + 1][j] >= table[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+/// Returns indexes of the longest increasing subsequence of `seq`.
+fn longest_increasing(seq: &[usize]) -> Vec<usize> {
+    // tails[k] = index of the smallest tail of increasing subsequences of length
+    // k + 1
+    let mut tails: Vec<usize> = vec![];
+    let mut prev = vec![None; seq.len()];
+
+    for (i, &v) in seq.iter().enumerate() {
+        let pos = tails.partition_point(|&t| seq[t] < v);
+        if pos > 0 {
+            prev[i] = Some(tails[pos - 1]);
+        }
+        if pos == tails.len() {
+            tails.push(i);
+        } else {
+            tails[pos] = i;
+        }
+    }
+
+    let mut indexes = vec![];
+    let mut cur = tails.last().copied();
+    while let Some(i) = cur {
+        indexes.push(i);
+        cur = prev[i];
+    }
+    indexes.reverse();
+    indexes
+}
+
+fn postorder(tree: &Tree) -> Vec<usize> {
+    fn visit(tree: &Tree, idx: usize, out: &mut Vec<usize>) {
+        for &c in &tree.nodes[idx].children {
+            visit(tree, c, out);
+        }
+        out.push(idx);
+    }
+
+    let mut out = Vec::with_capacity(tree.nodes.len());
+    visit(tree, 0, &mut out);
+    out.pop();
+    out
+}
@@ -1,4 +1,4 @@
-use crate::{Ctx, Diff, DiffResult, Node};
+use crate::{Ctx, Diff, DiffResult, Node, ToTree, TreeBuilder};
 use swc_common::EqIgnoreSpan;
 use swc_ecma_ast::*;
 
@@ -40,6 +40,11 @@ diff_enum!(
         TsAs,
         PrivateName,
         OptChain,
+        Pipeline,
+        TopicRef,
+        Do,
+        Record,
+        Tuple,
         Invalid
     ]
 );
@@ -126,6 +131,11 @@ diff_struct!(JSXExprContainer, [span, expr]);
 diff_enum!(JSXExpr, [JSXEmptyExpr, Expr]);
 diff_struct!(PrivateName, [span, id]);
 diff_struct!(OptChainExpr, [span, question_dot_token, expr]);
+diff_struct!(PipelineExpr, [span, left, right]);
+diff_struct!(TopicRef, [span]);
+diff_struct!(DoExpr, [span, body]);
+diff_struct!(RecordLit, [span, props]);
+diff_struct!(TupleLit, [span, elems]);
 diff_struct!(SpreadElement, [dot3_token, expr]);
 diff_struct!(Super, [span]);
 diff_struct!(Import, [span]);
@@ -151,6 +161,10 @@ impl Diff for StrKind {
     }
 }
 
+impl ToTree for StrKind {
+    fn to_tree(&self, _: &mut TreeBuilder) {}
+}
+
 impl Diff for Tpl {
     fn diff(&mut self, other: &mut Self, ctx: &mut Ctx) -> DiffResult {
         if self.eq_ignore_span(&*other) {
@@ -164,3 +178,13 @@ impl Diff for Tpl {
         })
     }
 }
+
+impl ToTree for Tpl {
+    fn to_tree(&self, b: &mut TreeBuilder) {
+        b.node("Tpl", |b| {
+            b.field("Tpl", "span", &self.span);
+            b.field("Tpl", "exprs", &self.exprs);
+            b.field("Tpl", "quasis", &self.quasis);
+        });
+    }
+}
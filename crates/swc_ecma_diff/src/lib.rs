@@ -1,11 +1,18 @@
-pub use self::ctx::{Ctx, PathComponent};
+pub use self::{
+    ctx::{Ctx, PathComponent},
+    edit::{edit_script, Edit, NodeRef},
+    tree::{PathSegment, ToTree, TreeBuilder},
+};
 use std::fmt::{self, Debug, Display, Formatter};
 use swc_common::Span;
 
 #[macro_use]
 mod macros;
+mod css_ast;
 mod ctx;
+mod edit;
 mod js_ast;
+mod tree;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Config {
@@ -13,6 +13,12 @@ macro_rules! trivial {
                 })
             }
         }
+
+        impl crate::ToTree for $T {
+            fn to_tree(&self, b: &mut crate::TreeBuilder) {
+                b.leaf(stringify!($T), format!("{:?}", self));
+            }
+        }
     };
 
     (
@@ -62,6 +68,16 @@ macro_rules! diff_struct {
                 result
             }
         }
+
+        impl crate::ToTree for $T {
+            fn to_tree(&self, b: &mut crate::TreeBuilder) {
+                b.node(stringify!($T), |b| {
+                    $(
+                        b.field(stringify!($T), stringify!($field), &self.$field);
+                    )*
+                });
+            }
+        }
     };
 }
 
@@ -90,6 +106,8 @@ macro_rules! diff_enum {
                             $T::$Variant(r),
                         ) => crate::Diff::diff(l,r,ctx),
                     )*
+                    // Unreachable for enums with only one variant.
+                    #[allow(unreachable_patterns)]
                     _ => crate::DiffResult::Different(crate::Difference {
                         path: ctx.path.clone(),
                         left: crate::Node(format!("{:?}", self)),
@@ -98,6 +116,16 @@ macro_rules! diff_enum {
                 }
             }
         }
+
+        impl crate::ToTree for $T {
+            fn to_tree(&self, b: &mut crate::TreeBuilder) {
+                match self {
+                    $(
+                        $T::$Variant(v) => crate::ToTree::to_tree(v, b),
+                    )*
+                }
+            }
+        }
     };
 }
 
@@ -0,0 +1,282 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
+};
+use swc_atoms::JsWord;
+use swc_common::{Span, DUMMY_SP};
+
+/// Converts a node into a generic labeled tree, which is used to compute edit
+/// scripts.
+#[auto_impl::auto_impl(Box)]
+pub trait ToTree {
+    fn to_tree(&self, b: &mut TreeBuilder);
+}
+
+/// An edge from a node to its child.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    Field { struct_name: JsWord, key: JsWord },
+    Index(usize),
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PathSegment::Field { struct_name, key } => write!(f, "({}.{})", struct_name, key),
+            PathSegment::Index(idx) => write!(f, "[{}]", idx),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct TreeNode {
+    /// Name of the type, or `Struct.field` for lists.
+    pub kind: JsWord,
+    /// The value of a leaf node.
+    pub label: Option<String>,
+    pub span: Span,
+    pub parent: Option<usize>,
+    pub edge: Option<PathSegment>,
+    pub children: Vec<usize>,
+    /// Hash of the subtree, which does not depend on spans.
+    pub hash: u64,
+    /// Number of nodes in the subtree, including this node.
+    pub size: usize,
+}
+
+/// Nodes are stored in preorder.
+#[derive(Debug, Default)]
+pub(crate) struct Tree {
+    pub nodes: Vec<TreeNode>,
+}
+
+impl Tree {
+    pub fn build<T>(node: &T) -> Self
+    where
+        T: ?Sized + ToTree,
+    {
+        let mut b = TreeBuilder {
+            tree: Tree {
+                nodes: vec![TreeNode {
+                    kind: "Root".into(),
+                    label: None,
+                    span: DUMMY_SP,
+                    parent: None,
+                    edge: None,
+                    children: Default::default(),
+                    hash: 0,
+                    size: 1,
+                }],
+            },
+            cur: 0,
+            edge: None,
+        };
+        node.to_tree(&mut b);
+
+        let mut tree = b.tree;
+        tree.finalize();
+        tree
+    }
+
+    /// Returns the real root, skipping the synthetic one.
+    pub fn root(&self) -> Option<usize> {
+        self.nodes[0].children.first().copied()
+    }
+
+    pub fn path(&self, mut idx: usize) -> Vec<PathSegment> {
+        let mut path = vec![];
+        while let Some(edge) = &self.nodes[idx].edge {
+            path.push(edge.clone());
+            idx = match self.nodes[idx].parent {
+                Some(v) => v,
+                None => break,
+            };
+        }
+        path.reverse();
+        path
+    }
+
+    /// Computes hashes, sizes and spans of nodes which don't have their own
+    /// span.
+    fn finalize(&mut self) {
+        for idx in (0..self.nodes.len()).rev() {
+            let mut hasher = DefaultHasher::new();
+            self.nodes[idx].kind.hash(&mut hasher);
+            self.nodes[idx].label.hash(&mut hasher);
+
+            let mut size = 1;
+            let mut span = self.nodes[idx].span;
+            for &child in &self.nodes[idx].children {
+                let child = &self.nodes[child];
+                match &child.edge {
+                    Some(PathSegment::Field { key, .. }) => key.hash(&mut hasher),
+                    _ => 0.hash(&mut hasher),
+                }
+                child.hash.hash(&mut hasher);
+                size += child.size;
+
+                if self.nodes[idx].span.is_dummy() && !child.span.is_dummy() {
+                    span = if span.is_dummy() {
+                        child.span
+                    } else {
+                        span.to(child.span)
+                    };
+                }
+            }
+
+            let node = &mut self.nodes[idx];
+            node.hash = hasher.finish();
+            node.size = size;
+            node.span = span;
+        }
+
+        // Leaves like identifiers are reported with the span of the parent.
+        for idx in 1..self.nodes.len() {
+            if self.nodes[idx].span.is_dummy() {
+                if let Some(parent) = self.nodes[idx].parent {
+                    self.nodes[idx].span = self.nodes[parent].span;
+                }
+            }
+        }
+    }
+}
+
+/// Used by [ToTree] to build a tree.
+pub struct TreeBuilder {
+    tree: Tree,
+    cur: usize,
+    /// The edge to use for the next node.
+    edge: Option<PathSegment>,
+}
+
+impl TreeBuilder {
+    fn add(&mut self, kind: JsWord, label: Option<String>, span: Span) -> usize {
+        let idx = self.tree.nodes.len();
+        self.tree.nodes.push(TreeNode {
+            kind,
+            label,
+            span,
+            parent: Some(self.cur),
+            edge: self.edge.take(),
+            children: Default::default(),
+            hash: 0,
+            size: 1,
+        });
+        self.tree.nodes[self.cur].children.push(idx);
+        idx
+    }
+
+    /// Adds a node with children.
+    pub fn node<F>(&mut self, kind: &str, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let idx = self.add(kind.into(), None, DUMMY_SP);
+
+        let old = self.cur;
+        self.cur = idx;
+        op(self);
+        self.cur = old;
+    }
+
+    /// Adds a node without children.
+    pub fn leaf(&mut self, kind: &str, label: String) {
+        self.add(kind.into(), Some(label), DUMMY_SP);
+    }
+
+    /// Adds a field of the current node.
+    pub fn field<T>(&mut self, struct_name: &str, key: &str, value: &T)
+    where
+        T: ?Sized + ToTree,
+    {
+        self.edge = Some(PathSegment::Field {
+            struct_name: struct_name.into(),
+            key: key.into(),
+        });
+        value.to_tree(self);
+        self.edge = None;
+    }
+
+    /// Adds a list. Each item becomes a child of the list node.
+    pub fn list<T>(&mut self, items: &[T])
+    where
+        T: ToTree,
+    {
+        let kind = match &self.edge {
+            Some(PathSegment::Field { struct_name, key }) => format!("{}.{}", struct_name, key),
+            _ => "Vec".into(),
+        };
+
+        self.node(&kind, |b| {
+            for (idx, item) in items.iter().enumerate() {
+                b.edge = Some(PathSegment::Index(idx));
+                item.to_tree(b);
+                b.edge = None;
+            }
+        });
+    }
+
+    /// Records a span. The `span` field of a node becomes the span of the
+    /// node, and other spans like `dot3_token` are added as leaves.
+    pub fn span(&mut self, span: Span) {
+        match &self.edge {
+            Some(PathSegment::Field { key, .. }) if &**key == "span" => {
+                self.tree.nodes[self.cur].span = span;
+                self.edge = None;
+            }
+            _ => {
+                self.add("Span".into(), Some(String::new()), span);
+            }
+        }
+    }
+
+    /// Records `None`. This only adds a node for an item of a list, so that
+    /// indexes are preserved.
+    pub fn none(&mut self) {
+        match self.edge {
+            Some(PathSegment::Index(..)) => {
+                self.add("None".into(), Some(String::new()), DUMMY_SP);
+            }
+            _ => {
+                self.edge = None;
+            }
+        }
+    }
+}
+
+impl ToTree for Span {
+    fn to_tree(&self, b: &mut TreeBuilder) {
+        b.span(*self);
+    }
+}
+
+impl<T> ToTree for Vec<T>
+where
+    T: ToTree,
+{
+    fn to_tree(&self, b: &mut TreeBuilder) {
+        b.list(self);
+    }
+}
+
+impl<T> ToTree for Option<T>
+where
+    T: ToTree,
+{
+    fn to_tree(&self, b: &mut TreeBuilder) {
+        match self {
+            Some(v) => v.to_tree(b),
+            None => b.none(),
+        }
+    }
+}
+
+impl<S> ToTree for string_cache::Atom<S>
+where
+    S: string_cache::StaticAtomSet,
+{
+    fn to_tree(&self, b: &mut TreeBuilder) {
+        b.leaf("Atom", format!("{:?}", &**self));
+    }
+}
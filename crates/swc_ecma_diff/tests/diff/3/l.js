@@ -0,0 +1,8 @@
+function greet(name) {
+    console.log("Hello, " + name);
+}
+
+const a = 1;
+const b = 2;
+
+greet("world");
@@ -0,0 +1,9 @@
+const b = 2;
+const a = 1;
+
+function greet(who) {
+    console.log("Hello, " + who);
+}
+
+greet("world");
+greet("swc");
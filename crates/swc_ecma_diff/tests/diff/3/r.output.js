@@ -0,0 +1,4 @@
+function greet(who) {
+    console.log("Hello, " + who);
+}
+greet("swc");
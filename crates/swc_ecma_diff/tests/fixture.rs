@@ -1,11 +1,13 @@
 use std::path::{Path, PathBuf};
 use swc_common::{input::SourceFileInput, sync::Lrc, SourceMap};
+use swc_css_ast::Stylesheet;
+use swc_css_parser::parser::ParserConfig;
 use swc_ecma_ast::Module;
 use swc_ecma_codegen::{
     text_writer::{JsWriter, WriteJs},
     Emitter,
 };
-use swc_ecma_diff::{Config, Ctx, Diff};
+use swc_ecma_diff::{edit_script, Config, Ctx, Diff, Edit};
 use swc_ecma_parser::{lexer::Lexer, EsConfig, Parser, Syntax};
 use testing::NormalizedOutput;
 
@@ -27,22 +29,42 @@ fn parse(cm: Lrc<SourceMap>, path: &Path) -> Module {
     parser.parse_module().unwrap()
 }
 
+fn parse_css(cm: Lrc<SourceMap>, path: &Path) -> Stylesheet {
+    let fm = cm.load_file(path).unwrap();
+
+    let mut errors = vec![];
+    let ss = swc_css_parser::parse_file(
+        &fm,
+        ParserConfig {
+            parse_values: true,
+            ..Default::default()
+        },
+        &mut errors,
+    )
+    .unwrap();
+    assert_eq!(errors, vec![]);
+
+    ss
+}
+
 #[testing::fixture("tests/diff/**/l.js")]
 fn diff(l: PathBuf) {
     let r = l.with_file_name("r.js");
     let spec = l.with_file_name("output.swc-diff");
 
-    let (diff_str, l_str, r_str) = testing::run_test(false, |cm, _handler| {
+    let (diff_str, edits_str, l_str, r_str) = testing::run_test(false, |cm, _handler| {
         let mut l = parse(cm.clone(), &l);
         let mut r = parse(cm.clone(), &r);
 
+        let edits = edit_script(&l, &r);
+
         let mut ctx = Ctx::new(Config { ignore_span: true });
         let res = l.diff(&mut r, &mut ctx);
 
         let l = print(cm.clone(), &[l]);
         let r = print(cm, &[r]);
 
-        Ok((format!("{}", res), l, r))
+        Ok((format!("{}", res), print_edits(&edits), l, r))
     })
     .unwrap();
 
@@ -57,6 +79,40 @@ fn diff(l: PathBuf) {
     NormalizedOutput::from(diff_str)
         .compare_to_file(&spec)
         .unwrap();
+
+    NormalizedOutput::from(edits_str)
+        .compare_to_file(&l.with_file_name("output.swc-edits"))
+        .unwrap();
+}
+
+#[testing::fixture("tests/css/**/l.css")]
+fn css_diff(l: PathBuf) {
+    let r = l.with_file_name("r.css");
+
+    let (diff_str, edits_str) = testing::run_test(false, |cm, _handler| {
+        let mut l = parse_css(cm.clone(), &l);
+        let mut r = parse_css(cm, &r);
+
+        let edits = edit_script(&l, &r);
+
+        let mut ctx = Ctx::new(Config { ignore_span: true });
+        let res = l.diff(&mut r, &mut ctx);
+
+        Ok((format!("{}", res), print_edits(&edits)))
+    })
+    .unwrap();
+
+    NormalizedOutput::from(diff_str)
+        .compare_to_file(&l.with_file_name("output.swc-diff"))
+        .unwrap();
+
+    NormalizedOutput::from(edits_str)
+        .compare_to_file(&l.with_file_name("output.swc-edits"))
+        .unwrap();
+}
+
+fn print_edits(edits: &[Edit]) -> String {
+    edits.iter().map(|e| format!("{}\n", e)).collect()
 }
 
 fn print<N: swc_ecma_codegen::Node>(cm: Lrc<SourceMap>, nodes: &[N]) -> String {
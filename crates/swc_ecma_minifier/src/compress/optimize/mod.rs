@@ -41,6 +41,7 @@ mod inline;
 mod join_vars;
 mod loops;
 mod ops;
+mod pure;
 mod sequences;
 mod strings;
 mod switches;
@@ -843,6 +844,23 @@ where
                 ..
             }) => return Some(e.take()),
 
+            // Calls to `pure_funcs` can be removed, but arguments may have side effects.
+            Expr::Call(CallExpr {
+                span,
+                callee: Callee::Expr(callee),
+                args,
+                ..
+            }) if self.is_pure_callee(callee) => {
+                tracing::debug!("ignore_return_value: Dropping a call to a pure function");
+                self.changed = true;
+
+                let mut seq = Expr::Seq(SeqExpr {
+                    span: *span,
+                    exprs: args.take().into_iter().map(|arg| arg.expr).collect(),
+                });
+                return self.ignore_return_value(&mut seq);
+            }
+
             // Pure calls can be removed
             Expr::Call(CallExpr {
                 callee: Callee::Expr(callee),
@@ -906,6 +924,19 @@ where
             | Expr::PrivateName(_)
             | Expr::Update(_) => return Some(e.take()),
 
+            // Property accesses are dropped if `pure_getters` allows it.
+            Expr::Member(MemberExpr { span, obj, prop }) if self.is_pure_prop_access(obj, prop) => {
+                tracing::debug!("ignore_return_value: Dropping a pure property access");
+                self.changed = true;
+
+                let mut exprs = vec![obj.take()];
+                if let MemberProp::Computed(prop) = prop {
+                    exprs.push(prop.expr.take());
+                }
+                let mut seq = Expr::Seq(SeqExpr { span: *span, exprs });
+                return self.ignore_return_value(&mut seq);
+            }
+
             // We drop `f.g` in
             //
             // function f() {
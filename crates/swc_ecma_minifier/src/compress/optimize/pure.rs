@@ -0,0 +1,111 @@
+use super::Optimizer;
+use crate::{mode::Mode, option::PureGetterOption};
+use swc_atoms::js_word;
+use swc_common::EqIgnoreSpan;
+use swc_ecma_ast::*;
+
+/// Methods related to the options `pure_funcs` and `pure_getters`.
+impl<M> Optimizer<'_, M>
+where
+    M: Mode,
+{
+    /// Returns `true` if `callee` is listed in `pure_funcs`.
+    pub(super) fn is_pure_callee(&self, callee: &Expr) -> bool {
+        self.options
+            .pure_funcs
+            .iter()
+            .any(|f| (**f).eq_ignore_span(callee))
+    }
+
+    /// Returns `true` if accessing `prop` of `obj` does not have a side effect
+    /// according to `pure_getters`.
+    ///
+    /// Note that side effects of `obj` and a computed `prop` are not
+    /// considered.
+    pub(super) fn is_pure_prop_access(&self, obj: &Expr, prop: &MemberProp) -> bool {
+        if has_getter(obj) {
+            return false;
+        }
+
+        match &self.options.pure_getters {
+            PureGetterOption::Bool(false) => false,
+            PureGetterOption::Bool(true) => !is_nullish(obj),
+            PureGetterOption::Strict => is_non_nullish(obj),
+            PureGetterOption::Str(names) => match prop {
+                MemberProp::Ident(prop) => names.contains(&prop.sym) && !is_nullish(obj),
+                _ => false,
+            },
+        }
+    }
+}
+
+/// Returns `true` if `e` is always `null` or `undefined`.
+fn is_nullish(e: &Expr) -> bool {
+    match e {
+        Expr::Paren(e) => is_nullish(&e.expr),
+        Expr::Lit(Lit::Null(..))
+        | Expr::Ident(Ident {
+            sym: js_word!("undefined"),
+            ..
+        })
+        | Expr::Unary(UnaryExpr {
+            op: op!("void"), ..
+        }) => true,
+        _ => false,
+    }
+}
+
+/// Returns `true` if accessing a property of `e` may invoke user code, like
+/// getters of an object literal or static getters of a class.
+fn has_getter(e: &Expr) -> bool {
+    match e {
+        Expr::Paren(e) => has_getter(&e.expr),
+        Expr::Object(obj) => obj.props.iter().any(|p| match p {
+            PropOrSpread::Spread(..) => true,
+            PropOrSpread::Prop(p) => match &**p {
+                Prop::Getter(..) => true,
+                // `__proto__: v` sets the prototype, which may have getters.
+                Prop::KeyValue(KeyValueProp { key, .. }) => is_proto_key(key),
+                _ => false,
+            },
+        }),
+        Expr::Class(ClassExpr { class, .. }) => {
+            // Static getters may be inherited from the super class.
+            class.super_class.is_some()
+                || class.body.iter().any(|m| match m {
+                    ClassMember::Method(ClassMethod {
+                        kind: MethodKind::Getter,
+                        is_static,
+                        ..
+                    }) => *is_static,
+                    _ => false,
+                })
+        }
+        _ => false,
+    }
+}
+
+fn is_proto_key(key: &PropName) -> bool {
+    match key {
+        PropName::Ident(Ident { sym, .. }) | PropName::Str(Str { value: sym, .. }) => {
+            &**sym == "__proto__"
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `e` is known to be neither `null` nor `undefined`.
+fn is_non_nullish(e: &Expr) -> bool {
+    match e {
+        Expr::Paren(e) => is_non_nullish(&e.expr),
+        Expr::Lit(Lit::Null(..)) => false,
+        Expr::Lit(..)
+        | Expr::Tpl(..)
+        | Expr::Array(..)
+        | Expr::Object(..)
+        | Expr::Fn(..)
+        | Expr::Arrow(..)
+        | Expr::Class(..) => true,
+        _ => false,
+    }
+}
@@ -22,7 +22,7 @@ use crate::{
     compress::compressor,
     marks::Marks,
     metadata::info_marker,
-    option::{ExtraOptions, MinifyOptions},
+    option::{ExtraOptions, MinifyOptions, NameCache},
     pass::{
         compute_char_freq::compute_char_freq, expand_names::name_expander, global_defs,
         mangle_names::name_mangler, mangle_props::mangle_properties,
@@ -54,12 +54,35 @@ const MAX_PAR_DEPTH: u8 = 3;
 
 #[inline]
 pub fn optimize(
+    m: Module,
+    cm: Lrc<SourceMap>,
+    comments: Option<&dyn Comments>,
+    timings: Option<&mut Timings>,
+    options: &MinifyOptions,
+    extra: &ExtraOptions,
+) -> Module {
+    optimize_with_name_cache(
+        m,
+        cm,
+        comments,
+        timings,
+        options,
+        extra,
+        &mut Default::default(),
+    )
+}
+
+/// Same as [optimize], but mangled names are read from `name_cache` and newly
+/// mangled names are stored in it. This allows separately minified files to
+/// agree on mangled names.
+pub fn optimize_with_name_cache(
     mut m: Module,
     _cm: Lrc<SourceMap>,
     comments: Option<&dyn Comments>,
     mut timings: Option<&mut Timings>,
     options: &MinifyOptions,
     extra: &ExtraOptions,
+    name_cache: &mut NameCache,
 ) -> Module {
     let _timer = timer!("minify");
 
@@ -147,11 +170,20 @@ pub fn optimize(
         // TODO: base54.reset();
 
         let char_freq_info = compute_char_freq(&m);
-        m.visit_mut_with(&mut name_mangler(mangle.clone(), char_freq_info, marks));
+        m.visit_mut_with(&mut name_mangler(
+            mangle.clone(),
+            char_freq_info,
+            marks,
+            &mut name_cache.vars.props,
+        ));
     }
 
     if let Some(property_mangle_options) = options.mangle.as_ref().and_then(|o| o.props.as_ref()) {
-        mangle_properties(&mut m, property_mangle_options.clone());
+        mangle_properties(
+            &mut m,
+            property_mangle_options.clone(),
+            &mut name_cache.props.props,
+        );
     }
 
     if let Some(ref mut t) = timings {
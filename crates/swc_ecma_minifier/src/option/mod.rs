@@ -73,13 +73,70 @@ pub struct ManglePropertiesOptions {
     pub regex: Option<Regex>,
 }
 
+/// Mangled names shared by separate runs of the minifier, like `nameCache` of
+/// terser.
+///
+/// The serialized form is compatible with terser.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NameCache {
+    /// Top-level variables. Used only if `mangle.toplevel` is enabled.
+    #[serde(default)]
+    pub vars: NameCacheEntries,
+
+    /// Properties. Used only if `mangle.props` is enabled.
+    #[serde(default)]
+    pub props: NameCacheEntries,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NameCacheEntries {
+    /// Original name to mangled name.
+    #[serde(default, with = "dollar_prefixed_keys")]
+    pub props: AHashMap<JsWord, JsWord>,
+}
+
+/// terser prefixes keys with `$` to avoid conflicts with properties of
+/// `Object.prototype`.
+mod dollar_prefixed_keys {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use swc_atoms::JsWord;
+    use swc_common::collections::AHashMap;
+
+    pub fn serialize<S>(map: &AHashMap<JsWord, JsWord>, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.collect_map(map.iter().map(|(k, v)| (format!("${}", k), v)))
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<AHashMap<JsWord, JsWord>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let map = AHashMap::<String, JsWord>::deserialize(d)?;
+
+        Ok(map
+            .into_iter()
+            .map(|(k, v)| match k.strip_prefix('$') {
+                Some(k) => (k.into(), v),
+                None => (k.into(), v),
+            })
+            .collect())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 #[serde(untagged)]
 pub enum PureGetterOption {
+    /// If `true`, property accesses are assumed to be side-effect-free unless
+    /// the object is `null` or `undefined`.
     Bool(bool),
+    /// Property accesses are side-effect-free only if the object is known to
+    /// be neither `null` nor `undefined`.
     #[serde(rename = "strict")]
     Strict,
+    /// Names of properties which can be accessed without side effects.
     Str(Vec<JsWord>),
 }
 
@@ -235,7 +292,15 @@ pub struct CompressOptions {
     #[serde(alias = "properties")]
     pub pure_getters: PureGetterOption,
 
-    // pure_funcs    : null,
+    /// Callees which don't have side effects, like `console.log`. Calls to
+    /// them are dropped if the return value is not used, while side effects
+    /// of the arguments are preserved.
+    ///
+    /// All expressions should have dummy span. Use [swc_ecma_utils::drop_span]
+    /// to remove spans.
+    #[serde(skip)]
+    pub pure_funcs: Vec<Box<Expr>>,
+
     #[serde(default)]
     #[serde(alias = "reduce_funcs")]
     pub reduce_fns: bool,
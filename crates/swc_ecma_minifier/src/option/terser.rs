@@ -9,7 +9,7 @@ use swc_common::{
     collections::AHashMap, input::SourceFileInput, sync::Lrc, FileName, SourceMap, DUMMY_SP,
 };
 use swc_ecma_ast::*;
-use swc_ecma_parser::{lexer::Lexer, Parser};
+use swc_ecma_parser::{lexer::Lexer, PResult, Parser};
 use swc_ecma_utils::drop_span;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -330,17 +330,7 @@ impl TerserCompressorOptions {
                 .into_iter()
                 .map(|(k, v)| {
                     let parse = |input: String| {
-                        let fm = cm.new_source_file(FileName::Anon, input);
-
-                        let lexer = Lexer::new(
-                            Default::default(),
-                            Default::default(),
-                            SourceFileInput::from(&*fm),
-                            None,
-                        );
-                        let mut parser = Parser::new_from(lexer);
-
-                        parser.parse_expr().map(drop_span).unwrap_or_else(|err| {
+                        parse_expr(&cm, input).unwrap_or_else(|err| {
                             panic!(
                                 "failed to parse `global_defs.{}` of minifier options: {:?}",
                                 k, err
@@ -403,10 +393,24 @@ impl TerserCompressorOptions {
             pure_getters: match self.pure_getters {
                 TerserPureGetterOption::Bool(v) => PureGetterOption::Bool(v),
                 TerserPureGetterOption::Strict => PureGetterOption::Strict,
+                // An untagged unit variant is only deserialized from `null`.
+                TerserPureGetterOption::Str(v) if v == "strict" => PureGetterOption::Strict,
                 TerserPureGetterOption::Str(v) => {
                     PureGetterOption::Str(v.split(',').map(From::from).collect())
                 }
             },
+            pure_funcs: self
+                .pure_funcs
+                .into_iter()
+                .map(|f| {
+                    parse_expr(&cm, f.clone()).unwrap_or_else(|err| {
+                        panic!(
+                            "failed to parse `{}` in `pure_funcs` of minifier options: {:?}",
+                            f, err
+                        )
+                    })
+                })
+                .collect(),
             reduce_fns: self.reduce_funcs,
             reduce_vars: self.reduce_vars,
             sequences: self
@@ -491,6 +495,20 @@ impl From<TerserTopRetainOption> for Vec<JsWord> {
     }
 }
 
+fn parse_expr(cm: &SourceMap, input: String) -> PResult<Box<Expr>> {
+    let fm = cm.new_source_file(FileName::Anon, input);
+
+    let lexer = Lexer::new(
+        Default::default(),
+        Default::default(),
+        SourceFileInput::from(&*fm),
+        None,
+    );
+    let mut parser = Parser::new_from(lexer);
+
+    parser.parse_expr().map(drop_span)
+}
+
 fn value_to_expr(v: Value) -> Box<Expr> {
     match v {
         Value::Null => Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
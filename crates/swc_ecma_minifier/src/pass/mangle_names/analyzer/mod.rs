@@ -15,12 +15,47 @@ pub(super) struct Analyzer {
 }
 
 impl Analyzer {
-    pub(super) fn into_rename_map(mut self, preserved: &AHashSet<Id>) -> AHashMap<Id, JsWord> {
+    /// `name_cache` contains mangled names of top-level bindings. Cached names
+    /// are reused and newly mangled top-level bindings are added to it.
+    ///
+    /// `name_cache` should be [None] unless `mangle.toplevel` is enabled, as
+    /// top-level bindings are not mangled in that case.
+    pub(super) fn into_rename_map(
+        mut self,
+        preserved: &AHashSet<Id>,
+        name_cache: Option<&mut AHashMap<JsWord, JsWord>>,
+    ) -> AHashMap<Id, JsWord> {
         let mut map = AHashMap::default();
 
-        let preserved_symbols = preserved.iter().cloned().map(|v| v.0).collect();
+        let mut preserved_symbols: AHashSet<JsWord> =
+            preserved.iter().cloned().map(|v| v.0).collect();
+
+        if let Some(name_cache) = name_cache.as_deref() {
+            for id in self.scope.decls() {
+                if preserved.contains(id) {
+                    continue;
+                }
+
+                if let Some(cached) = name_cache.get(&id.0) {
+                    if !preserved_symbols.contains(cached) {
+                        map.insert(id.clone(), cached.clone());
+                    }
+                }
+            }
+            // Other bindings should not use names reserved by other runs.
+            preserved_symbols.extend(name_cache.values().cloned());
+        }
+
         self.scope.rename(&mut map, preserved, &preserved_symbols);
 
+        if let Some(name_cache) = name_cache {
+            for id in self.scope.decls() {
+                if let Some(renamed) = map.get(id) {
+                    name_cache.insert(id.0.clone(), renamed.clone());
+                }
+            }
+        }
+
         map
     }
 
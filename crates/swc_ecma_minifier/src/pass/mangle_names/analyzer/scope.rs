@@ -38,6 +38,11 @@ impl Scope {
         }
     }
 
+    /// Declarations in this scope, excluding child scopes.
+    pub(super) fn decls(&self) -> impl '_ + Iterator<Item = &Id> {
+        self.data.decls.iter()
+    }
+
     pub(super) fn add_usage(&mut self, id: &Id) {
         if id.0 == js_word!("arguments") {
             return;
@@ -1,6 +1,7 @@
 use super::compute_char_freq::CharFreqInfo;
 use crate::{marks::Marks, option::MangleOptions};
-use swc_common::chain;
+use swc_atoms::JsWord;
+use swc_common::{chain, collections::AHashMap};
 use swc_ecma_visit::VisitMut;
 
 mod analyzer;
@@ -8,13 +9,14 @@ mod preserver;
 mod private_name;
 mod real_impl;
 
-pub(crate) fn name_mangler(
+pub(crate) fn name_mangler<'a>(
     options: MangleOptions,
     char_freq_info: CharFreqInfo,
     marks: Marks,
-) -> impl VisitMut {
+    name_cache: &'a mut AHashMap<JsWord, JsWord>,
+) -> impl 'a + VisitMut {
     chain!(
         self::private_name::private_name_mangler(options.keep_private_props),
-        self::real_impl::name_mangler(options, char_freq_info, marks)
+        self::real_impl::name_mangler(options, char_freq_info, marks, name_cache)
     )
 }
@@ -1,22 +1,40 @@
 use super::{analyzer::Analyzer, preserver::idents_to_preserve};
 use crate::{marks::Marks, option::MangleOptions, pass::compute_char_freq::CharFreqInfo};
+use swc_atoms::JsWord;
+use swc_common::collections::AHashMap;
 use swc_ecma_ast::*;
 use swc_ecma_transforms::hygiene::rename;
 use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith, VisitWith};
 
-pub(crate) fn name_mangler(
+pub(crate) fn name_mangler<'a>(
     options: MangleOptions,
     _char_freq_info: CharFreqInfo,
     _marks: Marks,
-) -> impl VisitMut {
-    Mangler { options }
+    name_cache: &'a mut AHashMap<JsWord, JsWord>,
+) -> impl 'a + VisitMut {
+    Mangler {
+        options,
+        name_cache,
+    }
 }
 
-struct Mangler {
+struct Mangler<'a> {
     options: MangleOptions,
+    /// Mangled names of top-level bindings.
+    name_cache: &'a mut AHashMap<JsWord, JsWord>,
+}
+
+impl Mangler<'_> {
+    fn top_level_name_cache(&mut self) -> Option<&mut AHashMap<JsWord, JsWord>> {
+        if self.options.top_level {
+            Some(&mut *self.name_cache)
+        } else {
+            None
+        }
+    }
 }
 
-impl VisitMut for Mangler {
+impl VisitMut for Mangler<'_> {
     noop_visit_mut_type!();
 
     fn visit_mut_module(&mut self, m: &mut Module) {
@@ -29,7 +47,7 @@ impl VisitMut for Mangler {
             };
             m.visit_with(&mut analyzer);
 
-            analyzer.into_rename_map(&preserved)
+            analyzer.into_rename_map(&preserved, self.top_level_name_cache())
         };
 
         m.visit_mut_with(&mut rename(&map));
@@ -45,7 +63,7 @@ impl VisitMut for Mangler {
             };
             s.visit_with(&mut analyzer);
 
-            analyzer.into_rename_map(&preserved)
+            analyzer.into_rename_map(&preserved, self.top_level_name_cache())
         };
 
         s.visit_mut_with(&mut rename(&map));
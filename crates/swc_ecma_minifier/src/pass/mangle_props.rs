@@ -40,6 +40,9 @@ struct ManglePropertiesState {
     // Cache of already mangled names
     cache: AHashMap<JsWord, JsWord>,
 
+    // Mangled names from the name cache, which should not be generated again
+    cached_names: AHashSet<JsWord>,
+
     // Numbers to pass to base54()
     n: usize,
 }
@@ -84,9 +87,14 @@ impl ManglePropertiesState {
             if let Some(cached) = self.cache.get(name) {
                 Some(cached.clone())
             } else {
-                let sym = incr_base54(&mut self.n).1;
+                let mangled_name: JsWord = loop {
+                    let sym: JsWord = incr_base54(&mut self.n).1.into();
+
+                    if !self.cached_names.contains(&sym) {
+                        break sym;
+                    }
+                };
 
-                let mangled_name: JsWord = sym.into();
                 self.cache.insert(name.clone(), mangled_name.clone());
                 Some(mangled_name)
             }
@@ -96,9 +104,17 @@ impl ManglePropertiesState {
     }
 }
 
-pub(crate) fn mangle_properties(m: &mut Module, options: ManglePropertiesOptions) {
+/// `name_cache` is used as the initial mapping and updated with newly mangled
+/// names.
+pub(crate) fn mangle_properties(
+    m: &mut Module,
+    options: ManglePropertiesOptions,
+    name_cache: &mut AHashMap<JsWord, JsWord>,
+) {
     let mut state = ManglePropertiesState {
         options,
+        cache: name_cache.clone(),
+        cached_names: name_cache.values().cloned().collect(),
         ..Default::default()
     };
 
@@ -109,6 +125,8 @@ pub(crate) fn mangle_properties(m: &mut Module, options: ManglePropertiesOptions
     });
 
     m.visit_mut_with(&mut Mangler { state: &mut state });
+
+    *name_cache = state.cache;
 }
 
 // Step 1 -- collect candidates to mangle
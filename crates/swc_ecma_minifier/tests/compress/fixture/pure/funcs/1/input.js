@@ -0,0 +1,6 @@
+export function f(a) {
+    debugLog("value", a);
+    console.log(a.b(), 1);
+    invariant(a, "a is required");
+    return a;
+}
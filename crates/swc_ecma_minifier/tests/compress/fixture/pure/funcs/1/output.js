@@ -0,0 +1,5 @@
+export function f(a) {
+    a.b();
+    invariant(a, "a is required");
+    return a;
+}
@@ -0,0 +1,14 @@
+export function f(A) {
+    (class {
+        static get b() {
+            return g();
+        }
+    }).b;
+    (class extends A {}).b;
+    (class {
+        get b() {
+            return g();
+        }
+    }).b;
+    return A;
+}
@@ -0,0 +1,10 @@
+export function f(A) {
+    (class {
+        static get b() {
+            return g();
+        }
+    }).b;
+    (class extends A {
+    }).b;
+    return A;
+}
@@ -0,0 +1,6 @@
+export function f(a) {
+    ({ __proto__: a }).b;
+    ({ "__proto__": a }).b;
+    ({ ["__proto__"]: a }).b;
+    return a;
+}
@@ -0,0 +1,7 @@
+export function f(a) {
+    a[g()];
+    ({}).b;
+    [].length;
+    null.b;
+    return a;
+}
@@ -0,0 +1,9 @@
+export function f(a) {
+    a.b;
+    a.b.c;
+    a[g()];
+    ({}).b;
+    [].length;
+    null.b;
+    return a;
+}
@@ -0,0 +1,5 @@
+export function f(a) {
+    g();
+    null.b;
+    return a;
+}
@@ -5,6 +5,7 @@ use std::{
     path::{Path, PathBuf},
     process::Command,
 };
+use swc_atoms::JsWord;
 use swc_common::{
     comments::SingleThreadedComments, errors::Handler, input::SourceFileInput, sync::Lrc, FileName,
     Mark, SourceFile, SourceMap,
@@ -15,8 +16,8 @@ use swc_ecma_codegen::{
     Emitter,
 };
 use swc_ecma_minifier::{
-    optimize,
-    option::{ExtraOptions, MangleOptions, ManglePropertiesOptions, MinifyOptions},
+    optimize, optimize_with_name_cache,
+    option::{ExtraOptions, MangleOptions, ManglePropertiesOptions, MinifyOptions, NameCache},
 };
 use swc_ecma_parser::{lexer::Lexer, Parser};
 use swc_ecma_transforms::{
@@ -276,3 +277,107 @@ fn exec(input: PathBuf) {
     })
     .unwrap()
 }
+
+fn mangle_with_name_cache(src: &str, top_level: bool, name_cache: &mut NameCache) -> String {
+    testing::run_test2(false, |cm, _handler| {
+        let mut m = parse_fm(cm.new_source_file(FileName::Anon, src.into()));
+
+        let top_level_mark = Mark::fresh(Mark::root());
+
+        m.visit_mut_with(&mut resolver_with_mark(top_level_mark));
+
+        let m = optimize_with_name_cache(
+            m,
+            cm.clone(),
+            None,
+            None,
+            &MinifyOptions {
+                mangle: Some(MangleOptions {
+                    props: Some(Default::default()),
+                    top_level,
+                    ..Default::default()
+                }),
+                compress: None,
+                ..Default::default()
+            },
+            &ExtraOptions { top_level_mark },
+            name_cache,
+        );
+
+        Ok(print(cm, &m, true))
+    })
+    .unwrap()
+}
+
+#[test]
+fn name_cache() {
+    let mangle =
+        |src: &str, name_cache: &mut NameCache| mangle_with_name_cache(src, true, name_cache);
+
+    let mut name_cache = NameCache::default();
+
+    mangle(
+        "var firstVariable = { myLongPropertyName: 1 };
+        console.log(firstVariable.myLongPropertyName);",
+        &mut name_cache,
+    );
+
+    let first_var = name_cache.vars.props[&JsWord::from("firstVariable")].clone();
+    let first_prop = name_cache.props.props[&JsWord::from("myLongPropertyName")].clone();
+
+    let output = mangle(
+        "var secondVariable = { anotherLongPropertyName: 2, myLongPropertyName: 3 };
+        var firstVariable = secondVariable.anotherLongPropertyName;
+        console.log(firstVariable, secondVariable.myLongPropertyName);",
+        &mut name_cache,
+    );
+
+    assert_eq!(
+        name_cache.vars.props[&JsWord::from("firstVariable")],
+        first_var
+    );
+    assert_ne!(
+        name_cache.vars.props[&JsWord::from("secondVariable")],
+        first_var
+    );
+    assert_eq!(
+        name_cache.props.props[&JsWord::from("myLongPropertyName")],
+        first_prop
+    );
+    assert_ne!(
+        name_cache.props.props[&JsWord::from("anotherLongPropertyName")],
+        first_prop
+    );
+    assert!(output.contains(&format!(".{}", first_prop)), "{}", output);
+
+    let json = serde_json::to_string(&name_cache).unwrap();
+    assert!(json.contains("\"$myLongPropertyName\""), "{}", json);
+
+    let deserialized: NameCache = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        deserialized.props.props[&JsWord::from("myLongPropertyName")],
+        first_prop
+    );
+}
+
+#[test]
+fn name_cache_without_top_level() {
+    let mut name_cache = NameCache::default();
+    name_cache
+        .vars
+        .props
+        .insert("firstVariable".into(), "cached".into());
+
+    let output = mangle_with_name_cache(
+        "var firstVariable = 1;
+        function f(secondVariable) {
+            return secondVariable;
+        }",
+        false,
+        &mut name_cache,
+    );
+
+    assert!(output.contains("var firstVariable"), "{}", output);
+    assert!(!output.contains("cached"), "{}", output);
+    assert_eq!(name_cache.vars.props.len(), 1);
+}
@@ -114,6 +114,12 @@ pub enum SyntaxError {
 
     NullishCoalescingWithLogicalOp,
 
+    TopicRefOutsidePipeline,
+    PipelineBodyWithoutTopic,
+    SpaceAfterRecordOrTupleHash,
+    InvalidRecordProp,
+    HoleInTuple,
+
     MultipleDefault {
         /// Span of the previous default case
         previous: Span,
@@ -441,6 +447,20 @@ impl SyntaxError {
                     .into()
             }
 
+            SyntaxError::TopicRefOutsidePipeline => {
+                "Topic reference is used outside of a pipeline body".into()
+            }
+            SyntaxError::PipelineBodyWithoutTopic => {
+                "Pipeline body should contain a topic reference".into()
+            }
+            SyntaxError::SpaceAfterRecordOrTupleHash => {
+                "`#` of a record or a tuple should be followed by `{` or `[` without a space".into()
+            }
+            SyntaxError::InvalidRecordProp => "Records can only contain key-value properties, \
+                                               shorthand properties and spreads"
+                .into(),
+            SyntaxError::HoleInTuple => "Tuples can't contain holes".into(),
+
             SyntaxError::TS1056 => {
                 "jsc.target should be es5 or upper to use getter / setter".into()
             }
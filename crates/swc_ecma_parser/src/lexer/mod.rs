@@ -349,6 +349,15 @@ impl<'a, I: Input> Lexer<'a, I> {
                     })));
                 }
 
+                // '|>'
+                if c == '|'
+                    && self.input.cur() == Some('>')
+                    && self.syntax.pipeline_operator().is_some()
+                {
+                    self.input.bump();
+                    return Ok(Some(Token::Pipeline));
+                }
+
                 BinOp(token)
             }
             '^' => {
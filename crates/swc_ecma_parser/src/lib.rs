@@ -92,6 +92,11 @@
 //!
 //! Enables typescript parser.
 //!
+//! ### `flow`
+//!
+//! Enables flow parser. Flow types are parsed into typescript types, so they
+//! can be stripped using the typescript transforms.
+//!
 //! ### `verify`
 //!
 //! Verify more errors, using `swc_ecma_visit`.
@@ -145,6 +150,11 @@ pub enum Syntax {
     #[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
     #[serde(rename = "typescript")]
     Typescript(TsConfig),
+    /// This variant requires the cargo feature `flow` to be enabled.
+    #[cfg(feature = "flow")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "flow")))]
+    #[serde(rename = "flow")]
+    Flow(FlowConfig),
 }
 
 impl Default for Syntax {
@@ -160,6 +170,8 @@ impl Syntax {
                 import_assertions, ..
             }) => import_assertions,
             Syntax::Typescript(_) => true,
+            #[cfg(feature = "flow")]
+            Syntax::Flow(_) => false,
         }
     }
 
@@ -178,7 +190,7 @@ impl Syntax {
         matches!(
             self,
             Syntax::Es(EsConfig { jsx: true, .. }) | Syntax::Typescript(TsConfig { tsx: true, .. })
-        )
+        ) || self.flow_config().map_or(false, |c| c.jsx)
     }
 
     pub const fn optional_chaining(self) -> bool {
@@ -203,7 +215,7 @@ impl Syntax {
                 decorators: true,
                 ..
             })
-        )
+        ) || self.flow_config().map_or(false, |c| c.decorators)
     }
 
     pub fn decorators_before_export(self) -> bool {
@@ -228,6 +240,45 @@ impl Syntax {
         matches!(self, Syntax::Typescript(..))
     }
 
+    /// Should we parse flow?
+    #[cfg(not(feature = "flow"))]
+    pub const fn flow(self) -> bool {
+        false
+    }
+
+    /// Should we parse flow?
+    #[cfg(feature = "flow")]
+    pub const fn flow(self) -> bool {
+        matches!(self, Syntax::Flow(..))
+    }
+
+    /// Flow types are parsed using the typescript parser.
+    pub(crate) const fn typescript_or_flow(self) -> bool {
+        self.typescript() || self.flow()
+    }
+
+    #[cfg(not(feature = "flow"))]
+    fn flow_config(self) -> Option<FlowConfig> {
+        None
+    }
+
+    #[cfg(feature = "flow")]
+    fn flow_config(self) -> Option<FlowConfig> {
+        match self {
+            Syntax::Flow(c) => Some(c),
+            _ => None,
+        }
+    }
+
+    pub fn pipeline_operator(self) -> Option<PipelineOperatorConfig> {
+        match self {
+            Syntax::Es(EsConfig {
+                pipeline_operator, ..
+            }) => pipeline_operator,
+            _ => None,
+        }
+    }
+
     pub fn export_default_from(self) -> bool {
         matches!(
             self,
@@ -245,12 +296,34 @@ impl Syntax {
         }
     }
 
+    pub fn do_expressions(self) -> bool {
+        matches!(
+            self,
+            Syntax::Es(EsConfig {
+                do_expressions: true,
+                ..
+            })
+        )
+    }
+
+    pub fn record_and_tuple(self) -> bool {
+        matches!(
+            self,
+            Syntax::Es(EsConfig {
+                record_and_tuple: true,
+                ..
+            })
+        )
+    }
+
     pub fn private_in_object(self) -> bool {
         match self {
             Syntax::Es(EsConfig {
                 private_in_object, ..
             }) => private_in_object,
             Syntax::Typescript(_) => true,
+            #[cfg(feature = "flow")]
+            Syntax::Flow(_) => false,
         }
     }
 
@@ -258,6 +331,8 @@ impl Syntax {
         match self {
             Syntax::Typescript(t) => !t.no_early_errors,
             Syntax::Es(..) => true,
+            #[cfg(feature = "flow")]
+            Syntax::Flow(..) => true,
         }
     }
 }
@@ -279,6 +354,16 @@ pub struct TsConfig {
     pub no_early_errors: bool,
 }
 
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowConfig {
+    #[serde(default)]
+    pub jsx: bool,
+
+    #[serde(default)]
+    pub decorators: bool,
+}
+
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EsConfig {
@@ -313,6 +398,47 @@ pub struct EsConfig {
 
     #[serde(default, rename = "privateInObject")]
     pub private_in_object: bool,
+
+    /// Hack-style pipeline operator (`|>`).
+    #[serde(default)]
+    pub pipeline_operator: Option<PipelineOperatorConfig>,
+
+    /// `do { ... }` expressions.
+    #[serde(default)]
+    pub do_expressions: bool,
+
+    /// Records (`#{}`) and tuples (`#[]`).
+    #[serde(default)]
+    pub record_and_tuple: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineOperatorConfig {
+    /// The token used as the topic reference.
+    #[serde(default)]
+    pub topic_token: TopicToken,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TopicToken {
+    #[serde(rename = "%")]
+    Percent,
+    #[serde(rename = "^^")]
+    DoubleCaret,
+    #[serde(rename = "@@")]
+    DoubleAt,
+    #[serde(rename = "^")]
+    Caret,
+    #[serde(rename = "#")]
+    Hash,
+}
+
+/// This value defaults to `%`.
+impl Default for TopicToken {
+    fn default() -> Self {
+        TopicToken::Percent
+    }
 }
 
 /// Syntactic context.
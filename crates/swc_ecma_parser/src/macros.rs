@@ -60,6 +60,9 @@ macro_rules! tok {
     ("=>") => {
         crate::token::Token::Arrow
     };
+    ("|>") => {
+        crate::token::Token::Pipeline
+    };
     ("...") => {
         crate::token::Token::DotDotDot
     };
@@ -83,7 +86,7 @@ macro_rules! tok {
         crate::token::Token::AssignOp(DivAssign)
     };
     ('%') => {
-        crate::token::Token::BinOp(Mod)
+        crate::token::Token::BinOp(crate::token::BinOpToken::Mod)
     };
     ('~') => {
         crate::token::Token::Tilde
@@ -298,6 +301,9 @@ macro_rules! tok {
     ("global") => {
         crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("global")))
     };
+    ("module") => {
+        crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("module")))
+    };
     ("require") => {
         crate::token::Token::Word(crate::token::Word::Ident(swc_atoms::js_word!("require")))
     };
@@ -1,6 +1,7 @@
 use super::{ident::MaybeOptionalIdentParser, *};
 use crate::{error::SyntaxError, lexer::TokenContext, parser::stmt::IsDirective, Tokens};
 use either::Either;
+use std::mem::take;
 use swc_atoms::js_word;
 use swc_common::{Spanned, SyntaxContext};
 
@@ -85,13 +86,13 @@ impl<'a, I: Tokens> Parser<I> {
             expect!(p, "class");
 
             let ident = p.parse_maybe_opt_binding_ident()?;
-            if p.input.syntax().typescript() {
+            if p.input.syntax().typescript_or_flow() {
                 if let Some(span) = ident.invalid_class_name() {
                     p.emit_err(span, SyntaxError::TS2414);
                 }
             }
 
-            let type_params = if p.input.syntax().typescript() {
+            let type_params = if p.input.syntax().typescript_or_flow() {
                 p.try_parse_ts_type_params()?
             } else {
                 None
@@ -99,13 +100,13 @@ impl<'a, I: Tokens> Parser<I> {
 
             let (mut super_class, mut super_type_params) = if eat!(p, "extends") {
                 let super_class = p.parse_lhs_expr().map(Some)?;
-                let super_type_params = if p.input.syntax().typescript() && is!(p, '<') {
+                let super_type_params = if p.input.syntax().typescript_or_flow() && is!(p, '<') {
                     Some(p.parse_ts_type_args()?)
                 } else {
                     None
                 };
 
-                if p.syntax().typescript() && eat!(p, ',') {
+                if p.syntax().typescript_or_flow() && eat!(p, ',') {
                     let exprs = p.parse_ts_heritage_clause()?;
 
                     for e in &exprs {
@@ -123,12 +124,12 @@ impl<'a, I: Tokens> Parser<I> {
                 p.emit_err(p.input.prev_span(), SyntaxError::TS1172);
 
                 p.parse_lhs_expr()?;
-                if p.input.syntax().typescript() && is!(p, '<') {
+                if p.input.syntax().typescript_or_flow() && is!(p, '<') {
                     p.parse_ts_type_args()?;
                 }
             };
 
-            let implements = if p.input.syntax().typescript() && eat!(p, "implements") {
+            let implements = if p.input.syntax().typescript_or_flow() && eat!(p, "implements") {
                 p.parse_ts_heritage_clause()?
             } else {
                 vec![]
@@ -136,7 +137,7 @@ impl<'a, I: Tokens> Parser<I> {
 
             {
                 // Handle TS1175
-                if p.input.syntax().typescript() && eat!(p, "implements") {
+                if p.input.syntax().typescript_or_flow() && eat!(p, "implements") {
                     p.emit_err(p.input.prev_span(), SyntaxError::TS1175);
 
                     p.parse_ts_heritage_clause()?;
@@ -144,11 +145,11 @@ impl<'a, I: Tokens> Parser<I> {
             }
 
             // Handle TS1173
-            if p.input.syntax().typescript() && eat!(p, "extends") {
+            if p.input.syntax().typescript_or_flow() && eat!(p, "extends") {
                 p.emit_err(p.input.prev_span(), SyntaxError::TS1173);
 
                 let sc = p.parse_lhs_expr()?;
-                let type_params = if p.input.syntax().typescript() && is!(p, '<') {
+                let type_params = if p.input.syntax().typescript_or_flow() && is!(p, '<') {
                     p.parse_ts_type_args().map(Some)?
                 } else {
                     None
@@ -258,7 +259,7 @@ impl<'a, I: Tokens> Parser<I> {
     }
 
     fn parse_maybe_decorator_args(&mut self, expr: Box<Expr>) -> PResult<Box<Expr>> {
-        let type_args = if self.input.syntax().typescript() && is!(self, '<') {
+        let type_args = if self.input.syntax().typescript_or_flow() && is!(self, '<') {
             Some(self.parse_ts_type_args()?)
         } else {
             None
@@ -325,7 +326,7 @@ impl<'a, I: Tokens> Parser<I> {
                     js_word!("declare"),
                     span!(self, start),
                 )));
-                let is_optional = self.input.syntax().typescript() && eat!(self, '?');
+                let is_optional = self.input.syntax().typescript_or_flow() && eat!(self, '?');
                 return self.make_method(
                     |p| p.parse_unique_formal_params(),
                     MakeMethodArgs {
@@ -343,7 +344,7 @@ impl<'a, I: Tokens> Parser<I> {
                     },
                 );
             } else if self.is_class_property(/* asi */ true)
-                || (self.syntax().typescript() && is!(self, '?'))
+                || (self.syntax().typescript_or_flow() && is!(self, '?'))
             {
                 // Property named `declare`
 
@@ -351,7 +352,7 @@ impl<'a, I: Tokens> Parser<I> {
                     js_word!("declare"),
                     span!(self, start),
                 )));
-                let is_optional = self.input.syntax().typescript() && eat!(self, '?');
+                let is_optional = self.input.syntax().typescript_or_flow() && eat!(self, '?');
                 return self.make_property(
                     start,
                     decorators,
@@ -387,7 +388,7 @@ impl<'a, I: Tokens> Parser<I> {
                     js_word!("static"),
                     static_token,
                 )));
-                let is_optional = self.input.syntax().typescript() && eat!(self, '?');
+                let is_optional = self.input.syntax().typescript_or_flow() && eat!(self, '?');
                 return self.make_method(
                     |p| p.parse_unique_formal_params(),
                     MakeMethodArgs {
@@ -405,7 +406,7 @@ impl<'a, I: Tokens> Parser<I> {
                     },
                 );
             } else if self.is_class_property(/* asi */ true)
-                || (self.syntax().typescript() && is!(self, '?'))
+                || (self.syntax().typescript_or_flow() && is!(self, '?'))
             {
                 // Property named `static`
 
@@ -419,7 +420,7 @@ impl<'a, I: Tokens> Parser<I> {
                         js_word!("static"),
                         static_token,
                     )));
-                    let is_optional = self.input.syntax().typescript() && eat!(self, '?');
+                    let is_optional = self.input.syntax().typescript_or_flow() && eat!(self, '?');
                     return self.make_property(
                         start,
                         decorators,
@@ -556,7 +557,11 @@ impl<'a, I: Tokens> Parser<I> {
             }
         }
 
-        if self.input.syntax().typescript()
+        if self.input.syntax().flow() && readonly.is_none() {
+            readonly = self.eat_flow_variance()?;
+        }
+
+        if self.input.syntax().typescript_or_flow()
             && !is_abstract
             && !is_override
             && accessibility.is_none()
@@ -604,7 +609,7 @@ impl<'a, I: Tokens> Parser<I> {
         } else {
             self.parse_class_prop_name()?
         };
-        let is_optional = self.input.syntax().typescript() && eat!(self, '?');
+        let is_optional = self.input.syntax().typescript_or_flow() && eat!(self, '?');
 
         if let Either::Right(PropName::Ident(i)) = &mut key {
             i.optional = is_optional;
@@ -625,14 +630,14 @@ impl<'a, I: Tokens> Parser<I> {
             let is_constructor = is_constructor(&key);
 
             if is_constructor {
-                if self.syntax().typescript() && is_override {
+                if self.syntax().typescript_or_flow() && is_override {
                     self.emit_err(
                         span!(self, start),
                         SyntaxError::TS1089(js_word!("override")),
                     );
                 }
 
-                if self.syntax().typescript() && is!(self, '<') {
+                if self.syntax().typescript_or_flow() && is!(self, '<') {
                     let start = cur_pos!(self);
                     if peeked_is!(self, '>') {
                         assert_and_bump!(self, '<');
@@ -656,7 +661,7 @@ impl<'a, I: Tokens> Parser<I> {
                 let params = self.parse_constructor_params()?;
                 expect!(self, ')');
 
-                if self.syntax().typescript() && is!(self, ':') {
+                if self.syntax().typescript_or_flow() && is!(self, ':') {
                     let start = cur_pos!(self);
                     let type_ann = self.parse_ts_type_ann(true, start)?;
 
@@ -666,7 +671,7 @@ impl<'a, I: Tokens> Parser<I> {
                 let body: Option<_> =
                     self.parse_fn_body(false, false, params.is_simple_parameter_list())?;
 
-                if self.syntax().typescript() && body.is_none() {
+                if self.syntax().typescript_or_flow() && body.is_none() {
                     // Declare constructors cannot have assignment pattern in parameters
                     for p in &params {
                         // TODO: Search deeply for assignment pattern using a Visitor
@@ -783,7 +788,8 @@ impl<'a, I: Tokens> Parser<I> {
             }
 
             // handle async foo(){}
-            let is_optional = is_optional || self.input.syntax().typescript() && eat!(self, '?');
+            let is_optional =
+                is_optional || self.input.syntax().typescript_or_flow() && eat!(self, '?');
             return self.make_method(
                 |p| p.parse_unique_formal_params(),
                 MakeMethodArgs {
@@ -893,7 +899,7 @@ impl<'a, I: Tokens> Parser<I> {
         if declare && key.is_left() {
             syntax_error!(self, key.span(), SyntaxError::DeclarePrivateIdentifier);
         }
-        let definite = self.input.syntax().typescript() && !is_optional && eat!(self, '!');
+        let definite = self.input.syntax().typescript_or_flow() && !is_optional && eat!(self, '!');
 
         let type_ann = self.try_parse_ts_type_ann()?;
 
@@ -952,12 +958,12 @@ impl<'a, I: Tokens> Parser<I> {
 
     fn is_class_method(&mut self) -> bool {
         is!(self, '(')
-            || (self.input.syntax().typescript() && is!(self, '<'))
-            || (self.input.syntax().typescript() && is!(self, JSXTagStart))
+            || (self.input.syntax().typescript_or_flow() && is!(self, '<'))
+            || (self.input.syntax().typescript_or_flow() && is!(self, JSXTagStart))
     }
 
     fn is_class_property(&mut self, asi: bool) -> bool {
-        (self.input.syntax().typescript() && is_one_of!(self, '!', ':'))
+        (self.input.syntax().typescript_or_flow() && is_one_of!(self, '!', ':'))
             || is_one_of!(self, '=', '}')
             || if asi {
                 is!(self, ';')
@@ -1058,7 +1064,7 @@ impl<'a, I: Tokens> Parser<I> {
         };
 
         self.with_ctx(ctx).parse_with(|p| {
-            let type_params = if p.syntax().typescript() {
+            let type_params = if p.syntax().typescript_or_flow() {
                 p.in_type().parse_with(|p| {
                     trace_cur!(p, parse_fn_args_body__type_params);
 
@@ -1097,17 +1103,20 @@ impl<'a, I: Tokens> Parser<I> {
             expect!(p, ')');
 
             // typescript extension
-            let return_type = if p.syntax().typescript() && is!(p, ':') {
+            let return_type = if p.syntax().typescript_or_flow() && is!(p, ':') {
                 p.parse_ts_type_or_type_predicate_ann(&tok!(':'))
                     .map(Some)?
             } else {
                 None
             };
+            if p.syntax().flow() {
+                p.skip_flow_predicate()?;
+            }
 
             let body: Option<_> =
                 p.parse_fn_body(is_async, is_generator, params.is_simple_parameter_list())?;
 
-            if p.syntax().typescript() && body.is_none() {
+            if p.syntax().typescript_or_flow() && body.is_none() {
                 // Declare functions cannot have assignment pattern in parameters
                 for param in &params {
                     // TODO: Search deeply for assignment pattern using a Visitor
@@ -1153,7 +1162,7 @@ impl<'a, I: Tokens> Parser<I> {
     where
         Self: FnBodyParser<T>,
     {
-        if self.ctx().in_declare && self.syntax().typescript() && is!(self, '{') {
+        if self.ctx().in_declare && self.syntax().typescript_or_flow() && is!(self, '{') {
             //            self.emit_err(
             //                self.ctx().span_of_fn_name.expect("we are not in function"),
             //                SyntaxError::TS1183,
@@ -1169,13 +1178,22 @@ impl<'a, I: Tokens> Parser<I> {
             is_continue_allowed: false,
             ..self.ctx()
         };
+        // Topic references in a function belong to the enclosing pipeline.
         let state = State {
             labels: vec![],
+            recover: self.state.recover,
+            topic_refs: take(&mut self.state.topic_refs),
             ..Default::default()
         };
-        self.with_ctx(ctx)
-            .with_state(state)
-            .parse_fn_body_inner(is_simple_parameter_list)
+        let (body, topic_refs) = {
+            let mut p = self.with_ctx(ctx);
+            let mut p = p.with_state(state);
+            let body = p.parse_fn_body_inner(is_simple_parameter_list);
+            (body, take(&mut p.state.topic_refs))
+        };
+        self.state.topic_refs = topic_refs;
+
+        body
     }
 }
 
@@ -1209,7 +1227,8 @@ impl<'a, I: Tokens> Parser<I> {
 
         match kind {
             MethodKind::Getter | MethodKind::Setter
-                if self.input.syntax().typescript() && self.input.target() == EsVersion::Es3 =>
+                if self.input.syntax().typescript_or_flow()
+                    && self.input.target() == EsVersion::Es3 =>
             {
                 self.emit_err(key.span(), SyntaxError::TS1056);
             }
@@ -1387,7 +1406,7 @@ impl<I: Tokens> FnBodyParser<BlockStmtOrExpr> for Parser<I> {
     fn parse_fn_body_inner(&mut self, is_simple_parameter_list: bool) -> PResult<BlockStmtOrExpr> {
         if is!(self, '{') {
             self.parse_block(false).map(|block_stmt| {
-                if !self.input.syntax().typescript() && !is_simple_parameter_list {
+                if !self.input.syntax().typescript_or_flow() && !is_simple_parameter_list {
                     if let Some(span) = has_use_strict(&block_stmt) {
                         self.emit_err(span, SyntaxError::IllegalLanguageModeDirective);
                     }
@@ -1406,12 +1425,12 @@ impl<I: Tokens> FnBodyParser<Option<BlockStmt>> for Parser<I> {
         is_simple_parameter_list: bool,
     ) -> PResult<Option<BlockStmt>> {
         // allow omitting body and allow placing `{` on next line
-        if self.input.syntax().typescript() && !is!(self, '{') && eat!(self, ';') {
+        if self.input.syntax().typescript_or_flow() && !is!(self, '{') && eat!(self, ';') {
             return Ok(None);
         }
         let block = self.include_in_expr(true).parse_block(true);
         block.map(|block_stmt| {
-            if !self.input.syntax().typescript() && !is_simple_parameter_list {
+            if !self.input.syntax().typescript_or_flow() && !is_simple_parameter_list {
                 if let Some(span) = has_use_strict(&block_stmt) {
                     self.emit_err(span, SyntaxError::IllegalLanguageModeDirective);
                 }
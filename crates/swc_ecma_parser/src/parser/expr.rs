@@ -1,6 +1,9 @@
 use super::{pat::PatType, util::ExprExt, *};
 use crate::{
-    lexer::TokenContext, parser::class_and_fn::IsSimpleParameterList, token::AssignOpToken,
+    lexer::TokenContext,
+    parser::class_and_fn::IsSimpleParameterList,
+    token::{AssignOpToken, BinOpToken},
+    TopicToken,
 };
 use either::Either;
 use swc_atoms::js_word;
@@ -37,7 +40,7 @@ impl<'a, I: Tokens> Parser<I> {
     pub(super) fn parse_assignment_expr(&mut self) -> PResult<Box<Expr>> {
         trace_cur!(self, parse_assignment_expr);
 
-        if self.input.syntax().typescript() && self.input.syntax().jsx() {
+        if self.input.syntax().typescript_or_flow() && self.input.syntax().jsx() {
             // Note: When the JSX plugin is on, type assertions (`<T> x`) aren't valid
             // syntax.
 
@@ -78,7 +81,7 @@ impl<'a, I: Tokens> Parser<I> {
     fn parse_assignment_expr_base(&mut self) -> PResult<Box<Expr>> {
         trace_cur!(self, parse_assignment_expr_base);
 
-        if self.input.syntax().typescript()
+        if self.input.syntax().typescript_or_flow()
             && (is_one_of!(self, '<', JSXTagStart))
             && (peeked_is!(self, IdentName) || peeked_is!(self, JSXName))
         {
@@ -157,7 +160,7 @@ impl<'a, I: Tokens> Parser<I> {
                 } else {
                     //It is an early Reference Error if IsValidSimpleAssignmentTarget of
                     // LeftHandSideExpression is false.
-                    if !self.input.syntax().typescript()
+                    if !self.input.syntax().typescript_or_flow()
                         && !cond.is_valid_simple_assignment_target(self.ctx().strict)
                     {
                         self.emit_err(cond.span(), SyntaxError::NotSimpleAssign)
@@ -168,7 +171,7 @@ impl<'a, I: Tokens> Parser<I> {
                         }
                         _ => false,
                     };
-                    if self.input.syntax().typescript() && is_eval_or_arguments {
+                    if self.input.syntax().typescript_or_flow() && is_eval_or_arguments {
                         self.emit_strict_mode_err(cond.span(), SyntaxError::TS1100);
                     }
 
@@ -239,6 +242,23 @@ impl<'a, I: Tokens> Parser<I> {
             .map(|s| s == start)
             .unwrap_or(false);
 
+        if let Some(config) = self.input.syntax().pipeline_operator() {
+            if self.is_topic_token(config.topic_token)? {
+                return self.parse_topic_ref(config.topic_token);
+            }
+        }
+
+        if self.input.syntax().do_expressions() && is!(self, "do") {
+            return self.parse_do_expr();
+        }
+
+        if self.input.syntax().record_and_tuple()
+            && is!(self, '#')
+            && (peeked_is!(self, '{') || peeked_is!(self, '['))
+        {
+            return self.parse_record_or_tuple();
+        }
+
         if let Some(tok) = self.input.cur() {
             match tok {
                 tok!("this") => {
@@ -273,7 +293,10 @@ impl<'a, I: Tokens> Parser<I> {
                         return self.parse_async_fn_expr();
                     }
 
-                    if can_be_arrow && self.input.syntax().typescript() && peeked_is!(self, '<') {
+                    if can_be_arrow
+                        && self.input.syntax().typescript_or_flow()
+                        && peeked_is!(self, '<')
+                    {
                         // try parsing `async<T>() => {}`
                         if let Some(res) = self.try_parse_ts(|p| {
                             let start = cur_pos!(p);
@@ -351,7 +374,7 @@ impl<'a, I: Tokens> Parser<I> {
         }
 
         if is!(self, "let")
-            || (self.input.syntax().typescript() && is!(self, IdentName))
+            || (self.input.syntax().typescript_or_flow() && is!(self, IdentName))
             || is!(self, IdentRef)
         {
             // TODO: Handle [Yield, Await]
@@ -379,7 +402,7 @@ impl<'a, I: Tokens> Parser<I> {
 
             if can_be_arrow && id.sym == js_word!("async") && is!(self, BindingIdent) {
                 let ident = self.parse_binding_ident()?;
-                if self.input.syntax().typescript()
+                if self.input.syntax().typescript_or_flow()
                     && ident.id.sym == js_word!("as")
                     && !is!(self, "=>")
                 {
@@ -442,6 +465,113 @@ impl<'a, I: Tokens> Parser<I> {
         )
     }
 
+    fn is_topic_token(&mut self, topic_token: TopicToken) -> PResult<bool> {
+        let caret = Token::BinOp(BinOpToken::BitXor);
+
+        Ok(match topic_token {
+            TopicToken::Percent => is!(self, '%'),
+            TopicToken::Caret => self.input.is(&caret),
+            TopicToken::DoubleCaret => {
+                self.input.is(&caret) && matches!(peek!(self), Ok(tok) if *tok == caret)
+            }
+            TopicToken::DoubleAt => is!(self, '@') && peeked_is!(self, '@'),
+            // Not `#x in obj`
+            TopicToken::Hash => is!(self, '#') && !peeked_is!(self, IdentName),
+        })
+    }
+
+    /// Parses the topic reference of a pipeline body, like `%` in `a |>
+    /// f(%)`.
+    fn parse_topic_ref(&mut self, topic_token: TopicToken) -> PResult<Box<Expr>> {
+        let start = cur_pos!(self);
+        bump!(self);
+        if matches!(topic_token, TopicToken::DoubleCaret | TopicToken::DoubleAt) {
+            // The second token is checked by `is_topic_token`.
+            cur!(self, true)?;
+            bump!(self);
+        }
+        // The topic reference is an operand, so `/` after it is a division.
+        self.input.set_expr_allowed(false);
+        let span = span!(self, start);
+
+        match self.state.topic_refs.last_mut() {
+            Some(count) => *count += 1,
+            None => self.emit_err(span, SyntaxError::TopicRefOutsidePipeline),
+        }
+
+        Ok(Box::new(Expr::TopicRef(TopicRef { span })))
+    }
+
+    /// Parses `do { ... }`.
+    fn parse_do_expr(&mut self) -> PResult<Box<Expr>> {
+        trace_cur!(self, parse_do_expr);
+
+        let start = cur_pos!(self);
+        assert_and_bump!(self, "do");
+
+        let body = self.parse_block(false)?;
+        // The lexer treats the body as a block statement, but `/` after a do
+        // expression is a division.
+        self.input.set_expr_allowed(false);
+
+        Ok(Box::new(Expr::Do(DoExpr {
+            span: span!(self, start),
+            body,
+        })))
+    }
+
+    /// Parses `#{ ... }` or `#[ ... ]`.
+    fn parse_record_or_tuple(&mut self) -> PResult<Box<Expr>> {
+        trace_cur!(self, parse_record_or_tuple);
+
+        let start = cur_pos!(self);
+        assert_and_bump!(self, '#');
+        if self.input.prev_span().hi != cur_pos!(self) {
+            let span = span!(self, start);
+            self.emit_err(span, SyntaxError::SpaceAfterRecordOrTupleHash);
+        }
+
+        if is!(self, '{') {
+            let obj: Box<Expr> = self.parse_object()?;
+            let props = match *obj {
+                Expr::Object(ObjectLit { props, .. }) => props,
+                _ => unreachable!("parse_object() returned non-object"),
+            };
+            for prop in &props {
+                if let PropOrSpread::Prop(prop) = prop {
+                    if !matches!(**prop, Prop::KeyValue(..) | Prop::Shorthand(..)) {
+                        self.emit_err(prop.span(), SyntaxError::InvalidRecordProp);
+                    }
+                }
+            }
+
+            return Ok(Box::new(Expr::Record(RecordLit {
+                span: span!(self, start),
+                props,
+            })));
+        }
+
+        let ctx = Context {
+            is_direct_child_of_cond: false,
+            dont_parse_colon_as_type_ann: false,
+            ..self.ctx()
+        };
+        let arr = self.with_ctx(ctx).parse_array_lit()?;
+        let span = span!(self, start);
+        let elems = match *arr {
+            Expr::Array(ArrayLit { elems, .. }) => elems,
+            _ => unreachable!("parse_array_lit() returned non-array"),
+        };
+        if elems.iter().any(|elem| elem.is_none()) {
+            self.emit_err(span, SyntaxError::HoleInTuple);
+        }
+
+        Ok(Box::new(Expr::Tuple(TupleLit {
+            span,
+            elems: elems.into_iter().flatten().collect(),
+        })))
+    }
+
     fn parse_array_lit(&mut self) -> PResult<Box<Expr>> {
         trace_cur!(self, parse_array_lit);
 
@@ -516,7 +646,7 @@ impl<'a, I: Tokens> Parser<I> {
             let callee = self.parse_member_expr_or_new_expr(is_new_expr)?;
             return_if_arrow!(self, callee);
 
-            let type_args = if self.input.syntax().typescript() && is!(self, '<') {
+            let type_args = if self.input.syntax().typescript_or_flow() && is!(self, '<') {
                 self.try_parse_ts(|p| {
                     let args = p.parse_ts_type_args()?;
                     if !is!(p, '(') {
@@ -672,7 +802,7 @@ impl<'a, I: Tokens> Parser<I> {
         let is_direct_child_of_cond = self.ctx().is_direct_child_of_cond;
 
         // This is slow path. We handle arrow in conditional expression.
-        if self.syntax().typescript() && self.ctx().in_cond_expr && is!(self, ':') {
+        if self.syntax().typescript_or_flow() && self.ctx().in_cond_expr && is!(self, ':') {
             // TODO: Remove clone
             let items_ref = &paren_items;
             if let Some(expr) = self.try_parse_ts(|p| {
@@ -711,7 +841,7 @@ impl<'a, I: Tokens> Parser<I> {
         }
 
         let return_type = if !(self.ctx().in_cond_expr && self.ctx().is_direct_child_of_cond)
-            && self.input.syntax().typescript()
+            && self.input.syntax().typescript_or_flow()
             && is!(self, ':')
             && !self.ctx().dont_parse_colon_as_type_ann
         {
@@ -992,8 +1122,11 @@ impl<'a, I: Tokens> Parser<I> {
     ) -> PResult<(Box<Expr>, bool)> {
         let _ = cur!(self, false);
 
-        if self.input.syntax().typescript() {
-            if !self.input.had_line_break_before_cur() && is!(self, '!') {
+        if self.input.syntax().typescript_or_flow() {
+            if self.input.syntax().typescript()
+                && !self.input.had_line_break_before_cur()
+                && is!(self, '!')
+            {
                 self.input.set_expr_allowed(false);
                 assert_and_bump!(self, '!');
 
@@ -1342,7 +1475,7 @@ impl<'a, I: Tokens> Parser<I> {
         let callee = self.parse_new_expr()?;
         return_if_arrow!(self, callee);
 
-        let type_args = if self.input.syntax().typescript() && is!(self, '<') {
+        let type_args = if self.input.syntax().typescript_or_flow() && is!(self, '<') {
             self.try_parse_ts(|p| {
                 let type_args = p.parse_ts_type_args()?;
                 if is!(p, '(') {
@@ -1435,7 +1568,7 @@ impl<'a, I: Tokens> Parser<I> {
             let pat_start = cur_pos!(self);
 
             let mut arg = {
-                if self.input.syntax().typescript()
+                if self.input.syntax().typescript_or_flow()
                     && (is!(self, IdentRef) || (is!(self, "...") && peeked_is!(self, IdentRef)))
                 {
                     let spread = if eat!(self, "...") {
@@ -1464,7 +1597,7 @@ impl<'a, I: Tokens> Parser<I> {
                 }
             };
 
-            let optional = if self.input.syntax().typescript() {
+            let optional = if self.input.syntax().typescript_or_flow() {
                 if is!(self, '?') {
                     if peeked_is!(self, ',')
                         || peeked_is!(self, ':')
@@ -1527,7 +1660,37 @@ impl<'a, I: Tokens> Parser<I> {
                 false
             };
 
-            if optional || (self.input.syntax().typescript() && is!(self, ':')) {
+            // `(expr: T)` is a type cast in flow. It's reparsed as a parameter if this
+            // is the head of an arrow function.
+            if self.input.syntax().flow() && !optional && arg.spread.is_none() && is!(self, ':') {
+                let type_ann_start = cur_pos!(self);
+                let type_ann = self.parse_ts_type_ann(/* eat_colon */ true, type_ann_start)?;
+                arg.expr = Box::new(Expr::TsAs(TsAsExpr {
+                    span: span!(self, pat_start),
+                    expr: arg.expr,
+                    type_ann: type_ann.type_ann,
+                }));
+
+                // `(x: T = 1) => {}`
+                if is!(self, '=') {
+                    let left = self.reparse_expr_as_pat(PatType::BindingPat, arg.expr)?;
+                    assert_and_bump!(self, '=');
+                    let right = self.parse_assignment_expr()?;
+                    items.push(PatOrExprOrSpread::Pat(Pat::Assign(AssignPat {
+                        span: span!(self, pat_start),
+                        left: Box::new(left),
+                        right,
+                        type_ann: None,
+                    })));
+
+                    if !is!(self, ')') {
+                        expect!(self, ',');
+                    }
+                    continue;
+                }
+            }
+
+            if optional || (self.input.syntax().typescript_or_flow() && is!(self, ':')) {
                 // TODO: `async(...args?: any[]) : any => {}`
                 //
                 // if self.input.syntax().typescript() && optional && arg.spread.is_some() {
@@ -1696,8 +1859,18 @@ impl<'a, I: Tokens> Parser<I> {
             syntax_error!(self, self.input.prev_span(), SyntaxError::YieldParamInGen)
         }
 
+        // `%` is an operand in `a |> (yield %)`.
+        let starts_with_topic = match self.input.syntax().pipeline_operator() {
+            Some(config) => {
+                !self.state.topic_refs.is_empty() && self.is_topic_token(config.topic_token)?
+            }
+            None => false,
+        };
+
         if is!(self, ';')
-            || (!is!(self, '*') && !cur!(self, false).map(Token::starts_expr).unwrap_or(true))
+            || (!is!(self, '*')
+                && !starts_with_topic
+                && !cur!(self, false).map(Token::starts_expr).unwrap_or(true))
         {
             Ok(Box::new(Expr::Yield(YieldExpr {
                 span: span!(self, start),
@@ -1794,7 +1967,7 @@ impl<'a, I: Tokens> Parser<I> {
 
     pub(super) fn check_assign_target(&mut self, expr: &Expr, deny_call: bool) {
         // We follow behavior of tsc
-        if self.input.syntax().typescript() && self.syntax().early_errors() {
+        if self.input.syntax().typescript_or_flow() && self.syntax().early_errors() {
             let is_eval_or_arguments = match *expr {
                 Expr::Ident(ref i) => i.sym == js_word!("eval") || i.sym == js_word!("arguments"),
                 _ => false,
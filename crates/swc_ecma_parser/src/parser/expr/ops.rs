@@ -32,7 +32,41 @@ impl<'a, I: Tokens> Parser<I> {
         };
 
         return_if_arrow!(self, left);
-        self.parse_bin_op_recursively(left, 0)
+        let expr = self.parse_bin_op_recursively(left, 0)?;
+
+        if self.input.syntax().pipeline_operator().is_some() {
+            return self.parse_pipeline_expr(expr);
+        }
+
+        Ok(expr)
+    }
+
+    /// Parses the body of a hack-style pipeline expression, like `|> f(%)`.
+    ///
+    /// The pipeline operator has the same precedence as assignment operators,
+    /// so the body is an assignment expression. The pipeline operator is
+    /// left-associative, and `a |> f(%) |> g(%)` is parsed as `(a |> f(%)) |>
+    /// g(%)`.
+    fn parse_pipeline_expr(&mut self, left: Box<Expr>) -> PResult<Box<Expr>> {
+        if !eat!(self, "|>") {
+            return Ok(left);
+        }
+
+        // Topic references in nested pipeline bodies belong to the nested
+        // pipelines, so references are counted per pipeline.
+        self.state.topic_refs.push(0);
+        let body = self.parse_assignment_expr();
+        let topic_refs = self.state.topic_refs.pop().unwrap_or_default();
+        let body = body?;
+
+        if topic_refs == 0 {
+            self.emit_err(
+                pipeline_head(&body).span(),
+                SyntaxError::PipelineBodyWithoutTopic,
+            );
+        }
+
+        Ok(make_pipeline(left, body))
     }
 
     /// Parse binary operators with the operator precedence parsing
@@ -292,7 +326,7 @@ impl<'a, I: Tokens> Parser<I> {
                 }
             }
 
-            if self.input.syntax().typescript() && op == op!("delete") {
+            if self.input.syntax().typescript_or_flow() && op == op!("delete") {
                 fn unwrap_paren(e: &Expr) -> &Expr {
                     match *e {
                         Expr::Paren(ref p) => unwrap_paren(&p.expr),
@@ -373,6 +407,31 @@ impl<'a, I: Tokens> Parser<I> {
     }
 }
 
+/// Returns the first body of pipelines in `body`, which is parsed as `b |> c`
+/// for `a |> b |> c`.
+fn pipeline_head(mut body: &Expr) -> &Expr {
+    while let Expr::Pipeline(PipelineExpr { left, .. }) = body {
+        body = left;
+    }
+    body
+}
+
+/// Converts `a |> (b |> c)` to `(a |> b) |> c`.
+fn make_pipeline(left: Box<Expr>, body: Box<Expr>) -> Box<Expr> {
+    let (left, right) = match *body {
+        Expr::Pipeline(PipelineExpr {
+            left: head, right, ..
+        }) => (make_pipeline(left, head), right),
+        body => (left, Box::new(body)),
+    };
+
+    Box::new(Expr::Pipeline(PipelineExpr {
+        span: Span::new(left.span().lo(), right.span().hi(), Default::default()),
+        left,
+        right,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,7 @@
 extern crate test;
 
 use super::*;
-use crate::EsConfig;
+use crate::{EsConfig, PipelineOperatorConfig, TopicToken};
 use std::hint::black_box;
 use swc_common::DUMMY_SP as span;
 use swc_ecma_visit::assert_eq_ignore_span;
@@ -491,6 +491,277 @@ fn super_expr_computed() {
     );
 }
 
+fn pipeline(s: &'static str, topic_token: TopicToken) -> Box<Expr> {
+    test_parser(
+        s,
+        Syntax::Es(EsConfig {
+            pipeline_operator: Some(PipelineOperatorConfig { topic_token }),
+            ..Default::default()
+        }),
+        |p| p.parse_expr(),
+    )
+}
+
+#[test]
+fn pipeline_expr() {
+    assert_eq_ignore_span!(
+        pipeline("a |> f(%)", TopicToken::Percent),
+        Box::new(Expr::Pipeline(PipelineExpr {
+            span,
+            left: Box::new(Expr::Ident(Ident::new("a".into(), span))),
+            right: Box::new(Expr::Call(CallExpr {
+                span,
+                callee: Callee::Expr(Box::new(Expr::Ident(Ident::new("f".into(), span)))),
+                args: vec![ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::TopicRef(TopicRef { span })),
+                }],
+                type_args: Default::default(),
+            })),
+        }))
+    );
+}
+
+#[test]
+fn pipeline_topic_tokens() {
+    let expected = pipeline("a |> % + 1 |> f(%, %)", TopicToken::Percent);
+
+    assert_eq_ignore_span!(
+        pipeline("a |> ^^ + 1 |> f(^^, ^^)", TopicToken::DoubleCaret),
+        expected.clone()
+    );
+    assert_eq_ignore_span!(
+        pipeline("a |> @@ + 1 |> f(@@, @@)", TopicToken::DoubleAt),
+        expected.clone()
+    );
+    assert_eq_ignore_span!(
+        pipeline("a |> ^ + 1 |> f(^, ^)", TopicToken::Caret),
+        expected.clone()
+    );
+    assert_eq_ignore_span!(
+        pipeline("a |> # + 1 |> f(#, #)", TopicToken::Hash),
+        expected
+    );
+}
+
+#[test]
+fn pipeline_modulo_in_body() {
+    assert_eq_ignore_span!(
+        pipeline("a |> % % 2", TopicToken::Percent),
+        Box::new(Expr::Pipeline(PipelineExpr {
+            span,
+            left: Box::new(Expr::Ident(Ident::new("a".into(), span))),
+            right: Box::new(Expr::Bin(BinExpr {
+                span,
+                op: op!("%"),
+                left: Box::new(Expr::TopicRef(TopicRef { span })),
+                right: Box::new(Expr::Lit(Lit::Num(Number { span, value: 2.0 }))),
+            })),
+        }))
+    );
+}
+
+#[test]
+fn pipeline_left_associative() {
+    let topic = || Box::new(Expr::TopicRef(TopicRef { span }));
+    let call = |f: &str| {
+        Box::new(Expr::Call(CallExpr {
+            span,
+            callee: Callee::Expr(Box::new(Expr::Ident(Ident::new(f.into(), span)))),
+            args: vec![ExprOrSpread {
+                spread: None,
+                expr: topic(),
+            }],
+            type_args: Default::default(),
+        }))
+    };
+
+    assert_eq_ignore_span!(
+        pipeline("a |> f(%) |> g(%)", TopicToken::Percent),
+        Box::new(Expr::Pipeline(PipelineExpr {
+            span,
+            left: Box::new(Expr::Pipeline(PipelineExpr {
+                span,
+                left: Box::new(Expr::Ident(Ident::new("a".into(), span))),
+                right: call("f"),
+            })),
+            right: call("g"),
+        }))
+    );
+}
+
+#[test]
+fn pipeline_cond_body() {
+    assert_eq_ignore_span!(
+        pipeline("a |> % ? b : c", TopicToken::Percent),
+        Box::new(Expr::Pipeline(PipelineExpr {
+            span,
+            left: Box::new(Expr::Ident(Ident::new("a".into(), span))),
+            right: Box::new(Expr::Cond(CondExpr {
+                span,
+                test: Box::new(Expr::TopicRef(TopicRef { span })),
+                cons: Box::new(Expr::Ident(Ident::new("b".into(), span))),
+                alt: Box::new(Expr::Ident(Ident::new("c".into(), span))),
+            })),
+        }))
+    );
+}
+
+#[test]
+fn pipeline_assign_body() {
+    assert_eq_ignore_span!(
+        pipeline("a |> b = %", TopicToken::Percent),
+        Box::new(Expr::Pipeline(PipelineExpr {
+            span,
+            left: Box::new(Expr::Ident(Ident::new("a".into(), span))),
+            right: Box::new(Expr::Assign(AssignExpr {
+                span,
+                op: op!("="),
+                left: PatOrExpr::Pat(Box::new(Pat::Ident(Ident::new("b".into(), span).into()))),
+                right: Box::new(Expr::TopicRef(TopicRef { span })),
+            })),
+        }))
+    );
+}
+
+#[test]
+fn pipeline_arrow_body() {
+    pipeline("a |> () => %", TopicToken::Percent);
+}
+
+#[test]
+fn pipeline_yield_body() {
+    test_parser(
+        "function* f() { a |> (yield %) }",
+        Syntax::Es(EsConfig {
+            pipeline_operator: Some(Default::default()),
+            ..Default::default()
+        }),
+        |p| p.parse_module(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Pipeline body should contain a topic reference")]
+fn pipeline_topic_in_nested_pipeline_only() {
+    // The topic reference belongs to the nested pipeline.
+    pipeline("a |> (b |> f(%))", TopicToken::Percent);
+}
+
+#[test]
+fn pipeline_topic_in_head_of_nested_pipeline() {
+    pipeline("a |> (% |> f(%))", TopicToken::Percent);
+}
+
+#[test]
+#[should_panic(expected = "Pipeline body should contain a topic reference")]
+fn pipeline_body_without_topic() {
+    pipeline("a |> f()", TopicToken::Percent);
+}
+
+#[test]
+#[should_panic(expected = "Topic reference is used outside of a pipeline body")]
+fn topic_ref_outside_pipeline() {
+    pipeline("f(%)", TopicToken::Percent);
+}
+
+fn proposal_expr(s: &'static str) -> Box<Expr> {
+    test_parser(
+        s,
+        Syntax::Es(EsConfig {
+            do_expressions: true,
+            record_and_tuple: true,
+            ..Default::default()
+        }),
+        |p| p.parse_expr(),
+    )
+}
+
+#[test]
+fn do_expr() {
+    assert_eq_ignore_span!(
+        proposal_expr("do { 1 }"),
+        Box::new(Expr::Do(DoExpr {
+            span,
+            body: BlockStmt {
+                span,
+                stmts: vec![Stmt::Expr(ExprStmt {
+                    span,
+                    expr: Box::new(Expr::Lit(Lit::Num(Number { span, value: 1.0 }))),
+                })],
+            },
+        }))
+    );
+}
+
+#[test]
+fn do_expr_followed_by_division() {
+    assert_eq_ignore_span!(
+        proposal_expr("do { 4 } / 2"),
+        Box::new(Expr::Bin(BinExpr {
+            span,
+            op: op!("/"),
+            left: proposal_expr("do { 4 }"),
+            right: Box::new(Expr::Lit(Lit::Num(Number { span, value: 2.0 }))),
+        }))
+    );
+}
+
+#[test]
+fn record_lit() {
+    assert_eq_ignore_span!(
+        proposal_expr("#{ a: 1, b, ...c }"),
+        Box::new(Expr::Record(RecordLit {
+            span,
+            props: match *proposal_expr("({ a: 1, b, ...c })") {
+                Expr::Paren(ParenExpr { expr, .. }) => match *expr {
+                    Expr::Object(ObjectLit { props, .. }) => props,
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            },
+        }))
+    );
+}
+
+#[test]
+fn tuple_lit() {
+    assert_eq_ignore_span!(
+        proposal_expr("#[1, ...a]"),
+        Box::new(Expr::Tuple(TupleLit {
+            span,
+            elems: vec![
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Lit(Lit::Num(Number { span, value: 1.0 }))),
+                },
+                ExprOrSpread {
+                    spread: Some(span),
+                    expr: Box::new(Expr::Ident(Ident::new("a".into(), span))),
+                },
+            ],
+        }))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Records can only contain")]
+fn record_with_method() {
+    proposal_expr("#{ a() {} }");
+}
+
+#[test]
+#[should_panic(expected = "Tuples can't contain holes")]
+fn tuple_with_hole() {
+    proposal_expr("#[1, , 2]");
+}
+
+#[test]
+#[should_panic(expected = "without a space")]
+fn record_with_space_after_hash() {
+    proposal_expr("# { a: 1 }");
+}
+
 #[bench]
 fn bench_new_expr_ts(b: &mut Bencher) {
     bench_parser(
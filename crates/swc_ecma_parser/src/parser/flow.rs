@@ -0,0 +1,707 @@
+//! Flow specific parts of the parser.
+//!
+//! Flow types are parsed into the typescript type ast, so most of the syntax
+//! is handled by the typescript parser. Only the syntax which does not exist
+//! in typescript is handled here.
+use super::{typescript::make_decl_declare, *};
+use crate::token::BinOpToken;
+use swc_common::Spanned;
+
+impl<I: Tokens> Parser<I> {
+    /// Eats `+` or `-`.
+    ///
+    /// Returns the span of `+`, because covariant properties are read-only.
+    pub(super) fn eat_flow_variance(&mut self) -> PResult<Option<Span>> {
+        debug_assert!(self.input.syntax().flow());
+
+        if eat!(self, '+') {
+            return Ok(Some(self.input.prev_span()));
+        }
+        eat!(self, '-');
+
+        Ok(None)
+    }
+
+    /// Keyword types which are not keyword types of typescript.
+    pub(super) fn flow_keyword_type_kind(&mut self) -> Option<TsKeywordTypeKind> {
+        if !self.input.syntax().flow() {
+            return None;
+        }
+
+        match self.input.cur() {
+            Some(Token::Word(Word::Ident(w))) => match &**w {
+                "mixed" => Some(TsKeywordTypeKind::TsUnknownKeyword),
+                "empty" => Some(TsKeywordTypeKind::TsNeverKeyword),
+                "bool" => Some(TsKeywordTypeKind::TsBooleanKeyword),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// `*` (existential type)
+    pub(super) fn parse_flow_existential_type(&mut self) -> PResult<Box<TsType>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = cur_pos!(self);
+        assert_and_bump!(self, '*');
+
+        Ok(Box::new(TsType::TsKeywordType(TsKeywordType {
+            span: span!(self, start),
+            kind: TsKeywordTypeKind::TsAnyKeyword,
+        })))
+    }
+
+    /// `?T`, which is parsed as `T | null | undefined`.
+    pub(super) fn parse_flow_maybe_type(&mut self) -> PResult<Box<TsType>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = cur_pos!(self);
+        assert_and_bump!(self, '?');
+
+        let ty = self.parse_ts_type_operator_or_higher()?;
+        let span = span!(self, start);
+        let keyword = |kind| {
+            Box::new(TsType::TsKeywordType(TsKeywordType {
+                span: ty.span(),
+                kind,
+            }))
+        };
+        let types = vec![
+            keyword(TsKeywordTypeKind::TsNullKeyword),
+            keyword(TsKeywordTypeKind::TsUndefinedKeyword),
+        ];
+
+        Ok(Box::new(TsType::TsUnionOrIntersectionType(
+            TsUnionOrIntersectionType::TsUnionType(TsUnionType {
+                span,
+                types: std::iter::once(ty).chain(types).collect(),
+            }),
+        )))
+    }
+
+    /// Flow allows unnamed parameters in function types, so we can't use
+    /// `is_ts_unambiguously_start_of_fn_type`.
+    pub(super) fn is_flow_start_of_fn_type(&mut self) -> PResult<bool> {
+        debug_assert!(self.input.syntax().flow());
+
+        if is!(self, '<') {
+            return Ok(true);
+        }
+        if !is!(self, '(') {
+            return Ok(false);
+        }
+
+        self.ts_look_ahead(|p| {
+            let mut depth = 0usize;
+            while !eof!(p) {
+                if is_one_of!(p, '(', '[', '{') {
+                    depth += 1;
+                } else if is_one_of!(p, ')', ']', '}') {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        bump!(p);
+                        return Ok(is!(p, "=>"));
+                    }
+                }
+                bump!(p);
+            }
+
+            Ok(false)
+        })
+    }
+
+    /// Parses parameters of a function type.
+    ///
+    /// Eats `)` at the end but does not eat `(` at start. Unnamed parameters
+    /// are named `_0`, `_1`, ... by their position.
+    pub(super) fn parse_flow_fn_type_params(&mut self) -> PResult<Vec<TsFnParam>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let mut params = vec![];
+
+        while !eof!(self) && !is!(self, ')') {
+            let start = cur_pos!(self);
+            let dot3_token = if eat!(self, "...") {
+                Some(self.input.prev_span())
+            } else {
+                None
+            };
+
+            let param = if is!(self, IdentName) && (peeked_is!(self, ':') || peeked_is!(self, '?'))
+            {
+                let mut id = self.parse_ident_name()?;
+                id.optional = eat!(self, '?');
+                let type_ann_start = cur_pos!(self);
+                let type_ann = self.parse_ts_type_ann(/* eat_colon */ true, type_ann_start)?;
+
+                BindingIdent {
+                    id,
+                    type_ann: Some(type_ann),
+                }
+            } else {
+                let type_ann = self.parse_ts_type()?;
+                let span = type_ann.span();
+
+                BindingIdent {
+                    id: Ident::new(format!("_{}", params.len()).into(), span),
+                    type_ann: Some(TsTypeAnn { span, type_ann }),
+                }
+            };
+
+            params.push(match dot3_token {
+                Some(dot3_token) => TsFnParam::Rest(RestPat {
+                    span: span!(self, start),
+                    dot3_token,
+                    arg: Box::new(Pat::Ident(param.id.into())),
+                    type_ann: param.type_ann,
+                }),
+                None => TsFnParam::Ident(param),
+            });
+
+            if !is!(self, ')') {
+                expect!(self, ',');
+            }
+        }
+        expect!(self, ')');
+
+        Ok(params)
+    }
+
+    /// Parses an object type, which may be exact (`{| |}`) and may contain
+    /// spreads.
+    ///
+    /// An object type with spreads is parsed as an intersection type.
+    pub(super) fn parse_flow_object_type(&mut self) -> PResult<Box<TsType>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = cur_pos!(self);
+        expect!(self, '{');
+
+        let mut members = vec![];
+        let mut spreads = vec![];
+
+        // `{||}`
+        let exact = if self.input.eat(&Token::BinOp(BinOpToken::LogicalOr)) {
+            false
+        } else {
+            eat!(self, '|')
+        };
+
+        loop {
+            if exact && self.is_flow_exact_object_end()? {
+                bump!(self);
+                break;
+            }
+            if is!(self, '}') || eof!(self) {
+                break;
+            }
+
+            if eat!(self, "...") {
+                // `...` without an argument marks an inexact object.
+                if !is_one_of!(self, ',', ';', '}', '|') {
+                    spreads.push(self.parse_ts_type()?);
+                }
+                if !is_one_of!(self, '}', '|') {
+                    self.parse_ts_type_member_semicolon()?;
+                }
+                continue;
+            }
+
+            members.push(self.parse_flow_object_type_member()?);
+        }
+        expect!(self, '}');
+
+        let span = span!(self, start);
+        if spreads.is_empty() {
+            return Ok(Box::new(TsType::TsTypeLit(TsTypeLit { span, members })));
+        }
+
+        let mut types = spreads;
+        if !members.is_empty() {
+            types.push(Box::new(TsType::TsTypeLit(TsTypeLit { span, members })));
+        }
+        if types.len() == 1 {
+            return Ok(types.pop().unwrap());
+        }
+
+        Ok(Box::new(TsType::TsUnionOrIntersectionType(
+            TsUnionOrIntersectionType::TsIntersectionType(TsIntersectionType { span, types }),
+        )))
+    }
+
+    /// `|}`, which is not a part of an union type.
+    pub(super) fn is_flow_exact_object_end(&mut self) -> PResult<bool> {
+        Ok(self.input.syntax().flow() && is!(self, '|') && peeked_is!(self, '}'))
+    }
+
+    fn parse_flow_object_type_member(&mut self) -> PResult<TsTypeElement> {
+        let start = cur_pos!(self);
+        let readonly = self.eat_flow_variance()?.is_some();
+
+        // `[K]: V`
+        if is!(self, '[') && !self.ts_look_ahead(|p| p.is_ts_unambiguously_index_signature())? {
+            let key_type = self.in_type().parse_with(|p| {
+                assert_and_bump!(p, '[');
+                let ty = p.parse_ts_type()?;
+                expect!(p, ']');
+                Ok(ty)
+            })?;
+            let key_span = key_type.span();
+            let type_ann = self.try_parse_ts_type_ann()?;
+            self.parse_ts_type_member_semicolon()?;
+
+            return Ok(TsTypeElement::TsIndexSignature(TsIndexSignature {
+                span: span!(self, start),
+                params: vec![TsFnParam::Ident(BindingIdent {
+                    id: Ident::new("key".into(), key_span),
+                    type_ann: Some(TsTypeAnn {
+                        span: key_span,
+                        type_ann: key_type,
+                    }),
+                })],
+                type_ann,
+                readonly,
+                is_static: false,
+            }));
+        }
+
+        let mut member = self.parse_ts_type_member()?;
+        if readonly {
+            match member {
+                TsTypeElement::TsPropertySignature(ref mut p) => {
+                    p.span = span!(self, start);
+                    p.readonly = true;
+                }
+                TsTypeElement::TsIndexSignature(ref mut i) => {
+                    i.span = span!(self, start);
+                    i.readonly = true;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(member)
+    }
+
+    /// `opaque type T: Super = Underlying;`
+    ///
+    /// If the underlying type is not known (in a declaration), the super type
+    /// is used instead.
+    pub(super) fn parse_flow_opaque_type(&mut self, start: BytePos) -> PResult<TsTypeAliasDecl> {
+        debug_assert!(self.input.syntax().flow());
+
+        expect!(self, "type");
+        let id = self.parse_ident_name()?;
+        let type_params = self.try_parse_ts_type_params()?;
+        let super_type = self.in_type().parse_with(|p| {
+            if !eat!(p, ':') {
+                return Ok(None);
+            }
+            p.parse_ts_type().map(Some)
+        })?;
+        let type_ann = self.in_type().parse_with(|p| {
+            if !eat!(p, '=') {
+                return Ok(None);
+            }
+            p.parse_ts_type().map(Some)
+        })?;
+        expect!(self, ';');
+
+        let type_ann = type_ann.or(super_type).unwrap_or_else(|| {
+            Box::new(TsType::TsKeywordType(TsKeywordType {
+                span: id.span,
+                kind: TsKeywordTypeKind::TsUnknownKeyword,
+            }))
+        });
+
+        Ok(TsTypeAliasDecl {
+            span: span!(self, start),
+            declare: false,
+            id,
+            type_params,
+            type_ann,
+        })
+    }
+
+    /// `declare module.exports: T;`
+    ///
+    /// This is parsed as `declare var exports: T;`, as there's no such
+    /// declaration in typescript.
+    pub(super) fn parse_flow_declare_module_exports(&mut self, start: BytePos) -> PResult<VarDecl> {
+        debug_assert!(self.input.syntax().flow());
+
+        assert_and_bump!(self, "module");
+        expect!(self, '.');
+        if !matches!(*cur!(self, true)?, Token::Word(Word::Ident(ref w)) if &**w == "exports") {
+            unexpected!(self, "exports")
+        }
+        let id = self.parse_ident_name()?;
+        if !is!(self, ':') {
+            unexpected!(self, ":")
+        }
+        let type_ann_start = cur_pos!(self);
+        let type_ann = self.parse_ts_type_ann(/* eat_colon */ true, type_ann_start)?;
+        expect!(self, ';');
+
+        Ok(VarDecl {
+            span: span!(self, start),
+            kind: VarDeclKind::Var,
+            declare: true,
+            decls: vec![VarDeclarator {
+                span: span!(self, id.span.lo()),
+                name: Pat::Ident(BindingIdent {
+                    id,
+                    type_ann: Some(type_ann),
+                }),
+                init: None,
+                definite: false,
+            }],
+        })
+    }
+
+    /// `declare export ...`, which is parsed like `export declare ...` of
+    /// typescript.
+    pub(super) fn parse_flow_declare_export(
+        &mut self,
+        decorators: Vec<Decorator>,
+    ) -> PResult<ModuleDecl> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = cur_pos!(self);
+        assert_and_bump!(self, "declare");
+
+        let ctx = Context {
+            in_declare: true,
+            ..self.ctx()
+        };
+        let decl = self.with_ctx(ctx).parse_export(decorators)?;
+
+        Ok(match decl {
+            ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => ModuleDecl::ExportDecl(ExportDecl {
+                span: span!(self, start),
+                decl: make_decl_declare(decl),
+            }),
+            // `declare export { a }` only exports types.
+            ModuleDecl::ExportNamed(e) => ModuleDecl::ExportNamed(NamedExport {
+                span: span!(self, start),
+                type_only: true,
+                ..e
+            }),
+            decl => decl,
+        })
+    }
+
+    /// Skips the predicate of a function, like `%checks` or
+    /// `%checks(typeof x === 'string')`.
+    pub(super) fn skip_flow_predicate(&mut self) -> PResult<()> {
+        debug_assert!(self.input.syntax().flow());
+
+        if !is!(self, '%')
+            || !matches!(peek!(self), Ok(&Token::Word(Word::Ident(ref w))) if &**w == "checks")
+        {
+            return Ok(());
+        }
+        bump!(self);
+        cur!(self, true)?;
+        bump!(self);
+
+        if eat!(self, '(') {
+            self.include_in_expr(true).parse_expr()?;
+            expect!(self, ')');
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lexer::Lexer, test_parser, FlowConfig, Parser, StringInput, Syntax};
+    use swc_common::BytePos;
+    use swc_ecma_ast::*;
+    use swc_ecma_visit::assert_eq_ignore_span;
+
+    fn flow(src: &'static str) -> Module {
+        test_parser(src, Syntax::Flow(FlowConfig::default()), |p| {
+            p.parse_module()
+        })
+    }
+
+    fn ts(src: &'static str) -> Module {
+        test_parser(src, Syntax::Typescript(Default::default()), |p| {
+            p.parse_module()
+        })
+    }
+
+    /// Asserts that `src` is not valid flow.
+    #[track_caller]
+    fn assert_flow_error(src: &'static str) {
+        let lexer = Lexer::new(
+            Syntax::Flow(FlowConfig::default()),
+            Default::default(),
+            StringInput::new(src, BytePos(0), BytePos(src.len() as _)),
+            None,
+        );
+        let mut p = Parser::new_from(lexer);
+        let res = p.parse_module();
+
+        assert!(
+            res.is_err() || !p.take_errors().is_empty(),
+            "`{}` should not be valid flow",
+            src
+        );
+    }
+
+    #[test]
+    fn maybe_type() {
+        assert_eq_ignore_span!(
+            flow("type A = ?string;"),
+            ts("type A = string | null | undefined;")
+        );
+    }
+
+    #[test]
+    fn exact_object_type() {
+        assert_eq_ignore_span!(
+            flow("type A = {| +a: string, -b?: number |};"),
+            ts("type A = { readonly a: string; b?: number };")
+        );
+        assert_eq_ignore_span!(flow("type A = {||};"), ts("type A = {};"));
+    }
+
+    #[test]
+    fn object_type_spread() {
+        assert_eq_ignore_span!(
+            flow("type A = { ...B, c: C, ... };"),
+            ts("type A = B & { c: C };")
+        );
+    }
+
+    #[test]
+    fn object_type_indexer() {
+        assert_eq_ignore_span!(
+            flow("type A = { [string]: number };"),
+            ts("type A = { [key: string]: number };")
+        );
+    }
+
+    #[test]
+    fn fn_type_unnamed_params() {
+        assert_eq_ignore_span!(
+            flow("type A = (string, y?: number, ...Array<C>) => void;"),
+            ts("type A = (_0: string, y?: number, ..._2: Array<C>) => void;")
+        );
+    }
+
+    #[test]
+    fn type_param_bound_and_variance() {
+        assert_eq_ignore_span!(
+            flow("type A<+T: Object = {}> = T;"),
+            ts("type A<T extends Object = {}> = T;")
+        );
+    }
+
+    #[test]
+    fn keyword_types() {
+        assert_eq_ignore_span!(
+            flow("type A = [mixed, empty, bool, *];"),
+            ts("type A = [unknown, never, boolean, any];")
+        );
+    }
+
+    #[test]
+    fn opaque_type() {
+        assert_eq_ignore_span!(
+            flow("opaque type A: string = string;"),
+            ts("type A = string;")
+        );
+    }
+
+    #[test]
+    fn type_cast() {
+        assert_eq_ignore_span!(flow("f((x: any));"), ts("f((x as any));"));
+        assert_eq_ignore_span!(flow("(x: T);"), ts("(x as T);"));
+        assert_eq_ignore_span!(flow("(a, (b: T));"), ts("(a, (b as T));"));
+    }
+
+    #[test]
+    fn type_cast_of_any_expr() {
+        assert_eq_ignore_span!(flow("(this.props: any);"), ts("(this.props as any);"));
+        assert_eq_ignore_span!(flow("(foo(): T);"), ts("(foo() as T);"));
+        assert_eq_ignore_span!(flow("({}: Props);"), ts("({} as Props);"));
+        assert_eq_ignore_span!(flow("([]: T[]);"), ts("([] as T[]);"));
+        assert_eq_ignore_span!(
+            flow("({ a: this.a, ...b }: Props);"),
+            ts("({ a: this.a, ...b } as Props);")
+        );
+        assert_eq_ignore_span!(flow("(a + 1: number);"), ts("(a + 1 as number);"));
+    }
+
+    #[test]
+    fn arrow_params_are_not_type_casts() {
+        assert_eq_ignore_span!(
+            flow("const f = (x: T, { a }: P, [b]: Q = [], ...c: R) => x;"),
+            ts("const f = (x: T, { a }: P, [b]: Q = [], ...c: R) => x;")
+        );
+        assert_eq_ignore_span!(
+            flow("const f = (x: T): R => x;"),
+            ts("const f = (x: T): R => x;")
+        );
+        assert_eq_ignore_span!(
+            flow("const f = async (x: T) => x;"),
+            ts("const f = async (x: T) => x;")
+        );
+    }
+
+    #[test]
+    fn ts_only_syntax() {
+        assert_flow_error("enum A { B }");
+        assert_flow_error("const enum A { B }");
+        assert_flow_error("namespace A {}");
+        assert_flow_error("declare namespace A {}");
+        assert_flow_error("declare enum A { B }");
+        assert_flow_error("declare global {}");
+        assert_flow_error("export declare const a: T;");
+        assert_flow_error("abstract class A {}");
+        assert_flow_error("x as T;");
+        assert_flow_error("<T>x;");
+        assert_flow_error("x!.y;");
+        assert_flow_error("class A { constructor(private x) {} }");
+        assert_flow_error("class A { private x: T; }");
+        assert_flow_error("import fs = require('fs');");
+    }
+
+    #[test]
+    fn import_typeof() {
+        let m = flow("import typeof A from './a';");
+        match &m.body[0] {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(i)) => assert!(i.type_only),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn import_typeof_specifier() {
+        let m = flow("import { typeof A, typeof B as C, D } from './a';");
+        let specifiers = match &m.body[0] {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(i)) => {
+                assert!(!i.type_only);
+                &i.specifiers
+            }
+            _ => unreachable!(),
+        };
+
+        let named = specifiers
+            .iter()
+            .map(|s| match s {
+                ImportSpecifier::Named(s) => (&*s.local.sym, s.is_type_only),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(named, vec![("A", true), ("C", true), ("D", false)]);
+    }
+
+    #[test]
+    fn declare() {
+        assert_eq_ignore_span!(
+            flow(
+                "declare var a: string; declare function f(x: number): void; declare class A { b: \
+                 string }"
+            ),
+            ts(
+                "declare var a: string; declare function f(x: number): void; declare class A { b: \
+                 string }"
+            )
+        );
+
+        let m = flow("declare module 'm' { declare function f(): void; }");
+        assert!(matches!(
+            &module_items(&m)[0],
+            ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl { declare: true, .. })))
+        ));
+    }
+
+    /// Items of the first `declare module`.
+    fn module_items(m: &Module) -> &[ModuleItem] {
+        match &m.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(TsModuleDecl {
+                declare: true,
+                body: Some(TsNamespaceBody::TsModuleBlock(block)),
+                ..
+            }))) => &block.body,
+            item => unreachable!("{:?}", item),
+        }
+    }
+
+    #[test]
+    fn declare_export() {
+        assert_eq_ignore_span!(
+            flow("declare export function f(): void;"),
+            ts("export declare function f(): void;")
+        );
+        assert_eq_ignore_span!(
+            flow("declare export var a: number;"),
+            ts("export declare var a: number;")
+        );
+        assert_eq_ignore_span!(
+            flow("declare export class A {}"),
+            ts("export declare class A {}")
+        );
+        assert_eq_ignore_span!(
+            flow("declare export { a, b };"),
+            ts("export type { a, b };")
+        );
+
+        let m = flow("declare module 'm' { declare export type A = string; }");
+        assert!(matches!(
+            &module_items(&m)[0],
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::TsTypeAlias(TsTypeAliasDecl { declare: true, .. }),
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn declare_module_exports() {
+        assert_eq_ignore_span!(
+            flow("declare module.exports: { a: number };"),
+            ts("declare var exports: { a: number };")
+        );
+
+        let m = flow("declare module 'm' { declare module.exports: () => void; }");
+        assert!(matches!(
+            &module_items(&m)[0],
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl { declare: true, .. })))
+        ));
+    }
+
+    #[test]
+    fn utility_types() {
+        assert_eq_ignore_span!(
+            flow("type A = $ReadOnly<{| a: string |}>; type B = $Keys<A>; type C = $Diff<A, B>;"),
+            ts("type A = $ReadOnly<{ a: string }>; type B = $Keys<A>; type C = $Diff<A, B>;")
+        );
+        assert_eq_ignore_span!(
+            flow("type A = $PropertyType<T, 'a'>; type B = $ElementType<T, K>;"),
+            ts("type A = $PropertyType<T, 'a'>; type B = $ElementType<T, K>;")
+        );
+    }
+
+    #[test]
+    fn predicate() {
+        assert_eq_ignore_span!(
+            flow("function f(x: mixed): boolean %checks { return !!x; }"),
+            ts("function f(x: unknown): boolean { return !!x; }")
+        );
+    }
+
+    #[test]
+    fn class_property_variance() {
+        assert_eq_ignore_span!(
+            flow("class A { +a: string; -b: number; }"),
+            ts("class A { readonly a: string; b: number; }")
+        );
+    }
+}
@@ -133,7 +133,7 @@ impl<'a, I: Tokens> Parser<I> {
             // value as the StringValue of any ReservedWord except for yield or await.
 
             match w {
-                Word::Keyword(Keyword::Await) if p.input.syntax().typescript() => {
+                Word::Keyword(Keyword::Await) if p.input.syntax().typescript_or_flow() => {
                     Ok(js_word!("await"))
                 }
 
@@ -142,7 +142,7 @@ impl<'a, I: Tokens> Parser<I> {
                 Word::Keyword(Keyword::Await) if p.ctx().module => {
                     syntax_error!(p, p.input.prev_span(), SyntaxError::ExpectedIdent)
                 }
-                Word::Keyword(Keyword::This) if p.input.syntax().typescript() => {
+                Word::Keyword(Keyword::This) if p.input.syntax().typescript_or_flow() => {
                     Ok(js_word!("this"))
                 }
                 Word::Keyword(Keyword::Let) => Ok(js_word!("let")),
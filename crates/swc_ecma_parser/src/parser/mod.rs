@@ -21,6 +21,7 @@ use test::Bencher;
 mod macros;
 mod class_and_fn;
 mod expr;
+mod flow;
 mod ident;
 pub mod input;
 mod jsx;
@@ -49,6 +50,14 @@ struct State {
     potential_arrow_start: Option<BytePos>,
 
     found_module_item: bool,
+
+    /// If true, statements which can't be parsed are replaced with
+    /// [Expr::Invalid] instead of failing.
+    recover: bool,
+
+    /// Number of topic references in each pipeline body being parsed. The
+    /// last one is the innermost pipeline.
+    topic_refs: Vec<usize>,
 }
 
 impl<'a, I: Input> Parser<Lexer<'a, I>> {
@@ -96,7 +105,7 @@ impl<I: Tokens> Parser<I> {
     pub fn parse_typescript_module(&mut self) -> PResult<Module> {
         trace_cur!(self, parse_typescript_module);
 
-        debug_assert!(self.syntax().typescript());
+        debug_assert!(self.syntax().typescript_or_flow());
 
         //TODO: parse() -> PResult<Program>
         let ctx = Context {
@@ -188,6 +197,33 @@ impl<I: Tokens> Parser<I> {
         })
     }
 
+    /// Parses a module, recovering from errors.
+    ///
+    /// A statement which can't be parsed is replaced with an expression
+    /// statement of [Expr::Invalid], which spans the skipped tokens, so this
+    /// always returns a [Module]. The returned errors include the errors which
+    /// are normally returned by [Parser::take_errors].
+    ///
+    /// This is useful for tools like an editor, which should work on
+    /// incomplete code.
+    pub fn parse_module_recovering(&mut self) -> (Module, Vec<Error>) {
+        self.state.recover = true;
+
+        let start = cur_pos!(self);
+        let module = self.parse_module().unwrap_or_else(|err| {
+            // Parsing the shebang or an error while parsing a directive
+            self.input_ref().add_error(err);
+            Module {
+                span: span!(self, start),
+                body: vec![],
+                shebang: None,
+            }
+        });
+        self.state.recover = false;
+
+        (module, self.take_errors())
+    }
+
     fn parse_shebang(&mut self) -> PResult<Option<JsWord>> {
         match cur!(self, false) {
             Ok(&Token::Shebang(..)) => match bump!(self) {
@@ -93,7 +93,7 @@ impl<'a, I: Tokens> Parser<I> {
 
                     let mut expr = p.include_in_expr(true).parse_assignment_expr()?;
 
-                    if p.syntax().typescript() && is!(p, ',') {
+                    if p.syntax().typescript_or_flow() && is!(p, ',') {
                         let mut exprs = vec![expr];
 
                         while eat!(p, ',') {
@@ -176,9 +176,9 @@ impl<I: Tokens> ParseObject<Box<Expr>> for Parser<I> {
 
         let key = self.parse_prop_name()?;
 
-        if self.input.syntax().typescript()
+        if self.input.syntax().typescript_or_flow()
             && !is_one_of!(self, '(', '[', ':', ',', '?', '=', '*', IdentName, Str, Num)
-            && !(self.input.syntax().typescript() && is!(self, '<'))
+            && !(self.input.syntax().typescript_or_flow() && is!(self, '<'))
             && !(is!(self, '}') && matches!(key, PropName::Ident(..)))
         {
             trace_cur!(self, parse_object_prop_error);
@@ -205,7 +205,7 @@ impl<I: Tokens> ParseObject<Box<Expr>> for Parser<I> {
         }
 
         // Handle `a(){}` (and async(){} / get(){} / set(){})
-        if (self.input.syntax().typescript() && is!(self, '<')) || is!(self, '(') {
+        if (self.input.syntax().typescript_or_flow() && is!(self, '<')) || is!(self, '(') {
             return self
                 .parse_fn_args_body(
                     // no decorator in an object literal
@@ -293,7 +293,7 @@ impl<I: Tokens> ParseObject<Box<Expr>> for Parser<I> {
                                     self.emit_err(type_params.span(), SyntaxError::TS1094);
                                 }
 
-                                if self.input.syntax().typescript()
+                                if self.input.syntax().typescript_or_flow()
                                     && self.input.target() == EsVersion::Es3
                                 {
                                     self.emit_err(key_span, SyntaxError::TS1056);
@@ -325,7 +325,7 @@ impl<I: Tokens> ParseObject<Box<Expr>> for Parser<I> {
                                     }
                                 }
 
-                                if p.input.syntax().typescript()
+                                if p.input.syntax().typescript_or_flow()
                                     && p.input.target() == EsVersion::Es3
                                 {
                                     p.emit_err(key_span, SyntaxError::TS1056);
@@ -376,7 +376,7 @@ impl<I: Tokens> ParseObject<Box<Expr>> for Parser<I> {
                 }
             }
             _ => {
-                if self.input.syntax().typescript() {
+                if self.input.syntax().typescript_or_flow() {
                     unexpected!(
                         self,
                         "... , *,  (, [, :, , ?, =, an identifier, public, protected, private, \
@@ -12,7 +12,9 @@ impl<'a, I: Tokens> Parser<I> {
     pub(super) fn parse_opt_binding_ident(&mut self) -> PResult<Option<BindingIdent>> {
         trace_cur!(self, parse_opt_binding_ident);
 
-        if is!(self, BindingIdent) || (self.input.syntax().typescript() && is!(self, "this")) {
+        if is!(self, BindingIdent)
+            || (self.input.syntax().typescript_or_flow() && is!(self, "this"))
+        {
             self.parse_binding_ident().map(Some)
         } else {
             Ok(None)
@@ -165,7 +167,7 @@ impl<'a, I: Tokens> Parser<I> {
         let mut pat = self.parse_binding_element()?;
         let mut opt = false;
 
-        if self.input.syntax().typescript() {
+        if self.input.syntax().typescript_or_flow() {
             if eat!(self, '?') {
                 match pat {
                     Pat::Ident(BindingIdent {
@@ -285,7 +287,7 @@ impl<'a, I: Tokens> Parser<I> {
                 let dot3_token = span!(self, pat_start);
 
                 let pat = self.parse_binding_pat_or_ident()?;
-                let type_ann = if self.input.syntax().typescript() && is!(self, ':') {
+                let type_ann = if self.input.syntax().typescript_or_flow() && is!(self, ':') {
                     let cur_pos = cur_pos!(self);
                     Some(self.parse_ts_type_ann(/* eat_colon */ true, cur_pos)?)
                 } else {
@@ -422,7 +424,7 @@ impl<'a, I: Tokens> Parser<I> {
                     .into();
                 }
 
-                let type_ann = if self.input.syntax().typescript() && is!(self, ':') {
+                let type_ann = if self.input.syntax().typescript_or_flow() && is!(self, ':') {
                     let cur_pos = cur_pos!(self);
                     let ty = self.parse_ts_type_ann(/* eat_colon */ true, cur_pos)?;
                     Some(ty)
@@ -437,7 +439,7 @@ impl<'a, I: Tokens> Parser<I> {
                     type_ann,
                 });
 
-                if self.syntax().typescript() && eat!(self, '?') {
+                if self.syntax().typescript_or_flow() && eat!(self, '?') {
                     self.emit_err(self.input.prev_span(), SyntaxError::TS1047);
                     //
                 }
@@ -518,6 +520,45 @@ impl<'a, I: Tokens> Parser<I> {
 
         let span = expr.span();
 
+        // `(x: T) => {}`, which is parsed as a type cast of flow.
+        if self.input.syntax().flow() && pat_ty == PatType::BindingPat && expr.is_ts_as() {
+            let TsAsExpr {
+                expr: inner,
+                type_ann,
+                ..
+            } = expr.expect_ts_as();
+            let mut pat = self.reparse_expr_as_pat_inner(pat_ty, inner)?;
+            let type_ann = Some(TsTypeAnn {
+                span: type_ann.span(),
+                type_ann,
+            });
+            match pat {
+                Pat::Ident(BindingIdent {
+                    id: Ident {
+                        span: ref mut s, ..
+                    },
+                    type_ann: ref mut t,
+                })
+                | Pat::Array(ArrayPat {
+                    span: ref mut s,
+                    type_ann: ref mut t,
+                    ..
+                })
+                | Pat::Object(ObjectPat {
+                    span: ref mut s,
+                    type_ann: ref mut t,
+                    ..
+                }) => {
+                    *s = span;
+                    *t = type_ann;
+                }
+                _ => {
+                    self.emit_err(span, SyntaxError::InvalidPat);
+                }
+            }
+            return Ok(pat);
+        }
+
         if pat_ty == PatType::AssignPat {
             match *expr {
                 Expr::Object(..) | Expr::Array(..) => {
@@ -26,7 +26,36 @@ impl<'a, I: Tokens> Parser<I> {
             let c = cur!(self, false).ok();
             c != end
         } {
-            let stmt = self.parse_stmt_like(true, top_level)?;
+            if self.state.recover && end.is_some() && eof!(self) {
+                // Keep the statements of an unterminated block, instead of
+                // failing the enclosing statement.
+                const TOKEN: &Token = &tok!('}');
+                let cur = self.input.dump_cur();
+                self.emit_err(self.input.cur_span(), SyntaxError::Expected(TOKEN, cur));
+
+                self.set_ctx(old_ctx);
+                return Ok(stmts.into_vec());
+            }
+
+            let start = cur_pos!(self);
+            let stmt = match self.parse_stmt_like(true, top_level) {
+                Ok(stmt) => stmt,
+                Err(err) if self.state.recover && !self.ctx().ignore_error => {
+                    self.input_ref().add_error(err);
+                    if let Err(err) = self.skip_to_sync_point(end) {
+                        // The invalid token is skipped, so we can continue.
+                        self.input_ref().add_error(err);
+                    }
+
+                    let span = span!(self, start);
+                    stmts.alloc(Type::from(Stmt::Expr(ExprStmt {
+                        span,
+                        expr: Box::new(Expr::Invalid(Invalid { span })),
+                    })));
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
             if allow_directives {
                 allow_directives = false;
                 if stmt.is_use_strict() {
@@ -58,6 +87,50 @@ impl<'a, I: Tokens> Parser<I> {
         Ok(stmts.into_vec())
     }
 
+    /// Skips tokens until a point where the next statement can be parsed.
+    ///
+    /// Used only in the recovery mode.
+    fn skip_to_sync_point(&mut self, end: Option<&Token>) -> PResult<()> {
+        let start = cur_pos!(self);
+        let mut depth = 0usize;
+
+        while !eof!(self) {
+            if depth == 0 && self.input.cur() == end {
+                break;
+            }
+
+            if is_one_of!(self, '{', '(', '[') {
+                depth += 1;
+            } else if is_one_of!(self, '}', ')', ']') {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    // End of a block or a stray closing token.
+                    bump!(self);
+                    break;
+                }
+            } else if depth == 0 {
+                if self.input.eat(&Token::Semi) {
+                    break;
+                }
+
+                let is_stmt_start = is_one_of!(
+                    self, "function", "class", "const", "let", "var", "if", "for", "while",
+                    "return", "throw", "try", "switch", "import", "export"
+                );
+                if is_stmt_start
+                    && cur_pos!(self) != start
+                    && self.input.had_line_break_before_cur()
+                {
+                    break;
+                }
+            }
+
+            bump!(self);
+        }
+
+        Ok(())
+    }
+
     pub fn parse_stmt(&mut self, top_level: bool) -> PResult<Stmt> {
         trace_cur!(self, parse_stmt);
         self.parse_stmt_like(false, top_level)
@@ -78,7 +151,9 @@ impl<'a, I: Tokens> Parser<I> {
         let start = cur_pos!(self);
         let decorators = self.parse_decorators(true)?;
 
-        if is_one_of!(self, "import", "export") {
+        if is_one_of!(self, "import", "export")
+            || (self.input.syntax().flow() && is!(self, "declare") && peeked_is!(self, "export"))
+        {
             return self.handle_import_export(top_level, decorators);
         }
 
@@ -321,7 +396,7 @@ impl<'a, I: Tokens> Parser<I> {
                 }));
             }
 
-            if self.input.syntax().typescript() {
+            if self.input.syntax().typescript_or_flow() {
                 if let Some(decl) = self.parse_ts_expr_stmt(decorators, ident.clone())? {
                     return Ok(Stmt::Decl(decl));
                 }
@@ -590,7 +665,7 @@ impl<'a, I: Tokens> Parser<I> {
 
             let type_ann_start = cur_pos!(self);
 
-            if self.syntax().typescript() && eat!(self, ':') {
+            if self.syntax().typescript_or_flow() && eat!(self, ':') {
                 let ctx = Context {
                     in_type: true,
                     ..self.ctx()
@@ -632,7 +707,7 @@ impl<'a, I: Tokens> Parser<I> {
         let var_span = span!(self, start);
         let should_include_in = kind != VarDeclKind::Var || !for_loop;
 
-        if self.syntax().typescript() && for_loop {
+        if self.syntax().typescript_or_flow() && for_loop {
             let res = if is_one_of!(self, "in", "of") {
                 self.ts_look_ahead(|p| {
                     //
@@ -724,7 +799,7 @@ impl<'a, I: Tokens> Parser<I> {
 
         let mut name = self.parse_binding_pat_or_ident()?;
 
-        let definite = if self.input.syntax().typescript() {
+        let definite = if self.input.syntax().typescript_or_flow() {
             match name {
                 Pat::Ident(..) => eat!(self, '!'),
                 _ => false,
@@ -734,7 +809,7 @@ impl<'a, I: Tokens> Parser<I> {
         };
 
         // Typescript extension
-        if self.input.syntax().typescript() && is!(self, ':') {
+        if self.input.syntax().typescript_or_flow() && is!(self, ':') {
             let type_annotation = self.try_parse_ts_type_ann()?;
             match name {
                 Pat::Array(ArrayPat {
@@ -836,7 +911,7 @@ impl<'a, I: Tokens> Parser<I> {
     }
 
     fn parse_with_stmt(&mut self) -> PResult<Stmt> {
-        if self.syntax().typescript() {
+        if self.syntax().typescript_or_flow() {
             let span = self.input.cur_span();
             self.emit_err(span, SyntaxError::TS2410);
         }
@@ -1003,7 +1078,7 @@ impl<'a, I: Tokens> Parser<I> {
                         );
                     }
 
-                    if self.syntax().typescript() {
+                    if self.syntax().typescript_or_flow() {
                         let type_ann = match decl.decls[0].name {
                             Pat::Ident(ref v) => Some(&v.type_ann),
                             Pat::Array(ref v) => Some(&v.type_ann),
@@ -1041,7 +1116,7 @@ impl<'a, I: Tokens> Parser<I> {
             let pat = self.reparse_expr_as_pat(PatType::AssignPat, init)?;
 
             // for ({} in foo) is invalid
-            if self.input.syntax().typescript() && is_in {
+            if self.input.syntax().typescript_or_flow() && is_in {
                 match pat {
                     Pat::Ident(..) => {}
                     Pat::Expr(..) => {}
@@ -88,10 +88,16 @@ impl<'a, I: Tokens> Parser<I> {
             .map(ModuleItem::from);
         }
 
-        let type_only = self.input.syntax().typescript()
+        let type_only = self.input.syntax().typescript_or_flow()
             && is!(self, "type")
             && (peeked_is!(self, '{') || !peeked_is!(self, "from") && !peeked_is!(self, ','));
 
+        // `import typeof A from 'a'`
+        let typeof_only = self.input.syntax().flow() && is!(self, "typeof");
+        if typeof_only {
+            bump!(self);
+        }
+
         if type_only {
             assert_and_bump!(self, "type");
 
@@ -105,6 +111,8 @@ impl<'a, I: Tokens> Parser<I> {
             }
         }
 
+        let type_only = type_only || typeof_only;
+
         let mut specifiers = vec![];
 
         if is!(self, BindingIdent) {
@@ -199,8 +207,10 @@ impl<'a, I: Tokens> Parser<I> {
                 // `import { type as } from 'mod'`
                 // `import { type as as } from 'mod'`
                 // `import { type as as as } from 'mod'`
-                if self.syntax().typescript()
-                    && orig_name.sym == js_word!("type")
+                // `import { typeof xx } from 'mod'` of flow
+                if self.syntax().typescript_or_flow()
+                    && (orig_name.sym == js_word!("type")
+                        || (self.syntax().flow() && orig_name.sym == js_word!("typeof")))
                     && is!(self, IdentName)
                 {
                     let possibly_orig_name = self.parse_ident_name()?;
@@ -333,7 +343,10 @@ impl<'a, I: Tokens> Parser<I> {
         Ok(self.with_ctx(ctx).parse_binding_ident()?.id)
     }
 
-    fn parse_export(&mut self, decorators: Vec<Decorator>) -> PResult<ModuleDecl> {
+    pub(in crate::parser) fn parse_export(
+        &mut self,
+        decorators: Vec<Decorator>,
+    ) -> PResult<ModuleDecl> {
         if !self.ctx().module {
             // Switch to module mode
             let ctx = Context {
@@ -362,7 +375,7 @@ impl<'a, I: Tokens> Parser<I> {
             }
         }
 
-        if self.input.syntax().typescript() && is!(self, IdentName) {
+        if self.input.syntax().typescript_or_flow() && is!(self, IdentName) {
             let sym = match *cur!(self, true)? {
                 Token::Word(ref w) => w.clone().into(),
                 _ => unreachable!(),
@@ -415,11 +428,11 @@ impl<'a, I: Tokens> Parser<I> {
         let mut export_ns = None;
         let ns_export_specifier_start = cur_pos!(self);
 
-        let type_only = self.input.syntax().typescript() && eat!(self, "type");
+        let type_only = self.input.syntax().typescript_or_flow() && eat!(self, "type");
 
         if eat!(self, '*') {
             has_star = true;
-            if self.input.syntax().typescript() && type_only {
+            if self.input.syntax().typescript_or_flow() && type_only {
                 // export type * from "mod";
                 // or
                 // export type * as foo from "mod";
@@ -687,7 +700,7 @@ impl<'a, I: Tokens> Parser<I> {
                 // `export { type as }`
                 // `export { type as as }`
                 // `export { type as as as }`
-                if self.syntax().typescript()
+                if self.syntax().typescript_or_flow()
                     && orig_ident.sym == js_word!("type")
                     && is!(self, IdentName)
                 {
@@ -838,6 +851,9 @@ impl<'a, I: Tokens> StmtLikeParser<'a, ModuleItem> for Parser<I> {
 
         let decl = if is!(self, "import") {
             self.parse_import()?
+        } else if is!(self, "declare") {
+            self.parse_flow_declare_export(decorators)
+                .map(ModuleItem::from)?
         } else if is!(self, "export") {
             self.parse_export(decorators).map(ModuleItem::from)?
         } else {
@@ -356,3 +356,139 @@ fn illegal_language_mode_directive2() {
         },
     );
 }
+
+fn module_recovering(src: &'static str) -> (Module, Vec<Error>) {
+    test_parser(src, Default::default(), |p| Ok(p.parse_module_recovering()))
+}
+
+#[test]
+fn recover_invalid_stmt() {
+    let (m, errors) = module_recovering(
+        "
+        const a = ;
+        function b() {}
+        export const c = 1;
+        ",
+    );
+
+    assert_ne!(errors, vec![]);
+    assert_eq!(m.body.len(), 3);
+    assert!(matches!(
+        m.body[0],
+        ModuleItem::Stmt(Stmt::Expr(ExprStmt { ref expr, .. }))
+            if matches!(**expr, Expr::Invalid(..))
+    ));
+    assert!(matches!(
+        m.body[1],
+        ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl { ref ident, .. }))) if &*ident.sym == "b"
+    ));
+    assert!(
+        matches!(
+            m.body[2],
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Var(..),
+                ..
+            }))
+        )
+    );
+}
+
+#[test]
+fn recover_in_fn_body() {
+    let (m, errors) = module_recovering(
+        "
+        function a() {
+            foo(;
+            return 1;
+        }
+        function b() {}
+        ",
+    );
+
+    assert_ne!(errors, vec![]);
+    assert_eq!(m.body.len(), 2);
+    match &m.body[0] {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f))) => {
+            let body = f.function.body.as_ref().unwrap();
+            assert_eq!(body.stmts.len(), 2);
+            assert!(matches!(
+                body.stmts[0],
+                Stmt::Expr(ExprStmt { ref expr, .. }) if matches!(**expr, Expr::Invalid(..))
+            ));
+            assert!(matches!(body.stmts[1], Stmt::Return(..)));
+        }
+        item => unreachable!("{:?}", item),
+    }
+    assert!(matches!(
+        m.body[1],
+        ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl { ref ident, .. }))) if &*ident.sym == "b"
+    ));
+}
+
+#[test]
+fn recover_unterminated_block() {
+    let (m, errors) = module_recovering(
+        "
+        class A {}
+        function b() {
+            foo();
+            let c =
+        ",
+    );
+
+    assert_ne!(errors, vec![]);
+    assert_eq!(m.body.len(), 2);
+    assert!(matches!(
+        m.body[0],
+        ModuleItem::Stmt(Stmt::Decl(Decl::Class(..)))
+    ));
+    match &m.body[1] {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f))) => {
+            assert_eq!(&*f.ident.sym, "b");
+
+            let body = f.function.body.as_ref().unwrap();
+            assert_eq!(body.stmts.len(), 2, "{:?}", body.stmts);
+            assert!(matches!(body.stmts[0], Stmt::Expr(..)));
+            assert!(matches!(
+                body.stmts[1],
+                Stmt::Expr(ExprStmt { ref expr, .. }) if matches!(**expr, Expr::Invalid(..))
+            ));
+        }
+        item => unreachable!("{:?}", item),
+    }
+}
+
+#[test]
+fn recover_missing_closing_brace() {
+    let (m, errors) = module_recovering(
+        "
+        function a() {
+            if (b) {
+                c();
+        ",
+    );
+
+    // Both blocks are unterminated.
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors
+        .iter()
+        .all(|err| matches!(err.kind(), SyntaxError::Expected(&tok!('}'), ..))));
+    assert_eq!(m.body.len(), 1);
+    match &m.body[0] {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f))) => {
+            let body = f.function.body.as_ref().unwrap();
+            assert_eq!(body.stmts.len(), 1, "{:?}", body.stmts);
+            match &body.stmts[0] {
+                Stmt::If(IfStmt { cons, .. }) => match &**cons {
+                    Stmt::Block(block) => {
+                        assert_eq!(block.stmts.len(), 1, "{:?}", block.stmts);
+                        assert!(matches!(block.stmts[0], Stmt::Expr(..)));
+                    }
+                    stmt => unreachable!("{:?}", stmt),
+                },
+                stmt => unreachable!("{:?}", stmt),
+            }
+        }
+        item => unreachable!("{:?}", item),
+    }
+}
@@ -7,7 +7,7 @@ use swc_common::{Spanned, SyntaxContext};
 impl<I: Tokens> Parser<I> {
     /// `tsNextTokenCanFollowModifier`
     fn ts_next_token_can_follow_modifier(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         // Note: TypeScript's implementation is much more complicated because
         // more things are considered modifiers there.
@@ -61,7 +61,7 @@ impl<I: Tokens> Parser<I> {
     /// `tsIsListTerminator`
 
     fn is_ts_list_terminator(&mut self, kind: ParsingContext) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         Ok(match kind {
             ParsingContext::EnumMembers | ParsingContext::TypeMembers => is!(self, '}'),
@@ -78,7 +78,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnMut(&mut Self) -> PResult<T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let mut buf = vec![];
         while !self.is_ts_list_terminator(kind)? {
@@ -114,7 +114,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnMut(&mut Self) -> PResult<(BytePos, T)>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let mut buf = vec![];
 
@@ -158,7 +158,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnMut(&mut Self) -> PResult<T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         if !skip_first_token {
             if bracket {
@@ -181,7 +181,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseEntityName`
     fn parse_ts_entity_name(&mut self, allow_reserved_words: bool) -> PResult<TsEntityName> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let init = self.parse_ident_name()?;
         if let Ident {
@@ -219,7 +219,7 @@ impl<I: Tokens> Parser<I> {
     /// `tsParseTypeReference`
     fn parse_ts_type_ref(&mut self) -> PResult<TsTypeRef> {
         trace_cur!(self, parse_ts_type_ref);
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
 
@@ -251,7 +251,7 @@ impl<I: Tokens> Parser<I> {
         has_asserts_keyword: bool,
         lhs: TsThisType,
     ) -> PResult<TsTypePredicate> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let param_name = TsThisTypeOrIdent::TsThisType(lhs);
         let type_ann = if eat!(self, "is") {
@@ -274,7 +274,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseThisTypeNode`
     fn parse_ts_this_type_node(&mut self) -> PResult<TsThisType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         expect!(self, "this");
 
@@ -342,7 +342,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTypeQuery`
     fn parse_ts_type_query(&mut self) -> PResult<TsTypeQuery> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         expect!(self, "typeof");
@@ -364,12 +364,20 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTypeParameter`
     fn parse_ts_type_param(&mut self) -> PResult<TsTypeParam> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
 
+        if self.input.syntax().flow() {
+            self.eat_flow_variance()?;
+        }
+
         let name = self.in_type().parse_ident_name()?;
-        let constraint = self.eat_then_parse_ts_type(&tok!("extends"))?;
+        let constraint = if self.input.syntax().flow() && is!(self, ':') {
+            self.eat_then_parse_ts_type(&tok!(':'))?
+        } else {
+            self.eat_then_parse_ts_type(&tok!("extends"))?
+        };
         let default = self.eat_then_parse_ts_type(&tok!('='))?;
 
         Ok(TsTypeParam {
@@ -412,7 +420,7 @@ impl<I: Tokens> Parser<I> {
         &mut self,
         return_token: &'static Token,
     ) -> PResult<TsTypeAnn> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         self.in_type().parse_with(|p| {
             let return_token_start = cur_pos!(p);
@@ -472,7 +480,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnOnce(&mut Self) -> PResult<Option<bool>>,
     {
-        if !self.input.syntax().typescript() {
+        if !self.input.syntax().typescript_or_flow() {
             return Ok(false);
         }
         let prev_ignore_error = self.input.get_ctx().ignore_error;
@@ -503,7 +511,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnOnce(&mut Self) -> PResult<Option<T>>,
     {
-        if !self.input.syntax().typescript() {
+        if !self.input.syntax().typescript_or_flow() {
             return None;
         }
         #[cfg(feature = "debug")]
@@ -554,7 +562,7 @@ impl<I: Tokens> Parser<I> {
     ) -> PResult<TsTypeAnn> {
         trace_cur!(self, parse_ts_type_ann);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         self.in_type().parse_with(|p| {
             if eat_colon {
@@ -596,7 +604,7 @@ impl<I: Tokens> Parser<I> {
         token: &'static Token,
         token_str: &'static str,
     ) -> PResult<Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         self.in_type().parse_with(|p| {
             if !p.input.eat(token) {
@@ -617,7 +625,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsNextThenParseType`
     pub(super) fn next_then_parse_ts_type(&mut self) -> PResult<Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         self.in_type().parse_with(|p| {
             bump!(p);
@@ -628,7 +636,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseEnumMember`
     fn parse_ts_enum_member(&mut self) -> PResult<TsEnumMember> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         // Computed property names are grammar errors in an enum, so accept just string
@@ -695,7 +703,7 @@ impl<I: Tokens> Parser<I> {
         start: BytePos,
         is_const: bool,
     ) -> PResult<TsEnumDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let id = self.parse_ident_name()?;
         expect!(self, '{');
@@ -716,7 +724,7 @@ impl<I: Tokens> Parser<I> {
     fn parse_ts_module_block(&mut self) -> PResult<TsModuleBlock> {
         trace_cur!(self, parse_ts_module_block);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         expect!(self, '{');
@@ -736,7 +744,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseModuleOrNamespaceDeclaration`
     fn parse_ts_module_or_ns_decl(&mut self, start: BytePos) -> PResult<TsModuleDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let id = self.parse_ident_name()?;
         let body: TsNamespaceBody = if eat!(self, '.') {
@@ -768,7 +776,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseAmbientExternalModuleDeclaration`
     fn parse_ts_ambient_external_module_decl(&mut self, start: BytePos) -> PResult<TsModuleDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let (global, id) = if is!(self, "global") {
             let id = self.parse_ident_name()?;
@@ -800,7 +808,7 @@ impl<I: Tokens> Parser<I> {
     }
 
     pub fn parse_type(&mut self) -> PResult<Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         self.in_type().parse_ts_type()
     }
@@ -811,7 +819,7 @@ impl<I: Tokens> Parser<I> {
     pub(super) fn parse_ts_type(&mut self) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_type);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         // Need to set `state.inType` so that we don't parse JSX in a type context.
         debug_assert!(self.ctx().in_type);
@@ -847,7 +855,7 @@ impl<I: Tokens> Parser<I> {
     fn parse_ts_non_conditional_type(&mut self) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_non_conditional_type);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         if self.is_ts_start_of_fn_type()? {
             return self
@@ -867,7 +875,11 @@ impl<I: Tokens> Parser<I> {
     }
 
     fn is_ts_start_of_fn_type(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
+
+        if self.input.syntax().flow() {
+            return self.is_flow_start_of_fn_type();
+        }
 
         if is!(self, '<') {
             return Ok(true);
@@ -878,7 +890,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTypeAssertion`
     pub(super) fn parse_ts_type_assertion(&mut self, start: BytePos) -> PResult<TsTypeAssertion> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         // Not actually necessary to set state.inType because we never reach here if JSX
         // plugin is enabled, but need `tsInType` to satisfy the assertion in
@@ -895,7 +907,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseHeritageClause`
     pub(super) fn parse_ts_heritage_clause(&mut self) -> PResult<Vec<TsExprWithTypeArgs>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         self.parse_ts_delimited_list(ParsingContext::HeritageClauseElement, |p| {
             p.parse_expr_with_type_args()
@@ -904,7 +916,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseExpressionWithTypeArguments`
     fn parse_expr_with_type_args(&mut self) -> PResult<TsExprWithTypeArgs> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         // Note: TS uses parseLeftHandSideExpressionOrHigher,
@@ -925,7 +937,7 @@ impl<I: Tokens> Parser<I> {
     }
     /// `tsParseInterfaceDeclaration`
     pub(super) fn parse_ts_interface_decl(&mut self, start: BytePos) -> PResult<TsInterfaceDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let id = self.parse_ident_name()?;
         match id.sym {
@@ -985,7 +997,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTypeAliasDeclaration`
     fn parse_ts_type_alias_decl(&mut self, start: BytePos) -> PResult<TsTypeAliasDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let id = self.parse_ident_name()?;
         let type_params = self.try_parse_ts_type_params()?;
@@ -1007,7 +1019,7 @@ impl<I: Tokens> Parser<I> {
         is_export: bool,
         is_type_only: bool,
     ) -> PResult<TsImportEqualsDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let id = self.parse_ident_name()?;
         expect!(self, '=');
@@ -1026,14 +1038,14 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsIsExternalModuleReference`
     fn is_ts_external_module_ref(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         Ok(is!(self, "require") && peeked_is!(self, '('))
     }
 
     /// `tsParseModuleReference`
     fn parse_ts_module_ref(&mut self) -> PResult<TsModuleRef> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         if self.is_ts_external_module_ref()? {
             self.parse_ts_external_module_ref().map(From::from)
@@ -1045,7 +1057,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseExternalModuleReference`
     fn parse_ts_external_module_ref(&mut self) -> PResult<TsExternalModuleRef> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         expect!(self, "require");
@@ -1069,7 +1081,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnOnce(&mut Self) -> PResult<T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let mut cloned = self.clone();
         let ctx = Context {
@@ -1082,7 +1094,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsIsUnambiguouslyStartOfFunctionType`
     fn is_ts_unambiguously_start_of_fn_type(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         assert_and_bump!(self, '(');
         if is_one_of!(self, ')', "...") {
@@ -1108,7 +1120,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsSkipParameterStart`
     fn skip_ts_parameter_start(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let _ = self.eat_any_ts_modifier()?;
 
@@ -1125,8 +1137,13 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// `tsParseTypeMemberSemicolon`
-    fn parse_ts_type_member_semicolon(&mut self) -> PResult<()> {
-        debug_assert!(self.input.syntax().typescript());
+    pub(super) fn parse_ts_type_member_semicolon(&mut self) -> PResult<()> {
+        debug_assert!(self.input.syntax().typescript_or_flow());
+
+        // End of an exact object type
+        if self.input.syntax().flow() && is!(self, '|') {
+            return Ok(());
+        }
 
         if !eat!(self, ',') {
             expect!(self, ';');
@@ -1140,7 +1157,7 @@ impl<I: Tokens> Parser<I> {
         &mut self,
         kind: SignatureParsingMode,
     ) -> PResult<Either<TsCallSignatureDecl, TsConstructSignatureDecl>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
 
@@ -1182,8 +1199,8 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// `tsIsUnambiguouslyIndexSignature`
-    fn is_ts_unambiguously_index_signature(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+    pub(super) fn is_ts_unambiguously_index_signature(&mut self) -> PResult<bool> {
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         // Note: babel's comment is wrong
         assert_and_bump!(self, '['); // Skip '['
@@ -1279,7 +1296,7 @@ impl<I: Tokens> Parser<I> {
         start: BytePos,
         readonly: bool,
     ) -> PResult<Either<TsPropertySignature, TsMethodSignature>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let (computed, key) = self.parse_ts_property_name()?;
 
@@ -1327,8 +1344,8 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// `tsParseTypeMember`
-    fn parse_ts_type_member(&mut self) -> PResult<TsTypeElement> {
-        debug_assert!(self.input.syntax().typescript());
+    pub(super) fn parse_ts_type_member(&mut self) -> PResult<TsTypeElement> {
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         fn into_type_elem(
             e: Either<TsCallSignatureDecl, TsConstructSignatureDecl>,
@@ -1420,7 +1437,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsIsStartOfConstructSignature`
     fn is_ts_start_of_construct_signature(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         bump!(self);
 
@@ -1429,7 +1446,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTypeLiteral`
     fn parse_ts_type_lit(&mut self) -> PResult<TsTypeLit> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         let members = self.parse_ts_object_type_members()?;
@@ -1441,7 +1458,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseObjectTypeMembers`
     fn parse_ts_object_type_members(&mut self) -> PResult<Vec<TsTypeElement>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         expect!(self, '{');
         let members =
@@ -1452,7 +1469,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsIsStartOfMappedType`
     fn is_ts_start_of_mapped_type(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         bump!(self);
         if eat!(self, '+') || eat!(self, '-') {
@@ -1475,7 +1492,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseMappedTypeParameter`
     fn parse_ts_mapped_type_param(&mut self) -> PResult<TsTypeParam> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         let name = self.parse_ident_name()?;
@@ -1491,7 +1508,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseMappedType`
     fn parse_ts_mapped_type(&mut self) -> PResult<TsMappedType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         expect!(self, '{');
@@ -1546,7 +1563,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTupleType`
     fn parse_ts_tuple_type(&mut self) -> PResult<TsTupleType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         let elems = self.parse_ts_bracketed_list(
@@ -1621,7 +1638,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTupleElementType`
     fn parse_ts_tuple_element_type(&mut self) -> PResult<TsTupleElement> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         // parses `...TsType[]`
         let start = cur_pos!(self);
@@ -1663,7 +1680,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseParenthesizedType`
     fn parse_ts_parenthesized_type(&mut self) -> PResult<TsParenthesizedType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         expect!(self, '(');
@@ -1682,7 +1699,7 @@ impl<I: Tokens> Parser<I> {
     ) -> PResult<TsFnOrConstructorType> {
         trace_cur!(self, parse_ts_fn_or_constructor_type);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         let is_abstract = if !is_fn_type {
@@ -1697,7 +1714,11 @@ impl<I: Tokens> Parser<I> {
         // ----- inlined `self.tsFillSignature(tt.arrow, node)`
         let type_params = self.try_parse_ts_type_params()?;
         expect!(self, '(');
-        let params = self.parse_ts_binding_list_for_signature()?;
+        let params = if self.input.syntax().flow() {
+            self.parse_flow_fn_type_params()?
+        } else {
+            self.parse_ts_binding_list_for_signature()?
+        };
         let type_ann = self.parse_ts_type_or_type_predicate_ann(&tok!("=>"))?;
         // ----- end
 
@@ -1721,7 +1742,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseLiteralTypeNode`
     fn parse_ts_lit_type_node(&mut self) -> PResult<TsLitType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
 
@@ -1747,7 +1768,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTemplateLiteralType`
     fn parse_ts_tpl_lit_type(&mut self) -> PResult<TsTplLitType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
 
@@ -1797,7 +1818,7 @@ impl<I: Tokens> Parser<I> {
             return Ok(Default::default());
         }
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let params = self.parse_formal_params()?;
         let mut list = vec![];
@@ -1877,9 +1898,10 @@ impl<I: Tokens> Parser<I> {
             unreachable!()
         }
         trace_cur!(self, parse_ts_non_array_type);
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
+        let flow = self.input.syntax().flow();
 
         match *cur!(self, true)? {
             Token::Word(Word::Ident(..))
@@ -1924,7 +1946,7 @@ impl<I: Tokens> Parser<I> {
                 } else if is!(self, "intrinsic") {
                     Some(TsKeywordTypeKind::TsIntrinsicKeyword)
                 } else {
-                    None
+                    self.flow_keyword_type_kind()
                 };
 
                 let peeked_is_dot = peeked_is!(self, '.');
@@ -1994,6 +2016,14 @@ impl<I: Tokens> Parser<I> {
                 return self.parse_ts_type_query().map(TsType::from).map(Box::new);
             }
 
+            tok!('*') if flow => {
+                return self.parse_flow_existential_type();
+            }
+
+            tok!('{') if flow => {
+                return self.parse_flow_object_type();
+            }
+
             tok!('{') => {
                 return if self.ts_look_ahead(|p| p.is_ts_start_of_mapped_type())? {
                     self.parse_ts_mapped_type().map(TsType::from).map(Box::new)
@@ -2025,7 +2055,7 @@ impl<I: Tokens> Parser<I> {
     /// `tsParseArrayTypeOrHigher`
     fn parse_ts_array_type_or_higher(&mut self, readonly: bool) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_array_type_or_higher);
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let mut ty = self.parse_ts_non_array_type()?;
 
@@ -2052,7 +2082,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTypeOperator`
     fn parse_ts_type_operator(&mut self, op: TsTypeOperatorOp) -> PResult<TsTypeOperator> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         match op {
@@ -2071,7 +2101,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseInferType`
     fn parse_ts_infer_type(&mut self) -> PResult<TsInferType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         expect!(self, "infer");
@@ -2089,9 +2119,9 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// `tsParseTypeOperatorOrHigher`
-    fn parse_ts_type_operator_or_higher(&mut self) -> PResult<Box<TsType>> {
+    pub(super) fn parse_ts_type_operator_or_higher(&mut self) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_type_operator_or_higher);
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let operator = if is!(self, "keyof") {
             Some(TsTypeOperatorOp::KeyOf)
@@ -2111,7 +2141,9 @@ impl<I: Tokens> Parser<I> {
             None => {
                 trace_cur!(self, parse_ts_type_operator_or_higher__not_operator);
 
-                if is!(self, "infer") {
+                if self.input.syntax().flow() && is!(self, '?') {
+                    self.parse_flow_maybe_type()
+                } else if is!(self, "infer") {
                     self.parse_ts_infer_type().map(TsType::from).map(Box::new)
                 } else {
                     let readonly = self.parse_ts_modifier(&["readonly"], false)?.is_some();
@@ -2201,11 +2233,12 @@ impl<I: Tokens> Parser<I> {
         start: BytePos,
         decorators: Vec<Decorator>,
     ) -> PResult<Option<Decl>> {
-        if !self.syntax().typescript() {
+        if !self.syntax().typescript_or_flow() {
             return Ok(None);
         }
 
-        if self.ctx().in_declare {
+        // Declarations in `declare module` of flow are always `declare`d.
+        if self.ctx().in_declare && self.input.syntax().typescript() {
             let span_of_declare = span!(self, start);
             self.emit_err(span_of_declare, SyntaxError::TS1038);
         }
@@ -2257,7 +2290,7 @@ impl<I: Tokens> Parser<I> {
                     .map(Some);
             }
 
-            if is!(p, "const") && peeked_is!(p, "enum") {
+            if p.input.syntax().typescript() && is!(p, "const") && peeked_is!(p, "enum") {
                 assert_and_bump!(p, "const");
                 let _ = cur!(p, true);
                 assert_and_bump!(p, "enum");
@@ -2290,7 +2323,14 @@ impl<I: Tokens> Parser<I> {
                     .map(Some);
             }
 
-            if is!(p, "global") {
+            if p.input.syntax().flow() && is!(p, "module") && peeked_is!(p, '.') {
+                return p
+                    .parse_flow_declare_module_exports(start)
+                    .map(From::from)
+                    .map(Some);
+            }
+
+            if p.input.syntax().typescript() && is!(p, "global") {
                 return p
                     .parse_ts_ambient_external_module_decl(start)
                     .map(Decl::from)
@@ -2346,7 +2386,7 @@ impl<I: Tokens> Parser<I> {
         }
 
         match value {
-            js_word!("abstract") => {
+            js_word!("abstract") if self.input.syntax().typescript() => {
                 if next || (is!(self, "class") && !self.input.had_line_break_before_cur()) {
                     if next {
                         bump!(self);
@@ -2367,7 +2407,7 @@ impl<I: Tokens> Parser<I> {
                 }
             }
 
-            js_word!("enum") => {
+            js_word!("enum") if self.input.syntax().typescript() => {
                 if next || is!(self, IdentRef) {
                     if next {
                         bump!(self);
@@ -2392,7 +2432,8 @@ impl<I: Tokens> Parser<I> {
                 }
             }
 
-            js_word!("module") => {
+            // `declare module 'm' {}` of flow
+            js_word!("module") if self.input.syntax().typescript() || self.ctx().in_declare => {
                 if next {
                     bump!(self);
                 }
@@ -2410,7 +2451,7 @@ impl<I: Tokens> Parser<I> {
                 }
             }
 
-            js_word!("namespace") => {
+            js_word!("namespace") if self.input.syntax().typescript() => {
                 if next || is!(self, IdentRef) {
                     if next {
                         bump!(self);
@@ -2434,6 +2475,15 @@ impl<I: Tokens> Parser<I> {
                 }
             }
 
+            _ if self.input.syntax().flow() && &*value == "opaque" => {
+                if next || (is!(self, "type") && !self.input.had_line_break_before_cur()) {
+                    if next {
+                        bump!(self);
+                    }
+                    return self.parse_flow_opaque_type(start).map(From::from).map(Some);
+                }
+            }
+
             _ => {}
         }
 
@@ -2498,7 +2548,7 @@ impl<I: Tokens> Parser<I> {
     /// `tsParseTypeArguments`
     pub fn parse_ts_type_args(&mut self) -> PResult<TsTypeParamInstantiation> {
         trace_cur!(self, parse_ts_type_args);
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self);
         let params = self.in_type().parse_with(|p| {
@@ -2528,7 +2578,7 @@ impl<I: Tokens> Parser<I> {
     fn parse_ts_intersection_type_or_higher(&mut self) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_intersection_type_or_higher);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         self.parse_ts_union_or_intersection_type(
             UnionOrIntersection::Intersection,
@@ -2540,7 +2590,7 @@ impl<I: Tokens> Parser<I> {
     /// `tsParseUnionTypeOrHigher`
     fn parse_ts_union_type_or_higher(&mut self) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_union_type_or_higher);
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         self.parse_ts_union_or_intersection_type(
             UnionOrIntersection::Union,
@@ -2561,7 +2611,7 @@ impl<I: Tokens> Parser<I> {
     {
         trace_cur!(self, parse_ts_union_or_intersection_type);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let start = cur_pos!(self); // include the leading operator in the start
         self.input.eat(operator);
@@ -2570,10 +2620,10 @@ impl<I: Tokens> Parser<I> {
         let ty = parse_constituent_type(self)?;
         trace_cur!(self, parse_ts_union_or_intersection_type__after_first);
 
-        if self.input.is(operator) {
+        if self.input.is(operator) && !self.is_flow_exact_object_end()? {
             let mut types = vec![ty];
 
-            while self.input.eat(operator) {
+            while !self.is_flow_exact_object_end()? && self.input.eat(operator) {
                 trace_cur!(self, parse_ts_union_or_intersection_type__constituent);
 
                 types.push(parse_constituent_type(self)?);
@@ -2603,7 +2653,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnOnce(&mut Self) -> PResult<T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().typescript_or_flow());
 
         let cloned = self.input.token_context().clone();
 
@@ -2642,7 +2692,7 @@ enum SignatureParsingMode {
 }
 
 /// Mark as declare
-fn make_decl_declare(mut decl: Decl) -> Decl {
+pub(super) fn make_decl_declare(mut decl: Decl) -> Decl {
     match decl {
         Decl::Class(ref mut c) => c.declare = true,
         Decl::Fn(ref mut f) => f.declare = true,
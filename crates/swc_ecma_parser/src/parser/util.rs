@@ -281,6 +281,10 @@ pub(super) trait ExprExt {
 
             Expr::Seq(..) => false,
 
+            Expr::Pipeline(..) | Expr::TopicRef(..) | Expr::Do(..) => false,
+
+            Expr::Record(..) | Expr::Tuple(..) => false,
+
             // MemberExpression is valid assignment target
             Expr::PrivateName(..) => false,
 
@@ -27,6 +27,10 @@ pub enum Token {
     #[kind(before_expr)]
     Arrow,
 
+    /// '|>'
+    #[kind(before_expr)]
+    Pipeline,
+
     /// '#'
     Hash,
 
@@ -585,6 +589,7 @@ impl Debug for Token {
         match self {
             Token::Word(w) => write!(f, "{:?}", w)?,
             Arrow => write!(f, "=>")?,
+            Pipeline => write!(f, "|>")?,
             Hash => write!(f, "#")?,
             At => write!(f, "@")?,
             Dot => write!(f, ".")?,
@@ -0,0 +1,66 @@
+//! Quasi quotation system for ecmascript.
+//!
+//! The template is parsed at compile time using `swc_ecma_parser`, and the
+//! macros expand to struct literals which create the ast nodes. All spans of
+//! the created nodes are `DUMMY_SP`.
+//!
+//! # Substitutions
+//!
+//! An identifier starting with `$` is replaced with the variable of the same
+//! name, if the type of the variable matches the position in the template.
+//! The type of a variable is one of
+//!
+//!  - `Ident` (default): Used for identifiers.
+//!  - `Expr`: Used for expressions.
+//!  - `Pat`: Used for patterns, like parameters.
+//!  - `Stmt`: Used for a statement like `$stmt;`.
+//!  - `Vec<Stmt>`: Used for a statement like `$stmts;` in a list of statements,
+//!    like the body of a block.
+//!
+//! Note that parens are not inserted for substituted expressions, so you may
+//! need to apply `fixer` to the result.
+//!
+//! It's a compile error if a `$name` in the template is not provided or its
+//! type does not match the position, or if a variable is not used.
+//!
+//! ```compile_fail
+//! # use swc_ecma_quote::{quote, swc_common::DUMMY_SP, swc_ecma_ast::*};
+//! # let a = Expr::Ident(Ident::new("a".into(), DUMMY_SP));
+//! // `$b` is not provided.
+//! let e: Expr = quote!("$a + $b" as Expr, a: Expr = a);
+//! ```
+//!
+//! ```compile_fail
+//! # use swc_ecma_quote::{quote, swc_common::DUMMY_SP, swc_ecma_ast::*};
+//! # let a = Expr::Ident(Ident::new("a".into(), DUMMY_SP));
+//! // An `Expr` cannot be used as a parameter.
+//! let e: Expr = quote!("($a) => 1" as Expr, a: Expr = a);
+//! ```
+//!
+//! ```compile_fail
+//! # use swc_ecma_quote::{quote, swc_common::DUMMY_SP, swc_ecma_ast::*};
+//! # let a = Expr::Ident(Ident::new("a".into(), DUMMY_SP));
+//! // `a` is not used.
+//! let e: Expr = quote!("b + 1" as Expr, a: Expr = a);
+//! ```
+//!
+//! # Example
+//!
+//! ```
+//! use swc_ecma_quote::{
+//!     quote,
+//!     swc_common::DUMMY_SP,
+//!     swc_ecma_ast::{Expr, Ident, Stmt},
+//! };
+//!
+//! let name = Ident::new("foo".into(), DUMMY_SP);
+//! let arg = Expr::Ident(Ident::new("bar".into(), DUMMY_SP));
+//!
+//! let stmt: Stmt = quote!("$name($arg + 1);" as Stmt, name = name, arg: Expr = arg);
+//! ```
+
+pub use num_bigint;
+pub use swc_atoms;
+pub use swc_common;
+pub use swc_ecma_ast;
+pub use swc_ecma_quote_macros::{quote, quote_expr};
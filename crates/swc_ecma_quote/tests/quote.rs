@@ -0,0 +1,101 @@
+use swc_ecma_codegen::{
+    text_writer::{JsWriter, WriteJs},
+    Emitter,
+};
+use swc_ecma_quote::{
+    quote, quote_expr,
+    swc_common::{sync::Lrc, SourceMap, DUMMY_SP},
+    swc_ecma_ast::*,
+};
+
+fn print<N: swc_ecma_codegen::Node>(node: &N) -> String {
+    let cm = Lrc::new(SourceMap::default());
+    let mut buf = vec![];
+
+    {
+        let wr: Box<dyn WriteJs> = Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None));
+
+        let mut emitter = Emitter {
+            cfg: swc_ecma_codegen::Config { minify: true },
+            cm,
+            comments: None,
+            wr,
+        };
+
+        node.emit_with(&mut emitter).unwrap();
+    }
+
+    String::from_utf8(buf).unwrap()
+}
+
+fn ident(sym: &str) -> Ident {
+    Ident::new(sym.into(), DUMMY_SP)
+}
+
+#[test]
+fn expr() {
+    let e: Expr = quote!("a + foo(1, 'str', 2n, true, null)" as Expr);
+
+    assert_eq!(print(&e), "a+foo(1,\"str\",2n,true,null)");
+}
+
+#[test]
+fn object_literal() {
+    let e = quote_expr!("{ a: 1, ...b }");
+
+    assert_eq!(print(&*e), "{a:1,...b}");
+}
+
+#[test]
+fn ident_var() {
+    let e: Expr = quote!("$a.$b($a)" as Expr, a = ident("foo"), b = ident("bar"));
+
+    assert_eq!(print(&e), "foo.bar(foo)");
+}
+
+#[test]
+fn expr_var() {
+    let a = quote_expr!("x.y");
+
+    let e: Expr = quote!(
+        "$a * foo($b)" as Expr,
+        a: Expr = *a,
+        b: Expr = Expr::This(ThisExpr { span: DUMMY_SP })
+    );
+
+    assert_eq!(print(&e), "x.y*foo(this)");
+}
+
+#[test]
+fn pat_var() {
+    let e: Expr = quote!(
+        "($p, [$q]) => 1" as Expr,
+        p: Pat = Pat::Ident(ident("a").into()),
+        q: Pat = quote!("{ b = 1 }" as Pat)
+    );
+
+    assert_eq!(print(&e), "(a,[{b=1}])=>1");
+}
+
+#[test]
+fn stmt_var() {
+    let s: Stmt = quote!(
+        "if ($cond) $stmt;" as Stmt,
+        cond: Expr = Expr::Ident(ident("x")),
+        stmt: Stmt = quote!("return 1;" as Stmt)
+    );
+
+    assert_eq!(print(&s), "if(x)return 1;");
+}
+
+#[test]
+fn stmts_var() {
+    let body = vec![quote!("a();" as Stmt), quote!("b();" as Stmt)];
+
+    let item: ModuleItem = quote!(
+        "function foo() { before(); $body; after(); }" as ModuleItem,
+        body: Vec<Stmt> = body
+    );
+
+    assert_eq!(print(&item), "function foo(){before();a();b();after();}");
+}
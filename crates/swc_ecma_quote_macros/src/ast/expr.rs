@@ -0,0 +1,156 @@
+use super::ToCode;
+use crate::ctx::{Ctx, VarKind};
+use swc_ecma_ast::*;
+use syn::parse_quote;
+
+impl_struct!(ThisExpr, [span,]);
+
+impl_struct!(ArrayLit, [span, elems,]);
+
+impl_struct!(ObjectLit, [span, props,]);
+
+impl_enum!(PropOrSpread, [Spread, Prop,]);
+
+impl_struct!(SpreadElement, [dot3_token, expr,]);
+
+impl_struct!(UnaryExpr, [span, op, arg,]);
+
+impl_struct!(UpdateExpr, [span, op, prefix, arg,]);
+
+impl_struct!(BinExpr, [span, op, left, right,]);
+
+impl_struct!(FnExpr, [ident, function,]);
+
+impl_struct!(ClassExpr, [ident, class,]);
+
+impl_struct!(AssignExpr, [span, op, left, right,]);
+
+impl_struct!(MemberExpr, [span, obj, prop,]);
+
+impl_enum!(MemberProp, [Ident, PrivateName, Computed,]);
+
+impl_struct!(SuperPropExpr, [span, obj, prop,]);
+
+impl_enum!(SuperProp, [Ident, Computed,]);
+
+impl_struct!(CondExpr, [span, test, cons, alt,]);
+
+impl_struct!(CallExpr, [span, callee, args, type_args,]);
+
+impl_struct!(NewExpr, [span, callee, args, type_args,]);
+
+impl_struct!(SeqExpr, [span, exprs,]);
+
+impl_struct!(
+    ArrowExpr,
+    [
+        span,
+        params,
+        body,
+        is_async,
+        is_generator,
+        type_params,
+        return_type,
+    ]
+);
+
+impl_struct!(YieldExpr, [span, arg, delegate,]);
+
+impl_struct!(MetaPropExpr, [span, kind,]);
+
+impl_simple_enum!(MetaPropKind, [NewTarget, ImportMeta,]);
+
+impl_struct!(AwaitExpr, [span, arg,]);
+
+impl_struct!(PipelineExpr, [span, left, right,]);
+
+impl_struct!(TopicRef, [span,]);
+
+impl_struct!(DoExpr, [span, body,]);
+
+impl_struct!(RecordLit, [span, props,]);
+
+impl_struct!(TupleLit, [span, elems,]);
+
+impl_struct!(Tpl, [span, exprs, quasis,]);
+
+impl_struct!(TaggedTpl, [span, tag, type_params, tpl,]);
+
+impl_struct!(TplElement, [span, tail, cooked, raw,]);
+
+impl_struct!(ParenExpr, [span, expr,]);
+
+impl_enum!(Callee, [Super, Import, Expr,]);
+
+impl_struct!(Super, [span,]);
+
+impl_struct!(Import, [span,]);
+
+impl_struct!(ExprOrSpread, [spread, expr,]);
+
+impl_enum!(BlockStmtOrExpr, [BlockStmt, Expr,]);
+
+impl_enum!(PatOrExpr, [Expr, Pat,]);
+
+impl_struct!(OptChainExpr, [span, question_dot_token, expr,]);
+
+impl_struct!(Invalid, [span,]);
+
+impl ToCode for Expr {
+    fn to_code(&self, cx: &Ctx) -> syn::Expr {
+        if let Expr::Ident(i) = self {
+            if let Some(var) = cx.var(VarKind::Expr, &i.sym) {
+                return parse_quote!(#var.clone());
+            }
+        }
+
+        impl_enum_body!(
+            Expr,
+            self,
+            cx,
+            [
+                This,
+                Array,
+                Object,
+                Fn,
+                Unary,
+                Update,
+                Bin,
+                Assign,
+                Member,
+                SuperProp,
+                Cond,
+                Call,
+                New,
+                Seq,
+                Ident,
+                Lit,
+                Tpl,
+                TaggedTpl,
+                Arrow,
+                Class,
+                Yield,
+                MetaProp,
+                Await,
+                Paren,
+                JSXMember,
+                JSXNamespacedName,
+                JSXEmpty,
+                JSXElement,
+                JSXFragment,
+                TsTypeAssertion,
+                TsConstAssertion,
+                TsNonNull,
+                TsAs,
+                PrivateName,
+                OptChain,
+                Pipeline,
+                TopicRef,
+                Do,
+                Record,
+                Tuple,
+                Invalid,
+            ]
+        )
+    }
+}
@@ -0,0 +1,19 @@
+use swc_ecma_ast::*;
+
+impl_struct!(
+    Function,
+    [
+        params,
+        decorators,
+        span,
+        body,
+        is_generator,
+        is_async,
+        type_params,
+        return_type,
+    ]
+);
+
+impl_struct!(Param, [span, decorators, pat,]);
+
+impl_enum!(ParamOrTsParamProp, [TsParamProp, Param,]);
@@ -0,0 +1,26 @@
+use super::ToCode;
+use crate::ctx::{Ctx, VarKind};
+use swc_ecma_ast::*;
+use syn::parse_quote;
+
+impl_struct!(BindingIdent, [id, type_ann,]);
+
+impl_struct!(PrivateName, [span, id,]);
+
+impl ToCode for Ident {
+    fn to_code(&self, cx: &Ctx) -> syn::Expr {
+        if let Some(var) = cx.var(VarKind::Ident, &self.sym) {
+            return parse_quote!(#var.clone());
+        }
+        cx.check_unreplaced(&self.sym);
+
+        let sym = &*self.sym;
+        let optional = self.optional;
+
+        parse_quote!(swc_ecma_quote::swc_ecma_ast::Ident {
+            span: swc_ecma_quote::swc_common::DUMMY_SP,
+            sym: swc_ecma_quote::swc_atoms::JsWord::from(#sym),
+            optional: #optional,
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use swc_ecma_ast::*;
+
+impl_enum!(JSXObject, [JSXMemberExpr, Ident,]);
+
+impl_struct!(JSXMemberExpr, [obj, prop,]);
+
+impl_struct!(JSXNamespacedName, [ns, name,]);
+
+impl_struct!(JSXEmptyExpr, [span,]);
+
+impl_struct!(JSXExprContainer, [span, expr,]);
+
+impl_enum!(JSXExpr, [JSXEmptyExpr, Expr,]);
+
+impl_struct!(JSXSpreadChild, [span, expr,]);
+
+impl_enum!(JSXElementName, [Ident, JSXMemberExpr, JSXNamespacedName,]);
+
+impl_struct!(
+    JSXOpeningElement,
+    [name, span, attrs, self_closing, type_args,]
+);
+
+impl_enum!(JSXAttrOrSpread, [JSXAttr, SpreadElement,]);
+
+impl_struct!(JSXClosingElement, [span, name,]);
+
+impl_struct!(JSXAttr, [span, name, value,]);
+
+impl_enum!(JSXAttrName, [Ident, JSXNamespacedName,]);
+
+impl_enum!(
+    JSXAttrValue,
+    [Lit, JSXExprContainer, JSXElement, JSXFragment,]
+);
+
+impl_struct!(JSXText, [span, value, raw,]);
+
+impl_struct!(JSXElement, [span, opening, children, closing,]);
+
+impl_enum!(
+    JSXElementChild,
+    [
+        JSXText,
+        JSXExprContainer,
+        JSXSpreadChild,
+        JSXElement,
+        JSXFragment,
+    ]
+);
+
+impl_struct!(JSXFragment, [span, opening, children, closing,]);
+
+impl_struct!(JSXOpeningFragment, [span,]);
+
+impl_struct!(JSXClosingFragment, [span,]);
@@ -0,0 +1,25 @@
+use super::ToCode;
+use crate::ctx::Ctx;
+use swc_ecma_ast::*;
+use syn::parse_quote;
+
+impl_enum!(Lit, [Str, Bool, Null, Num, BigInt, Regex, JSXText,]);
+
+impl_struct!(BigInt, [span, value,]);
+
+impl_struct!(Str, [span, value, has_escape, kind,]);
+
+impl_struct!(Bool, [span, value,]);
+
+impl_struct!(Null, [span,]);
+
+impl_struct!(Regex, [span, exp, flags,]);
+
+impl_struct!(Number, [span, value,]);
+
+/// Quoted strings are always printed from `value`.
+impl ToCode for StrKind {
+    fn to_code(&self, _: &Ctx) -> syn::Expr {
+        parse_quote!(swc_ecma_quote::swc_ecma_ast::StrKind::Synthesized)
+    }
+}
@@ -0,0 +1,159 @@
+use crate::ctx::Ctx;
+use num_bigint::BigInt;
+use swc_atoms::JsWord;
+use swc_common::Span;
+use syn::parse_quote;
+
+/// Generates rust code which creates the same ast node.
+///
+/// All spans are replaced with `DUMMY_SP`.
+pub(crate) trait ToCode {
+    fn to_code(&self, cx: &Ctx) -> syn::Expr;
+
+    /// Used by `Vec<T>`, so that a list can be spliced into it.
+    fn to_code_in_vec(items: &[Self], cx: &Ctx) -> syn::Expr
+    where
+        Self: Sized,
+    {
+        let items = items.iter().map(|item| item.to_code(cx));
+        parse_quote!(vec![#(#items),*])
+    }
+}
+
+macro_rules! impl_struct {
+    ($S:ident, [$($f:ident),* $(,)?]) => {
+        impl crate::ast::ToCode for $S {
+            fn to_code(&self, cx: &crate::ctx::Ctx) -> syn::Expr {
+                let fields: Vec<proc_macro2::TokenStream> = vec![$({
+                    let value = crate::ast::ToCode::to_code(&self.$f, cx);
+                    quote::quote!($f: #value)
+                }),*];
+
+                syn::parse_quote!(swc_ecma_quote::swc_ecma_ast::$S { #(#fields),* })
+            }
+        }
+    };
+}
+
+macro_rules! impl_enum_body {
+    ($E:ident, $s:expr, $cx:expr, [$($v:ident),* $(,)?]) => {
+        match $s {
+            $(
+                $E::$v(inner) => {
+                    let inner = crate::ast::ToCode::to_code(inner, $cx);
+                    syn::parse_quote!(swc_ecma_quote::swc_ecma_ast::$E::$v(#inner))
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! impl_enum {
+    ($E:ident, [$($v:ident),* $(,)?]) => {
+        impl crate::ast::ToCode for $E {
+            fn to_code(&self, cx: &crate::ctx::Ctx) -> syn::Expr {
+                impl_enum_body!($E, self, cx, [$($v),*])
+            }
+        }
+    };
+}
+
+macro_rules! impl_simple_enum {
+    ($E:ident, [$($v:ident),* $(,)?]) => {
+        impl crate::ast::ToCode for $E {
+            fn to_code(&self, _: &crate::ctx::Ctx) -> syn::Expr {
+                match self {
+                    $(
+                        $E::$v => syn::parse_quote!(swc_ecma_quote::swc_ecma_ast::$E::$v),
+                    )*
+                }
+            }
+        }
+    };
+}
+
+mod class;
+mod decl;
+mod expr;
+mod function;
+mod ident;
+mod jsx;
+mod lit;
+mod module;
+mod module_decl;
+mod operators;
+mod pat;
+mod prop;
+mod stmt;
+mod typescript;
+
+impl<T> ToCode for Box<T>
+where
+    T: ToCode,
+{
+    fn to_code(&self, cx: &Ctx) -> syn::Expr {
+        let inner = (**self).to_code(cx);
+        parse_quote!(Box::new(#inner))
+    }
+}
+
+impl<T> ToCode for Option<T>
+where
+    T: ToCode,
+{
+    fn to_code(&self, cx: &Ctx) -> syn::Expr {
+        match self {
+            Some(inner) => {
+                let inner = inner.to_code(cx);
+                parse_quote!(Some(#inner))
+            }
+            None => parse_quote!(None),
+        }
+    }
+}
+
+impl<T> ToCode for Vec<T>
+where
+    T: ToCode,
+{
+    fn to_code(&self, cx: &Ctx) -> syn::Expr {
+        T::to_code_in_vec(self, cx)
+    }
+}
+
+impl ToCode for Span {
+    fn to_code(&self, _: &Ctx) -> syn::Expr {
+        parse_quote!(swc_ecma_quote::swc_common::DUMMY_SP)
+    }
+}
+
+impl ToCode for bool {
+    fn to_code(&self, _: &Ctx) -> syn::Expr {
+        parse_quote!(#self)
+    }
+}
+
+impl ToCode for f64 {
+    fn to_code(&self, _: &Ctx) -> syn::Expr {
+        if self.is_infinite() {
+            // Literals like `1e400`
+            return parse_quote!(f64::INFINITY);
+        }
+
+        parse_quote!(#self)
+    }
+}
+
+impl ToCode for JsWord {
+    fn to_code(&self, _: &Ctx) -> syn::Expr {
+        let s = &**self;
+        parse_quote!(swc_ecma_quote::swc_atoms::JsWord::from(#s))
+    }
+}
+
+impl ToCode for BigInt {
+    fn to_code(&self, _: &Ctx) -> syn::Expr {
+        let s = self.to_string();
+        parse_quote!(#s.parse::<swc_ecma_quote::num_bigint::BigInt>().unwrap())
+    }
+}
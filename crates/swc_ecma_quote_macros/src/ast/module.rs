@@ -0,0 +1,9 @@
+use swc_ecma_ast::*;
+
+impl_enum!(Program, [Module, Script,]);
+
+impl_struct!(Module, [span, body, shebang,]);
+
+impl_struct!(Script, [span, body, shebang,]);
+
+impl_enum!(ModuleItem, [ModuleDecl, Stmt,]);
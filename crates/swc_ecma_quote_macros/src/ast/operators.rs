@@ -0,0 +1,58 @@
+use swc_ecma_ast::*;
+
+impl_simple_enum!(
+    BinaryOp,
+    [
+        EqEq,
+        NotEq,
+        EqEqEq,
+        NotEqEq,
+        Lt,
+        LtEq,
+        Gt,
+        GtEq,
+        LShift,
+        RShift,
+        ZeroFillRShift,
+        Add,
+        Sub,
+        Mul,
+        Div,
+        Mod,
+        BitOr,
+        BitXor,
+        BitAnd,
+        LogicalOr,
+        LogicalAnd,
+        In,
+        InstanceOf,
+        Exp,
+        NullishCoalescing,
+    ]
+);
+
+impl_simple_enum!(
+    AssignOp,
+    [
+        Assign,
+        AddAssign,
+        SubAssign,
+        MulAssign,
+        DivAssign,
+        ModAssign,
+        LShiftAssign,
+        RShiftAssign,
+        ZeroFillRShiftAssign,
+        BitOrAssign,
+        BitXorAssign,
+        BitAndAssign,
+        ExpAssign,
+        AndAssign,
+        OrAssign,
+        NullishAssign,
+    ]
+);
+
+impl_simple_enum!(UpdateOp, [PlusPlus, MinusMinus,]);
+
+impl_simple_enum!(UnaryOp, [Minus, Plus, Bang, Tilde, TypeOf, Void, Delete,]);
@@ -0,0 +1,35 @@
+use super::ToCode;
+use crate::ctx::{Ctx, VarKind};
+use swc_ecma_ast::*;
+use syn::parse_quote;
+
+impl_struct!(ArrayPat, [span, elems, optional, type_ann,]);
+
+impl_struct!(ObjectPat, [span, props, optional, type_ann,]);
+
+impl_struct!(AssignPat, [span, left, right, type_ann,]);
+
+impl_struct!(RestPat, [span, dot3_token, arg, type_ann,]);
+
+impl_enum!(ObjectPatProp, [KeyValue, Assign, Rest,]);
+
+impl_struct!(KeyValuePatProp, [key, value,]);
+
+impl_struct!(AssignPatProp, [span, key, value,]);
+
+impl ToCode for Pat {
+    fn to_code(&self, cx: &Ctx) -> syn::Expr {
+        if let Pat::Ident(BindingIdent { id, type_ann: None }) = self {
+            if let Some(var) = cx.var(VarKind::Pat, &id.sym) {
+                return parse_quote!(#var.clone());
+            }
+        }
+
+        impl_enum_body!(
+            Pat,
+            self,
+            cx,
+            [Ident, Array, Rest, Object, Assign, Invalid, Expr,]
+        )
+    }
+}
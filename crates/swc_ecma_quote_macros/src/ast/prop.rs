@@ -0,0 +1,17 @@
+use swc_ecma_ast::*;
+
+impl_enum!(Prop, [Shorthand, KeyValue, Assign, Getter, Setter, Method,]);
+
+impl_struct!(KeyValueProp, [key, value,]);
+
+impl_struct!(AssignProp, [key, value,]);
+
+impl_struct!(GetterProp, [span, key, type_ann, body,]);
+
+impl_struct!(SetterProp, [span, key, param, body,]);
+
+impl_struct!(MethodProp, [key, function,]);
+
+impl_enum!(PropName, [Ident, Str, Num, Computed, BigInt,]);
+
+impl_struct!(ComputedPropName, [span, expr,]);
@@ -0,0 +1,108 @@
+use super::ToCode;
+use crate::ctx::{Ctx, VarKind};
+use proc_macro2::Span;
+use swc_ecma_ast::*;
+use syn::parse_quote;
+
+impl_struct!(BlockStmt, [span, stmts,]);
+
+impl_struct!(ExprStmt, [span, expr,]);
+
+impl_struct!(EmptyStmt, [span,]);
+
+impl_struct!(DebuggerStmt, [span,]);
+
+impl_struct!(WithStmt, [span, obj, body,]);
+
+impl_struct!(ReturnStmt, [span, arg,]);
+
+impl_struct!(LabeledStmt, [span, label, body,]);
+
+impl_struct!(BreakStmt, [span, label,]);
+
+impl_struct!(ContinueStmt, [span, label,]);
+
+impl_struct!(IfStmt, [span, test, cons, alt,]);
+
+impl_struct!(SwitchStmt, [span, discriminant, cases,]);
+
+impl_struct!(ThrowStmt, [span, arg,]);
+
+impl_struct!(TryStmt, [span, block, handler, finalizer,]);
+
+impl_struct!(WhileStmt, [span, test, body,]);
+
+impl_struct!(DoWhileStmt, [span, test, body,]);
+
+impl_struct!(ForStmt, [span, init, test, update, body,]);
+
+impl_struct!(ForInStmt, [span, left, right, body,]);
+
+impl_struct!(ForOfStmt, [span, await_token, left, right, body,]);
+
+impl_struct!(SwitchCase, [span, test, cons,]);
+
+impl_struct!(CatchClause, [span, param, body,]);
+
+impl_enum!(VarDeclOrPat, [VarDecl, Pat,]);
+
+impl_enum!(VarDeclOrExpr, [VarDecl, Expr,]);
+
+impl ToCode for Stmt {
+    fn to_code(&self, cx: &Ctx) -> syn::Expr {
+        if let Some(var) = stmt_var(self, cx, VarKind::Stmt) {
+            return parse_quote!(#var.clone());
+        }
+
+        impl_enum_body!(
+            Stmt,
+            self,
+            cx,
+            [
+                Block, Empty, Debugger, With, Return, Labeled, Break, Continue, If, Switch, Throw,
+                Try, While, DoWhile, For, ForIn, ForOf, Decl, Expr,
+            ]
+        )
+    }
+
+    /// Splices `$stmts;` into the list if `stmts` is a `Vec<Stmt>` variable.
+    fn to_code_in_vec(items: &[Self], cx: &Ctx) -> syn::Expr {
+        if items
+            .iter()
+            .all(|stmt| stmt_var(stmt, cx, VarKind::Stmts).is_none())
+        {
+            let items = items.iter().map(|stmt| stmt.to_code(cx));
+            return parse_quote!(vec![#(#items),*]);
+        }
+
+        // Not visible to the variables provided by the user.
+        let list = syn::Ident::new("stmts", Span::mixed_site());
+        let stmts: Vec<syn::Stmt> = items
+            .iter()
+            .map(|stmt| match stmt_var(stmt, cx, VarKind::Stmts) {
+                Some(var) => parse_quote!(#list.extend(#var.iter().cloned());),
+                None => {
+                    let stmt = stmt.to_code(cx);
+                    parse_quote!(#list.push(#stmt);)
+                }
+            })
+            .collect();
+
+        parse_quote!({
+            let mut #list = Vec::new();
+            #(#stmts)*
+            #list
+        })
+    }
+}
+
+/// Returns the variable of kind `kind` if `stmt` is `$name;`.
+fn stmt_var<'a>(stmt: &Stmt, cx: &'a Ctx, kind: VarKind) -> Option<&'a syn::Ident> {
+    match stmt {
+        Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+            Expr::Ident(i) => cx.var(kind, &i.sym),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,262 @@
+use swc_ecma_ast::*;
+
+impl_struct!(TsTypeAnn, [span, type_ann,]);
+
+impl_struct!(TsTypeParamDecl, [span, params,]);
+
+impl_struct!(TsTypeParam, [span, name, constraint, default,]);
+
+impl_struct!(TsTypeParamInstantiation, [span, params,]);
+
+impl_struct!(
+    TsParamProp,
+    [
+        span,
+        decorators,
+        accessibility,
+        is_override,
+        readonly,
+        param,
+    ]
+);
+
+impl_enum!(TsParamPropParam, [Ident, Assign,]);
+
+impl_struct!(TsQualifiedName, [left, right,]);
+
+impl_enum!(TsEntityName, [TsQualifiedName, Ident,]);
+
+impl_enum!(
+    TsTypeElement,
+    [
+        TsCallSignatureDecl,
+        TsConstructSignatureDecl,
+        TsPropertySignature,
+        TsGetterSignature,
+        TsSetterSignature,
+        TsMethodSignature,
+        TsIndexSignature,
+    ]
+);
+
+impl_struct!(TsCallSignatureDecl, [span, params, type_ann, type_params,]);
+
+impl_struct!(
+    TsConstructSignatureDecl,
+    [span, params, type_ann, type_params,]
+);
+
+impl_struct!(
+    TsPropertySignature,
+    [
+        span,
+        readonly,
+        key,
+        computed,
+        optional,
+        init,
+        params,
+        type_ann,
+        type_params,
+    ]
+);
+
+impl_struct!(
+    TsGetterSignature,
+    [span, readonly, key, computed, optional, type_ann,]
+);
+
+impl_struct!(
+    TsSetterSignature,
+    [span, readonly, key, computed, optional, param,]
+);
+
+impl_struct!(
+    TsMethodSignature,
+    [
+        span,
+        readonly,
+        key,
+        computed,
+        optional,
+        params,
+        type_ann,
+        type_params,
+    ]
+);
+
+impl_struct!(
+    TsIndexSignature,
+    [params, type_ann, readonly, is_static, span,]
+);
+
+impl_enum!(
+    TsType,
+    [
+        TsKeywordType,
+        TsThisType,
+        TsFnOrConstructorType,
+        TsTypeRef,
+        TsTypeQuery,
+        TsTypeLit,
+        TsArrayType,
+        TsTupleType,
+        TsOptionalType,
+        TsRestType,
+        TsUnionOrIntersectionType,
+        TsConditionalType,
+        TsInferType,
+        TsParenthesizedType,
+        TsTypeOperator,
+        TsIndexedAccessType,
+        TsMappedType,
+        TsLitType,
+        TsTypePredicate,
+        TsImportType,
+    ]
+);
+
+impl_enum!(TsFnOrConstructorType, [TsFnType, TsConstructorType,]);
+
+impl_struct!(TsKeywordType, [span, kind,]);
+
+impl_simple_enum!(
+    TsKeywordTypeKind,
+    [
+        TsAnyKeyword,
+        TsUnknownKeyword,
+        TsNumberKeyword,
+        TsObjectKeyword,
+        TsBooleanKeyword,
+        TsBigIntKeyword,
+        TsStringKeyword,
+        TsSymbolKeyword,
+        TsVoidKeyword,
+        TsUndefinedKeyword,
+        TsNullKeyword,
+        TsNeverKeyword,
+        TsIntrinsicKeyword,
+    ]
+);
+
+impl_struct!(TsThisType, [span,]);
+
+impl_enum!(TsFnParam, [Ident, Array, Rest, Object,]);
+
+impl_struct!(TsFnType, [span, params, type_params, type_ann,]);
+
+impl_struct!(
+    TsConstructorType,
+    [span, params, type_params, type_ann, is_abstract,]
+);
+
+impl_struct!(TsTypeRef, [span, type_name, type_params,]);
+
+impl_struct!(TsTypePredicate, [span, asserts, param_name, type_ann,]);
+
+impl_enum!(TsThisTypeOrIdent, [TsThisType, Ident,]);
+
+impl_struct!(TsTypeQuery, [span, expr_name,]);
+
+impl_enum!(TsTypeQueryExpr, [TsEntityName, Import,]);
+
+impl_struct!(TsImportType, [span, arg, qualifier, type_args,]);
+
+impl_struct!(TsTypeLit, [span, members,]);
+
+impl_struct!(TsArrayType, [span, elem_type,]);
+
+impl_struct!(TsTupleType, [span, elem_types,]);
+
+impl_struct!(TsTupleElement, [span, label, ty,]);
+
+impl_struct!(TsOptionalType, [span, type_ann,]);
+
+impl_struct!(TsRestType, [span, type_ann,]);
+
+impl_enum!(
+    TsUnionOrIntersectionType,
+    [TsUnionType, TsIntersectionType,]
+);
+
+impl_struct!(TsUnionType, [span, types,]);
+
+impl_struct!(TsIntersectionType, [span, types,]);
+
+impl_struct!(
+    TsConditionalType,
+    [span, check_type, extends_type, true_type, false_type,]
+);
+
+impl_struct!(TsInferType, [span, type_param,]);
+
+impl_struct!(TsParenthesizedType, [span, type_ann,]);
+
+impl_struct!(TsTypeOperator, [span, op, type_ann,]);
+
+impl_simple_enum!(TsTypeOperatorOp, [KeyOf, Unique, ReadOnly,]);
+
+impl_struct!(TsIndexedAccessType, [span, readonly, obj_type, index_type,]);
+
+impl_simple_enum!(TruePlusMinus, [True, Plus, Minus,]);
+
+impl_struct!(
+    TsMappedType,
+    [span, readonly, type_param, name_type, optional, type_ann,]
+);
+
+impl_struct!(TsLitType, [span, lit,]);
+
+impl_enum!(TsLit, [Number, Str, Bool, BigInt, Tpl,]);
+
+impl_struct!(TsTplLitType, [span, types, quasis,]);
+
+impl_struct!(
+    TsInterfaceDecl,
+    [span, id, declare, type_params, extends, body,]
+);
+
+impl_struct!(TsInterfaceBody, [span, body,]);
+
+impl_struct!(TsExprWithTypeArgs, [span, expr, type_args,]);
+
+impl_struct!(TsTypeAliasDecl, [span, declare, id, type_params, type_ann,]);
+
+impl_struct!(TsEnumDecl, [span, declare, is_const, id, members,]);
+
+impl_struct!(TsEnumMember, [span, id, init,]);
+
+impl_enum!(TsEnumMemberId, [Ident, Str,]);
+
+impl_struct!(TsModuleDecl, [span, declare, global, id, body,]);
+
+impl_enum!(TsNamespaceBody, [TsModuleBlock, TsNamespaceDecl,]);
+
+impl_struct!(TsModuleBlock, [span, body,]);
+
+impl_struct!(TsNamespaceDecl, [span, declare, global, id, body,]);
+
+impl_enum!(TsModuleName, [Ident, Str,]);
+
+impl_struct!(
+    TsImportEqualsDecl,
+    [span, declare, is_export, is_type_only, id, module_ref,]
+);
+
+impl_enum!(TsModuleRef, [TsEntityName, TsExternalModuleRef,]);
+
+impl_struct!(TsExternalModuleRef, [span, expr,]);
+
+impl_struct!(TsExportAssignment, [span, expr,]);
+
+impl_struct!(TsNamespaceExportDecl, [span, id,]);
+
+impl_struct!(TsAsExpr, [span, expr, type_ann,]);
+
+impl_struct!(TsTypeAssertion, [span, expr, type_ann,]);
+
+impl_struct!(TsNonNullExpr, [span, expr,]);
+
+impl_simple_enum!(Accessibility, [Public, Protected, Private,]);
+
+impl_struct!(TsConstAssertion, [span, expr,]);
@@ -0,0 +1,130 @@
+use crate::input::QuoteVar;
+use proc_macro2::Span;
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
+use syn::Error;
+
+/// Type of a substitution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VarKind {
+    /// `Ident`
+    Ident,
+    /// `Expr`
+    Expr,
+    /// `Pat`
+    Pat,
+    /// `Stmt`
+    Stmt,
+    /// `Vec<Stmt>`
+    Stmts,
+}
+
+impl VarKind {
+    fn type_name(self) -> &'static str {
+        match self {
+            VarKind::Ident => "Ident",
+            VarKind::Expr => "Expr",
+            VarKind::Pat => "Pat",
+            VarKind::Stmt => "Stmt",
+            VarKind::Stmts => "Vec<Stmt>",
+        }
+    }
+}
+
+pub(crate) struct Ctx {
+    vars: HashMap<String, (VarKind, syn::Ident)>,
+    /// Span of the template.
+    span: Span,
+    used: RefCell<HashSet<String>>,
+    errors: RefCell<Vec<Error>>,
+}
+
+impl Ctx {
+    pub fn new(vars: &[QuoteVar], span: Span) -> Self {
+        Ctx {
+            vars: vars
+                .iter()
+                .map(|var| (var.name.to_string(), (var.kind, var.name.clone())))
+                .collect(),
+            span,
+            used: Default::default(),
+            errors: Default::default(),
+        }
+    }
+
+    /// Returns the variable to use for `$name` if `sym` is `$name` and the
+    /// type of the variable is `kind`.
+    pub fn var(&self, kind: VarKind, sym: &str) -> Option<&syn::Ident> {
+        let name = var_name(sym)?;
+
+        let (_, ident) = self.vars.get(name).filter(|(k, _)| *k == kind)?;
+        self.used.borrow_mut().insert(name.to_string());
+
+        Some(ident)
+    }
+
+    /// Reports an error if `sym` is `$name` but it's emitted as an identifier,
+    /// which means `name` is not a variable or the type of it does not match
+    /// the position.
+    pub fn check_unreplaced(&self, sym: &str) {
+        let name = match var_name(sym) {
+            Some(name) => name,
+            None => return,
+        };
+
+        let msg = match self.vars.get(name) {
+            Some((kind, _)) => {
+                // Not reported as unused.
+                self.used.borrow_mut().insert(name.to_string());
+
+                format!(
+                    "`${}` cannot be used here because the type of `{}` is `{}`",
+                    name,
+                    name,
+                    kind.type_name()
+                )
+            }
+            None => format!(
+                "`${}` is used in the template, but `{}` is not provided",
+                name, name
+            ),
+        };
+        self.errors.borrow_mut().push(Error::new(self.span, msg));
+    }
+
+    /// Returns errors reported while generating code, including variables
+    /// which are not used in the template.
+    pub fn finish(self) -> syn::Result<()> {
+        let mut errors = self.errors.into_inner();
+        let used = self.used.into_inner();
+
+        let mut unused = self
+            .vars
+            .into_iter()
+            .filter(|(name, _)| !used.contains(name))
+            .map(|(_, (_, ident))| ident)
+            .collect::<Vec<_>>();
+        unused.sort_by_key(|ident| ident.to_string());
+        errors.extend(unused.into_iter().map(|ident| {
+            Error::new(
+                ident.span(),
+                format!("`{}` is not used in the template", ident),
+            )
+        }));
+
+        errors
+            .into_iter()
+            .reduce(|mut err, other| {
+                err.combine(other);
+                err
+            })
+            .map_or(Ok(()), Err)
+    }
+}
+
+/// Returns `name` if `sym` is `$name`.
+fn var_name(sym: &str) -> Option<&str> {
+    sym.strip_prefix('$').filter(|name| !name.is_empty())
+}
@@ -0,0 +1,81 @@
+use crate::ctx::VarKind;
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, ParseStream},
+    Error, Ident, LitStr, Token, Type,
+};
+
+/// `"$a + b" as Expr, a = value, b: Expr = value`
+pub(crate) struct QuoteInput {
+    pub src: LitStr,
+    pub output_type: Option<Ident>,
+    pub vars: Vec<QuoteVar>,
+}
+
+/// `name = value` or `name: Type = value`.
+pub(crate) struct QuoteVar {
+    pub name: Ident,
+    pub kind: VarKind,
+    pub value: syn::Expr,
+}
+
+impl Parse for QuoteInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let src = input.parse()?;
+
+        let output_type = if input.peek(Token![as]) {
+            input.parse::<Token![as]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        let mut vars = vec![];
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            vars.push(input.parse()?);
+        }
+
+        Ok(QuoteInput {
+            src,
+            output_type,
+            vars,
+        })
+    }
+}
+
+impl Parse for QuoteVar {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+
+        let kind = if input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+            let ty: Type = input.parse()?;
+
+            match &*ty.to_token_stream().to_string() {
+                "Ident" => VarKind::Ident,
+                "Expr" => VarKind::Expr,
+                "Pat" => VarKind::Pat,
+                "Stmt" => VarKind::Stmt,
+                "Vec < Stmt >" => VarKind::Stmts,
+                _ => {
+                    return Err(Error::new_spanned(
+                        ty,
+                        "expected one of `Ident`, `Expr`, `Pat`, `Stmt` or `Vec<Stmt>`",
+                    ))
+                }
+            }
+        } else {
+            VarKind::Ident
+        };
+
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+
+        Ok(QuoteVar { name, kind, value })
+    }
+}
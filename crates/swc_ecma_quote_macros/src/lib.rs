@@ -0,0 +1,117 @@
+//! Macros for `swc_ecma_quote`.
+//!
+//! See the documentation of `swc_ecma_quote`.
+
+extern crate proc_macro;
+
+use crate::{
+    ctx::{Ctx, VarKind},
+    input::QuoteInput,
+    template::Template,
+};
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, Error};
+
+mod ast;
+mod ctx;
+mod input;
+mod template;
+
+/// Creates an ast node from a template.
+///
+/// The first argument is the template, followed by `as` and the type of the
+/// output, which is one of `Expr`, `Pat`, `Stmt` and `ModuleItem`.
+/// Remaining arguments are variables, like `name = value` or
+/// `name: Type = value`. See the documentation of `swc_ecma_quote` for the
+/// supported types.
+///
+/// ```ignore
+/// let e: Expr = quote!(
+///     "$obj.$prop" as Expr,
+///     obj: Expr = obj,
+///     prop = Ident::new("foo".into(), DUMMY_SP)
+/// );
+/// ```
+#[proc_macro]
+pub fn quote(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as QuoteInput);
+
+    let output_type = match &input.output_type {
+        Some(ty) => ty.to_string(),
+        None => {
+            return Error::new(
+                input.src.span(),
+                "the output type is required, like `quote!(\"foo()\" as Expr)`",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+
+    expand(&input, &output_type, false)
+        .unwrap_or_else(to_compile_error)
+        .into()
+}
+
+/// Same as `quote!(... as Expr, ...)`, but returns `Box<Expr>`.
+///
+/// ```ignore
+/// let e: Box<Expr> = quote_expr!("foo + $bar", bar: Expr = bar);
+/// ```
+#[proc_macro]
+pub fn quote_expr(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as QuoteInput);
+
+    if let Some(ty) = &input.output_type {
+        return Error::new(ty.span(), "`quote_expr!` always returns `Box<Expr>`")
+            .to_compile_error()
+            .into();
+    }
+
+    expand(&input, "Expr", true)
+        .unwrap_or_else(to_compile_error)
+        .into()
+}
+
+fn expand(
+    input: &QuoteInput,
+    output_type: &str,
+    boxed: bool,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let template = Template::parse(&input.src, output_type)?;
+
+    let cx = Ctx::new(&input.vars, input.src.span());
+    let code = template.to_code(&cx);
+    cx.finish()?;
+    let code = if boxed {
+        quote::quote!(Box::new(#code))
+    } else {
+        quote::quote!(#code)
+    };
+
+    let bindings = input.vars.iter().map(|var| {
+        let name = &var.name;
+        let value = &var.value;
+        let ty = match var.kind {
+            VarKind::Ident => quote::quote!(swc_ecma_quote::swc_ecma_ast::Ident),
+            VarKind::Expr => quote::quote!(swc_ecma_quote::swc_ecma_ast::Expr),
+            VarKind::Pat => quote::quote!(swc_ecma_quote::swc_ecma_ast::Pat),
+            VarKind::Stmt => quote::quote!(swc_ecma_quote::swc_ecma_ast::Stmt),
+            VarKind::Stmts => quote::quote!(Vec<swc_ecma_quote::swc_ecma_ast::Stmt>),
+        };
+
+        quote::quote!(let #name: #ty = #value;)
+    });
+
+    Ok(quote::quote!({
+        #(#bindings)*
+        #code
+    }))
+}
+
+/// Multiple errors are wrapped in a block, as the macros are used as
+/// expressions.
+fn to_compile_error(err: Error) -> proc_macro2::TokenStream {
+    let err = err.to_compile_error();
+    quote::quote!({ #err })
+}
@@ -0,0 +1,106 @@
+use crate::{ast::ToCode, ctx::Ctx};
+use swc_common::BytePos;
+use swc_ecma_ast::*;
+use swc_ecma_parser::{error::SyntaxError, lexer::Lexer, EsConfig, Parser, StringInput, Syntax};
+use syn::{Error, LitStr};
+
+/// Parsed template.
+pub(crate) enum Template {
+    Expr(Box<Expr>),
+    Pat(Pat),
+    Stmt(Stmt),
+    ModuleItem(ModuleItem),
+}
+
+impl Template {
+    /// Parses `src` as `output_type`.
+    pub fn parse(src: &LitStr, output_type: &str) -> syn::Result<Self> {
+        let code = src.value();
+        let err = |msg: &str| Error::new(src.span(), msg);
+
+        match output_type {
+            "Expr" => {
+                // Wrapped, so that object literals and sequences are parsed as
+                // an expression.
+                match parse_one(&format!("({})", code), false).map_err(|msg| err(&msg))? {
+                    ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match *expr {
+                        Expr::Paren(ParenExpr { expr, .. }) => Ok(Template::Expr(expr)),
+                        _ => Err(err("expected an expression")),
+                    },
+                    _ => Err(err("expected an expression")),
+                }
+            }
+            "Pat" => {
+                match parse_one(&format!("({}) => {{}}", code), false).map_err(|msg| err(&msg))? {
+                    ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match *expr {
+                        Expr::Arrow(ArrowExpr { mut params, .. }) if params.len() == 1 => {
+                            Ok(Template::Pat(params.remove(0)))
+                        }
+                        _ => Err(err("expected a pattern")),
+                    },
+                    _ => Err(err("expected a pattern")),
+                }
+            }
+            "Stmt" => match parse_one(&code, true).map_err(|msg| err(&msg))? {
+                ModuleItem::Stmt(stmt) => Ok(Template::Stmt(stmt)),
+                _ => Err(err("expected a statement")),
+            },
+            "ModuleItem" => Ok(Template::ModuleItem(
+                parse_one(&code, false).map_err(|msg| err(&msg))?,
+            )),
+            _ => Err(err(
+                "expected one of `Expr`, `Pat`, `Stmt` or `ModuleItem` as the output type"
+            )),
+        }
+    }
+
+    pub fn to_code(&self, cx: &Ctx) -> syn::Expr {
+        match self {
+            Template::Expr(e) => (**e).to_code(cx),
+            Template::Pat(p) => p.to_code(cx),
+            Template::Stmt(s) => s.to_code(cx),
+            Template::ModuleItem(m) => m.to_code(cx),
+        }
+    }
+}
+
+/// Parses `code` as a module which contains exactly one item.
+///
+/// If `allow_return` is true, return statements are allowed at the top level.
+fn parse_one(code: &str, allow_return: bool) -> Result<ModuleItem, String> {
+    let lexer = Lexer::new(
+        Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        StringInput::new(code, BytePos(0), BytePos(code.len() as _)),
+        None,
+    );
+    let mut parser = Parser::new_from(lexer);
+
+    let module = parser
+        .parse_module()
+        .map_err(|err| format!("failed to parse the template: {}", err.kind().msg()))?;
+
+    let errors = parser.take_errors();
+    if let Some(err) = errors
+        .into_iter()
+        .find(|err| !allow_return || !matches!(err.kind(), SyntaxError::ReturnNotAllowed))
+    {
+        return Err(format!(
+            "failed to parse the template: {}",
+            err.kind().msg()
+        ));
+    }
+
+    let mut body = module.body;
+    if body.len() != 1 {
+        return Err(format!(
+            "expected exactly one item in the template, but got {}",
+            body.len()
+        ));
+    }
+
+    Ok(body.remove(0))
+}
@@ -0,0 +1,516 @@
+use crate::{Binding, BindingKind, Export, Reference, ScopeId, ScopeKind, SemanticModel};
+use swc_atoms::{js_word, JsWord};
+use swc_common::Span;
+use swc_ecma_ast::*;
+use swc_ecma_utils::{find_ids, ident::IdentLike};
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+#[derive(Debug, Clone, Copy)]
+enum PatMode {
+    Decl(BindingKind),
+    Assign { is_read: bool },
+}
+
+pub(crate) struct Analyzer<'a> {
+    model: &'a mut SemanticModel,
+    scope: ScopeId,
+}
+
+impl<'a> Analyzer<'a> {
+    pub fn new(model: &'a mut SemanticModel) -> Self {
+        Analyzer {
+            model,
+            scope: ScopeId::ROOT,
+        }
+    }
+
+    fn with_scope<F>(&mut self, kind: ScopeKind, span: Span, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let scope = self.model.add_scope(kind, span, self.scope);
+
+        let old = self.scope;
+        self.scope = scope;
+        op(self);
+        self.scope = old;
+    }
+
+    fn declare(&mut self, i: &Ident, kind: BindingKind) {
+        let scope = match kind {
+            BindingKind::Var => self.model.fn_scope(self.scope),
+            _ => self.scope,
+        };
+
+        let id = i.to_id();
+        if self.model.bindings.contains_key(&id) {
+            return;
+        }
+
+        self.model.scopes[scope.0 as usize]
+            .bindings
+            .push(id.clone());
+        self.model.bindings.insert(
+            id.clone(),
+            Binding {
+                id,
+                kind,
+                span: i.span,
+                scope,
+                references: Default::default(),
+                captured_by: Default::default(),
+            },
+        );
+    }
+
+    fn add_ref(&mut self, i: &Ident, is_read: bool, is_write: bool) {
+        self.model.references.push(Reference {
+            id: i.to_id(),
+            span: i.span,
+            scope: self.scope,
+            is_read,
+            is_write,
+        });
+    }
+
+    fn add_export(&mut self, name: JsWord, local: Option<&Ident>, span: Span) {
+        // Exports in typescript namespaces are not exports of the module.
+        if self.scope != ScopeId::ROOT {
+            return;
+        }
+
+        self.model.exports.push(Export {
+            name,
+            local: local.map(|i| i.to_id()),
+            span,
+        });
+    }
+
+    fn visit_pat_with_mode(&mut self, pat: &Pat, mode: PatMode) {
+        match pat {
+            Pat::Ident(i) => self.visit_pat_ident(&i.id, mode),
+            Pat::Array(a) => {
+                for elem in a.elems.iter().flatten() {
+                    self.visit_pat_with_mode(elem, mode);
+                }
+            }
+            Pat::Rest(r) => self.visit_pat_with_mode(&r.arg, mode),
+            Pat::Object(o) => {
+                for prop in &o.props {
+                    match prop {
+                        ObjectPatProp::KeyValue(p) => {
+                            p.key.visit_with(self);
+                            self.visit_pat_with_mode(&p.value, mode);
+                        }
+                        ObjectPatProp::Assign(p) => {
+                            self.visit_pat_ident(&p.key, mode);
+                            p.value.visit_with(self);
+                        }
+                        ObjectPatProp::Rest(r) => self.visit_pat_with_mode(&r.arg, mode),
+                    }
+                }
+            }
+            Pat::Assign(a) => {
+                self.visit_pat_with_mode(&a.left, mode);
+                a.right.visit_with(self);
+            }
+            Pat::Expr(e) => self.visit_assign_target(e, mode),
+            Pat::Invalid(..) => {}
+        }
+    }
+
+    fn visit_pat_ident(&mut self, i: &Ident, mode: PatMode) {
+        match mode {
+            PatMode::Decl(kind) => self.declare(i, kind),
+            PatMode::Assign { is_read } => self.add_ref(i, is_read, true),
+        }
+    }
+
+    fn visit_assign_target(&mut self, e: &Expr, mode: PatMode) {
+        match e {
+            Expr::Ident(i) => self.visit_pat_ident(i, mode),
+            _ => e.visit_with(self),
+        }
+    }
+
+    /// `name` is the name of a function expression, which is only visible from
+    /// the function itself.
+    fn visit_fn(&mut self, name: Option<&Ident>, f: &Function) {
+        f.decorators.visit_with(self);
+
+        self.with_scope(ScopeKind::Fn, f.span, |a| {
+            if let Some(name) = name {
+                a.declare(name, BindingKind::Function);
+            }
+
+            for param in &f.params {
+                param.decorators.visit_with(a);
+                a.visit_pat_with_mode(&param.pat, PatMode::Decl(BindingKind::Param));
+            }
+
+            if let Some(body) = &f.body {
+                body.stmts.visit_with(a);
+            }
+        });
+    }
+
+    /// `name` is the name of a class expression, which is only visible from
+    /// the class itself.
+    fn visit_class_with_name(&mut self, name: Option<&Ident>, c: &Class) {
+        c.decorators.visit_with(self);
+        c.super_class.visit_with(self);
+
+        self.with_scope(ScopeKind::Class, c.span, |a| {
+            if let Some(name) = name {
+                a.declare(name, BindingKind::Class);
+            }
+
+            c.body.visit_with(a);
+        });
+    }
+}
+
+impl Visit for Analyzer<'_> {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, f: &ArrowExpr) {
+        self.with_scope(ScopeKind::Fn, f.span, |a| {
+            for param in &f.params {
+                a.visit_pat_with_mode(param, PatMode::Decl(BindingKind::Param));
+            }
+
+            match &f.body {
+                BlockStmtOrExpr::BlockStmt(body) => body.stmts.visit_with(a),
+                BlockStmtOrExpr::Expr(body) => body.visit_with(a),
+            }
+        });
+    }
+
+    fn visit_assign_expr(&mut self, e: &AssignExpr) {
+        let mode = PatMode::Assign {
+            is_read: e.op != op!("="),
+        };
+
+        match &e.left {
+            PatOrExpr::Pat(p) => self.visit_pat_with_mode(p, mode),
+            PatOrExpr::Expr(l) => self.visit_assign_target(l, mode),
+        }
+
+        e.right.visit_with(self);
+    }
+
+    fn visit_block_stmt(&mut self, s: &BlockStmt) {
+        self.with_scope(ScopeKind::Block, s.span, |a| s.stmts.visit_with(a));
+    }
+
+    fn visit_catch_clause(&mut self, c: &CatchClause) {
+        self.with_scope(ScopeKind::Catch, c.span, |a| {
+            if let Some(param) = &c.param {
+                a.visit_pat_with_mode(param, PatMode::Decl(BindingKind::CatchParam));
+            }
+
+            c.body.stmts.visit_with(a);
+        });
+    }
+
+    fn visit_class(&mut self, c: &Class) {
+        self.visit_class_with_name(None, c);
+    }
+
+    fn visit_class_decl(&mut self, c: &ClassDecl) {
+        self.declare(&c.ident, BindingKind::Class);
+
+        self.visit_class_with_name(None, &c.class);
+    }
+
+    fn visit_class_expr(&mut self, c: &ClassExpr) {
+        self.visit_class_with_name(c.ident.as_ref(), &c.class);
+    }
+
+    fn visit_constructor(&mut self, c: &Constructor) {
+        c.key.visit_with(self);
+
+        self.with_scope(ScopeKind::Fn, c.span, |a| {
+            for param in &c.params {
+                match param {
+                    ParamOrTsParamProp::Param(param) => {
+                        param.decorators.visit_with(a);
+                        a.visit_pat_with_mode(&param.pat, PatMode::Decl(BindingKind::Param));
+                    }
+                    ParamOrTsParamProp::TsParamProp(param) => {
+                        param.decorators.visit_with(a);
+                        match &param.param {
+                            TsParamPropParam::Ident(i) => a.declare(&i.id, BindingKind::Param),
+                            TsParamPropParam::Assign(p) => {
+                                a.visit_pat_with_mode(&p.left, PatMode::Decl(BindingKind::Param));
+                                p.right.visit_with(a);
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(body) = &c.body {
+                body.stmts.visit_with(a);
+            }
+        });
+    }
+
+    fn visit_export_decl(&mut self, e: &ExportDecl) {
+        let ids: Vec<Ident> = match &e.decl {
+            Decl::Class(c) => vec![c.ident.clone()],
+            Decl::Fn(f) => vec![f.ident.clone()],
+            Decl::Var(v) => find_ids(&v.decls),
+            Decl::TsEnum(e) => vec![e.id.clone()],
+            Decl::TsModule(TsModuleDecl {
+                id: TsModuleName::Ident(i),
+                ..
+            }) => vec![i.clone()],
+            _ => vec![],
+        };
+        for i in &ids {
+            self.add_export(i.sym.clone(), Some(i), e.span);
+        }
+
+        e.decl.visit_with(self);
+    }
+
+    fn visit_export_default_decl(&mut self, e: &ExportDefaultDecl) {
+        let local = match &e.decl {
+            DefaultDecl::Class(c) => c.ident.as_ref(),
+            DefaultDecl::Fn(f) => f.ident.as_ref(),
+            DefaultDecl::TsInterfaceDecl(..) => return,
+        };
+        self.add_export(js_word!("default"), local, e.span);
+
+        // The name of a default export is visible from the module.
+        match &e.decl {
+            DefaultDecl::Class(c) => {
+                if let Some(i) = &c.ident {
+                    self.declare(i, BindingKind::Class);
+                }
+                self.visit_class_with_name(None, &c.class);
+            }
+            DefaultDecl::Fn(f) => {
+                if let Some(i) = &f.ident {
+                    self.declare(i, BindingKind::Function);
+                }
+                self.visit_fn(None, &f.function);
+            }
+            DefaultDecl::TsInterfaceDecl(..) => {}
+        }
+    }
+
+    fn visit_export_default_expr(&mut self, e: &ExportDefaultExpr) {
+        let local = match &*e.expr {
+            Expr::Ident(i) => Some(i),
+            _ => None,
+        };
+        self.add_export(js_word!("default"), local, e.span);
+
+        e.expr.visit_with(self);
+    }
+
+    fn visit_expr(&mut self, e: &Expr) {
+        match e {
+            Expr::Ident(i) => self.add_ref(i, true, false),
+            _ => e.visit_children_with(self),
+        }
+    }
+
+    fn visit_fn_decl(&mut self, f: &FnDecl) {
+        self.declare(&f.ident, BindingKind::Function);
+
+        self.visit_fn(None, &f.function);
+    }
+
+    fn visit_fn_expr(&mut self, f: &FnExpr) {
+        self.visit_fn(f.ident.as_ref(), &f.function);
+    }
+
+    fn visit_for_in_stmt(&mut self, s: &ForInStmt) {
+        self.with_scope(ScopeKind::Block, s.span, |a| {
+            match &s.left {
+                VarDeclOrPat::VarDecl(v) => v.visit_with(a),
+                VarDeclOrPat::Pat(p) => {
+                    a.visit_pat_with_mode(p, PatMode::Assign { is_read: false })
+                }
+            }
+
+            s.right.visit_with(a);
+            s.body.visit_with(a);
+        });
+    }
+
+    fn visit_for_of_stmt(&mut self, s: &ForOfStmt) {
+        self.with_scope(ScopeKind::Block, s.span, |a| {
+            match &s.left {
+                VarDeclOrPat::VarDecl(v) => v.visit_with(a),
+                VarDeclOrPat::Pat(p) => {
+                    a.visit_pat_with_mode(p, PatMode::Assign { is_read: false })
+                }
+            }
+
+            s.right.visit_with(a);
+            s.body.visit_with(a);
+        });
+    }
+
+    fn visit_for_stmt(&mut self, s: &ForStmt) {
+        self.with_scope(ScopeKind::Block, s.span, |a| s.visit_children_with(a));
+    }
+
+    fn visit_function(&mut self, f: &Function) {
+        self.visit_fn(None, f);
+    }
+
+    fn visit_getter_prop(&mut self, p: &GetterProp) {
+        p.key.visit_with(self);
+
+        self.with_scope(ScopeKind::Fn, p.span, |a| {
+            if let Some(body) = &p.body {
+                body.stmts.visit_with(a);
+            }
+        });
+    }
+
+    fn visit_import_decl(&mut self, i: &ImportDecl) {
+        // `import type` does not create a binding.
+        if i.type_only {
+            return;
+        }
+
+        for s in &i.specifiers {
+            let local = match s {
+                ImportSpecifier::Named(s) if s.is_type_only => continue,
+                ImportSpecifier::Named(s) => &s.local,
+                ImportSpecifier::Default(s) => &s.local,
+                ImportSpecifier::Namespace(s) => &s.local,
+            };
+            self.declare(local, BindingKind::Import);
+        }
+    }
+
+    fn visit_jsx_element_name(&mut self, n: &JSXElementName) {
+        match n {
+            // Lowercase names are intrinsic elements.
+            JSXElementName::Ident(i) => {
+                if !i.sym.starts_with(|c: char| c.is_ascii_lowercase()) {
+                    self.add_ref(i, true, false);
+                }
+            }
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_jsx_object(&mut self, n: &JSXObject) {
+        match n {
+            JSXObject::Ident(i) => self.add_ref(i, true, false),
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_named_export(&mut self, e: &NamedExport) {
+        if e.type_only {
+            return;
+        }
+
+        for s in &e.specifiers {
+            match s {
+                ExportSpecifier::Named(s) if s.is_type_only => {}
+                ExportSpecifier::Named(s) => {
+                    let exported = s.exported.as_ref().unwrap_or(&s.orig);
+                    let name = match exported {
+                        ModuleExportName::Ident(i) => i.sym.clone(),
+                        ModuleExportName::Str(s) => s.value.clone(),
+                    };
+
+                    match (&e.src, &s.orig) {
+                        (None, ModuleExportName::Ident(orig)) => {
+                            self.add_ref(orig, true, false);
+                            self.add_export(name, Some(orig), s.span);
+                        }
+                        _ => self.add_export(name, None, s.span),
+                    }
+                }
+                ExportSpecifier::Namespace(s) => {
+                    let name = match &s.name {
+                        ModuleExportName::Ident(i) => i.sym.clone(),
+                        ModuleExportName::Str(s) => s.value.clone(),
+                    };
+                    self.add_export(name, None, s.span);
+                }
+                ExportSpecifier::Default(s) => {
+                    self.add_export(s.exported.sym.clone(), None, s.exported.span);
+                }
+            }
+        }
+    }
+
+    fn visit_prop(&mut self, p: &Prop) {
+        match p {
+            Prop::Shorthand(i) => self.add_ref(i, true, false),
+            _ => p.visit_children_with(self),
+        }
+    }
+
+    fn visit_setter_prop(&mut self, p: &SetterProp) {
+        p.key.visit_with(self);
+
+        self.with_scope(ScopeKind::Fn, p.span, |a| {
+            a.visit_pat_with_mode(&p.param, PatMode::Decl(BindingKind::Param));
+
+            if let Some(body) = &p.body {
+                body.stmts.visit_with(a);
+            }
+        });
+    }
+
+    fn visit_switch_stmt(&mut self, s: &SwitchStmt) {
+        s.discriminant.visit_with(self);
+
+        self.with_scope(ScopeKind::Block, s.span, |a| s.cases.visit_with(a));
+    }
+
+    fn visit_ts_enum_decl(&mut self, e: &TsEnumDecl) {
+        self.declare(&e.id, BindingKind::Enum);
+
+        e.members.visit_with(self);
+    }
+
+    fn visit_ts_import_equals_decl(&mut self, i: &TsImportEqualsDecl) {
+        if i.is_type_only {
+            return;
+        }
+
+        self.declare(&i.id, BindingKind::Import);
+    }
+
+    fn visit_ts_module_decl(&mut self, m: &TsModuleDecl) {
+        if let TsModuleName::Ident(i) = &m.id {
+            self.declare(i, BindingKind::Namespace);
+        }
+
+        self.with_scope(ScopeKind::Block, m.span, |a| m.body.visit_with(a));
+    }
+
+    fn visit_update_expr(&mut self, e: &UpdateExpr) {
+        match &*e.arg {
+            Expr::Ident(i) => self.add_ref(i, true, true),
+            _ => e.arg.visit_with(self),
+        }
+    }
+
+    fn visit_var_decl(&mut self, v: &VarDecl) {
+        let kind = match v.kind {
+            VarDeclKind::Var => BindingKind::Var,
+            VarDeclKind::Let => BindingKind::Let,
+            VarDeclKind::Const => BindingKind::Const,
+        };
+
+        for decl in &v.decls {
+            self.visit_pat_with_mode(&decl.name, PatMode::Decl(kind));
+            decl.init.visit_with(self);
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! Semantic model of ECMAScript programs.
+//!
+//! [analyze] builds a [SemanticModel] from a program which is already
+//! processed by the `resolver` pass. The model contains
+//!
+//!  - the tree of scopes ([Scope]),
+//!  - declarations ([Binding]),
+//!  - references to bindings ([Reference]),
+//!  - closures capturing each binding ([Binding::captured_by]),
+//!  - exported names ([Export]).
+//!
+//! As the program is resolved, a binding is identified by its [Id].
+//! Type-only declarations of typescript, including `import type` and type-only
+//! import and export specifiers, are ignored.
+
+use swc_atoms::JsWord;
+use swc_common::{collections::AHashMap, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::VisitWith;
+
+mod analyzer;
+
+/// Builds the semantic model of `program`.
+///
+/// `program` should be processed by the `resolver` pass.
+pub fn analyze(program: &Program) -> SemanticModel {
+    let mut model = SemanticModel {
+        scopes: vec![Scope {
+            kind: ScopeKind::Top,
+            span: program.span(),
+            parent: None,
+            children: Default::default(),
+            bindings: Default::default(),
+        }],
+        bindings: Default::default(),
+        references: Default::default(),
+        exports: Default::default(),
+    };
+
+    program.visit_with(&mut analyzer::Analyzer::new(&mut model));
+    model.link_references();
+
+    model
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScopeId(u32);
+
+impl ScopeId {
+    /// The scope of the program.
+    pub const ROOT: ScopeId = ScopeId(0);
+
+    /// Index of the scope in [SemanticModel::scopes].
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ReferenceId(u32);
+
+impl ReferenceId {
+    /// Index of the reference in [SemanticModel::references].
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScopeKind {
+    /// Script or module.
+    Top,
+    /// Functions, arrow functions, methods and constructors.
+    Fn,
+    /// Blocks, `for` statements, `switch` statements and typescript
+    /// namespaces.
+    Block,
+    Catch,
+    Class,
+}
+
+#[derive(Debug, Clone)]
+pub struct Scope {
+    pub kind: ScopeKind,
+    pub span: Span,
+    /// [None] only for [ScopeId::ROOT].
+    pub parent: Option<ScopeId>,
+    pub children: Vec<ScopeId>,
+    /// Bindings declared in this scope.
+    ///
+    /// `var` declarations are stored in the nearest function scope.
+    pub bindings: Vec<Id>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BindingKind {
+    Var,
+    Let,
+    Const,
+    Function,
+    Class,
+    Param,
+    CatchParam,
+    Import,
+    /// Typescript enums.
+    Enum,
+    /// Typescript namespaces.
+    Namespace,
+}
+
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub id: Id,
+    pub kind: BindingKind,
+    /// Span of the identifier in the (first) declaration.
+    pub span: Span,
+    pub scope: ScopeId,
+    pub references: Vec<ReferenceId>,
+    /// Function scopes which reference this binding while it's declared
+    /// outside of them.
+    pub captured_by: Vec<ScopeId>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Reference {
+    pub id: Id,
+    pub span: Span,
+    /// Scope containing the reference.
+    pub scope: ScopeId,
+    pub is_read: bool,
+    pub is_write: bool,
+}
+
+/// An exported name of a module.
+#[derive(Debug, Clone)]
+pub struct Export {
+    /// The name visible to other modules. `default` for default exports.
+    pub name: JsWord,
+    /// The exported binding, if it's declared in this module.
+    pub local: Option<Id>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct SemanticModel {
+    scopes: Vec<Scope>,
+    bindings: AHashMap<Id, Binding>,
+    references: Vec<Reference>,
+    exports: Vec<Export>,
+}
+
+impl SemanticModel {
+    pub fn scope(&self, id: ScopeId) -> &Scope {
+        &self.scopes[id.0 as usize]
+    }
+
+    pub fn scopes(&self) -> impl '_ + Iterator<Item = (ScopeId, &Scope)> {
+        self.scopes
+            .iter()
+            .enumerate()
+            .map(|(idx, scope)| (ScopeId(idx as _), scope))
+    }
+
+    /// Returns `scope` and its ancestors, from the innermost one.
+    pub fn ancestors(&self, scope: ScopeId) -> impl '_ + Iterator<Item = ScopeId> {
+        let mut next = Some(scope);
+
+        std::iter::from_fn(move || {
+            let cur = next?;
+            next = self.scope(cur).parent;
+            Some(cur)
+        })
+    }
+
+    /// Returns the nearest function (or top-level) scope containing `scope`.
+    pub fn fn_scope(&self, scope: ScopeId) -> ScopeId {
+        self.ancestors(scope)
+            .find(|&s| matches!(self.scope(s).kind, ScopeKind::Fn | ScopeKind::Top))
+            .unwrap_or(ScopeId::ROOT)
+    }
+
+    pub fn binding(&self, id: &Id) -> Option<&Binding> {
+        self.bindings.get(id)
+    }
+
+    pub fn bindings(&self) -> impl '_ + Iterator<Item = &Binding> {
+        self.bindings.values()
+    }
+
+    pub fn reference(&self, id: ReferenceId) -> &Reference {
+        &self.references[id.0 as usize]
+    }
+
+    /// All references, in the order of appearance.
+    pub fn references(&self) -> &[Reference] {
+        &self.references
+    }
+
+    /// References to `id`, in the order of appearance.
+    pub fn references_of<'a>(&'a self, id: &Id) -> impl 'a + Iterator<Item = &'a Reference> {
+        self.bindings
+            .get(id)
+            .into_iter()
+            .flat_map(move |b| b.references.iter().map(move |&r| self.reference(r)))
+    }
+
+    /// Returns `true` if `id` is declared and assigned after the declaration.
+    pub fn is_reassigned(&self, id: &Id) -> bool {
+        self.references_of(id).any(|r| r.is_write)
+    }
+
+    /// References to undeclared variables, like globals.
+    pub fn unresolved_references(&self) -> impl '_ + Iterator<Item = &Reference> {
+        self.references
+            .iter()
+            .filter(move |r| !self.bindings.contains_key(&r.id))
+    }
+
+    pub fn exports(&self) -> &[Export] {
+        &self.exports
+    }
+
+    pub fn is_exported(&self, id: &Id) -> bool {
+        self.exports.iter().any(|e| e.local.as_ref() == Some(id))
+    }
+
+    fn add_scope(&mut self, kind: ScopeKind, span: Span, parent: ScopeId) -> ScopeId {
+        let id = ScopeId(self.scopes.len() as _);
+        self.scopes.push(Scope {
+            kind,
+            span,
+            parent: Some(parent),
+            children: Default::default(),
+            bindings: Default::default(),
+        });
+        self.scopes[parent.0 as usize].children.push(id);
+        id
+    }
+
+    /// Fills [Binding::references] and [Binding::captured_by].
+    fn link_references(&mut self) {
+        for (idx, r) in self.references.iter().enumerate() {
+            let binding = match self.bindings.get_mut(&r.id) {
+                Some(v) => v,
+                None => continue,
+            };
+            binding.references.push(ReferenceId(idx as _));
+
+            let mut scope = r.scope;
+            while scope != binding.scope {
+                let data = &self.scopes[scope.0 as usize];
+                if data.kind == ScopeKind::Fn && !binding.captured_by.contains(&scope) {
+                    binding.captured_by.push(scope);
+                }
+
+                scope = match data.parent {
+                    Some(v) => v,
+                    None => break,
+                };
+            }
+        }
+    }
+}
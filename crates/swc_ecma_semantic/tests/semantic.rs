@@ -0,0 +1,184 @@
+use swc_common::{FileName, Mark};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+use swc_ecma_semantic::{analyze, Binding, BindingKind, ScopeId, ScopeKind, SemanticModel};
+use swc_ecma_transforms_base::resolver::{resolver, ts_resolver};
+use swc_ecma_visit::VisitMutWith;
+
+fn run(src: &str, op: impl FnOnce(&SemanticModel)) {
+    run_with_syntax(Syntax::Es(Default::default()), src, op)
+}
+
+fn run_with_syntax(syntax: Syntax, src: &str, op: impl FnOnce(&SemanticModel)) {
+    testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+
+        let lexer = Lexer::new(syntax, EsVersion::latest(), StringInput::from(&*fm), None);
+        let mut parser = Parser::new_from(lexer);
+
+        let mut program = Program::Module(parser.parse_module().unwrap());
+        if syntax.typescript() {
+            program.visit_mut_with(&mut ts_resolver(Mark::fresh(Mark::root())));
+        } else {
+            program.visit_mut_with(&mut resolver());
+        }
+
+        op(&analyze(&program));
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+fn binding<'a>(model: &'a SemanticModel, sym: &str) -> &'a Binding {
+    let mut bindings = model.bindings().filter(|b| &*b.id.0 == sym);
+    let b = bindings.next().unwrap();
+    assert!(
+        bindings.next().is_none(),
+        "multiple bindings named `{}`",
+        sym
+    );
+    b
+}
+
+#[test]
+fn bindings() {
+    run(
+        "import x from 'x'; var a; let b; function f(c) { var d; { let e; } } try {} catch (g) {}",
+        |model| {
+            assert_eq!(binding(model, "x").kind, BindingKind::Import);
+            assert_eq!(binding(model, "a").kind, BindingKind::Var);
+            assert_eq!(binding(model, "a").scope, ScopeId::ROOT);
+            assert_eq!(binding(model, "b").kind, BindingKind::Let);
+            assert_eq!(binding(model, "f").kind, BindingKind::Function);
+            assert_eq!(binding(model, "f").scope, ScopeId::ROOT);
+
+            let c = binding(model, "c");
+            assert_eq!(c.kind, BindingKind::Param);
+            assert_eq!(model.scope(c.scope).kind, ScopeKind::Fn);
+
+            let d = binding(model, "d");
+            assert_eq!(d.scope, c.scope);
+
+            let e = binding(model, "e");
+            assert_eq!(model.scope(e.scope).kind, ScopeKind::Block);
+            assert_eq!(model.scope(e.scope).parent, Some(c.scope));
+
+            let g = binding(model, "g");
+            assert_eq!(g.kind, BindingKind::CatchParam);
+            assert_eq!(model.scope(g.scope).kind, ScopeKind::Catch);
+        },
+    );
+}
+
+#[test]
+fn references() {
+    run(
+        "let a = 1; a = 2; a += 1; a++; [a] = [3]; foo(a, { a });",
+        |model| {
+            let a = binding(model, "a");
+
+            let flags = model
+                .references_of(&a.id)
+                .map(|r| (r.is_read, r.is_write))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                flags,
+                vec![
+                    (false, true),
+                    (true, true),
+                    (true, true),
+                    (false, true),
+                    (true, false),
+                    (true, false),
+                ]
+            );
+            assert!(model.is_reassigned(&a.id));
+
+            let unresolved = model
+                .unresolved_references()
+                .map(|r| &*r.id.0)
+                .collect::<Vec<_>>();
+            assert_eq!(unresolved, vec!["foo"]);
+        },
+    );
+}
+
+#[test]
+fn non_references() {
+    run(
+        "const a = 1; a.b; ({ b: 1 }); label: for (;;) { break label; }",
+        |model| {
+            let a = binding(model, "a");
+
+            assert_eq!(a.references.len(), 1);
+            assert!(!model.is_reassigned(&a.id));
+            assert_eq!(model.unresolved_references().count(), 0);
+        },
+    );
+}
+
+#[test]
+fn captured() {
+    run(
+        "let a, b; function f() { let c; return () => a + c; } b;",
+        |model| {
+            let a = binding(model, "a");
+            let f = model.scope(binding(model, "c").scope);
+            assert_eq!(f.kind, ScopeKind::Fn);
+
+            assert_eq!(a.captured_by.len(), 2);
+            assert!(a.captured_by.contains(&binding(model, "c").scope));
+
+            assert_eq!(binding(model, "b").captured_by, vec![]);
+            assert_eq!(binding(model, "c").captured_by.len(), 1);
+        },
+    );
+}
+
+#[test]
+fn exports() {
+    run(
+        "export const a = 1, { b } = {}; function c() {} export { c as d }; export default class \
+         E {} export { x } from 'x';",
+        |model| {
+            let names = model
+                .exports()
+                .iter()
+                .map(|e| (&*e.name, e.local.as_ref().map(|id| &*id.0)))
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                names,
+                vec![
+                    ("a", Some("a")),
+                    ("b", Some("b")),
+                    ("d", Some("c")),
+                    ("default", Some("E")),
+                    ("x", None),
+                ]
+            );
+
+            assert!(model.is_exported(&binding(model, "c").id));
+            assert_eq!(binding(model, "E").kind, BindingKind::Class);
+        },
+    );
+}
+
+#[test]
+fn type_only_imports() {
+    run_with_syntax(
+        Syntax::Typescript(Default::default()),
+        "import type A from 'a';
+        import { type B, C } from 'b';
+        import type D = require('d');
+        export type { C };
+        export { type C as E };",
+        |model| {
+            let names = model.bindings().map(|b| &*b.id.0).collect::<Vec<_>>();
+            assert_eq!(names, vec!["C"]);
+
+            assert!(model.exports().is_empty());
+        },
+    );
+}
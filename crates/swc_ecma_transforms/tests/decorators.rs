@@ -5134,6 +5134,231 @@ test!(
     ok_if_code_eq
 );
 
+test!(
+    ts(),
+    |_| decorators(Config {
+        legacy: true,
+        emit_metadata: true,
+    }),
+    legacy_metadata_enums_and_type_params,
+    r#"
+import type { Options } from "./options";
+import { type Logger } from "./logger";
+
+enum Kind { A, B }
+enum Name { A = "a", B = "b" }
+
+class Service<T> {
+  @Inject()
+  method<U>(kind: Kind, name: Name, value: T, other: U, options: Options, logger: Logger): Kind {}
+}
+"#,
+    r#"
+var _class, _dec, _dec1, _dec2;
+import type { Options } from "./options";
+import { type Logger } from "./logger";
+enum Kind { A, B }
+enum Name { A = "a", B = "b" }
+let Service = ((_class = class Service<T> {
+    method<U>(kind: Kind, name: Name, value: T, other: U, options: Options, logger: Logger): Kind {
+    }
+}) || _class, _dec = Inject(), _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    Number,
+    String,
+    Object,
+    Object,
+    Object,
+    Object
+]), _applyDecoratedDescriptor(_class.prototype, "method", [
+    _dec,
+    _dec1,
+    _dec2
+], Object.getOwnPropertyDescriptor(_class.prototype, "method"), _class.prototype), _class);
+"#,
+    ok_if_code_eq
+);
+
+test!(
+    ts(),
+    |_| decorators(Config {
+        legacy: true,
+        emit_metadata: true,
+    }),
+    legacy_metadata_accessors,
+    r#"
+class Foo {
+  @Dec()
+  get value(): number {
+    return 1;
+  }
+
+  @Dec()
+  set name(v: string) {}
+}
+"#,
+    r#"
+var _class, _dec, _dec1, _dec2, _dec3, _dec4, _dec5;
+let Foo = ((_class = class Foo {
+    get value(): number {
+        return 1;
+    }
+    set name(v: string) {
+    }
+}) || _class, _dec = Dec(), _dec1 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec2 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", []), _applyDecoratedDescriptor(_class.prototype, "value", [
+    _dec,
+    _dec1,
+    _dec2
+], Object.getOwnPropertyDescriptor(_class.prototype, "value"), _class.prototype), _dec3 = Dec(), _dec4 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function), _dec5 = typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    String
+]), _applyDecoratedDescriptor(_class.prototype, "name", [
+    _dec3,
+    _dec4,
+    _dec5
+], Object.getOwnPropertyDescriptor(_class.prototype, "name"), _class.prototype), _class);
+"#,
+    ok_if_code_eq
+);
+
+test!(
+    ts(),
+    |_| simple_strip(Config {
+        legacy: false,
+        emit_metadata: true,
+    }),
+    metadata_without_legacy,
+    r#"
+import { Service } from "./service";
+import type { Options } from "./options";
+
+@Injectable()
+class Foo {
+  constructor(service: Service, options: Options) {}
+
+  @Get()
+  async find(id: string, [key]: [string]) {}
+
+  @Inject()
+  static service: Service;
+
+  @Prop()
+  [key()]: number;
+
+  @Accessor()
+  get value(): string {
+    return "";
+  }
+}
+
+@Injectable()
+export default class {
+  constructor(foo: Foo) {}
+}
+
+const Bar = @Injectable() class {
+  constructor(foo: Foo) {}
+};
+"#,
+    r#"
+var _key, _class;
+import { Service } from "./service";
+let Foo = _decorate([
+    Injectable()
+], function(_initialize) {
+    class Foo1 {
+        constructor(service, options){
+            _initialize(this);
+        }
+    }
+    return {
+        F: Foo1,
+        d: [
+            {
+                kind: "method",
+                decorators: [
+                    Get()
+                ],
+                key: "find",
+                value: async function find(id, [key]) {}
+            },
+            {
+                kind: "field",
+                static: true,
+                decorators: [
+                    Inject()
+                ],
+                key: "service",
+                value: void 0
+            },
+            {
+                kind: "field",
+                decorators: [
+                    Prop()
+                ],
+                key: _key = key(),
+                value: void 0
+            },
+            {
+                kind: "get",
+                decorators: [
+                    Accessor()
+                ],
+                key: "value",
+                value: function value() {
+                    return "";
+                }
+            }
+        ]
+    };
+});
+typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    typeof Service === "undefined" ? Object : Service,
+    Object
+])(Foo);
+typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Function)(Foo.prototype, "find");
+typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    String,
+    Array
+])(Foo.prototype, "find");
+typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:returntype", Promise)(Foo.prototype, "find");
+typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", typeof Service === "undefined" ? Object : Service)(Foo, "service");
+typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", Number)(Foo.prototype, _key);
+typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:type", String)(Foo.prototype, "value");
+let _default = _decorate([
+    Injectable()
+], function(_initialize) {
+    class _default {
+        constructor(foo){
+            _initialize(this);
+        }
+    }
+    return {
+        F: _default,
+        d: []
+    };
+});
+export { _default as default };
+typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    typeof Foo === "undefined" ? Object : Foo
+])(_default);
+const Bar = (_class = _decorate([
+    Injectable()
+], function(_initialize) {
+    class _class1 {
+        constructor(foo){
+            _initialize(this);
+        }
+    }
+    return {
+        F: _class1,
+        d: []
+    };
+}), typeof Reflect !== "undefined" && typeof Reflect.metadata === "function" && Reflect.metadata("design:paramtypes", [
+    typeof Foo === "undefined" ? Object : Foo
+])(_class), _class);
+"#,
+    ok_if_code_eq
+);
+
 // decorators_legacy_interop_local_define_property
 test!(
     // See: https://github.com/swc-project/swc/issues/421
@@ -799,6 +799,9 @@ impl Fixer<'_> {
             // It's important for arrow pass to work properly.
             Expr::Object(..) | Expr::Class(..) | Expr::Fn(..) => self.wrap(expr),
 
+            // `do {}` at the start of a statement is a do-while statement.
+            Expr::Do(..) => self.wrap(expr),
+
             // ({ a } = foo)
             Expr::Assign(AssignExpr {
                 left: PatOrExpr::Pat(left),
@@ -131,6 +131,32 @@ macro_rules! define_helpers {
                     }
                 )*
             }
+
+            /// Returns names of used helpers, which can be passed to
+            /// [Helpers::enable_by_name].
+            pub fn used_names(&self) -> Vec<&'static str> {
+                let mut buf = vec![];
+                $(
+                    if self.inner.$name.load(Ordering::SeqCst) {
+                        buf.push(stringify!($name));
+                    }
+                )*
+                buf
+            }
+
+            /// Marks the helper named `name` as used.
+            ///
+            /// Returns `false` if there's no such helper.
+            pub fn enable_by_name(&self, name: &str) -> bool {
+                match name {
+                    $(
+                        stringify!($name) => self.$name(),
+                    )*
+                    _ => return false,
+                }
+
+                true
+            }
         }
 
         impl InjectHelpers {
@@ -1,5 +1,6 @@
 import { Nullable } from 'nullable';
 import { Component } from 'react';
-class Foo extends Component {
+class Foo extends Component<Nullable, {
+}> {
 }
 new Foo();
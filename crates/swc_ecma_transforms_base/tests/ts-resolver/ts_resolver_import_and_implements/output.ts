@@ -1,5 +1,5 @@
 import { Nullable } from 'nullable';
 import { Component } from 'react';
-class Foo {
+class Foo implements Component<Nullable> {
 }
 new Foo();
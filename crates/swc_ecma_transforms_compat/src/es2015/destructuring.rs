@@ -1187,6 +1187,10 @@ fn can_be_null(e: &Expr) -> bool {
         | Expr::TsTypeAssertion(TsTypeAssertion { ref expr, .. })
         | Expr::TsConstAssertion(TsConstAssertion { ref expr, .. }) => can_be_null(expr),
         Expr::OptChain(ref e) => can_be_null(&e.expr),
+        Expr::Pipeline(PipelineExpr { ref right, .. }) => can_be_null(right),
+        Expr::TopicRef(..) => true,
+        Expr::Do(..) => true,
+        Expr::Record(..) | Expr::Tuple(..) => false,
 
         Expr::Invalid(..) => unreachable!(),
     }
@@ -3,6 +3,7 @@ use smallvec::SmallVec;
 use std::mem::take;
 use swc_atoms::JsWord;
 use swc_common::{
+    errors::HANDLER,
     util::{map::Map, move_map::MoveMap, take::Take},
     BytePos, Span, Spanned, SyntaxContext, DUMMY_SP,
 };
@@ -333,6 +334,7 @@ impl CaseHandler<'_> {
             | Expr::TsNonNull(..)
             | Expr::TsAs(..)
             | Expr::PrivateName(..)
+            | Expr::TopicRef(..)
             | Expr::Invalid(..) => e,
 
             Expr::OptChain(e) => Expr::OptChain(OptChainExpr {
@@ -341,6 +343,18 @@ impl CaseHandler<'_> {
             }),
 
             Expr::Await(..) => unimplemented!("regenerator: await in generator"),
+            Expr::Pipeline(..) => {
+                report_unlowered(e.span(), "pipeline expressions");
+                e
+            }
+            Expr::Do(..) => {
+                report_unlowered(e.span(), "do expressions");
+                e
+            }
+            Expr::Record(..) | Expr::Tuple(..) => {
+                report_unlowered(e.span(), "records and tuples");
+                e
+            }
 
             Expr::Paren(ParenExpr { span, expr }) => Expr::Paren(ParenExpr {
                 span,
@@ -1637,3 +1651,20 @@ impl VisitMut for CatchParamHandler<'_> {
         node.visit_mut_children_with(self);
     }
 }
+
+/// Reports a proposal which should be lowered before the regenerator, as it
+/// can't be split at `yield`.
+fn report_unlowered(span: Span, name: &str) {
+    HANDLER.with(|handler| {
+        handler
+            .struct_span_err(
+                span,
+                &format!(
+                    "regenerator: {} containing `yield` should be transformed before the \
+                     regenerator",
+                    name
+                ),
+            )
+            .emit()
+    });
+}
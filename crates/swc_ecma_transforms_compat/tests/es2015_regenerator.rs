@@ -1,10 +1,10 @@
 use swc_common::{chain, comments::SingleThreadedComments, Mark};
-use swc_ecma_parser::Syntax;
+use swc_ecma_parser::{EsConfig, Syntax};
 use swc_ecma_transforms_base::resolver::resolver;
 use swc_ecma_transforms_compat::{
     es2015, es2015::regenerator, es2016, es2017, es2017::async_to_generator, es2018, es2021, es2022,
 };
-use swc_ecma_transforms_testing::{test, test_exec};
+use swc_ecma_transforms_testing::{test, test_exec, Tester};
 use swc_ecma_visit::Fold;
 
 fn syntax() -> Syntax {
@@ -2125,3 +2125,45 @@ test_exec!(
     await res;
     "
 );
+
+fn test_error(syntax: Syntax, src: &str) {
+    Tester::run(|tester| {
+        tester.apply_transform(tr(()), "input.js", syntax, src)?;
+
+        if tester.handler.has_errors() {
+            return Err(());
+        }
+
+        Ok(())
+    })
+}
+
+#[test]
+#[should_panic(
+    expected = "regenerator: do expressions containing `yield` should be transformed before the \
+                regenerator"
+)]
+fn unlowered_do_expression() {
+    test_error(
+        Syntax::Es(EsConfig {
+            do_expressions: true,
+            ..Default::default()
+        }),
+        "function* f() { let x = do { yield 1; }; }",
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "regenerator: pipeline expressions containing `yield` should be transformed before \
+                the regenerator"
+)]
+fn unlowered_pipeline() {
+    test_error(
+        Syntax::Es(EsConfig {
+            pipeline_operator: Some(Default::default()),
+            ..Default::default()
+        }),
+        "function* f() { let x = a |> (yield %); }",
+    );
+}
@@ -1,7 +1,7 @@
 use either::Either;
 use serde::Deserialize;
 use std::{iter, mem::take};
-use swc_common::{Spanned, DUMMY_SP};
+use swc_common::{chain, pass::Optional, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::helper;
 use swc_ecma_transforms_classes::super_field::SuperFieldAccessFolder;
@@ -58,16 +58,28 @@ pub fn decorators(c: Config) -> impl Fold {
     if c.legacy {
         Either::Left(self::legacy::new(c.emit_metadata))
     } else {
-        if c.emit_metadata {
-            unimplemented!("emitting decorator metadata while using new proposal")
-        }
-        Either::Right(Decorators {
-            is_in_strict: false,
-            vars: Default::default(),
-        })
+        Either::Right(chain!(
+            Optional::new(metadata(), c.emit_metadata),
+            Decorators {
+                is_in_strict: false,
+                vars: Default::default(),
+            }
+        ))
     }
 }
 
+/// Emits `design:type`, `design:paramtypes` and `design:returntype` metadata
+/// of decorated classes, like `emitDecoratorMetadata` of TypeScript, without
+/// transforming decorators. The metadata is applied with `Reflect.metadata`
+/// after the class is defined. Parameter decorators are converted to method or
+/// class decorators.
+///
+/// This pass requires type annotations, so it should be applied before
+/// stripping types. References to imported types keep the imports alive.
+pub fn metadata() -> impl Fold {
+    self::legacy::metadata()
+}
+
 #[derive(Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Config {
@@ -1,9 +1,335 @@
 use super::EnumKind;
-use swc_atoms::js_word;
-use swc_common::{collections::AHashMap, util::move_map::MoveMap, Spanned, DUMMY_SP};
+use std::mem::take;
+use swc_atoms::{js_word, JsWord};
+use swc_common::{
+    collections::{AHashMap, AHashSet},
+    util::{move_map::MoveMap, take::Take},
+    Spanned, DUMMY_SP,
+};
 use swc_ecma_ast::*;
-use swc_ecma_utils::{ident::IdentLike, member_expr, quote_ident, undefined, ExprFactory, Id};
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+use swc_ecma_utils::{
+    ident::IdentLike, member_expr, prepend, private_ident, prop_name_to_expr_value, quote_ident,
+    undefined, ExprFactory, Id, ModuleItemLike,
+};
+use swc_ecma_visit::{
+    noop_fold_type, noop_visit_mut_type, Fold, FoldWith, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+/// Declarations which affect serialization of types.
+#[derive(Debug, Default)]
+pub(super) struct TypeDecls {
+    pub(super) enums: AHashMap<Id, EnumKind>,
+    /// Bindings created by `import type` or `import { type A }`, which don't
+    /// exist at runtime.
+    pub(super) type_only_imports: AHashSet<Id>,
+}
+
+impl Visit for TypeDecls {
+    fn visit_import_decl(&mut self, n: &ImportDecl) {
+        for s in &n.specifiers {
+            let local = match s {
+                ImportSpecifier::Named(s) if n.type_only || s.is_type_only => &s.local,
+                ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. })
+                    if n.type_only =>
+                {
+                    local
+                }
+                _ => continue,
+            };
+            self.type_only_imports.insert(local.to_id());
+        }
+    }
+
+    fn visit_ts_enum_decl(&mut self, e: &TsEnumDecl) {
+        let enum_kind = e
+            .members
+            .iter()
+            .map(|member| member.init.as_ref())
+            .map(|init| match init {
+                Some(e) => match &**e {
+                    Expr::Lit(lit) => match lit {
+                        Lit::Str(_) => EnumKind::Str,
+                        Lit::Num(_) => EnumKind::Num,
+                        _ => EnumKind::Mixed,
+                    },
+                    _ => EnumKind::Mixed,
+                },
+                None => EnumKind::Num,
+            })
+            .fold(None, |opt: Option<EnumKind>, item| {
+                //
+                let a = match item {
+                    EnumKind::Mixed => return Some(EnumKind::Mixed),
+                    _ => item,
+                };
+
+                let b = match opt {
+                    Some(EnumKind::Mixed) => return Some(EnumKind::Mixed),
+                    Some(v) => v,
+                    None => return Some(item),
+                };
+                if a == b {
+                    Some(a)
+                } else {
+                    Some(EnumKind::Mixed)
+                }
+            });
+        if let Some(kind) = enum_kind {
+            self.enums.insert(e.id.to_id(), kind);
+        }
+    }
+}
+
+/// Emits metadata of decorated classes, without transforming decorators.
+///
+/// Decorators of the new proposal are not called with the target and the key
+/// `Reflect.metadata` expects, so the metadata is applied to the class and its
+/// prototype after the class is defined.
+#[derive(Default)]
+pub(super) struct DecoratorMetadata {
+    types: TypeDecls,
+    /// Variables for class expressions and computed keys.
+    vars: Vec<VarDeclarator>,
+}
+
+impl DecoratorMetadata {
+    /// Returns expressions which apply the metadata of `class`, which is
+    /// referenced by `class_ref` once it's defined.
+    fn class_metadata(
+        &mut self,
+        class: &mut Class,
+        class_name: Option<&Ident>,
+        class_ref: &Ident,
+    ) -> Vec<Expr> {
+        *class = class.take().fold_with(&mut ParamMetadata);
+
+        let mut m = Metadata {
+            types: &self.types,
+            class_name,
+            type_params: type_param_names(class.type_params.as_ref()).collect(),
+        };
+        let mut exprs = vec![];
+
+        if !class.decorators.is_empty() {
+            if let Some(types) = m.constructor_param_types(class) {
+                exprs.push(reflect_metadata(
+                    "design:paramtypes",
+                    types.as_arg(),
+                    Some(vec![class_ref.clone().as_arg()]),
+                ));
+            }
+        }
+
+        for member in &mut class.body {
+            let (key, is_static, types) = match member {
+                ClassMember::Method(method) if !method.function.decorators.is_empty() => (
+                    &mut method.key,
+                    method.is_static,
+                    m.method_types(&method.function, method.kind),
+                ),
+                ClassMember::ClassProp(prop) if !prop.decorators.is_empty() => {
+                    match m.prop_type(prop) {
+                        Some(ty) => (&mut prop.key, prop.is_static, vec![("design:type", ty)]),
+                        None => continue,
+                    }
+                }
+                _ => continue,
+            };
+
+            let key = key_to_expr(key, &mut self.vars);
+            let target = if is_static {
+                Expr::Ident(class_ref.clone())
+            } else {
+                class_ref.clone().make_member(quote_ident!("prototype"))
+            };
+
+            exprs.extend(types.into_iter().map(|(design, ty)| {
+                reflect_metadata(
+                    design,
+                    ty.as_arg(),
+                    Some(vec![target.clone().as_arg(), key.clone().as_arg()]),
+                )
+            }));
+        }
+
+        exprs
+    }
+
+    fn visit_mut_stmt_likes<T>(&mut self, stmts: &mut Vec<T>)
+    where
+        T: ModuleItemLike + VisitMutWith<Self>,
+    {
+        let mut buf = Vec::with_capacity(stmts.len());
+
+        for mut stmt in take(stmts) {
+            stmt.visit_mut_with(self);
+
+            let (stmt, exprs) = match stmt.try_into_stmt() {
+                Ok(mut stmt) => {
+                    let exprs = match &mut stmt {
+                        Stmt::Decl(Decl::Class(c)) => {
+                            let ident = c.ident.clone();
+                            self.class_metadata(&mut c.class, Some(&ident), &ident)
+                        }
+                        _ => vec![],
+                    };
+                    (T::from_stmt(stmt), exprs)
+                }
+                Err(item) => match item.try_into_module_decl() {
+                    Ok(mut decl) => {
+                        let exprs = self.module_decl_metadata(&mut decl);
+                        match T::try_from_module_decl(decl) {
+                            Ok(item) => (item, exprs),
+                            Err(..) => unreachable!(),
+                        }
+                    }
+                    Err(item) => (item, vec![]),
+                },
+            };
+
+            buf.push(stmt);
+            buf.extend(exprs.into_iter().map(|expr| T::from_stmt(expr.into_stmt())));
+        }
+
+        *stmts = buf;
+    }
+
+    fn module_decl_metadata(&mut self, decl: &mut ModuleDecl) -> Vec<Expr> {
+        match decl {
+            ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Class(c),
+                ..
+            }) => {
+                let ident = c.ident.clone();
+                self.class_metadata(&mut c.class, Some(&ident), &ident)
+            }
+
+            ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::Class(c),
+                ..
+            }) => {
+                // Anonymous classes need a name to be referenced, like
+                // `default_1` of tsc.
+                let ident = c
+                    .ident
+                    .get_or_insert_with(|| private_ident!("_default"))
+                    .clone();
+                self.class_metadata(&mut c.class, Some(&ident), &ident)
+            }
+
+            _ => vec![],
+        }
+    }
+}
+
+impl VisitMut for DecoratorMetadata {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if let Expr::Class(ClassExpr { ident, class }) = e {
+            let class_ref = private_ident!("_class");
+            let exprs = self.class_metadata(class, ident.as_ref(), &class_ref);
+            if exprs.is_empty() {
+                return;
+            }
+
+            self.vars.push(VarDeclarator {
+                span: DUMMY_SP,
+                name: class_ref.clone().into(),
+                init: None,
+                definite: false,
+            });
+
+            let class = Box::new(e.take());
+            *e = Expr::Seq(SeqExpr {
+                span: DUMMY_SP,
+                exprs: std::iter::once(Box::new(Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    op: op!("="),
+                    left: PatOrExpr::Pat(Box::new(class_ref.clone().into())),
+                    right: class,
+                })))
+                .chain(exprs.into_iter().map(Box::new))
+                .chain(std::iter::once(Box::new(Expr::Ident(class_ref))))
+                .collect(),
+            });
+        }
+    }
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        m.visit_with(&mut self.types);
+
+        m.visit_mut_children_with(self);
+
+        if !self.vars.is_empty() {
+            prepend(
+                &mut m.body,
+                Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: take(&mut self.vars),
+                }))
+                .into(),
+            );
+        }
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        self.visit_mut_stmt_likes(items);
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        s.visit_with(&mut self.types);
+
+        s.visit_mut_children_with(self);
+
+        if !self.vars.is_empty() {
+            prepend(
+                &mut s.body,
+                Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: take(&mut self.vars),
+                })),
+            );
+        }
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        self.visit_mut_stmt_likes(stmts);
+    }
+}
+
+/// Returns the value of `key`. A computed key is stored in a variable when
+/// it's defined, so it's evaluated only once.
+fn key_to_expr(key: &mut PropName, vars: &mut Vec<VarDeclarator>) -> Expr {
+    match key {
+        PropName::Computed(ComputedPropName { expr, .. }) if !expr.is_lit() => {
+            let alias = private_ident!("_key");
+            vars.push(VarDeclarator {
+                span: DUMMY_SP,
+                name: alias.clone().into(),
+                init: None,
+                definite: false,
+            });
+
+            **expr = Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Pat(Box::new(alias.clone().into())),
+                right: expr.take(),
+            });
+
+            Expr::Ident(alias)
+        }
+        _ => prop_name_to_expr_value(key.clone()),
+    }
+}
 
 /// https://github.com/leonardfactory/babel-plugin-transform-typescript-metadata/blob/master/src/parameter/parameterVisitor.ts
 pub(super) struct ParamMetadata;
@@ -121,9 +447,12 @@ impl ParamMetadata {
 
 /// https://github.com/leonardfactory/babel-plugin-transform-typescript-metadata/blob/master/src/metadata/metadataVisitor.ts
 pub(super) struct Metadata<'a> {
-    pub(super) enums: &'a AHashMap<Id, EnumKind>,
+    pub(super) types: &'a TypeDecls,
 
     pub(super) class_name: Option<&'a Ident>,
+
+    /// Type parameters in scope, which are serialized as `Object`.
+    pub(super) type_params: Vec<JsWord>,
 }
 
 /// TODO: VisitMut
@@ -131,54 +460,14 @@ impl Fold for Metadata<'_> {
     noop_fold_type!();
 
     fn fold_class(&mut self, mut c: Class) -> Class {
-        c = c.fold_children_with(self);
+        let type_params_len = self.type_params.len();
+        self.type_params
+            .extend(type_param_names(c.type_params.as_ref()));
 
-        if c.decorators.is_empty() {
-            return c;
-        }
-
-        let constructor = c.body.iter().find_map(|m| match m {
-            ClassMember::Constructor(c) => Some(c),
-            _ => None,
-        });
-        if constructor.is_none() {
-            return c;
-        }
+        c = c.fold_children_with(self);
+        c = self.add_class_metadata(c);
 
-        {
-            let dec = self
-                .create_metadata_design_decorator("design:type", quote_ident!("Function").as_arg());
-            c.decorators.push(dec);
-        }
-        {
-            let dec = self.create_metadata_design_decorator(
-                "design:paramtypes",
-                ArrayLit {
-                    span: DUMMY_SP,
-                    elems: constructor
-                        .as_ref()
-                        .unwrap()
-                        .params
-                        .iter()
-                        .map(|v| match v {
-                            ParamOrTsParamProp::TsParamProp(p) => {
-                                let ann = match &p.param {
-                                    TsParamPropParam::Ident(i) => i.type_ann.as_ref(),
-                                    TsParamPropParam::Assign(a) => get_type_ann_of_pat(&a.left),
-                                };
-                                Some(serialize_type(self.class_name, ann).as_arg())
-                            }
-                            ParamOrTsParamProp::Param(p) => Some(
-                                serialize_type(self.class_name, get_type_ann_of_pat(&p.pat))
-                                    .as_arg(),
-                            ),
-                        })
-                        .collect(),
-                }
-                .as_arg(),
-            );
-            c.decorators.push(dec);
-        }
+        self.type_params.truncate(type_params_len);
         c
     }
 
@@ -187,32 +476,24 @@ impl Fold for Metadata<'_> {
             return m;
         }
 
-        {
-            let dec = self
-                .create_metadata_design_decorator("design:type", quote_ident!("Function").as_arg());
-            m.function.decorators.push(dec);
-        }
-        {
-            let dec = self.create_metadata_design_decorator(
-                "design:paramtypes",
-                ArrayLit {
-                    span: DUMMY_SP,
-                    elems: m
-                        .function
-                        .params
-                        .iter()
-                        .map(|v| {
-                            Some(
-                                serialize_type(self.class_name, get_type_ann_of_pat(&v.pat))
-                                    .as_arg(),
-                            )
-                        })
-                        .collect(),
-                }
-                .as_arg(),
-            );
-            m.function.decorators.push(dec);
-        }
+        // Legacy decorators only get `design:type` and `design:paramtypes`, even for
+        // accessors.
+        let type_params_len = self.type_params.len();
+        self.type_params
+            .extend(type_param_names(m.function.type_params.as_ref()));
+
+        let dec =
+            self.create_metadata_design_decorator("design:type", quote_ident!("Function").as_arg());
+        m.function.decorators.push(dec);
+
+        let dec = self.create_metadata_design_decorator(
+            "design:paramtypes",
+            self.param_types(&m.function).as_arg(),
+        );
+        m.function.decorators.push(dec);
+
+        self.type_params.truncate(type_params_len);
+
         m
     }
 
@@ -221,118 +502,217 @@ impl Fold for Metadata<'_> {
             return p;
         }
 
-        if p.type_ann.is_none() {
-            return p;
+        if let Some(ty) = self.prop_type(&p) {
+            let dec = self.create_metadata_design_decorator("design:type", ty.as_arg());
+            p.decorators.push(dec);
         }
-        if let Some(name) = p
-            .type_ann
-            .as_ref()
-            .map(|ty| &ty.type_ann)
-            .and_then(|type_ann| match &**type_ann {
-                TsType::TsTypeRef(r) => Some(r),
-                _ => None,
-            })
-            .and_then(|r| match &r.type_name {
-                TsEntityName::TsQualifiedName(_) => None,
-                TsEntityName::Ident(i) => Some(i),
-            })
-        {
-            if let Some(kind) = self.enums.get(&name.to_id()) {
-                let dec = self.create_metadata_design_decorator(
-                    "design:type",
-                    match kind {
-                        EnumKind::Mixed => quote_ident!("Object").as_arg(),
-                        EnumKind::Str => quote_ident!("String").as_arg(),
-                        EnumKind::Num => quote_ident!("Number").as_arg(),
-                    },
-                );
-                p.decorators.push(dec);
-                return p;
-            }
-        }
-
-        let dec = self.create_metadata_design_decorator(
-            "design:type",
-            serialize_type(self.class_name, p.type_ann.as_ref()).as_arg(),
-        );
-        p.decorators.push(dec);
         p
     }
 }
 
 impl Metadata<'_> {
+    fn add_class_metadata(&self, mut c: Class) -> Class {
+        if c.decorators.is_empty() {
+            return c;
+        }
+
+        if let Some(types) = self.constructor_param_types(&c) {
+            let dec = self
+                .create_metadata_design_decorator("design:type", quote_ident!("Function").as_arg());
+            c.decorators.push(dec);
+
+            let dec = self.create_metadata_design_decorator("design:paramtypes", types.as_arg());
+            c.decorators.push(dec);
+        }
+        c
+    }
+
+    /// Returns `design:paramtypes` of the constructor, if `c` has one.
+    fn constructor_param_types(&self, c: &Class) -> Option<Expr> {
+        let constructor = c.body.iter().find_map(|m| match m {
+            ClassMember::Constructor(c) => Some(c),
+            _ => None,
+        })?;
+
+        Some(Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: constructor
+                .params
+                .iter()
+                .map(|v| match v {
+                    ParamOrTsParamProp::TsParamProp(p) => {
+                        let ann = match &p.param {
+                            TsParamPropParam::Ident(i) => i.type_ann.as_ref(),
+                            TsParamPropParam::Assign(a) => get_type_ann_of_pat(&a.left),
+                        };
+                        Some(serialize_type(self, ann).as_arg())
+                    }
+                    ParamOrTsParamProp::Param(p) => {
+                        Some(serialize_type(self, get_type_ann_of_pat(&p.pat)).as_arg())
+                    }
+                })
+                .collect(),
+        }))
+    }
+
+    /// Returns the metadata of a method, a getter or a setter.
+    fn method_types(&mut self, f: &Function, kind: MethodKind) -> Vec<(&'static str, Expr)> {
+        let type_params_len = self.type_params.len();
+        self.type_params
+            .extend(type_param_names(f.type_params.as_ref()));
+
+        let types = match kind {
+            MethodKind::Method => vec![
+                ("design:type", quote_ident!("Function").into()),
+                ("design:paramtypes", self.param_types(f)),
+                (
+                    "design:returntype",
+                    if f.return_type.is_none() && f.is_async {
+                        quote_ident!("Promise").into()
+                    } else {
+                        serialize_type(self, f.return_type.as_ref())
+                    },
+                ),
+            ],
+            MethodKind::Getter => {
+                vec![("design:type", serialize_type(self, f.return_type.as_ref()))]
+            }
+            MethodKind::Setter => vec![(
+                "design:type",
+                serialize_type(
+                    self,
+                    f.params.first().and_then(|p| get_type_ann_of_pat(&p.pat)),
+                ),
+            )],
+        };
+
+        self.type_params.truncate(type_params_len);
+        types
+    }
+
+    /// Returns `design:paramtypes` of a function.
+    fn param_types(&self, f: &Function) -> Expr {
+        Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: f
+                .params
+                .iter()
+                .map(|v| Some(serialize_type(self, get_type_ann_of_pat(&v.pat)).as_arg()))
+                .collect(),
+        })
+    }
+
+    /// Returns `design:type` of a property with a type annotation.
+    fn prop_type(&self, p: &ClassProp) -> Option<Expr> {
+        p.type_ann.as_ref()?;
+
+        Some(serialize_type(self, p.type_ann.as_ref()))
+    }
+
     fn create_metadata_design_decorator(&self, design: &str, type_arg: ExprOrSpread) -> Decorator {
         Decorator {
             span: DUMMY_SP,
-            expr: Box::new(Expr::Bin(BinExpr {
+            expr: Box::new(reflect_metadata(design, type_arg, None)),
+        }
+    }
+}
+
+/// Creates `Reflect.metadata(design, type_arg)`, which is called with `args` if
+/// it's [Some], guarded by a check for `Reflect.metadata`.
+fn reflect_metadata(design: &str, type_arg: ExprOrSpread, args: Option<Vec<ExprOrSpread>>) -> Expr {
+    let metadata = Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: member_expr!(DUMMY_SP, Reflect.metadata).as_callee(),
+        args: vec![
+            Str {
+                span: DUMMY_SP,
+                value: design.into(),
+                has_escape: false,
+                kind: Default::default(),
+            }
+            .as_arg(),
+            type_arg,
+        ],
+
+        type_args: Default::default(),
+    });
+
+    Expr::Bin(BinExpr {
+        span: DUMMY_SP,
+        left: Box::new(Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            left: Box::new(Expr::Bin(BinExpr {
                 span: DUMMY_SP,
-                left: Box::new(Expr::Bin(BinExpr {
+                left: Box::new(Expr::Unary(UnaryExpr {
                     span: DUMMY_SP,
-                    left: Box::new(Expr::Bin(BinExpr {
-                        span: DUMMY_SP,
-                        left: Box::new(Expr::Unary(UnaryExpr {
-                            span: DUMMY_SP,
-                            op: op!("typeof"),
-                            arg: Box::new(Expr::Ident(quote_ident!("Reflect"))),
-                        })),
-                        op: op!("!=="),
-                        right: Box::new(Expr::Lit(Lit::Str(Str {
-                            span: DUMMY_SP,
-                            value: "undefined".into(),
-                            has_escape: false,
-                            kind: Default::default(),
-                        }))),
-                    })),
-                    op: op!("&&"),
-                    right: Box::new(Expr::Bin(BinExpr {
-                        span: DUMMY_SP,
-                        left: Box::new(Expr::Unary(UnaryExpr {
-                            span: DUMMY_SP,
-                            op: op!("typeof"),
-                            arg: member_expr!(DUMMY_SP, Reflect.metadata),
-                        })),
-                        op: op!("==="),
-                        right: Box::new(Expr::Lit(Lit::Str(Str {
-                            span: DUMMY_SP,
-                            value: "function".into(),
-                            has_escape: false,
-                            kind: Default::default(),
-                        }))),
-                    })),
+                    op: op!("typeof"),
+                    arg: Box::new(Expr::Ident(quote_ident!("Reflect"))),
                 })),
-                op: op!("&&"),
-                right: Box::new(Expr::Call(CallExpr {
+                op: op!("!=="),
+                right: Box::new(Expr::Lit(Lit::Str(Str {
                     span: DUMMY_SP,
-                    callee: member_expr!(DUMMY_SP, Reflect.metadata).as_callee(),
-                    args: vec![
-                        Str {
-                            span: DUMMY_SP,
-                            value: design.into(),
-                            has_escape: false,
-                            kind: Default::default(),
-                        }
-                        .as_arg(),
-                        type_arg,
-                    ],
-
-                    type_args: Default::default(),
+                    value: "undefined".into(),
+                    has_escape: false,
+                    kind: Default::default(),
+                }))),
+            })),
+            op: op!("&&"),
+            right: Box::new(Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                left: Box::new(Expr::Unary(UnaryExpr {
+                    span: DUMMY_SP,
+                    op: op!("typeof"),
+                    arg: member_expr!(DUMMY_SP, Reflect.metadata),
                 })),
+                op: op!("==="),
+                right: Box::new(Expr::Lit(Lit::Str(Str {
+                    span: DUMMY_SP,
+                    value: "function".into(),
+                    has_escape: false,
+                    kind: Default::default(),
+                }))),
             })),
-        }
-    }
+        })),
+        op: op!("&&"),
+        right: Box::new(match args {
+            Some(args) => metadata.as_call(DUMMY_SP, args),
+            None => metadata,
+        }),
+    })
 }
 
-fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr {
-    fn serialize_type_ref(class_name: &str, ty: &TsTypeRef) -> Expr {
+fn serialize_type(m: &Metadata, param: Option<&TsTypeAnn>) -> Expr {
+    fn serialize_type_ref(m: &Metadata, ty: &TsTypeRef) -> Expr {
         match &ty.type_name {
             // We should omit references to self (class) since it will throw a ReferenceError at
             // runtime due to babel transpile output.
-            TsEntityName::Ident(i) if &*i.sym == class_name => {
+            TsEntityName::Ident(i) if m.class_name.map_or(false, |c| c.sym == i.sym) => {
                 return quote_ident!("Object").into()
             }
+            TsEntityName::Ident(i) => {
+                if let Some(kind) = m.types.enums.get(&i.to_id()) {
+                    return match kind {
+                        EnumKind::Mixed => quote_ident!("Object").into(),
+                        EnumKind::Str => quote_ident!("String").into(),
+                        EnumKind::Num => quote_ident!("Number").into(),
+                    };
+                }
+
+                if m.type_params.contains(&i.sym) {
+                    return quote_ident!("Object").into();
+                }
+            }
             _ => {}
         }
 
+        // Type-only imports don't exist at runtime.
+        if m.types
+            .type_only_imports
+            .contains(&leftmost_ident(&ty.type_name).to_id())
+        {
+            return quote_ident!("Object").into();
+        }
+
         let member_expr = ts_entity_to_member_expr(&ty.type_name);
 
         fn check_object_existed(expr: Box<Expr>) -> Box<Expr> {
@@ -392,7 +772,7 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
         })
     }
 
-    fn serialize_type_list(class_name: &str, types: &[Box<TsType>]) -> Expr {
+    fn serialize_type_list(m: &Metadata, types: &[Box<TsType>]) -> Expr {
         let mut u = None;
         for ty in types {
             // Skip parens if need be
@@ -425,7 +805,7 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
                 _ => {}
             }
 
-            let item = serialize_type_node(class_name, ty);
+            let item = serialize_type_node(m, ty);
 
             // One of the individual is global object, return immediately
             if let Expr::Ident(Ident {
@@ -466,7 +846,7 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
         }
     }
 
-    fn serialize_type_node(class_name: &str, ty: &TsType) -> Expr {
+    fn serialize_type_node(m: &Metadata, ty: &TsType) -> Expr {
         let span = ty.span();
         match ty {
             TsType::TsKeywordType(TsKeywordType {
@@ -486,7 +866,7 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
                 ..
             }) => *undefined(span),
 
-            TsType::TsParenthesizedType(ty) => serialize_type_node(class_name, &*ty.type_ann),
+            TsType::TsParenthesizedType(ty) => serialize_type_node(m, &*ty.type_ann),
 
             TsType::TsFnOrConstructorType(_) => quote_ident!("Function").into(),
 
@@ -522,11 +902,6 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
                 ..
             }) => quote_ident!("Number").into(),
 
-            TsType::TsLitType(ty) => {
-                // TODO: Proper error reporting
-                panic!("Bad type for decoration: {:?}", ty);
-            }
-
             TsType::TsKeywordType(TsKeywordType {
                 kind: TsKeywordTypeKind::TsSymbolKeyword,
                 ..
@@ -548,21 +923,19 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
             | TsType::TsThisType(..) => quote_ident!("Object").into(),
 
             TsType::TsUnionOrIntersectionType(ty) => match ty {
-                TsUnionOrIntersectionType::TsUnionType(ty) => {
-                    serialize_type_list(class_name, &ty.types)
-                }
+                TsUnionOrIntersectionType::TsUnionType(ty) => serialize_type_list(m, &ty.types),
                 TsUnionOrIntersectionType::TsIntersectionType(ty) => {
-                    serialize_type_list(class_name, &ty.types)
+                    serialize_type_list(m, &ty.types)
                 }
             },
 
             TsType::TsConditionalType(ty) => {
-                serialize_type_list(class_name, &[ty.true_type.clone(), ty.false_type.clone()])
+                serialize_type_list(m, &[ty.true_type.clone(), ty.false_type.clone()])
             }
 
-            TsType::TsTypeRef(ty) => serialize_type_ref(class_name, ty),
+            TsType::TsTypeRef(ty) => serialize_type_ref(m, ty),
 
-            _ => panic!("Bad type for decorator: {:?}", ty),
+            _ => quote_ident!("Object").into(),
         }
     }
 
@@ -571,7 +944,20 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
         None => return *undefined(DUMMY_SP),
     };
 
-    serialize_type_node(class_name.map(|v| &*v.sym).unwrap_or(""), &**param)
+    serialize_type_node(m, &**param)
+}
+
+fn leftmost_ident(name: &TsEntityName) -> &Ident {
+    match name {
+        TsEntityName::TsQualifiedName(q) => leftmost_ident(&q.left),
+        TsEntityName::Ident(i) => i,
+    }
+}
+
+fn type_param_names(type_params: Option<&TsTypeParamDecl>) -> impl '_ + Iterator<Item = JsWord> {
+    type_params
+        .into_iter()
+        .flat_map(|decl| decl.params.iter().map(|p| p.name.sym.clone()))
 }
 
 fn ts_entity_to_member_expr(type_name: &TsEntityName) -> Expr {
@@ -607,7 +993,7 @@ fn get_type_ann_of_pat(p: &Pat) -> Option<&TsTypeAnn> {
 fn is_str(ty: &TsType) -> bool {
     match ty {
         TsType::TsLitType(TsLitType {
-            lit: TsLit::Str(..),
+            lit: TsLit::Str(..) | TsLit::Tpl(..),
             ..
         })
         | TsType::TsKeywordType(TsKeywordType {
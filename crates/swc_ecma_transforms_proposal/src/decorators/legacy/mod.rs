@@ -1,16 +1,16 @@
-use self::metadata::{Metadata, ParamMetadata};
+use self::metadata::{DecoratorMetadata, Metadata, ParamMetadata, TypeDecls};
 use super::{contains_decorator, DecoratorFinder};
 use smallvec::SmallVec;
 use std::mem::take;
-use swc_common::{collections::AHashMap, util::move_map::MoveMap, DUMMY_SP};
+use swc_common::{util::move_map::MoveMap, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::helper;
 use swc_ecma_utils::{
     alias_if_required, default_constructor, ident::IdentLike, member_expr, prepend, private_ident,
     prop_name_to_expr, prop_name_to_expr_value, quote_ident, replace_ident, undefined, ExprFactory,
-    Id, ModuleItemLike, StmtLike,
+    ModuleItemLike, StmtLike,
 };
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Visit, VisitWith};
+use swc_ecma_visit::{as_folder, noop_fold_type, Fold, FoldWith, VisitWith};
 
 mod metadata;
 
@@ -27,7 +27,7 @@ pub(super) struct Legacy {
     uninitialized_vars: Vec<VarDeclarator>,
     initialized_vars: Vec<VarDeclarator>,
     exports: Vec<ExportSpecifier>,
-    enums: AHashMap<Id, EnumKind>,
+    types: TypeDecls,
 }
 
 pub(super) fn new(metadata: bool) -> Legacy {
@@ -36,49 +36,13 @@ pub(super) fn new(metadata: bool) -> Legacy {
         uninitialized_vars: Default::default(),
         initialized_vars: Default::default(),
         exports: Default::default(),
-        enums: Default::default(),
+        types: Default::default(),
     }
 }
 
-impl Visit for Legacy {
-    fn visit_ts_enum_decl(&mut self, e: &TsEnumDecl) {
-        let enum_kind = e
-            .members
-            .iter()
-            .map(|member| member.init.as_ref())
-            .map(|init| match init {
-                Some(e) => match &**e {
-                    Expr::Lit(lit) => match lit {
-                        Lit::Str(_) => EnumKind::Str,
-                        Lit::Num(_) => EnumKind::Num,
-                        _ => EnumKind::Mixed,
-                    },
-                    _ => EnumKind::Mixed,
-                },
-                None => EnumKind::Num,
-            })
-            .fold(None, |opt: Option<EnumKind>, item| {
-                //
-                let a = match item {
-                    EnumKind::Mixed => return Some(EnumKind::Mixed),
-                    _ => item,
-                };
-
-                let b = match opt {
-                    Some(EnumKind::Mixed) => return Some(EnumKind::Mixed),
-                    Some(v) => v,
-                    None => return Some(item),
-                };
-                if a == b {
-                    Some(a)
-                } else {
-                    Some(EnumKind::Mixed)
-                }
-            });
-        if let Some(kind) = enum_kind {
-            self.enums.insert(e.id.to_id(), kind);
-        }
-    }
+/// Emits metadata of decorated classes, without transforming decorators.
+pub(super) fn metadata() -> impl Fold {
+    as_folder(DecoratorMetadata::default())
 }
 
 /// TODO: VisitMut
@@ -125,7 +89,7 @@ impl Fold for Legacy {
     fn fold_module(&mut self, m: Module) -> Module {
         // Collect required information.
         // For example, value type of enum affects codegen
-        m.visit_with(self);
+        m.visit_with(&mut self.types);
 
         let mut m = m.fold_children_with(self);
 
@@ -198,6 +162,8 @@ impl Fold for Legacy {
     }
 
     fn fold_script(&mut self, s: Script) -> Script {
+        s.visit_with(&mut self.types);
+
         let mut s = s.fold_children_with(self);
 
         if !self.uninitialized_vars.is_empty() {
@@ -262,8 +228,9 @@ impl Legacy {
             let i = c.ident.clone();
 
             c = c.fold_with(&mut ParamMetadata).fold_with(&mut Metadata {
-                enums: &self.enums,
+                types: &self.types,
                 class_name: i.as_ref(),
+                type_params: Default::default(),
             });
         }
 
@@ -0,0 +1,398 @@
+use swc_atoms::JsWord;
+use swc_common::{errors::HANDLER, util::take::Take, Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+/// `@babel/plugin-proposal-do-expressions`
+///
+/// A do expression is converted to an immediately invoked arrow function,
+/// which returns the completion value of the body. Arrow functions preserve
+/// `this` and `arguments`, and the arrow function is awaited if the body
+/// contains `await`.
+///
+/// `return`, `yield`, `break` and `continue` which jump out of the do
+/// expression, and `var` declarations, would change their meaning inside the
+/// arrow function, so they are reported as errors. Loops and labeled
+/// statements are also reported if they produce the completion value.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// let x = do {
+///     if (a) {
+///         1;
+///     } else {
+///         2;
+///     }
+/// };
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// let x = (() => {
+///     if (a) {
+///         return 1;
+///     } else {
+///         return 2;
+///     }
+/// })();
+/// ```
+pub fn do_expressions() -> impl Fold + VisitMut {
+    as_folder(DoExpressions)
+}
+
+struct DoExpressions;
+
+impl VisitMut for DoExpressions {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if let Expr::Do(DoExpr { span, body }) = e {
+            let span = *span;
+            let mut body = body.take();
+
+            body.visit_with(&mut EscapeFinder::default());
+
+            let is_async = {
+                let mut v = AwaitFinder { found: false };
+                body.visit_with(&mut v);
+                v.found
+            };
+
+            return_completion_value_of(&mut body.stmts);
+
+            let call = Expr::Call(CallExpr {
+                span,
+                callee: Callee::Expr(Box::new(Expr::Arrow(ArrowExpr {
+                    span: DUMMY_SP,
+                    params: vec![],
+                    body: BlockStmtOrExpr::BlockStmt(body),
+                    is_async,
+                    is_generator: false,
+                    type_params: None,
+                    return_type: None,
+                }))),
+                args: vec![],
+                type_args: None,
+            });
+
+            *e = if is_async {
+                Expr::Await(AwaitExpr {
+                    span,
+                    arg: Box::new(call),
+                })
+            } else {
+                call
+            };
+        }
+    }
+}
+
+/// Converts the statement which produces the completion value of `stmts` to
+/// a return statement.
+///
+/// Returns `false` if all statements have an empty completion value, like
+/// declarations.
+fn return_completion_value_of(stmts: &mut [Stmt]) -> bool {
+    match stmts.iter_mut().rev().find(|s| !is_empty_completion(s)) {
+        Some(s) => {
+            return_completion_value(s);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Returns true for statements which don't change the completion value of a
+/// statement list.
+fn is_empty_completion(s: &Stmt) -> bool {
+    matches!(s, Stmt::Decl(..) | Stmt::Empty(..) | Stmt::Debugger(..))
+}
+
+/// Converts the statement which produces the completion value to a return
+/// statement.
+fn return_completion_value(s: &mut Stmt) {
+    match s {
+        Stmt::Expr(ExprStmt { span, expr }) => {
+            *s = Stmt::Return(ReturnStmt {
+                span: *span,
+                arg: Some(expr.take()),
+            });
+        }
+
+        Stmt::Block(BlockStmt { stmts, .. }) => {
+            return_completion_value_of(stmts);
+        }
+
+        Stmt::If(IfStmt { cons, alt, .. }) => {
+            return_completion_value(cons);
+            if let Some(alt) = alt {
+                return_completion_value(alt);
+            }
+        }
+
+        Stmt::Try(TryStmt { block, handler, .. }) => {
+            return_completion_value_of(&mut block.stmts);
+            if let Some(CatchClause { body, .. }) = handler {
+                return_completion_value_of(&mut body.stmts);
+            }
+        }
+
+        Stmt::With(WithStmt { body, .. }) => return_completion_value(body),
+
+        Stmt::Switch(s) => return_switch_completion_value(s),
+
+        Stmt::For(ForStmt { span, .. })
+        | Stmt::ForIn(ForInStmt { span, .. })
+        | Stmt::ForOf(ForOfStmt { span, .. })
+        | Stmt::While(WhileStmt { span, .. })
+        | Stmt::DoWhile(DoWhileStmt { span, .. }) => report(
+            *span,
+            "a loop cannot be the last statement of a do expression because its completion value \
+             is not supported",
+        ),
+
+        Stmt::Labeled(LabeledStmt { span, .. }) => report(
+            *span,
+            "a labeled statement cannot be the last statement of a do expression because its \
+             completion value is not supported",
+        ),
+
+        // `return`, `throw`, `break` and `continue` don't complete normally.
+        _ => {}
+    }
+}
+
+/// Converts the statements before each `break` of a `switch`, and the last
+/// statement of the last case, to return statements, like babel does.
+///
+/// If a case without `break` falls through, its completion value is used only
+/// if the following statements don't have one, so it's reported instead.
+fn return_switch_completion_value(s: &mut SwitchStmt) {
+    let len = s.cases.len();
+    // A case which falls through with a completion value.
+    let mut fallthrough: Option<Span> = None;
+
+    for (i, case) in s.cases.iter_mut().enumerate() {
+        let has_value = match return_completion_value_before_break(&mut case.cons) {
+            Some(has_value) => has_value,
+            None if i == len - 1 => return_completion_value_of(&mut case.cons),
+            None => {
+                if case.cons.iter().any(|s| !is_empty_completion(s)) {
+                    fallthrough = Some(case.span);
+                }
+                continue;
+            }
+        };
+
+        if let Some(span) = fallthrough.take() {
+            if !has_value {
+                report(
+                    span,
+                    "the completion value of a `switch` case which falls through is not supported \
+                     in a do expression",
+                );
+            }
+        }
+    }
+
+    if let Some(span) = fallthrough {
+        report(
+            span,
+            "the completion value of a `switch` case which falls through is not supported in a do \
+             expression",
+        );
+    }
+}
+
+/// If `stmts` of a `switch` case breaks, converts the statement which produces
+/// the completion value of the `break` to a return statement.
+///
+/// Returns [None] if there's no `break`, and otherwise whether a completion
+/// value was found.
+fn return_completion_value_before_break(stmts: &mut [Stmt]) -> Option<bool> {
+    for i in 0..stmts.len() {
+        let has_value = match &mut stmts[i] {
+            Stmt::Break(BreakStmt { label: None, .. }) => false,
+            // Unlike other statements, a block keeps the completion value of
+            // `break`.
+            Stmt::Block(block) => match return_completion_value_before_break(&mut block.stmts) {
+                Some(has_value) => has_value,
+                None => continue,
+            },
+            _ => continue,
+        };
+
+        return Some(has_value || return_completion_value_of(&mut stmts[..i]));
+    }
+
+    None
+}
+
+struct AwaitFinder {
+    found: bool,
+}
+
+impl Visit for AwaitFinder {
+    noop_visit_type!();
+
+    fn visit_await_expr(&mut self, _: &AwaitExpr) {
+        self.found = true;
+    }
+
+    fn visit_for_of_stmt(&mut self, s: &ForOfStmt) {
+        if s.await_token.is_some() {
+            self.found = true;
+        }
+
+        s.visit_children_with(self);
+    }
+
+    /// Don't recurse into functions.
+    fn visit_function(&mut self, _: &Function) {}
+
+    /// Don't recurse into arrow functions.
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+}
+
+/// Reports statements and expressions in the body of a do expression which
+/// behave differently once the body is moved into a function.
+#[derive(Default)]
+struct EscapeFinder {
+    /// Labels declared in the do expression.
+    labels: Vec<JsWord>,
+    /// Number of enclosing loops in the do expression.
+    loop_depth: usize,
+    /// Number of enclosing loops and `switch` statements in the do
+    /// expression.
+    breakable_depth: usize,
+}
+
+impl EscapeFinder {
+    fn visit_loop<N>(&mut self, n: &N)
+    where
+        N: VisitWith<Self>,
+    {
+        self.loop_depth += 1;
+        self.breakable_depth += 1;
+        n.visit_children_with(self);
+        self.loop_depth -= 1;
+        self.breakable_depth -= 1;
+    }
+
+    fn is_declared(&self, label: &Ident) -> bool {
+        self.labels.contains(&label.sym)
+    }
+}
+
+impl Visit for EscapeFinder {
+    noop_visit_type!();
+
+    fn visit_return_stmt(&mut self, s: &ReturnStmt) {
+        report(s.span, "`return` is not supported in a do expression");
+    }
+
+    fn visit_yield_expr(&mut self, e: &YieldExpr) {
+        report(e.span, "`yield` is not supported in a do expression");
+    }
+
+    fn visit_break_stmt(&mut self, s: &BreakStmt) {
+        let escapes = match &s.label {
+            Some(label) => !self.is_declared(label),
+            None => self.breakable_depth == 0,
+        };
+
+        if escapes {
+            report(
+                s.span,
+                "`break` cannot jump out of a do expression because it is compiled to a function",
+            );
+        }
+    }
+
+    fn visit_continue_stmt(&mut self, s: &ContinueStmt) {
+        let escapes = match &s.label {
+            Some(label) => !self.is_declared(label),
+            None => self.loop_depth == 0,
+        };
+
+        if escapes {
+            report(
+                s.span,
+                "`continue` cannot jump out of a do expression because it is compiled to a \
+                 function",
+            );
+        }
+    }
+
+    fn visit_var_decl(&mut self, v: &VarDecl) {
+        if v.kind == VarDeclKind::Var {
+            report(
+                v.span,
+                "`var` is not supported in a do expression because it would be scoped to the \
+                 generated function; use `let` or `const` instead",
+            );
+        }
+
+        v.visit_children_with(self);
+    }
+
+    fn visit_labeled_stmt(&mut self, s: &LabeledStmt) {
+        self.labels.push(s.label.sym.clone());
+        s.body.visit_with(self);
+        self.labels.pop();
+    }
+
+    fn visit_switch_stmt(&mut self, s: &SwitchStmt) {
+        s.discriminant.visit_with(self);
+
+        self.breakable_depth += 1;
+        s.cases.visit_with(self);
+        self.breakable_depth -= 1;
+    }
+
+    fn visit_for_stmt(&mut self, s: &ForStmt) {
+        self.visit_loop(s);
+    }
+
+    fn visit_for_in_stmt(&mut self, s: &ForInStmt) {
+        self.visit_loop(s);
+    }
+
+    fn visit_for_of_stmt(&mut self, s: &ForOfStmt) {
+        self.visit_loop(s);
+    }
+
+    fn visit_while_stmt(&mut self, s: &WhileStmt) {
+        self.visit_loop(s);
+    }
+
+    fn visit_do_while_stmt(&mut self, s: &DoWhileStmt) {
+        self.visit_loop(s);
+    }
+
+    /// Don't recurse into functions.
+    fn visit_function(&mut self, _: &Function) {}
+
+    /// Don't recurse into arrow functions.
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_constructor(&mut self, _: &Constructor) {}
+
+    fn visit_static_block(&mut self, _: &StaticBlock) {}
+
+    fn visit_getter_prop(&mut self, _: &GetterProp) {}
+
+    fn visit_setter_prop(&mut self, _: &SetterProp) {}
+}
+
+fn report(span: Span, msg: &str) {
+    HANDLER.with(|handler| handler.struct_span_err(span, msg).emit());
+}
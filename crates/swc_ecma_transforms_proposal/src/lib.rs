@@ -1,10 +1,14 @@
 #![deny(clippy::all)]
 
 pub use self::{
-    decorators::decorators, export_default_from::export_default_from,
-    import_assertions::import_assertions,
+    decorators::decorators, do_expressions::do_expressions,
+    export_default_from::export_default_from, import_assertions::import_assertions,
+    pipeline_operator::pipeline_operator, record_and_tuple::record_and_tuple,
 };
 
 pub mod decorators;
+mod do_expressions;
 mod export_default_from;
 mod import_assertions;
+mod pipeline_operator;
+pub mod record_and_tuple;
@@ -0,0 +1,300 @@
+use std::mem::{replace, take};
+use swc_common::{util::take::Take, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{private_ident, ExprFactory, StmtLike};
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+/// `@babel/plugin-proposal-pipeline-operator` with `proposal: "hack"`
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// value |> foo(%) |> bar(%, 1);
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// var _topic, _topic1;
+/// _topic1 = (_topic = value, foo(_topic)), bar(_topic1, 1);
+/// ```
+///
+/// Topic variables are declared in the function containing the pipeline, so
+/// each call gets its own binding. If the topic is captured by a function in
+/// the body, or there's no function body to declare a variable in (like
+/// default values of parameters), the body is wrapped in an arrow function
+/// instead, so each evaluation gets its own binding.
+pub fn pipeline_operator() -> impl Fold + VisitMut {
+    as_folder(PipelineOperator {
+        vars: Default::default(),
+        can_declare_var: true,
+    })
+}
+
+struct PipelineOperator {
+    vars: Vec<VarDeclarator>,
+    /// `false` if variables declared by the closest statement don't belong to
+    /// the current function, like in default values of parameters.
+    can_declare_var: bool,
+}
+
+impl PipelineOperator {
+    fn visit_mut_stmt_like<T>(&mut self, stmts: &mut Vec<T>)
+    where
+        T: VisitMutWith<Self> + StmtLike,
+    {
+        let mut buf = Vec::with_capacity(stmts.len() + 2);
+
+        for mut stmt in stmts.take() {
+            stmt.visit_mut_with(self);
+
+            if !self.vars.is_empty() {
+                buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    decls: take(&mut self.vars),
+                    declare: false,
+                }))));
+            }
+
+            buf.push(stmt);
+        }
+
+        *stmts = buf
+    }
+}
+
+impl VisitMut for PipelineOperator {
+    noop_visit_mut_type!();
+
+    fn visit_mut_arrow_expr(&mut self, f: &mut ArrowExpr) {
+        let old_can_declare_var = replace(&mut self.can_declare_var, false);
+        f.params.visit_mut_with(self);
+
+        self.can_declare_var = true;
+        let old_vars = self.vars.take();
+        f.body.visit_mut_with(self);
+
+        if !self.vars.is_empty() {
+            if let BlockStmtOrExpr::Expr(expr) = &mut f.body {
+                f.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: expr.span(),
+                    stmts: vec![
+                        Stmt::Decl(Decl::Var(VarDecl {
+                            span: DUMMY_SP,
+                            kind: VarDeclKind::Var,
+                            decls: self.vars.take(),
+                            declare: false,
+                        })),
+                        Stmt::Return(ReturnStmt {
+                            span: DUMMY_SP,
+                            arg: Some(expr.take()),
+                        }),
+                    ],
+                });
+            }
+        }
+
+        self.vars = old_vars;
+        self.can_declare_var = old_can_declare_var;
+    }
+
+    fn visit_mut_block_stmt(&mut self, s: &mut BlockStmt) {
+        let old_vars = self.vars.take();
+        let old_can_declare_var = replace(&mut self.can_declare_var, true);
+        s.visit_mut_children_with(self);
+        self.can_declare_var = old_can_declare_var;
+        self.vars = old_vars;
+    }
+
+    fn visit_mut_class_prop(&mut self, n: &mut ClassProp) {
+        let old = replace(&mut self.can_declare_var, false);
+        n.visit_mut_children_with(self);
+        self.can_declare_var = old;
+    }
+
+    fn visit_mut_param(&mut self, n: &mut Param) {
+        let old = replace(&mut self.can_declare_var, false);
+        n.visit_mut_children_with(self);
+        self.can_declare_var = old;
+    }
+
+    fn visit_mut_param_or_ts_param_prop(&mut self, n: &mut ParamOrTsParamProp) {
+        let old = replace(&mut self.can_declare_var, false);
+        n.visit_mut_children_with(self);
+        self.can_declare_var = old;
+    }
+
+    fn visit_mut_private_prop(&mut self, n: &mut PrivateProp) {
+        let old = replace(&mut self.can_declare_var, false);
+        n.visit_mut_children_with(self);
+        self.can_declare_var = old;
+    }
+
+    fn visit_mut_setter_prop(&mut self, n: &mut SetterProp) {
+        let old = replace(&mut self.can_declare_var, false);
+        n.key.visit_mut_with(self);
+        n.param.visit_mut_with(self);
+        self.can_declare_var = old;
+
+        n.body.visit_mut_with(self);
+    }
+
+    fn visit_mut_switch_case(&mut self, s: &mut SwitchCase) {
+        let old_vars = self.vars.take();
+        s.visit_mut_children_with(self);
+        self.vars = old_vars;
+    }
+
+    fn visit_mut_module_items(&mut self, n: &mut Vec<ModuleItem>) {
+        self.visit_mut_stmt_like(n)
+    }
+
+    fn visit_mut_stmts(&mut self, n: &mut Vec<Stmt>) {
+        self.visit_mut_stmt_like(n)
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        // Nested pipelines are lowered first, so topic references remaining in
+        // the body belong to this pipeline.
+        e.visit_mut_children_with(self);
+
+        if let Expr::Pipeline(PipelineExpr { span, left, right }) = e {
+            let topic = private_ident!("_topic");
+
+            let mut v = TopicFinder::default();
+            right.visit_with(&mut v);
+
+            let mut right = right.take();
+            right.visit_mut_with(&mut TopicReplacer { topic: &topic });
+
+            // `yield` and `await` can't be moved into an arrow function.
+            if (v.captured || !self.can_declare_var) && !v.found_yield_or_await {
+                *e = ArrowExpr {
+                    span: DUMMY_SP,
+                    params: vec![topic.into()],
+                    body: BlockStmtOrExpr::Expr(right),
+                    is_async: false,
+                    is_generator: false,
+                    type_params: Default::default(),
+                    return_type: Default::default(),
+                }
+                .as_call(
+                    *span,
+                    vec![ExprOrSpread {
+                        spread: None,
+                        expr: left.take(),
+                    }],
+                );
+                return;
+            }
+
+            self.vars.push(VarDeclarator {
+                span: DUMMY_SP,
+                name: topic.clone().into(),
+                init: None,
+                definite: false,
+            });
+
+            *e = Expr::Seq(SeqExpr {
+                span: *span,
+                exprs: vec![
+                    Box::new(Expr::Assign(AssignExpr {
+                        span: DUMMY_SP,
+                        op: op!("="),
+                        left: PatOrExpr::Pat(Box::new(Pat::Ident(topic.into()))),
+                        right: left.take(),
+                    })),
+                    right,
+                ],
+            });
+        }
+    }
+}
+
+#[derive(Default)]
+struct TopicFinder {
+    in_fn: bool,
+    /// The topic is referenced from a function in the body.
+    captured: bool,
+    found_yield_or_await: bool,
+}
+
+impl TopicFinder {
+    fn visit_fn<N>(&mut self, n: &N)
+    where
+        N: VisitWith<Self>,
+    {
+        let old = replace(&mut self.in_fn, true);
+        n.visit_children_with(self);
+        self.in_fn = old;
+    }
+}
+
+impl Visit for TopicFinder {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        self.visit_fn(n)
+    }
+
+    fn visit_await_expr(&mut self, n: &AwaitExpr) {
+        if !self.in_fn {
+            self.found_yield_or_await = true;
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_class(&mut self, n: &Class) {
+        self.visit_fn(n)
+    }
+
+    fn visit_function(&mut self, n: &Function) {
+        self.visit_fn(n)
+    }
+
+    fn visit_getter_prop(&mut self, n: &GetterProp) {
+        self.visit_fn(n)
+    }
+
+    fn visit_setter_prop(&mut self, n: &SetterProp) {
+        self.visit_fn(n)
+    }
+
+    fn visit_topic_ref(&mut self, _: &TopicRef) {
+        if self.in_fn {
+            self.captured = true;
+        }
+    }
+
+    fn visit_yield_expr(&mut self, n: &YieldExpr) {
+        if !self.in_fn {
+            self.found_yield_or_await = true;
+        }
+        n.visit_children_with(self);
+    }
+}
+
+struct TopicReplacer<'a> {
+    topic: &'a Ident,
+}
+
+impl VisitMut for TopicReplacer<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        if let Expr::TopicRef(TopicRef { span }) = e {
+            *e = Expr::Ident(Ident {
+                span: span.with_ctxt(self.topic.span.ctxt),
+                ..self.topic.clone()
+            });
+            return;
+        }
+
+        e.visit_mut_children_with(self);
+    }
+}
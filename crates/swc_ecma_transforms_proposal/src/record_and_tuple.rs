@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+use swc_atoms::JsWord;
+use swc_common::{util::take::Take, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{prepend, private_ident, quote_ident, quote_str, ExprFactory};
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Import path of the polyfill, which exports `Record` and `Tuple`.
+    ///
+    /// Defaults to `@bloomberg/record-tuple-polyfill`.
+    #[serde(default)]
+    pub import_path: Option<JsWord>,
+}
+
+/// `@babel/plugin-proposal-record-and-tuple`
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// const a = #{ x: 1, y: #[1, 2] };
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// import { Record as _Record, Tuple as _Tuple } from "@bloomberg/record-tuple-polyfill";
+/// const a = _Record({ x: 1, y: _Tuple(1, 2) });
+/// ```
+pub fn record_and_tuple(config: Config) -> impl Fold + VisitMut {
+    as_folder(RecordAndTuple {
+        config,
+        record: None,
+        tuple: None,
+    })
+}
+
+struct RecordAndTuple {
+    config: Config,
+    /// Local name of `Record`, if used.
+    record: Option<Ident>,
+    /// Local name of `Tuple`, if used.
+    tuple: Option<Ident>,
+}
+
+impl RecordAndTuple {
+    fn import_path(&self) -> JsWord {
+        self.config
+            .import_path
+            .clone()
+            .unwrap_or_else(|| "@bloomberg/record-tuple-polyfill".into())
+    }
+
+    /// Returns the imported names and their local names.
+    fn used(&mut self) -> Vec<(JsWord, Ident)> {
+        let mut used = vec![];
+        if let Some(local) = self.record.take() {
+            used.push(("Record".into(), local));
+        }
+        if let Some(local) = self.tuple.take() {
+            used.push(("Tuple".into(), local));
+        }
+        used
+    }
+}
+
+impl VisitMut for RecordAndTuple {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        match e {
+            Expr::Record(RecordLit { span, props }) => {
+                let callee = self
+                    .record
+                    .get_or_insert_with(|| private_ident!("_Record"))
+                    .clone();
+
+                *e = Expr::Call(CallExpr {
+                    span: *span,
+                    callee: callee.as_callee(),
+                    args: vec![ObjectLit {
+                        span: DUMMY_SP,
+                        props: props.take(),
+                    }
+                    .as_arg()],
+                    type_args: None,
+                });
+            }
+
+            Expr::Tuple(TupleLit { span, elems }) => {
+                let callee = self
+                    .tuple
+                    .get_or_insert_with(|| private_ident!("_Tuple"))
+                    .clone();
+
+                *e = Expr::Call(CallExpr {
+                    span: *span,
+                    callee: callee.as_callee(),
+                    args: elems.take(),
+                    type_args: None,
+                });
+            }
+
+            _ => {}
+        }
+    }
+
+    /// Injects `import { Record as _Record } from
+    /// "@bloomberg/record-tuple-polyfill";`
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        m.visit_mut_children_with(self);
+
+        let used = self.used();
+        if used.is_empty() {
+            return;
+        }
+
+        prepend(
+            &mut m.body,
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                span: DUMMY_SP,
+                specifiers: used
+                    .into_iter()
+                    .map(|(imported, local)| {
+                        ImportSpecifier::Named(ImportNamedSpecifier {
+                            span: DUMMY_SP,
+                            local,
+                            imported: Some(ModuleExportName::Ident(quote_ident!(imported))),
+                            is_type_only: false,
+                        })
+                    })
+                    .collect(),
+                src: quote_str!(self.import_path()),
+                type_only: false,
+                asserts: None,
+            })),
+        );
+    }
+
+    /// Injects `var _Record =
+    /// require("@bloomberg/record-tuple-polyfill").Record;`
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        s.visit_mut_children_with(self);
+
+        let used = self.used();
+        if used.is_empty() {
+            return;
+        }
+
+        let src = self.import_path();
+        prepend(
+            &mut s.body,
+            Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: used
+                    .into_iter()
+                    .map(|(imported, local)| VarDeclarator {
+                        span: DUMMY_SP,
+                        name: local.into(),
+                        init: Some(Box::new(
+                            CallExpr {
+                                span: DUMMY_SP,
+                                callee: quote_ident!("require").as_callee(),
+                                args: vec![quote_str!(src.clone()).as_arg()],
+                                type_args: None,
+                            }
+                            .make_member(quote_ident!(imported)),
+                        )),
+                        definite: false,
+                    })
+                    .collect(),
+            })),
+        );
+    }
+}
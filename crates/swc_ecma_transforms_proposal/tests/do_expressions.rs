@@ -0,0 +1,345 @@
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms_proposal::do_expressions;
+use swc_ecma_transforms_testing::{test, Tester};
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        do_expressions: true,
+        ..Default::default()
+    })
+}
+
+fn test_error(src: &str) {
+    Tester::run(|tester| {
+        tester.apply_transform(do_expressions(), "input.js", syntax(), src)?;
+
+        if tester.handler.has_errors() {
+            return Err(());
+        }
+
+        Ok(())
+    })
+}
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    simple,
+    r#"let x = do { 1; };"#,
+    r#"
+let x = (() => {
+    return 1;
+})();
+"#
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    if_else,
+    r#"
+let x = do {
+    let y = f();
+    if (y) {
+        y + 1;
+    } else {
+        0;
+    }
+};
+"#,
+    r#"
+let x = (() => {
+    let y = f();
+    if (y) {
+        return y + 1;
+    } else {
+        return 0;
+    }
+})();
+"#
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    try_catch,
+    r#"
+let x = do {
+    try {
+        f();
+    } catch (e) {
+        null;
+    }
+};
+"#,
+    r#"
+let x = (() => {
+    try {
+        return f();
+    } catch (e) {
+        return null;
+    }
+})();
+"#
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    await_in_body,
+    r#"
+async function f() {
+    let x = do {
+        await g();
+    };
+}
+"#,
+    r#"
+async function f() {
+    let x = await (async () => {
+        return await g();
+    })();
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    await_in_nested_fn,
+    r#"
+let x = do {
+    async () => await g();
+};
+"#,
+    r#"
+let x = (() => {
+    return async () => await g();
+})();
+"#
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    nested,
+    r#"let x = do { let y = do { 1; }; y + 1; };"#,
+    r#"
+let x = (() => {
+    let y = (() => {
+        return 1;
+    })();
+    return y + 1;
+})();
+"#
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    jumps_in_body,
+    r#"
+let x = do {
+    let y = 0;
+    a: {
+        for (const z of zs) {
+            if (z) continue;
+            switch (z) {
+                case 1:
+                    break;
+            }
+            break a;
+        }
+    }
+    y;
+};
+"#,
+    r#"
+let x = (() => {
+    let y = 0;
+    a: {
+        for (const z of zs) {
+            if (z) continue;
+            switch (z) {
+                case 1:
+                    break;
+            }
+            break a;
+        }
+    }
+    return y;
+})();
+"#
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    return_in_nested_fn,
+    r#"
+let x = do {
+    function f() {
+        var y = 1;
+        return y;
+    }
+    f();
+};
+"#,
+    r#"
+let x = (() => {
+    function f() {
+        var y = 1;
+        return y;
+    }
+    return f();
+})();
+"#
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    trailing_declaration,
+    r#"
+let x = do {
+    f();
+    let y = 1;
+};
+"#,
+    r#"
+let x = (() => {
+    return f();
+    let y = 1;
+})();
+"#
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    switch,
+    r#"
+let x = do {
+    switch (y) {
+        case 1:
+            "a";
+            break;
+        case 2: {
+            "b";
+            break;
+        }
+        case 3:
+        case 4:
+            f();
+            if (z) break;
+            "c";
+            break;
+        default:
+            "d";
+    }
+};
+"#,
+    r#"
+let x = (() => {
+    switch (y) {
+        case 1:
+            return "a";
+            break;
+        case 2: {
+            return "b";
+            break;
+        }
+        case 3:
+        case 4:
+            f();
+            if (z) break;
+            return "c";
+            break;
+        default:
+            return "d";
+    }
+})();
+"#
+);
+
+test!(
+    syntax(),
+    |_| do_expressions(),
+    switch_fallthrough,
+    r#"
+let x = do {
+    switch (y) {
+        case 1:
+            "a";
+        case 2:
+            "b";
+            break;
+    }
+};
+"#,
+    r#"
+let x = (() => {
+    switch (y) {
+        case 1:
+            "a";
+        case 2:
+            return "b";
+            break;
+    }
+})();
+"#
+);
+
+#[test]
+#[should_panic(expected = "the completion value of a `switch` case which falls through")]
+fn error_switch_fallthrough() {
+    test_error("let x = do { switch (y) { case 1: 'a'; case 2: break; } };");
+}
+
+#[test]
+#[should_panic(expected = "a loop cannot be the last statement of a do expression")]
+fn error_loop() {
+    test_error("let x = do { for (const y of z) { y; } };");
+}
+
+#[test]
+#[should_panic(expected = "a labeled statement cannot be the last statement of a do expression")]
+fn error_labeled() {
+    test_error("let x = do { a: { 1; break a; } };");
+}
+
+#[test]
+#[should_panic(expected = "`return` is not supported in a do expression")]
+fn error_return() {
+    test_error("function f() { let x = do { return 1; }; }");
+}
+
+#[test]
+#[should_panic(expected = "`yield` is not supported in a do expression")]
+fn error_yield() {
+    test_error("function* f() { let x = do { yield 1; }; }");
+}
+
+#[test]
+#[should_panic(expected = "`break` cannot jump out of a do expression")]
+fn error_break() {
+    test_error("for (;;) { let x = do { break; }; }");
+}
+
+#[test]
+#[should_panic(expected = "`break` cannot jump out of a do expression")]
+fn error_labeled_break() {
+    test_error("a: for (;;) { let x = do { for (;;) { break a; } }; }");
+}
+
+#[test]
+#[should_panic(expected = "`continue` cannot jump out of a do expression")]
+fn error_continue() {
+    test_error("for (;;) { let x = do { switch (y) { default: continue; } }; }");
+}
+
+#[test]
+#[should_panic(expected = "`var` is not supported in a do expression")]
+fn error_var() {
+    test_error("let x = do { var y = 1; y; };");
+}
@@ -0,0 +1,122 @@
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms_proposal::pipeline_operator;
+use swc_ecma_transforms_testing::test;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        pipeline_operator: Some(Default::default()),
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| pipeline_operator(),
+    simple,
+    r#"value |> foo(%);"#,
+    r#"
+var _topic;
+_topic = value, foo(_topic);
+"#
+);
+
+test!(
+    syntax(),
+    |_| pipeline_operator(),
+    chained,
+    r#"value |> foo(%) |> bar(%, %);"#,
+    r#"
+var _topic, _topic1;
+_topic1 = (_topic = value, foo(_topic)), bar(_topic1, _topic1);
+"#
+);
+
+test!(
+    syntax(),
+    |_| pipeline_operator(),
+    nested_body,
+    r#"a |> (% |> f(%, b));"#,
+    r#"
+var _topic, _topic1;
+_topic1 = a, _topic = _topic1, f(_topic, b);
+"#
+);
+
+test!(
+    syntax(),
+    |_| pipeline_operator(),
+    in_function,
+    r#"
+function f(x) {
+    return x |> % + 1;
+}
+"#,
+    r#"
+function f(x) {
+    var _topic;
+    return _topic = x, _topic + 1;
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| pipeline_operator(),
+    arrow_expr_body,
+    r#"const f = (n) => n |> (% > 0 ? f(% - 1) + % : 0);"#,
+    r#"
+const f = (n) => {
+    var _topic;
+    return _topic = n, _topic > 0 ? f(_topic - 1) + _topic : 0;
+};
+"#
+);
+
+test!(
+    syntax(),
+    |_| pipeline_operator(),
+    captured_topic,
+    r#"
+for (const x of xs) {
+    fns.push(x |> () => %);
+}
+"#,
+    r#"
+for (const x of xs) {
+    fns.push(((_topic) => () => _topic)(x));
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| pipeline_operator(),
+    default_param,
+    r#"function f(a, b = a |> % + 1) {}"#,
+    r#"function f(a, b = ((_topic) => _topic + 1)(a)) {}"#
+);
+
+test!(
+    syntax(),
+    |_| pipeline_operator(),
+    class_prop,
+    r#"class A { a = b |> % + 1; }"#,
+    r#"class A { a = ((_topic) => _topic + 1)(b); }"#
+);
+
+test!(
+    syntax(),
+    |_| pipeline_operator(),
+    yield_in_body,
+    r#"
+function* f() {
+    return x |> (yield %);
+}
+"#,
+    r#"
+function* f() {
+    var _topic;
+    return _topic = x, yield _topic;
+}
+"#
+);
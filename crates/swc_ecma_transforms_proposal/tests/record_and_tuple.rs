@@ -0,0 +1,71 @@
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms_proposal::record_and_tuple::{record_and_tuple, Config};
+use swc_ecma_transforms_testing::test;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        record_and_tuple: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| record_and_tuple(Default::default()),
+    record,
+    r#"const a = #{ x: 1, ...b };"#,
+    r#"
+import { Record as _Record } from "@bloomberg/record-tuple-polyfill";
+const a = _Record({ x: 1, ...b });
+"#
+);
+
+test!(
+    syntax(),
+    |_| record_and_tuple(Default::default()),
+    tuple,
+    r#"const a = #[1, ...b];"#,
+    r#"
+import { Tuple as _Tuple } from "@bloomberg/record-tuple-polyfill";
+const a = _Tuple(1, ...b);
+"#
+);
+
+test!(
+    syntax(),
+    |_| record_and_tuple(Default::default()),
+    nested,
+    r#"const a = #{ x: #[1, #{ y: 2 }] };"#,
+    r#"
+import { Record as _Record, Tuple as _Tuple } from "@bloomberg/record-tuple-polyfill";
+const a = _Record({ x: _Tuple(1, _Record({ y: 2 })) });
+"#
+);
+
+test!(
+    syntax(),
+    |_| record_and_tuple(Config {
+        import_path: Some("my-polyfill".into()),
+    }),
+    custom_import_path,
+    r#"const a = #[];"#,
+    r#"
+import { Tuple as _Tuple } from "my-polyfill";
+const a = _Tuple();
+"#
+);
+
+test!(
+    syntax(),
+    |_| record_and_tuple(Default::default()),
+    shadowed_name,
+    r#"
+const Record = 1, _Record = 2;
+const a = #{};
+"#,
+    r#"
+import { Record as _Record1 } from "@bloomberg/record-tuple-polyfill";
+const Record = 1, _Record = 2;
+const a = _Record1({});
+"#
+);
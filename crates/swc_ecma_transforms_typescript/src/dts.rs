@@ -0,0 +1,723 @@
+//! Emits typescript declarations (`.d.ts`).
+//!
+//! Like `isolatedDeclarations` of `tsc`, declarations are created without
+//! type inference, so exported functions, variables and class members should
+//! have explicit type annotations. An error is reported for each annotation
+//! which would require inference.
+
+use swc_atoms::JsWord;
+use swc_common::{
+    collections::AHashSet, errors::HANDLER, util::take::Take, EqIgnoreSpan, Span, Spanned, DUMMY_SP,
+};
+use swc_ecma_ast::*;
+use swc_ecma_utils::find_ids;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, Visit, VisitMut, VisitWith};
+
+/// Converts a typescript module to a declaration module, which can be printed
+/// as a `.d.ts` file using `swc_ecma_codegen`.
+///
+/// - Bodies of functions and methods, and initializers of variables and
+///   properties are removed.
+/// - Variables and properties without type annotations get the types of
+///   literals, type assertions and annotated functions used as initializers.
+/// - Private class members and statements other than declarations are removed.
+/// - Declarations which are not exported are removed, unless an exported
+///   declaration refers to them.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```ts
+/// export function add(a: number, b = 1): number {
+///     return a + b;
+/// }
+/// ```
+///
+/// ## Out
+///
+/// ```ts
+/// export declare function add(a: number, b?: number): number;
+/// ```
+pub fn dts() -> impl Fold + VisitMut {
+    as_folder(Dts::default())
+}
+
+#[derive(Default)]
+struct Dts {
+    /// True while processing the body of a namespace, where `declare` is not
+    /// allowed.
+    in_ambient: bool,
+}
+
+impl VisitMut for Dts {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        m.body = self.transform_items(m.body.take());
+    }
+}
+
+impl Dts {
+    fn transform_items(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        let items = remove_overload_impls(items);
+
+        let mut out: Vec<Option<ModuleItem>> = Vec::with_capacity(items.len());
+        // Declarations which are not exported, with their index in `out`.
+        let mut pending = vec![];
+
+        for item in items {
+            match item {
+                ModuleItem::ModuleDecl(decl) => {
+                    out.push(self.transform_module_decl(decl).map(ModuleItem::ModuleDecl));
+                }
+                ModuleItem::Stmt(Stmt::Decl(decl)) => {
+                    pending.push((out.len(), decl));
+                    out.push(None);
+                }
+                ModuleItem::Stmt(..) => {}
+            }
+        }
+
+        // Declarations which are used by exported declarations are also
+        // required.
+        loop {
+            let mut v = RefCollector::default();
+            for item in out.iter().flatten() {
+                item.visit_with(&mut v);
+            }
+
+            let (used, unused): (Vec<_>, Vec<_>) = pending.into_iter().partition(|(_, decl)| {
+                declared_names(decl)
+                    .iter()
+                    .any(|name| v.refs.contains(name))
+            });
+            pending = unused;
+
+            if used.is_empty() {
+                break;
+            }
+
+            for (idx, decl) in used {
+                out[idx] = Some(ModuleItem::Stmt(Stmt::Decl(self.transform_decl(decl))));
+            }
+        }
+
+        out.into_iter().flatten().collect()
+    }
+
+    fn transform_module_decl(&mut self, decl: ModuleDecl) -> Option<ModuleDecl> {
+        match decl {
+            ModuleDecl::Import(..)
+            | ModuleDecl::ExportNamed(..)
+            | ModuleDecl::ExportAll(..)
+            | ModuleDecl::TsImportEquals(..)
+            | ModuleDecl::TsExportAssignment(..)
+            | ModuleDecl::TsNamespaceExport(..) => Some(decl),
+
+            ModuleDecl::ExportDecl(ExportDecl { span, decl }) => {
+                Some(ModuleDecl::ExportDecl(ExportDecl {
+                    span,
+                    decl: self.transform_decl(decl),
+                }))
+            }
+
+            ModuleDecl::ExportDefaultDecl(mut export) => {
+                match &mut export.decl {
+                    DefaultDecl::Class(ClassExpr { class, .. }) => self.transform_class(class),
+                    DefaultDecl::Fn(FnExpr { function, .. }) => self.transform_fn(function, true),
+                    DefaultDecl::TsInterfaceDecl(..) => {}
+                }
+
+                Some(ModuleDecl::ExportDefaultDecl(export))
+            }
+
+            ModuleDecl::ExportDefaultExpr(export) => match *export.expr {
+                Expr::Ident(..) => Some(ModuleDecl::ExportDefaultExpr(export)),
+                _ => {
+                    report(
+                        export.span,
+                        "Default export of an expression requires type inference; export an \
+                         annotated variable instead",
+                    );
+                    None
+                }
+            },
+        }
+    }
+
+    fn transform_decl(&mut self, decl: Decl) -> Decl {
+        match decl {
+            Decl::Class(mut c) => {
+                c.declare = !self.in_ambient;
+                self.transform_class(&mut c.class);
+                Decl::Class(c)
+            }
+
+            Decl::Fn(mut f) => {
+                f.declare = !self.in_ambient;
+                self.transform_fn(&mut f.function, true);
+                Decl::Fn(f)
+            }
+
+            Decl::Var(mut v) => {
+                v.declare = !self.in_ambient;
+                for d in &mut v.decls {
+                    transform_var_declarator(d, v.kind);
+                }
+                Decl::Var(v)
+            }
+
+            Decl::TsEnum(mut e) => {
+                e.declare = !self.in_ambient;
+                Decl::TsEnum(e)
+            }
+
+            Decl::TsModule(mut m) => {
+                m.declare = !self.in_ambient;
+                if let Some(body) = &mut m.body {
+                    let old = self.in_ambient;
+                    self.in_ambient = true;
+                    self.transform_namespace_body(body);
+                    self.in_ambient = old;
+                }
+                Decl::TsModule(m)
+            }
+
+            Decl::TsInterface(..) | Decl::TsTypeAlias(..) => decl,
+        }
+    }
+
+    fn transform_namespace_body(&mut self, body: &mut TsNamespaceBody) {
+        match body {
+            TsNamespaceBody::TsModuleBlock(block) => {
+                block.body = self.transform_items(block.body.take());
+            }
+            TsNamespaceBody::TsNamespaceDecl(decl) => {
+                decl.declare = false;
+                self.transform_namespace_body(&mut decl.body);
+            }
+        }
+    }
+
+    /// `needs_return_type` is false for setters and constructors.
+    fn transform_fn(&mut self, f: &mut Function, needs_return_type: bool) {
+        f.decorators.clear();
+        f.body = None;
+        f.is_async = false;
+        f.is_generator = false;
+
+        for param in &mut f.params {
+            param.decorators.clear();
+            transform_param(&mut param.pat);
+        }
+
+        if needs_return_type && f.return_type.is_none() {
+            report(
+                f.span,
+                "Function must have an explicit return type annotation",
+            );
+        }
+    }
+
+    fn transform_class(&mut self, class: &mut Class) {
+        class.decorators.clear();
+
+        let mut members = Vec::with_capacity(class.body.len());
+
+        for member in remove_overload_members(class.body.take()) {
+            match member {
+                ClassMember::Constructor(mut c) => {
+                    c.body = None;
+
+                    if c.accessibility == Some(Accessibility::Private) {
+                        c.params.clear();
+                        members.push(ClassMember::Constructor(c));
+                        continue;
+                    }
+
+                    for param in &mut c.params {
+                        match param {
+                            ParamOrTsParamProp::Param(p) => {
+                                p.decorators.clear();
+                                transform_param(&mut p.pat);
+                            }
+                            ParamOrTsParamProp::TsParamProp(p) => {
+                                let mut pat = match p.param.clone() {
+                                    TsParamPropParam::Ident(i) => Pat::Ident(i),
+                                    TsParamPropParam::Assign(a) => Pat::Assign(a),
+                                };
+                                transform_param(&mut pat);
+
+                                if p.accessibility != Some(Accessibility::Private) {
+                                    if let Pat::Ident(i) = &pat {
+                                        members.push(ClassMember::ClassProp(ClassProp {
+                                            span: p.span,
+                                            key: PropName::Ident(Ident {
+                                                optional: false,
+                                                ..i.id.clone()
+                                            }),
+                                            value: None,
+                                            type_ann: i.type_ann.clone(),
+                                            is_static: false,
+                                            decorators: vec![],
+                                            accessibility: p.accessibility,
+                                            is_abstract: false,
+                                            is_optional: false,
+                                            is_override: p.is_override,
+                                            readonly: p.readonly,
+                                            declare: false,
+                                            definite: false,
+                                        }));
+                                    }
+                                }
+
+                                *param = ParamOrTsParamProp::Param(Param {
+                                    span: p.span,
+                                    decorators: vec![],
+                                    pat,
+                                });
+                            }
+                        }
+                    }
+
+                    members.push(ClassMember::Constructor(c));
+                }
+
+                ClassMember::Method(mut m) => {
+                    if m.accessibility == Some(Accessibility::Private) {
+                        continue;
+                    }
+
+                    self.transform_fn(&mut m.function, m.kind != MethodKind::Setter);
+                    members.push(ClassMember::Method(m));
+                }
+
+                ClassMember::ClassProp(mut p) => {
+                    if p.accessibility == Some(Accessibility::Private) {
+                        continue;
+                    }
+
+                    p.decorators.clear();
+                    p.declare = false;
+                    p.definite = false;
+
+                    if p.type_ann.is_none() {
+                        match p.value.as_deref() {
+                            // `readonly a = 1;`
+                            Some(value) if p.readonly && is_const_lit(value) => {
+                                members.push(ClassMember::ClassProp(p));
+                                continue;
+                            }
+                            Some(value) => p.type_ann = infer_type(value),
+                            None => {}
+                        }
+
+                        if p.type_ann.is_none() {
+                            report(p.span, "Property must have an explicit type annotation");
+                        }
+                    }
+                    p.value = None;
+
+                    members.push(ClassMember::ClassProp(p));
+                }
+
+                ClassMember::TsIndexSignature(..) => members.push(member),
+
+                ClassMember::PrivateMethod(..)
+                | ClassMember::PrivateProp(..)
+                | ClassMember::StaticBlock(..)
+                | ClassMember::Empty(..) => {}
+            }
+        }
+
+        class.body = members;
+    }
+}
+
+fn transform_var_declarator(d: &mut VarDeclarator, kind: VarDeclKind) {
+    d.definite = false;
+
+    match &mut d.name {
+        Pat::Ident(i) => {
+            if i.type_ann.is_none() {
+                match d.init.as_deref() {
+                    // `declare const a = 1;`
+                    Some(init) if kind == VarDeclKind::Const && is_const_lit(init) => return,
+                    Some(init) => i.type_ann = infer_type(init),
+                    None => {}
+                }
+
+                if i.type_ann.is_none() {
+                    report(d.span, "Variable must have an explicit type annotation");
+                }
+            }
+        }
+        _ => {
+            report(d.span, "Destructuring declarations require type inference");
+        }
+    }
+
+    d.init = None;
+}
+
+/// Removes the default value of `pat`, and reports a missing type annotation.
+fn transform_param(pat: &mut Pat) {
+    match pat {
+        Pat::Ident(i) => {
+            if i.type_ann.is_none() {
+                report(i.id.span, "Parameter must have an explicit type annotation");
+            }
+        }
+
+        // `a: T = v` => `a?: T`
+        Pat::Assign(AssignPat {
+            left,
+            right,
+            type_ann,
+            ..
+        }) => {
+            let type_ann = type_ann
+                .take()
+                .or_else(|| match &mut **left {
+                    Pat::Ident(i) => i.type_ann.take(),
+                    Pat::Object(o) => o.type_ann.take(),
+                    Pat::Array(a) => a.type_ann.take(),
+                    _ => None,
+                })
+                .or_else(|| infer_type_from_lit(right));
+
+            if type_ann.is_none() {
+                report(
+                    left.span(),
+                    "Parameter must have an explicit type annotation",
+                );
+            }
+
+            let mut left = left.take();
+            remove_pat_defaults(&mut left);
+            match &mut *left {
+                Pat::Ident(i) => {
+                    i.id.optional = true;
+                    i.type_ann = type_ann;
+                }
+                Pat::Object(o) => {
+                    o.optional = true;
+                    o.type_ann = type_ann;
+                }
+                Pat::Array(a) => {
+                    a.optional = true;
+                    a.type_ann = type_ann;
+                }
+                _ => {}
+            }
+
+            *pat = *left;
+        }
+
+        Pat::Rest(r) => {
+            let has_type = r.type_ann.is_some()
+                || matches!(
+                    &*r.arg,
+                    Pat::Ident(BindingIdent {
+                        type_ann: Some(..),
+                        ..
+                    })
+                );
+            if !has_type {
+                report(r.span, "Parameter must have an explicit type annotation");
+            }
+        }
+
+        Pat::Object(ObjectPat { span, type_ann, .. })
+        | Pat::Array(ArrayPat { span, type_ann, .. }) => {
+            if type_ann.is_none() {
+                report(*span, "Parameter must have an explicit type annotation");
+            }
+            remove_pat_defaults(pat);
+        }
+
+        _ => {}
+    }
+}
+
+/// Default values are not allowed in declaration files.
+fn remove_pat_defaults(pat: &mut Pat) {
+    match pat {
+        Pat::Assign(AssignPat { left, .. }) => {
+            let mut left = left.take();
+            remove_pat_defaults(&mut left);
+            *pat = *left;
+        }
+        Pat::Array(ArrayPat { elems, .. }) => {
+            for elem in elems.iter_mut().flatten() {
+                remove_pat_defaults(elem);
+            }
+        }
+        Pat::Object(ObjectPat { props, .. }) => {
+            for prop in props {
+                match prop {
+                    ObjectPatProp::KeyValue(KeyValuePatProp { value, .. }) => {
+                        remove_pat_defaults(value)
+                    }
+                    ObjectPatProp::Assign(AssignPatProp { value, .. }) => *value = None,
+                    ObjectPatProp::Rest(RestPat { arg, .. }) => remove_pat_defaults(arg),
+                }
+            }
+        }
+        Pat::Rest(RestPat { arg, .. }) => remove_pat_defaults(arg),
+        _ => {}
+    }
+}
+
+/// Returns true if `e` can be used as the initializer of a constant in a
+/// declaration file.
+fn is_const_lit(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(Lit::Num(..) | Lit::Str(..) | Lit::Bool(..) | Lit::BigInt(..)) => true,
+        Expr::Unary(UnaryExpr {
+            op: op!(unary, "-"),
+            arg,
+            ..
+        }) => matches!(&**arg, Expr::Lit(Lit::Num(..) | Lit::BigInt(..))),
+        _ => false,
+    }
+}
+
+/// Infers the type of an initializer from literals, type assertions and type
+/// annotations of functions, which doesn't require type checking.
+fn infer_type(e: &Expr) -> Option<TsTypeAnn> {
+    match e {
+        Expr::Paren(ParenExpr { expr, .. }) => infer_type(expr),
+
+        // `a as T`, `<T>a`
+        Expr::TsAs(TsAsExpr { type_ann, .. })
+        | Expr::TsTypeAssertion(TsTypeAssertion { type_ann, .. }) => Some(TsTypeAnn {
+            span: DUMMY_SP,
+            type_ann: type_ann.clone(),
+        }),
+
+        // `(a: T): U => {}`
+        Expr::Arrow(ArrowExpr {
+            params,
+            type_params,
+            return_type: Some(return_type),
+            ..
+        }) => Some(fn_type(
+            params.clone(),
+            type_params.clone(),
+            return_type.clone(),
+        )),
+
+        // `function (a: T): U {}`
+        Expr::Fn(FnExpr {
+            function:
+                Function {
+                    params,
+                    type_params,
+                    return_type: Some(return_type),
+                    ..
+                },
+            ..
+        }) => Some(fn_type(
+            params.iter().map(|p| p.pat.clone()).collect(),
+            type_params.clone(),
+            return_type.clone(),
+        )),
+
+        _ => infer_type_from_lit(e),
+    }
+}
+
+/// Creates a function type from the signature of a function expression.
+fn fn_type(
+    params: Vec<Pat>,
+    type_params: Option<TsTypeParamDecl>,
+    return_type: TsTypeAnn,
+) -> TsTypeAnn {
+    let params = params
+        .into_iter()
+        .filter_map(|mut pat| {
+            transform_param(&mut pat);
+
+            match pat {
+                Pat::Ident(i) => Some(TsFnParam::Ident(i)),
+                Pat::Array(a) => Some(TsFnParam::Array(a)),
+                Pat::Rest(r) => Some(TsFnParam::Rest(r)),
+                Pat::Object(o) => Some(TsFnParam::Object(o)),
+                _ => None,
+            }
+        })
+        .collect();
+
+    TsTypeAnn {
+        span: DUMMY_SP,
+        type_ann: Box::new(TsType::TsFnOrConstructorType(
+            TsFnOrConstructorType::TsFnType(TsFnType {
+                span: DUMMY_SP,
+                params,
+                type_params,
+                type_ann: return_type,
+            }),
+        )),
+    }
+}
+
+/// Infers the type of a literal, which doesn't require type checking.
+fn infer_type_from_lit(e: &Expr) -> Option<TsTypeAnn> {
+    let kind = match e {
+        Expr::Lit(Lit::Num(..)) => TsKeywordTypeKind::TsNumberKeyword,
+        Expr::Lit(Lit::Str(..)) => TsKeywordTypeKind::TsStringKeyword,
+        Expr::Lit(Lit::Bool(..)) => TsKeywordTypeKind::TsBooleanKeyword,
+        Expr::Lit(Lit::BigInt(..)) => TsKeywordTypeKind::TsBigIntKeyword,
+        Expr::Tpl(Tpl { exprs, .. }) if exprs.is_empty() => TsKeywordTypeKind::TsStringKeyword,
+        Expr::Unary(UnaryExpr {
+            op: op!(unary, "-"),
+            arg,
+            ..
+        }) => match &**arg {
+            Expr::Lit(Lit::Num(..)) => TsKeywordTypeKind::TsNumberKeyword,
+            Expr::Lit(Lit::BigInt(..)) => TsKeywordTypeKind::TsBigIntKeyword,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(TsTypeAnn {
+        span: DUMMY_SP,
+        type_ann: Box::new(TsType::TsKeywordType(TsKeywordType {
+            span: DUMMY_SP,
+            kind,
+        })),
+    })
+}
+
+/// Removes the implementation of overloaded functions.
+fn remove_overload_impls(items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+    let mut overloaded: Option<JsWord> = None;
+
+    items
+        .into_iter()
+        .filter(|item| {
+            let f = match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f)))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Fn(f),
+                    ..
+                })) => f,
+                _ => {
+                    overloaded = None;
+                    return true;
+                }
+            };
+
+            if f.function.body.is_none() {
+                overloaded = Some(f.ident.sym.clone());
+                return true;
+            }
+
+            overloaded.take() != Some(f.ident.sym.clone())
+        })
+        .collect()
+}
+
+/// Removes the implementation of overloaded methods and constructors.
+fn remove_overload_members(members: Vec<ClassMember>) -> Vec<ClassMember> {
+    let mut overloaded: Option<(bool, PropName)> = None;
+    let mut ctor_overloaded = false;
+
+    members
+        .into_iter()
+        .filter(|member| match member {
+            ClassMember::Constructor(c) => {
+                if c.body.is_none() {
+                    ctor_overloaded = true;
+                    return true;
+                }
+
+                !ctor_overloaded
+            }
+
+            ClassMember::Method(m) => {
+                if m.function.body.is_none() {
+                    overloaded = Some((m.is_static, m.key.clone()));
+                    return true;
+                }
+
+                !matches!(
+                    overloaded.take(),
+                    Some((is_static, key)) if is_static == m.is_static && key.eq_ignore_span(&m.key)
+                )
+            }
+
+            _ => {
+                overloaded = None;
+                true
+            }
+        })
+        .collect()
+}
+
+fn declared_names(decl: &Decl) -> Vec<JsWord> {
+    match decl {
+        Decl::Class(c) => vec![c.ident.sym.clone()],
+        Decl::Fn(f) => vec![f.ident.sym.clone()],
+        Decl::Var(v) => find_ids::<_, Ident>(&v.decls)
+            .into_iter()
+            .map(|i| i.sym)
+            .collect(),
+        Decl::TsInterface(i) => vec![i.id.sym.clone()],
+        Decl::TsTypeAlias(a) => vec![a.id.sym.clone()],
+        Decl::TsEnum(e) => vec![e.id.sym.clone()],
+        Decl::TsModule(m) => match &m.id {
+            TsModuleName::Ident(i) => vec![i.sym.clone()],
+            TsModuleName::Str(..) => vec![],
+        },
+    }
+}
+
+/// Collects names referenced by declarations.
+#[derive(Default)]
+struct RefCollector {
+    refs: AHashSet<JsWord>,
+}
+
+impl Visit for RefCollector {
+    fn visit_expr(&mut self, e: &Expr) {
+        match e {
+            Expr::Ident(i) => {
+                self.refs.insert(i.sym.clone());
+            }
+            _ => e.visit_children_with(self),
+        }
+    }
+
+    fn visit_ts_entity_name(&mut self, n: &TsEntityName) {
+        match n {
+            TsEntityName::Ident(i) => {
+                self.refs.insert(i.sym.clone());
+            }
+            TsEntityName::TsQualifiedName(q) => q.left.visit_with(self),
+        }
+    }
+
+    fn visit_named_export(&mut self, e: &NamedExport) {
+        if e.src.is_some() {
+            return;
+        }
+
+        for s in &e.specifiers {
+            if let ExportSpecifier::Named(ExportNamedSpecifier {
+                orig: ModuleExportName::Ident(orig),
+                ..
+            }) = s
+            {
+                self.refs.insert(orig.sym.clone());
+            }
+        }
+    }
+}
+
+fn report(span: Span, msg: &str) {
+    HANDLER.with(|handler| handler.struct_span_err(span, msg).emit());
+}
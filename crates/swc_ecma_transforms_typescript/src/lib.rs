@@ -1,6 +1,7 @@
 #![deny(clippy::all)]
 #![allow(clippy::vec_box)]
 
-pub use self::strip::*;
+pub use self::{dts::dts, strip::*};
 
+pub mod dts;
 pub mod strip;
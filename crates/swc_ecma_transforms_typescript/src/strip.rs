@@ -4,6 +4,7 @@ use swc_atoms::{js_word, JsWord};
 use swc_common::{
     collections::{AHashMap, AHashSet},
     comments::{Comments, NoopComments},
+    errors::HANDLER,
     sync::Lrc,
     util::{move_map::MoveMap, take::Take},
     Mark, SourceMap, Span, Spanned, SyntaxContext, DUMMY_SP,
@@ -16,13 +17,20 @@ use swc_ecma_utils::{
     StmtLike,
 };
 use swc_ecma_visit::{
-    as_folder, noop_visit_mut_type, visit_obj_and_computed, Fold, Visit, VisitMut, VisitMutWith,
-    VisitWith,
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Visit, VisitMut, VisitMutWith, VisitWith,
 };
 
 /// Value does not contain TsLit::Bool
 type EnumValues = AHashMap<JsWord, Option<TsLit>>;
 
+/// Values of `const enum`s exported by a module, keyed by the name of the enum
+/// and then by the name of the member.
+pub type ModuleConstEnums = AHashMap<JsWord, AHashMap<JsWord, TsLit>>;
+
+/// Values of `const enum`s exported by other modules, keyed by the module
+/// specifier used by imports of the file.
+pub type ConstEnums = AHashMap<JsWord, ModuleConstEnums>;
+
 #[derive(Debug, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum ImportsNotUsedAsValues {
@@ -30,6 +38,13 @@ pub enum ImportsNotUsedAsValues {
     Remove,
     #[serde(rename = "preserve")]
     Preserve,
+    /// Like `verbatimModuleSyntax` of `tsc`. Imports are preserved as
+    /// written, except for `import type` and type-only specifiers.
+    ///
+    /// An error is reported for an import which is only used as a type but
+    /// not marked with `type`.
+    #[serde(rename = "verbatim")]
+    Verbatim,
 }
 
 #[deprecated = "ImportNotUsedAsValues is renamed to ImportsNotUsedAsValues"]
@@ -72,6 +87,21 @@ pub struct Config {
     #[serde(default)]
     pub no_empty_export: bool,
 
+    /// Replace accesses to members of `const enum`s with their values, and
+    /// remove declarations of `const enum`s, like `tsc` without
+    /// `preserveConstEnums`.
+    #[serde(default)]
+    pub inline_const_enums: bool,
+
+    /// Values of `const enum`s declared in other files. An imported `const
+    /// enum` is looked up by the source of the import and its imported name,
+    /// and members of namespace imports (`ns.E.A`) are inlined too.
+    ///
+    /// This is used only if `inline_const_enums` is true, and the values of a
+    /// module can be created using [collect_const_enums].
+    #[serde(default)]
+    pub const_enums: ConstEnums,
+
     /// Note: this pass handle jsx directives in comments
     #[serde(default)]
     pub pragma: Option<String>,
@@ -94,6 +124,7 @@ pub fn strip_with_config(config: Config, top_level_mark: Mark) -> impl Fold + Vi
         uninitialized_vars: Default::default(),
         decl_names: Default::default(),
         in_var_pat: Default::default(),
+        const_enums: Default::default(),
     })
 }
 
@@ -105,6 +136,23 @@ pub fn strip(top_level_mark: Mark) -> impl Fold + VisitMut {
     strip_with_config(Default::default(), top_level_mark)
 }
 
+/// Strips flow type annotations out.
+///
+/// Flow types are parsed as typescript types, so this is [strip] configured
+/// for flow. Flow does not elide imports, so an import of which all bindings
+/// are only used as types is kept as a side-effect import. `import type` and
+/// `import typeof` are removed.
+pub fn strip_flow(top_level_mark: Mark) -> impl Fold + VisitMut {
+    strip_with_config(
+        Config {
+            import_not_used_as_values: ImportsNotUsedAsValues::Preserve,
+            no_empty_export: true,
+            ..Default::default()
+        },
+        top_level_mark,
+    )
+}
+
 /// [strip], but aware of jsx.
 ///
 /// If you are using jsx, you should use this before jsx pass.
@@ -157,6 +205,7 @@ where
         uninitialized_vars: Default::default(),
         decl_names: Default::default(),
         in_var_pat: Default::default(),
+        const_enums: Default::default(),
     })
 }
 
@@ -208,6 +257,9 @@ where
     /// This field is filled by [Visit] impl and [VisitMut] impl.
     decl_names: AHashSet<Id>,
     in_var_pat: bool,
+
+    /// `const enum`s which can be inlined, including imported ones.
+    const_enums: InlinableConstEnums,
 }
 
 impl<C> Strip<C>
@@ -268,6 +320,112 @@ where
     }
 }
 
+impl<C> Strip<C>
+where
+    C: Comments,
+{
+    /// Collects `const enum`s which can be inlined.
+    fn collect_const_enums<N>(&mut self, n: &N)
+    where
+        N: for<'a> VisitWith<ConstEnumCollector<'a>> + for<'a> VisitWith<ConstEnumValueUses<'a>>,
+    {
+        if !self.config.inline_const_enums {
+            return;
+        }
+
+        let mut v = ConstEnumCollector {
+            external: &self.config.const_enums,
+            enums: Default::default(),
+            invalid: Default::default(),
+        };
+        n.visit_with(&mut v);
+
+        for span in v.invalid {
+            HANDLER.with(|handler| {
+                handler
+                    .struct_span_err(
+                        span,
+                        "const enum member initializers can only contain literal values and other \
+                         computed enum values",
+                    )
+                    .emit()
+            });
+        }
+        let mut enums = v.enums;
+
+        let mut v = ConstEnumValueUses {
+            enums: &enums,
+            used: Default::default(),
+        };
+        n.visit_with(&mut v);
+        enums.used_as_value = v.used;
+
+        self.const_enums = enums;
+    }
+
+    /// Returns the value of `E.A` if `E` is an inlinable `const enum`.
+    ///
+    /// Enums used as values are preserved, so their members are not inlined.
+    fn const_enum_member_value(&self, e: &MemberExpr) -> Option<&TsLit> {
+        match &*e.obj {
+            Expr::Ident(i) if self.const_enums.used_as_value.contains(&i.to_id()) => None,
+            _ => self.const_enums.member_value(e),
+        }
+    }
+
+    /// Returns true if the declaration of a `const enum` can be removed,
+    /// because all of its members are inlined and the enum itself is not used
+    /// as a value.
+    fn is_inlined_const_enum(&self, e: &TsEnumDecl) -> bool {
+        let id = e.id.to_id();
+
+        e.is_const
+            && self.config.inline_const_enums
+            && self.const_enums.enums.contains_key(&id)
+            && !self.const_enums.used_as_value.contains(&id)
+    }
+}
+
+/// `const enum`s which can be inlined.
+#[derive(Default)]
+struct InlinableConstEnums {
+    /// Enums declared in the file or imported by name.
+    enums: AHashMap<Id, AHashMap<JsWord, TsLit>>,
+    /// Namespace imports of modules which export `const enum`s.
+    namespaces: AHashMap<Id, ModuleConstEnums>,
+    /// Enums which are referenced other than by an inlinable member, like
+    /// `Object.keys(E)`. Their declarations are preserved.
+    used_as_value: AHashSet<Id>,
+}
+
+impl InlinableConstEnums {
+    /// Returns the value of `E.A` or `ns.E.A`.
+    fn member_value(&self, e: &MemberExpr) -> Option<&TsLit> {
+        let values = match &*e.obj {
+            Expr::Ident(i) => self.enums.get(&i.to_id())?,
+            Expr::Member(MemberExpr { obj, prop, .. }) => match &**obj {
+                Expr::Ident(ns) => self.namespaces.get(&ns.to_id())?.get(prop_name(prop)?)?,
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        values.get(prop_name(&e.prop)?)
+    }
+}
+
+/// Returns the name of `.a` and `['a']`.
+fn prop_name(prop: &MemberProp) -> Option<&JsWord> {
+    match prop {
+        MemberProp::Ident(i) => Some(&i.sym),
+        MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+            Expr::Lit(Lit::Str(s)) => Some(&s.value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 #[derive(Default)]
 struct Scope {
     decls: AHashMap<Id, DeclInfo>,
@@ -686,6 +844,13 @@ where
 
     /// Returns [Some] if the method should be called again.
     fn handle_expr<'a>(&mut self, n: &'a mut Expr) -> Vec<&'a mut Expr> {
+        if let Expr::Member(m) = n {
+            if let Some(value) = self.const_enum_member_value(m) {
+                *n = const_enum_value_to_expr(m.span, value.clone());
+                return vec![];
+            }
+        }
+
         if n.is_class() {
             let ClassExpr {
                 ident: old_ident,
@@ -762,150 +927,6 @@ where
 
     /// Returns `(var_decl, init)`.
     fn handle_enum(&mut self, e: TsEnumDecl, module_name: Option<&Ident>) -> (Option<Decl>, Stmt) {
-        /// Called only for enums.
-        ///
-        /// If both of the default value and the initialization is None, this
-        /// method returns [Err].
-        fn compute(
-            e: &TsEnumDecl,
-            span: Span,
-            values: &mut EnumValues,
-            default: Option<i64>,
-            init: Option<&Expr>,
-        ) -> Result<TsLit, ()> {
-            fn compute_bin(
-                e: &TsEnumDecl,
-                span: Span,
-                values: &mut EnumValues,
-                expr: &BinExpr,
-            ) -> Result<TsLit, ()> {
-                let l = compute(e, span, values, None, Some(&expr.left))?;
-                let r = compute(e, span, values, None, Some(&expr.right))?;
-
-                Ok(match (l, r) {
-                    (
-                        TsLit::Number(Number { value: l, .. }),
-                        TsLit::Number(Number { value: r, .. }),
-                    ) => {
-                        TsLit::Number(Number {
-                            span,
-                            value: match expr.op {
-                                op!(bin, "+") => l + r,
-                                op!(bin, "-") => l - r,
-                                op!("*") => l * r,
-                                op!("/") => l / r,
-
-                                // TODO
-                                op!("&") => ((l.round() as i64) & (r.round() as i64)) as _,
-                                op!("|") => ((l.round() as i64) | (r.round() as i64)) as _,
-                                op!("^") => ((l.round() as i64) ^ (r.round() as i64)) as _,
-
-                                op!("<<") => ((l.round() as i64) << (r.round() as i64)) as _,
-                                op!(">>") => ((l.round() as i64) >> (r.round() as i64)) as _,
-                                // TODO: Verify this
-                                op!(">>>") => ((l.round() as u64) >> (r.round() as u64)) as _,
-                                _ => return Err(()),
-                            },
-                        })
-                    }
-                    (TsLit::Str(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
-                        span,
-                        value: format!("{}{}", l.value, r.value).into(),
-                        has_escape: l.has_escape || r.has_escape,
-                        kind: Default::default(),
-                    }),
-                    (TsLit::Number(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => {
-                        TsLit::Str(Str {
-                            span,
-                            value: format!("{}{}", l.value, r.value).into(),
-                            has_escape: r.has_escape,
-                            kind: Default::default(),
-                        })
-                    }
-                    (TsLit::Str(l), TsLit::Number(r)) if expr.op == op!(bin, "+") => {
-                        TsLit::Str(Str {
-                            span,
-                            value: format!("{}{}", l.value, r.value).into(),
-                            has_escape: l.has_escape,
-                            kind: Default::default(),
-                        })
-                    }
-                    _ => return Err(()),
-                })
-            }
-
-            if let Some(expr) = init {
-                match expr {
-                    Expr::Lit(Lit::Str(s)) => return Ok(TsLit::Str(s.clone())),
-                    Expr::Lit(Lit::Num(s)) => return Ok(TsLit::Number(*s)),
-                    Expr::Bin(ref bin) => return compute_bin(e, span, values, bin),
-                    Expr::Paren(ref paren) => {
-                        return compute(e, span, values, default, Some(&paren.expr))
-                    }
-
-                    Expr::Ident(ref id) => {
-                        if let Some(Some(v)) = values.get(&id.sym) {
-                            return Ok(v.clone());
-                        }
-                        //
-                        for m in e.members.iter() {
-                            match m.id {
-                                TsEnumMemberId::Str(Str { value: ref sym, .. })
-                                | TsEnumMemberId::Ident(Ident { ref sym, .. }) => {
-                                    if *sym == id.sym {
-                                        return compute(e, span, values, None, m.init.as_deref());
-                                    }
-                                }
-                            }
-                        }
-                        return Err(());
-                    }
-                    Expr::Unary(ref expr) => {
-                        let v = compute(e, span, values, None, Some(&expr.arg))?;
-                        match v {
-                            TsLit::BigInt(BigInt { .. }) => {}
-                            TsLit::Number(Number { value: v, .. }) => {
-                                return Ok(TsLit::Number(Number {
-                                    span,
-                                    value: match expr.op {
-                                        op!(unary, "+") => v,
-                                        op!(unary, "-") => -v,
-                                        op!("!") => {
-                                            if v == 0.0f64 {
-                                                0.0
-                                            } else {
-                                                1.0
-                                            }
-                                        }
-                                        op!("~") => (!(v as i32)) as f64,
-                                        _ => return Err(()),
-                                    },
-                                }))
-                            }
-                            TsLit::Str(_) => {}
-                            TsLit::Bool(_) => {}
-                            TsLit::Tpl(_) => {}
-                        }
-                    }
-
-                    Expr::Tpl(ref t) if t.exprs.is_empty() => {
-                        if let Some(v) = &t.quasis[0].cooked {
-                            return Ok(v.clone().into());
-                        }
-                    }
-
-                    _ => {}
-                }
-            } else if let Some(value) = default {
-                return Ok(TsLit::Number(Number {
-                    span,
-                    value: value as _,
-                }));
-            }
-
-            Err(())
-        }
-
         // let Foo;
         // (function (Foo) {
         //     Foo[Foo["a"] = 0] = "a";
@@ -934,50 +955,51 @@ where
             .into_iter()
             .map(|m| -> Result<_, ()> {
                 let id_span = m.id.span();
-                let val = compute(&e, id_span, &mut values, Some(default), m.init.as_deref())
-                    .map(|val| {
-                        if let TsLit::Number(n) = val {
-                            default = n.value as i64 + 1;
-                        }
-                        values.insert(
-                            match &m.id {
-                                TsEnumMemberId::Ident(i) => i.sym.clone(),
-                                TsEnumMemberId::Str(s) => s.value.clone(),
-                            },
-                            Some(val.clone()),
-                        );
-
-                        match val {
-                            TsLit::Number(v) => Expr::Lit(Lit::Num(v)),
-                            TsLit::Str(v) => Expr::Lit(Lit::Str(v)),
-                            TsLit::Bool(v) => Expr::Lit(Lit::Bool(v)),
-                            TsLit::Tpl(v) => {
-                                Expr::Lit(Lit::Str(v.quasis.into_iter().next().unwrap().raw))
+                let val =
+                    compute_enum_value(&e, id_span, &mut values, Some(default), m.init.as_deref())
+                        .map(|val| {
+                            if let TsLit::Number(n) = val {
+                                default = n.value as i64 + 1;
                             }
-                            TsLit::BigInt(v) => Expr::Lit(Lit::BigInt(v)),
-                        }
-                    })
-                    .or_else(|err| match &m.init {
-                        None => Err(err),
-                        Some(v) => {
-                            let mut v = *v.clone();
-                            let mut visitor = EnumValuesVisitor {
-                                previous: &values,
-                                ident: &id,
-                            };
-                            visitor.visit_mut_expr(&mut v);
-
                             values.insert(
                                 match &m.id {
                                     TsEnumMemberId::Ident(i) => i.sym.clone(),
                                     TsEnumMemberId::Str(s) => s.value.clone(),
                                 },
-                                None,
+                                Some(val.clone()),
                             );
 
-                            Ok(v)
-                        }
-                    })?;
+                            match val {
+                                TsLit::Number(v) => Expr::Lit(Lit::Num(v)),
+                                TsLit::Str(v) => Expr::Lit(Lit::Str(v)),
+                                TsLit::Bool(v) => Expr::Lit(Lit::Bool(v)),
+                                TsLit::Tpl(v) => {
+                                    Expr::Lit(Lit::Str(v.quasis.into_iter().next().unwrap().raw))
+                                }
+                                TsLit::BigInt(v) => Expr::Lit(Lit::BigInt(v)),
+                            }
+                        })
+                        .or_else(|err| match &m.init {
+                            None => Err(err),
+                            Some(v) => {
+                                let mut v = *v.clone();
+                                let mut visitor = EnumValuesVisitor {
+                                    previous: &values,
+                                    ident: &id,
+                                };
+                                visitor.visit_mut_expr(&mut v);
+
+                                values.insert(
+                                    match &m.id {
+                                        TsEnumMemberId::Ident(i) => i.sym.clone(),
+                                        TsEnumMemberId::Str(s) => s.value.clone(),
+                                    },
+                                    None,
+                                );
+
+                                Ok(v)
+                            }
+                        })?;
 
                 Ok((m, val))
             })
@@ -1788,6 +1810,9 @@ where
     fn visit_binding_ident(&mut self, n: &BindingIdent) {
         if !self.in_var_pat {
             n.visit_children_with(self)
+        } else if let ImportsNotUsedAsValues::Verbatim = self.config.import_not_used_as_values {
+            // Types of variables are only needed to report imports used as types.
+            n.type_ann.visit_with(self)
         }
     }
 
@@ -1875,7 +1900,23 @@ where
         }
     }
 
-    visit_obj_and_computed!();
+    fn visit_member_expr(&mut self, n: &MemberExpr) {
+        // Inlined members of `const enum`s don't reference the enum.
+        if self.const_enum_member_value(n).is_some() {
+            return;
+        }
+
+        n.obj.visit_with(self);
+        if let MemberProp::Computed(c) = &n.prop {
+            c.visit_with(self);
+        }
+    }
+
+    fn visit_super_prop_expr(&mut self, n: &SuperPropExpr) {
+        if let SuperProp::Computed(c) = &n.prop {
+            c.visit_with(self);
+        }
+    }
 
     fn visit_module_items(&mut self, n: &[ModuleItem]) {
         let old = self.non_top_level;
@@ -2024,9 +2065,20 @@ where
         members.visit_mut_children_with(self);
     }
 
+    fn visit_mut_class_method(&mut self, method: &mut ClassMethod) {
+        method.visit_mut_children_with(self);
+        method.is_optional = false;
+        method.is_override = false;
+    }
+
     fn visit_mut_class_prop(&mut self, prop: &mut ClassProp) {
         prop.visit_mut_children_with(self);
         prop.readonly = false;
+        prop.declare = false;
+        prop.is_abstract = false;
+        prop.is_optional = false;
+        prop.is_override = false;
+        prop.definite = false;
     }
 
     fn visit_mut_constructor(&mut self, n: &mut Constructor) {
@@ -2124,6 +2176,29 @@ where
             ImportSpecifier::Default(ImportDefaultSpecifier { ref local, .. })
             | ImportSpecifier::Named(ImportNamedSpecifier { ref local, .. })
             | ImportSpecifier::Namespace(ImportStarAsSpecifier { ref local, .. }) => {
+                if let ImportsNotUsedAsValues::Verbatim = self.config.import_not_used_as_values {
+                    if let Some(&DeclInfo {
+                        has_type: true,
+                        has_concrete: false,
+                        ..
+                    }) = self.scope.referenced_idents.get(&local.to_id())
+                    {
+                        HANDLER.with(|handler| {
+                            handler
+                                .struct_span_err(
+                                    local.span,
+                                    &format!(
+                                        "`{}` is only used as a type, so it must be imported \
+                                         using a type-only import",
+                                        local.sym
+                                    ),
+                                )
+                                .emit()
+                        });
+                    }
+                    return true;
+                }
+
                 if let Some(jsx) = &self.jsx {
                     if local.sym == jsx.pragma_id.0 || local.sym == jsx.pragma_frag_id.0 {
                         return true;
@@ -2154,12 +2229,14 @@ where
         if import.specifiers.is_empty() && !self.is_side_effect_import {
             self.is_side_effect_import = match self.config.import_not_used_as_values {
                 ImportsNotUsedAsValues::Remove => false,
-                ImportsNotUsedAsValues::Preserve => true,
+                ImportsNotUsedAsValues::Preserve | ImportsNotUsedAsValues::Verbatim => true,
             };
         }
     }
 
     fn visit_mut_module(&mut self, module: &mut Module) {
+        self.collect_const_enums(&*module);
+
         let was_module = module
             .body
             .iter()
@@ -2350,6 +2427,16 @@ where
                     }
                 }
 
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::TsEnum(ref e),
+                    ..
+                }))
+                | ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(ref e)))
+                    if self.is_inlined_const_enum(e) =>
+                {
+                    continue
+                }
+
                 ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
                     decl: Decl::TsEnum(e),
                     ..
@@ -2545,6 +2632,8 @@ where
     }
 
     fn visit_mut_script(&mut self, n: &mut Script) {
+        self.collect_const_enums(&*n);
+
         self.parse_jsx_directives(n.span);
 
         for item in &n.body {
@@ -2605,6 +2694,8 @@ where
                     stmts.push(init)
                 }
 
+                Stmt::Decl(Decl::TsEnum(ref e)) if self.is_inlined_const_enum(e) => continue,
+
                 Stmt::Decl(Decl::TsEnum(e)) => {
                     let (decl, init) = self.handle_enum(e, None);
                     stmts.extend(decl.map(Stmt::Decl));
@@ -2718,6 +2809,361 @@ fn create_prop_pat(obj: &Ident, pat: Pat) -> Pat {
     }
 }
 
+/// Called only for enums.
+///
+/// If both of the default value and the initialization is None, this
+/// method returns [Err].
+fn compute_enum_value(
+    e: &TsEnumDecl,
+    span: Span,
+    values: &mut EnumValues,
+    default: Option<i64>,
+    init: Option<&Expr>,
+) -> Result<TsLit, ()> {
+    fn compute_bin(
+        e: &TsEnumDecl,
+        span: Span,
+        values: &mut EnumValues,
+        expr: &BinExpr,
+    ) -> Result<TsLit, ()> {
+        let l = compute_enum_value(e, span, values, None, Some(&expr.left))?;
+        let r = compute_enum_value(e, span, values, None, Some(&expr.right))?;
+
+        Ok(match (l, r) {
+            (TsLit::Number(Number { value: l, .. }), TsLit::Number(Number { value: r, .. })) => {
+                TsLit::Number(Number {
+                    span,
+                    value: match expr.op {
+                        op!(bin, "+") => l + r,
+                        op!(bin, "-") => l - r,
+                        op!("*") => l * r,
+                        op!("/") => l / r,
+
+                        // TODO
+                        op!("&") => ((l.round() as i64) & (r.round() as i64)) as _,
+                        op!("|") => ((l.round() as i64) | (r.round() as i64)) as _,
+                        op!("^") => ((l.round() as i64) ^ (r.round() as i64)) as _,
+
+                        op!("<<") => ((l.round() as i64) << (r.round() as i64)) as _,
+                        op!(">>") => ((l.round() as i64) >> (r.round() as i64)) as _,
+                        // TODO: Verify this
+                        op!(">>>") => ((l.round() as u64) >> (r.round() as u64)) as _,
+                        _ => return Err(()),
+                    },
+                })
+            }
+            (TsLit::Str(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
+                span,
+                value: format!("{}{}", l.value, r.value).into(),
+                has_escape: l.has_escape || r.has_escape,
+                kind: Default::default(),
+            }),
+            (TsLit::Number(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
+                span,
+                value: format!("{}{}", l.value, r.value).into(),
+                has_escape: r.has_escape,
+                kind: Default::default(),
+            }),
+            (TsLit::Str(l), TsLit::Number(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
+                span,
+                value: format!("{}{}", l.value, r.value).into(),
+                has_escape: l.has_escape,
+                kind: Default::default(),
+            }),
+            _ => return Err(()),
+        })
+    }
+
+    if let Some(expr) = init {
+        match expr {
+            Expr::Lit(Lit::Str(s)) => return Ok(TsLit::Str(s.clone())),
+            Expr::Lit(Lit::Num(s)) => return Ok(TsLit::Number(*s)),
+            Expr::Bin(ref bin) => return compute_bin(e, span, values, bin),
+            Expr::Paren(ref paren) => {
+                return compute_enum_value(e, span, values, default, Some(&paren.expr))
+            }
+
+            Expr::Ident(ref id) => {
+                if let Some(Some(v)) = values.get(&id.sym) {
+                    return Ok(v.clone());
+                }
+                //
+                for m in e.members.iter() {
+                    match m.id {
+                        TsEnumMemberId::Str(Str { value: ref sym, .. })
+                        | TsEnumMemberId::Ident(Ident { ref sym, .. }) => {
+                            if *sym == id.sym {
+                                return compute_enum_value(
+                                    e,
+                                    span,
+                                    values,
+                                    None,
+                                    m.init.as_deref(),
+                                );
+                            }
+                        }
+                    }
+                }
+                return Err(());
+            }
+            Expr::Unary(ref expr) => {
+                let v = compute_enum_value(e, span, values, None, Some(&expr.arg))?;
+                match v {
+                    TsLit::BigInt(BigInt { .. }) => {}
+                    TsLit::Number(Number { value: v, .. }) => {
+                        return Ok(TsLit::Number(Number {
+                            span,
+                            value: match expr.op {
+                                op!(unary, "+") => v,
+                                op!(unary, "-") => -v,
+                                op!("!") => {
+                                    if v == 0.0f64 {
+                                        0.0
+                                    } else {
+                                        1.0
+                                    }
+                                }
+                                op!("~") => (!(v as i32)) as f64,
+                                _ => return Err(()),
+                            },
+                        }))
+                    }
+                    TsLit::Str(_) => {}
+                    TsLit::Bool(_) => {}
+                    TsLit::Tpl(_) => {}
+                }
+            }
+
+            Expr::Tpl(ref t) if t.exprs.is_empty() => {
+                if let Some(v) = &t.quasis[0].cooked {
+                    return Ok(v.clone().into());
+                }
+            }
+
+            _ => {}
+        }
+    } else if let Some(value) = default {
+        return Ok(TsLit::Number(Number {
+            span,
+            value: value as _,
+        }));
+    }
+
+    Err(())
+}
+
+/// Computes the values of the members of a `const enum`. Members of the enum
+/// itself and of `known` enums are inlined into initializers first.
+///
+/// Returns the span of the first member which cannot be computed on failure.
+fn const_enum_values(
+    e: &TsEnumDecl,
+    known: &InlinableConstEnums,
+) -> Result<AHashMap<JsWord, TsLit>, Span> {
+    let mut default = 0;
+    let mut values = EnumValues::default();
+
+    for m in &e.members {
+        let name = match &m.id {
+            TsEnumMemberId::Ident(i) => i.sym.clone(),
+            TsEnumMemberId::Str(s) => s.value.clone(),
+        };
+
+        let init = m.init.as_deref().map(|init| {
+            let mut init = init.clone();
+            init.visit_mut_with(&mut ConstEnumRefInliner {
+                value: &|member: &MemberExpr| match &*member.obj {
+                    Expr::Ident(i) if i.to_id() == e.id.to_id() => {
+                        values.get(prop_name(&member.prop)?).cloned().flatten()
+                    }
+                    _ => known.member_value(member).cloned(),
+                },
+            });
+            init
+        });
+
+        let val = compute_enum_value(e, m.id.span(), &mut values, Some(default), init.as_ref())
+            .map_err(|_| m.span)?;
+        if let TsLit::Number(n) = &val {
+            default = n.value as i64 + 1;
+        }
+        values.insert(name, Some(val));
+    }
+
+    Ok(values
+        .into_iter()
+        .filter_map(|(name, val)| Some((name, val?)))
+        .collect())
+}
+
+/// Replaces members of `const enum`s with their values.
+struct ConstEnumRefInliner<'a> {
+    value: &'a dyn Fn(&MemberExpr) -> Option<TsLit>,
+}
+
+impl VisitMut for ConstEnumRefInliner<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        if let Expr::Member(m) = e {
+            if let Some(value) = (self.value)(m) {
+                *e = const_enum_value_to_expr(m.span, value);
+                return;
+            }
+        }
+
+        e.visit_mut_children_with(self);
+    }
+}
+
+fn const_enum_value_to_expr(span: Span, value: TsLit) -> Expr {
+    match value {
+        TsLit::Number(Number { value, .. }) if value.is_sign_negative() && value != 0.0 => {
+            Expr::Unary(UnaryExpr {
+                span,
+                op: op!(unary, "-"),
+                arg: Box::new(Expr::Lit(Lit::Num(Number {
+                    span: DUMMY_SP,
+                    value: -value,
+                }))),
+            })
+        }
+        TsLit::Number(v) => Expr::Lit(Lit::Num(Number { span, ..v })),
+        TsLit::Str(v) => Expr::Lit(Lit::Str(Str { span, ..v })),
+        TsLit::Bool(v) => Expr::Lit(Lit::Bool(Bool { span, ..v })),
+        TsLit::Tpl(v) => Expr::Lit(Lit::Str(v.quasis.into_iter().next().unwrap().raw)),
+        TsLit::BigInt(v) => Expr::Lit(Lit::BigInt(BigInt { span, ..v })),
+    }
+}
+
+/// Collects the values of exported `const enum`s of a module, so they can be
+/// inlined into other modules using [Config::const_enums].
+///
+/// `imported` is used to compute members which refer to `const enum`s of
+/// other modules. Enums with members which cannot be computed are skipped.
+pub fn collect_const_enums(module: &Module, imported: &ConstEnums) -> ModuleConstEnums {
+    let mut v = ConstEnumCollector {
+        external: imported,
+        enums: Default::default(),
+        invalid: Default::default(),
+    };
+    module.visit_with(&mut v);
+
+    module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::TsEnum(e),
+                ..
+            })) if e.is_const => {
+                Some((e.id.sym.clone(), v.enums.enums.get(&e.id.to_id())?.clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+struct ConstEnumCollector<'a> {
+    /// [Config::const_enums]
+    external: &'a ConstEnums,
+    enums: InlinableConstEnums,
+    /// Members of `const enum`s which cannot be computed.
+    invalid: Vec<Span>,
+}
+
+impl Visit for ConstEnumCollector<'_> {
+    noop_visit_type!();
+
+    fn visit_ts_enum_decl(&mut self, e: &TsEnumDecl) {
+        if e.is_const {
+            match const_enum_values(e, &self.enums) {
+                Ok(values) => {
+                    self.enums.enums.insert(e.id.to_id(), values);
+                }
+                Err(span) => self.invalid.push(span),
+            }
+        }
+    }
+
+    fn visit_import_decl(&mut self, i: &ImportDecl) {
+        let module = match self.external.get(&i.src.value) {
+            Some(module) => module,
+            None => return,
+        };
+
+        for s in &i.specifiers {
+            match s {
+                ImportSpecifier::Named(s) => {
+                    let imported = match &s.imported {
+                        Some(ModuleExportName::Ident(i)) => &i.sym,
+                        Some(ModuleExportName::Str(s)) => &s.value,
+                        None => &s.local.sym,
+                    };
+
+                    if let Some(values) = module.get(imported) {
+                        self.enums.enums.insert(s.local.to_id(), values.clone());
+                    }
+                }
+                ImportSpecifier::Namespace(s) => {
+                    self.enums
+                        .namespaces
+                        .insert(s.local.to_id(), module.clone());
+                }
+                ImportSpecifier::Default(..) => {}
+            }
+        }
+    }
+}
+
+/// Finds `const enum`s which are used as values, instead of only having their
+/// members accessed.
+struct ConstEnumValueUses<'a> {
+    enums: &'a InlinableConstEnums,
+    used: AHashSet<Id>,
+}
+
+impl ConstEnumValueUses<'_> {
+    fn mark(&mut self, i: &Ident) {
+        let id = i.to_id();
+        if self.enums.enums.contains_key(&id) {
+            self.used.insert(id);
+        }
+    }
+}
+
+impl Visit for ConstEnumValueUses<'_> {
+    noop_visit_type!();
+
+    fn visit_expr(&mut self, e: &Expr) {
+        match e {
+            Expr::Ident(i) => self.mark(i),
+            Expr::Member(m) if self.enums.member_value(m).is_some() => {}
+            _ => e.visit_children_with(self),
+        }
+    }
+
+    fn visit_prop(&mut self, p: &Prop) {
+        match p {
+            Prop::Shorthand(i) => self.mark(i),
+            _ => p.visit_children_with(self),
+        }
+    }
+
+    fn visit_export_named_specifier(&mut self, s: &ExportNamedSpecifier) {
+        if let ModuleExportName::Ident(i) = &s.orig {
+            self.mark(i);
+        }
+    }
+
+    fn visit_named_export(&mut self, e: &NamedExport) {
+        // Re-exports don't refer to local bindings.
+        if e.src.is_none() {
+            e.visit_children_with(self);
+        }
+    }
+}
+
 struct EnumValuesVisitor<'a> {
     ident: &'a Ident,
     previous: &'a EnumValues,
@@ -0,0 +1,250 @@
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms_testing::{test, Tester};
+use swc_ecma_transforms_typescript::dts;
+
+fn syntax() -> Syntax {
+    Syntax::Typescript(Default::default())
+}
+
+fn test_error(src: &str) {
+    Tester::run(|tester| {
+        tester.apply_transform(dts(), "input.ts", syntax(), src)?;
+
+        if tester.handler.has_errors() {
+            return Err(());
+        }
+
+        Ok(())
+    })
+}
+
+test!(
+    syntax(),
+    |_| dts(),
+    function_decl,
+    "export function add(a: number, b = 1, { c }: { c: string } = { c: '' }): number {
+        return a + b;
+    }
+    export async function load(): Promise<void> {
+        await fetch();
+    }",
+    "export declare function add(a: number, b?: number, { c }?: { c: string }): number;
+    export declare function load(): Promise<void>;"
+);
+
+test!(
+    syntax(),
+    |_| dts(),
+    function_overloads,
+    "export function f(a: string): string;
+    export function f(a: number): number;
+    export function f(a: any): any {
+        return a;
+    }",
+    "export declare function f(a: string): string;
+    export declare function f(a: number): number;"
+);
+
+test!(
+    syntax(),
+    |_| dts(),
+    var_decl,
+    "export const a = 1;
+    export let b = 'b';
+    export const c: Foo = create();
+    export var d = -1;",
+    "export declare const a = 1;
+    export declare let b: string;
+    export declare const c: Foo;
+    export declare var d: number;"
+);
+
+test!(
+    syntax(),
+    |_| dts(),
+    class_decl,
+    "export class A<T> extends B<T> implements I {
+        private secret: string = '';
+        #hidden = 1;
+        static count = 0;
+        readonly kind = 'a';
+        value: T;
+
+        constructor(public name: string, private id: number, size = 1) {
+            super();
+        }
+
+        get size(): number {
+            return 1;
+        }
+
+        set size(v: number) {}
+
+        method(): void {}
+
+        private helper() {}
+
+        static {
+            init();
+        }
+    }",
+    "export declare class A<T> extends B<T> implements I {
+        static count: number;
+        readonly kind = 'a';
+        value: T;
+        name: string;
+        constructor(name: string, id: number, size?: number);
+        get size(): number;
+        set size(v: number);
+        method(): void;
+    }"
+);
+
+test!(
+    syntax(),
+    |_| dts(),
+    referenced_locals,
+    "interface Options {
+        value: Value;
+    }
+    type Value = string;
+    interface Unused {}
+    const helper = () => {};
+    export function create(options: Options): void {
+        helper();
+    }",
+    "interface Options {
+        value: Value;
+    }
+    type Value = string;
+    export declare function create(options: Options): void;"
+);
+
+test!(
+    syntax(),
+    |_| dts(),
+    local_named_export,
+    "function a(): void {}
+    function b(): void {}
+    export { a };",
+    "declare function a(): void;
+    export { a };"
+);
+
+test!(
+    syntax(),
+    |_| dts(),
+    namespace,
+    "export namespace NS {
+        export const a: number = 1;
+        export function f(): void {}
+        console.log(a);
+    }",
+    "export declare namespace NS {
+        export const a: number;
+        export function f(): void;
+    }"
+);
+
+test!(
+    syntax(),
+    |_| dts(),
+    types_and_enums,
+    "import { Foo } from './foo';
+    export interface I {
+        foo: Foo;
+    }
+    export type T = I | null;
+    export enum E {
+        A,
+        B = 2,
+    }
+    export * from './bar';
+    run();",
+    "import { Foo } from './foo';
+    export interface I {
+        foo: Foo;
+    }
+    export type T = I | null;
+    export declare enum E {
+        A,
+        B = 2,
+    }
+    export * from './bar';"
+);
+
+test!(
+    syntax(),
+    |_| dts(),
+    drop_statements,
+    "import './side-effect';
+    const a = 1;
+    run();",
+    "import './side-effect';"
+);
+
+test!(
+    syntax(),
+    |_| dts(),
+    default_export,
+    "export default class {
+        m(): number {
+            return 1;
+        }
+    }",
+    "export default class {
+        m(): number;
+    }"
+);
+
+test!(
+    syntax(),
+    |_| dts(),
+    annotated_initializers,
+    "type Id = string;
+    export const add = (a: number, b = 1): number => a + b;
+    export const find = async function <T>(id: Id, ...rest: T[]): Promise<T> {
+        return rest[0];
+    };
+    export const options = {} as Options;
+    export class A {
+        handler = (e: Event): void => {};
+    }",
+    "type Id = string;
+    export declare const add: (a: number, b?: number) => number;
+    export declare const find: <T>(id: Id, ...rest: T[]) => Promise<T>;
+    export declare const options: Options;
+    export declare class A {
+        handler: (e: Event) => void;
+    }"
+);
+
+#[test]
+#[should_panic(expected = "Function must have an explicit return type annotation")]
+fn error_missing_return_type() {
+    test_error("export function f(a: number) { return a; }");
+}
+
+#[test]
+#[should_panic(expected = "Parameter must have an explicit type annotation")]
+fn error_missing_param_type() {
+    test_error("export function f(a): void {}");
+}
+
+#[test]
+#[should_panic(expected = "Variable must have an explicit type annotation")]
+fn error_missing_var_type() {
+    test_error("export const a = create();");
+}
+
+#[test]
+#[should_panic(expected = "Default export of an expression requires type inference")]
+fn error_default_export_expr() {
+    test_error("export default create();");
+}
+
+#[test]
+#[should_panic(expected = "Variable must have an explicit type annotation")]
+fn error_arrow_missing_return_type() {
+    test_error("export const f = (a: number) => a;");
+}
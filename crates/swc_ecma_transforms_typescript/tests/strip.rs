@@ -1,16 +1,17 @@
 use std::path::PathBuf;
 
-use swc_common::{chain, pass::Optional, Mark};
+use swc_common::{chain, collections::AHashMap, errors::HANDLER, pass::Optional, Mark, DUMMY_SP};
+use swc_ecma_ast::{Number, Str, TsLit};
 use swc_ecma_parser::{Syntax, TsConfig};
-use swc_ecma_transforms_base::resolver::resolver_with_mark;
+use swc_ecma_transforms_base::resolver::{resolver_with_mark, ts_resolver};
 use swc_ecma_transforms_compat::{
     es2015::{block_scoping, destructuring, parameters},
     es2017::async_to_generator,
     es2020::{nullish_coalescing, optional_chaining},
 };
 use swc_ecma_transforms_proposal::decorators;
-use swc_ecma_transforms_testing::{test, test_exec, test_fixture};
-use swc_ecma_transforms_typescript::{strip, strip::strip_with_config};
+use swc_ecma_transforms_testing::{test, test_exec, test_fixture, Tester};
+use swc_ecma_transforms_typescript::{strip, strip::strip_with_config, strip_flow};
 use swc_ecma_visit::Fold;
 
 fn tr() -> impl Fold {
@@ -794,7 +795,7 @@ to!(
     "export abstract class Kernel {
   [key: string]: any
 }",
-    "export abstract class Kernel {}"
+    "export class Kernel {}"
 );
 
 to!(
@@ -4480,3 +4481,355 @@ fn exec(input: PathBuf) {
         &output,
     );
 }
+
+macro_rules! to_flow {
+    ($name:ident, $from:expr, $to:expr) => {
+        test!(
+            Syntax::Flow(Default::default()),
+            |_| {
+                let mark = Mark::fresh(Mark::root());
+                chain!(resolver_with_mark(mark), strip_flow(mark))
+            },
+            $name,
+            $from,
+            $to,
+            ok_if_code_eq
+        );
+    };
+}
+
+to_flow!(
+    flow_type_annotations,
+    "
+    type Maybe<+T> = ?T;
+    opaque type Id: string = string;
+    function f(x: ?number, cb: (string, number) => void): {| a: mixed |} {
+        return { a: (x: any) };
+    }
+    ",
+    "
+    function f(x, cb) {
+        return { a: x };
+    }
+    "
+);
+
+to_flow!(
+    flow_imports,
+    "
+    import type { A } from './a';
+    import typeof B from './b';
+    import C from './c';
+    let c: C;
+    ",
+    "
+    import './c';
+    let c;
+    "
+);
+
+to_flow!(
+    flow_class_variance,
+    "
+    class A {
+        +a: string;
+        -b: number;
+        m(x: mixed): boolean %checks {
+            return !!x;
+        }
+    }
+    ",
+    "
+    class A {
+        m(x) {
+            return !!x;
+        }
+    }
+    "
+);
+
+test_with_config!(
+    inline_const_enums,
+    strip::Config {
+        inline_const_enums: true,
+        no_empty_export: true,
+        ..Default::default()
+    },
+    "
+    const enum Direction {
+        Up = 1,
+        Down,
+        Left = -1,
+        Name = 'name',
+    }
+    enum Kept {
+        A,
+    }
+    console.log(Direction.Up, Direction.Down, Direction['Left'], Direction.Name, Kept.A);
+    ",
+    "
+    var Kept;
+    (function(Kept) {
+        Kept[Kept['A'] = 0] = 'A';
+    })(Kept || (Kept = {}));
+    console.log(1, 2, -1, 'name', Kept.A);
+    "
+);
+
+test_with_config!(
+    inline_const_enums_exported,
+    strip::Config {
+        inline_const_enums: true,
+        no_empty_export: true,
+        ..Default::default()
+    },
+    "
+    export const enum Flags {
+        A = 1 << 0,
+        B = 1 << 1,
+        AB = A | B,
+    }
+    export const x = Flags.AB;
+    ",
+    "
+    export const x = 3;
+    "
+);
+
+fn external_const_enums() -> strip::ConstEnums {
+    let mut values = AHashMap::default();
+    values.insert(
+        "Ping".into(),
+        TsLit::Number(Number {
+            span: DUMMY_SP,
+            value: 0.0,
+        }),
+    );
+    values.insert(
+        "Pong".into(),
+        TsLit::Str(Str {
+            span: DUMMY_SP,
+            value: "pong".into(),
+            has_escape: false,
+            kind: Default::default(),
+        }),
+    );
+
+    let mut enums = AHashMap::default();
+    enums.insert("Message".into(), values);
+
+    let mut modules = AHashMap::default();
+    modules.insert("./protocol".into(), enums);
+    modules
+}
+
+test_with_config!(
+    inline_const_enums_imported,
+    strip::Config {
+        inline_const_enums: true,
+        const_enums: external_const_enums(),
+        no_empty_export: true,
+        ..Default::default()
+    },
+    "
+    import { Message as M, other } from './protocol';
+    send(M.Ping, M.Pong, other);
+    ",
+    "
+    import { other } from './protocol';
+    send(0, 'pong', other);
+    "
+);
+
+test_with_config!(
+    inline_const_enums_namespace_import,
+    strip::Config {
+        inline_const_enums: true,
+        const_enums: external_const_enums(),
+        no_empty_export: true,
+        ..Default::default()
+    },
+    "
+    import * as protocol from './protocol';
+    import { Message } from './other';
+    send(protocol.Message.Pong, Message.Ping);
+    ",
+    "
+    import { Message } from './other';
+    send('pong', Message.Ping);
+    "
+);
+
+test_with_config!(
+    inline_const_enums_referencing_enums,
+    strip::Config {
+        inline_const_enums: true,
+        const_enums: external_const_enums(),
+        no_empty_export: true,
+        ..Default::default()
+    },
+    "
+    import { Message } from './protocol';
+    const enum A {
+        X = 1,
+    }
+    const enum B {
+        Y = A.X + 1,
+        Z = B.Y * 2,
+        W = Message.Ping,
+    }
+    f(B.Y, B.Z, B.W);
+    ",
+    "
+    f(2, 4, 0);
+    "
+);
+
+/// Applies `strip` with `config` to `src` and returns the emitted errors.
+fn strip_errors(config: strip::Config, src: &str) -> String {
+    let res = testing::run_test(false, |cm, handler| {
+        HANDLER.set(handler, || {
+            let mut tester = Tester {
+                cm,
+                handler,
+                comments: Default::default(),
+            };
+            let mark = Mark::fresh(Mark::root());
+
+            tester.apply_transform(
+                chain!(ts_resolver(mark), strip_with_config(config, mark)),
+                "input.ts",
+                Syntax::Typescript(Default::default()),
+                src,
+            )?;
+
+            if handler.has_errors() {
+                return Err(());
+            }
+
+            Ok(())
+        })
+    });
+
+    match res {
+        Ok(()) => String::new(),
+        Err(stderr) => stderr.to_string(),
+    }
+}
+
+#[test]
+fn inline_const_enums_not_constant() {
+    let stderr = strip_errors(
+        strip::Config {
+            inline_const_enums: true,
+            no_empty_export: true,
+            ..Default::default()
+        },
+        "
+        const enum E {
+            A = f(),
+        }
+        g(E.A);
+        ",
+    );
+
+    assert!(
+        stderr.contains(
+            "const enum member initializers can only contain literal values and other computed \
+             enum values"
+        ),
+        "{}",
+        stderr
+    );
+}
+
+test_with_config!(
+    inline_const_enums_used_as_value,
+    strip::Config {
+        inline_const_enums: true,
+        no_empty_export: true,
+        ..Default::default()
+    },
+    "
+    const enum E {
+        A,
+        B,
+    }
+    const enum Inlined {
+        C = 2,
+    }
+    f(E.A, Object.keys(E), Inlined.C);
+    ",
+    "
+    var E;
+    (function(E) {
+        E[E['A'] = 0] = 'A';
+        E[E['B'] = 1] = 'B';
+    })(E || (E = {}));
+    f(E.A, Object.keys(E), 2);
+    "
+);
+
+test_with_config!(
+    inline_const_enums_disabled_for_external,
+    strip::Config {
+        const_enums: external_const_enums(),
+        no_empty_export: true,
+        ..Default::default()
+    },
+    "
+    import { Message } from './protocol';
+    send(Message.Ping);
+    ",
+    "
+    import { Message } from './protocol';
+    send(Message.Ping);
+    "
+);
+
+test_with_config!(
+    imports_verbatim,
+    strip::Config {
+        import_not_used_as_values: strip::ImportsNotUsedAsValues::Verbatim,
+        no_empty_export: true,
+        ..Default::default()
+    },
+    "
+    import type { A } from './a';
+    import { type B, c } from './b';
+    import D, { unused } from './d';
+    import { type E } from './e';
+    import * as ns from './ns';
+    c();
+    ",
+    "
+    import { c } from './b';
+    import D, { unused } from './d';
+    import './e';
+    import * as ns from './ns';
+    c();
+    "
+);
+
+#[test]
+fn imports_verbatim_type_used_as_value_import() {
+    let stderr = strip_errors(
+        strip::Config {
+            import_not_used_as_values: strip::ImportsNotUsedAsValues::Verbatim,
+            no_empty_export: true,
+            ..Default::default()
+        },
+        "
+        import { A } from './a';
+        let a: A;
+        ",
+    );
+
+    assert!(
+        stderr.contains(
+            "`A` is only used as a type, so it must be imported using a type-only import"
+        ),
+        "{}",
+        stderr
+    );
+}
@@ -1139,6 +1139,9 @@ pub trait ExprExt {
                 .iter()
                 .filter_map(|e| e.as_ref())
                 .any(|e| e.expr.may_have_side_effects()),
+            Expr::Tuple(TupleLit { ref elems, .. }) => {
+                elems.iter().any(|e| e.expr.may_have_side_effects())
+            }
             Expr::Unary(UnaryExpr { ref arg, .. }) => arg.may_have_side_effects(),
             Expr::Bin(BinExpr {
                 ref left,
@@ -1183,7 +1186,8 @@ pub trait ExprExt {
                     || alt.may_have_side_effects()
             }
 
-            Expr::Object(ObjectLit { ref props, .. }) => props.iter().any(|node| match node {
+            Expr::Object(ObjectLit { ref props, .. })
+            | Expr::Record(RecordLit { ref props, .. }) => props.iter().any(|node| match node {
                 PropOrSpread::Prop(node) => match &**node {
                     Prop::Shorthand(..) => false,
                     Prop::KeyValue(KeyValueProp { ref key, ref value }) => {
@@ -1211,6 +1215,14 @@ pub trait ExprExt {
                 expr.may_have_side_effects()
             }
             Expr::OptChain(ref e) => e.expr.may_have_side_effects(),
+            Expr::Pipeline(PipelineExpr {
+                ref left,
+                ref right,
+                ..
+            }) => left.may_have_side_effects() || right.may_have_side_effects(),
+            Expr::TopicRef(..) => false,
+
+            Expr::Do(..) => true,
 
             Expr::Invalid(..) => true,
         }
@@ -2059,6 +2071,9 @@ pub fn extract_side_effects_to(to: &mut Vec<Box<Expr>>, expr: Box<Expr>) {
             extract_side_effects_to(to, expr)
         }
         Expr::OptChain(e) => extract_side_effects_to(to, e.expr),
+        Expr::Pipeline(..) => to.push(Box::new(expr)),
+        Expr::TopicRef(..) => {}
+        Expr::Do(..) | Expr::Record(..) | Expr::Tuple(..) => to.push(Box::new(expr)),
 
         Expr::Invalid(..) => unreachable!(),
     }
@@ -652,11 +652,36 @@ define!({
         TsAs(TsAsExpr),
         PrivateName(PrivateName),
         OptChain(OptChainExpr),
+        Pipeline(PipelineExpr),
+        TopicRef(TopicRef),
+        Do(DoExpr),
+        Record(RecordLit),
+        Tuple(TupleLit),
         Invalid(Invalid),
     }
     pub struct ThisExpr {
         pub span: Span,
     }
+    pub struct PipelineExpr {
+        pub span: Span,
+        pub left: Box<Expr>,
+        pub right: Box<Expr>,
+    }
+    pub struct TopicRef {
+        pub span: Span,
+    }
+    pub struct DoExpr {
+        pub span: Span,
+        pub body: BlockStmt,
+    }
+    pub struct RecordLit {
+        pub span: Span,
+        pub props: Vec<PropOrSpread>,
+    }
+    pub struct TupleLit {
+        pub span: Span,
+        pub elems: Vec<ExprOrSpread>,
+    }
     pub struct ArrayLit {
         pub span: Span,
         pub elems: Vec<Option<ExprOrSpread>>,
@@ -133,6 +133,7 @@ struct BabelClassMethod<'a> {
 }
 
 #[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
 struct AcornClassMethodValue<'a> {
     /// `FuncionExpression`
     #[serde(rename = "type")]
@@ -147,6 +148,11 @@ struct AcornClassMethodValue<'a> {
     generator: bool,
     #[serde(rename = "async")]
     is_async: bool,
+
+    #[serde(skip_serializing_if = "crate::ser::skip_typescript")]
+    return_type: Option<&'a TypeAnnotOrNoop>,
+    #[serde(skip_serializing_if = "crate::ser::skip_typescript")]
+    type_parameters: Option<&'a TypeParamDeclOrNoop>,
 }
 
 impl Serialize for ClassMethod {
@@ -177,7 +183,7 @@ impl Serialize for ClassMethod {
                 };
                 actual.serialize(serializer)
             }
-            Flavor::Acorn { .. } => {
+            Flavor::Acorn { .. } | Flavor::Espree => {
                 let mut s = serializer.serialize_map(None)?;
 
                 {
@@ -200,8 +206,17 @@ impl Serialize for ClassMethod {
                         params: &self.params,
                         generator: self.generator.unwrap_or(false),
                         is_async: self.is_async.unwrap_or(false),
+                        return_type: self.return_type.as_deref(),
+                        type_parameters: self.type_parameters.as_ref(),
                     },
                 )?;
+                if let Flavor::Espree = Flavor::current() {
+                    s.serialize_entry("abstract", &self.is_abstract.unwrap_or(false))?;
+                    s.serialize_entry("optional", &self.optional.unwrap_or(false))?;
+                    if let Some(accessibility) = &self.accessibility {
+                        s.serialize_entry("accessibility", accessibility)?;
+                    }
+                }
 
                 s.end()
             }
@@ -344,7 +359,7 @@ impl Serialize for ClassProperty {
                 };
                 actual.serialize(serializer)
             }
-            Flavor::Acorn { .. } => {
+            Flavor::Acorn { .. } | Flavor::Espree => {
                 let mut s = serializer.serialize_map(None)?;
 
                 {
@@ -364,6 +379,17 @@ impl Serialize for ClassProperty {
                     }
                 }
                 s.serialize_entry("computed", &self.computed)?;
+                if let Flavor::Espree = Flavor::current() {
+                    s.serialize_entry("typeAnnotation", &self.type_annotation)?;
+                    s.serialize_entry("abstract", &self.is_abstract.unwrap_or(false))?;
+                    s.serialize_entry("declare", &self.declare.unwrap_or(false))?;
+                    s.serialize_entry("definite", &self.definite.unwrap_or(false))?;
+                    s.serialize_entry("optional", &self.optional.unwrap_or(false))?;
+                    s.serialize_entry("readonly", &self.readonly.unwrap_or(false))?;
+                    if let Some(accessibility) = &self.accessibility {
+                        s.serialize_entry("accessibility", accessibility)?;
+                    }
+                }
 
                 s.end()
             }
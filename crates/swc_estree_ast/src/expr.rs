@@ -95,6 +95,8 @@ pub enum Expression {
     Bind(BindExpression),
     #[tag("PipelinePrimaryTopicReference")]
     PipelinePrimaryTopicRef(PipelinePrimaryTopicReference),
+    #[tag("TopicReference")]
+    TopicRef(TopicReference),
     #[tag("DoExpression")]
     Do(DoExpression),
     #[tag("RecordExpression")]
@@ -222,6 +224,8 @@ pub enum BinaryExprOp {
     GreaterThanOrEqual,
     #[serde(rename = "<=")]
     LessThanOrEqual,
+    #[serde(rename = "|>")]
+    Pipeline,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -632,6 +636,13 @@ pub struct PipelinePrimaryTopicReference {
     pub base: BaseNode,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[ast_serde("TopicReference")]
+pub struct TopicReference {
+    #[serde(flatten)]
+    pub base: BaseNode,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[ast_serde("DoExpression")]
 pub struct DoExpression {
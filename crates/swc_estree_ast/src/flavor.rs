@@ -6,7 +6,13 @@ scoped_thread_local!(static FLAVOR: Flavor);
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum Flavor {
     Babel,
-    Acorn { extra_comments: bool },
+    Acorn {
+        extra_comments: bool,
+    },
+    /// Output of `espree`, the parser of ESLint.
+    ///
+    /// Same as [Flavor::Acorn], but typescript nodes are preserved.
+    Espree,
 }
 
 impl Default for Flavor {
@@ -43,14 +49,15 @@ impl Flavor {
     where
         T: IsEmpty,
     {
-        matches!(Self::current(), Flavor::Acorn { .. }) && v.is_empty()
+        matches!(Self::current(), Flavor::Acorn { .. } | Flavor::Espree) && v.is_empty()
     }
 
     pub(crate) fn skip_none<T>(v: &Option<T>) -> bool {
-        matches!(Self::current(), Flavor::Acorn { .. }) && v.is_none()
+        matches!(Self::current(), Flavor::Acorn { .. } | Flavor::Espree) && v.is_none()
     }
     pub(crate) fn skip_none_and_false(v: &Option<bool>) -> bool {
-        matches!(Self::current(), Flavor::Acorn { .. }) && matches!(v, None | Some(false))
+        matches!(Self::current(), Flavor::Acorn { .. } | Flavor::Espree)
+            && matches!(v, None | Some(false))
     }
 }
 
@@ -18,14 +18,14 @@ where
 
 pub(crate) fn skip_expression_for_fn<T>(_: T) -> bool {
     match Flavor::current() {
-        Flavor::Acorn { .. } => false,
+        Flavor::Acorn { .. } | Flavor::Espree => false,
         Flavor::Babel => true,
     }
 }
 
 pub(crate) fn skip_interpreter<T>(_: T) -> bool {
     match Flavor::current() {
-        Flavor::Acorn { .. } => true,
+        Flavor::Acorn { .. } | Flavor::Espree => true,
         Flavor::Babel => false,
     }
 }
@@ -33,7 +33,7 @@ pub(crate) fn skip_interpreter<T>(_: T) -> bool {
 pub(crate) fn skip_typescript<T>(_: T) -> bool {
     match Flavor::current() {
         Flavor::Acorn { .. } => true,
-        Flavor::Babel => false,
+        Flavor::Babel | Flavor::Espree => false,
     }
 }
 
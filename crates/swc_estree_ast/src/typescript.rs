@@ -430,6 +430,7 @@ pub struct TSThisType {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
 #[serde(tag = "type")]
 pub struct TSFunctionType {
     #[serde(flatten)]
@@ -8,7 +8,7 @@ use swc_ecma_ast::{
 use swc_estree_ast::{
     ClassBody, ClassBodyEl, ClassExpression, ClassMethod as BabelClassMethod, ClassMethodKind,
     ClassPrivateMethod, ClassPrivateProperty, ClassProperty, Decorator as BabelDecorator,
-    StaticBlock as BabelStaticBlock,
+    StaticBlock as BabelStaticBlock, TSDeclareMethod, TSFuncDeclTypeAnnot, TSFuncDeclTypeParams,
 };
 
 impl Babelify for Class {
@@ -48,7 +48,13 @@ impl Babelify for ClassMember {
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         match self {
+            ClassMember::Constructor(c) if c.body.is_none() => {
+                ClassBodyEl::TSMethod(babelify_declare_constructor(c, ctx))
+            }
             ClassMember::Constructor(c) => ClassBodyEl::Method(c.babelify(ctx)),
+            ClassMember::Method(m) if m.function.body.is_none() => {
+                ClassBodyEl::TSMethod(babelify_declare_method(m, ctx))
+            }
             ClassMember::Method(m) => ClassBodyEl::Method(m.babelify(ctx)),
             ClassMember::PrivateMethod(m) => ClassBodyEl::PrivateMethod(m.babelify(ctx)),
             ClassMember::ClassProp(p) => ClassBodyEl::Prop(p.babelify(ctx)),
@@ -138,6 +144,54 @@ impl Babelify for ClassMethod {
     }
 }
 
+/// Babel represents methods without a body, like overloads and abstract
+/// methods, as `TSDeclareMethod`.
+fn babelify_declare_method(m: ClassMethod, ctx: &Context) -> TSDeclareMethod {
+    TSDeclareMethod {
+        base: ctx.base(m.span),
+        decorators: Some(m.function.decorators.babelify(ctx)),
+        computed: Some(m.key.is_computed()),
+        key: m.key.babelify(ctx),
+        type_parameters: m
+            .function
+            .type_params
+            .map(|t| TSFuncDeclTypeParams::Type(t.babelify(ctx))),
+        params: m.function.params.babelify(ctx),
+        return_type: m
+            .function
+            .return_type
+            .map(|t| TSFuncDeclTypeAnnot::Type(Box::alloc().init(t.babelify(ctx)))),
+        is_abstract: Some(m.is_abstract),
+        access: m.accessibility.map(|access| access.babelify(ctx)),
+        accessibility: m.accessibility.map(|access| access.babelify(ctx)),
+        is_async: Some(m.function.is_async),
+        generator: Some(m.function.is_generator),
+        kind: Some(m.kind.babelify(ctx)),
+        optional: Some(m.is_optional),
+        is_static: Some(m.is_static),
+    }
+}
+
+fn babelify_declare_constructor(c: Constructor, ctx: &Context) -> TSDeclareMethod {
+    TSDeclareMethod {
+        base: ctx.base(c.span),
+        decorators: Default::default(),
+        computed: Some(false),
+        key: c.key.babelify(ctx),
+        type_parameters: Default::default(),
+        params: c.params.babelify(ctx),
+        return_type: Default::default(),
+        is_abstract: Some(false),
+        access: c.accessibility.map(|access| access.babelify(ctx)),
+        accessibility: c.accessibility.map(|access| access.babelify(ctx)),
+        is_async: Some(false),
+        generator: Some(false),
+        kind: Some(ClassMethodKind::Constructor),
+        optional: Some(c.is_optional),
+        is_static: Some(false),
+    }
+}
+
 impl Babelify for PrivateMethod {
     type Output = ClassPrivateMethod;
 
@@ -1,8 +1,9 @@
 use crate::babelify::{extract_class_body_span, Babelify, Context};
 use copyless::BoxHelper;
 use swc_estree_ast::{
-    ClassBody, ClassDeclaration, Declaration, FunctionDeclaration, VariableDeclaration,
-    VariableDeclarationKind, VariableDeclarator,
+    ClassBody, ClassDeclaration, Declaration, FunctionDeclaration, TSDeclareFunction,
+    TSFuncDeclTypeAnnot, TSFuncDeclTypeParams, VariableDeclaration, VariableDeclarationKind,
+    VariableDeclarator,
 };
 
 use swc_ecma_ast::{ClassDecl, Decl, FnDecl, VarDecl, VarDeclKind, VarDeclarator};
@@ -13,6 +14,9 @@ impl Babelify for Decl {
     fn babelify(self, ctx: &Context) -> Self::Output {
         match self {
             Decl::Class(d) => Declaration::ClassDecl(d.babelify(ctx)),
+            Decl::Fn(d) if d.function.body.is_none() => {
+                Declaration::TSDeclFunc(babelify_declare_function(d, ctx))
+            }
             Decl::Fn(d) => Declaration::FuncDecl(d.babelify(ctx)),
             Decl::Var(d) => Declaration::VarDecl(d.babelify(ctx)),
             Decl::TsInterface(d) => Declaration::TSInterfaceDecl(d.babelify(ctx)),
@@ -42,6 +46,27 @@ impl Babelify for FnDecl {
     }
 }
 
+/// Babel represents functions without a body, like overloads and ambient
+/// declarations, as `TSDeclareFunction`.
+fn babelify_declare_function(d: FnDecl, ctx: &Context) -> TSDeclareFunction {
+    TSDeclareFunction {
+        base: ctx.base(d.function.span),
+        id: Some(d.ident.babelify(ctx)),
+        type_parameters: d
+            .function
+            .type_params
+            .map(|t| TSFuncDeclTypeParams::Type(t.babelify(ctx))),
+        params: d.function.params.babelify(ctx),
+        return_type: d
+            .function
+            .return_type
+            .map(|t| TSFuncDeclTypeAnnot::Type(Box::alloc().init(t.babelify(ctx)))),
+        is_async: Some(d.function.is_async),
+        declare: Some(d.declare),
+        generator: Some(d.function.is_generator),
+    }
+}
+
 impl Babelify for ClassDecl {
     type Output = ClassDeclaration;
 
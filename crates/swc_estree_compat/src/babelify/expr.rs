@@ -5,21 +5,22 @@ use swc_atoms::js_word;
 use swc_common::{BytePos, Span, Spanned};
 use swc_ecma_ast::{
     ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BinaryOp, BlockStmtOrExpr, CallExpr,
-    Callee, ClassExpr, CondExpr, Expr, ExprOrSpread, FnExpr, Ident, Import, Lit, MemberExpr,
-    MemberProp, MetaPropExpr, MetaPropKind, NewExpr, ObjectLit, ParenExpr, PatOrExpr, PropOrSpread,
-    SeqExpr, SpreadElement, Super, SuperProp, SuperPropExpr, TaggedTpl, ThisExpr, Tpl, TplElement,
-    UnaryExpr, UpdateExpr, YieldExpr,
+    Callee, ClassExpr, CondExpr, DoExpr, Expr, ExprOrSpread, FnExpr, Ident, Import, Lit,
+    MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, NewExpr, ObjectLit, ParenExpr, PatOrExpr,
+    PipelineExpr, PropOrSpread, RecordLit, SeqExpr, SpreadElement, Super, SuperProp, SuperPropExpr,
+    TaggedTpl, ThisExpr, TopicRef, Tpl, TplElement, TupleLit, UnaryExpr, UpdateExpr, YieldExpr,
 };
 use swc_estree_ast::{
     flavor::Flavor, ArrayExprEl, ArrayExpression, ArrowFuncExprBody, ArrowFunctionExpression,
-    AssignmentExpression, AwaitExpression, BinaryExprLeft, BinaryExpression, CallExpression,
-    Callee as BabelCallee, ClassExpression, ConditionalExpression, Expression, FunctionExpression,
-    Import as BabelImport, LVal, Literal, LogicalExpression, MemberExprProp, MemberExpression,
-    MetaProperty, NewExpression, ObjectExprProp, ObjectExpression, ObjectKey, ObjectMember,
-    ParenthesizedExpression, PrivateName, SequenceExpression, SpreadElement as BabelSpreadElement,
+    AssignmentExpression, AwaitExpression, BinaryExprLeft, BinaryExprOp, BinaryExpression,
+    CallExpression, Callee as BabelCallee, ClassExpression, ConditionalExpression, DoExpression,
+    Expression, FunctionExpression, Import as BabelImport, LVal, Literal, LogicalExpression,
+    MemberExprProp, MemberExpression, MetaProperty, NewExpression, ObjectExprProp,
+    ObjectExpression, ObjectKey, ObjectMember, ParenthesizedExpression, PrivateName,
+    RecordExprProp, RecordExpression, SequenceExpression, SpreadElement as BabelSpreadElement,
     Super as BabelSuper, TaggedTemplateExprTypeParams, TaggedTemplateExpression, TemplateElVal,
-    TemplateElement, TemplateLiteral, TemplateLiteralExpr, ThisExpression, UnaryExpression,
-    UpdateExpression, YieldExpression,
+    TemplateElement, TemplateLiteral, TemplateLiteralExpr, ThisExpression, TopicReference,
+    TupleExprEl, TupleExpression, UnaryExpression, UpdateExpression, YieldExpression,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -137,6 +138,16 @@ impl Babelify for Expr {
             }
             Expr::TsAs(a) => ExprOutput::Expr(Box::alloc().init(Expression::TSAs(a.babelify(ctx)))),
             Expr::PrivateName(p) => ExprOutput::Private(p.babelify(ctx)),
+            Expr::TopicRef(t) => {
+                ExprOutput::Expr(Box::alloc().init(Expression::TopicRef(t.babelify(ctx))))
+            }
+            Expr::Do(d) => ExprOutput::Expr(Box::alloc().init(Expression::Do(d.babelify(ctx)))),
+            Expr::Record(r) => {
+                ExprOutput::Expr(Box::alloc().init(Expression::Record(r.babelify(ctx))))
+            }
+            Expr::Tuple(t) => {
+                ExprOutput::Expr(Box::alloc().init(Expression::Tuple(t.babelify(ctx))))
+            }
 
             // TODO(dwoznicki): how does babel handle these?
             Expr::JSXMember(_) => panic!(
@@ -159,6 +170,9 @@ impl Babelify for Expr {
                 "illegal conversion: Cannot convert {:?} to ExprOutput - babel has no equivalent",
                 &self
             ),
+            Expr::Pipeline(p) => {
+                ExprOutput::Expr(Box::alloc().init(Expression::Binary(p.babelify(ctx))))
+            }
             Expr::Invalid(_) => panic!(
                 "illegal conversion: Cannot convert {:?} to ExprOutput - babel has no equivalent",
                 &self
@@ -227,6 +241,29 @@ impl Babelify for ThisExpr {
     }
 }
 
+impl Babelify for PipelineExpr {
+    type Output = BinaryExpression;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        BinaryExpression {
+            base: ctx.base(self.span),
+            operator: BinaryExprOp::Pipeline,
+            left: Box::alloc().init(self.left.babelify(ctx).into()),
+            right: Box::alloc().init(self.right.babelify(ctx).into()),
+        }
+    }
+}
+
+impl Babelify for TopicRef {
+    type Output = TopicReference;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        TopicReference {
+            base: ctx.base(self.span),
+        }
+    }
+}
+
 impl Babelify for ArrayLit {
     type Output = ArrayExpression;
 
@@ -249,6 +286,56 @@ impl Babelify for ObjectLit {
     }
 }
 
+impl Babelify for DoExpr {
+    type Output = DoExpression;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        DoExpression {
+            base: ctx.base(self.span),
+            body: self.body.babelify(ctx),
+        }
+    }
+}
+
+impl Babelify for RecordLit {
+    type Output = RecordExpression;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        RecordExpression {
+            base: ctx.base(self.span),
+            properties: self
+                .props
+                .into_iter()
+                .map(|prop| match prop.babelify(ctx) {
+                    ObjectExprProp::Prop(p) => RecordExprProp::Prop(p),
+                    ObjectExprProp::Spread(s) => RecordExprProp::Spread(s),
+                    ObjectExprProp::Method(_) => {
+                        panic!("illegal conversion: Records can't contain methods")
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Babelify for TupleLit {
+    type Output = TupleExpression;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        TupleExpression {
+            base: ctx.base(self.span),
+            elements: self
+                .elems
+                .into_iter()
+                .map(|elem| match elem.babelify(ctx) {
+                    ArrayExprEl::Spread(s) => TupleExprEl::Spread(s),
+                    ArrayExprEl::Expr(e) => TupleExprEl::Expr(e),
+                })
+                .collect(),
+        }
+    }
+}
+
 impl Babelify for PropOrSpread {
     type Output = ObjectExprProp;
 
@@ -512,7 +599,7 @@ impl Babelify for ArrowExpr {
             is_async: self.is_async,
             expression: match Flavor::current() {
                 Flavor::Babel => Default::default(),
-                Flavor::Acorn { .. } => true,
+                Flavor::Acorn { .. } | Flavor::Espree => true,
             },
             generator: self.is_generator,
             return_type: self
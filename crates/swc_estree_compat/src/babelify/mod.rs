@@ -34,7 +34,10 @@ pub struct Context {
 
 impl Context {
     /// Byte offset starting from the 0. (counted separately for each file)
-    fn offset(&self, span: Span) -> (Option<u32>, Option<u32>) {
+    ///
+    /// For [Flavor::Espree], offsets are in UTF-16 code units, like indices of
+    /// javascript strings.
+    pub(crate) fn offset(&self, span: Span) -> (Option<u32>, Option<u32>) {
         if span.is_dummy() {
             return (None, None);
         }
@@ -45,29 +48,44 @@ impl Context {
         let mut start = span.lo.0 - start_offset.0;
         let mut end = span.hi.0 - start_offset.0;
 
+        let utf16 = matches!(Flavor::current(), Flavor::Espree);
+
         for mb in self.fm.multibyte_chars.iter() {
+            // Characters outside of the BMP are surrogate pairs in UTF-16.
+            let len = if utf16 && mb.bytes == 4 { 2 } else { 1 };
+
             if mb.pos < span.lo {
-                start -= (mb.bytes - 1) as u32;
+                start -= (mb.bytes - len) as u32;
             }
 
             if mb.pos < span.hi {
-                end -= (mb.bytes - 1) as u32;
+                end -= (mb.bytes - len) as u32;
             }
         }
 
         (Some(start), Some(end))
     }
 
-    fn line_col(&self, pos: BytePos) -> Option<LineCol> {
+    pub(crate) fn line_col(&self, pos: BytePos) -> Option<LineCol> {
         let loc = self.cm.lookup_char_pos_with(self.fm.clone(), pos);
 
+        let column = if let Flavor::Espree = Flavor::current() {
+            let line_start = self.fm.line_begin_pos(pos);
+            let lo = (line_start - self.fm.start_pos).0 as usize;
+            let hi = (pos - self.fm.start_pos).0 as usize;
+
+            self.fm.src.get(lo..hi)?.encode_utf16().count()
+        } else {
+            loc.col_display
+        };
+
         Some(LineCol {
             line: loc.line,
-            column: loc.col_display,
+            column,
         })
     }
 
-    fn loc(&self, span: Span) -> Option<Loc> {
+    pub(crate) fn loc(&self, span: Span) -> Option<Loc> {
         if span.is_dummy() {
             return None;
         }
@@ -134,7 +152,7 @@ impl Context {
             start,
             end,
             loc,
-            range: if matches!(Flavor::current(), Flavor::Acorn { .. }) {
+            range: if matches!(Flavor::current(), Flavor::Acorn { .. } | Flavor::Espree) {
                 match (start, end) {
                     (Some(start), Some(end)) => Some([start, end]),
                     _ => None,
@@ -1,11 +1,11 @@
-use crate::babelify::{Babelify, Context};
+use crate::babelify::{module_decl::ModuleDeclOutput, Babelify, Context};
 use serde::{Deserialize, Serialize};
 use swc_common::{comments::Comment, Span};
 use swc_ecma_ast::{Module, ModuleItem, Program, Script};
 use swc_ecma_visit::{Visit, VisitWith};
 use swc_estree_ast::{
-    flavor::Flavor, BaseNode, File, InterpreterDirective, LineCol, Loc, ModuleDeclaration,
-    Program as BabelProgram, SrcType, Statement,
+    flavor::Flavor, BaseNode, File, InterpreterDirective, LineCol, Loc, Program as BabelProgram,
+    SrcType, Statement,
 };
 use swc_node_comments::SwcComments;
 
@@ -27,7 +27,7 @@ impl Babelify for Program {
                 start: program.base.start,
                 end: program.base.end,
                 loc: program.base.loc,
-                range: if matches!(Flavor::current(), Flavor::Acorn { .. }) {
+                range: if matches!(Flavor::current(), Flavor::Acorn { .. } | Flavor::Espree) {
                     match (program.base.start, program.base.end) {
                         (Some(start), Some(end)) => Some([start, end]),
                         _ => None,
@@ -132,7 +132,7 @@ fn has_comment_first_line(sp: Span, ctx: &Context) -> bool {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ModuleItemOutput {
-    ModuleDecl(ModuleDeclaration),
+    ModuleDecl(ModuleDeclOutput),
     Stmt(Statement),
 }
 
@@ -145,7 +145,7 @@ impl Babelify for ModuleItem {
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         match self {
-            ModuleItem::ModuleDecl(d) => ModuleItemOutput::ModuleDecl(d.babelify(ctx).into()),
+            ModuleItem::ModuleDecl(d) => ModuleItemOutput::ModuleDecl(d.babelify(ctx)),
             ModuleItem::Stmt(s) => ModuleItemOutput::Stmt(s.babelify(ctx)),
         }
     }
@@ -156,10 +156,13 @@ impl From<ModuleItemOutput> for Statement {
         match m {
             ModuleItemOutput::Stmt(stmt) => stmt,
             ModuleItemOutput::ModuleDecl(decl) => match decl {
-                ModuleDeclaration::ExportAll(e) => Statement::ExportAllDecl(e),
-                ModuleDeclaration::ExportDefault(e) => Statement::ExportDefaultDecl(e),
-                ModuleDeclaration::ExportNamed(e) => Statement::ExportNamedDecl(e),
-                ModuleDeclaration::Import(i) => Statement::ImportDecl(i),
+                ModuleDeclOutput::ExportAll(e) => Statement::ExportAllDecl(e),
+                ModuleDeclOutput::ExportDefault(e) => Statement::ExportDefaultDecl(e),
+                ModuleDeclOutput::ExportNamed(e) => Statement::ExportNamedDecl(e),
+                ModuleDeclOutput::Import(i) => Statement::ImportDecl(i),
+                ModuleDeclOutput::TsImportEquals(i) => Statement::TSImportEqualsDecl(i),
+                ModuleDeclOutput::TsExportAssignment(a) => Statement::TSExportAssignment(a),
+                ModuleDeclOutput::TsNamespaceExport(e) => Statement::TSNamespaceExportDecl(e),
             },
         }
     }
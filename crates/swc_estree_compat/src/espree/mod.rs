@@ -0,0 +1,146 @@
+//! Output compatible with `espree`, the default parser of ESLint.
+//!
+//! [parse_for_eslint] returns the `Program` node with `range`, `loc`,
+//! `tokens` and `comments`, and the result of scope analysis. Like espree,
+//! ranges and columns are in UTF-16 code units.
+//!
+//! `eslint-scope` objects can't be created from rust, so [ScopeManager]
+//! refers to nodes and identifiers by their `range`. The javascript side of
+//! the parser is expected to build the `scopeManager` of ESLint from it.
+//!
+//! Typescript nodes are emitted in the shape of
+//! `@typescript-eslint/typescript-estree`.
+
+pub use self::{
+    scope::{
+        Definition, DefinitionType, EspreeReference, EspreeScope, ScopeManager, ScopeType, Variable,
+    },
+    token::{EspreeComment, EspreeCommentType, EspreeToken, TokenRegex, TokenType},
+};
+use crate::babelify::{Babelify, Context};
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::Arc;
+use swc_common::{sync::Lrc, Mark, SourceFile, SourceMap, Span};
+use swc_ecma_ast::EsVersion;
+use swc_ecma_parser::{
+    lexer::Lexer, token::Token, Capturing, PResult, Parser, StringInput, Syntax,
+};
+use swc_ecma_semantic::analyze;
+use swc_ecma_transforms_base::resolver::{resolver_with_mark, ts_resolver};
+use swc_ecma_visit::VisitMutWith;
+use swc_estree_ast::flavor::Flavor;
+use swc_node_comments::SwcComments;
+
+mod scope;
+mod token;
+mod typescript;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EspreeOutput {
+    /// The `Program` node.
+    pub ast: Value,
+    pub scope_manager: ScopeManager,
+}
+
+/// Parses `fm` and converts it to the output of `parseForESLint`.
+///
+/// This should be called while `swc_common::GLOBALS` is set, as the program
+/// is processed by the `resolver` pass before the scope analysis.
+pub fn parse_for_eslint(
+    cm: Lrc<SourceMap>,
+    fm: Arc<SourceFile>,
+    syntax: Syntax,
+    target: EsVersion,
+) -> PResult<EspreeOutput> {
+    let comments = SwcComments::default();
+
+    let mut capturing = Capturing::new(Lexer::new(
+        syntax,
+        target,
+        StringInput::from(&*fm),
+        Some(&comments),
+    ));
+    let mut parser = Parser::new_from(capturing.clone());
+    let mut program = parser.parse_program()?;
+    // ESLint can't lint a file with syntax errors.
+    if let Some(err) = parser.take_errors().into_iter().next() {
+        return Err(err);
+    }
+    // `Iterator::take` is picked if this is called as a method.
+    let raw_tokens = Capturing::take(&mut capturing);
+
+    let top_level_mark = Mark::fresh(Mark::root());
+    if matches!(syntax, Syntax::Typescript(..)) {
+        program.visit_mut_with(&mut ts_resolver(top_level_mark));
+    } else {
+        program.visit_mut_with(&mut resolver_with_mark(top_level_mark));
+    }
+
+    // Comments are not attached to nodes by espree.
+    let ctx = Context {
+        fm: fm.clone(),
+        cm,
+        comments: Default::default(),
+    };
+
+    let mut all_comments = comments
+        .leading
+        .iter()
+        .chain(comments.trailing.iter())
+        .flat_map(|v| v.value().clone())
+        .collect::<Vec<_>>();
+    all_comments.sort_by_key(|c| c.span.lo);
+    all_comments.dedup_by_key(|c| c.span.lo);
+
+    // Ranges and locations are in UTF-16 code units while the flavor is set.
+    let (comments, tokens, scope_manager, mut ast) = Flavor::Espree.with(|| {
+        let shebang = raw_tokens.iter().find_map(|t| match &t.token {
+            Token::Shebang(value) => Some(EspreeComment::shebang(&ctx, t.span, value)),
+            _ => None,
+        });
+        let comments = shebang
+            .into_iter()
+            .chain(
+                all_comments
+                    .into_iter()
+                    .map(|c| EspreeComment::from_comment(&ctx, c)),
+            )
+            .collect::<Vec<_>>();
+        let tokens = token::convert_tokens(&ctx, raw_tokens);
+
+        let scope_manager = ScopeManager::new(&ctx, &program, &analyze(&program));
+
+        let ast = serde_json::to_value(&program.babelify(&ctx).program)
+            .expect("failed to serialize the program");
+
+        (comments, tokens, scope_manager, ast)
+    });
+
+    // espree uses the first and the last token as the range of `Program`.
+    if let (Some(first), Some(last)) = (tokens.first(), tokens.last()) {
+        ast["start"] = first.start.into();
+        ast["end"] = last.end.into();
+        ast["range"] = serde_json::json!([first.start, last.end]);
+        ast["loc"] = serde_json::json!({
+            "start": first.loc.start,
+            "end": last.loc.end,
+        });
+    }
+    ast["tokens"] = serde_json::to_value(&tokens).expect("failed to serialize tokens");
+    ast["comments"] = serde_json::to_value(&comments).expect("failed to serialize comments");
+
+    if matches!(syntax, Syntax::Typescript(..)) {
+        typescript::convert(&mut ast);
+    }
+
+    Ok(EspreeOutput { ast, scope_manager })
+}
+
+/// `range` of `span`, in UTF-16 code units.
+fn range(ctx: &Context, span: Span) -> [u32; 2] {
+    let (start, end) = ctx.offset(span);
+
+    [start.unwrap_or_default(), end.unwrap_or_default()]
+}
@@ -0,0 +1,206 @@
+use super::range;
+use crate::babelify::Context;
+use serde::Serialize;
+use swc_atoms::JsWord;
+use swc_common::{collections::AHashMap, Spanned};
+use swc_ecma_ast::Program;
+use swc_ecma_semantic::{BindingKind, ScopeKind, SemanticModel};
+
+/// Data of the `scopeManager` of ESLint.
+///
+/// Scopes, variables and references are referred by their index.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScopeManager {
+    /// `scopes[0]` is the global scope.
+    pub scopes: Vec<EspreeScope>,
+    pub variables: Vec<Variable>,
+    pub references: Vec<EspreeReference>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ScopeType {
+    Global,
+    Module,
+    Function,
+    Block,
+    Catch,
+    Class,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EspreeScope {
+    #[serde(rename = "type")]
+    pub type_: ScopeType,
+    /// `range` of the node which creates this scope.
+    pub block: [u32; 2],
+    pub upper: Option<usize>,
+    pub child_scopes: Vec<usize>,
+    /// Variables declared in this scope.
+    pub variables: Vec<usize>,
+    /// References which occur in this scope.
+    pub references: Vec<usize>,
+    /// References which are not resolved in this scope.
+    pub through: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Variable {
+    pub name: JsWord,
+    pub scope: usize,
+    /// `range` of the declared identifiers.
+    pub identifiers: Vec<[u32; 2]>,
+    pub defs: Vec<Definition>,
+    pub references: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum DefinitionType {
+    CatchClause,
+    ClassName,
+    FunctionName,
+    ImportBinding,
+    Parameter,
+    Variable,
+    TSEnumName,
+    TSModuleName,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Definition {
+    #[serde(rename = "type")]
+    pub type_: DefinitionType,
+    /// `range` of the declared identifier.
+    pub name: [u32; 2],
+    /// `var`, `let` or `const` for [DefinitionType::Variable].
+    pub kind: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EspreeReference {
+    /// `range` of the identifier.
+    pub identifier: [u32; 2],
+    pub from: usize,
+    /// [None] for references to undeclared variables.
+    pub resolved: Option<usize>,
+    pub is_read: bool,
+    pub is_write: bool,
+}
+
+impl ScopeManager {
+    pub(super) fn new(ctx: &Context, program: &Program, model: &SemanticModel) -> Self {
+        let is_module = matches!(program, Program::Module(..));
+        // eslint-scope creates a module scope inside of the global scope.
+        let base = if is_module { 1 } else { 0 };
+
+        let mut scopes = Vec::new();
+        if is_module {
+            scopes.push(EspreeScope {
+                type_: ScopeType::Global,
+                block: range(ctx, program.span()),
+                upper: None,
+                child_scopes: vec![1],
+                variables: Default::default(),
+                references: Default::default(),
+                through: Default::default(),
+            });
+        }
+
+        for (_, scope) in model.scopes() {
+            scopes.push(EspreeScope {
+                type_: match scope.kind {
+                    ScopeKind::Top if is_module => ScopeType::Module,
+                    ScopeKind::Top => ScopeType::Global,
+                    ScopeKind::Fn => ScopeType::Function,
+                    ScopeKind::Block => ScopeType::Block,
+                    ScopeKind::Catch => ScopeType::Catch,
+                    ScopeKind::Class => ScopeType::Class,
+                },
+                block: range(ctx, scope.span),
+                upper: match scope.parent {
+                    Some(parent) => Some(parent.index() + base),
+                    None if is_module => Some(0),
+                    None => None,
+                },
+                child_scopes: scope.children.iter().map(|c| c.index() + base).collect(),
+                variables: Default::default(),
+                references: Default::default(),
+                through: Default::default(),
+            });
+        }
+
+        let mut variables = Vec::new();
+        let mut variable_indices = AHashMap::default();
+
+        for (scope_id, scope) in model.scopes() {
+            for id in &scope.bindings {
+                let binding = match model.binding(id) {
+                    Some(v) => v,
+                    None => continue,
+                };
+
+                let (type_, kind) = match binding.kind {
+                    BindingKind::Var => (DefinitionType::Variable, Some("var")),
+                    BindingKind::Let => (DefinitionType::Variable, Some("let")),
+                    BindingKind::Const => (DefinitionType::Variable, Some("const")),
+                    BindingKind::Function => (DefinitionType::FunctionName, None),
+                    BindingKind::Class => (DefinitionType::ClassName, None),
+                    BindingKind::Param => (DefinitionType::Parameter, None),
+                    BindingKind::CatchParam => (DefinitionType::CatchClause, None),
+                    BindingKind::Import => (DefinitionType::ImportBinding, None),
+                    BindingKind::Enum => (DefinitionType::TSEnumName, None),
+                    BindingKind::Namespace => (DefinitionType::TSModuleName, None),
+                };
+                let name = range(ctx, binding.span);
+
+                let idx = variables.len();
+                scopes[scope_id.index() + base].variables.push(idx);
+                variable_indices.insert(binding.id.clone(), idx);
+
+                variables.push(Variable {
+                    name: binding.id.0.clone(),
+                    scope: scope_id.index() + base,
+                    identifiers: vec![name],
+                    defs: vec![Definition { type_, name, kind }],
+                    references: binding.references.iter().map(|r| r.index()).collect(),
+                });
+            }
+        }
+
+        let mut references = Vec::with_capacity(model.references().len());
+
+        for (idx, r) in model.references().iter().enumerate() {
+            scopes[r.scope.index() + base].references.push(idx);
+
+            let declared_in = model.binding(&r.id).map(|b| b.scope);
+            for scope in model.ancestors(r.scope) {
+                if Some(scope) == declared_in {
+                    break;
+                }
+                scopes[scope.index() + base].through.push(idx);
+            }
+            if is_module && declared_in.is_none() {
+                scopes[0].through.push(idx);
+            }
+
+            references.push(EspreeReference {
+                identifier: range(ctx, r.span),
+                from: r.scope.index() + base,
+                resolved: variable_indices.get(&r.id).copied(),
+                is_read: r.is_read,
+                is_write: r.is_write,
+            });
+        }
+
+        ScopeManager {
+            scopes,
+            variables,
+            references,
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use super::range;
+use crate::babelify::Context;
+use serde::Serialize;
+use swc_atoms::JsWord;
+use swc_common::{
+    comments::{Comment, CommentKind},
+    Span,
+};
+use swc_ecma_parser::token::{Keyword, Token, TokenAndSpan, Word};
+use swc_estree_ast::Loc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum TokenType {
+    Boolean,
+    Null,
+    Numeric,
+    String,
+    RegularExpression,
+    Template,
+    Identifier,
+    PrivateIdentifier,
+    Keyword,
+    Punctuator,
+    JSXIdentifier,
+    JSXText,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TokenRegex {
+    pub pattern: JsWord,
+    pub flags: JsWord,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EspreeToken {
+    #[serde(rename = "type")]
+    pub type_: TokenType,
+    /// Source text of the token.
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regex: Option<TokenRegex>,
+    pub start: u32,
+    pub end: u32,
+    pub range: [u32; 2],
+    pub loc: Loc,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum EspreeCommentType {
+    Line,
+    Block,
+    Shebang,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EspreeComment {
+    #[serde(rename = "type")]
+    pub type_: EspreeCommentType,
+    pub value: String,
+    pub start: u32,
+    pub end: u32,
+    pub range: [u32; 2],
+    pub loc: Loc,
+}
+
+impl EspreeComment {
+    pub(super) fn from_comment(ctx: &Context, c: Comment) -> Self {
+        let range = range(ctx, c.span);
+
+        EspreeComment {
+            type_: match c.kind {
+                CommentKind::Line => EspreeCommentType::Line,
+                CommentKind::Block => EspreeCommentType::Block,
+            },
+            value: c.text,
+            start: range[0],
+            end: range[1],
+            range,
+            loc: ctx.loc(c.span).unwrap_or_else(Loc::dummy),
+        }
+    }
+
+    pub(super) fn shebang(ctx: &Context, span: Span, value: &JsWord) -> Self {
+        let range = range(ctx, span);
+
+        EspreeComment {
+            type_: EspreeCommentType::Shebang,
+            value: value.to_string(),
+            start: range[0],
+            end: range[1],
+            range,
+            loc: ctx.loc(span).unwrap_or_else(Loc::dummy),
+        }
+    }
+}
+
+/// Converts tokens captured by [swc_ecma_parser::Capturing].
+///
+/// Like espree, a template element is a single token including the
+/// surrounding `` ` ``, `${` and `}`, and `#foo` is a single
+/// `PrivateIdentifier` token.
+pub(super) fn convert_tokens(ctx: &Context, tokens: Vec<TokenAndSpan>) -> Vec<EspreeToken> {
+    let mut merged: Vec<(TokenType, Span, Option<TokenRegex>)> = Vec::with_capacity(tokens.len());
+    // `true` if the last token is `` ` `` or `}` which may start a template
+    // element.
+    let mut last_is_template_start = false;
+    let mut last_is_hash = false;
+    let mut last_is_template = false;
+
+    for TokenAndSpan { token, span, .. } in tokens {
+        let last_span = merged.last().map(|v| v.1);
+
+        match &token {
+            Token::Template { .. } => {
+                let mut span = span;
+                if last_is_template_start {
+                    if let Some((_, last, _)) = merged.pop() {
+                        span = span.with_lo(last.lo);
+                    }
+                }
+                merged.push((TokenType::Template, span, None));
+            }
+
+            Token::DollarLBrace | Token::BackQuote if last_is_template => {
+                if let Some(last) = merged.last_mut() {
+                    last.1 = last.1.with_hi(span.hi);
+                }
+            }
+
+            Token::Word(..) if last_is_hash && last_span.map(|s| s.hi) == Some(span.lo) => {
+                if let Some((_, last, _)) = merged.pop() {
+                    merged.push((TokenType::PrivateIdentifier, span.with_lo(last.lo), None));
+                }
+            }
+
+            Token::Regex(pattern, flags) => merged.push((
+                TokenType::RegularExpression,
+                span,
+                Some(TokenRegex {
+                    pattern: pattern.clone(),
+                    flags: flags.clone(),
+                }),
+            )),
+
+            _ => {
+                if let Some(type_) = token_type(&token) {
+                    merged.push((type_, span, None));
+                }
+            }
+        }
+
+        last_is_template_start = matches!(token, Token::BackQuote | Token::RBrace);
+        last_is_hash = matches!(token, Token::Hash);
+        last_is_template = matches!(token, Token::Template { .. });
+    }
+
+    merged
+        .into_iter()
+        .map(|(type_, span, regex)| {
+            let range = range(ctx, span);
+
+            let lo = (span.lo - ctx.fm.start_pos).0 as usize;
+            let hi = (span.hi - ctx.fm.start_pos).0 as usize;
+            let mut value = &ctx.fm.src[lo..hi];
+            if type_ == TokenType::PrivateIdentifier {
+                value = value.trim_start_matches('#');
+            }
+
+            EspreeToken {
+                type_,
+                value: value.to_string(),
+                regex,
+                start: range[0],
+                end: range[1],
+                range,
+                loc: ctx.loc(span).unwrap_or_else(Loc::dummy),
+            }
+        })
+        .collect()
+}
+
+fn token_type(token: &Token) -> Option<TokenType> {
+    Some(match token {
+        Token::Word(Word::Null) => TokenType::Null,
+        Token::Word(Word::True | Word::False) => TokenType::Boolean,
+        // acorn lexes `await` as a name.
+        Token::Word(Word::Keyword(Keyword::Await)) => TokenType::Identifier,
+        Token::Word(Word::Keyword(..)) => TokenType::Keyword,
+        Token::Word(Word::Ident(sym)) if &**sym == "static" => TokenType::Keyword,
+        Token::Word(Word::Ident(..)) => TokenType::Identifier,
+        Token::Str { .. } => TokenType::String,
+        Token::Num(..) | Token::BigInt(..) => TokenType::Numeric,
+        Token::Regex(..) => TokenType::RegularExpression,
+        Token::Template { .. } => TokenType::Template,
+        Token::JSXName { .. } => TokenType::JSXIdentifier,
+        Token::JSXText { .. } => TokenType::JSXText,
+        Token::Shebang(..) | Token::Error(..) => return None,
+        _ => TokenType::Punctuator,
+    })
+}
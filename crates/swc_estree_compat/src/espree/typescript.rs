@@ -0,0 +1,172 @@
+use serde_json::{json, Map, Value};
+
+/// Converts typescript nodes in the shape of babel to the shape of
+/// `typescript-estree`.
+pub(super) fn convert(ast: &mut Value) {
+    convert_value(ast, None);
+}
+
+fn convert_value(v: &mut Value, key: Option<&str>) {
+    match v {
+        Value::Array(items) => {
+            for item in items {
+                convert_value(item, key);
+            }
+        }
+
+        Value::Object(node) => {
+            for (k, child) in node.iter_mut() {
+                convert_value(child, Some(k.as_str()));
+            }
+
+            if let Some(inner) = convert_node(node, key) {
+                *v = inner;
+            }
+        }
+
+        _ => {}
+    }
+}
+
+/// Returns a node which should replace `node`.
+fn convert_node(node: &mut Map<String, Value>, key: Option<&str>) -> Option<Value> {
+    let type_ = node.get("type").and_then(Value::as_str)?.to_string();
+
+    match &*type_ {
+        "TSMethodSignature"
+        | "TSCallSignatureDeclaration"
+        | "TSConstructSignatureDeclaration"
+        | "TSFunctionType"
+        | "TSConstructorType" => {
+            rename(node, "parameters", "params");
+            rename(node, "typeAnnotation", "returnType");
+        }
+
+        "TSExpressionWithTypeArguments" => {
+            let type_ = match key {
+                Some("implements") => "TSClassImplements",
+                _ => "TSInterfaceHeritage",
+            };
+            node.insert("type".into(), type_.into());
+        }
+
+        // Heritage clauses of an interface are an array in `typescript-estree`.
+        "TSInterfaceDeclaration" => {
+            let extends = match node.remove("extends") {
+                Some(Value::Array(v)) => v,
+                Some(Value::Null) | None => vec![],
+                Some(v) => vec![v],
+            };
+            node.insert("extends".into(), extends.into());
+        }
+
+        "MethodDefinition" | "PropertyDefinition" if is_true(node, "abstract") => {
+            let type_ = format!("TSAbstract{}", type_);
+            node.insert("type".into(), type_.into());
+        }
+
+        // Methods without a body.
+        "TSDeclareMethod" => return Some(declare_method(node)),
+
+        // `typescript-estree` does not have parenthesized types.
+        "TSParenthesizedType" => return node.remove("typeAnnotation"),
+
+        "TSTypeParameter" => {
+            if let Some(name) = node.get("name").and_then(Value::as_str) {
+                let name = type_param_name(node, name);
+                node.insert("name".into(), name);
+            }
+        }
+
+        _ => {}
+    }
+
+    None
+}
+
+fn is_true(node: &Map<String, Value>, key: &str) -> bool {
+    node.get(key).and_then(Value::as_bool).unwrap_or(false)
+}
+
+/// Converts `TSDeclareMethod` of babel to a method definition whose value is a
+/// `TSEmptyBodyFunctionExpression`.
+fn declare_method(node: &mut Map<String, Value>) -> Value {
+    let type_ = if is_true(node, "abstract") {
+        "TSAbstractMethodDefinition"
+    } else {
+        "MethodDefinition"
+    };
+
+    let mut value = json!({
+        "type": "TSEmptyBodyFunctionExpression",
+        "id": null,
+        "params": node.remove("params").unwrap_or_else(|| json!([])),
+        "body": null,
+        "async": is_true(node, "async"),
+        "generator": is_true(node, "generator"),
+        "expression": false,
+        "returnType": node.remove("returnType").unwrap_or(Value::Null),
+        "typeParameters": node.remove("typeParameters").unwrap_or(Value::Null),
+    });
+    for key in ["start", "end", "range", "loc"] {
+        if let Some(v) = node.get(key) {
+            value[key] = v.clone();
+        }
+    }
+
+    let mut method = json!({
+        "type": type_,
+        "key": node.remove("key").unwrap_or(Value::Null),
+        "computed": is_true(node, "computed"),
+        "static": is_true(node, "static"),
+        "kind": node.remove("kind").unwrap_or_else(|| "method".into()),
+        "optional": is_true(node, "optional"),
+        "decorators": node.remove("decorators").unwrap_or_else(|| json!([])),
+        "value": value,
+    });
+    for key in ["start", "end", "range", "loc", "accessibility"] {
+        if let Some(v) = node.remove(key) {
+            method[key] = v;
+        }
+    }
+
+    method
+}
+
+fn rename(node: &mut Map<String, Value>, from: &str, to: &str) {
+    if let Some(v) = node.remove(from) {
+        node.insert(to.into(), v);
+    }
+}
+
+/// `typescript-estree` uses an `Identifier` for the name of a type parameter,
+/// which starts at the start of the type parameter.
+fn type_param_name(node: &Map<String, Value>, name: &str) -> Value {
+    let len = name.encode_utf16().count() as u64;
+    let start = node
+        .get("start")
+        .and_then(Value::as_u64)
+        .unwrap_or_default();
+    let loc_start = node.get("loc").map(|loc| loc["start"].clone());
+
+    let mut ident = json!({
+        "type": "Identifier",
+        "name": name,
+        "start": start,
+        "end": start + len,
+        "range": [start, start + len],
+    });
+
+    if let Some(loc_start) = loc_start {
+        let mut loc_end = loc_start.clone();
+        if let Some(column) = loc_start["column"].as_u64() {
+            loc_end["column"] = (column + len).into();
+        }
+        ident["loc"] = json!({
+            "start": loc_start,
+            "end": loc_end,
+        });
+    }
+
+    ident
+}
@@ -5,4 +5,5 @@
 #![feature(never_type)]
 
 pub mod babelify;
+pub mod espree;
 pub mod swcify;
@@ -1,9 +1,11 @@
-use super::Context;
+use super::{Context, ErrorKind};
 use crate::swcify::Swcify;
-use swc_ecma_ast::{ClassMember, Function, MethodKind, ParamOrTsParamProp, TsExprWithTypeArgs};
-use swc_estree_ast::{
-    ClassBody, ClassBodyEl, ClassImpl, ClassMethodKind, TSExpressionWithTypeArguments,
+use swc_common::Span;
+use swc_ecma_ast::{
+    BlockStmt, ClassMember, Function, MethodKind, ParamOrTsParamProp, StaticBlock, TsEntityName,
+    TsExprWithTypeArgs,
 };
+use swc_estree_ast::{ClassBody, ClassBodyEl, ClassImpl, ClassMethodKind, Param, TSDeclareMethod};
 
 impl Swcify for ClassBody {
     type Output = Vec<ClassMember>;
@@ -22,8 +24,23 @@ impl Swcify for ClassBodyEl {
             ClassBodyEl::PrivateMethod(v) => v.swcify(ctx).into(),
             ClassBodyEl::Prop(v) => v.swcify(ctx).into(),
             ClassBodyEl::PrivateProp(v) => v.swcify(ctx).into(),
-            _ => {
-                unimplemented!("swcify: {:?}", self)
+            ClassBodyEl::TSMethod(v) => v.swcify(ctx),
+            ClassBodyEl::TSIndex(v) => ClassMember::TsIndexSignature(v.swcify(ctx)),
+            ClassBodyEl::StaticBlock(v) => {
+                let span = ctx.span(&v.base);
+
+                ClassMember::StaticBlock(StaticBlock {
+                    span,
+                    body: BlockStmt {
+                        span,
+                        stmts: v
+                            .body
+                            .swcify(ctx)
+                            .into_iter()
+                            .map(|v| v.expect_stmt())
+                            .collect(),
+                    },
+                })
             }
         }
     }
@@ -48,17 +65,7 @@ impl Swcify for swc_estree_ast::ClassMethod {
                         type_params: self.type_parameters.swcify(ctx).flatten(),
                         return_type: self.return_type.swcify(ctx).flatten(),
                     },
-                    kind: self
-                        .kind
-                        .map(|kind| match kind {
-                            ClassMethodKind::Get => MethodKind::Getter,
-                            ClassMethodKind::Set => MethodKind::Setter,
-                            ClassMethodKind::Method => MethodKind::Getter,
-                            ClassMethodKind::Constructor => {
-                                unreachable!()
-                            }
-                        })
-                        .unwrap_or(MethodKind::Method),
+                    kind: method_kind(ctx, ctx.span(&self.base), self.kind),
                     is_static: self.is_static.unwrap_or_default(),
                     accessibility: self.accessibility.swcify(ctx),
                     is_abstract: self.is_abstract.unwrap_or_default(),
@@ -70,12 +77,7 @@ impl Swcify for swc_estree_ast::ClassMethod {
             ClassMethodKind::Constructor => swc_ecma_ast::Constructor {
                 span: ctx.span(&self.base),
                 key: self.key.swcify(ctx),
-                params: self
-                    .params
-                    .into_iter()
-                    .map(|v| v.swcify(ctx))
-                    .map(ParamOrTsParamProp::Param)
-                    .collect(),
+                params: constructor_params(self.params, ctx),
                 body: Some(self.body.swcify(ctx)),
                 accessibility: self.accessibility.swcify(ctx),
                 is_optional: self.optional.unwrap_or_default(),
@@ -85,6 +87,48 @@ impl Swcify for swc_estree_ast::ClassMethod {
     }
 }
 
+impl Swcify for TSDeclareMethod {
+    type Output = ClassMember;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        if let Some(ClassMethodKind::Constructor) = self.kind {
+            return swc_ecma_ast::Constructor {
+                span,
+                key: self.key.swcify(ctx),
+                params: constructor_params(self.params, ctx),
+                body: None,
+                accessibility: self.accessibility.swcify(ctx),
+                is_optional: self.optional.unwrap_or_default(),
+            }
+            .into();
+        }
+
+        swc_ecma_ast::ClassMethod {
+            span,
+            key: self.key.swcify(ctx),
+            function: Function {
+                params: self.params.swcify(ctx),
+                decorators: self.decorators.swcify(ctx).unwrap_or_default(),
+                span,
+                body: None,
+                is_generator: self.generator.unwrap_or_default(),
+                is_async: self.is_async.unwrap_or_default(),
+                type_params: self.type_parameters.swcify(ctx).flatten(),
+                return_type: self.return_type.swcify(ctx).flatten(),
+            },
+            kind: method_kind(ctx, span, self.kind),
+            is_static: self.is_static.unwrap_or_default(),
+            accessibility: self.accessibility.swcify(ctx),
+            is_abstract: self.is_abstract.unwrap_or_default(),
+            is_optional: self.optional.unwrap_or_default(),
+            is_override: false,
+        }
+        .into()
+    }
+}
+
 impl Swcify for swc_estree_ast::ClassPrivateMethod {
     type Output = swc_ecma_ast::PrivateMethod;
 
@@ -102,14 +146,7 @@ impl Swcify for swc_estree_ast::ClassPrivateMethod {
                 type_params: self.type_parameters.swcify(ctx).flatten(),
                 return_type: self.return_type.swcify(ctx).flatten(),
             },
-            kind: match self.kind.unwrap_or(ClassMethodKind::Method) {
-                ClassMethodKind::Get => MethodKind::Getter,
-                ClassMethodKind::Set => MethodKind::Setter,
-                ClassMethodKind::Method => MethodKind::Getter,
-                ClassMethodKind::Constructor => {
-                    unreachable!()
-                }
-            },
+            kind: method_kind(ctx, ctx.span(&self.base), self.kind),
             is_static: self.is_static.unwrap_or_default(),
             accessibility: self.accessibility.swcify(ctx),
             is_abstract: self.is_abstract.unwrap_or_default(),
@@ -171,21 +208,38 @@ impl Swcify for ClassImpl {
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
             ClassImpl::TSExpr(v) => v.swcify(ctx),
-            ClassImpl::Implements(_) => {
-                unreachable!()
+            ClassImpl::Implements(v) => {
+                let span = ctx.span(&v.base);
+                ctx.report(span, ErrorKind::Flow);
+                TsExprWithTypeArgs {
+                    span,
+                    expr: TsEntityName::Ident(v.id.swcify(ctx).id),
+                    type_args: None,
+                }
             }
         }
     }
 }
 
-impl Swcify for TSExpressionWithTypeArguments {
-    type Output = TsExprWithTypeArgs;
-
-    fn swcify(self, ctx: &Context) -> Self::Output {
-        TsExprWithTypeArgs {
-            span: ctx.span(&self.base),
-            expr: self.expression.swcify(ctx),
-            type_args: self.type_parameters.swcify(ctx),
+fn method_kind(ctx: &Context, span: Span, kind: Option<ClassMethodKind>) -> MethodKind {
+    match kind.unwrap_or(ClassMethodKind::Method) {
+        ClassMethodKind::Get => MethodKind::Getter,
+        ClassMethodKind::Set => MethodKind::Setter,
+        ClassMethodKind::Method => MethodKind::Method,
+        ClassMethodKind::Constructor => {
+            ctx.report(span, ErrorKind::InvalidNode("constructor"));
+            MethodKind::Method
         }
     }
 }
+
+/// Parameter properties are allowed only for constructors.
+fn constructor_params(params: Vec<Param>, ctx: &Context) -> Vec<ParamOrTsParamProp> {
+    params
+        .into_iter()
+        .map(|v| match v {
+            Param::TSProp(v) => ParamOrTsParamProp::TsParamProp(v.swcify(ctx)),
+            _ => ParamOrTsParamProp::Param(v.swcify(ctx)),
+        })
+        .collect()
+}
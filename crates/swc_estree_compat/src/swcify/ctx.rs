@@ -1,4 +1,5 @@
-use std::sync::Arc;
+use super::{Error, ErrorKind};
+use std::{cell::RefCell, mem::take, sync::Arc};
 use swc_common::{BytePos, FileName, SourceFile, SourceMap, Span, SyntaxContext, DUMMY_SP};
 use swc_estree_ast::{BaseNode, LineCol, Loc};
 use swc_node_comments::SwcComments;
@@ -9,6 +10,7 @@ pub struct Context {
     pub(crate) fm: Arc<SourceFile>,
     #[allow(unused)]
     pub(crate) comments: SwcComments,
+    errors: RefCell<Vec<Error>>,
 }
 
 impl Context {
@@ -66,6 +68,20 @@ impl Context {
         comments: SwcComments,
         fm: Arc<SourceFile>,
     ) -> Self {
-        Self { cm, comments, fm }
+        Self {
+            cm,
+            comments,
+            fm,
+            errors: Default::default(),
+        }
+    }
+
+    /// Takes errors reported while converting nodes.
+    pub fn take_errors(&self) -> Vec<Error> {
+        take(&mut *self.errors.borrow_mut())
+    }
+
+    pub(crate) fn report(&self, span: Span, kind: ErrorKind) {
+        self.errors.borrow_mut().push(Error { span, kind });
     }
 }
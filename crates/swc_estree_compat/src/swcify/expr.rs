@@ -3,32 +3,34 @@ use swc_atoms::js_word;
 use swc_common::{Spanned, DUMMY_SP};
 use swc_ecma_ast::{
     op, ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BinaryOp, BindingIdent,
-    BlockStmtOrExpr, CallExpr, Callee, ClassExpr, ComputedPropName, CondExpr, Expr, ExprOrSpread,
-    FnExpr, Function, Ident, Import, JSXAttr, JSXAttrOrSpread, JSXAttrValue, JSXEmptyExpr, JSXExpr,
-    JSXExprContainer, JSXMemberExpr, JSXObject, KeyValueProp, Lit, MemberExpr, MemberProp,
-    MetaPropExpr, MetaPropKind, MethodProp, NewExpr, ObjectLit, OptChainExpr, ParenExpr, PatOrExpr,
-    Prop, PropName, PropOrSpread, SeqExpr, SpreadElement, SuperProp, SuperPropExpr, TaggedTpl,
-    ThisExpr, TsAsExpr, TsNonNullExpr, TsTypeAssertion, TsTypeParamInstantiation, UnaryExpr,
-    UnaryOp, UpdateExpr, YieldExpr,
+    BlockStmtOrExpr, CallExpr, Callee, ClassExpr, ComputedPropName, CondExpr, DoExpr, Expr,
+    ExprOrSpread, FnExpr, Function, Ident, Import, Invalid, JSXAttr, JSXAttrOrSpread, JSXAttrValue,
+    JSXEmptyExpr, JSXExpr, JSXExprContainer, JSXMemberExpr, JSXObject, KeyValueProp, Lit,
+    MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, MethodProp, NewExpr, ObjectLit,
+    OptChainExpr, ParenExpr, PatOrExpr, PipelineExpr, Prop, PropName, PropOrSpread, RecordLit,
+    SeqExpr, SpreadElement, SuperProp, SuperPropExpr, TaggedTpl, ThisExpr, TopicRef, TsAsExpr,
+    TsNonNullExpr, TsTypeAssertion, TsTypeParamInstantiation, TupleLit, UnaryExpr, UnaryOp,
+    UpdateExpr, YieldExpr,
 };
 use swc_estree_ast::{
-    Arg, ArrayExprEl, ArrayExpression, ArrowFuncExprBody, ArrowFunctionExpression,
-    AssignmentExpression, AwaitExpression, BinaryExprLeft, BinaryExprOp, BinaryExpression,
-    BindExpression, CallExpression, Callee as BabelCallee, ClassExpression, ConditionalExpression,
-    DoExpression, Expression, FunctionExpression, Identifier, Import as BabelImport, JSXAttrVal,
-    JSXAttribute, JSXEmptyExpression, JSXExprContainerExpr, JSXExpressionContainer,
-    JSXMemberExprObject, JSXMemberExpression, JSXSpreadAttribute, Literal, LogicalExprOp,
-    LogicalExpression, MemberExprProp, MemberExpression, MetaProperty, ModuleExpression,
-    NewExpression, ObjectExprProp, ObjectExpression, ObjectKey, ObjectMethod, ObjectPropVal,
-    ObjectProperty, OptionalCallExpression, OptionalMemberExprProp, OptionalMemberExpression,
-    ParenthesizedExpression, PatternLike, PipelinePrimaryTopicReference, RecordExpression,
-    SequenceExpression, TSAsExpression, TSNonNullExpression, TSTypeAssertion,
-    TaggedTemplateExprTypeParams, TaggedTemplateExpression, ThisExpression, TupleExpression,
-    TypeCastExpression, UnaryExprOp, UnaryExpression, UpdateExprOp, UpdateExpression,
-    YieldExpression,
+    Arg, ArrayExprEl, ArrayExpression, ArrayPattern, ArrowFuncExprBody, ArrowFunctionExpression,
+    AssignmentExpression, AssignmentPattern, AwaitExpression, BaseNode, BinaryExprLeft,
+    BinaryExprOp, BinaryExpression, BindExpression, CallExpression, Callee as BabelCallee,
+    ClassExpression, ConditionalExpression, DoExpression, Expression, FunctionExpression,
+    Identifier, Import as BabelImport, JSXAttrVal, JSXAttribute, JSXEmptyExpression,
+    JSXExprContainerExpr, JSXExpressionContainer, JSXMemberExprObject, JSXMemberExpression,
+    JSXSpreadAttribute, Literal, LogicalExprOp, LogicalExpression, MemberExprProp,
+    MemberExpression, MetaProperty, ModuleExpression, NewExpression, ObjectExprProp,
+    ObjectExpression, ObjectKey, ObjectMethod, ObjectPattern, ObjectPropVal, ObjectProperty,
+    OptionalCallExpression, OptionalMemberExprProp, OptionalMemberExpression,
+    ParenthesizedExpression, PatternLike, PipelinePrimaryTopicReference, RecordExprProp,
+    RecordExpression, RestElement, SequenceExpression, TSAsExpression, TSNonNullExpression,
+    TSTypeAssertion, TaggedTemplateExprTypeParams, TaggedTemplateExpression, ThisExpression,
+    TopicReference, TupleExprEl, TupleExpression, TypeCastExpression, UnaryExprOp, UnaryExpression,
+    UpdateExprOp, UpdateExpression, YieldExpression,
 };
 
-use super::Context;
+use super::{Context, ErrorKind};
 
 impl Swcify for Expression {
     type Output = Box<Expr>;
@@ -59,7 +61,11 @@ impl Swcify for Expression {
             Expression::ArrowFunc(e) => e.swcify(ctx).into(),
             Expression::Class(e) => e.swcify(ctx).into(),
             Expression::MetaProp(e) => e.swcify(ctx).into(),
-            Expression::Super(..) => unreachable!(),
+            Expression::Super(e) => invalid(
+                ctx,
+                &e.base,
+                ErrorKind::InvalidNode("super outside of a call or a member expression"),
+            ),
             Expression::TaggedTemplate(e) => e.swcify(ctx).into(),
             Expression::TemplateLiteral(e) => e.swcify(ctx).into(),
             Expression::Yield(e) => e.swcify(ctx).into(),
@@ -73,9 +79,20 @@ impl Swcify for Expression {
             Expression::TSAs(e) => e.swcify(ctx).into(),
             Expression::TSTypeAssertion(e) => e.swcify(ctx).into(),
             Expression::TSNonNull(e) => e.swcify(ctx).into(),
-            _ => {
-                unimplemented!("swcify: {:?}", self)
-            }
+            Expression::Literal(Literal::Template(e)) => e.swcify(ctx).into(),
+            Expression::Import(e) => invalid(
+                ctx,
+                &e.base,
+                ErrorKind::InvalidNode("import outside of a call"),
+            ),
+            Expression::TypeCast(e) => e.swcify(ctx),
+            Expression::Bind(e) => e.swcify(ctx),
+            Expression::PipelinePrimaryTopicRef(e) => e.swcify(ctx),
+            Expression::TopicRef(e) => e.swcify(ctx).into(),
+            Expression::Do(e) => e.swcify(ctx).into(),
+            Expression::Record(e) => e.swcify(ctx).into(),
+            Expression::Tuple(e) => e.swcify(ctx).into(),
+            Expression::Module(e) => e.swcify(ctx),
         })
     }
 }
@@ -98,11 +115,11 @@ impl Swcify for AssignmentExpression {
         AssignExpr {
             span: ctx.span(&self.base),
             op: self.operator.parse().unwrap_or_else(|_| {
-                panic!(
-                    "failed to convert `AssignmentExpression` of babel: Unknown assignment \
-                     operator {}",
-                    self.operator,
-                )
+                ctx.report(
+                    ctx.span(&self.base),
+                    ErrorKind::InvalidNode("unknown assignment operator"),
+                );
+                op!("=")
             }),
             left: PatOrExpr::Pat(Box::new(self.left.swcify(ctx))),
             right: self.right.swcify(ctx),
@@ -111,14 +128,23 @@ impl Swcify for AssignmentExpression {
 }
 
 impl Swcify for BinaryExpression {
-    type Output = BinExpr;
+    type Output = Expr;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
-        BinExpr {
-            span: ctx.span(&self.base),
-            op: self.operator.swcify(ctx),
-            left: self.left.swcify(ctx),
-            right: self.right.swcify(ctx),
+        match self.operator.swcify(ctx) {
+            Some(op) => BinExpr {
+                span: ctx.span(&self.base),
+                op,
+                left: self.left.swcify(ctx),
+                right: self.right.swcify(ctx),
+            }
+            .into(),
+            None => PipelineExpr {
+                span: ctx.span(&self.base),
+                left: self.left.swcify(ctx),
+                right: self.right.swcify(ctx),
+            }
+            .into(),
         }
     }
 }
@@ -145,11 +171,13 @@ impl Swcify for BinaryExprLeft {
     }
 }
 
+/// `|>` is not a binary operator in swc, so it's converted to [None] and the
+/// expression becomes a [PipelineExpr].
 impl Swcify for BinaryExprOp {
-    type Output = BinaryOp;
+    type Output = Option<BinaryOp>;
 
     fn swcify(self, _: &Context) -> Self::Output {
-        match self {
+        Some(match self {
             BinaryExprOp::Addition => {
                 op!(bin, "+")
             }
@@ -216,7 +244,8 @@ impl Swcify for BinaryExprOp {
             BinaryExprOp::LessThanOrEqual => {
                 op!("<=")
             }
-        }
+            BinaryExprOp::Pipeline => return None,
+        })
     }
 }
 
@@ -225,9 +254,11 @@ impl Swcify for BabelCallee {
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            BabelCallee::V8Id(_) => {
-                unreachable!("what is v8 id?")
-            }
+            BabelCallee::V8Id(v) => Callee::Expr(Box::new(invalid(
+                ctx,
+                &v.base,
+                ErrorKind::UnsupportedSyntax("v8 intrinsics"),
+            ))),
             BabelCallee::Expr(e) => match *e {
                 Expression::Super(s) => Callee::Super(s.swcify(ctx)),
                 Expression::Import(s) => Callee::Import(s.swcify(ctx)),
@@ -244,22 +275,17 @@ impl Swcify for CallExpression {
         CallExpr {
             span: ctx.span(&self.base),
             callee: self.callee.swcify(ctx),
-            args: self
-                .arguments
-                .swcify(ctx)
-                .into_iter()
-                .map(|v| v.expect("failed to swcify arguments"))
-                .collect(),
+            args: self.arguments.swcify(ctx),
             type_args: self.type_parameters.swcify(ctx),
         }
     }
 }
 
 impl Swcify for Arg {
-    type Output = Option<ExprOrSpread>;
+    type Output = ExprOrSpread;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
-        Some(match self {
+        match self {
             Arg::Spread(s) => ExprOrSpread {
                 spread: Some(ctx.span(&s.base)),
                 expr: s.argument.swcify(ctx),
@@ -268,12 +294,19 @@ impl Swcify for Arg {
                 spread: None,
                 expr: Box::new(Expr::JSXNamespacedName(e.swcify(ctx))),
             },
-            Arg::Placeholder(_) => return None,
+            Arg::Placeholder(v) => ExprOrSpread {
+                spread: None,
+                expr: Box::new(invalid(
+                    ctx,
+                    &v.base,
+                    ErrorKind::UnsupportedSyntax("partial application"),
+                )),
+            },
             Arg::Expr(e) => ExprOrSpread {
                 spread: None,
                 expr: e.swcify(ctx),
             },
-        })
+        }
     }
 }
 
@@ -373,7 +406,7 @@ impl Swcify for MemberExpression {
                             expr,
                         })
                     }
-                    _ => unreachable!(),
+                    _ => SuperProp::Computed(invalid_prop(ctx, &self.base)),
                 },
             }),
             _ => Expr::Member(MemberExpr {
@@ -391,7 +424,7 @@ impl Swcify for MemberExpression {
                             expr,
                         })
                     }
-                    _ => unreachable!(),
+                    _ => MemberProp::Computed(invalid_prop(ctx, &self.base)),
                 },
             }),
         }
@@ -405,18 +438,14 @@ impl Swcify for NewExpression {
         NewExpr {
             span: ctx.span(&self.base),
             callee: match self.callee {
-                BabelCallee::V8Id(..) => {
-                    unreachable!()
-                }
+                BabelCallee::V8Id(v) => Box::new(invalid(
+                    ctx,
+                    &v.base,
+                    ErrorKind::UnsupportedSyntax("v8 intrinsics"),
+                )),
                 BabelCallee::Expr(e) => e.swcify(ctx),
             },
-            args: Some(
-                self.arguments
-                    .swcify(ctx)
-                    .into_iter()
-                    .map(|v| v.expect("failed to swcify arguments"))
-                    .collect(),
-            ),
+            args: Some(self.arguments.swcify(ctx)),
             type_args: self.type_parameters.swcify(ctx),
         }
     }
@@ -508,9 +537,14 @@ impl Swcify for ObjectProperty {
             value: match self.value {
                 ObjectPropVal::Pattern(pat) => match pat {
                     PatternLike::Id(i) => Box::new(Expr::Ident(i.swcify(ctx).id)),
-                    _ => {
-                        panic!("swc does not support ObjectPropVal::Pattern({:?})", pat)
-                    }
+                    PatternLike::RestEl(RestElement { base, .. })
+                    | PatternLike::AssignmentPat(AssignmentPattern { base, .. })
+                    | PatternLike::ArrayPat(ArrayPattern { base, .. })
+                    | PatternLike::ObjectPat(ObjectPattern { base, .. }) => Box::new(invalid(
+                        ctx,
+                        &base,
+                        ErrorKind::InvalidNode("pattern in an object expression"),
+                    )),
                 },
                 ObjectPropVal::Expr(e) => e.swcify(ctx),
             },
@@ -554,8 +588,13 @@ impl Swcify for UnaryExpression {
     type Output = UnaryExpr;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        if let UnaryExprOp::Throw = self.operator {
+            ctx.report(span, ErrorKind::UnsupportedSyntax("throw expressions"));
+        }
+
         UnaryExpr {
-            span: ctx.span(&self.base),
+            span,
             op: self.operator.swcify(ctx),
             arg: self.argument.swcify(ctx),
         }
@@ -570,8 +609,9 @@ impl Swcify for UnaryExprOp {
             UnaryExprOp::Void => {
                 op!("void")
             }
+            // Reported by `UnaryExpression`.
             UnaryExprOp::Throw => {
-                panic!("swc does not support throw expressions")
+                op!("void")
             }
             UnaryExprOp::Delete => {
                 op!("delete")
@@ -692,10 +732,17 @@ impl Swcify for MetaProperty {
                     ..
                 },
             ) => MetaPropExpr {
-                kind: MetaPropKind::NewTarget,
+                kind: MetaPropKind::ImportMeta,
                 span: ctx.span(&self.base),
             },
-            _ => unreachable!("there are only two kind of meta prop"),
+            _ => {
+                let span = ctx.span(&self.base);
+                ctx.report(span, ErrorKind::InvalidNode("unknown meta property"));
+                MetaPropExpr {
+                    kind: MetaPropKind::NewTarget,
+                    span,
+                }
+            }
         }
     }
 }
@@ -728,7 +775,14 @@ impl Swcify for TaggedTemplateExprTypeParams {
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            TaggedTemplateExprTypeParams::Flow(_) => unimplemented!("flow types"),
+            TaggedTemplateExprTypeParams::Flow(v) => {
+                let span = ctx.span(&v.base);
+                ctx.report(span, ErrorKind::Flow);
+                TsTypeParamInstantiation {
+                    span,
+                    params: Default::default(),
+                }
+            }
             TaggedTemplateExprTypeParams::TS(v) => v.swcify(ctx),
         }
     }
@@ -787,7 +841,7 @@ impl Swcify for OptionalMemberExpression {
                             expr,
                         })
                     }
-                    _ => unreachable!(),
+                    _ => MemberProp::Computed(invalid_prop(ctx, &self.base)),
                 },
             })),
         }
@@ -816,7 +870,7 @@ impl Swcify for OptionalCallExpression {
             expr: Box::new(Expr::Call(CallExpr {
                 span: ctx.span(&self.base),
                 callee: Callee::Expr(self.callee.swcify(ctx)),
-                args: self.arguments.swcify(ctx).into_iter().flatten().collect(),
+                args: self.arguments.swcify(ctx),
                 type_args: self.type_parameters.swcify(ctx),
             })),
         }
@@ -824,10 +878,10 @@ impl Swcify for OptionalCallExpression {
 }
 
 impl Swcify for TypeCastExpression {
-    type Output = !;
+    type Output = Expr;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow type cast")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        invalid(ctx, &self.base, ErrorKind::Flow)
     }
 }
 
@@ -1084,50 +1138,96 @@ impl Swcify for swc_estree_ast::JSXClosingFragment {
 }
 
 impl Swcify for BindExpression {
-    type Output = !;
+    type Output = Expr;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        panic!("swc does not support bind expressions")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        invalid(
+            ctx,
+            &self.base,
+            ErrorKind::UnsupportedSyntax("bind expressions"),
+        )
     }
 }
 
 impl Swcify for DoExpression {
-    type Output = !;
+    type Output = DoExpr;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        panic!("swc does not support do expressions")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        DoExpr {
+            span: ctx.span(&self.base),
+            body: self.body.swcify(ctx),
+        }
     }
 }
 
 impl Swcify for PipelinePrimaryTopicReference {
-    type Output = !;
+    type Output = Expr;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        panic!("swc does not support `PipelinePrimaryTopicReference`")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        invalid(
+            ctx,
+            &self.base,
+            ErrorKind::UnsupportedSyntax("`PipelinePrimaryTopicReference`"),
+        )
+    }
+}
+
+impl Swcify for TopicReference {
+    type Output = TopicRef;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TopicRef {
+            span: ctx.span(&self.base),
+        }
     }
 }
 
 impl Swcify for RecordExpression {
-    type Output = !;
+    type Output = RecordLit;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        panic!("swc does not support record expressions")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        RecordLit {
+            span: ctx.span(&self.base),
+            props: self.properties.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for RecordExprProp {
+    type Output = PropOrSpread;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            RecordExprProp::Prop(p) => PropOrSpread::Prop(Box::new(Prop::KeyValue(p.swcify(ctx)))),
+            RecordExprProp::Spread(p) => PropOrSpread::Spread(SpreadElement {
+                // TODO: Use exact span
+                dot3_token: ctx.span(&p.base),
+                expr: p.argument.swcify(ctx),
+            }),
+        }
     }
 }
 
 impl Swcify for TupleExpression {
-    type Output = !;
+    type Output = TupleLit;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        panic!("swc does not support tuple expressions")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TupleLit {
+            span: ctx.span(&self.base),
+            elems: self.elements.swcify(ctx),
+        }
     }
 }
 
 impl Swcify for ModuleExpression {
-    type Output = !;
+    type Output = Expr;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        panic!("swc does not support module expressions")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        invalid(
+            ctx,
+            &self.base,
+            ErrorKind::UnsupportedSyntax("module expressions"),
+        )
     }
 }
 
@@ -1183,3 +1283,40 @@ impl Swcify for ArrayExprEl {
         }
     }
 }
+
+impl Swcify for TupleExprEl {
+    type Output = ExprOrSpread;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TupleExprEl::Spread(s) => ExprOrSpread {
+                // TODO: Use correct span
+                spread: Some(ctx.span(&s.base)),
+                expr: s.argument.swcify(ctx),
+            },
+            TupleExprEl::Expr(e) => ExprOrSpread {
+                spread: None,
+                expr: e.swcify(ctx),
+            },
+        }
+    }
+}
+
+/// Reports `kind` and returns a placeholder.
+fn invalid(ctx: &Context, base: &BaseNode, kind: ErrorKind) -> Expr {
+    let span = ctx.span(base);
+    ctx.report(span, kind);
+    Expr::Invalid(Invalid { span })
+}
+
+fn invalid_prop(ctx: &Context, base: &BaseNode) -> ComputedPropName {
+    let span = ctx.span(base);
+    ctx.report(
+        span,
+        ErrorKind::InvalidNode("property of a member expression"),
+    );
+    ComputedPropName {
+        span,
+        expr: Box::new(Expr::Invalid(Invalid { span })),
+    }
+}
@@ -1,7 +1,7 @@
-use super::Context;
+use super::{Context, ErrorKind};
 use crate::swcify::Swcify;
-use swc_common::DUMMY_SP;
-use swc_ecma_ast::{BigInt, Bool, Expr, Lit, Null, Number, Regex, Str, Tpl, TplElement};
+use swc_common::{Spanned, DUMMY_SP};
+use swc_ecma_ast::{BigInt, Bool, Expr, Invalid, Lit, Null, Number, Regex, Str, Tpl, TplElement};
 use swc_estree_ast::{
     BigIntLiteral, BooleanLiteral, DecimalLiteral, Literal, NullLiteral, NumberLiteral,
     NumericLiteral, RegExpLiteral, StringLiteral, TemplateElement, TemplateLiteral,
@@ -18,7 +18,15 @@ impl Swcify for Literal {
             Literal::Null(v) => v.swcify(ctx).into(),
             Literal::Boolean(v) => v.swcify(ctx).into(),
             Literal::RegExp(v) => v.swcify(ctx).into(),
-            Literal::Template(..) => unreachable!(),
+            // Handled by `Expression`.
+            Literal::Template(v) => {
+                let span = ctx.span(&v.base);
+                ctx.report(
+                    span,
+                    ErrorKind::InvalidNode("template literal as a literal"),
+                );
+                Lit::Null(Null { span })
+            }
             Literal::BigInt(v) => v.swcify(ctx).into(),
             Literal::Decimal(v) => v.swcify(ctx).into(),
         }
@@ -110,7 +118,11 @@ impl Swcify for TemplateLiteralExpr {
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            TemplateLiteralExpr::TSType(..) => todo!(),
+            TemplateLiteralExpr::TSType(v) => {
+                let span = v.swcify(ctx).span();
+                ctx.report(span, ErrorKind::InvalidNode("type in a template literal"));
+                Box::new(Expr::Invalid(Invalid { span }))
+            }
             TemplateLiteralExpr::Expr(v) => v.swcify(ctx),
         }
     }
@@ -145,10 +157,13 @@ impl Swcify for BigIntLiteral {
     fn swcify(self, ctx: &Context) -> Self::Output {
         BigInt {
             span: ctx.span(&self.base),
-            value: self
-                .value
-                .parse()
-                .expect("failed to parse the value of BigIntLiteral"),
+            value: self.value.parse().unwrap_or_else(|_| {
+                ctx.report(
+                    ctx.span(&self.base),
+                    ErrorKind::InvalidNode("invalid bigint literal"),
+                );
+                Default::default()
+            }),
         }
     }
 }
@@ -159,10 +174,13 @@ impl Swcify for DecimalLiteral {
     fn swcify(self, ctx: &Context) -> Self::Output {
         Number {
             span: ctx.span(&self.base),
-            value: self
-                .value
-                .parse()
-                .expect("failed to parse the value of DecimalLiteral"),
+            value: self.value.parse().unwrap_or_else(|_| {
+                ctx.report(
+                    ctx.span(&self.base),
+                    ErrorKind::InvalidNode("invalid decimal literal"),
+                );
+                Default::default()
+            }),
         }
     }
 }
@@ -1,5 +1,8 @@
 pub use self::ctx::Context;
-use std::fmt::Debug;
+use std::fmt::{self, Debug, Display};
+use swc_common::Span;
+use swc_ecma_ast::Program;
+use swc_estree_ast::File;
 
 mod class;
 mod ctx;
@@ -11,7 +14,57 @@ mod program;
 mod stmt;
 mod typescript;
 
-/// Used to convert a babel ast node to
+/// Converts a babel ast to a swc program.
+///
+/// Returns all errors if the ast contains nodes which cannot be represented
+/// by swc.
+pub fn swcify_file(file: File, ctx: &Context) -> Result<Program, Vec<Error>> {
+    let program = file.swcify(ctx);
+
+    let errors = ctx.take_errors();
+    if errors.is_empty() {
+        Ok(program)
+    } else {
+        Err(errors)
+    }
+}
+
+/// A babel ast node which cannot be converted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub span: Span,
+    pub kind: ErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Flow type annotations, which cannot be represented by the swc ast.
+    ///
+    /// swc can parse flow, but it does not keep flow types in the ast.
+    Flow,
+    /// Syntax which is not supported by swc, like v8 intrinsics.
+    UnsupportedSyntax(&'static str),
+    /// A node which is not allowed at the position.
+    InvalidNode(&'static str),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::Flow => write!(f, "flow types cannot be represented in the swc ast"),
+            ErrorKind::UnsupportedSyntax(syntax) => write!(f, "swc does not support {}", syntax),
+            ErrorKind::InvalidNode(node) => write!(f, "invalid node: {}", node),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Used to convert a babel ast node to a swc ast node.
+///
+/// Nodes which cannot be converted are reported to [Context] and replaced
+/// with a placeholder, like [swc_ecma_ast::Invalid].
 pub trait Swcify {
     type Output: Debug + Send + Sync;
 
@@ -1,4 +1,4 @@
-use crate::swcify::{Context, Swcify};
+use crate::swcify::{Context, ErrorKind, Swcify};
 use swc_common::Spanned;
 use swc_ecma_ast::*;
 use swc_estree_ast::{
@@ -17,7 +17,16 @@ impl Swcify for LVal {
             LVal::AssignmentPat(e) => e.swcify(ctx).into(),
             LVal::ArrayPat(e) => e.swcify(ctx).into(),
             LVal::ObjectPat(e) => e.swcify(ctx).into(),
-            LVal::TSParamProp(..) => todo!(),
+            LVal::TSParamProp(v) => {
+                ctx.report(
+                    ctx.span(&v.base),
+                    ErrorKind::InvalidNode("parameter property outside of a constructor"),
+                );
+                match v.swcify(ctx).param {
+                    TsParamPropParam::Ident(v) => v.into(),
+                    TsParamPropParam::Assign(v) => v.into(),
+                }
+            }
         }
     }
 }
@@ -32,7 +41,7 @@ impl Swcify for RestElement {
             span,
             dot3_token: span,
             arg: Box::new(self.argument.swcify(ctx)),
-            type_ann: None,
+            type_ann: self.type_annotation.swcify(ctx).flatten(),
         }
     }
 }
@@ -178,7 +187,22 @@ impl Swcify for swc_estree_ast::Param {
                 decorators: v.decorators.swcify(ctx).unwrap_or_default(),
                 pat: v.argument.swcify(ctx),
             },
-            swc_estree_ast::Param::TSProp(..) => todo!(),
+            swc_estree_ast::Param::TSProp(v) => {
+                let span = ctx.span(&v.base);
+                ctx.report(
+                    span,
+                    ErrorKind::InvalidNode("parameter property outside of a constructor"),
+                );
+
+                swc_ecma_ast::Param {
+                    span,
+                    decorators: Default::default(),
+                    pat: match v.swcify(ctx).param {
+                        TsParamPropParam::Ident(v) => v.into(),
+                        TsParamPropParam::Assign(v) => v.into(),
+                    },
+                }
+            }
         }
     }
 }
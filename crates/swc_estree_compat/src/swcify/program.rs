@@ -1 +1,70 @@
+use super::{Context, ErrorKind, Swcify};
+use swc_common::Spanned;
+use swc_ecma_ast::{
+    EmptyStmt, Expr, ExprStmt, Lit, Module, ModuleItem, Program, Script, Stmt, Str,
+};
+use swc_estree_ast::{Directive, File, SrcType};
 
+impl Swcify for File {
+    type Output = Program;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        self.program.swcify(ctx)
+    }
+}
+
+impl Swcify for swc_estree_ast::Program {
+    type Output = Program;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let shebang = self.interpreter.map(|v| v.value);
+        let directives = self.directives.swcify(ctx);
+
+        match self.source_type {
+            SrcType::Module => Program::Module(Module {
+                span,
+                body: directives
+                    .into_iter()
+                    .map(ModuleItem::Stmt)
+                    .chain(self.body.swcify(ctx))
+                    .collect(),
+                shebang,
+            }),
+            SrcType::Script => Program::Script(Script {
+                span,
+                body: directives
+                    .into_iter()
+                    .chain(self.body.swcify(ctx).into_iter().map(|item| match item {
+                        ModuleItem::Stmt(stmt) => stmt,
+                        ModuleItem::ModuleDecl(decl) => {
+                            let span = decl.span();
+                            ctx.report(
+                                span,
+                                ErrorKind::InvalidNode("module declaration in script"),
+                            );
+                            Stmt::Empty(EmptyStmt { span })
+                        }
+                    }))
+                    .collect(),
+                shebang,
+            }),
+        }
+    }
+}
+
+impl Swcify for Directive {
+    type Output = Stmt;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Stmt::Expr(ExprStmt {
+            span: ctx.span(&self.base),
+            expr: Box::new(Expr::Lit(Lit::Str(Str {
+                span: ctx.span(&self.value.base),
+                value: self.value.value,
+                has_escape: false,
+                kind: Default::default(),
+            }))),
+        })
+    }
+}
@@ -1,27 +1,28 @@
-use super::Context;
+use super::{typescript::declare_function, Context, ErrorKind};
 use crate::swcify::Swcify;
+use swc_atoms::js_word;
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::{
     BlockStmt, BreakStmt, ClassDecl, ClassExpr, ContinueStmt, DebuggerStmt, Decl, DefaultDecl,
     DoWhileStmt, EmptyStmt, ExportAll, ExportDecl, ExportDefaultDecl, ExportDefaultExpr,
-    ExportNamedSpecifier, Expr, ExprStmt, FnDecl, FnExpr, ForInStmt, ForOfStmt, ForStmt, IfStmt,
-    ImportDecl, ImportNamedSpecifier, ImportSpecifier, ImportStarAsSpecifier, KeyValueProp,
-    LabeledStmt, Lit, ModuleDecl, ModuleItem, NamedExport, ObjectLit, Pat, Prop, PropName,
-    PropOrSpread, ReturnStmt, Stmt, SwitchStmt, ThrowStmt, TryStmt, TsExportAssignment,
-    TsInterfaceDecl, TsModuleDecl, TsTypeAliasDecl, VarDecl, VarDeclKind, VarDeclOrExpr,
-    VarDeclOrPat, VarDeclarator, WhileStmt, WithStmt,
+    ExportNamedSpecifier, Expr, ExprStmt, FnDecl, FnExpr, ForInStmt, ForOfStmt, ForStmt, Function,
+    Ident, IfStmt, ImportDecl, ImportNamedSpecifier, ImportSpecifier, ImportStarAsSpecifier,
+    KeyValueProp, LabeledStmt, Lit, ModuleDecl, ModuleItem, NamedExport, ObjectLit, Pat, Prop,
+    PropName, PropOrSpread, ReturnStmt, Stmt, SwitchStmt, ThrowStmt, TryStmt, VarDecl, VarDeclKind,
+    VarDeclOrExpr, VarDeclOrPat, VarDeclarator, WhileStmt, WithStmt,
 };
 use swc_estree_ast::{
     BlockStatement, BreakStatement, ClassDeclaration, ContinueStatement, DebuggerStatement,
-    DeclareClass, DeclareExportAllDeclaration, DeclareExportDeclaration, DeclareFunction,
-    DeclareInterface, DeclareModule, DeclareModuleExports, DeclareTypeAlias, DeclareVariable,
-    DoWhileStatement, EmptyStatement, ExportAllDeclaration, ExportDefaultDeclType,
-    ExportDefaultDeclaration, ExportKind, ExportNamedDeclaration, ExpressionStatement,
-    ForInStatement, ForOfStatement, ForStatement, ForStmtInit, ForStmtLeft, FunctionDeclaration,
-    IdOrString, IfStatement, ImportAttribute, ImportDeclaration, ImportKind,
-    ImportNamespaceSpecifier, ImportSpecifierType, LabeledStatement, ReturnStatement, Statement,
-    SwitchStatement, ThrowStatement, TryStatement, VariableDeclaration, VariableDeclarationKind,
-    VariableDeclarator, WhileStatement, WithStatement,
+    Declaration, DeclareClass, DeclareExportAllDeclaration, DeclareExportDeclaration,
+    DeclareFunction, DeclareInterface, DeclareModule, DeclareModuleExports, DeclareOpaqueType,
+    DeclareTypeAlias, DeclareVariable, DoWhileStatement, EmptyStatement, EnumDeclaration,
+    ExportAllDeclaration, ExportDefaultDeclType, ExportDefaultDeclaration, ExportKind,
+    ExportNamedDeclaration, ExpressionStatement, ForInStatement, ForOfStatement, ForStatement,
+    ForStmtInit, ForStmtLeft, FunctionDeclaration, IdOrString, IfStatement, ImportAttribute,
+    ImportDeclaration, ImportKind, ImportNamespaceSpecifier, ImportSpecifierType,
+    InterfaceDeclaration, LabeledStatement, OpaqueType, ReturnStatement, Statement,
+    SwitchStatement, ThrowStatement, TryStatement, TypeAlias, VariableDeclaration,
+    VariableDeclarationKind, VariableDeclarator, WhileStatement, WithStatement,
 };
 
 impl Swcify for BlockStatement {
@@ -31,10 +32,10 @@ impl Swcify for BlockStatement {
         BlockStmt {
             span: ctx.span(&self.base),
             stmts: self
-                .body
+                .directives
                 .swcify(ctx)
                 .into_iter()
-                .map(|v| v.expect_stmt())
+                .chain(self.body.swcify(ctx).into_iter().map(|v| v.expect_stmt()))
                 .collect(),
         }
     }
@@ -69,30 +70,44 @@ impl Swcify for Statement {
                 return ModuleItem::ModuleDecl(ModuleDecl::from(v.swcify(ctx)))
             }
             Statement::ExportDefaultDecl(v) => return ModuleItem::ModuleDecl(v.swcify(ctx)),
-            Statement::ExportNamedDecl(v) => {
-                return ModuleItem::ModuleDecl(ModuleDecl::from(v.swcify(ctx)))
-            }
+            Statement::ExportNamedDecl(v) => return ModuleItem::ModuleDecl(v.swcify(ctx)),
             Statement::ForOf(v) => v.swcify(ctx).into(),
             Statement::ImportDecl(v) => {
                 return ModuleItem::ModuleDecl(ModuleDecl::from(v.swcify(ctx)))
             }
-            Statement::DeclClass(v) => Stmt::Decl(v.swcify(ctx).into()),
-            Statement::DeclFunc(v) => Stmt::Decl(v.swcify(ctx).into()),
-            Statement::DeclInterface(v) => Stmt::Decl(v.swcify(ctx).into()),
-            Statement::DeclModule(v) => Stmt::Decl(v.swcify(ctx).into()),
-            Statement::DeclareModuleExports(v) => {
+            Statement::DeclExportAllDeclaration(v) => {
                 return ModuleItem::ModuleDecl(ModuleDecl::from(v.swcify(ctx)))
             }
-            Statement::DeclTypeAlias(v) => Stmt::Decl(Decl::from(v.swcify(ctx))),
-            Statement::DeclVar(v) => Stmt::Decl(Decl::from(v.swcify(ctx))),
-            Statement::DeclExportDeclaration(v) => {
-                return ModuleItem::ModuleDecl(ModuleDecl::from(v.swcify(ctx)))
+            Statement::TSDeclFunc(v) => Stmt::Decl(Decl::Fn(v.swcify(ctx))),
+            Statement::TSInterfaceDecl(v) => Stmt::Decl(Decl::TsInterface(v.swcify(ctx))),
+            Statement::TSTypeAliasDecl(v) => Stmt::Decl(Decl::TsTypeAlias(v.swcify(ctx))),
+            Statement::TSEnumDecl(v) => Stmt::Decl(Decl::TsEnum(v.swcify(ctx))),
+            Statement::TSModuleDecl(v) => Stmt::Decl(Decl::TsModule(v.swcify(ctx))),
+            Statement::TSImportEqualsDecl(v) => {
+                return ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(v.swcify(ctx)))
             }
-            Statement::DeclExportAllDeclaration(v) => {
-                return ModuleItem::ModuleDecl(ModuleDecl::from(v.swcify(ctx)))
+            Statement::TSExportAssignment(v) => {
+                return ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(v.swcify(ctx)))
+            }
+            Statement::TSNamespaceExportDecl(v) => {
+                return ModuleItem::ModuleDecl(ModuleDecl::TsNamespaceExport(v.swcify(ctx)))
             }
-            _ => {
-                todo!("swcify: {:?}", self)
+            Statement::DeclClass(DeclareClass { base, .. })
+            | Statement::DeclFunc(DeclareFunction { base, .. })
+            | Statement::DeclInterface(DeclareInterface { base, .. })
+            | Statement::DeclModule(DeclareModule { base, .. })
+            | Statement::DeclareModuleExports(DeclareModuleExports { base, .. })
+            | Statement::DeclTypeAlias(DeclareTypeAlias { base, .. })
+            | Statement::DeclOpaqueType(DeclareOpaqueType { base, .. })
+            | Statement::DeclVar(DeclareVariable { base, .. })
+            | Statement::DeclExportDeclaration(DeclareExportDeclaration { base, .. })
+            | Statement::InterfaceDecl(InterfaceDeclaration { base, .. })
+            | Statement::OpaqueType(OpaqueType { base, .. })
+            | Statement::TypeAlias(TypeAlias { base, .. })
+            | Statement::EnumDecl(EnumDeclaration { base, .. }) => {
+                let span = ctx.span(&base);
+                ctx.report(span, ErrorKind::Flow);
+                Stmt::Empty(EmptyStmt { span })
             }
         })
     }
@@ -215,24 +230,41 @@ impl Swcify for ForStmtInit {
 impl Swcify for FunctionDeclaration {
     type Output = FnDecl;
 
-    fn swcify(self, ctx: &Context) -> Self::Output {
+    fn swcify(mut self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let ident = match self.id.take() {
+            Some(v) => v.swcify(ctx).id,
+            None => {
+                ctx.report(
+                    span,
+                    ErrorKind::InvalidNode("function declaration without a name"),
+                );
+                Ident::new(js_word!(""), span)
+            }
+        };
+
         FnDecl {
-            ident: self.id.swcify(ctx).map(|v| v.id).unwrap(),
+            ident,
             declare: false,
-            function: swc_ecma_ast::Function {
-                params: self.params.swcify(ctx),
-                decorators: Default::default(),
-                span: ctx.span(&self.base),
-                body: Some(self.body.swcify(ctx)),
-                is_generator: false,
-                is_async: self.is_async.unwrap_or_default(),
-                type_params: Default::default(),
-                return_type: Default::default(),
-            },
+            function: function(self, ctx),
         }
     }
 }
 
+/// `id` of `f` is ignored.
+fn function(f: FunctionDeclaration, ctx: &Context) -> Function {
+    Function {
+        params: f.params.swcify(ctx),
+        decorators: Default::default(),
+        span: ctx.span(&f.base),
+        body: Some(f.body.swcify(ctx)),
+        is_generator: f.generator.unwrap_or_default(),
+        is_async: f.is_async.unwrap_or_default(),
+        type_params: f.type_parameters.swcify(ctx).flatten(),
+        return_type: f.return_type.swcify(ctx).flatten(),
+    }
+}
+
 impl Swcify for IfStatement {
     type Output = IfStmt;
 
@@ -417,9 +449,9 @@ impl Swcify for ClassDeclaration {
                 body: self.body.swcify(ctx),
                 super_class: self.super_class.swcify(ctx),
                 is_abstract: self.is_abstract.unwrap_or_default(),
-                type_params: None,
-                super_type_params: None,
-                implements: Default::default(),
+                type_params: self.type_parameters.swcify(ctx).flatten(),
+                super_type_params: self.super_type_parameters.swcify(ctx),
+                implements: self.implements.swcify(ctx).unwrap_or_default(),
             },
         }
     }
@@ -478,17 +510,22 @@ impl Swcify for ExportDefaultDeclaration {
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self.declaration {
-            ExportDefaultDeclType::Func(v) => {
-                let d = v.swcify(ctx);
-                ExportDefaultDecl {
-                    span: ctx.span(&self.base),
-                    decl: DefaultDecl::Fn(FnExpr {
-                        ident: Some(d.ident),
-                        function: d.function,
-                    }),
-                }
-                .into()
+            ExportDefaultDeclType::Func(mut v) => ExportDefaultDecl {
+                span: ctx.span(&self.base),
+                decl: DefaultDecl::Fn(FnExpr {
+                    ident: v.id.take().swcify(ctx).map(|v| v.id),
+                    function: function(v, ctx),
+                }),
             }
+            .into(),
+            ExportDefaultDeclType::TSFunc(mut v) => ExportDefaultDecl {
+                span: ctx.span(&self.base),
+                decl: DefaultDecl::Fn(FnExpr {
+                    ident: v.id.take().swcify(ctx).map(|v| v.id),
+                    function: declare_function(v, ctx),
+                }),
+            }
+            .into(),
             ExportDefaultDeclType::Class(v) => {
                 let d = v.swcify(ctx);
                 ExportDefaultDecl {
@@ -505,19 +542,22 @@ impl Swcify for ExportDefaultDeclaration {
                 expr: v.swcify(ctx),
             }
             .into(),
-            _ => {
-                todo!("swcify: {:?}", self)
-            }
         }
     }
 }
 
 impl Swcify for ExportNamedDeclaration {
-    type Output = NamedExport;
+    type Output = ModuleDecl;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        if let Some(decl) = self.declaration.swcify(ctx).flatten() {
+            return ExportDecl { span, decl }.into();
+        }
+
         NamedExport {
-            span: ctx.span(&self.base),
+            span,
             specifiers: self.specifiers.swcify(ctx),
             src: self.source.swcify(ctx),
             type_only: false,
@@ -537,6 +577,51 @@ impl Swcify for ExportNamedDeclaration {
                     props,
                 }),
         }
+        .into()
+    }
+}
+
+impl Swcify for Declaration {
+    type Output = Option<Decl>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Some(match self {
+            Declaration::FuncDecl(v) => Decl::Fn(v.swcify(ctx)),
+            Declaration::VarDecl(v) => Decl::Var(v.swcify(ctx)),
+            Declaration::ClassDecl(v) => Decl::Class(v.swcify(ctx)),
+            Declaration::TSDeclFunc(v) => Decl::Fn(v.swcify(ctx)),
+            Declaration::TSInterfaceDecl(v) => Decl::TsInterface(v.swcify(ctx)),
+            Declaration::TSTypeAliasDecl(v) => Decl::TsTypeAlias(v.swcify(ctx)),
+            Declaration::TSEnumDecl(v) => Decl::TsEnum(v.swcify(ctx)),
+            Declaration::TSModuleDecl(v) => Decl::TsModule(v.swcify(ctx)),
+            Declaration::ExportAllDecl(ExportAllDeclaration { base, .. })
+            | Declaration::ExportDefaultDecl(ExportDefaultDeclaration { base, .. })
+            | Declaration::ExportNamedDecl(ExportNamedDeclaration { base, .. })
+            | Declaration::ImportDecl(ImportDeclaration { base, .. }) => {
+                ctx.report(
+                    ctx.span(&base),
+                    ErrorKind::InvalidNode("module declaration in an export declaration"),
+                );
+                return None;
+            }
+            Declaration::DeclClass(DeclareClass { base, .. })
+            | Declaration::DeclFunc(DeclareFunction { base, .. })
+            | Declaration::DeclInterface(DeclareInterface { base, .. })
+            | Declaration::DeclModule(DeclareModule { base, .. })
+            | Declaration::DeclModuleExports(DeclareModuleExports { base, .. })
+            | Declaration::DeclTypeAlias(DeclareTypeAlias { base, .. })
+            | Declaration::DeclOpaqueType(DeclareOpaqueType { base, .. })
+            | Declaration::DeclVar(DeclareVariable { base, .. })
+            | Declaration::DeclExportDecl(DeclareExportDeclaration { base, .. })
+            | Declaration::DeclExportAllDecl(DeclareExportAllDeclaration { base, .. })
+            | Declaration::InterfaceDecl(InterfaceDeclaration { base, .. })
+            | Declaration::OpaqueType(OpaqueType { base, .. })
+            | Declaration::TypeAlias(TypeAlias { base, .. })
+            | Declaration::EnumDecl(EnumDeclaration { base, .. }) => {
+                ctx.report(ctx.span(&base), ErrorKind::Flow);
+                return None;
+            }
+        })
     }
 }
 
@@ -694,70 +779,6 @@ impl Swcify for ImportNamespaceSpecifier {
     }
 }
 
-impl Swcify for DeclareClass {
-    type Output = ClassDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareFunction {
-    type Output = FnDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareInterface {
-    type Output = TsInterfaceDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareModule {
-    type Output = TsModuleDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareModuleExports {
-    type Output = TsExportAssignment;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareTypeAlias {
-    type Output = TsTypeAliasDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareVariable {
-    type Output = VarDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareExportDeclaration {
-    type Output = ExportDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
 impl Swcify for DeclareExportAllDeclaration {
     type Output = ExportAll;
 
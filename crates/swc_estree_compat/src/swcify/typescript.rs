@@ -1,13 +1,33 @@
-use super::Context;
+use super::{Context, ErrorKind};
 use crate::swcify::Swcify;
+use swc_atoms::js_word;
+use swc_common::{Span, Spanned};
 use swc_ecma_ast::{
-    Accessibility, Ident, TsEntityName, TsQualifiedName, TsType, TsTypeAnn, TsTypeParam,
-    TsTypeParamDecl, TsTypeParamInstantiation,
+    Accessibility, FnDecl, Function, Ident, Pat, TruePlusMinus, TsArrayType, TsCallSignatureDecl,
+    TsConditionalType, TsConstructSignatureDecl, TsConstructorType, TsEntityName, TsEnumDecl,
+    TsEnumMember, TsEnumMemberId, TsExportAssignment, TsExprWithTypeArgs, TsExternalModuleRef,
+    TsFnOrConstructorType, TsFnParam, TsFnType, TsImportEqualsDecl, TsImportType, TsIndexSignature,
+    TsIndexedAccessType, TsInferType, TsInterfaceBody, TsInterfaceDecl, TsIntersectionType,
+    TsKeywordType, TsKeywordTypeKind, TsLit, TsLitType, TsMappedType, TsMethodSignature,
+    TsModuleBlock, TsModuleDecl, TsModuleName, TsModuleRef, TsNamespaceBody, TsNamespaceDecl,
+    TsNamespaceExportDecl, TsOptionalType, TsParamProp, TsParamPropParam, TsParenthesizedType,
+    TsPropertySignature, TsQualifiedName, TsRestType, TsThisType, TsThisTypeOrIdent,
+    TsTupleElement, TsTupleType, TsType, TsTypeAliasDecl, TsTypeAnn, TsTypeElement, TsTypeLit,
+    TsTypeOperator, TsTypeOperatorOp, TsTypeParam, TsTypeParamDecl, TsTypeParamInstantiation,
+    TsTypePredicate, TsTypeQuery, TsTypeQueryExpr, TsTypeRef, TsUnionOrIntersectionType,
+    TsUnionType,
 };
 use swc_estree_ast::{
-    Access, FlowType, SuperTypeParams, TSEntityName, TSQualifiedName, TSType, TSTypeAnnotation,
-    TSTypeParameter, TSTypeParameterDeclaration, TSTypeParameterInstantiation, TypeAnnotOrNoop,
-    TypeParamDeclOrNoop,
+    Access, BaseNode, IdOrRest, IdOrString, SuperTypeParams, TSCallSignatureDeclaration,
+    TSConstructSignatureDeclaration, TSDeclareFunction, TSEntityName, TSEnumDeclaration,
+    TSEnumMember, TSExportAssignment, TSExpressionWithTypeArguments, TSFuncDeclTypeAnnot,
+    TSFuncDeclTypeParams, TSImportEqualsDeclModuleRef, TSImportEqualsDeclaration, TSImportType,
+    TSIndexSignature, TSInterfaceBody, TSInterfaceDeclaration, TSLiteralTypeLiteral,
+    TSMethodSignature, TSModuleBlock, TSModuleDeclBody, TSModuleDeclaration,
+    TSNamespaceExportDeclaration, TSParamPropParam, TSParameterProperty, TSPropertySignature,
+    TSQualifiedName, TSTupleTypeElType, TSType, TSTypeAliasDeclaration, TSTypeAnnotation,
+    TSTypeElement, TSTypeParameter, TSTypeParameterDeclaration, TSTypeParameterInstantiation,
+    TSTypePredicateParamName, TSTypeQueryExprName, TypeAnnotOrNoop, TypeParamDeclOrNoop,
 };
 
 impl Swcify for TSTypeParameterInstantiation {
@@ -21,20 +41,15 @@ impl Swcify for TSTypeParameterInstantiation {
     }
 }
 
-impl Swcify for FlowType {
-    type Output = !;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unreachable!("swc does not support flow types")
-    }
-}
-
 impl Swcify for TypeParamDeclOrNoop {
     type Output = Option<TsTypeParamDecl>;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            TypeParamDeclOrNoop::Flow(_) => None,
+            TypeParamDeclOrNoop::Flow(v) => {
+                ctx.report(ctx.span(&v.base), ErrorKind::Flow);
+                None
+            }
             TypeParamDeclOrNoop::TS(v) => Some(v.swcify(ctx)),
             TypeParamDeclOrNoop::Noop(_) => None,
         }
@@ -71,7 +86,10 @@ impl Swcify for TypeAnnotOrNoop {
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            TypeAnnotOrNoop::Flow(_) => None,
+            TypeAnnotOrNoop::Flow(v) => {
+                ctx.report(ctx.span(&v.base), ErrorKind::Flow);
+                None
+            }
             TypeAnnotOrNoop::TS(v) => Some(v.swcify(ctx)),
             TypeAnnotOrNoop::Noop(_) => None,
         }
@@ -89,11 +107,379 @@ impl Swcify for TSTypeAnnotation {
     }
 }
 
+impl Swcify for TSFuncDeclTypeParams {
+    type Output = Option<TsTypeParamDecl>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSFuncDeclTypeParams::Type(v) => Some(v.swcify(ctx)),
+            TSFuncDeclTypeParams::Noop(_) => None,
+        }
+    }
+}
+
+impl Swcify for TSFuncDeclTypeAnnot {
+    type Output = Option<TsTypeAnn>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSFuncDeclTypeAnnot::Type(v) => Some(v.swcify(ctx)),
+            TSFuncDeclTypeAnnot::Noop(_) => None,
+        }
+    }
+}
+
 impl Swcify for TSType {
     type Output = Box<TsType>;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        todo!("swc currently does not support importing typescript module from babel")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let keyword = |base: &BaseNode, kind| {
+            TsType::TsKeywordType(TsKeywordType {
+                span: ctx.span(base),
+                kind,
+            })
+        };
+
+        Box::new(match self {
+            TSType::AnyKeyword(v) => keyword(&v.base, TsKeywordTypeKind::TsAnyKeyword),
+            TSType::BooleanKeyword(v) => keyword(&v.base, TsKeywordTypeKind::TsBooleanKeyword),
+            TSType::BigIntKeyword(v) => keyword(&v.base, TsKeywordTypeKind::TsBigIntKeyword),
+            TSType::IntrinsicKeyword(v) => keyword(&v.base, TsKeywordTypeKind::TsIntrinsicKeyword),
+            TSType::NeverKeyword(v) => keyword(&v.base, TsKeywordTypeKind::TsNeverKeyword),
+            TSType::NullKeyword(v) => keyword(&v.base, TsKeywordTypeKind::TsNullKeyword),
+            TSType::NumberKeyword(v) => keyword(&v.base, TsKeywordTypeKind::TsNumberKeyword),
+            TSType::ObjectKeyword(v) => keyword(&v.base, TsKeywordTypeKind::TsObjectKeyword),
+            TSType::StringKeyword(v) => keyword(&v.base, TsKeywordTypeKind::TsStringKeyword),
+            TSType::SymbolKeyword(v) => keyword(&v.base, TsKeywordTypeKind::TsSymbolKeyword),
+            TSType::UndefinedKeyword(v) => keyword(&v.base, TsKeywordTypeKind::TsUndefinedKeyword),
+            TSType::UnknownKeyword(v) => keyword(&v.base, TsKeywordTypeKind::TsUnknownKeyword),
+            TSType::VoidKeyword(v) => keyword(&v.base, TsKeywordTypeKind::TsVoidKeyword),
+            TSType::This(v) => TsType::TsThisType(TsThisType {
+                span: ctx.span(&v.base),
+            }),
+            TSType::Function(v) => {
+                let span = ctx.span(&v.base);
+                TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(TsFnType {
+                    span,
+                    params: v.parameters.swcify(ctx),
+                    type_params: v.type_parameters.swcify(ctx),
+                    type_ann: return_type(ctx, span, v.type_annotation),
+                }))
+            }
+            TSType::Constructor(v) => {
+                let span = ctx.span(&v.base);
+                TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsConstructorType(
+                    TsConstructorType {
+                        span,
+                        params: v.parameters.swcify(ctx),
+                        type_params: v.type_parameters.swcify(ctx),
+                        type_ann: return_type(ctx, span, v.type_annotation),
+                        is_abstract: v.is_abstract.unwrap_or_default(),
+                    },
+                ))
+            }
+            TSType::TypeRef(v) => TsType::TsTypeRef(TsTypeRef {
+                span: ctx.span(&v.base),
+                type_name: v.type_name.swcify(ctx),
+                type_params: v.type_parameters.swcify(ctx),
+            }),
+            TSType::TypePredicate(v) => TsType::TsTypePredicate(TsTypePredicate {
+                span: ctx.span(&v.base),
+                asserts: v.asserts.unwrap_or_default(),
+                param_name: match v.parameter_name {
+                    TSTypePredicateParamName::Id(v) => TsThisTypeOrIdent::Ident(v.swcify(ctx).id),
+                    TSTypePredicateParamName::This(v) => {
+                        TsThisTypeOrIdent::TsThisType(TsThisType {
+                            span: ctx.span(&v.base),
+                        })
+                    }
+                },
+                type_ann: v.type_annotation.swcify(ctx),
+            }),
+            TSType::TypeQuery(v) => TsType::TsTypeQuery(TsTypeQuery {
+                span: ctx.span(&v.base),
+                expr_name: match v.expr_name {
+                    TSTypeQueryExprName::EntityName(v) => {
+                        TsTypeQueryExpr::TsEntityName(v.swcify(ctx))
+                    }
+                    TSTypeQueryExprName::ImportType(v) => TsTypeQueryExpr::Import(v.swcify(ctx)),
+                },
+            }),
+            TSType::TypeLiteral(v) => TsType::TsTypeLit(TsTypeLit {
+                span: ctx.span(&v.base),
+                members: v.members.swcify(ctx),
+            }),
+            TSType::Array(v) => TsType::TsArrayType(TsArrayType {
+                span: ctx.span(&v.base),
+                elem_type: v.element_type.swcify(ctx),
+            }),
+            TSType::Tuple(v) => TsType::TsTupleType(TsTupleType {
+                span: ctx.span(&v.base),
+                elem_types: v.element_types.swcify(ctx),
+            }),
+            TSType::Optional(v) => TsType::TsOptionalType(TsOptionalType {
+                span: ctx.span(&v.base),
+                type_ann: v.type_annotation.swcify(ctx),
+            }),
+            TSType::Rest(v) => TsType::TsRestType(TsRestType {
+                span: ctx.span(&v.base),
+                type_ann: v.type_annotation.swcify(ctx),
+            }),
+            TSType::Union(v) => TsType::TsUnionOrIntersectionType(
+                TsUnionOrIntersectionType::TsUnionType(TsUnionType {
+                    span: ctx.span(&v.base),
+                    types: v.types.swcify(ctx),
+                }),
+            ),
+            TSType::Intersection(v) => TsType::TsUnionOrIntersectionType(
+                TsUnionOrIntersectionType::TsIntersectionType(TsIntersectionType {
+                    span: ctx.span(&v.base),
+                    types: v.types.swcify(ctx),
+                }),
+            ),
+            TSType::Conditional(v) => TsType::TsConditionalType(TsConditionalType {
+                span: ctx.span(&v.base),
+                check_type: v.check_type.swcify(ctx),
+                extends_type: v.extends_type.swcify(ctx),
+                true_type: v.true_type.swcify(ctx),
+                false_type: v.false_type.swcify(ctx),
+            }),
+            TSType::Infer(v) => TsType::TsInferType(TsInferType {
+                span: ctx.span(&v.base),
+                type_param: v.type_parameter.swcify(ctx),
+            }),
+            TSType::Parenthesized(v) => TsType::TsParenthesizedType(TsParenthesizedType {
+                span: ctx.span(&v.base),
+                type_ann: v.type_annotation.swcify(ctx),
+            }),
+            TSType::TypeOp(v) => {
+                let span = ctx.span(&v.base);
+                TsType::TsTypeOperator(TsTypeOperator {
+                    span,
+                    op: match &*v.operator {
+                        "keyof" => TsTypeOperatorOp::KeyOf,
+                        "unique" => TsTypeOperatorOp::Unique,
+                        "readonly" => TsTypeOperatorOp::ReadOnly,
+                        _ => {
+                            ctx.report(span, ErrorKind::InvalidNode("unknown type operator"));
+                            TsTypeOperatorOp::KeyOf
+                        }
+                    },
+                    type_ann: v.type_annotation.swcify(ctx),
+                })
+            }
+            TSType::IndexedAccess(v) => TsType::TsIndexedAccessType(TsIndexedAccessType {
+                span: ctx.span(&v.base),
+                readonly: false,
+                obj_type: v.object_type.swcify(ctx),
+                index_type: v.index_type.swcify(ctx),
+            }),
+            TSType::Mapped(v) => TsType::TsMappedType(TsMappedType {
+                span: ctx.span(&v.base),
+                readonly: true_plus_minus(v.readonly),
+                type_param: v.type_parameter.swcify(ctx),
+                name_type: v.name_type.swcify(ctx),
+                optional: true_plus_minus(v.optional),
+                type_ann: v.type_annotation.swcify(ctx),
+            }),
+            TSType::Literal(v) => TsType::TsLitType(TsLitType {
+                span: ctx.span(&v.base),
+                lit: match v.literal {
+                    TSLiteralTypeLiteral::Numeric(v) => TsLit::Number(v.swcify(ctx)),
+                    TSLiteralTypeLiteral::String(v) => TsLit::Str(v.swcify(ctx)),
+                    TSLiteralTypeLiteral::Boolean(v) => TsLit::Bool(v.swcify(ctx)),
+                    TSLiteralTypeLiteral::BigInt(v) => TsLit::BigInt(v.swcify(ctx)),
+                },
+            }),
+            // swc does not have a type node for `TSExpressionWithTypeArguments`.
+            TSType::ExprWithArgs(v) => TsType::TsTypeRef(TsTypeRef {
+                span: ctx.span(&v.base),
+                type_name: v.expression.swcify(ctx),
+                type_params: v.type_parameters.swcify(ctx),
+            }),
+            TSType::Import(v) => TsType::TsImportType(v.swcify(ctx)),
+        })
+    }
+}
+
+/// Babel allows omitting the return type of function types while swc does
+/// not.
+fn return_type(ctx: &Context, span: Span, type_ann: Option<Box<TSTypeAnnotation>>) -> TsTypeAnn {
+    match type_ann {
+        Some(v) => v.swcify(ctx),
+        None => {
+            ctx.report(
+                span,
+                ErrorKind::InvalidNode("function type without a return type"),
+            );
+            TsTypeAnn {
+                span,
+                type_ann: Box::new(TsType::TsKeywordType(TsKeywordType {
+                    span,
+                    kind: TsKeywordTypeKind::TsAnyKeyword,
+                })),
+            }
+        }
+    }
+}
+
+fn true_plus_minus(v: Option<bool>) -> Option<TruePlusMinus> {
+    match v {
+        Some(true) => Some(TruePlusMinus::True),
+        _ => None,
+    }
+}
+
+impl Swcify for IdOrRest {
+    type Output = TsFnParam;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            IdOrRest::Id(v) => TsFnParam::Ident(v.swcify(ctx)),
+            IdOrRest::Rest(v) => TsFnParam::Rest(v.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for TSTupleTypeElType {
+    type Output = TsTupleElement;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSTupleTypeElType::Member(v) => {
+                let span = ctx.span(&v.base);
+                let ty = v.element_type.swcify(ctx);
+
+                TsTupleElement {
+                    span,
+                    label: Some(Pat::Ident(v.label.swcify(ctx))),
+                    ty: if v.optional {
+                        TsType::TsOptionalType(TsOptionalType { span, type_ann: ty })
+                    } else {
+                        *ty
+                    },
+                }
+            }
+            TSTupleTypeElType::TSType(v) => {
+                let ty = v.swcify(ctx);
+
+                TsTupleElement {
+                    span: ty.span(),
+                    label: None,
+                    ty: *ty,
+                }
+            }
+        }
+    }
+}
+
+impl Swcify for TSTypeElement {
+    type Output = TsTypeElement;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSTypeElement::CallSignatureDecl(v) => {
+                TsTypeElement::TsCallSignatureDecl(v.swcify(ctx))
+            }
+            TSTypeElement::ConstructSignatureDecl(v) => {
+                TsTypeElement::TsConstructSignatureDecl(v.swcify(ctx))
+            }
+            TSTypeElement::PropSignature(v) => TsTypeElement::TsPropertySignature(v.swcify(ctx)),
+            TSTypeElement::MethodSignature(v) => TsTypeElement::TsMethodSignature(v.swcify(ctx)),
+            TSTypeElement::IndexSignature(v) => TsTypeElement::TsIndexSignature(v.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for TSCallSignatureDeclaration {
+    type Output = TsCallSignatureDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsCallSignatureDecl {
+            span: ctx.span(&self.base),
+            params: self.parameters.swcify(ctx),
+            type_ann: self.type_annotation.swcify(ctx),
+            type_params: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSConstructSignatureDeclaration {
+    type Output = TsConstructSignatureDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsConstructSignatureDecl {
+            span: ctx.span(&self.base),
+            params: self.parameters.swcify(ctx),
+            type_ann: self.type_annotation.swcify(ctx),
+            type_params: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSPropertySignature {
+    type Output = TsPropertySignature;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsPropertySignature {
+            span: ctx.span(&self.base),
+            readonly: self.readonly.unwrap_or_default(),
+            key: self.key.swcify(ctx),
+            computed: self.computed.unwrap_or_default(),
+            optional: self.optional.unwrap_or_default(),
+            init: self.initializer.swcify(ctx),
+            params: Default::default(),
+            type_ann: self.type_annotation.swcify(ctx),
+            type_params: None,
+        }
+    }
+}
+
+impl Swcify for TSMethodSignature {
+    type Output = TsMethodSignature;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsMethodSignature {
+            span: ctx.span(&self.base),
+            readonly: false,
+            key: self.key.swcify(ctx),
+            computed: self.computed.unwrap_or_default(),
+            optional: self.optional.unwrap_or_default(),
+            params: self.parameters.swcify(ctx),
+            type_ann: self.type_annotation.swcify(ctx),
+            type_params: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSIndexSignature {
+    type Output = TsIndexSignature;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsIndexSignature {
+            params: self
+                .paramters
+                .into_iter()
+                .map(|v| TsFnParam::Ident(v.swcify(ctx)))
+                .collect(),
+            type_ann: self.type_annotation.swcify(ctx),
+            readonly: self.readonly.unwrap_or_default(),
+            is_static: false,
+            span: ctx.span(&self.base),
+        }
+    }
+}
+
+impl Swcify for TSImportType {
+    type Output = TsImportType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsImportType {
+            span: ctx.span(&self.base),
+            arg: self.argument.swcify(ctx),
+            qualifier: self.qualifier.swcify(ctx),
+            type_args: self.type_parameters.swcify(ctx),
+        }
     }
 }
 
@@ -102,7 +488,14 @@ impl Swcify for SuperTypeParams {
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            SuperTypeParams::Flow(_) => unimplemented!("flow type"),
+            SuperTypeParams::Flow(v) => {
+                let span = ctx.span(&v.base);
+                ctx.report(span, ErrorKind::Flow);
+                TsTypeParamInstantiation {
+                    span,
+                    params: Default::default(),
+                }
+            }
             SuperTypeParams::TS(v) => v.swcify(ctx),
         }
     }
@@ -141,3 +534,245 @@ impl Swcify for TSQualifiedName {
         }
     }
 }
+
+impl Swcify for TSParameterProperty {
+    type Output = TsParamProp;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsParamProp {
+            span: ctx.span(&self.base),
+            decorators: Default::default(),
+            accessibility: self.accessibility.swcify(ctx),
+            is_override: false,
+            readonly: self.readonly.unwrap_or_default(),
+            param: match self.parameter {
+                TSParamPropParam::Id(v) => TsParamPropParam::Ident(v.swcify(ctx)),
+                TSParamPropParam::Assignment(v) => TsParamPropParam::Assign(v.swcify(ctx)),
+            },
+        }
+    }
+}
+
+impl Swcify for TSDeclareFunction {
+    type Output = FnDecl;
+
+    fn swcify(mut self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let ident = match self.id.take() {
+            Some(v) => v.swcify(ctx).id,
+            None => {
+                ctx.report(
+                    span,
+                    ErrorKind::InvalidNode("function declaration without a name"),
+                );
+                Ident::new(js_word!(""), span)
+            }
+        };
+
+        FnDecl {
+            ident,
+            declare: self.declare.unwrap_or_default(),
+            function: declare_function(self, ctx),
+        }
+    }
+}
+
+/// Converts a `TSDeclareFunction` to a function without body.
+///
+/// `id` of `f` is ignored.
+pub(super) fn declare_function(f: TSDeclareFunction, ctx: &Context) -> Function {
+    Function {
+        params: f.params.swcify(ctx),
+        decorators: Default::default(),
+        span: ctx.span(&f.base),
+        body: None,
+        is_generator: f.generator.unwrap_or_default(),
+        is_async: f.is_async.unwrap_or_default(),
+        type_params: f.type_parameters.swcify(ctx).flatten(),
+        return_type: f.return_type.swcify(ctx).flatten(),
+    }
+}
+
+impl Swcify for TSInterfaceDeclaration {
+    type Output = TsInterfaceDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsInterfaceDecl {
+            span: ctx.span(&self.base),
+            id: self.id.swcify(ctx).id,
+            declare: self.declare.unwrap_or_default(),
+            type_params: self.type_parameters.swcify(ctx),
+            extends: self.extends.swcify(ctx).into_iter().collect(),
+            body: self.body.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSInterfaceBody {
+    type Output = TsInterfaceBody;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsInterfaceBody {
+            span: ctx.span(&self.base),
+            body: self.body.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSExpressionWithTypeArguments {
+    type Output = TsExprWithTypeArgs;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsExprWithTypeArgs {
+            span: ctx.span(&self.base),
+            expr: self.expression.swcify(ctx),
+            type_args: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSTypeAliasDeclaration {
+    type Output = TsTypeAliasDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeAliasDecl {
+            span: ctx.span(&self.base),
+            declare: self.declare.unwrap_or_default(),
+            id: self.id.swcify(ctx).id,
+            type_params: self.type_parameters.swcify(ctx),
+            type_ann: self.type_annotation.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSEnumDeclaration {
+    type Output = TsEnumDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsEnumDecl {
+            span: ctx.span(&self.base),
+            declare: self.declare.unwrap_or_default(),
+            is_const: self.is_const.unwrap_or_default(),
+            id: self.id.swcify(ctx).id,
+            members: self.members.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSEnumMember {
+    type Output = TsEnumMember;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsEnumMember {
+            span: ctx.span(&self.base),
+            id: match self.id {
+                IdOrString::Id(v) => TsEnumMemberId::Ident(v.swcify(ctx).id),
+                IdOrString::String(v) => TsEnumMemberId::Str(v.swcify(ctx)),
+            },
+            init: self.initializer.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSModuleDeclaration {
+    type Output = TsModuleDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsModuleDecl {
+            span: ctx.span(&self.base),
+            declare: self.declare.unwrap_or_default(),
+            global: self.global.unwrap_or_default(),
+            id: match self.id {
+                IdOrString::Id(v) => TsModuleName::Ident(v.swcify(ctx).id),
+                IdOrString::String(v) => TsModuleName::Str(v.swcify(ctx)),
+            },
+            body: Some(self.body.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for TSModuleDeclBody {
+    type Output = TsNamespaceBody;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSModuleDeclBody::Block(v) => TsNamespaceBody::TsModuleBlock(v.swcify(ctx)),
+            TSModuleDeclBody::Decl(v) => {
+                let span = ctx.span(&v.base);
+
+                TsNamespaceBody::TsNamespaceDecl(TsNamespaceDecl {
+                    span,
+                    declare: v.declare.unwrap_or_default(),
+                    global: v.global.unwrap_or_default(),
+                    id: match v.id {
+                        IdOrString::Id(v) => v.swcify(ctx).id,
+                        IdOrString::String(s) => {
+                            ctx.report(
+                                span,
+                                ErrorKind::InvalidNode("string literal as a namespace name"),
+                            );
+                            Ident::new(s.value, ctx.span(&s.base))
+                        }
+                    },
+                    body: Box::new(v.body.swcify(ctx)),
+                })
+            }
+        }
+    }
+}
+
+impl Swcify for TSModuleBlock {
+    type Output = TsModuleBlock;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsModuleBlock {
+            span: ctx.span(&self.base),
+            body: self.body.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSImportEqualsDeclaration {
+    type Output = TsImportEqualsDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsImportEqualsDecl {
+            span: ctx.span(&self.base),
+            declare: false,
+            is_export: self.is_export,
+            is_type_only: false,
+            id: self.id.swcify(ctx).id,
+            module_ref: match self.module_reference {
+                TSImportEqualsDeclModuleRef::Name(v) => TsModuleRef::TsEntityName(v.swcify(ctx)),
+                TSImportEqualsDeclModuleRef::External(v) => {
+                    TsModuleRef::TsExternalModuleRef(TsExternalModuleRef {
+                        span: ctx.span(&v.base),
+                        expr: v.expression.swcify(ctx),
+                    })
+                }
+            },
+        }
+    }
+}
+
+impl Swcify for TSExportAssignment {
+    type Output = TsExportAssignment;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsExportAssignment {
+            span: ctx.span(&self.base),
+            expr: self.expression.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSNamespaceExportDeclaration {
+    type Output = TsNamespaceExportDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsNamespaceExportDecl {
+            span: ctx.span(&self.base),
+            id: self.id.swcify(ctx).id,
+        }
+    }
+}
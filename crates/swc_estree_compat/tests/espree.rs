@@ -0,0 +1,198 @@
+use serde_json::{json, Value};
+use swc_common::FileName;
+use swc_ecma_ast::EsVersion;
+use swc_ecma_parser::{Syntax, TsConfig};
+use swc_estree_compat::espree::{parse_for_eslint, EspreeOutput, ScopeType};
+
+fn parse(syntax: Syntax, src: &str, op: impl FnOnce(&EspreeOutput)) {
+    testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+
+        let output = parse_for_eslint(cm, fm, syntax, EsVersion::latest()).unwrap();
+        op(&output);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+fn tokens(output: &EspreeOutput) -> Vec<(String, String)> {
+    output.ast["tokens"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| {
+            (
+                t["type"].as_str().unwrap().to_string(),
+                t["value"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn tokens_template() {
+    parse(Default::default(), "let a = `x${b}y`;", |output| {
+        assert_eq!(
+            tokens(output),
+            vec![
+                ("Keyword".into(), "let".into()),
+                ("Identifier".into(), "a".into()),
+                ("Punctuator".into(), "=".into()),
+                ("Template".into(), "`x${".into()),
+                ("Identifier".into(), "b".into()),
+                ("Template".into(), "}y`".into()),
+                ("Punctuator".into(), ";".into()),
+            ]
+        );
+        assert_eq!(output.ast["tokens"][3]["range"], json!([8, 12]));
+        assert_eq!(output.ast["range"], json!([0, 17]));
+    });
+}
+
+#[test]
+fn tokens_private_name() {
+    parse(Default::default(), "class A { #p = /a/g }", |output| {
+        let tokens = &output.ast["tokens"];
+
+        assert_eq!(tokens[3]["type"], "PrivateIdentifier");
+        assert_eq!(tokens[3]["value"], "p");
+        assert_eq!(tokens[3]["range"], json!([10, 12]));
+        assert_eq!(tokens[5]["type"], "RegularExpression");
+        assert_eq!(tokens[5]["regex"], json!({ "pattern": "a", "flags": "g" }));
+    });
+}
+
+#[test]
+fn comments() {
+    parse(Default::default(), "// a\n/* b */ x;", |output| {
+        let comments = &output.ast["comments"];
+
+        assert_eq!(comments[0]["type"], "Line");
+        assert_eq!(comments[0]["value"], " a");
+        assert_eq!(comments[0]["range"], json!([0, 4]));
+        assert_eq!(comments[1]["type"], "Block");
+        assert_eq!(comments[1]["range"], json!([5, 12]));
+        assert_eq!(
+            comments[1]["loc"]["start"],
+            json!({ "line": 2, "column": 0 })
+        );
+
+        // espree does not attach comments to nodes.
+        assert_eq!(output.ast["body"][0].get("leadingComments"), None);
+    });
+}
+
+#[test]
+fn scope_manager() {
+    parse(
+        Default::default(),
+        "import x from 'x'; function f(a) { return a + x + y; }",
+        |output| {
+            let sm = &output.scope_manager;
+
+            assert_eq!(sm.scopes[0].type_, ScopeType::Global);
+            assert_eq!(sm.scopes[1].type_, ScopeType::Module);
+            assert_eq!(sm.scopes[1].upper, Some(0));
+
+            let names = sm.variables.iter().map(|v| &*v.name).collect::<Vec<_>>();
+            assert!(names.contains(&"x"));
+            assert!(names.contains(&"f"));
+            assert!(names.contains(&"a"));
+
+            let y = sm
+                .references
+                .iter()
+                .position(|r| r.resolved.is_none())
+                .unwrap();
+            assert_eq!(sm.references[y].identifier, [50, 51]);
+            assert!(sm.scopes[0].through.contains(&y));
+
+            let a = sm.variables.iter().find(|v| &*v.name == "a").unwrap();
+            assert_eq!(a.references.len(), 1);
+            assert_eq!(sm.references[a.references[0]].from, a.scope);
+        },
+    );
+}
+
+#[test]
+fn typescript_nodes() {
+    parse(
+        Syntax::Typescript(TsConfig::default()),
+        "interface A extends B { m(a: string): void }\nlet f: (x: number) => (string);",
+        |output| {
+            let interface = &output.ast["body"][0];
+            assert_eq!(interface["extends"][0]["type"], "TSInterfaceHeritage");
+
+            let method = &interface["body"]["body"][0];
+            assert_eq!(method["type"], "TSMethodSignature");
+            assert_eq!(method["params"][0]["name"], "a");
+            assert_eq!(
+                method["returnType"]["typeAnnotation"]["type"],
+                "TSVoidKeyword"
+            );
+
+            let fn_type: &Value =
+                &output.ast["body"][1]["declarations"][0]["id"]["typeAnnotation"]["typeAnnotation"];
+            assert_eq!(fn_type["type"], "TSFunctionType");
+            assert_eq!(
+                fn_type["returnType"]["typeAnnotation"]["type"],
+                "TSStringKeyword"
+            );
+        },
+    );
+}
+
+#[test]
+fn ranges_in_utf16() {
+    parse(
+        Default::default(),
+        "let a = '😀é';\nb; // 😀",
+        |output| {
+            let tokens = &output.ast["tokens"];
+
+            assert_eq!(tokens[3]["range"], json!([8, 13]));
+            assert_eq!(tokens[5]["range"], json!([15, 16]));
+            assert_eq!(output.ast["body"][1]["range"], json!([15, 17]));
+            assert_eq!(output.ast["comments"][0]["range"], json!([18, 23]));
+            assert_eq!(
+                output.ast["comments"][0]["loc"]["end"],
+                json!({ "line": 2, "column": 8 })
+            );
+        },
+    );
+}
+
+#[test]
+fn typescript_class_members() {
+    parse(
+        Syntax::Typescript(TsConfig::default()),
+        "abstract class A {
+            abstract value: number;
+            abstract m(): void;
+            f(a: string): void;
+            f(a: any) {}
+        }",
+        |output| {
+            let body = &output.ast["body"][0]["body"]["body"];
+
+            assert_eq!(body[0]["type"], "TSAbstractPropertyDefinition");
+
+            assert_eq!(body[1]["type"], "TSAbstractMethodDefinition");
+            assert_eq!(body[1]["value"]["type"], "TSEmptyBodyFunctionExpression");
+            assert_eq!(
+                body[1]["value"]["returnType"]["typeAnnotation"]["type"],
+                "TSVoidKeyword"
+            );
+
+            assert_eq!(body[2]["type"], "MethodDefinition");
+            assert_eq!(body[2]["kind"], "method");
+            assert_eq!(body[2]["key"]["name"], "f");
+            assert_eq!(body[2]["value"]["type"], "TSEmptyBodyFunctionExpression");
+            assert_eq!(body[2]["value"]["params"][0]["name"], "a");
+
+            assert_eq!(body[3]["type"], "MethodDefinition");
+            assert_eq!(body[3]["value"]["type"], "FunctionExpression");
+        },
+    );
+}
@@ -0,0 +1,195 @@
+use serde_json::json;
+use swc_common::{FileName, Span};
+use swc_ecma_ast::{
+    ClassMember, Decl, Expr, ModuleItem, ParamOrTsParamProp, Program, PropOrSpread, Stmt,
+};
+use swc_ecma_parser::{lexer::Lexer, EsConfig, Parser, StringInput, Syntax, TsConfig};
+use swc_estree_ast::File;
+use swc_estree_compat::{
+    babelify::{self, Babelify},
+    swcify::{swcify_file, Context, ErrorKind},
+};
+use swc_node_comments::SwcComments;
+
+fn roundtrip(syntax: Syntax, src: &str, op: impl FnOnce(Program)) {
+    testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+
+        let lexer = Lexer::new(syntax, Default::default(), StringInput::from(&*fm), None);
+        let module = Parser::new_from(lexer).parse_module().unwrap();
+
+        let file = Program::Module(module).babelify(&babelify::Context {
+            fm: fm.clone(),
+            cm: cm.clone(),
+            comments: Default::default(),
+        });
+
+        let ctx = Context::new_without_alloc(cm, SwcComments::default(), fm);
+        op(swcify_file(file, &ctx).unwrap());
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn ts_parameter_properties() {
+    roundtrip(
+        Syntax::Typescript(TsConfig {
+            decorators: true,
+            ..Default::default()
+        }),
+        "@Injectable() export class A { constructor(private readonly b: B, c) {} }",
+        |program| {
+            let class = match &program.expect_module().body[0] {
+                ModuleItem::ModuleDecl(decl) => decl.clone().expect_export_decl().decl,
+                item => panic!("expected an export declaration, got {:?}", item),
+            }
+            .expect_class()
+            .class;
+
+            let params = match &class.body[0] {
+                ClassMember::Constructor(c) => &c.params,
+                member => panic!("expected a constructor, got {:?}", member),
+            };
+            assert!(matches!(params[0], ParamOrTsParamProp::TsParamProp(..)));
+            assert!(matches!(params[1], ParamOrTsParamProp::Param(..)));
+        },
+    );
+}
+
+#[test]
+fn ts_modules() {
+    roundtrip(
+        Syntax::Typescript(Default::default()),
+        "import fs = require('fs'); declare module 'm' { export function f(): void; } export = fs;",
+        |program| {
+            let body = program.expect_module().body;
+
+            assert_eq!(body.len(), 3);
+            assert!(matches!(
+                body[1],
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(..)))
+            ));
+        },
+    );
+}
+
+#[test]
+fn pipeline() {
+    roundtrip(
+        Syntax::Es(EsConfig {
+            pipeline_operator: Some(Default::default()),
+            ..Default::default()
+        }),
+        "a |> f(%);",
+        |program| {
+            let expr = match &program.expect_module().body[0] {
+                ModuleItem::Stmt(Stmt::Expr(s)) => s.expr.clone(),
+                item => panic!("expected an expression statement, got {:?}", item),
+            };
+            let pipeline = match *expr {
+                Expr::Pipeline(p) => p,
+                expr => panic!("expected a pipeline expression, got {:?}", expr),
+            };
+
+            assert!(matches!(*pipeline.left, Expr::Ident(..)));
+            let call = match *pipeline.right {
+                Expr::Call(c) => c,
+                expr => panic!("expected a call expression, got {:?}", expr),
+            };
+            assert!(matches!(*call.args[0].expr, Expr::TopicRef(..)));
+        },
+    );
+}
+
+#[test]
+fn do_expressions_and_records() {
+    roundtrip(
+        Syntax::Es(EsConfig {
+            do_expressions: true,
+            record_and_tuple: true,
+            ..Default::default()
+        }),
+        "x = do { 1 }; y = #{ a: 1, ...b }; z = #[1, ...c];",
+        |program| {
+            let exprs = program
+                .expect_module()
+                .body
+                .into_iter()
+                .map(|item| match item {
+                    ModuleItem::Stmt(Stmt::Expr(s)) => *s.expr.expect_assign().right,
+                    item => panic!("expected an expression statement, got {:?}", item),
+                })
+                .collect::<Vec<_>>();
+
+            match &exprs[0] {
+                Expr::Do(d) => assert_eq!(d.body.stmts.len(), 1),
+                expr => panic!("expected a do expression, got {:?}", expr),
+            }
+            match &exprs[1] {
+                Expr::Record(r) => {
+                    assert!(matches!(r.props[0], PropOrSpread::Prop(..)));
+                    assert!(matches!(r.props[1], PropOrSpread::Spread(..)));
+                }
+                expr => panic!("expected a record, got {:?}", expr),
+            }
+            match &exprs[2] {
+                Expr::Tuple(t) => {
+                    assert_eq!(t.elems.len(), 2);
+                    assert!(t.elems[0].spread.is_none());
+                    assert!(t.elems[1].spread.is_some());
+                }
+                expr => panic!("expected a tuple, got {:?}", expr),
+            }
+        },
+    );
+}
+
+#[test]
+fn unsupported_nodes() {
+    testing::run_test(false, |cm, _| {
+        let file: File = serde_json::from_value(json!({
+            "type": "File",
+            "program": {
+                "type": "Program",
+                "sourceType": "script",
+                "body": [{
+                    "type": "ExpressionStatement",
+                    "expression": {
+                        "type": "CallExpression",
+                        "start": 0,
+                        "end": 13,
+                        "callee": {
+                            "type": "V8IntrinsicIdentifier",
+                            "start": 0,
+                            "end": 11,
+                            "name": "DebugPrint"
+                        },
+                        "arguments": []
+                    }
+                }],
+                "directives": []
+            }
+        }))
+        .unwrap();
+
+        let ctx = Context::new(
+            cm,
+            Default::default(),
+            FileName::Anon,
+            "%DebugPrint()".into(),
+        );
+        let errors = swcify_file(file, &ctx).unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].kind,
+            ErrorKind::UnsupportedSyntax("v8 intrinsics")
+        );
+        assert_ne!(errors[0].span, Span::default());
+
+        Ok(())
+    })
+    .unwrap();
+}
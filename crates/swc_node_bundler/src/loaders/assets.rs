@@ -0,0 +1,173 @@
+use crate::loaders::css::StyleExtractor;
+use anyhow::{Context, Error};
+use dashmap::DashMap;
+use sha1::{Digest, Sha1};
+use std::{fs, path::Path};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::quote_str;
+use swc_ecma_visit::VisitMutWith;
+
+#[derive(Debug, Clone)]
+pub struct AssetConfig {
+    /// Assets smaller than this (in bytes) are inlined as data urls instead
+    /// of being emitted as a file.
+    pub inline_limit: usize,
+
+    /// Prepended to the name of emitted files to create urls.
+    pub public_path: String,
+
+    /// Minify emitted css.
+    pub minify: bool,
+}
+
+impl Default for AssetConfig {
+    fn default() -> Self {
+        AssetConfig {
+            inline_limit: 8 * 1024,
+            public_path: Default::default(),
+            minify: false,
+        }
+    }
+}
+
+/// Stores outputs of the loader which are not javascript.
+///
+/// Stylesheets are referenced from the bundled modules by a marker statement,
+/// and should be extracted from each bundle using [Assets::extract_styles]
+/// before printing it.
+#[derive(Debug, Default)]
+pub struct Assets {
+    config: AssetConfig,
+    /// Printed stylesheets, keyed by the id used in markers.
+    styles: DashMap<String, String>,
+    /// Files to emit, keyed by the output name.
+    files: DashMap<String, Vec<u8>>,
+}
+
+impl Assets {
+    pub fn new(config: AssetConfig) -> Self {
+        Assets {
+            config,
+            ..Default::default()
+        }
+    }
+
+    pub fn config(&self) -> &AssetConfig {
+        &self.config
+    }
+
+    pub(super) fn add_style(&self, id: String, code: String) {
+        self.styles.insert(id, code);
+    }
+
+    /// Returns the url of the file at `path`, which is either a data url or
+    /// the url of an emitted file.
+    pub(super) fn url_of(&self, path: &Path) -> Result<String, Error> {
+        let mime = mime_type(path)
+            .with_context(|| format!("`{}` is not a known asset type", path.display()))?;
+        let content = fs::read(path)
+            .with_context(|| format!("failed to read asset at `{}`", path.display()))?;
+
+        if content.len() < self.config.inline_limit {
+            return Ok(format!("data:{};base64,{}", mime, base64::encode(&content)));
+        }
+
+        let hash = {
+            let mut hasher = Sha1::new();
+            hasher.update(&content);
+            format!("{:x}", hasher.finalize())
+        };
+        let stem = path
+            .file_stem()
+            .map(|s| s.to_string_lossy())
+            .unwrap_or_default();
+        let name = match path.extension() {
+            Some(ext) => format!("{}.{}.{}", stem, &hash[..8], ext.to_string_lossy()),
+            None => format!("{}.{}", stem, &hash[..8]),
+        };
+
+        let url = format!("{}{}", self.config.public_path, name);
+        self.files.insert(name, content);
+
+        Ok(url)
+    }
+
+    /// Removes markers of stylesheets from `module` and returns the
+    /// stylesheets, concatenated in the order they are referenced.
+    ///
+    /// Returns [None] if `module` does not reference any stylesheet.
+    pub fn extract_styles(&self, module: &mut Module) -> Option<String> {
+        let mut v = StyleExtractor::default();
+        module.visit_mut_with(&mut v);
+
+        if v.ids.is_empty() {
+            return None;
+        }
+
+        let styles = v
+            .ids
+            .iter()
+            .filter_map(|id| self.styles.get(id).map(|code| code.clone()))
+            .collect::<Vec<_>>();
+
+        Some(styles.join("\n"))
+    }
+
+    /// Takes all files emitted so far, sorted by name.
+    pub fn take_files(&self) -> Vec<(String, Vec<u8>)> {
+        let mut names = self
+            .files
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect::<Vec<_>>();
+        names.sort();
+
+        names
+            .into_iter()
+            .filter_map(|name| self.files.remove(&name))
+            .collect()
+    }
+}
+
+/// Returns true if `path` should be loaded using [load_asset_as_module].
+pub(super) fn is_asset(path: &Path) -> bool {
+    mime_type(path).is_some()
+}
+
+fn mime_type(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_string_lossy().to_ascii_lowercase();
+
+    Some(match &*ext {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "ico" => "image/x-icon",
+        "bmp" => "image/bmp",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "eot" => "application/vnd.ms-fontobject",
+        _ => return None,
+    })
+}
+
+/// Creates a module which exports the url of the asset as the default export.
+pub(super) fn load_asset_as_module(path: &Path, assets: &Assets) -> Result<Module, Error> {
+    let url = assets.url_of(path)?;
+
+    Ok(Module {
+        span: DUMMY_SP,
+        body: vec![ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(
+            ExportDefaultExpr {
+                span: DUMMY_SP,
+                expr: Box::new(Expr::Lit(Lit::Str(quote_str!(url)))),
+            },
+        ))],
+        shebang: None,
+    })
+}
@@ -0,0 +1,280 @@
+use crate::loaders::assets::{is_asset, Assets};
+use anyhow::{bail, Context, Error};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use swc_common::{collections::AHashSet, errors::Handler, SourceFile, SourceMap, DUMMY_SP};
+use swc_css_ast::{AtRule, Function, ImportHref, ImportRule, Rule, Stylesheet, UrlValue, Value};
+use swc_css_codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use swc_css_parser::{parse_file, parser::ParserConfig};
+use swc_css_visit::{VisitMut as CssVisitMut, VisitMutWith as CssVisitMutWith};
+use swc_ecma_ast::*;
+use swc_ecma_utils::quote_str;
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+/// Prefix of the string literal statements used to mark usages of
+/// stylesheets.
+///
+/// A string literal statement does nothing at runtime and is not removed by
+/// the dead code elimination of the bundler.
+const STYLE_MARKER: &str = "__swcpack_css__:";
+
+/// Stores the printed stylesheet in `assets` and creates a module which
+/// marks the usage of it.
+///
+/// `@import`s of local stylesheets are replaced with the imported rules, and
+/// relative urls of known asset types are replaced with the urls of assets.
+pub(super) fn load_css_as_module(
+    cm: &SourceMap,
+    handler: &Handler,
+    fm: &Arc<SourceFile>,
+    path: &Path,
+    assets: &Assets,
+) -> Result<Module, Error> {
+    let mut imported = AHashSet::default();
+    imported.insert(fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf()));
+
+    let ss = load_stylesheet(cm, handler, fm, path, assets, &mut imported)?;
+
+    let mut code = String::new();
+    {
+        let mut wr = BasicCssWriter::new(&mut code, BasicCssWriterConfig { indent: "  " });
+        let mut gen = CodeGenerator::new(
+            &mut wr,
+            CodegenConfig {
+                minify: assets.config().minify,
+            },
+        );
+
+        gen.emit(&ss)
+            .with_context(|| format!("failed to print css file at {}", fm.name))?;
+    }
+
+    let id = fm.name.to_string();
+    let marker = ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+        span: DUMMY_SP,
+        expr: Box::new(Expr::Lit(Lit::Str(quote_str!(format!(
+            "{}{}",
+            STYLE_MARKER, id
+        ))))),
+    }));
+    assets.add_style(id, code);
+
+    Ok(Module {
+        span: DUMMY_SP,
+        body: vec![marker],
+        shebang: None,
+    })
+}
+
+/// Parses the stylesheet at `path` and inlines the stylesheets imported by it.
+///
+/// `imported` contains the canonical paths of stylesheets which are already
+/// inlined. Those are skipped, like browsers skip cyclic imports.
+fn load_stylesheet(
+    cm: &SourceMap,
+    handler: &Handler,
+    fm: &Arc<SourceFile>,
+    path: &Path,
+    assets: &Assets,
+    imported: &mut AHashSet<PathBuf>,
+) -> Result<Stylesheet, Error> {
+    let mut errors = vec![];
+    let ss: Result<Stylesheet, _> = parse_file(
+        fm,
+        ParserConfig {
+            parse_values: true,
+            ..Default::default()
+        },
+        &mut errors,
+    );
+    for err in errors {
+        err.to_diagnostics(handler).emit();
+    }
+    let mut ss = match ss {
+        Ok(ss) => ss,
+        Err(err) => {
+            err.to_diagnostics(handler).emit();
+            bail!("failed to parse css file at {}", fm.name)
+        }
+    };
+    let dir = path.parent().unwrap_or(path);
+
+    {
+        let mut v = UrlRewriter {
+            dir,
+            assets,
+            error: None,
+        };
+        ss.visit_mut_with(&mut v);
+        if let Some(err) = v.error {
+            return Err(err);
+        }
+    }
+
+    let mut rules = Vec::with_capacity(ss.rules.len());
+    for rule in ss.rules {
+        let import = match rule {
+            Rule::AtRule(AtRule::Import(import)) => import,
+            _ => {
+                rules.push(rule);
+                continue;
+            }
+        };
+
+        let href = import_href(&import);
+        if !(href.starts_with("./") || href.starts_with("../")) {
+            bail!(
+                "`@import \"{}\"` in {} cannot be bundled; only relative imports are supported",
+                href,
+                fm.name
+            );
+        }
+        if import.media.is_some() || import.layer_name.is_some() {
+            bail!(
+                "`@import \"{}\"` in {} cannot be bundled, because it has media queries or a layer",
+                href,
+                fm.name
+            );
+        }
+
+        let path = dir.join(href);
+        let path = fs::canonicalize(&path)
+            .with_context(|| format!("failed to resolve `{}` imported by {}", href, fm.name))?;
+        if !imported.insert(path.clone()) {
+            continue;
+        }
+
+        let fm = cm
+            .load_file(&path)
+            .with_context(|| format!("failed to load css file at {}", path.display()))?;
+        let nested = load_stylesheet(cm, handler, &fm, &path, assets, imported)?;
+        rules.extend(nested.rules);
+    }
+    ss.rules = rules;
+
+    Ok(ss)
+}
+
+/// Returns the url of `@import "url"`, `@import url(url)` or
+/// `@import url("url")`.
+fn import_href(import: &ImportRule) -> &str {
+    match &import.href {
+        ImportHref::Str(s) => &*s.value,
+        ImportHref::Url(url) => &*url.url,
+        ImportHref::Function(f) => match &*f.value {
+            [Value::Str(s)] if &*f.name.value == "url" => &*s.value,
+            _ => "",
+        },
+    }
+}
+
+struct UrlRewriter<'a> {
+    dir: &'a Path,
+    assets: &'a Assets,
+    error: Option<Error>,
+}
+
+impl UrlRewriter<'_> {
+    fn rewrite(&mut self, url: &str) -> Option<String> {
+        if self.error.is_some() || !(url.starts_with("./") || url.starts_with("../")) {
+            return None;
+        }
+
+        // Preserve query and fragment, which are used for hacks like
+        // `font.eot?#iefix`.
+        let (file, suffix) = match url.find(|c| c == '?' || c == '#') {
+            Some(idx) => url.split_at(idx),
+            None => (url, ""),
+        };
+        let path = self.dir.join(file);
+        if !is_asset(&path) {
+            return None;
+        }
+
+        match self.assets.url_of(&path) {
+            Ok(url) => Some(format!("{}{}", url, suffix)),
+            Err(err) => {
+                self.error = Some(err);
+                None
+            }
+        }
+    }
+}
+
+impl CssVisitMut for UrlRewriter<'_> {
+    fn visit_mut_url_value(&mut self, n: &mut UrlValue) {
+        if let Some(url) = self.rewrite(&n.url) {
+            n.url = url.clone().into();
+            n.raw = url.into();
+        }
+    }
+
+    fn visit_mut_function(&mut self, n: &mut Function) {
+        n.visit_mut_children_with(self);
+
+        if &*n.name.value != "url" {
+            return;
+        }
+
+        if let [Value::Str(s)] = &mut *n.value {
+            if let Some(url) = self.rewrite(&s.value) {
+                s.raw = format!("\"{}\"", url).into();
+                s.value = url.into();
+            }
+        }
+    }
+}
+
+/// Removes markers created by [load_css_as_module] and collects ids of
+/// stylesheets.
+#[derive(Default)]
+pub(super) struct StyleExtractor {
+    pub ids: Vec<String>,
+}
+
+impl StyleExtractor {
+    /// Returns `false` if `stmt` is a marker.
+    fn check(&mut self, stmt: &Stmt) -> bool {
+        let id = match stmt {
+            Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+                Expr::Lit(Lit::Str(s)) => match s.value.strip_prefix(STYLE_MARKER) {
+                    Some(id) => id.to_string(),
+                    None => return true,
+                },
+                _ => return true,
+            },
+            _ => return true,
+        };
+
+        if !self.ids.contains(&id) {
+            self.ids.push(id);
+        }
+
+        false
+    }
+}
+
+impl VisitMut for StyleExtractor {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+
+        items.retain(|item| match item {
+            ModuleItem::Stmt(stmt) => self.check(stmt),
+            _ => true,
+        });
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+
+        stmts.retain(|stmt| self.check(stmt));
+    }
+}
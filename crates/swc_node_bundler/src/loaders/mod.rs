@@ -1,2 +1,4 @@
+pub mod assets;
+mod css;
 mod json;
 pub mod swc;
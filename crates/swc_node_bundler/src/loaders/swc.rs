@@ -1,13 +1,17 @@
-use crate::loaders::json::load_json_as_module;
+use crate::loaders::{
+    assets::{is_asset, load_asset_as_module, Assets},
+    css::load_css_as_module,
+    json::load_json_as_module,
+};
 use anyhow::{bail, Context, Error};
 use helpers::Helpers;
-use std::{collections::HashMap, env, sync::Arc};
+use std::{collections::HashMap, env, fs, path::PathBuf, sync::Arc};
 use swc::{
     config::{GlobalInliningPassEnvs, InputSourceMap, IsModule, JscConfig, TransformConfig},
     try_with_handler,
 };
 use swc_atoms::JsWord;
-use swc_bundler::{Load, ModuleData};
+use swc_bundler::{Cache, Load, ModuleData};
 use swc_common::{
     collections::AHashMap,
     errors::{Handler, HANDLER},
@@ -27,14 +31,77 @@ use swc_ecma_transforms::{
 use swc_ecma_visit::FoldWith;
 
 /// JavaScript loader
+///
+/// Stylesheets and assets like images or fonts are stored in [Assets], and
+/// can be imported only if it's configured using [SwcLoader::with_assets].
 pub struct SwcLoader {
     compiler: Arc<swc::Compiler>,
     options: swc::config::Options,
+    assets: Option<Arc<Assets>>,
+    cache: Option<Cache>,
 }
 
 impl SwcLoader {
     pub fn new(compiler: Arc<swc::Compiler>, options: swc::config::Options) -> Self {
-        SwcLoader { compiler, options }
+        SwcLoader {
+            compiler,
+            options,
+            assets: None,
+            cache: None,
+        }
+    }
+
+    /// Use `assets` to store stylesheets and assets.
+    ///
+    /// Bundles importing stylesheets contain markers, so
+    /// [Assets::extract_styles] should be called for each bundle before
+    /// printing it.
+    pub fn with_assets(mut self, assets: Arc<Assets>) -> Self {
+        self.assets = Some(assets);
+        self
+    }
+
+    pub fn assets(&self) -> Option<&Arc<Assets>> {
+        self.assets.as_ref()
+    }
+
+    fn assets_for(&self, name: &FileName) -> Result<&Assets, Error> {
+        match &self.assets {
+            Some(assets) => Ok(assets),
+            None => bail!(
+                "`{}` cannot be imported because assets are not configured for the loader",
+                name
+            ),
+        }
+    }
+
+    /// Store transformed javascript files in `dir`, so unchanged files are not
+    /// processed again by the next build.
+    ///
+    /// `key` should be changed if the options are changed. Entries are also
+    /// invalidated if the version of swc, environment variables used by the
+    /// options, or the `.swcrc` file in the working directory is changed.
+    /// Stylesheets and assets are not cached.
+    pub fn with_cache(mut self, dir: impl Into<PathBuf>, key: &str) -> Self {
+        let mut envs = self
+            .env_map()
+            .iter()
+            .map(|(k, v)| format!("{}={:?}", k, v))
+            .collect::<Vec<_>>();
+        envs.sort();
+        let swcrc = fs::read_to_string(self.options.cwd.join(".swcrc")).unwrap_or_default();
+
+        let key = format!(
+            "{}\n{}\n{:?}\n{}\n{}",
+            swc::VERSION,
+            key,
+            env::var("NODE_ENV").ok(),
+            envs.join("\n"),
+            swcrc
+        );
+
+        self.cache = Some(Cache::new(dir, key));
+        self
     }
 
     fn env_map(&self) -> Lrc<AHashMap<JsWord, Expr>> {
@@ -122,6 +189,21 @@ impl SwcLoader {
             }
         }
 
+        if let FileName::Real(path) = name {
+            // Assets are not utf-8 in general, so we should not load them using
+            // `SourceMap`.
+            if is_asset(path) {
+                let fm = self.compiler.cm.new_source_file(name.clone(), "".into());
+                let module = load_asset_as_module(path, self.assets_for(name)?)
+                    .with_context(|| format!("failed to load asset at {}", fm.name))?;
+                return Ok(ModuleData {
+                    fm,
+                    module,
+                    helpers: Default::default(),
+                });
+            }
+        }
+
         let fm = self
             .compiler
             .cm
@@ -142,11 +224,40 @@ impl SwcLoader {
                         helpers: Default::default(),
                     });
                 }
+
+                if ext == "css" {
+                    let module = load_css_as_module(
+                        &self.compiler.cm,
+                        handler,
+                        &fm,
+                        path,
+                        self.assets_for(name)?,
+                    )
+                    .with_context(|| format!("failed to load css file at {}", fm.name))?;
+                    return Ok(ModuleData {
+                        fm,
+                        module,
+                        helpers: Default::default(),
+                    });
+                }
             }
         }
 
         tracing::trace!("JsLoader.load: loaded");
 
+        if let Some(cache) = &self.cache {
+            match cache.get(&fm, Some(self.compiler.comments())) {
+                Ok(Some(data)) => {
+                    tracing::trace!("JsLoader.load: using cached module");
+                    return Ok(data);
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    tracing::warn!("Ignoring the cache entry of `{}`: {:?}", fm.name, err);
+                }
+            }
+        }
+
         let program = if fm.name.to_string().contains("node_modules") {
             let program = self.compiler.parse_js(
                 fm.clone(),
@@ -257,14 +368,23 @@ impl SwcLoader {
             program
         };
 
-        match program {
-            Program::Module(module) => Ok(ModuleData {
+        let data = match program {
+            Program::Module(module) => ModuleData {
                 fm,
                 module,
                 helpers,
-            }),
+            },
             _ => unreachable!(),
+        };
+
+        if let Some(cache) = &self.cache {
+            // A broken cache should not break the build.
+            if let Err(err) = cache.put(&data, Some(self.compiler.comments())) {
+                tracing::warn!("Failed to cache `{}`: {:?}", data.fm.name, err);
+            }
         }
+
+        Ok(data)
     }
 }
 
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename = "Cache", rename_all = "camelCase")]
+pub struct CacheConfig {
+    /// Directory to store transformed modules in.
+    pub dir: PathBuf,
+}
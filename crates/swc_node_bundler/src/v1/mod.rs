@@ -1,9 +1,11 @@
 pub use self::{
+    cache::CacheConfig,
     module::ModuleConfig,
-    optimization::OptimizationConfig,
-    output::OutputConfig,
+    optimization::{CacheGroupConfig, ChunkMatcher, OptimizationConfig, SplitChunksConfig},
+    output::{OutputConfig, OutputFormat},
     resolve::{AliasConfig, ResolveConfig},
 };
+use crate::loaders::assets::AssetConfig;
 use serde::Deserialize;
 use std::{collections::HashMap, fmt, marker::PhantomData, path::PathBuf};
 use string_enum::StringEnum;
@@ -12,6 +14,7 @@ use swc_common::{collections::AHashMap, FileName};
 use swc_ecma_ast::EsVersion;
 use swc_ecma_loader::TargetEnv;
 
+mod cache;
 mod module;
 mod optimization;
 mod output;
@@ -51,6 +54,10 @@ pub struct Config {
 
     #[serde(default)]
     pub alias: AHashMap<TargetEnv, AHashMap<String, String>>,
+
+    /// Persistent cache for transformed modules.
+    #[serde(default)]
+    pub cache: Option<CacheConfig>,
 }
 
 impl Config {
@@ -59,6 +66,22 @@ impl Config {
             .as_ref()
             .and_then(|options| options.codegen_target())
     }
+
+    pub fn asset_config(&self) -> AssetConfig {
+        AssetConfig {
+            inline_limit: self.module.asset_inline_limit,
+            public_path: self
+                .output
+                .as_ref()
+                .map(|output| output.public_path.clone())
+                .unwrap_or_default(),
+            minify: self
+                .options
+                .as_ref()
+                .map(|options| options.config.minify)
+                .unwrap_or(false),
+        }
+    }
 }
 
 #[derive(StringEnum)]
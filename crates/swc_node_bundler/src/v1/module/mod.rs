@@ -1,5 +1,23 @@
+use crate::loaders::assets::AssetConfig;
 use serde::Deserialize;
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename = "Module", rename_all = "camelCase")]
-pub struct ModuleConfig {}
+pub struct ModuleConfig {
+    /// Images and fonts smaller than this (in bytes) are inlined as data
+    /// urls.
+    #[serde(default = "default_asset_inline_limit")]
+    pub asset_inline_limit: usize,
+}
+
+impl Default for ModuleConfig {
+    fn default() -> Self {
+        ModuleConfig {
+            asset_inline_limit: default_asset_inline_limit(),
+        }
+    }
+}
+
+fn default_asset_inline_limit() -> usize {
+    AssetConfig::default().inline_limit
+}
@@ -1,5 +1,10 @@
 use crate::v1::JsCallback;
+use anyhow::{Context, Error};
+use regex::Regex;
 use serde::Deserialize;
+use std::{cmp::Reverse, collections::BTreeMap};
+use swc_bundler::ChunkConfig;
+use swc_common::FileName;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename = "Optimization", rename_all = "camelCase")]
@@ -11,5 +16,96 @@ pub struct OptimizationConfig {
     pub minimizer: Option<JsCallback<String, String>>,
 
     #[serde(default)]
-    pub split_chunks: Option<()>,
+    pub split_chunks: Option<SplitChunksConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename = "SplitChunks", rename_all = "camelCase")]
+pub struct SplitChunksConfig {
+    /// Minimum number of entries which should share a module before
+    /// extracting it to a shared chunk.
+    #[serde(default = "default_min_chunks")]
+    pub min_chunks: usize,
+
+    /// Minimum size of an automatically extracted chunk, in bytes.
+    #[serde(default = "default_min_size")]
+    pub min_size: usize,
+
+    /// Manual chunks, by name.
+    ///
+    /// If a module matches multiple groups, the group with the highest
+    /// `priority` is used. Groups with the same priority are tried in the
+    /// order of their names.
+    #[serde(default)]
+    pub cache_groups: BTreeMap<String, CacheGroupConfig>,
+}
+
+fn default_min_chunks() -> usize {
+    ChunkConfig::default().min_shared
+}
+
+fn default_min_size() -> usize {
+    ChunkConfig::default().min_size
+}
+
+impl SplitChunksConfig {
+    pub fn chunk_config(&self) -> ChunkConfig {
+        ChunkConfig {
+            min_shared: self.min_chunks,
+            min_size: self.min_size,
+        }
+    }
+
+    pub fn matcher(&self) -> Result<ChunkMatcher, Error> {
+        let mut groups = self
+            .cache_groups
+            .iter()
+            .map(|(name, group)| -> Result<_, Error> {
+                let test = Regex::new(&group.test)
+                    .with_context(|| format!("invalid `test` of cache group `{}`", name))?;
+
+                Ok((group.priority, name.clone(), test))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // The sort is stable, so groups with the same priority are sorted by name.
+        groups.sort_by_key(|(priority, ..)| Reverse(*priority));
+
+        let groups = groups
+            .into_iter()
+            .map(|(_, name, test)| (name, test))
+            .collect();
+
+        Ok(ChunkMatcher { groups })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename = "CacheGroup", rename_all = "camelCase")]
+pub struct CacheGroupConfig {
+    /// Modules with path matching this regex are moved to the chunk.
+    pub test: String,
+
+    /// Groups with higher priority are preferred if a module matches multiple
+    /// groups.
+    #[serde(default)]
+    pub priority: i32,
+}
+
+/// Assigns modules to chunks using `cacheGroups`.
+#[derive(Debug, Default)]
+pub struct ChunkMatcher {
+    groups: Vec<(String, Regex)>,
+}
+
+impl ChunkMatcher {
+    /// Returns the name of the cache group with the highest priority among
+    /// groups matching `file_name`.
+    pub fn chunk_name(&self, file_name: &FileName) -> Option<String> {
+        let path = file_name.to_string();
+
+        self.groups
+            .iter()
+            .find(|(_, test)| test.is_match(&path))
+            .map(|(name, _)| name.clone())
+    }
 }
@@ -1,5 +1,8 @@
+use anyhow::{bail, Error};
 use serde::Deserialize;
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf};
+use string_enum::StringEnum;
+use swc_bundler::ModuleType;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename = "Output")]
@@ -7,6 +10,57 @@ use std::path::PathBuf;
 pub struct OutputConfig {
     pub path: PathBuf,
 
+    /// Used as the name of the global variable if `format` is `umd`.
     #[serde(default)]
     pub name: String,
+
+    #[serde(default)]
+    pub format: OutputFormat,
+
+    /// Names of global variables for external modules, used if `format` is
+    /// `umd`.
+    #[serde(default)]
+    pub globals: BTreeMap<String, String>,
+
+    /// Prepended to the names of emitted assets to create urls.
+    #[serde(default)]
+    pub public_path: String,
+}
+
+impl OutputConfig {
+    pub fn module_type(&self) -> Result<ModuleType, Error> {
+        Ok(match self.format {
+            OutputFormat::Es => ModuleType::Es,
+            OutputFormat::Iife => ModuleType::Iife,
+            OutputFormat::Cjs => ModuleType::Cjs,
+            OutputFormat::Umd => {
+                if self.name.is_empty() {
+                    bail!("`output.name` is required to bundle as an umd module")
+                }
+
+                ModuleType::Umd {
+                    name: self.name.clone(),
+                    globals: self.globals.clone(),
+                }
+            }
+        })
+    }
+}
+
+#[derive(StringEnum, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// `es`
+    Es,
+    /// `iife`
+    Iife,
+    /// `cjs`
+    Cjs,
+    /// `umd`
+    Umd,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        OutputFormat::Es
+    }
 }
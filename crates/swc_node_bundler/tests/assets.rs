@@ -0,0 +1,127 @@
+use std::{fs, path::PathBuf, sync::Arc};
+use swc_bundler::Load;
+use swc_common::{FileName, GLOBALS};
+use swc_ecma_ast::{Expr, Lit, ModuleDecl, ModuleItem};
+use swc_node_bundler::loaders::{
+    assets::{AssetConfig, Assets},
+    swc::SwcLoader,
+};
+
+fn logo() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("pass")
+        .join("css")
+        .join("basic")
+        .join("input")
+        .join("logo.svg")
+}
+
+/// Returns the default export of the module created for the asset at `path`.
+fn load_url(loader: &SwcLoader, path: PathBuf) -> String {
+    let data = loader.load(&FileName::Real(path)).unwrap();
+
+    match &*data.module.body {
+        [ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export))] => match &*export.expr {
+            Expr::Lit(Lit::Str(s)) => s.value.to_string(),
+            _ => unreachable!("an asset should be exported as a string"),
+        },
+        _ => unreachable!("an asset should be loaded as a default export"),
+    }
+}
+
+#[test]
+fn emit_file() {
+    testing::run_test2(false, |cm, _| {
+        let compiler = Arc::new(swc::Compiler::new(cm));
+
+        GLOBALS.set(compiler.globals(), || {
+            let assets = Arc::new(Assets::new(AssetConfig {
+                inline_limit: 0,
+                public_path: "/static/".into(),
+                minify: false,
+            }));
+            let loader =
+                SwcLoader::new(compiler.clone(), Default::default()).with_assets(assets.clone());
+
+            let url = load_url(&loader, logo());
+            let files = assets.take_files();
+
+            assert_eq!(files.len(), 1);
+            let (name, content) = &files[0];
+            assert!(
+                name.starts_with("logo.") && name.ends_with(".svg"),
+                "{}",
+                name
+            );
+            assert_eq!(url, format!("/static/{}", name));
+            assert_eq!(*content, fs::read(logo()).unwrap());
+
+            Ok(())
+        })
+    })
+    .unwrap();
+}
+
+#[test]
+fn inline_data_url() {
+    testing::run_test2(false, |cm, _| {
+        let compiler = Arc::new(swc::Compiler::new(cm));
+
+        GLOBALS.set(compiler.globals(), || {
+            let assets = Arc::new(Assets::default());
+            let loader =
+                SwcLoader::new(compiler.clone(), Default::default()).with_assets(assets.clone());
+
+            let url = load_url(&loader, logo());
+
+            assert!(url.starts_with("data:image/svg+xml;base64,"), "{}", url);
+            assert!(assets.take_files().is_empty());
+
+            Ok(())
+        })
+    })
+    .unwrap();
+}
+
+#[test]
+fn assets_not_configured() {
+    testing::run_test2(false, |cm, _| {
+        let compiler = Arc::new(swc::Compiler::new(cm));
+
+        GLOBALS.set(compiler.globals(), || {
+            let loader = SwcLoader::new(compiler.clone(), Default::default());
+
+            assert!(loader.load(&FileName::Real(logo())).is_err());
+
+            Ok(())
+        })
+    })
+    .unwrap();
+}
+
+#[test]
+fn css_import_not_relative() {
+    testing::run_test2(false, |cm, _| {
+        let compiler = Arc::new(swc::Compiler::new(cm));
+
+        GLOBALS.set(compiler.globals(), || {
+            let loader = SwcLoader::new(compiler.clone(), Default::default())
+                .with_assets(Arc::new(Assets::default()));
+            let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("css")
+                .join("bare_import.css");
+
+            let err = loader.load(&FileName::Real(path)).unwrap_err();
+            assert!(
+                format!("{:?}", err).contains("only relative imports are supported"),
+                "{:?}",
+                err
+            );
+
+            Ok(())
+        })
+    })
+    .unwrap();
+}
@@ -17,7 +17,7 @@ use swc_ecma_ast::{
 use swc_ecma_loader::{TargetEnv, NODE_BUILTINS};
 use swc_ecma_transforms::fixer;
 use swc_ecma_visit::FoldWith;
-use swc_node_bundler::loaders::swc::SwcLoader;
+use swc_node_bundler::loaders::{assets::Assets, swc::SwcLoader};
 use testing::NormalizedOutput;
 
 #[testing::fixture("tests/pass/**/input")]
@@ -51,13 +51,15 @@ fn pass(input_dir: PathBuf) {
         let compiler = Arc::new(swc::Compiler::new(cm.clone()));
 
         GLOBALS.set(compiler.globals(), || {
+            let assets = Arc::new(Assets::default());
             let loader = SwcLoader::new(
                 compiler.clone(),
                 swc::config::Options {
                     swcrc: true,
                     ..Default::default()
                 },
-            );
+            )
+            .with_assets(assets.clone());
             let mut bundler = Bundler::new(
                 compiler.globals(),
                 cm.clone(),
@@ -80,7 +82,9 @@ fn pass(input_dir: PathBuf) {
 
             let mut error = false;
 
-            for bundled in modules {
+            for mut bundled in modules {
+                let styles = assets.extract_styles(&mut bundled.module);
+
                 let code = compiler
                     .print(
                         &bundled.module.fold_with(&mut fixer(None)),
@@ -128,6 +132,18 @@ fn pass(input_dir: PathBuf) {
                         error = true;
                     }
                 }
+
+                if let Some(styles) = styles {
+                    let s = NormalizedOutput::from(styles);
+
+                    match s.compare_to_file(&output_path.with_extension("css")) {
+                        Ok(_) => {}
+                        Err(err) => {
+                            println!("Diff: {:?}", err);
+                            error = true;
+                        }
+                    }
+                }
             }
 
             if error {
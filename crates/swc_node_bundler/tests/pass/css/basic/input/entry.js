@@ -0,0 +1,4 @@
+import "./a.css";
+import logo from "./logo.svg";
+
+console.log(logo);
@@ -160,6 +160,13 @@ import {
   TsConstAssertion,
   Import,
   SuperPropExpression,
+  BigIntLiteral,
+  StaticBlock,
+  PipelineExpression,
+  TopicReference,
+  DoExpression,
+  RecordExpression,
+  TupleExpression,
 } from "./types";
 
 export class Visitor {
@@ -261,7 +268,7 @@ export class Visitor {
   visitTsExternalModuleReference(
     n: TsExternalModuleReference
   ): TsExternalModuleReference {
-    n.expression = this.visitExpression(n.expression);
+    n.expression = this.visitStringLiteral(n.expression);
     return n;
   }
 
@@ -296,15 +303,19 @@ export class Visitor {
     }
   }
   visitNamedExportSpecifier(n: NamedExportSpecifier): ExportSpecifier {
-    if (n.exported) {
+    if (n.exported && n.exported.type === "Identifier") {
       n.exported = this.visitBindingIdentifier(n.exported);
     }
-    n.orig = this.visitIdentifierReference(n.orig);
+    if (n.orig.type === "Identifier") {
+      n.orig = this.visitIdentifierReference(n.orig);
+    }
     return n;
   }
 
   visitExportNamespaceSpecifier(n: ExportNamespaceSpecifier): ExportSpecifier {
-    n.name = this.visitBindingIdentifier(n.name);
+    if (n.name.type === "Identifier") {
+      n.name = this.visitBindingIdentifier(n.name);
+    }
     return n;
   }
 
@@ -314,11 +325,12 @@ export class Visitor {
   }
 
   visitOptionalStringLiteral(
-    n: StringLiteral | undefined
-  ): StringLiteral | undefined {
+    n: StringLiteral | null
+  ): StringLiteral | null {
     if (n) {
       return this.visitStringLiteral(n);
     }
+    return null;
   }
 
   visitExportDefaultDeclaration(
@@ -368,11 +380,12 @@ export class Visitor {
   }
 
   visitArrayElement(
-    e: ExprOrSpread | undefined
-  ): ExprOrSpread | undefined {
+    e: ExprOrSpread | null
+  ): ExprOrSpread | null {
     if (e) {
       return this.visitExprOrSpread(e);
     }
+    return null;
   }
 
   visitExprOrSpread(e: ExprOrSpread): ExprOrSpread {
@@ -387,10 +400,11 @@ export class Visitor {
     return e;
   }
 
-  visitOptionalExpression(e: Expression | undefined): Expression | undefined {
+  visitOptionalExpression(e: Expression | null): Expression | null {
     if (e) {
       return this.visitExpression(e);
     }
+    return null;
   }
 
   visitArrowFunctionExpression(e: ArrowFunctionExpression): Expression {
@@ -501,10 +515,11 @@ export class Visitor {
     return stmt;
   }
 
-  visitOptionalStatement(stmt: Statement | undefined): Statement | undefined {
+  visitOptionalStatement(stmt: Statement | null): Statement | null {
     if (stmt) {
       return this.visitStatement(stmt);
     }
+    return null;
   }
 
   visitBreakStatement(stmt: BreakStatement): Statement {
@@ -530,7 +545,7 @@ export class Visitor {
     return stmt;
   }
 
-  visitCatchClause(handler: CatchClause | undefined): CatchClause | undefined {
+  visitCatchClause(handler: CatchClause | null): CatchClause | null {
     if (handler) {
       if (handler.param) {
         handler.param = this.visitPattern(handler.param);
@@ -677,14 +692,12 @@ export class Visitor {
     }
   }
 
-  visitTsNamespaceBody(n: TsNamespaceBody): TsNamespaceBody | undefined {
-    if (n) {
-      switch (n.type) {
-        case "TsModuleBlock":
-          return this.visitTsModuleBlock(n);
-        case "TsNamespaceDeclaration":
-          return this.visitTsNamespaceDeclaration(n);
-      }
+  visitTsNamespaceBody(n: TsNamespaceBody): TsNamespaceBody {
+    switch (n.type) {
+      case "TsModuleBlock":
+        return this.visitTsModuleBlock(n);
+      case "TsNamespaceDeclaration":
+        return this.visitTsNamespaceDeclaration(n);
     }
   }
 
@@ -724,8 +737,25 @@ export class Visitor {
   }
 
   visitTsTypeElement(n: TsTypeElement): TsTypeElement {
-    n.params = this.visitTsFnParameters(n.params);
-    n.typeAnnotation = this.visitTsTypeAnnotation(n.typeAnnotation);
+    switch (n.type) {
+      case "TsCallSignatureDeclaration":
+      case "TsConstructSignatureDeclaration":
+      case "TsPropertySignature":
+      case "TsIndexSignature":
+        n.params = this.visitTsFnParameters(n.params);
+        n.typeAnnotation = this.visitTsTypeAnnotation(n.typeAnnotation);
+        break;
+      case "TsGetterSignature":
+        n.typeAnnotation = this.visitTsTypeAnnotation(n.typeAnnotation);
+        break;
+      case "TsSetterSignature":
+        n.param = this.visitTsFnParameter(n.param);
+        break;
+      case "TsMethodSignature":
+        n.params = this.visitTsFnParameters(n.params);
+        n.typeAnn = this.visitTsTypeAnnotation(n.typeAnn);
+        break;
+    }
     return n;
   }
 
@@ -785,9 +815,18 @@ export class Visitor {
         return this.visitPrivateProperty(member);
       case "TsIndexSignature":
         return this.visitTsIndexSignature(member);
+      case "StaticBlock":
+        return this.visitStaticBlock(member);
+      case "EmptyStatement":
+        return member;
     }
   }
 
+  visitStaticBlock(n: StaticBlock): ClassMember {
+    n.body = this.visitBlockStatement(n.body);
+    return n;
+  }
+
   visitTsIndexSignature(n: TsIndexSignature): ClassMember {
     n.params = this.visitTsFnParameters(n.params);
     n.typeAnnotation = this.visitTsTypeAnnotation(n.typeAnnotation);
@@ -873,19 +912,21 @@ export class Visitor {
         return this.visitStringLiteral(key);
       case "NumericLiteral":
         return this.visitNumericLiteral(key);
+      case "BigIntLiteral":
+        return this.visitBigIntLiteral(key);
       default:
         return this.visitComputedPropertyKey(key);
     }
   }
 
-  visitAccessibility(n: Accessibility | undefined): Accessibility | undefined {
+  visitAccessibility(n: Accessibility | null): Accessibility | null {
     return n;
   }
 
   visitClassProperty(n: ClassProperty): ClassMember {
     n.accessibility = this.visitAccessibility(n.accessibility);
     n.decorators = this.visitDecorators(n.decorators);
-    n.key = this.visitExpression(n.key);
+    n.key = this.visitPropertyName(n.key);
     n.typeAnnotation = this.visitTsTypeAnnotation(n.typeAnnotation);
     n.value = this.visitOptionalExpression(n.value);
     return n;
@@ -906,6 +947,8 @@ export class Visitor {
         return this.visitNumericLiteral(n);
       case "StringLiteral":
         return this.visitStringLiteral(n);
+      case "BigIntLiteral":
+        return this.visitBigIntLiteral(n);
       case "Computed":
         return this.visitComputedPropertyKey(n);
     }
@@ -957,8 +1000,8 @@ export class Visitor {
   }
 
   visitTsTypeParameterInstantiation(
-    n: TsTypeParameterInstantiation | undefined
-  ): TsTypeParameterInstantiation | undefined {
+    n: TsTypeParameterInstantiation | null
+  ): TsTypeParameterInstantiation | null {
     if (n) {
       n.params = this.visitTsTypes(n.params);
     }
@@ -984,10 +1027,8 @@ export class Visitor {
     return n;
   }
 
-  visitDecorators(nodes: Decorator[] | undefined): Decorator[] | undefined {
-    if (nodes) {
-      return nodes.map(this.visitDecorator.bind(this));
-    }
+  visitDecorators(nodes: Decorator[]): Decorator[] {
+    return nodes.map(this.visitDecorator.bind(this));
   }
 
   visitDecorator(n: Decorator): Decorator {
@@ -1020,6 +1061,8 @@ export class Visitor {
         return this.visitAwaitExpression(n);
       case "BinaryExpression":
         return this.visitBinaryExpression(n);
+      case "BigIntLiteral":
+        return this.visitBigIntLiteral(n);
       case "BooleanLiteral":
         return this.visitBooleanLiteral(n);
       case "CallExpression":
@@ -1090,6 +1133,16 @@ export class Visitor {
         return this.visitYieldExpression(n);
       case "OptionalChainingExpression":
         return this.visitOptionalChainingExpression(n);
+      case "PipelineExpression":
+        return this.visitPipelineExpression(n);
+      case "TopicReference":
+        return this.visitTopicReference(n);
+      case "DoExpression":
+        return this.visitDoExpression(n);
+      case "RecordExpression":
+        return this.visitRecordExpression(n);
+      case "TupleExpression":
+        return this.visitTupleExpression(n);
       case "Invalid":
         return n;
     }
@@ -1099,6 +1152,31 @@ export class Visitor {
     return n;
   }
 
+  visitPipelineExpression(n: PipelineExpression): Expression {
+    n.left = this.visitExpression(n.left);
+    n.right = this.visitExpression(n.right);
+    return n;
+  }
+
+  visitTopicReference(n: TopicReference): Expression {
+    return n;
+  }
+
+  visitDoExpression(n: DoExpression): Expression {
+    n.body = this.visitBlockStatement(n.body);
+    return n;
+  }
+
+  visitRecordExpression(n: RecordExpression): Expression {
+    n.properties = this.visitObjectProperties(n.properties);
+    return n;
+  }
+
+  visitTupleExpression(n: TupleExpression): Expression {
+    n.elements = n.elements.map(this.visitExprOrSpread.bind(this));
+    return n;
+  }
+
   visitAssignmentExpression(n: AssignmentExpression): Expression {
     n.left = this.visitPatternOrExpressison(n.left);
     n.right = this.visitExpression(n.right);
@@ -1292,8 +1370,8 @@ export class Visitor {
   }
 
   visitTsTypeArguments(
-    n: TsTypeParameterInstantiation | undefined
-  ): TsTypeParameterInstantiation | undefined {
+    n: TsTypeParameterInstantiation | null
+  ): TsTypeParameterInstantiation | null {
     if (n) {
       n.params = this.visitTsTypes(n.params);
     }
@@ -1436,8 +1514,8 @@ export class Visitor {
   }
 
   visitJSXClosingElement(
-    n: JSXClosingElement | undefined
-  ): JSXClosingElement | undefined {
+    n: JSXClosingElement | null
+  ): JSXClosingElement | null {
     if (n) {
       n.name = this.visitJSXElementName(n.name);
     }
@@ -1463,9 +1541,9 @@ export class Visitor {
   }
 
   visitJSXAttributes(
-    attrs: JSXAttributeOrSpread[] | undefined
-  ): JSXAttributeOrSpread[] | undefined {
-    if (attrs) return attrs.map(this.visitJSXAttributeOrSpread.bind(this));
+    attrs: JSXAttributeOrSpread[]
+  ): JSXAttributeOrSpread[] {
+    return attrs.map(this.visitJSXAttributeOrSpread.bind(this));
   }
 
   visitJSXAttributeOrSpread(n: JSXAttributeOrSpread): JSXAttributeOrSpread {
@@ -1484,8 +1562,8 @@ export class Visitor {
   }
 
   visitJSXAttributeValue(
-    n: JSXAttrValue | undefined
-  ): JSXAttrValue | undefined {
+    n: JSXAttrValue | null
+  ): JSXAttrValue | null {
     if (!n) return n;
 
     switch (n.type) {
@@ -1552,8 +1630,8 @@ export class Visitor {
   }
 
   visitTsTypeParameterDeclaration(
-    n: TsTypeParameterDeclaration | undefined
-  ): TsTypeParameterDeclaration | undefined {
+    n: TsTypeParameterDeclaration | null
+  ): TsTypeParameterDeclaration | null {
     if (n) {
       n.parameters = this.visitTsTypeParameters(n.parameters);
     }
@@ -1576,8 +1654,8 @@ export class Visitor {
   }
 
   visitTsTypeAnnotation(
-    a: TsTypeAnnotation | undefined
-  ): TsTypeAnnotation | undefined {
+    a: TsTypeAnnotation | null
+  ): TsTypeAnnotation | null {
     if (a) {
       a.typeAnnotation = this.visitTsType(a.typeAnnotation);
     }
@@ -1617,7 +1695,7 @@ export class Visitor {
   visitNamedImportSpecifier(node: NamedImportSpecifier): NamedImportSpecifier {
     node.local = this.visitBindingIdentifier(node.local);
 
-    if (node.imported) {
+    if (node.imported && node.imported.type === "Identifier") {
       node.imported = this.visitIdentifierReference(node.imported);
     }
 
@@ -1658,6 +1736,10 @@ export class Visitor {
     return n;
   }
 
+  visitBigIntLiteral(n: BigIntLiteral): BigIntLiteral {
+    return n;
+  }
+
   visitNumericLiteral(n: NumericLiteral): NumericLiteral {
     return n;
   }
@@ -1738,12 +1820,12 @@ export class Visitor {
   }
 
   visitArrayPatternElements(
-    nodes: (Pattern | undefined)[]
-  ): (Pattern | undefined)[] {
+    nodes: (Pattern | null)[]
+  ): (Pattern | null)[] {
     return nodes.map(this.visitArrayPatternElement.bind(this));
   }
 
-  visitArrayPatternElement(n: Pattern | undefined): Pattern | undefined {
+  visitArrayPatternElement(n: Pattern | null): Pattern | null {
     if (n) {
       n = this.visitPattern(n);
     }
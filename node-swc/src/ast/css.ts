@@ -0,0 +1,1241 @@
+// This file is generated by `swc_ast_typegen`. Do not edit it manually.
+// Run `cargo run -p swc_ast_typegen` to update it.
+
+export interface Span {
+  start: number;
+  end: number;
+  ctxt: number;
+}
+
+export interface CharsetRule {
+  type: "CharsetRule";
+  span: Span;
+  charset: String;
+}
+
+export interface DocumentRule {
+  type: "DocumentRule";
+  span: Span;
+  selectors: Function[];
+  block: Rule[];
+}
+
+export type ImportHref =
+  | Function
+  | UrlValue
+  | String;
+
+export type ImportLayerName =
+  | Identifier
+  | Function;
+
+export interface ImportRule {
+  type: "ImportRule";
+  span: Span;
+  href: ImportHref;
+  layerName: ImportLayerName | null;
+  media: MediaQueryList | null;
+}
+
+export type KeyframesName =
+  | Identifier
+  | String;
+
+export interface KeyframesRule {
+  type: "KeyframesRule";
+  span: Span;
+  name: KeyframesName;
+  blocks: KeyframeBlock[];
+}
+
+export interface KeyframeBlock {
+  span: Span;
+  selector: KeyframeSelector[];
+  rule: KeyframeBlockRule;
+}
+
+export type KeyframeSelector =
+  | Identifier
+  | PercentValue;
+
+export type KeyframeBlockRule =
+  | Block
+  | AtRule;
+
+export interface LayerName {
+  type: "LayerName";
+  span: Span;
+  name: Identifier[];
+}
+
+export interface LayerNameList {
+  type: "LayerNameList";
+  span: Span;
+  nameList: LayerName[];
+}
+
+export type LayerPrelude =
+  | LayerName
+  | LayerNameList;
+
+export interface LayerRule {
+  type: "LayerRule";
+  span: Span;
+  prelude: LayerPrelude | null;
+  rules: Rule[] | null;
+}
+
+export interface MediaRule {
+  type: "MediaRule";
+  span: Span;
+  media: MediaQueryList;
+  rules: Rule[];
+}
+
+export interface MediaQueryList {
+  type: "MediaQueryList";
+  span: Span;
+  queries: MediaQuery[];
+}
+
+export interface MediaQuery {
+  type: "MediaQuery";
+  span: Span;
+  modifier: Identifier | null;
+  mediaType: Identifier | null;
+  condition: MediaConditionType | null;
+}
+
+export type MediaConditionType =
+  | MediaCondition
+  | MediaConditionWithoutOr;
+
+export interface MediaCondition {
+  type: "MediaCondition";
+  span: Span;
+  conditions: MediaConditionAllType[];
+}
+
+export interface MediaConditionWithoutOr {
+  type: "MediaConditionWithoutOr";
+  span: Span;
+  conditions: MediaConditionWithoutOrType[];
+}
+
+export type MediaConditionAllType =
+  | MediaNot
+  | MediaAnd
+  | MediaOr
+  | MediaInParens;
+
+export type MediaConditionWithoutOrType =
+  | MediaNot
+  | MediaAnd
+  | MediaInParens;
+
+export interface MediaNot {
+  type: "MediaNot";
+  span: Span;
+  condition: MediaInParens;
+}
+
+export interface MediaAnd {
+  type: "MediaAnd";
+  span: Span;
+  condition: MediaInParens;
+}
+
+export interface MediaOr {
+  type: "MediaOr";
+  span: Span;
+  condition: MediaInParens;
+}
+
+export type MediaInParens =
+  | MediaCondition
+  | MediaFeature;
+
+export type MediaFeature =
+  | MediaFeaturePlain
+  | MediaFeatureBoolean
+  | MediaFeatureRange
+  | MediaFeatureRangeInterval;
+
+export type MediaFeatureName = Identifier;
+
+export type MediaFeatureValue =
+  | Number
+  | UnitValue
+  | Identifier
+  | BinValue;
+
+export interface MediaFeaturePlain {
+  type: "MediaFeaturePlain";
+  span: Span;
+  name: MediaFeatureName;
+  value: MediaFeatureValue;
+}
+
+export interface MediaFeatureBoolean {
+  type: "MediaFeatureBoolean";
+  span: Span;
+  name: MediaFeatureName;
+}
+
+export type MediaFeatureRangeComparison =
+  | "<"
+  | "<="
+  | ">"
+  | ">="
+  | "=";
+
+export interface MediaFeatureRange {
+  type: "MediaFeatureRange";
+  span: Span;
+  left: MediaFeatureValue;
+  comparison: MediaFeatureRangeComparison;
+  right: MediaFeatureValue;
+}
+
+export interface MediaFeatureRangeInterval {
+  type: "MediaFeatureRangeInterval";
+  span: Span;
+  left: MediaFeatureValue;
+  leftComparison: MediaFeatureRangeComparison;
+  name: MediaFeatureName;
+  rightComparison: MediaFeatureRangeComparison;
+  right: MediaFeatureValue;
+}
+
+export type AtRule =
+  | CharsetRule
+  | ImportRule
+  | FontFaceRule
+  | KeyframesRule
+  | LayerRule
+  | MediaRule
+  | SupportsRule
+  | PageRule
+  | NamespaceRule
+  | ViewportRule
+  | DocumentRule
+  | UnknownAtRule;
+
+export interface FontFaceRule {
+  type: "FontFaceRule";
+  span: Span;
+  block: Block;
+}
+
+export type NamespaceUri =
+  | UrlValue
+  | String;
+
+export interface NamespaceRule {
+  type: "NamespaceRule";
+  span: Span;
+  prefix: Identifier | null;
+  uri: NamespaceUri;
+}
+
+export interface ViewportRule {
+  type: "ViewportRule";
+  span: Span;
+  block: Block;
+}
+
+export interface UnknownAtRule {
+  type: "UnknownAtRule";
+  span: Span;
+  name: Identifier;
+  prelude: Value[];
+  block: SimpleBlock | null;
+}
+
+export interface PageRule {
+  type: "PageRule";
+  span: Span;
+  prelude: PageSelector[];
+  block: PageRuleBlock;
+}
+
+export interface PageSelector {
+  span: Span;
+  ident: Identifier | null;
+  pseudo: Identifier | null;
+}
+
+export interface PageRuleBlock {
+  span: Span;
+  items: PageRuleBlockItem[];
+}
+
+export type PageRuleBlockItem =
+  | Declaration
+  | NestedPageRule;
+
+export interface NestedPageRule {
+  type: "NestedPageRule";
+  span: Span;
+  prelude: SelectorList;
+  block: PageRuleBlock;
+}
+
+export interface SupportsRule {
+  type: "SupportsRule";
+  span: Span;
+  query: SupportQuery;
+  rules: Rule[];
+}
+
+export type SupportQuery =
+  | NotSupportQuery
+  | AndSupportQuery
+  | OrSupportQuery
+  | Declaration
+  | ParenSupportQuery;
+
+export interface NotSupportQuery {
+  type: "NotSupportQuery";
+  span: Span;
+  query: SupportQuery;
+}
+
+export interface AndSupportQuery {
+  type: "AndSupportQuery";
+  span: Span;
+  left: SupportQuery;
+  right: SupportQuery;
+}
+
+export interface OrSupportQuery {
+  type: "OrSupportQuery";
+  span: Span;
+  left: SupportQuery;
+  right: SupportQuery;
+}
+
+export interface ParenSupportQuery {
+  type: "ParenSupportQuery";
+  span: Span;
+  query: SupportQuery;
+}
+
+export interface Identifier {
+  type: "Identifier";
+  span: Span;
+  value: string;
+  raw: string;
+}
+
+/** Quoted string. */
+export interface String {
+  type: "String";
+  span: Span;
+  value: string;
+  raw: string;
+}
+
+export interface Number {
+  type: "Number";
+  span: Span;
+  value: number;
+  raw: string;
+}
+
+export interface Stylesheet {
+  type: "Stylesheet";
+  span: Span;
+  rules: Rule[];
+}
+
+export type Rule =
+  | QualifiedRule
+  | Tokens
+  | AtRule;
+
+export interface SelectorList {
+  type: "SelectorList";
+  span: Span;
+  children: ComplexSelector[];
+}
+
+export interface ComplexSelector {
+  type: "ComplexSelector";
+  span: Span;
+  children: ComplexSelectorChildren[];
+}
+
+export type ComplexSelectorChildren =
+  | CompoundSelector
+  | Combinator;
+
+/** e.g. `foo.c1.c2` */
+export interface CompoundSelector {
+  type: "CompoundSelector";
+  span: Span;
+  /** "&" */
+  nestingSelector: NestingSelector | null;
+  typeSelector: TypeSelector | null;
+  subclassSelectors: SubclassSelector[];
+}
+
+export interface Combinator {
+  type: "Combinator";
+  span: Span;
+  value: CombinatorValue;
+}
+
+export type CombinatorValue =
+  | " "
+  | "+"
+  | ">"
+  | "~";
+
+export interface NestingSelector {
+  type: "NestingSelector";
+  span: Span;
+}
+
+export interface TypeSelector {
+  type: "TypeSelector";
+  span: Span;
+  /**
+   * If present, this is an identifier or "*" and is followed by a "|"
+   * character
+   */
+  prefix: Identifier | null;
+  /** This is an identifier or "*". */
+  name: Identifier;
+}
+
+export type SubclassSelector =
+  | IdSelector
+  | ClassSelector
+  | AttributeSelector
+  | PseudoClassSelector
+  | PseudoElementSelector
+  | AtSelector;
+
+export type AttrSelectorMatcher =
+  | "="
+  | "~="
+  | "|="
+  | "^="
+  | "$="
+  | "*=";
+
+export type AttrSelectorValue =
+  | String
+  | Identifier;
+
+export interface AttributeSelector {
+  type: "AttributeSelector";
+  span: Span;
+  prefix: Identifier | null;
+  name: Identifier;
+  matcher: AttrSelectorMatcher | null;
+  value: AttrSelectorValue | null;
+  modifier: string | null;
+}
+
+export type PseudoSelectorChildren =
+  | Nth
+  | Tokens;
+
+export interface Nth {
+  type: "Nth";
+  span: Span;
+  nth: NthValue;
+  selectorList: SelectorList | null;
+}
+
+export interface AnPlusB {
+  type: "AnPlusB";
+  span: Span;
+  a: number | null;
+  aRaw: string | null;
+  b: number | null;
+  bRaw: string | null;
+}
+
+export type NthValue =
+  | AnPlusB
+  | Identifier;
+
+export interface PseudoClassSelector {
+  type: "PseudoClassSelector";
+  span: Span;
+  name: Identifier;
+  children: PseudoSelectorChildren | null;
+}
+
+export interface PseudoElementSelector {
+  type: "PseudoElementSelector";
+  span: Span;
+  name: Identifier;
+  children: Tokens | null;
+}
+
+export interface IdSelector {
+  type: "IdSelector";
+  span: Span;
+  /** Does not include `#` */
+  text: Identifier;
+}
+
+export interface ClassSelector {
+  type: "ClassSelector";
+  span: Span;
+  /** Does not include `.` */
+  text: Identifier;
+}
+
+export interface TagSelector {
+  type: "TagSelector";
+  span: Span;
+  text: Identifier;
+}
+
+/** Type for `@top-center`. Allowwed in only some contexts. */
+export interface AtSelector {
+  type: "AtSelector";
+  span: Span;
+  text: Identifier;
+}
+
+export interface QualifiedRule {
+  type: "QualifiedRule";
+  span: Span;
+  prelude: SelectorList;
+  block: Block;
+}
+
+export interface Block {
+  type: "Block";
+  span: Span;
+  value: DeclarationBlockItem[];
+}
+
+export type DeclarationBlockItem =
+  | Tokens
+  | Declaration
+  | AtRule;
+
+export interface Declaration {
+  type: "Declaration";
+  span: Span;
+  property: Identifier;
+  value: Value[];
+  /** The span includes `!` */
+  important: Span | null;
+}
+
+export interface Tokens {
+  type: "Tokens";
+  span: Span;
+  tokens: TokenAndSpan[];
+}
+
+export interface TokenAndSpan {
+  span: Span;
+  token: Token;
+}
+
+export type NumberType =
+  | "integer"
+  | "number";
+
+export type Token =
+  | {
+    Ident: {
+      value: string;
+      raw: string;
+    };
+  }
+  | {
+    Function: {
+      value: string;
+      raw: string;
+    };
+  }
+  | {
+    AtKeyword: {
+      value: string;
+      raw: string;
+    };
+  }
+  | {
+    Hash: {
+      is_id: boolean;
+      value: string;
+      raw: string;
+    };
+  }
+  | {
+    Str: {
+      value: string;
+      raw: string;
+    };
+  }
+  | {
+    BadStr: {
+      value: string;
+      raw: string;
+    };
+  }
+  | {
+    Url: {
+      value: string;
+      raw: string;
+    };
+  }
+  | {
+    BadUrl: {
+      value: string;
+      raw: string;
+    };
+  }
+  | {
+    Delim: {
+      value: string;
+    };
+  }
+  | {
+    Num: {
+      value: number;
+      raw: string;
+      type: NumberType;
+    };
+  }
+  | {
+    Percent: {
+      value: number;
+      raw: string;
+    };
+  }
+  | {
+    Dimension: {
+      value: number;
+      raw_value: string;
+      unit: string;
+      raw_unit: string;
+      type: NumberType;
+    };
+  }
+  | {
+    WhiteSpace: {
+      value: string;
+    };
+  }
+  | "CDO"
+  | "CDC"
+  | "Colon"
+  | "Semi"
+  | "Comma"
+  | "LBracket"
+  | "RBracket"
+  | "LParen"
+  | "RParen"
+  | "LBrace"
+  | "RBrace";
+
+export type Value =
+  | SimpleBlock
+  | SquareBracketBlock
+  | RoundBracketBlock
+  | UnitValue
+  | Number
+  | PercentValue
+  | HashValue
+  | Identifier
+  | String
+  | Function
+  | BinValue
+  | SpaceValues
+  | CommaValues
+  | BraceValue
+  | Tokens
+  | AtTextValue
+  | UrlValue;
+
+/** List of values separated by a space. */
+export interface SpaceValues {
+  type: "SpaceValues";
+  span: Span;
+  values: Value[];
+}
+
+/** List of values separated by a space. */
+export interface CommaValues {
+  type: "CommaValues";
+  span: Span;
+  values: Value[];
+}
+
+export interface BinValue {
+  type: "BinValue";
+  span: Span;
+  op: BinOp;
+  left: Value;
+  right: Value;
+}
+
+export interface Function {
+  type: "Function";
+  /** Span starting from the `lo` of identifier and to the end of `)`. */
+  span: Span;
+  name: Identifier;
+  value: Value[];
+}
+
+export interface RoundBracketBlock {
+  type: "RoundBracketBlock";
+  /** Includes `(` and `)`. */
+  span: Span;
+  children: Value[] | null;
+}
+
+export interface SquareBracketBlock {
+  type: "SquareBracketBlock";
+  /** Includes `[` and `]`. */
+  span: Span;
+  children: Value[] | null;
+}
+
+export interface HashValue {
+  type: "HashValue";
+  /** Includes `#` */
+  span: Span;
+  /** Does **not** include `#` */
+  value: string;
+  /** Does **not** include `#` */
+  raw: string;
+}
+
+export interface Unit {
+  span: Span;
+  value: string;
+  raw: string;
+}
+
+export interface UnitValue {
+  type: "UnitValue";
+  span: Span;
+  value: Number;
+  unit: Unit;
+}
+
+export interface PercentValue {
+  type: "PercentValue";
+  span: Span;
+  value: Number;
+}
+
+export type BinOp =
+  | "+"
+  | "-"
+  | "*"
+  | "/";
+
+/** Values starting with `{` and ending with `}`. */
+export interface BraceValue {
+  type: "BraceValue";
+  span: Span;
+  value: Value;
+}
+
+export interface AtTextValue {
+  type: "AtTextValue";
+  span: Span;
+  /** Includes `@`. */
+  name: Identifier;
+  block: BraceValue | null;
+}
+
+export interface UrlValue {
+  type: "UrlValue";
+  span: Span;
+  url: string;
+  raw: string;
+}
+
+export interface SimpleBlock {
+  type: "SimpleBlock";
+  span: Span;
+  name: string;
+  value: Value[];
+}
+
+/**
+ * Types accepted by the deserializer, which fills in omitted properties with
+ * default values. Other types are the same as the output types.
+ */
+export namespace Input {
+  export interface DocumentRule {
+    type: "DocumentRule";
+    span: Span;
+    selectors: Function[];
+    block: Rule[];
+  }
+
+  export type ImportHref =
+    | Function
+    | UrlValue
+    | String;
+
+  export type ImportLayerName =
+    | Identifier
+    | Function;
+
+  export interface ImportRule {
+    type: "ImportRule";
+    span: Span;
+    href: ImportHref;
+    layerName?: ImportLayerName | null;
+    media?: MediaQueryList | null;
+  }
+
+  export interface KeyframesRule {
+    type: "KeyframesRule";
+    span: Span;
+    name: KeyframesName;
+    blocks: KeyframeBlock[];
+  }
+
+  export interface KeyframeBlock {
+    span: Span;
+    selector: KeyframeSelector[];
+    rule: KeyframeBlockRule;
+  }
+
+  export type KeyframeBlockRule =
+    | Block
+    | AtRule;
+
+  export interface LayerRule {
+    type: "LayerRule";
+    span: Span;
+    prelude?: LayerPrelude | null;
+    rules?: Rule[] | null;
+  }
+
+  export interface MediaRule {
+    type: "MediaRule";
+    span: Span;
+    media: MediaQueryList;
+    rules: Rule[];
+  }
+
+  export interface MediaQueryList {
+    type: "MediaQueryList";
+    span: Span;
+    queries: MediaQuery[];
+  }
+
+  export interface MediaQuery {
+    type: "MediaQuery";
+    span: Span;
+    modifier?: Identifier | null;
+    mediaType?: Identifier | null;
+    condition?: MediaConditionType | null;
+  }
+
+  export type MediaConditionType =
+    | MediaCondition
+    | MediaConditionWithoutOr;
+
+  export interface MediaCondition {
+    type: "MediaCondition";
+    span: Span;
+    conditions: MediaConditionAllType[];
+  }
+
+  export interface MediaConditionWithoutOr {
+    type: "MediaConditionWithoutOr";
+    span: Span;
+    conditions: MediaConditionWithoutOrType[];
+  }
+
+  export type MediaConditionAllType =
+    | MediaNot
+    | MediaAnd
+    | MediaOr
+    | MediaInParens;
+
+  export type MediaConditionWithoutOrType =
+    | MediaNot
+    | MediaAnd
+    | MediaInParens;
+
+  export interface MediaNot {
+    type: "MediaNot";
+    span: Span;
+    condition: MediaInParens;
+  }
+
+  export interface MediaAnd {
+    type: "MediaAnd";
+    span: Span;
+    condition: MediaInParens;
+  }
+
+  export interface MediaOr {
+    type: "MediaOr";
+    span: Span;
+    condition: MediaInParens;
+  }
+
+  export type MediaInParens =
+    | MediaCondition
+    | MediaFeature;
+
+  export type MediaFeature =
+    | MediaFeaturePlain
+    | MediaFeatureBoolean
+    | MediaFeatureRange
+    | MediaFeatureRangeInterval;
+
+  export type MediaFeatureValue =
+    | Number
+    | UnitValue
+    | Identifier
+    | BinValue;
+
+  export interface MediaFeaturePlain {
+    type: "MediaFeaturePlain";
+    span: Span;
+    name: MediaFeatureName;
+    value: MediaFeatureValue;
+  }
+
+  export interface MediaFeatureRange {
+    type: "MediaFeatureRange";
+    span: Span;
+    left: MediaFeatureValue;
+    comparison: MediaFeatureRangeComparison;
+    right: MediaFeatureValue;
+  }
+
+  export interface MediaFeatureRangeInterval {
+    type: "MediaFeatureRangeInterval";
+    span: Span;
+    left: MediaFeatureValue;
+    leftComparison: MediaFeatureRangeComparison;
+    name: MediaFeatureName;
+    rightComparison: MediaFeatureRangeComparison;
+    right: MediaFeatureValue;
+  }
+
+  export type AtRule =
+    | CharsetRule
+    | ImportRule
+    | FontFaceRule
+    | KeyframesRule
+    | LayerRule
+    | MediaRule
+    | SupportsRule
+    | PageRule
+    | NamespaceRule
+    | ViewportRule
+    | DocumentRule
+    | UnknownAtRule;
+
+  export interface FontFaceRule {
+    type: "FontFaceRule";
+    span: Span;
+    block: Block;
+  }
+
+  export interface NamespaceRule {
+    type: "NamespaceRule";
+    span: Span;
+    prefix?: Identifier | null;
+    uri: NamespaceUri;
+  }
+
+  export interface ViewportRule {
+    type: "ViewportRule";
+    span: Span;
+    block: Block;
+  }
+
+  export interface UnknownAtRule {
+    type: "UnknownAtRule";
+    span: Span;
+    name: Identifier;
+    prelude: Value[];
+    block?: SimpleBlock | null;
+  }
+
+  export interface PageRule {
+    type: "PageRule";
+    span: Span;
+    prelude: PageSelector[];
+    block: PageRuleBlock;
+  }
+
+  export interface PageSelector {
+    span: Span;
+    ident?: Identifier | null;
+    pseudo?: Identifier | null;
+  }
+
+  export interface PageRuleBlock {
+    span: Span;
+    items: PageRuleBlockItem[];
+  }
+
+  export type PageRuleBlockItem =
+    | Declaration
+    | NestedPageRule;
+
+  export interface NestedPageRule {
+    type: "NestedPageRule";
+    span: Span;
+    prelude: SelectorList;
+    block: PageRuleBlock;
+  }
+
+  export interface SupportsRule {
+    type: "SupportsRule";
+    span: Span;
+    query: SupportQuery;
+    rules: Rule[];
+  }
+
+  export type SupportQuery =
+    | NotSupportQuery
+    | AndSupportQuery
+    | OrSupportQuery
+    | Declaration
+    | ParenSupportQuery;
+
+  export interface NotSupportQuery {
+    type: "NotSupportQuery";
+    span: Span;
+    query: SupportQuery;
+  }
+
+  export interface AndSupportQuery {
+    type: "AndSupportQuery";
+    span: Span;
+    left: SupportQuery;
+    right: SupportQuery;
+  }
+
+  export interface OrSupportQuery {
+    type: "OrSupportQuery";
+    span: Span;
+    left: SupportQuery;
+    right: SupportQuery;
+  }
+
+  export interface ParenSupportQuery {
+    type: "ParenSupportQuery";
+    span: Span;
+    query: SupportQuery;
+  }
+
+  export interface Stylesheet {
+    type: "Stylesheet";
+    span: Span;
+    rules: Rule[];
+  }
+
+  export type Rule =
+    | QualifiedRule
+    | Tokens
+    | AtRule;
+
+  export interface SelectorList {
+    type: "SelectorList";
+    span: Span;
+    children: ComplexSelector[];
+  }
+
+  export interface ComplexSelector {
+    type: "ComplexSelector";
+    span: Span;
+    children: ComplexSelectorChildren[];
+  }
+
+  export type ComplexSelectorChildren =
+    | CompoundSelector
+    | Combinator;
+
+  export interface CompoundSelector {
+    type: "CompoundSelector";
+    span: Span;
+    nestingSelector?: NestingSelector | null;
+    typeSelector?: TypeSelector | null;
+    subclassSelectors: SubclassSelector[];
+  }
+
+  export interface TypeSelector {
+    type: "TypeSelector";
+    span: Span;
+    prefix?: Identifier | null;
+    name: Identifier;
+  }
+
+  export type SubclassSelector =
+    | IdSelector
+    | ClassSelector
+    | AttributeSelector
+    | PseudoClassSelector
+    | PseudoElementSelector
+    | AtSelector;
+
+  export interface AttributeSelector {
+    type: "AttributeSelector";
+    span: Span;
+    prefix?: Identifier | null;
+    name: Identifier;
+    matcher?: AttrSelectorMatcher | null;
+    value?: AttrSelectorValue | null;
+    modifier?: string | null;
+  }
+
+  export type PseudoSelectorChildren =
+    | Nth
+    | Tokens;
+
+  export interface Nth {
+    type: "Nth";
+    span: Span;
+    nth: NthValue;
+    selectorList?: SelectorList | null;
+  }
+
+  export interface AnPlusB {
+    type: "AnPlusB";
+    span: Span;
+    a?: number | null;
+    aRaw?: string | null;
+    b?: number | null;
+    bRaw?: string | null;
+  }
+
+  export type NthValue =
+    | AnPlusB
+    | Identifier;
+
+  export interface PseudoClassSelector {
+    type: "PseudoClassSelector";
+    span: Span;
+    name: Identifier;
+    children?: PseudoSelectorChildren | null;
+  }
+
+  export interface PseudoElementSelector {
+    type: "PseudoElementSelector";
+    span: Span;
+    name: Identifier;
+    children?: Tokens | null;
+  }
+
+  export interface QualifiedRule {
+    type: "QualifiedRule";
+    span: Span;
+    prelude: SelectorList;
+    block: Block;
+  }
+
+  export interface Block {
+    type: "Block";
+    span: Span;
+    value: DeclarationBlockItem[];
+  }
+
+  export type DeclarationBlockItem =
+    | Tokens
+    | Declaration
+    | AtRule;
+
+  export interface Declaration {
+    type: "Declaration";
+    span: Span;
+    property: Identifier;
+    value: Value[];
+    important?: Span | null;
+  }
+
+  export type Value =
+    | SimpleBlock
+    | SquareBracketBlock
+    | RoundBracketBlock
+    | UnitValue
+    | Number
+    | PercentValue
+    | HashValue
+    | Identifier
+    | String
+    | Function
+    | BinValue
+    | SpaceValues
+    | CommaValues
+    | BraceValue
+    | Tokens
+    | AtTextValue
+    | UrlValue;
+
+  export interface SpaceValues {
+    type: "SpaceValues";
+    span: Span;
+    values: Value[];
+  }
+
+  export interface CommaValues {
+    type: "CommaValues";
+    span: Span;
+    values: Value[];
+  }
+
+  export interface BinValue {
+    type: "BinValue";
+    span: Span;
+    op: BinOp;
+    left: Value;
+    right: Value;
+  }
+
+  export interface Function {
+    type: "Function";
+    span: Span;
+    name: Identifier;
+    value: Value[];
+  }
+
+  export interface RoundBracketBlock {
+    type: "RoundBracketBlock";
+    span: Span;
+    children?: Value[] | null;
+  }
+
+  export interface SquareBracketBlock {
+    type: "SquareBracketBlock";
+    span: Span;
+    children?: Value[] | null;
+  }
+
+  export interface BraceValue {
+    type: "BraceValue";
+    span: Span;
+    value: Value;
+  }
+
+  export interface AtTextValue {
+    type: "AtTextValue";
+    span: Span;
+    name: Identifier;
+    block?: BraceValue | null;
+  }
+
+  export interface SimpleBlock {
+    type: "SimpleBlock";
+    span: Span;
+    name: string;
+    value: Value[];
+  }
+}
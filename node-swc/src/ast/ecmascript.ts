@@ -0,0 +1,3292 @@
+// This file is generated by `swc_ast_typegen`. Do not edit it manually.
+// Run `cargo run -p swc_ast_typegen` to update it.
+
+export interface Span {
+  start: number;
+  end: number;
+  ctxt: number;
+}
+
+export interface Class {
+  span: Span;
+  decorators: Decorator[];
+  body: ClassMember[];
+  superClass: Expr | null;
+  isAbstract: boolean;
+  typeParams: TsTypeParameterDeclaration | null;
+  superTypeParams: TsTypeParameterInstantiation | null;
+  /** Typescript extension. */
+  implements: TsExpressionWithTypeArguments[];
+}
+
+export type ClassMember =
+  | Constructor
+  | ClassMethod
+  | PrivateMethod
+  | ClassProperty
+  | PrivateProperty
+  | TsIndexSignature
+  | EmptyStatement
+  | StaticBlock;
+
+export interface ClassProperty {
+  type: "ClassProperty";
+  span: Span;
+  key: PropName;
+  value: Expr | null;
+  typeAnnotation: TsTypeAnnotation | null;
+  isStatic: boolean;
+  decorators: Decorator[];
+  /** Typescript extension. */
+  accessibility: Accessibility | null;
+  /** Typescript extension. */
+  isAbstract: boolean;
+  isOptional: boolean;
+  isOverride: boolean;
+  readonly: boolean;
+  declare: boolean;
+  definite: boolean;
+}
+
+export interface PrivateProperty {
+  type: "PrivateProperty";
+  span: Span;
+  key: PrivateName;
+  value: Expr | null;
+  typeAnnotation: TsTypeAnnotation | null;
+  isStatic: boolean;
+  decorators: Decorator[];
+  computed: boolean;
+  /** Typescript extension. */
+  accessibility: Accessibility | null;
+  /** Typescript extension. */
+  isAbstract: boolean;
+  isOptional: boolean;
+  isOverride: boolean;
+  readonly: boolean;
+  definite: boolean;
+}
+
+export interface ClassMethod {
+  type: "ClassMethod";
+  span: Span;
+  key: PropName;
+  function: Function;
+  kind: MethodKind;
+  isStatic: boolean;
+  /** Typescript extension. */
+  accessibility: Accessibility | null;
+  /** Typescript extension. */
+  isAbstract: boolean;
+  isOptional: boolean;
+  isOverride: boolean;
+}
+
+export interface PrivateMethod {
+  type: "PrivateMethod";
+  span: Span;
+  key: PrivateName;
+  function: Function;
+  kind: MethodKind;
+  isStatic: boolean;
+  /** Typescript extension. */
+  accessibility: Accessibility | null;
+  /** Typescript extension. */
+  isAbstract: boolean;
+  isOptional: boolean;
+  isOverride: boolean;
+}
+
+export interface Constructor {
+  type: "Constructor";
+  span: Span;
+  key: PropName;
+  params: ParamOrTsParamProp[];
+  body: BlockStatement | null;
+  accessibility: Accessibility | null;
+  isOptional: boolean;
+}
+
+export interface Decorator {
+  type: "Decorator";
+  span: Span;
+  expression: Expr;
+}
+
+export type MethodKind =
+  | "method"
+  | "getter"
+  | "setter";
+
+export interface StaticBlock {
+  type: "StaticBlock";
+  span: Span;
+  body: BlockStatement;
+}
+
+export type Decl =
+  | ClassDeclaration
+  | FunctionDeclaration
+  | VariableDeclaration
+  | TsInterfaceDeclaration
+  | TsTypeAliasDeclaration
+  | TsEnumDeclaration
+  | TsModuleDeclaration;
+
+export interface FunctionDeclaration extends Function {
+  type: "FunctionDeclaration";
+  identifier: Identifier;
+  declare: boolean;
+}
+
+export interface ClassDeclaration extends Class {
+  type: "ClassDeclaration";
+  identifier: Identifier;
+  declare: boolean;
+}
+
+export interface VariableDeclaration {
+  type: "VariableDeclaration";
+  span: Span;
+  kind: VarDeclKind;
+  declare: boolean;
+  declarations: VariableDeclarator[];
+}
+
+export type VarDeclKind =
+  | "var"
+  | "let"
+  | "const";
+
+export interface VariableDeclarator {
+  type: "VariableDeclarator";
+  span: Span;
+  id: Pat;
+  /** Initialization expression. */
+  init: Expr | null;
+  /** Typescript only */
+  definite: boolean;
+}
+
+export type Expr =
+  | ThisExpression
+  | ArrayExpression
+  | ObjectExpression
+  | FunctionExpression
+  | UnaryExpression
+  | UpdateExpression
+  | BinaryExpression
+  | AssignmentExpression
+  | MemberExpression
+  | SuperPropExpression
+  | ConditionalExpression
+  | CallExpression
+  | NewExpression
+  | SequenceExpression
+  | Identifier
+  | Lit
+  | Tpl
+  | TaggedTemplateExpression
+  | ArrowFunctionExpression
+  | ClassExpression
+  | YieldExpression
+  | MetaProperty
+  | AwaitExpression
+  | ParenthesisExpression
+  | JSXMemberExpression
+  | JSXNamespacedName
+  | JSXEmptyExpression
+  | JSXElement
+  | JSXFragment
+  | TsTypeAssertion
+  | TsConstAssertion
+  | TsNonNullExpression
+  | TsAsExpression
+  | PrivateName
+  | OptionalChainingExpression
+  | PipelineExpression
+  | TopicReference
+  | DoExpression
+  | RecordExpression
+  | TupleExpression
+  | Invalid;
+
+export interface ThisExpression {
+  type: "ThisExpression";
+  span: Span;
+}
+
+/** Array literal. */
+export interface ArrayExpression {
+  type: "ArrayExpression";
+  span: Span;
+  elements: (ExprOrSpread | null)[];
+}
+
+/** Object literal. */
+export interface ObjectExpression {
+  type: "ObjectExpression";
+  span: Span;
+  properties: PropOrSpread[];
+}
+
+export type PropOrSpread =
+  | SpreadElement
+  | Prop;
+
+export interface SpreadElement {
+  type: "SpreadElement";
+  spread: Span;
+  arguments: Expr;
+}
+
+export interface UnaryExpression {
+  type: "UnaryExpression";
+  span: Span;
+  operator: UnaryOp;
+  argument: Expr;
+}
+
+export interface UpdateExpression {
+  type: "UpdateExpression";
+  span: Span;
+  operator: UpdateOp;
+  prefix: boolean;
+  argument: Expr;
+}
+
+export interface BinaryExpression {
+  type: "BinaryExpression";
+  span: Span;
+  operator: BinaryOp;
+  left: Expr;
+  right: Expr;
+}
+
+/** Function expression. */
+export interface FunctionExpression extends Function {
+  type: "FunctionExpression";
+  identifier: Identifier | null;
+}
+
+/** Class expression. */
+export interface ClassExpression extends Class {
+  type: "ClassExpression";
+  identifier: Identifier | null;
+}
+
+export interface AssignmentExpression {
+  type: "AssignmentExpression";
+  span: Span;
+  operator: AssignOp;
+  left: PatOrExpr;
+  right: Expr;
+}
+
+export interface MemberExpression {
+  type: "MemberExpression";
+  span: Span;
+  object: Expr;
+  property: MemberProp;
+}
+
+export type MemberProp =
+  | Identifier
+  | PrivateName
+  | Computed;
+
+export interface SuperPropExpression {
+  type: "SuperPropExpression";
+  span: Span;
+  obj: Super;
+  property: SuperProp;
+}
+
+export type SuperProp =
+  | Identifier
+  | Computed;
+
+export interface ConditionalExpression {
+  type: "ConditionalExpression";
+  span: Span;
+  test: Expr;
+  consequent: Expr;
+  alternate: Expr;
+}
+
+export interface CallExpression {
+  type: "CallExpression";
+  span: Span;
+  callee: Callee;
+  arguments: ExprOrSpread[];
+  typeArguments: TsTypeParameterInstantiation | null;
+}
+
+export interface NewExpression {
+  type: "NewExpression";
+  span: Span;
+  callee: Expr;
+  arguments: ExprOrSpread[] | null;
+  typeArguments: TsTypeParameterInstantiation | null;
+}
+
+export interface SequenceExpression {
+  type: "SequenceExpression";
+  span: Span;
+  expressions: Expr[];
+}
+
+export interface ArrowFunctionExpression {
+  type: "ArrowFunctionExpression";
+  span: Span;
+  params: Pat[];
+  body: BlockStmtOrExpr;
+  async: boolean;
+  generator: boolean;
+  typeParameters: TsTypeParameterDeclaration | null;
+  returnType: TsTypeAnnotation | null;
+}
+
+export interface YieldExpression {
+  type: "YieldExpression";
+  span: Span;
+  argument: Expr | null;
+  delegate: boolean;
+}
+
+export interface MetaProperty {
+  type: "MetaProperty";
+  span: Span;
+  kind: MetaPropKind;
+}
+
+export type MetaPropKind =
+  | "new.target"
+  | "import.meta";
+
+export interface AwaitExpression {
+  type: "AwaitExpression";
+  span: Span;
+  argument: Expr;
+}
+
+/**
+ * Hack-style pipeline expression, `left |> right`.
+ *
+ * `right` refers to the value of `left` using [TopicRef].
+ */
+export interface PipelineExpression {
+  type: "PipelineExpression";
+  span: Span;
+  left: Expr;
+  right: Expr;
+}
+
+/** The topic reference of a pipeline expression, like `%` in `a |> f(%)`. */
+export interface TopicReference {
+  type: "TopicReference";
+  span: Span;
+}
+
+/**
+ * `do { ... }`
+ *
+ * The value of the expression is the completion value of `body`.
+ */
+export interface DoExpression {
+  type: "DoExpression";
+  span: Span;
+  body: BlockStatement;
+}
+
+/**
+ * Record literal, `#{ a: 1 }`.
+ *
+ * Properties are always key-value pairs, shorthands or spreads.
+ */
+export interface RecordExpression {
+  type: "RecordExpression";
+  span: Span;
+  properties: PropOrSpread[];
+}
+
+/** Tuple literal, `#[1, 2]`. */
+export interface TupleExpression {
+  type: "TupleExpression";
+  span: Span;
+  elements: ExprOrSpread[];
+}
+
+export interface Tpl {
+  type: "TemplateLiteral";
+  span: Span;
+  expressions: Expr[];
+  quasis: TemplateElement[];
+}
+
+export interface TaggedTemplateExpression {
+  type: "TaggedTemplateExpression";
+  span: Span;
+  tag: Expr;
+  typeParameters: TsTypeParameterInstantiation | null;
+  template: Tpl;
+}
+
+export interface TemplateElement {
+  type: "TemplateElement";
+  span: Span;
+  tail: boolean;
+  /**
+   * This value is never used by `swc_ecma_codegen`, and this fact is
+   * considered as a public API.
+   *
+   * If you are going to use codegen right after creating a [TplElement], you
+   * don't have to worry about this value.
+   */
+  cooked: StringLiteral | null;
+  raw: StringLiteral;
+}
+
+export interface ParenthesisExpression {
+  type: "ParenthesisExpression";
+  span: Span;
+  expression: Expr;
+}
+
+export type Callee =
+  | Super
+  | Import
+  | Expr;
+
+export interface Super {
+  type: "Super";
+  span: Span;
+}
+
+export interface Import {
+  type: "Import";
+  span: Span;
+}
+
+export interface ExprOrSpread {
+  spread: Span | null;
+  expression: Expr;
+}
+
+export type BlockStmtOrExpr =
+  | BlockStatement
+  | Expr;
+
+export type PatOrExpr =
+  | Expr
+  | Pat;
+
+export interface OptionalChainingExpression {
+  type: "OptionalChainingExpression";
+  span: Span;
+  questionDotToken: Span;
+  expr: Expr;
+}
+
+/** Common parts of function and method. */
+export interface Function {
+  params: Parameter[];
+  decorators: Decorator[];
+  span: Span;
+  body: BlockStatement | null;
+  /** if it's a generator. */
+  generator: boolean;
+  /** if it's an async function. */
+  async: boolean;
+  typeParameters: TsTypeParameterDeclaration | null;
+  returnType: TsTypeAnnotation | null;
+}
+
+export interface Parameter {
+  type: "Parameter";
+  span: Span;
+  decorators: Decorator[];
+  pat: Pat;
+}
+
+export type ParamOrTsParamProp =
+  | TsParameterProperty
+  | Parameter;
+
+/** Identifier used as a pattern. */
+export interface BindingIdent extends Identifier {
+  typeAnnotation: TsTypeAnnotation | null;
+}
+
+/**
+ * A complete identifier with span.
+ *
+ * Identifier of swc consists of two parts. The first one is symbol, which is
+ * stored using an interned string, [JsWord] . The second
+ * one is [SyntaxContext][swc_common::SyntaxContext], which can be
+ * used to distinguish identifier with same symbol.
+ *
+ * Let me explain this with an example.
+ *
+ * ```ts
+ * let a = 5
+ * {
+ *     let a = 3;
+ * }
+ * ```
+ * In the code above, there are two variables with the symbol a.
+ *
+ *
+ * Other compilers typically uses type like `Scope`, and store them nested, but
+ * in rust, type like `Scope`  requires [Arc<Mutex<Scope>>] so swc uses
+ * different approach. Instead of passing scopes, swc annotates two variables
+ * with different tag, which is named
+ * [SyntaxContext]. The notation for the syntax
+ * context is #n where n is a number. e.g. `foo#1`
+ *
+ * For the example above, after applying resolver pass, it becomes.
+ *
+ * ```ts
+ * let a#1 = 5
+ * {
+ *     let a#2 = 3;
+ * }
+ * ```
+ *
+ * Thanks to the `tag` we attached, we can now distinguish them.
+ *
+ * ([JsWord], [SyntaxContext])
+ *
+ * See [Id], which is a type alias for this.
+ *
+ * This can be used to store all variables in a module to single hash map.
+ *
+ * # Comparison
+ *
+ * While comparing two identifiers, you can use `.to_id()`.
+ *
+ * # HashMap
+ *
+ * There's a type named [Id] which only contains minimal information to
+ * distinguish identifiers.
+ */
+export interface Identifier {
+  type: "Identifier";
+  span: Span;
+  value: string;
+  /** TypeScript only. Used in case of an optional parameter. */
+  optional: boolean;
+}
+
+export interface PrivateName {
+  type: "PrivateName";
+  span: Span;
+  id: Identifier;
+}
+
+/** Used for `obj` property of `JSXMemberExpr`. */
+export type JSXObject =
+  | JSXMemberExpression
+  | Identifier;
+
+export interface JSXMemberExpression {
+  type: "JSXMemberExpression";
+  object: JSXObject;
+  property: Identifier;
+}
+
+/** XML-based namespace syntax: */
+export interface JSXNamespacedName {
+  type: "JSXNamespacedName";
+  namespace: Identifier;
+  name: Identifier;
+}
+
+export interface JSXEmptyExpression {
+  type: "JSXEmptyExpression";
+  span: Span;
+}
+
+export interface JSXExpressionContainer {
+  type: "JSXExpressionContainer";
+  span: Span;
+  expression: JSXExpr;
+}
+
+export type JSXExpr =
+  | JSXEmptyExpression
+  | Expr;
+
+export interface JSXSpreadChild {
+  type: "JSXSpreadChild";
+  span: Span;
+  expression: Expr;
+}
+
+export type JSXElementName =
+  | Identifier
+  | JSXMemberExpression
+  | JSXNamespacedName;
+
+export interface JSXOpeningElement {
+  type: "JSXOpeningElement";
+  name: JSXElementName;
+  span: Span;
+  attributes: JSXAttrOrSpread[];
+  selfClosing: boolean;
+  /**
+   * Note: This field's name is different from one from babel because it is
+   * misleading
+   */
+  typeArguments: TsTypeParameterInstantiation | null;
+}
+
+export type JSXAttrOrSpread =
+  | JSXAttribute
+  | SpreadElement;
+
+export interface JSXClosingElement {
+  type: "JSXClosingElement";
+  span: Span;
+  name: JSXElementName;
+}
+
+export interface JSXAttribute {
+  type: "JSXAttribute";
+  span: Span;
+  name: JSXAttrName;
+  /** Babel uses Expr instead of JSXAttrValue */
+  value: JSXAttrValue | null;
+}
+
+export type JSXAttrName =
+  | Identifier
+  | JSXNamespacedName;
+
+export type JSXAttrValue =
+  | Lit
+  | JSXExpressionContainer
+  | JSXElement
+  | JSXFragment;
+
+export interface JSXText {
+  type: "JSXText";
+  span: Span;
+  value: string;
+  raw: string;
+}
+
+export interface JSXElement {
+  type: "JSXElement";
+  span: Span;
+  opening: JSXOpeningElement;
+  children: JSXElementChild[];
+  closing: JSXClosingElement | null;
+}
+
+export type JSXElementChild =
+  | JSXText
+  | JSXExpressionContainer
+  | JSXSpreadChild
+  | JSXElement
+  | JSXFragment;
+
+export interface JSXFragment {
+  type: "JSXFragment";
+  span: Span;
+  opening: JSXOpeningFragment;
+  children: JSXElementChild[];
+  closing: JSXClosingFragment;
+}
+
+export interface JSXOpeningFragment {
+  type: "JSXOpeningFragment";
+  span: Span;
+}
+
+export interface JSXClosingFragment {
+  type: "JSXClosingFragment";
+  span: Span;
+}
+
+/** Represents a invalid node. */
+export interface Invalid {
+  type: "Invalid";
+  span: Span;
+}
+
+export type EsVersion =
+  | "es3"
+  | "es5"
+  | "es2015"
+  | "es2016"
+  | "es2017"
+  | "es2018"
+  | "es2019"
+  | "es2020"
+  | "es2021"
+  | "es2022";
+
+export type Lit =
+  | StringLiteral
+  | BooleanLiteral
+  | NullLiteral
+  | NumericLiteral
+  | BigIntLiteral
+  | RegExpLiteral
+  | JSXText;
+
+export interface BigIntLiteral {
+  type: "BigIntLiteral";
+  span: Span;
+  value: [number, number[]];
+}
+
+/**
+ * A string literal.
+ *
+ * # Note
+ *
+ * You have to use [StrKind::Synthesized] if you modify the `value` of [Str].
+ * This behavior is for preserving the original source code.
+ *
+ * In other words, `swc_ecma_codegen` tries to preserve escapes or unicode
+ * characters in the original source code and you can opt-out of this by using
+ * [StrKind::Synthesized].
+ */
+export interface StringLiteral {
+  type: "StringLiteral";
+  span: Span;
+  value: string;
+  /** This includes line escape. */
+  hasEscape: boolean;
+  kind: StrKind;
+}
+
+/** THis enum determines how string literal should be printed. */
+export type StrKind =
+  | {
+    type: "normal";
+    /**
+     * Does span of this string literal contains quote?
+     *
+     * True for string literals generated by parser, false for string
+     * literals generated by various passes.
+     */
+    containsQuote: boolean;
+  }
+  | {
+    type: "synthesized";
+  };
+
+export interface BooleanLiteral {
+  type: "BooleanLiteral";
+  span: Span;
+  value: boolean;
+}
+
+export interface NullLiteral {
+  type: "NullLiteral";
+  span: Span;
+}
+
+export interface RegExpLiteral {
+  type: "RegExpLiteral";
+  span: Span;
+  pattern: string;
+  flags: string;
+}
+
+export interface NumericLiteral {
+  type: "NumericLiteral";
+  span: Span;
+  /**
+   * **Note**: This should not be `NaN`. Use [crate::Ident] to represent NaN.
+   *
+   * If you store `NaN` in this field, a hash map will behave strangely.
+   */
+  value: number;
+}
+
+export type Program =
+  | Module
+  | Script;
+
+export interface Module {
+  type: "Module";
+  span: Span;
+  body: ModuleItem[];
+  interpreter: string | null;
+}
+
+export interface Script {
+  type: "Script";
+  span: Span;
+  body: Stmt[];
+  interpreter: string | null;
+}
+
+export type ModuleItem =
+  | ModuleDecl
+  | Stmt;
+
+export type ModuleDecl =
+  | ImportDeclaration
+  | ExportDeclaration
+  | ExportNamedDeclaration
+  | ExportDefaultDeclaration
+  | ExportDefaultExpression
+  | ExportAllDeclaration
+  | TsImportEqualsDeclaration
+  | TsExportAssignment
+  | TsNamespaceExportDeclaration;
+
+export interface ExportDefaultExpression {
+  type: "ExportDefaultExpression";
+  span: Span;
+  expression: Expr;
+}
+
+export interface ExportDeclaration {
+  type: "ExportDeclaration";
+  span: Span;
+  declaration: Decl;
+}
+
+export interface ImportDeclaration {
+  type: "ImportDeclaration";
+  span: Span;
+  specifiers: ImportSpecifier[];
+  source: StringLiteral;
+  typeOnly: boolean;
+  asserts: ObjectExpression | null;
+}
+
+/** `export * from 'mod'` */
+export interface ExportAllDeclaration {
+  type: "ExportAllDeclaration";
+  span: Span;
+  source: StringLiteral;
+  asserts: ObjectExpression | null;
+}
+
+/**
+ * `export { foo } from 'mod'`
+ * `export { foo as bar } from 'mod'`
+ */
+export interface ExportNamedDeclaration {
+  type: "ExportNamedDeclaration";
+  span: Span;
+  specifiers: ExportSpecifier[];
+  source: StringLiteral | null;
+  typeOnly: boolean;
+  asserts: ObjectExpression | null;
+}
+
+export interface ExportDefaultDeclaration {
+  type: "ExportDefaultDeclaration";
+  span: Span;
+  decl: DefaultDecl;
+}
+
+export type DefaultDecl =
+  | ClassExpression
+  | FunctionExpression
+  | TsInterfaceDeclaration;
+
+export type ImportSpecifier =
+  | ImportNamedSpecifier
+  | ImportDefaultSpecifier
+  | ImportNamespaceSpecifier;
+
+/** e.g. `import foo from 'mod.js'` */
+export interface ImportDefaultSpecifier {
+  type: "ImportDefaultSpecifier";
+  span: Span;
+  local: Identifier;
+}
+
+/** e.g. `import * as foo from 'mod.js'`. */
+export interface ImportNamespaceSpecifier {
+  type: "ImportNamespaceSpecifier";
+  span: Span;
+  local: Identifier;
+}
+
+/**
+ * e.g. local = foo, imported = None `import { foo } from 'mod.js'`
+ * e.g. local = bar, imported = Some(foo) for `import { foo as bar } from
+ * 'mod.js'`
+ */
+export interface ImportNamedSpecifier {
+  type: "ImportSpecifier";
+  span: Span;
+  local: Identifier;
+  imported: ModuleExportName | null;
+  isTypeOnly: boolean;
+}
+
+export type ExportSpecifier =
+  | ExportNamespaceSpecifier
+  | ExportDefaultSpecifier
+  | ExportNamedSpecifier;
+
+/** `export * as foo from 'src';` */
+export interface ExportNamespaceSpecifier {
+  type: "ExportNamespaceSpecifier";
+  span: Span;
+  name: ModuleExportName;
+}
+
+export interface ExportDefaultSpecifier {
+  type: "ExportDefaultSpecifier";
+  exported: Identifier;
+}
+
+export interface ExportNamedSpecifier {
+  type: "ExportSpecifier";
+  span: Span;
+  /** `foo` in `export { foo as bar }` */
+  orig: ModuleExportName;
+  /** `Some(bar)` in `export { foo as bar }` */
+  exported: ModuleExportName | null;
+  /** `type` in `export { type foo as bar }` */
+  isTypeOnly: boolean;
+}
+
+export type ModuleExportName =
+  | Identifier
+  | StringLiteral;
+
+export type BinaryOp =
+  | "=="
+  | "!="
+  | "==="
+  | "!=="
+  | "<"
+  | "<="
+  | ">"
+  | ">="
+  | "<<"
+  | ">>"
+  | ">>>"
+  | "+"
+  | "-"
+  | "*"
+  | "/"
+  | "%"
+  | "|"
+  | "^"
+  | "&"
+  | "||"
+  | "&&"
+  | "in"
+  | "instanceof"
+  | "**"
+  | "??";
+
+export type AssignOp =
+  | "="
+  | "+="
+  | "-="
+  | "*="
+  | "/="
+  | "%="
+  | "<<="
+  | ">>="
+  | ">>>="
+  | "|="
+  | "^="
+  | "&="
+  | "**="
+  | "&&="
+  | "||="
+  | "??=";
+
+export type UpdateOp =
+  | "++"
+  | "--";
+
+export type UnaryOp =
+  | "-"
+  | "+"
+  | "!"
+  | "~"
+  | "typeof"
+  | "void"
+  | "delete";
+
+export type Pat =
+  | BindingIdent
+  | ArrayPattern
+  | RestElement
+  | ObjectPattern
+  | AssignmentPattern
+  | Invalid
+  | Expr;
+
+export interface ArrayPattern {
+  type: "ArrayPattern";
+  span: Span;
+  elements: (Pat | null)[];
+  /** Only in an ambient context */
+  optional: boolean;
+  typeAnnotation: TsTypeAnnotation | null;
+}
+
+export interface ObjectPattern {
+  type: "ObjectPattern";
+  span: Span;
+  properties: ObjectPatProp[];
+  /** Only in an ambient context */
+  optional: boolean;
+  typeAnnotation: TsTypeAnnotation | null;
+}
+
+export interface AssignmentPattern {
+  type: "AssignmentPattern";
+  span: Span;
+  left: Pat;
+  right: Expr;
+  typeAnnotation: TsTypeAnnotation | null;
+}
+
+/** EsTree `RestElement` */
+export interface RestElement {
+  type: "RestElement";
+  span: Span;
+  rest: Span;
+  argument: Pat;
+  typeAnnotation: TsTypeAnnotation | null;
+}
+
+export type ObjectPatProp =
+  | KeyValuePatternProperty
+  | AssignmentPatternProperty
+  | RestElement;
+
+/** `{key: value}` */
+export interface KeyValuePatternProperty {
+  type: "KeyValuePatternProperty";
+  key: PropName;
+  value: Pat;
+}
+
+/** `{key}` or `{key = value}` */
+export interface AssignmentPatternProperty {
+  type: "AssignmentPatternProperty";
+  span: Span;
+  key: Identifier;
+  value: Expr | null;
+}
+
+export type Prop =
+  | Identifier
+  | KeyValueProperty
+  | AssignmentProperty
+  | GetterProperty
+  | SetterProperty
+  | MethodProperty;
+
+export interface KeyValueProperty {
+  type: "KeyValueProperty";
+  key: PropName;
+  value: Expr;
+}
+
+export interface AssignmentProperty {
+  type: "AssignmentProperty";
+  key: Identifier;
+  value: Expr;
+}
+
+export interface GetterProperty {
+  type: "GetterProperty";
+  span: Span;
+  key: PropName;
+  typeAnnotation: TsTypeAnnotation | null;
+  body: BlockStatement | null;
+}
+
+export interface SetterProperty {
+  type: "SetterProperty";
+  span: Span;
+  key: PropName;
+  param: Pat;
+  body: BlockStatement | null;
+}
+
+export interface MethodProperty extends Function {
+  type: "MethodProperty";
+  key: PropName;
+}
+
+export type PropName =
+  | Identifier
+  | StringLiteral
+  | NumericLiteral
+  | Computed
+  | BigIntLiteral;
+
+export interface Computed {
+  type: "Computed";
+  /** Span including `[` and `]`. */
+  span: Span;
+  expression: Expr;
+}
+
+/** Use when only block statements are allowed. */
+export interface BlockStatement {
+  type: "BlockStatement";
+  /** Span including the braces. */
+  span: Span;
+  stmts: Stmt[];
+}
+
+export type Stmt =
+  | BlockStatement
+  | EmptyStatement
+  | DebuggerStatement
+  | WithStatement
+  | ReturnStatement
+  | LabeledStatement
+  | BreakStatement
+  | ContinueStatement
+  | IfStatement
+  | SwitchStatement
+  | ThrowStatement
+  | TryStatement
+  | WhileStatement
+  | DoWhileStatement
+  | ForStatement
+  | ForInStatement
+  | ForOfStatement
+  | Decl
+  | ExpressionStatement;
+
+export interface ExpressionStatement {
+  type: "ExpressionStatement";
+  span: Span;
+  expression: Expr;
+}
+
+export interface EmptyStatement {
+  type: "EmptyStatement";
+  /** Span of semicolon. */
+  span: Span;
+}
+
+export interface DebuggerStatement {
+  type: "DebuggerStatement";
+  span: Span;
+}
+
+export interface WithStatement {
+  type: "WithStatement";
+  span: Span;
+  object: Expr;
+  body: Stmt;
+}
+
+export interface ReturnStatement {
+  type: "ReturnStatement";
+  span: Span;
+  argument: Expr | null;
+}
+
+export interface LabeledStatement {
+  type: "LabeledStatement";
+  span: Span;
+  label: Identifier;
+  body: Stmt;
+}
+
+export interface BreakStatement {
+  type: "BreakStatement";
+  span: Span;
+  label: Identifier | null;
+}
+
+export interface ContinueStatement {
+  type: "ContinueStatement";
+  span: Span;
+  label: Identifier | null;
+}
+
+export interface IfStatement {
+  type: "IfStatement";
+  span: Span;
+  test: Expr;
+  consequent: Stmt;
+  alternate: Stmt | null;
+}
+
+export interface SwitchStatement {
+  type: "SwitchStatement";
+  span: Span;
+  discriminant: Expr;
+  cases: SwitchCase[];
+}
+
+export interface ThrowStatement {
+  type: "ThrowStatement";
+  span: Span;
+  argument: Expr;
+}
+
+export interface TryStatement {
+  type: "TryStatement";
+  span: Span;
+  block: BlockStatement;
+  handler: CatchClause | null;
+  finalizer: BlockStatement | null;
+}
+
+export interface WhileStatement {
+  type: "WhileStatement";
+  span: Span;
+  test: Expr;
+  body: Stmt;
+}
+
+export interface DoWhileStatement {
+  type: "DoWhileStatement";
+  span: Span;
+  test: Expr;
+  body: Stmt;
+}
+
+export interface ForStatement {
+  type: "ForStatement";
+  span: Span;
+  init: VarDeclOrExpr | null;
+  test: Expr | null;
+  update: Expr | null;
+  body: Stmt;
+}
+
+export interface ForInStatement {
+  type: "ForInStatement";
+  span: Span;
+  left: VarDeclOrPat;
+  right: Expr;
+  body: Stmt;
+}
+
+export interface ForOfStatement {
+  type: "ForOfStatement";
+  span: Span;
+  /**
+   * Span of the await token.
+   *
+   * es2018
+   *
+   * for-await-of statements, e.g., `for await (const x of xs) {`
+   */
+  await: Span | null;
+  left: VarDeclOrPat;
+  right: Expr;
+  body: Stmt;
+}
+
+export interface SwitchCase {
+  type: "SwitchCase";
+  span: Span;
+  /** None for `default:` */
+  test: Expr | null;
+  consequent: Stmt[];
+}
+
+export interface CatchClause {
+  type: "CatchClause";
+  span: Span;
+  /**
+   * es2019
+   *
+   * The param is null if the catch binding is omitted. E.g., try { foo() }
+   * catch { bar() }
+   */
+  param: Pat | null;
+  body: BlockStatement;
+}
+
+export type VarDeclOrPat =
+  | VariableDeclaration
+  | Pat;
+
+export type VarDeclOrExpr =
+  | VariableDeclaration
+  | Expr;
+
+export interface TsTypeAnnotation {
+  type: "TsTypeAnnotation";
+  span: Span;
+  typeAnnotation: TsType;
+}
+
+export interface TsTypeParameterDeclaration {
+  type: "TsTypeParameterDeclaration";
+  span: Span;
+  parameters: TsTypeParameter[];
+}
+
+export interface TsTypeParameter {
+  type: "TsTypeParameter";
+  span: Span;
+  name: Identifier;
+  constraint: TsType | null;
+  default: TsType | null;
+}
+
+export interface TsTypeParameterInstantiation {
+  type: "TsTypeParameterInstantiation";
+  span: Span;
+  params: TsType[];
+}
+
+export interface TsParameterProperty {
+  type: "TsParameterProperty";
+  span: Span;
+  decorators: Decorator[];
+  /** At least one of `accessibility` or `readonly` must be set. */
+  accessibility: Accessibility | null;
+  override: boolean;
+  readonly: boolean;
+  param: TsParamPropParam;
+}
+
+export type TsParamPropParam =
+  | BindingIdent
+  | AssignmentPattern;
+
+export interface TsQualifiedName {
+  type: "TsQualifiedName";
+  left: TsEntityName;
+  right: Identifier;
+}
+
+export type TsEntityName =
+  | TsQualifiedName
+  | Identifier;
+
+export type TsTypeElement =
+  | TsCallSignatureDeclaration
+  | TsConstructSignatureDeclaration
+  | TsPropertySignature
+  | TsGetterSignature
+  | TsSetterSignature
+  | TsMethodSignature
+  | TsIndexSignature;
+
+export interface TsCallSignatureDeclaration {
+  type: "TsCallSignatureDeclaration";
+  span: Span;
+  params: TsFnParam[];
+  typeAnnotation: TsTypeAnnotation | null;
+  typeParams: TsTypeParameterDeclaration | null;
+}
+
+export interface TsConstructSignatureDeclaration {
+  type: "TsConstructSignatureDeclaration";
+  span: Span;
+  params: TsFnParam[];
+  typeAnnotation: TsTypeAnnotation | null;
+  typeParams: TsTypeParameterDeclaration | null;
+}
+
+export interface TsPropertySignature {
+  type: "TsPropertySignature";
+  span: Span;
+  readonly: boolean;
+  key: Expr;
+  computed: boolean;
+  optional: boolean;
+  init: Expr | null;
+  params: TsFnParam[];
+  typeAnnotation: TsTypeAnnotation | null;
+  typeParams: TsTypeParameterDeclaration | null;
+}
+
+export interface TsGetterSignature {
+  type: "TsGetterSignature";
+  span: Span;
+  readonly: boolean;
+  key: Expr;
+  computed: boolean;
+  optional: boolean;
+  typeAnnotation: TsTypeAnnotation | null;
+}
+
+export interface TsSetterSignature {
+  type: "TsSetterSignature";
+  span: Span;
+  readonly: boolean;
+  key: Expr;
+  computed: boolean;
+  optional: boolean;
+  param: TsFnParam;
+}
+
+export interface TsMethodSignature {
+  type: "TsMethodSignature";
+  span: Span;
+  readonly: boolean;
+  key: Expr;
+  computed: boolean;
+  optional: boolean;
+  params: TsFnParam[];
+  typeAnn: TsTypeAnnotation | null;
+  typeParams: TsTypeParameterDeclaration | null;
+}
+
+export interface TsIndexSignature {
+  type: "TsIndexSignature";
+  params: TsFnParam[];
+  typeAnnotation: TsTypeAnnotation | null;
+  readonly: boolean;
+  static: boolean;
+  span: Span;
+}
+
+export type TsType =
+  | TsKeywordType
+  | TsThisType
+  | TsFnOrConstructorType
+  | TsTypeReference
+  | TsTypeQuery
+  | TsTypeLiteral
+  | TsArrayType
+  | TsTupleType
+  | TsOptionalType
+  | TsRestType
+  | TsUnionOrIntersectionType
+  | TsConditionalType
+  | TsInferType
+  | TsParenthesizedType
+  | TsTypeOperator
+  | TsIndexedAccessType
+  | TsMappedType
+  | TsLiteralType
+  | TsTypePredicate
+  | TsImportType;
+
+export type TsFnOrConstructorType =
+  | TsFunctionType
+  | TsConstructorType;
+
+export interface TsKeywordType {
+  type: "TsKeywordType";
+  span: Span;
+  kind: TsKeywordTypeKind;
+}
+
+export type TsKeywordTypeKind =
+  | "any"
+  | "unknown"
+  | "number"
+  | "object"
+  | "boolean"
+  | "bigint"
+  | "string"
+  | "symbol"
+  | "void"
+  | "undefined"
+  | "null"
+  | "never"
+  | "intrinsic";
+
+export interface TsThisType {
+  type: "TsThisType";
+  span: Span;
+}
+
+export type TsFnParam =
+  | BindingIdent
+  | ArrayPattern
+  | RestElement
+  | ObjectPattern;
+
+export interface TsFunctionType {
+  type: "TsFunctionType";
+  span: Span;
+  params: TsFnParam[];
+  typeParams: TsTypeParameterDeclaration | null;
+  typeAnnotation: TsTypeAnnotation;
+}
+
+export interface TsConstructorType {
+  type: "TsConstructorType";
+  span: Span;
+  params: TsFnParam[];
+  typeParams: TsTypeParameterDeclaration | null;
+  typeAnnotation: TsTypeAnnotation;
+  isAbstract: boolean;
+}
+
+export interface TsTypeReference {
+  type: "TsTypeReference";
+  span: Span;
+  typeName: TsEntityName;
+  typeParams: TsTypeParameterInstantiation | null;
+}
+
+export interface TsTypePredicate {
+  type: "TsTypePredicate";
+  span: Span;
+  asserts: boolean;
+  paramName: TsThisTypeOrIdent;
+  typeAnnotation: TsTypeAnnotation | null;
+}
+
+export type TsThisTypeOrIdent =
+  | TsThisType
+  | Identifier;
+
+/** `typeof` operator */
+export interface TsTypeQuery {
+  type: "TsTypeQuery";
+  span: Span;
+  exprName: TsTypeQueryExpr;
+}
+
+export type TsTypeQueryExpr =
+  | TsEntityName
+  | TsImportType;
+
+export interface TsImportType {
+  type: "TsImportType";
+  span: Span;
+  argument: StringLiteral;
+  qualifier: TsEntityName | null;
+  typeArguments: TsTypeParameterInstantiation | null;
+}
+
+export interface TsTypeLiteral {
+  type: "TsTypeLiteral";
+  span: Span;
+  members: TsTypeElement[];
+}
+
+export interface TsArrayType {
+  type: "TsArrayType";
+  span: Span;
+  elemType: TsType;
+}
+
+export interface TsTupleType {
+  type: "TsTupleType";
+  span: Span;
+  elemTypes: TsTupleElement[];
+}
+
+export interface TsTupleElement {
+  type: "TsTupleElement";
+  span: Span;
+  /** `Ident` or `RestPat { arg: Ident }` */
+  label: Pat | null;
+  ty: TsType;
+}
+
+export interface TsOptionalType {
+  type: "TsOptionalType";
+  span: Span;
+  typeAnnotation: TsType;
+}
+
+export interface TsRestType {
+  type: "TsRestType";
+  span: Span;
+  typeAnnotation: TsType;
+}
+
+export type TsUnionOrIntersectionType =
+  | TsUnionType
+  | TsIntersectionType;
+
+export interface TsUnionType {
+  type: "TsUnionType";
+  span: Span;
+  types: TsType[];
+}
+
+export interface TsIntersectionType {
+  type: "TsIntersectionType";
+  span: Span;
+  types: TsType[];
+}
+
+export interface TsConditionalType {
+  type: "TsConditionalType";
+  span: Span;
+  checkType: TsType;
+  extendsType: TsType;
+  trueType: TsType;
+  falseType: TsType;
+}
+
+export interface TsInferType {
+  type: "TsInferType";
+  span: Span;
+  typeParam: TsTypeParameter;
+}
+
+export interface TsParenthesizedType {
+  type: "TsParenthesizedType";
+  span: Span;
+  typeAnnotation: TsType;
+}
+
+export interface TsTypeOperator {
+  type: "TsTypeOperator";
+  span: Span;
+  op: TsTypeOperatorOp;
+  typeAnnotation: TsType;
+}
+
+export type TsTypeOperatorOp =
+  | "keyof"
+  | "unique"
+  | "readonly";
+
+export interface TsIndexedAccessType {
+  type: "TsIndexedAccessType";
+  span: Span;
+  readonly: boolean;
+  objectType: TsType;
+  indexType: TsType;
+}
+
+export interface TsMappedType {
+  type: "TsMappedType";
+  span: Span;
+  readonly: true | "+" | "-" | null;
+  typeParam: TsTypeParameter;
+  nameType: TsType | null;
+  optional: true | "+" | "-" | null;
+  typeAnnotation: TsType | null;
+}
+
+export interface TsLiteralType {
+  type: "TsLiteralType";
+  span: Span;
+  literal: TsLit;
+}
+
+export type TsLit =
+  | NumericLiteral
+  | StringLiteral
+  | BooleanLiteral
+  | BigIntLiteral
+  | TsTplLitType;
+
+export interface TsTplLitType {
+  type: "TemplateLiteral";
+  span: Span;
+  types: TsType[];
+  quasis: TemplateElement[];
+}
+
+export interface TsInterfaceDeclaration {
+  type: "TsInterfaceDeclaration";
+  span: Span;
+  id: Identifier;
+  declare: boolean;
+  typeParams: TsTypeParameterDeclaration | null;
+  extends: TsExpressionWithTypeArguments[];
+  body: TsInterfaceBody;
+}
+
+export interface TsInterfaceBody {
+  type: "TsInterfaceBody";
+  span: Span;
+  body: TsTypeElement[];
+}
+
+export interface TsExpressionWithTypeArguments {
+  type: "TsExpressionWithTypeArguments";
+  span: Span;
+  expression: TsEntityName;
+  typeArguments: TsTypeParameterInstantiation | null;
+}
+
+export interface TsTypeAliasDeclaration {
+  type: "TsTypeAliasDeclaration";
+  span: Span;
+  declare: boolean;
+  id: Identifier;
+  typeParams: TsTypeParameterDeclaration | null;
+  typeAnnotation: TsType;
+}
+
+export interface TsEnumDeclaration {
+  type: "TsEnumDeclaration";
+  span: Span;
+  declare: boolean;
+  isConst: boolean;
+  id: Identifier;
+  members: TsEnumMember[];
+}
+
+export interface TsEnumMember {
+  type: "TsEnumMember";
+  span: Span;
+  id: TsEnumMemberId;
+  init: Expr | null;
+}
+
+/** - Invalid: [Ident] with empty symbol. */
+export type TsEnumMemberId =
+  | Identifier
+  | StringLiteral;
+
+export interface TsModuleDeclaration {
+  type: "TsModuleDeclaration";
+  span: Span;
+  declare: boolean;
+  /** In TypeScript, this is only available through`node.flags`. */
+  global: boolean;
+  id: TsModuleName;
+  body: TsNamespaceBody | null;
+}
+
+/**
+ * `namespace A.B { }` is a namespace named `A` with another TsNamespaceDecl as
+ * its body.
+ */
+export type TsNamespaceBody =
+  | TsModuleBlock
+  | TsNamespaceDeclaration;
+
+export interface TsModuleBlock {
+  type: "TsModuleBlock";
+  span: Span;
+  body: ModuleItem[];
+}
+
+export interface TsNamespaceDeclaration {
+  type: "TsNamespaceDeclaration";
+  span: Span;
+  declare: boolean;
+  /** In TypeScript, this is only available through`node.flags`. */
+  global: boolean;
+  id: Identifier;
+  body: TsNamespaceBody;
+}
+
+export type TsModuleName =
+  | Identifier
+  | StringLiteral;
+
+export interface TsImportEqualsDeclaration {
+  type: "TsImportEqualsDeclaration";
+  span: Span;
+  declare: boolean;
+  isExport: boolean;
+  isTypeOnly: boolean;
+  id: Identifier;
+  moduleRef: TsModuleRef;
+}
+
+export type TsModuleRef =
+  | TsEntityName
+  | TsExternalModuleReference;
+
+export interface TsExternalModuleReference {
+  type: "TsExternalModuleReference";
+  span: Span;
+  expression: StringLiteral;
+}
+
+/**
+ * TypeScript's own parser uses ExportAssignment for both `export default` and
+ * `export =`. But for @babel/parser, `export default` is an ExportDefaultDecl,
+ * so a TsExportAssignment is always `export =`.
+ */
+export interface TsExportAssignment {
+  type: "TsExportAssignment";
+  span: Span;
+  expression: Expr;
+}
+
+export interface TsNamespaceExportDeclaration {
+  type: "TsNamespaceExportDeclaration";
+  span: Span;
+  id: Identifier;
+}
+
+export interface TsAsExpression {
+  type: "TsAsExpression";
+  span: Span;
+  expression: Expr;
+  typeAnnotation: TsType;
+}
+
+export interface TsTypeAssertion {
+  type: "TsTypeAssertion";
+  span: Span;
+  expression: Expr;
+  typeAnnotation: TsType;
+}
+
+export interface TsNonNullExpression {
+  type: "TsNonNullExpression";
+  span: Span;
+  expression: Expr;
+}
+
+export type Accessibility =
+  | "public"
+  | "protected"
+  | "private";
+
+export interface TsConstAssertion {
+  type: "TsConstAssertion";
+  span: Span;
+  expression: Expr;
+}
+
+/**
+ * Types accepted by the deserializer, which fills in omitted properties with
+ * default values. Other types are the same as the output types.
+ */
+export namespace Input {
+  export interface Class {
+    span: Span;
+    decorators?: Decorator[];
+    body?: ClassMember[];
+    superClass?: Expr | null;
+    isAbstract?: boolean;
+    typeParams?: TsTypeParameterDeclaration | null;
+    superTypeParams?: TsTypeParameterInstantiation | null;
+    implements?: TsExpressionWithTypeArguments[];
+  }
+
+  export type ClassMember =
+    | Constructor
+    | ClassMethod
+    | PrivateMethod
+    | ClassProperty
+    | PrivateProperty
+    | TsIndexSignature
+    | EmptyStatement
+    | StaticBlock;
+
+  export interface ClassProperty {
+    type: "ClassProperty";
+    span?: Span;
+    key: PropName;
+    value?: Expr | null;
+    typeAnnotation?: TsTypeAnnotation | null;
+    isStatic?: boolean;
+    decorators?: Decorator[];
+    accessibility?: Accessibility | null;
+    isAbstract?: boolean;
+    isOptional?: boolean;
+    isOverride?: boolean;
+    readonly?: boolean;
+    declare?: boolean;
+    definite?: boolean;
+  }
+
+  export interface PrivateProperty {
+    type: "PrivateProperty";
+    span?: Span;
+    key: PrivateName;
+    value?: Expr | null;
+    typeAnnotation?: TsTypeAnnotation | null;
+    isStatic?: boolean;
+    decorators?: Decorator[];
+    computed?: boolean;
+    accessibility?: Accessibility | null;
+    isAbstract?: boolean;
+    isOptional?: boolean;
+    isOverride?: boolean;
+    readonly?: boolean;
+    definite?: boolean;
+  }
+
+  export interface ClassMethod {
+    type: "ClassMethod";
+    span?: Span;
+    key: PropName;
+    function: Function;
+    kind: MethodKind;
+    isStatic?: boolean;
+    accessibility?: Accessibility | null;
+    isAbstract?: boolean;
+    isOptional?: boolean;
+    isOverride?: boolean;
+  }
+
+  export interface PrivateMethod {
+    type: "PrivateMethod";
+    span?: Span;
+    key: PrivateName;
+    function: Function;
+    kind: MethodKind;
+    isStatic?: boolean;
+    accessibility?: Accessibility | null;
+    isAbstract?: boolean;
+    isOptional?: boolean;
+    isOverride?: boolean;
+  }
+
+  export interface Constructor {
+    type: "Constructor";
+    span: Span;
+    key: PropName;
+    params: ParamOrTsParamProp[];
+    body?: BlockStatement | null;
+    accessibility?: Accessibility | null;
+    isOptional?: boolean;
+  }
+
+  export interface Decorator {
+    type: "Decorator";
+    span: Span;
+    expression: Expr;
+  }
+
+  export interface StaticBlock {
+    type: "StaticBlock";
+    span: Span;
+    body: BlockStatement;
+  }
+
+  export type Decl =
+    | ClassDeclaration
+    | FunctionDeclaration
+    | VariableDeclaration
+    | TsInterfaceDeclaration
+    | TsTypeAliasDeclaration
+    | TsEnumDeclaration
+    | TsModuleDeclaration;
+
+  export interface FunctionDeclaration extends Function {
+    type: "FunctionDeclaration";
+    identifier: Identifier;
+    declare?: boolean;
+  }
+
+  export interface ClassDeclaration extends Class {
+    type: "ClassDeclaration";
+    identifier: Identifier;
+    declare?: boolean;
+  }
+
+  export interface VariableDeclaration {
+    type: "VariableDeclaration";
+    span: Span;
+    kind: VarDeclKind;
+    declare?: boolean;
+    declarations: VariableDeclarator[];
+  }
+
+  export interface VariableDeclarator {
+    type: "VariableDeclarator";
+    span: Span;
+    id: Pat;
+    init?: Expr | null;
+    definite?: boolean;
+  }
+
+  export type Expr =
+    | ThisExpression
+    | ArrayExpression
+    | ObjectExpression
+    | FunctionExpression
+    | UnaryExpression
+    | UpdateExpression
+    | BinaryExpression
+    | AssignmentExpression
+    | MemberExpression
+    | SuperPropExpression
+    | ConditionalExpression
+    | CallExpression
+    | NewExpression
+    | SequenceExpression
+    | Identifier
+    | Lit
+    | Tpl
+    | TaggedTemplateExpression
+    | ArrowFunctionExpression
+    | ClassExpression
+    | YieldExpression
+    | MetaProperty
+    | AwaitExpression
+    | ParenthesisExpression
+    | JSXMemberExpression
+    | JSXNamespacedName
+    | JSXEmptyExpression
+    | JSXElement
+    | JSXFragment
+    | TsTypeAssertion
+    | TsConstAssertion
+    | TsNonNullExpression
+    | TsAsExpression
+    | PrivateName
+    | OptionalChainingExpression
+    | PipelineExpression
+    | TopicReference
+    | DoExpression
+    | RecordExpression
+    | TupleExpression
+    | Invalid;
+
+  export interface ArrayExpression {
+    type: "ArrayExpression";
+    span: Span;
+    elements?: (ExprOrSpread | null)[];
+  }
+
+  export interface ObjectExpression {
+    type: "ObjectExpression";
+    span: Span;
+    properties?: PropOrSpread[];
+  }
+
+  export type PropOrSpread =
+    | SpreadElement
+    | Prop;
+
+  export interface SpreadElement {
+    type: "SpreadElement";
+    spread: Span;
+    arguments: Expr;
+  }
+
+  export interface UnaryExpression {
+    type: "UnaryExpression";
+    span: Span;
+    operator: UnaryOp;
+    argument: Expr;
+  }
+
+  export interface UpdateExpression {
+    type: "UpdateExpression";
+    span: Span;
+    operator: UpdateOp;
+    prefix: boolean;
+    argument: Expr;
+  }
+
+  export interface BinaryExpression {
+    type: "BinaryExpression";
+    span: Span;
+    operator: BinaryOp;
+    left: Expr;
+    right: Expr;
+  }
+
+  export interface FunctionExpression extends Function {
+    type: "FunctionExpression";
+    identifier?: Identifier | null;
+  }
+
+  export interface ClassExpression extends Class {
+    type: "ClassExpression";
+    identifier?: Identifier | null;
+  }
+
+  export interface AssignmentExpression {
+    type: "AssignmentExpression";
+    span: Span;
+    operator: AssignOp;
+    left: PatOrExpr;
+    right: Expr;
+  }
+
+  export interface MemberExpression {
+    type: "MemberExpression";
+    span: Span;
+    object: Expr;
+    property: MemberProp;
+  }
+
+  export type MemberProp =
+    | Identifier
+    | PrivateName
+    | Computed;
+
+  export interface SuperPropExpression {
+    type: "SuperPropExpression";
+    span: Span;
+    obj: Super;
+    property: SuperProp;
+  }
+
+  export type SuperProp =
+    | Identifier
+    | Computed;
+
+  export interface ConditionalExpression {
+    type: "ConditionalExpression";
+    span: Span;
+    test: Expr;
+    consequent: Expr;
+    alternate: Expr;
+  }
+
+  export interface CallExpression {
+    type: "CallExpression";
+    span: Span;
+    callee: Callee;
+    arguments?: ExprOrSpread[];
+    typeArguments?: TsTypeParameterInstantiation | null;
+  }
+
+  export interface NewExpression {
+    type: "NewExpression";
+    span: Span;
+    callee: Expr;
+    arguments?: ExprOrSpread[] | null;
+    typeArguments?: TsTypeParameterInstantiation | null;
+  }
+
+  export interface SequenceExpression {
+    type: "SequenceExpression";
+    span: Span;
+    expressions: Expr[];
+  }
+
+  export interface ArrowFunctionExpression {
+    type: "ArrowFunctionExpression";
+    span: Span;
+    params: Pat[];
+    body: BlockStmtOrExpr;
+    async?: boolean;
+    generator?: boolean;
+    typeParameters?: TsTypeParameterDeclaration | null;
+    returnType?: TsTypeAnnotation | null;
+  }
+
+  export interface YieldExpression {
+    type: "YieldExpression";
+    span: Span;
+    argument?: Expr | null;
+    delegate?: boolean;
+  }
+
+  export interface AwaitExpression {
+    type: "AwaitExpression";
+    span: Span;
+    argument: Expr;
+  }
+
+  export interface PipelineExpression {
+    type: "PipelineExpression";
+    span: Span;
+    left: Expr;
+    right: Expr;
+  }
+
+  export interface DoExpression {
+    type: "DoExpression";
+    span: Span;
+    body: BlockStatement;
+  }
+
+  export interface RecordExpression {
+    type: "RecordExpression";
+    span: Span;
+    properties?: PropOrSpread[];
+  }
+
+  export interface TupleExpression {
+    type: "TupleExpression";
+    span: Span;
+    elements?: ExprOrSpread[];
+  }
+
+  export interface Tpl {
+    type: "TemplateLiteral";
+    span: Span;
+    expressions: Expr[];
+    quasis: TemplateElement[];
+  }
+
+  export interface TaggedTemplateExpression {
+    type: "TaggedTemplateExpression";
+    span: Span;
+    tag: Expr;
+    typeParameters?: TsTypeParameterInstantiation | null;
+    template: Tpl;
+  }
+
+  export interface TemplateElement {
+    type: "TemplateElement";
+    span: Span;
+    tail: boolean;
+    cooked?: StringLiteral | null;
+    raw: StringLiteral;
+  }
+
+  export interface ParenthesisExpression {
+    type: "ParenthesisExpression";
+    span: Span;
+    expression: Expr;
+  }
+
+  export type Callee =
+    | Super
+    | Import
+    | Expr;
+
+  export interface ExprOrSpread {
+    spread?: Span | null;
+    expression: Expr;
+  }
+
+  export type BlockStmtOrExpr =
+    | BlockStatement
+    | Expr;
+
+  export type PatOrExpr =
+    | Expr
+    | Pat;
+
+  export interface OptionalChainingExpression {
+    type: "OptionalChainingExpression";
+    span: Span;
+    questionDotToken: Span;
+    expr: Expr;
+  }
+
+  export interface Function {
+    params: Parameter[];
+    decorators?: Decorator[];
+    span: Span;
+    body?: BlockStatement | null;
+    generator?: boolean;
+    async?: boolean;
+    typeParameters?: TsTypeParameterDeclaration | null;
+    returnType?: TsTypeAnnotation | null;
+  }
+
+  export interface Parameter {
+    type: "Parameter";
+    span: Span;
+    decorators?: Decorator[];
+    pat: Pat;
+  }
+
+  export type ParamOrTsParamProp =
+    | TsParameterProperty
+    | Parameter;
+
+  export interface BindingIdent extends Identifier {
+    typeAnnotation?: TsTypeAnnotation | null;
+  }
+
+  export interface Identifier {
+    type: "Identifier";
+    span: Span;
+    value: string;
+    optional?: boolean;
+  }
+
+  export interface PrivateName {
+    type: "PrivateName";
+    span: Span;
+    id: Identifier;
+  }
+
+  export type JSXObject =
+    | JSXMemberExpression
+    | Identifier;
+
+  export interface JSXMemberExpression {
+    type: "JSXMemberExpression";
+    object: JSXObject;
+    property: Identifier;
+  }
+
+  export interface JSXNamespacedName {
+    type: "JSXNamespacedName";
+    namespace: Identifier;
+    name: Identifier;
+  }
+
+  export interface JSXExpressionContainer {
+    type: "JSXExpressionContainer";
+    span: Span;
+    expression: JSXExpr;
+  }
+
+  export type JSXExpr =
+    | JSXEmptyExpression
+    | Expr;
+
+  export interface JSXSpreadChild {
+    type: "JSXSpreadChild";
+    span: Span;
+    expression: Expr;
+  }
+
+  export type JSXElementName =
+    | Identifier
+    | JSXMemberExpression
+    | JSXNamespacedName;
+
+  export interface JSXOpeningElement {
+    type: "JSXOpeningElement";
+    name: JSXElementName;
+    span: Span;
+    attributes?: JSXAttrOrSpread[];
+    selfClosing: boolean;
+    typeArguments?: TsTypeParameterInstantiation | null;
+  }
+
+  export type JSXAttrOrSpread =
+    | JSXAttribute
+    | SpreadElement;
+
+  export interface JSXClosingElement {
+    type: "JSXClosingElement";
+    span: Span;
+    name: JSXElementName;
+  }
+
+  export interface JSXAttribute {
+    type: "JSXAttribute";
+    span: Span;
+    name: JSXAttrName;
+    value?: JSXAttrValue | null;
+  }
+
+  export type JSXAttrName =
+    | Identifier
+    | JSXNamespacedName;
+
+  export type JSXAttrValue =
+    | Lit
+    | JSXExpressionContainer
+    | JSXElement
+    | JSXFragment;
+
+  export interface JSXElement {
+    type: "JSXElement";
+    span: Span;
+    opening: JSXOpeningElement;
+    children: JSXElementChild[];
+    closing?: JSXClosingElement | null;
+  }
+
+  export type JSXElementChild =
+    | JSXText
+    | JSXExpressionContainer
+    | JSXSpreadChild
+    | JSXElement
+    | JSXFragment;
+
+  export interface JSXFragment {
+    type: "JSXFragment";
+    span: Span;
+    opening: JSXOpeningFragment;
+    children?: JSXElementChild[];
+    closing: JSXClosingFragment;
+  }
+
+  export type Lit =
+    | StringLiteral
+    | BooleanLiteral
+    | NullLiteral
+    | NumericLiteral
+    | BigIntLiteral
+    | RegExpLiteral
+    | JSXText;
+
+  export interface StringLiteral {
+    type: "StringLiteral";
+    span: Span;
+    value: string;
+    hasEscape?: boolean;
+    kind?: StrKind;
+  }
+
+  export interface RegExpLiteral {
+    type: "RegExpLiteral";
+    span: Span;
+    pattern: string;
+    flags?: string;
+  }
+
+  export type Program =
+    | Module
+    | Script;
+
+  export interface Module {
+    type: "Module";
+    span: Span;
+    body: ModuleItem[];
+    interpreter?: string | null;
+  }
+
+  export interface Script {
+    type: "Script";
+    span: Span;
+    body: Stmt[];
+    interpreter?: string | null;
+  }
+
+  export type ModuleItem =
+    | ModuleDecl
+    | Stmt;
+
+  export type ModuleDecl =
+    | ImportDeclaration
+    | ExportDeclaration
+    | ExportNamedDeclaration
+    | ExportDefaultDeclaration
+    | ExportDefaultExpression
+    | ExportAllDeclaration
+    | TsImportEqualsDeclaration
+    | TsExportAssignment
+    | TsNamespaceExportDeclaration;
+
+  export interface ExportDefaultExpression {
+    type: "ExportDefaultExpression";
+    span: Span;
+    expression: Expr;
+  }
+
+  export interface ExportDeclaration {
+    type: "ExportDeclaration";
+    span: Span;
+    declaration: Decl;
+  }
+
+  export interface ImportDeclaration {
+    type: "ImportDeclaration";
+    span: Span;
+    specifiers?: ImportSpecifier[];
+    source: StringLiteral;
+    typeOnly?: boolean;
+    asserts?: ObjectExpression | null;
+  }
+
+  export interface ExportAllDeclaration {
+    type: "ExportAllDeclaration";
+    span: Span;
+    source: StringLiteral;
+    asserts?: ObjectExpression | null;
+  }
+
+  export interface ExportNamedDeclaration {
+    type: "ExportNamedDeclaration";
+    span: Span;
+    specifiers: ExportSpecifier[];
+    source?: StringLiteral | null;
+    typeOnly: boolean;
+    asserts?: ObjectExpression | null;
+  }
+
+  export interface ExportDefaultDeclaration {
+    type: "ExportDefaultDeclaration";
+    span: Span;
+    decl: DefaultDecl;
+  }
+
+  export type DefaultDecl =
+    | ClassExpression
+    | FunctionExpression
+    | TsInterfaceDeclaration;
+
+  export type ImportSpecifier =
+    | ImportNamedSpecifier
+    | ImportDefaultSpecifier
+    | ImportNamespaceSpecifier;
+
+  export interface ImportDefaultSpecifier {
+    type: "ImportDefaultSpecifier";
+    span: Span;
+    local: Identifier;
+  }
+
+  export interface ImportNamespaceSpecifier {
+    type: "ImportNamespaceSpecifier";
+    span: Span;
+    local: Identifier;
+  }
+
+  export interface ImportNamedSpecifier {
+    type: "ImportSpecifier";
+    span: Span;
+    local: Identifier;
+    imported?: ModuleExportName | null;
+    isTypeOnly?: boolean;
+  }
+
+  export type ExportSpecifier =
+    | ExportNamespaceSpecifier
+    | ExportDefaultSpecifier
+    | ExportNamedSpecifier;
+
+  export interface ExportNamespaceSpecifier {
+    type: "ExportNamespaceSpecifier";
+    span: Span;
+    name: ModuleExportName;
+  }
+
+  export interface ExportDefaultSpecifier {
+    type: "ExportDefaultSpecifier";
+    exported: Identifier;
+  }
+
+  export interface ExportNamedSpecifier {
+    type: "ExportSpecifier";
+    span: Span;
+    orig: ModuleExportName;
+    exported?: ModuleExportName | null;
+    isTypeOnly?: boolean;
+  }
+
+  export type ModuleExportName =
+    | Identifier
+    | StringLiteral;
+
+  export type Pat =
+    | BindingIdent
+    | ArrayPattern
+    | RestElement
+    | ObjectPattern
+    | AssignmentPattern
+    | Invalid
+    | Expr;
+
+  export interface ArrayPattern {
+    type: "ArrayPattern";
+    span: Span;
+    elements: (Pat | null)[];
+    optional: boolean;
+    typeAnnotation?: TsTypeAnnotation | null;
+  }
+
+  export interface ObjectPattern {
+    type: "ObjectPattern";
+    span: Span;
+    properties: ObjectPatProp[];
+    optional: boolean;
+    typeAnnotation?: TsTypeAnnotation | null;
+  }
+
+  export interface AssignmentPattern {
+    type: "AssignmentPattern";
+    span: Span;
+    left: Pat;
+    right: Expr;
+    typeAnnotation?: TsTypeAnnotation | null;
+  }
+
+  export interface RestElement {
+    type: "RestElement";
+    span: Span;
+    rest: Span;
+    argument: Pat;
+    typeAnnotation?: TsTypeAnnotation | null;
+  }
+
+  export type ObjectPatProp =
+    | KeyValuePatternProperty
+    | AssignmentPatternProperty
+    | RestElement;
+
+  export interface KeyValuePatternProperty {
+    type: "KeyValuePatternProperty";
+    key: PropName;
+    value: Pat;
+  }
+
+  export interface AssignmentPatternProperty {
+    type: "AssignmentPatternProperty";
+    span: Span;
+    key: Identifier;
+    value?: Expr | null;
+  }
+
+  export type Prop =
+    | Identifier
+    | KeyValueProperty
+    | AssignmentProperty
+    | GetterProperty
+    | SetterProperty
+    | MethodProperty;
+
+  export interface KeyValueProperty {
+    type: "KeyValueProperty";
+    key: PropName;
+    value: Expr;
+  }
+
+  export interface AssignmentProperty {
+    type: "AssignmentProperty";
+    key: Identifier;
+    value: Expr;
+  }
+
+  export interface GetterProperty {
+    type: "GetterProperty";
+    span: Span;
+    key: PropName;
+    typeAnnotation?: TsTypeAnnotation | null;
+    body?: BlockStatement | null;
+  }
+
+  export interface SetterProperty {
+    type: "SetterProperty";
+    span: Span;
+    key: PropName;
+    param: Pat;
+    body?: BlockStatement | null;
+  }
+
+  export interface MethodProperty extends Function {
+    type: "MethodProperty";
+    key: PropName;
+  }
+
+  export type PropName =
+    | Identifier
+    | StringLiteral
+    | NumericLiteral
+    | Computed
+    | BigIntLiteral;
+
+  export interface Computed {
+    type: "Computed";
+    span: Span;
+    expression: Expr;
+  }
+
+  export interface BlockStatement {
+    type: "BlockStatement";
+    span: Span;
+    stmts: Stmt[];
+  }
+
+  export type Stmt =
+    | BlockStatement
+    | EmptyStatement
+    | DebuggerStatement
+    | WithStatement
+    | ReturnStatement
+    | LabeledStatement
+    | BreakStatement
+    | ContinueStatement
+    | IfStatement
+    | SwitchStatement
+    | ThrowStatement
+    | TryStatement
+    | WhileStatement
+    | DoWhileStatement
+    | ForStatement
+    | ForInStatement
+    | ForOfStatement
+    | Decl
+    | ExpressionStatement;
+
+  export interface ExpressionStatement {
+    type: "ExpressionStatement";
+    span: Span;
+    expression: Expr;
+  }
+
+  export interface WithStatement {
+    type: "WithStatement";
+    span: Span;
+    object: Expr;
+    body: Stmt;
+  }
+
+  export interface ReturnStatement {
+    type: "ReturnStatement";
+    span: Span;
+    argument?: Expr | null;
+  }
+
+  export interface LabeledStatement {
+    type: "LabeledStatement";
+    span: Span;
+    label: Identifier;
+    body: Stmt;
+  }
+
+  export interface BreakStatement {
+    type: "BreakStatement";
+    span: Span;
+    label?: Identifier | null;
+  }
+
+  export interface ContinueStatement {
+    type: "ContinueStatement";
+    span: Span;
+    label?: Identifier | null;
+  }
+
+  export interface IfStatement {
+    type: "IfStatement";
+    span: Span;
+    test: Expr;
+    consequent: Stmt;
+    alternate?: Stmt | null;
+  }
+
+  export interface SwitchStatement {
+    type: "SwitchStatement";
+    span: Span;
+    discriminant: Expr;
+    cases: SwitchCase[];
+  }
+
+  export interface ThrowStatement {
+    type: "ThrowStatement";
+    span: Span;
+    argument: Expr;
+  }
+
+  export interface TryStatement {
+    type: "TryStatement";
+    span: Span;
+    block: BlockStatement;
+    handler?: CatchClause | null;
+    finalizer?: BlockStatement | null;
+  }
+
+  export interface WhileStatement {
+    type: "WhileStatement";
+    span: Span;
+    test: Expr;
+    body: Stmt;
+  }
+
+  export interface DoWhileStatement {
+    type: "DoWhileStatement";
+    span: Span;
+    test: Expr;
+    body: Stmt;
+  }
+
+  export interface ForStatement {
+    type: "ForStatement";
+    span: Span;
+    init?: VarDeclOrExpr | null;
+    test?: Expr | null;
+    update?: Expr | null;
+    body: Stmt;
+  }
+
+  export interface ForInStatement {
+    type: "ForInStatement";
+    span: Span;
+    left: VarDeclOrPat;
+    right: Expr;
+    body: Stmt;
+  }
+
+  export interface ForOfStatement {
+    type: "ForOfStatement";
+    span: Span;
+    await?: Span | null;
+    left: VarDeclOrPat;
+    right: Expr;
+    body: Stmt;
+  }
+
+  export interface SwitchCase {
+    type: "SwitchCase";
+    span: Span;
+    test?: Expr | null;
+    consequent: Stmt[];
+  }
+
+  export interface CatchClause {
+    type: "CatchClause";
+    span: Span;
+    param?: Pat | null;
+    body: BlockStatement;
+  }
+
+  export type VarDeclOrPat =
+    | VariableDeclaration
+    | Pat;
+
+  export type VarDeclOrExpr =
+    | VariableDeclaration
+    | Expr;
+
+  export interface TsTypeAnnotation {
+    type: "TsTypeAnnotation";
+    span: Span;
+    typeAnnotation: TsType;
+  }
+
+  export interface TsTypeParameterDeclaration {
+    type: "TsTypeParameterDeclaration";
+    span: Span;
+    parameters: TsTypeParameter[];
+  }
+
+  export interface TsTypeParameter {
+    type: "TsTypeParameter";
+    span: Span;
+    name: Identifier;
+    constraint?: TsType | null;
+    default?: TsType | null;
+  }
+
+  export interface TsTypeParameterInstantiation {
+    type: "TsTypeParameterInstantiation";
+    span: Span;
+    params: TsType[];
+  }
+
+  export interface TsParameterProperty {
+    type: "TsParameterProperty";
+    span: Span;
+    decorators?: Decorator[];
+    accessibility?: Accessibility | null;
+    override: boolean;
+    readonly: boolean;
+    param: TsParamPropParam;
+  }
+
+  export type TsParamPropParam =
+    | BindingIdent
+    | AssignmentPattern;
+
+  export interface TsQualifiedName {
+    type: "TsQualifiedName";
+    left: TsEntityName;
+    right: Identifier;
+  }
+
+  export type TsEntityName =
+    | TsQualifiedName
+    | Identifier;
+
+  export type TsTypeElement =
+    | TsCallSignatureDeclaration
+    | TsConstructSignatureDeclaration
+    | TsPropertySignature
+    | TsGetterSignature
+    | TsSetterSignature
+    | TsMethodSignature
+    | TsIndexSignature;
+
+  export interface TsCallSignatureDeclaration {
+    type: "TsCallSignatureDeclaration";
+    span: Span;
+    params: TsFnParam[];
+    typeAnnotation?: TsTypeAnnotation | null;
+    typeParams?: TsTypeParameterDeclaration | null;
+  }
+
+  export interface TsConstructSignatureDeclaration {
+    type: "TsConstructSignatureDeclaration";
+    span: Span;
+    params: TsFnParam[];
+    typeAnnotation?: TsTypeAnnotation | null;
+    typeParams?: TsTypeParameterDeclaration | null;
+  }
+
+  export interface TsPropertySignature {
+    type: "TsPropertySignature";
+    span: Span;
+    readonly: boolean;
+    key: Expr;
+    computed: boolean;
+    optional: boolean;
+    init?: Expr | null;
+    params: TsFnParam[];
+    typeAnnotation?: TsTypeAnnotation | null;
+    typeParams?: TsTypeParameterDeclaration | null;
+  }
+
+  export interface TsGetterSignature {
+    type: "TsGetterSignature";
+    span: Span;
+    readonly: boolean;
+    key: Expr;
+    computed: boolean;
+    optional: boolean;
+    typeAnnotation?: TsTypeAnnotation | null;
+  }
+
+  export interface TsSetterSignature {
+    type: "TsSetterSignature";
+    span: Span;
+    readonly: boolean;
+    key: Expr;
+    computed: boolean;
+    optional: boolean;
+    param: TsFnParam;
+  }
+
+  export interface TsMethodSignature {
+    type: "TsMethodSignature";
+    span: Span;
+    readonly: boolean;
+    key: Expr;
+    computed: boolean;
+    optional: boolean;
+    params: TsFnParam[];
+    typeAnn?: TsTypeAnnotation | null;
+    typeParams?: TsTypeParameterDeclaration | null;
+  }
+
+  export interface TsIndexSignature {
+    type: "TsIndexSignature";
+    params: TsFnParam[];
+    typeAnnotation?: TsTypeAnnotation | null;
+    readonly: boolean;
+    static: boolean;
+    span: Span;
+  }
+
+  export type TsType =
+    | TsKeywordType
+    | TsThisType
+    | TsFnOrConstructorType
+    | TsTypeReference
+    | TsTypeQuery
+    | TsTypeLiteral
+    | TsArrayType
+    | TsTupleType
+    | TsOptionalType
+    | TsRestType
+    | TsUnionOrIntersectionType
+    | TsConditionalType
+    | TsInferType
+    | TsParenthesizedType
+    | TsTypeOperator
+    | TsIndexedAccessType
+    | TsMappedType
+    | TsLiteralType
+    | TsTypePredicate
+    | TsImportType;
+
+  export type TsFnOrConstructorType =
+    | TsFunctionType
+    | TsConstructorType;
+
+  export type TsFnParam =
+    | BindingIdent
+    | ArrayPattern
+    | RestElement
+    | ObjectPattern;
+
+  export interface TsFunctionType {
+    type: "TsFunctionType";
+    span: Span;
+    params: TsFnParam[];
+    typeParams?: TsTypeParameterDeclaration | null;
+    typeAnnotation: TsTypeAnnotation;
+  }
+
+  export interface TsConstructorType {
+    type: "TsConstructorType";
+    span: Span;
+    params: TsFnParam[];
+    typeParams?: TsTypeParameterDeclaration | null;
+    typeAnnotation: TsTypeAnnotation;
+    isAbstract: boolean;
+  }
+
+  export interface TsTypeReference {
+    type: "TsTypeReference";
+    span: Span;
+    typeName: TsEntityName;
+    typeParams?: TsTypeParameterInstantiation | null;
+  }
+
+  export interface TsTypePredicate {
+    type: "TsTypePredicate";
+    span: Span;
+    asserts: boolean;
+    paramName: TsThisTypeOrIdent;
+    typeAnnotation?: TsTypeAnnotation | null;
+  }
+
+  export type TsThisTypeOrIdent =
+    | TsThisType
+    | Identifier;
+
+  export interface TsTypeQuery {
+    type: "TsTypeQuery";
+    span: Span;
+    exprName: TsTypeQueryExpr;
+  }
+
+  export type TsTypeQueryExpr =
+    | TsEntityName
+    | TsImportType;
+
+  export interface TsImportType {
+    type: "TsImportType";
+    span: Span;
+    argument: StringLiteral;
+    qualifier?: TsEntityName | null;
+    typeArguments?: TsTypeParameterInstantiation | null;
+  }
+
+  export interface TsTypeLiteral {
+    type: "TsTypeLiteral";
+    span: Span;
+    members: TsTypeElement[];
+  }
+
+  export interface TsArrayType {
+    type: "TsArrayType";
+    span: Span;
+    elemType: TsType;
+  }
+
+  export interface TsTupleType {
+    type: "TsTupleType";
+    span: Span;
+    elemTypes: TsTupleElement[];
+  }
+
+  export interface TsTupleElement {
+    type: "TsTupleElement";
+    span: Span;
+    label?: Pat | null;
+    ty: TsType;
+  }
+
+  export interface TsOptionalType {
+    type: "TsOptionalType";
+    span: Span;
+    typeAnnotation: TsType;
+  }
+
+  export interface TsRestType {
+    type: "TsRestType";
+    span: Span;
+    typeAnnotation: TsType;
+  }
+
+  export type TsUnionOrIntersectionType =
+    | TsUnionType
+    | TsIntersectionType;
+
+  export interface TsUnionType {
+    type: "TsUnionType";
+    span: Span;
+    types: TsType[];
+  }
+
+  export interface TsIntersectionType {
+    type: "TsIntersectionType";
+    span: Span;
+    types: TsType[];
+  }
+
+  export interface TsConditionalType {
+    type: "TsConditionalType";
+    span: Span;
+    checkType: TsType;
+    extendsType: TsType;
+    trueType: TsType;
+    falseType: TsType;
+  }
+
+  export interface TsInferType {
+    type: "TsInferType";
+    span: Span;
+    typeParam: TsTypeParameter;
+  }
+
+  export interface TsParenthesizedType {
+    type: "TsParenthesizedType";
+    span: Span;
+    typeAnnotation: TsType;
+  }
+
+  export interface TsTypeOperator {
+    type: "TsTypeOperator";
+    span: Span;
+    op: TsTypeOperatorOp;
+    typeAnnotation: TsType;
+  }
+
+  export interface TsIndexedAccessType {
+    type: "TsIndexedAccessType";
+    span: Span;
+    readonly: boolean;
+    objectType: TsType;
+    indexType: TsType;
+  }
+
+  export interface TsMappedType {
+    type: "TsMappedType";
+    span: Span;
+    readonly?: true | "+" | "-" | null;
+    typeParam: TsTypeParameter;
+    nameType?: TsType | null;
+    optional?: true | "+" | "-" | null;
+    typeAnnotation?: TsType | null;
+  }
+
+  export interface TsLiteralType {
+    type: "TsLiteralType";
+    span: Span;
+    literal: TsLit;
+  }
+
+  export type TsLit =
+    | NumericLiteral
+    | StringLiteral
+    | BooleanLiteral
+    | BigIntLiteral
+    | TsTplLitType;
+
+  export interface TsTplLitType {
+    type: "TemplateLiteral";
+    span: Span;
+    types: TsType[];
+    quasis: TemplateElement[];
+  }
+
+  export interface TsInterfaceDeclaration {
+    type: "TsInterfaceDeclaration";
+    span: Span;
+    id: Identifier;
+    declare: boolean;
+    typeParams?: TsTypeParameterDeclaration | null;
+    extends: TsExpressionWithTypeArguments[];
+    body: TsInterfaceBody;
+  }
+
+  export interface TsInterfaceBody {
+    type: "TsInterfaceBody";
+    span: Span;
+    body: TsTypeElement[];
+  }
+
+  export interface TsExpressionWithTypeArguments {
+    type: "TsExpressionWithTypeArguments";
+    span: Span;
+    expression: TsEntityName;
+    typeArguments?: TsTypeParameterInstantiation | null;
+  }
+
+  export interface TsTypeAliasDeclaration {
+    type: "TsTypeAliasDeclaration";
+    span: Span;
+    declare: boolean;
+    id: Identifier;
+    typeParams?: TsTypeParameterDeclaration | null;
+    typeAnnotation: TsType;
+  }
+
+  export interface TsEnumDeclaration {
+    type: "TsEnumDeclaration";
+    span: Span;
+    declare: boolean;
+    isConst: boolean;
+    id: Identifier;
+    members: TsEnumMember[];
+  }
+
+  export interface TsEnumMember {
+    type: "TsEnumMember";
+    span: Span;
+    id: TsEnumMemberId;
+    init?: Expr | null;
+  }
+
+  export type TsEnumMemberId =
+    | Identifier
+    | StringLiteral;
+
+  export interface TsModuleDeclaration {
+    type: "TsModuleDeclaration";
+    span: Span;
+    declare: boolean;
+    global: boolean;
+    id: TsModuleName;
+    body?: TsNamespaceBody | null;
+  }
+
+  export type TsNamespaceBody =
+    | TsModuleBlock
+    | TsNamespaceDeclaration;
+
+  export interface TsModuleBlock {
+    type: "TsModuleBlock";
+    span: Span;
+    body: ModuleItem[];
+  }
+
+  export interface TsNamespaceDeclaration {
+    type: "TsNamespaceDeclaration";
+    span: Span;
+    declare: boolean;
+    global: boolean;
+    id: Identifier;
+    body: TsNamespaceBody;
+  }
+
+  export type TsModuleName =
+    | Identifier
+    | StringLiteral;
+
+  export interface TsImportEqualsDeclaration {
+    type: "TsImportEqualsDeclaration";
+    span: Span;
+    declare: boolean;
+    isExport: boolean;
+    isTypeOnly: boolean;
+    id: Identifier;
+    moduleRef: TsModuleRef;
+  }
+
+  export type TsModuleRef =
+    | TsEntityName
+    | TsExternalModuleReference;
+
+  export interface TsExternalModuleReference {
+    type: "TsExternalModuleReference";
+    span: Span;
+    expression: StringLiteral;
+  }
+
+  export interface TsExportAssignment {
+    type: "TsExportAssignment";
+    span: Span;
+    expression: Expr;
+  }
+
+  export interface TsNamespaceExportDeclaration {
+    type: "TsNamespaceExportDeclaration";
+    span: Span;
+    id: Identifier;
+  }
+
+  export interface TsAsExpression {
+    type: "TsAsExpression";
+    span: Span;
+    expression: Expr;
+    typeAnnotation: TsType;
+  }
+
+  export interface TsTypeAssertion {
+    type: "TsTypeAssertion";
+    span: Span;
+    expression: Expr;
+    typeAnnotation: TsType;
+  }
+
+  export interface TsNonNullExpression {
+    type: "TsNonNullExpression";
+    span: Span;
+    expression: Expr;
+  }
+
+  export interface TsConstAssertion {
+    type: "TsConstAssertion";
+    span: Span;
+    expression: Expr;
+  }
+}
@@ -7,9 +7,12 @@ import {
   Options,
   Script,
   Program,
+  Input,
   JsMinifyOptions,
 } from "./types";
 export * from "./types";
+export * as ast from "./ast/ecmascript";
+export * as cssAst from "./ast/css";
 import { BundleInput, compileBundleOptions } from "./spack";
 
 // Allow overrides to the location of the .node binding file
@@ -93,7 +96,7 @@ export class Compiler {
    * Note: this method should be invoked on the compiler instance used
    *  for `parse()` / `parseSync()`.
    */
-  async print(m: Program, options?: Options): Promise<Output> {
+  async print(m: Input.Program, options?: Options): Promise<Output> {
     options = options || {};
 
     return bindings.print(JSON.stringify(m), toBuffer(options))
@@ -103,13 +106,13 @@ export class Compiler {
    * Note: this method should be invoked on the compiler instance used
    *  for `parse()` / `parseSync()`.
    */
-  printSync(m: Program, options?: Options): Output {
+  printSync(m: Input.Program, options?: Options): Output {
     options = options || {};
 
     return bindings.printSync(JSON.stringify(m), toBuffer(options));
   }
 
-  async transform(src: string | Program, options?: Options): Promise<Output> {
+  async transform(src: string | Input.Program, options?: Options): Promise<Output> {
     const isModule = typeof src !== "string";
     options = options || {};
 
@@ -124,14 +127,15 @@ export class Compiler {
       const m =
         typeof src === "string"
           ? await this.parse(src, options?.jsc?.parser, options.filename)
-          : src;
+          // Plugins see the program as given, including omitted properties.
+          : src as Program;
       return this.transform(plugin(m), newOptions);
     }
 
     return bindings.transform(isModule ? JSON.stringify(src) : src, isModule, toBuffer(newOptions))
   }
 
-  transformSync(src: string | Program, options?: Options): Output {
+  transformSync(src: string | Input.Program, options?: Options): Output {
     const isModule = typeof src !== "string";
     options = options || {};
 
@@ -144,7 +148,7 @@ export class Compiler {
 
     if (plugin) {
       const m =
-        typeof src === "string" ? this.parseSync(src, options?.jsc?.parser, options.filename) : src;
+        typeof src === "string" ? this.parseSync(src, options?.jsc?.parser, options.filename) : src as Program;
       return this.transformSync(plugin(m), newOptions);
     }
 
@@ -260,23 +264,23 @@ export function parseFileSync(path: string, options?: ParseOptions): Program {
   return compiler.parseFileSync(path, options);
 }
 
-export function print(m: Program, options?: Options): Promise<Output> {
+export function print(m: Input.Program, options?: Options): Promise<Output> {
   return compiler.print(m, options);
 }
 
-export function printSync(m: Program, options?: Options): Output {
+export function printSync(m: Input.Program, options?: Options): Output {
   return compiler.printSync(m, options);
 }
 
 export function transform(
-  src: string | Program,
+  src: string | Input.Program,
   options?: Options
 ): Promise<Output> {
   return compiler.transform(src, options);
 }
 
 export function transformSync(
-  src: string | Program,
+  src: string | Input.Program,
   options?: Options
 ): Output {
   return compiler.transformSync(src, options);
@@ -80,16 +80,97 @@ export interface SpackConfig {
      * Modules to exclude from bundle.
      */
     externalModules?: string[]
+
+    optimization?: OptimizationConfig
+
+    /**
+     * Stores transformed modules on disk, so unchanged files are not processed
+     * again by the next build.
+     */
+    cache?: CacheConfig
+}
+
+export interface CacheConfig {
+    /**
+     * Directory to store transformed modules in.
+     */
+    dir: string
+}
+
+export interface OptimizationConfig {
+    /**
+     * Moves modules shared by entries to separate chunks.
+     */
+    splitChunks?: SplitChunksConfig
+}
+
+export interface SplitChunksConfig {
+    /**
+     * Minimum number of entries sharing a module before extracting it.
+     *
+     * @default 2
+     */
+    minChunks?: number
+
+    /**
+     * Minimum size of an extracted chunk, in bytes.
+     *
+     * @default 20000
+     */
+    minSize?: number
+
+    /**
+     * Modules with a path matching `test` are moved to the chunk with the
+     * name of the group.
+     *
+     * If a module matches multiple groups, the group with the highest
+     * `priority` is used. Groups with the same priority are tried in the
+     * order of their names.
+     */
+    cacheGroups?: { [name: string]: CacheGroup }
+}
+
+export interface CacheGroup {
+    test: string
+
+    /**
+     * @default 0
+     */
+    priority?: number
 }
 
 export interface OutputConfig {
+    /**
+     * Used as the name of the global variable if `format` is `umd`.
+     */
     name: string
     path: string
+    /**
+     * Defaults to `es`.
+     */
+    format?: 'es' | 'iife' | 'cjs' | 'umd'
+    /**
+     * Names of global variables for external modules, used if `format` is
+     * `umd`.
+     *
+     * If an external module is not listed, the name is derived from the
+     * module specifier, like `react-dom` => `reactDom`.
+     */
+    globals?: { [specifier: string]: string }
+    /**
+     * Prepended to the names of emitted assets to create urls.
+     */
+    publicPath?: string
 }
 
 
 export interface ModuleConfig {
-
+    /**
+     * Images and fonts smaller than this (in bytes) are inlined as data urls.
+     *
+     * Defaults to `8192`.
+     */
+    assetInlineLimit?: number
 }
 
 export type Mode = 'production' | 'development' | 'none';